@@ -1,7 +1,31 @@
-import { app, BrowserWindow, ipcMain, dialog, safeStorage } from 'electron';
+import {
+  app,
+  BrowserWindow,
+  clipboard,
+  ipcMain,
+  dialog,
+  Notification,
+  protocol,
+  safeStorage,
+  shell,
+} from 'electron';
 import path from 'node:path';
 import { fileURLToPath } from 'node:url';
-import { promises as fs } from 'node:fs';
+import { constants as fsConstants, promises as fs, watch as watchFs } from 'node:fs';
+import http from 'node:http';
+import os from 'node:os';
+import { spawn } from 'node:child_process';
+import {
+  createHash,
+  createPrivateKey,
+  createPublicKey,
+  generateKeyPairSync,
+  randomUUID,
+  sign as signData,
+  timingSafeEqual,
+  verify as verifyData,
+} from 'node:crypto';
+import { isUtf8 } from 'node:buffer';
 import git from 'isomorphic-git';
 
 const __filename = fileURLToPath(import.meta.url);
@@ -29,7 +53,14 @@ function createWindow() {
   }
 }
 
+// A second launch (e.g. from a tracyfy:// link) hands its arguments to the running app
+const isPrimaryInstance = app.requestSingleInstanceLock();
+if (!isPrimaryInstance) {
+  app.quit();
+}
+
 app.whenReady().then(() => {
+  if (!isPrimaryInstance) return;
   createWindow();
 
   app.on('activate', () => {
@@ -45,9 +76,122 @@ app.on('window-all-closed', () => {
   }
 });
 
-// Git IPC handlers - run in Node context with real fs
+// ========== ARGUMENT VALIDATION ==========
+
+// Commands check the arguments from the renderer before doing anything, so malformed
+// input is refused with a message naming the argument instead of surfacing as a
+// confusing git or fs error deep inside the handler. The renderer gets the code.
+const MAX_PATH_LENGTH = 4096;
+const MAX_REF_LENGTH = 255;
+const MAX_MESSAGE_LENGTH = 64 * 1024;
+// Artifact folders of a project, as sent in plugin export requests
+const ARTIFACT_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+class InvalidArgumentError extends Error {
+  constructor(name, problem) {
+    super(`Invalid ${name}: ${problem}`);
+    this.name = 'InvalidArgumentError';
+    this.code = 'EINVALIDARG';
+  }
+}
+
+function requireString(value, name) {
+  if (typeof value !== 'string') throw new InvalidArgumentError(name, 'must be a string');
+  if (!value.trim()) throw new InvalidArgumentError(name, 'must not be empty');
+  return value;
+}
+
+const validate = {
+  path(value, name = 'path') {
+    requireString(value, name);
+    if (value.length > MAX_PATH_LENGTH) {
+      throw new InvalidArgumentError(name, `longer than ${MAX_PATH_LENGTH} characters`);
+    }
+    if (value.includes('\0')) throw new InvalidArgumentError(name, 'contains a NUL character');
+    return value;
+  },
+  paths(value, name = 'paths') {
+    if (!Array.isArray(value)) throw new InvalidArgumentError(name, 'must be a list');
+    return value.map((item) => validate.path(item, name));
+  },
+  // Full 40-character SHA-1 object ID; abbreviations are resolved by the renderer
+  oid(value, name = 'commit hash') {
+    if (typeof value !== 'string' || !/^[0-9a-f]{40}$/i.test(value)) {
+      throw new InvalidArgumentError(name, `not a full object ID: ${String(value).slice(0, 50)}`);
+    }
+    return value;
+  },
+  // Branch, tag or ref name following the rules of git check-ref-format
+  ref(value, name = 'ref') {
+    requireString(value, name);
+    if (
+      value.length > MAX_REF_LENGTH ||
+      /[^\x21-\x7e\u0080-\uffff]|[~^:?*[\\]|\.\.|@\{|\/\/|^[/.]|[/.]$|\.lock$/.test(value) ||
+      value === '@'
+    ) {
+      throw new InvalidArgumentError(name, `not a valid name: ${value.slice(0, 50)}`);
+    }
+    return value;
+  },
+  message(value, name = 'commit message') {
+    requireString(value, name);
+    if (value.length > MAX_MESSAGE_LENGTH) {
+      throw new InvalidArgumentError(name, `longer than ${MAX_MESSAGE_LENGTH} characters`);
+    }
+    return value;
+  },
+  // Author or tagger; absent means the default identity
+  person(value, name = 'author') {
+    if (value === undefined || value === null) return value;
+    if (typeof value !== 'object') throw new InvalidArgumentError(name, 'must be an object');
+    requireString(value.name, `${name} name`);
+    if (value.email !== undefined && typeof value.email !== 'string') {
+      throw new InvalidArgumentError(`${name} email`, 'must be a string');
+    }
+    return value;
+  },
+  // String that may be empty, e.g. file content
+  text(value, name = 'content') {
+    if (typeof value !== 'string') throw new InvalidArgumentError(name, 'must be a string');
+    return value;
+  },
+  url(value, name = 'URL') {
+    requireString(value, name);
+    let protocol;
+    try {
+      ({ protocol } = new URL(value));
+    } catch {
+      throw new InvalidArgumentError(name, `not a URL: ${value.slice(0, 100)}`);
+    }
+    if (protocol !== 'https:' && protocol !== 'http:') {
+      throw new InvalidArgumentError(name, `unsupported protocol ${protocol}`);
+    }
+    return value;
+  },
+  count(value, name) {
+    if (!Number.isInteger(value) || value < 0) {
+      throw new InvalidArgumentError(name, 'must be a whole number of at least 0');
+    }
+    return value;
+  },
+  artifactType(value, name = 'artifact type') {
+    if (!ARTIFACT_TYPES.includes(value)) {
+      throw new InvalidArgumentError(name, `must be one of ${ARTIFACT_TYPES.join(', ')}`);
+    }
+    return value;
+  },
+  // Undefined and null pass; anything else is checked with the given validator
+  optional(check, value, ...args) {
+    return value === undefined || value === null ? value : check(value, ...args);
+  },
+};
+
+// Git IPC handlers - run in Node context with real fs. dir must be the open project or a
+// folder inside it, and git works on the resolved path, never on the one the renderer sent.
 ipcMain.handle('git:status', async (_event, dir, filepath) => {
   try {
+    dir = resolveInProject(dir);
+    validate.path(filepath, 'file path');
     return await git.status({ fs, dir, filepath });
   } catch (error) {
     return { error: error.message };
@@ -56,6 +200,7 @@ ipcMain.handle('git:status', async (_event, dir, filepath) => {
 
 ipcMain.handle('git:statusMatrix', async (_event, dir) => {
   try {
+    dir = resolveInProject(dir);
     return await git.statusMatrix({ fs, dir });
   } catch (error) {
     return { error: error.message };
@@ -65,14 +210,19 @@ ipcMain.handle('git:statusMatrix', async (_event, dir) => {
 console.log('[Main] Registering git:checkout handler');
 ipcMain.handle('git:checkout', async (_event, dir, filepath, force) => {
   try {
+    dir = resolveInProject(dir);
+    validate.path(filepath, 'file path');
+    assertWritable('restore files');
     console.log(`[Main] git:checkout ${filepath} in ${dir} (force: ${force})`);
-    await git.checkout({
-      fs,
-      dir,
-      ref: 'HEAD',
-      filepaths: [filepath],
-      force: !!force,
-    });
+    await withOwnBulkChange(() =>
+      git.checkout({
+        fs,
+        dir,
+        ref: 'HEAD',
+        filepaths: [filepath],
+        force: !!force,
+      })
+    );
     console.log(`[Main] git:checkout ${filepath} success`);
     return { ok: true };
   } catch (error) {
@@ -83,6 +233,9 @@ ipcMain.handle('git:checkout', async (_event, dir, filepath, force) => {
 
 ipcMain.handle('git:add', async (_event, dir, filepath) => {
   try {
+    dir = resolveInProject(dir);
+    validate.path(filepath, 'file path');
+    assertWritable('stage files');
     await git.add({ fs, dir, filepath });
     return { ok: true };
   } catch (error) {
@@ -92,6 +245,9 @@ ipcMain.handle('git:add', async (_event, dir, filepath) => {
 
 ipcMain.handle('git:remove', async (_event, dir, filepath) => {
   try {
+    dir = resolveInProject(dir);
+    validate.path(filepath, 'file path');
+    assertWritable('stage deletions');
     await git.remove({ fs, dir, filepath });
     return { ok: true };
   } catch (error) {
@@ -101,6 +257,10 @@ ipcMain.handle('git:remove', async (_event, dir, filepath) => {
 
 ipcMain.handle('git:commit', async (_event, dir, message, author) => {
   try {
+    dir = resolveInProject(dir);
+    validate.message(message);
+    validate.person(author);
+    assertWritable('commit');
     // Ensure HEAD is attached to main branch (not detached)
     // If HEAD contains a raw SHA, commits won't update the branch pointer
     const headPath = path.join(dir, '.git', 'HEAD');
@@ -146,6 +306,10 @@ ipcMain.handle('git:commit', async (_event, dir, message, author) => {
 
 ipcMain.handle('git:log', async (_event, dir, depth, filepath, ref) => {
   try {
+    dir = resolveInProject(dir);
+    validate.optional(validate.count, depth, 'depth');
+    validate.optional(validate.path, filepath, 'file path');
+    validate.optional(validate.ref, ref);
     const commits = await git.log({
       fs,
       dir,
@@ -167,6 +331,8 @@ ipcMain.handle('git:log', async (_event, dir, depth, filepath, ref) => {
 
 ipcMain.handle('git:listFiles', async (_event, dir, ref) => {
   try {
+    dir = resolveInProject(dir);
+    validate.optional(validate.ref, ref);
     return await git.listFiles({ fs, dir, ref });
   } catch (error) {
     return { error: error.message };
@@ -175,6 +341,9 @@ ipcMain.handle('git:listFiles', async (_event, dir, ref) => {
 
 ipcMain.handle('git:readBlob', async (_event, dir, oid, filepath) => {
   try {
+    dir = resolveInProject(dir);
+    validate.oid(oid);
+    validate.optional(validate.path, filepath, 'file path');
     const { blob } = await git.readBlob({ fs, dir, oid, filepath });
     return { blob: Array.from(blob) };
   } catch (error) {
@@ -184,6 +353,8 @@ ipcMain.handle('git:readBlob', async (_event, dir, oid, filepath) => {
 
 ipcMain.handle('git:resolveRef', async (_event, dir, ref) => {
   try {
+    dir = resolveInProject(dir);
+    validate.ref(ref);
     return await git.resolveRef({ fs, dir, ref });
   } catch (error) {
     return { error: error.message };
@@ -192,6 +363,10 @@ ipcMain.handle('git:resolveRef', async (_event, dir, ref) => {
 
 ipcMain.handle('git:isDescendent', async (_event, dir, oid, ancestor, depth) => {
   try {
+    dir = resolveInProject(dir);
+    validate.oid(oid);
+    validate.oid(ancestor, 'ancestor commit hash');
+    validate.optional(validate.count, depth, 'depth');
     return await git.isDescendent({ fs, dir, oid, ancestor, depth });
   } catch (error) {
     return { error: error.message };
@@ -200,6 +375,7 @@ ipcMain.handle('git:isDescendent', async (_event, dir, oid, ancestor, depth) =>
 
 ipcMain.handle('git:currentBranch', async (_event, dir) => {
   try {
+    dir = resolveInProject(dir);
     return await git.currentBranch({ fs, dir });
   } catch (error) {
     return { error: error.message };
@@ -208,6 +384,8 @@ ipcMain.handle('git:currentBranch', async (_event, dir) => {
 
 ipcMain.handle('git:init', async (_event, dir) => {
   try {
+    dir = resolveInProject(dir);
+    assertWritable('create a repository');
     await git.init({ fs, dir, defaultBranch: 'main' });
     return { ok: true };
   } catch (error) {
@@ -217,6 +395,11 @@ ipcMain.handle('git:init', async (_event, dir) => {
 
 ipcMain.handle('git:annotatedTag', async (_event, dir, ref, message, tagger) => {
   try {
+    dir = resolveInProject(dir);
+    validate.ref(ref, 'tag name');
+    validate.message(message, 'tag message');
+    validate.person(tagger, 'tagger');
+    assertWritable('create tags');
     await git.annotatedTag({
       fs,
       dir,
@@ -232,6 +415,7 @@ ipcMain.handle('git:annotatedTag', async (_event, dir, ref, message, tagger) =>
 
 ipcMain.handle('git:listTags', async (_event, dir) => {
   try {
+    dir = resolveInProject(dir);
     return await git.listTags({ fs, dir });
   } catch (error) {
     return { error: error.message };
@@ -240,6 +424,8 @@ ipcMain.handle('git:listTags', async (_event, dir) => {
 
 ipcMain.handle('git:readTag', async (_event, dir, oid) => {
   try {
+    dir = resolveInProject(dir);
+    validate.oid(oid, 'tag ID');
     const tag = await git.readTag({ fs, dir, oid });
     return {
       message: tag.tag.message,
@@ -251,106 +437,555 @@ ipcMain.handle('git:readTag', async (_event, dir, oid) => {
   }
 });
 
+ipcMain.handle('git:writeBlob', async (_event, dir, content) => {
+  try {
+    dir = resolveInProject(dir);
+    validate.text(content);
+    assertWritable('write to the repository');
+    const oid = await git.writeBlob({ fs, dir, blob: Buffer.from(content, 'utf-8') });
+    return { oid };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// Point a ref under refs/tracyfy at a parentless commit of the given blobs (a flat tree).
+// Other refs are refused, so the renderer cannot force-move branches or tags.
+const SNAPSHOT_REF_PREFIX = 'refs/tracyfy/';
+
+ipcMain.handle('git:writeSnapshotRef', async (_event, dir, ref, entries, message) => {
+  try {
+    dir = resolveInProject(dir);
+    validate.ref(ref);
+    if (!ref.startsWith(SNAPSHOT_REF_PREFIX)) {
+      throw new InvalidArgumentError('ref', `must be under ${SNAPSHOT_REF_PREFIX}: ${ref}`);
+    }
+    validate.message(message, 'snapshot message');
+    if (!Array.isArray(entries)) {
+      throw new InvalidArgumentError('snapshot entries', 'must be a list');
+    }
+    entries.forEach((entry) => {
+      validate.path(entry?.path, 'snapshot entry path');
+      validate.oid(entry.oid, 'snapshot entry blob ID');
+    });
+    assertWritable('write to the repository');
+    const tree = await git.writeTree({
+      fs,
+      dir,
+      tree: entries.map(({ path: entryPath, oid }) => ({
+        mode: '100644',
+        path: entryPath,
+        oid,
+        type: 'blob',
+      })),
+    });
+    const signature = {
+      name: 'Tracyfy',
+      email: 'user@tracyfy.local',
+      timestamp: Math.floor(Date.now() / 1000),
+      timezoneOffset: new Date().getTimezoneOffset(),
+    };
+    const oid = await git.writeCommit({
+      fs,
+      dir,
+      commit: { message, tree, parent: [], author: signature, committer: signature },
+    });
+    await git.writeRef({ fs, dir, ref, value: oid, force: true });
+    return { oid };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
 // Filesystem IPC handlers - use Node fs for real disk access
 ipcMain.handle('fs:selectDirectory', async () => {
   try {
+    const { defaultProjectLocation } = await readSettings();
     const result = await dialog.showOpenDialog({
       properties: ['openDirectory', 'createDirectory'],
+      ...(defaultProjectLocation ? { defaultPath: defaultProjectLocation } : {}),
     });
     if (result.canceled || !result.filePaths.length) {
       return { canceled: true };
     }
+    approveProjectRoot(result.filePaths[0]);
     return { path: result.filePaths[0] };
   } catch (error) {
     return { error: error.message };
   }
 });
 
-ipcMain.handle('fs:readFile', async (_event, fullPath) => {
+// ========== OPEN PROJECT ==========
+
+// The renderer opens a project folder with project:open; from then on every fs and git
+// call must stay inside it, and the asset protocol and file manager serve only from it.
+// Changes to its files are reported as project:filesChanged, and those not made by the app
+// itself (other editors, git on the command line) also as project:externalChanges.
+const WATCH_DEBOUNCE_MS = 300;
+const OWN_CHANGE_GRACE_MS = 2000;
+// Folder of app state in the project, never committed (see src/services/git/types)
+const APP_STATE_DIR = '.tracyfy';
+
+let projectRoot = null;
+let projectWatcher = null;
+// Reviewer mode of the open project: commands changing its files or history are refused.
+// Kept per project folder in the settings (readOnlyProjects); see project:setReadOnly
+let projectReadOnly = false;
+
+// Project paths the app changed itself, with the time; and the git operations rewriting the
+// working tree that are running, whose changes all count as the app's own
+const ownChanges = new Map();
+let ownBulkChanges = 0;
+let ownBulkChangesUntil = 0;
+
+function isInside(root, fullPath) {
+  const relative = path.relative(root, fullPath);
+  return relative !== '..' && !relative.startsWith(`..${path.sep}`) && !path.isAbsolute(relative);
+}
+
+// Thrown for paths leading outside the open project; the renderer gets the code
+class PathOutsideProjectError extends Error {
+  constructor(target) {
+    super(`Path is outside the open project: ${target}`);
+    this.name = 'PathOutsideProjectError';
+    this.code = 'EOUTSIDEPROJECT';
+  }
+}
+
+class ReadOnlyProjectError extends Error {
+  constructor(action) {
+    super(`The project is open read-only, cannot ${action}`);
+    this.name = 'ReadOnlyProjectError';
+    this.code = 'EREADONLY';
+  }
+}
+
+// Refuse a change while the project is read-only. App state (drafts, scan index)
+// under .tracyfy is not part of the project, so target paths there are allowed.
+function assertWritable(action, target) {
+  if (!projectReadOnly) return;
+  if (target !== undefined && projectRoot) {
+    const fullPath = path.resolve(projectRoot, String(target));
+    if (isInside(path.join(projectRoot, APP_STATE_DIR), fullPath)) return;
+  }
+  throw new ReadOnlyProjectError(action);
+}
+
+// Absolute path of target, which must be the open project folder or inside it
+function resolveInProject(target) {
+  validate.path(target);
+  if (!projectRoot) throw new Error('No project is open');
+  const fullPath = path.resolve(projectRoot, String(target));
+  if (!isInside(projectRoot, fullPath)) {
+    throw new PathOutsideProjectError(target);
+  }
+  return fullPath;
+}
+
+// Canonical path of target for file access: symlinks along the part of the path
+// that exists are followed and must not lead outside the project. With followLink
+// false the last component is not followed, so deleting a symlink removes the link.
+async function resolveFileInProject(target, { followLink = true } = {}) {
+  const fullPath = resolveInProject(target);
+  const root = await fs.realpath(projectRoot);
+  let existing = followLink ? fullPath : path.dirname(fullPath);
+  const missing = followLink ? [] : [path.basename(fullPath)];
+  for (;;) {
+    try {
+      existing = await fs.realpath(existing);
+      break;
+    } catch (err) {
+      if (err.code !== 'ENOENT' || existing === path.dirname(existing)) throw err;
+      missing.unshift(path.basename(existing));
+      existing = path.dirname(existing);
+    }
+  }
+  const canonical = path.join(existing, ...missing);
+  if (!isInside(root, canonical)) {
+    throw new PathOutsideProjectError(target);
+  }
+  return canonical;
+}
+
+// Resolve a project-relative path to an existing file, or null when it leads
+// outside the project (also after following symlinks). Throws ENOENT if missing.
+async function resolveProjectPath(relativePath) {
+  const root = await fs.realpath(resolveInProject('.'));
+  const fullPath = await fs.realpath(path.resolve(root, relativePath));
+  return isInside(root, fullPath) ? fullPath : null;
+}
+
+function markOwnChange(fullPath) {
+  if (!projectRoot) return;
+  ownChanges.set(path.relative(projectRoot, fullPath).split(path.sep).join('/'), Date.now());
+}
+
+async function withOwnBulkChange(operation) {
+  ownBulkChanges++;
+  try {
+    return await operation();
+  } finally {
+    ownBulkChanges--;
+    ownBulkChangesUntil = Date.now() + OWN_CHANGE_GRACE_MS;
+  }
+}
+
+function isOwnChange(relative, now = Date.now()) {
+  if (ownBulkChanges > 0 || now < ownBulkChangesUntil) return true;
+  const changedAt = ownChanges.get(relative);
+  return changedAt !== undefined && now - changedAt < OWN_CHANGE_GRACE_MS;
+}
+
+function startProjectWatcher(root) {
+  let changed = new Set();
+  let external = new Set();
+  let timer = null;
+  projectWatcher = watchFs(root, { recursive: true }, (_eventType, filename) => {
+    const relative = filename?.split(path.sep).join('/');
+    if (!relative || /^\.(git|tracyfy)(\/|$)/.test(relative)) return;
+    changed.add(relative);
+    if (!isOwnChange(relative)) external.add(relative);
+    clearTimeout(timer);
+    timer = setTimeout(() => {
+      const paths = [...changed];
+      const externalPaths = [...external];
+      changed = new Set();
+      external = new Set();
+      for (const [ownPath, changedAt] of ownChanges) {
+        if (Date.now() - changedAt >= OWN_CHANGE_GRACE_MS) ownChanges.delete(ownPath);
+      }
+      for (const win of BrowserWindow.getAllWindows()) {
+        win.webContents.send('project:filesChanged', paths);
+        if (externalPaths.length > 0) {
+          win.webContents.send('project:externalChanges', externalPaths);
+        }
+      }
+    }, WATCH_DEBOUNCE_MS);
+  });
+  projectWatcher.on('error', (error) => {
+    writeLog('warn', 'project', `File watcher stopped: ${error.message}`);
+    closeProject();
+  });
+}
+
+// Folders the renderer may open as a project: those the user chose in the folder dialog or
+// named on the command line or through a file association in this run, and those opened
+// before, which are remembered in recent-projects.json in the app's config directory. The
+// renderer cannot add to either, so it cannot open any other folder on its own.
+const RECENT_PROJECTS_FILE = 'recent-projects.json';
+const MAX_RECENT_PROJECTS = 20;
+const approvedProjectRoots = new Set();
+
+function approveProjectRoot(dir) {
+  approvedProjectRoots.add(path.resolve(dir));
+}
+
+function recentProjectsPath() {
+  return path.join(app.getPath('userData'), RECENT_PROJECTS_FILE);
+}
+
+async function readRecentProjects() {
+  try {
+    const recent = JSON.parse(await fs.readFile(recentProjectsPath(), 'utf-8'));
+    return Array.isArray(recent) ? recent.filter((root) => typeof root === 'string') : [];
+  } catch (error) {
+    if (error.code !== 'ENOENT') console.warn('Could not read recent projects:', error.message);
+    return [];
+  }
+}
+
+async function rememberProject(root) {
+  const recent = await readRecentProjects();
+  const updated = [root, ...recent.filter((r) => r !== root)].slice(0, MAX_RECENT_PROJECTS);
+  const target = recentProjectsPath();
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(`${target}.tmp`, JSON.stringify(updated, null, 2), 'utf-8');
+  await fs.rename(`${target}.tmp`, target);
+}
+
+async function isApprovedProjectRoot(root) {
+  return approvedProjectRoots.has(root) || (await readRecentProjects()).includes(root);
+}
+
+function closeProject() {
+  projectWatcher?.close();
+  projectWatcher = null;
+  projectRoot = null;
+  projectReadOnly = false;
+  ownChanges.clear();
+}
+
+async function openProject(dir) {
   try {
-    const content = await fs.readFile(fullPath, 'utf8');
-    return { content };
+    const root = path.resolve(String(dir));
+    if (!(await isApprovedProjectRoot(root))) {
+      return { error: `Open ${dir} with Choose Folder first` };
+    }
+    const stat = await fs.stat(root);
+    if (!stat.isDirectory()) {
+      return { error: `Not a folder: ${dir}` };
+    }
+    await fs.access(root, fsConstants.R_OK | fsConstants.W_OK);
+    const { readOnlyProjects } = await readSettings();
+    closeProject();
+    projectRoot = root;
+    projectReadOnly = readOnlyProjects.includes(root);
+    try {
+      startProjectWatcher(root);
+    } catch (error) {
+      // Still usable, just without notice of external changes
+      writeLog('warn', 'project', `Could not watch ${root}: ${error.message}`);
+    }
+    const hasGit = await fs.access(path.join(root, '.git')).then(
+      () => true,
+      () => false
+    );
+    await rememberProject(root).catch((error) =>
+      writeLog('warn', 'project', `Could not remember ${root}: ${error.message}`)
+    );
+    return { path: root, hasGit, readOnly: projectReadOnly };
   } catch (error) {
     if (error.code === 'ENOENT') {
-      return { notFound: true };
+      return { error: `Folder not found: ${dir}` };
     }
     return { error: error.message };
   }
+}
+
+ipcMain.handle('project:open', async (_event, dir) => openProject(dir));
+
+// Turning reviewer mode on is always allowed. Turning it off takes the user's confirmation in
+// a dialog of the main process, so the renderer cannot lift it by itself.
+ipcMain.handle('project:setReadOnly', async (event, readOnly) => {
+  try {
+    const root = resolveInProject('.');
+    if (!readOnly && projectReadOnly) {
+      const parent = BrowserWindow.fromWebContents(event.sender);
+      const { response } = await dialog.showMessageBox(parent, {
+        type: 'warning',
+        buttons: ['Make Editable', 'Cancel'],
+        defaultId: 1,
+        cancelId: 1,
+        message: 'Make this project editable?',
+        detail: `${root}\n\nChanges to its files and history will be allowed again.`,
+      });
+      if (response !== 0) return { readOnly: true, canceled: true };
+    }
+    const current = await readSettings();
+    const others = current.readOnlyProjects.filter((dir) => dir !== root);
+    await writeSettings({ ...current, readOnlyProjects: readOnly ? [...others, root] : others });
+    projectReadOnly = !!readOnly;
+    writeLog('info', 'project', `Read-only ${projectReadOnly ? 'on' : 'off'} for ${root}`);
+    return { readOnly: projectReadOnly };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
 });
 
-ipcMain.handle('fs:readFileBinary', async (_event, fullPath) => {
+ipcMain.handle('project:close', async () => {
+  closeProject();
+  return { ok: true };
+});
+
+// Show a project file selected in Explorer/Finder/the desktop file manager. API version 1
+// clients pass the project folder first; it must be the open project.
+ipcMain.handle('shell:revealInFileManager', async (_event, ...args) => {
+  const relativePath = args.length > 1 ? args[1] : args[0];
   try {
-    const buffer = await fs.readFile(fullPath);
-    return { data: Array.from(buffer) };
+    if (args.length > 1) {
+      warnDeprecated('shell:revealInFileManager(rootDir, relativePath)');
+      if (path.resolve(String(args[0])) !== resolveInProject('.')) {
+        return { error: `Not the open project: ${args[0]}` };
+      }
+    }
+    const fullPath = await resolveProjectPath(relativePath);
+    if (!fullPath) {
+      return { error: `Path is outside the project: ${relativePath}` };
+    }
+    shell.showItemInFolder(fullPath);
+    return { ok: true };
   } catch (error) {
     if (error.code === 'ENOENT') {
-      return { notFound: true };
+      return { error: `File not found: ${relativePath}` };
     }
     return { error: error.message };
   }
 });
 
-ipcMain.handle('fs:writeFile', async (_event, fullPath, content) => {
+// Put a table on the clipboard as HTML and tab-separated text at once, so
+// spreadsheets and word processors each pick the flavor they understand
+ipcMain.handle('clipboard:writeTable', async (_event, { html, text }) => {
   try {
-    await fs.mkdir(path.dirname(fullPath), { recursive: true });
-    await fs.writeFile(fullPath, content, 'utf8');
+    if (typeof html !== 'string' || typeof text !== 'string') {
+      return { error: 'Clipboard table needs html and text' };
+    }
+    clipboard.write({ html, text });
     return { ok: true };
   } catch (error) {
     return { error: error.message };
   }
 });
 
+async function readTextFile(fullPath) {
+  try {
+    const buffer = await fs.readFile(await resolveFileInProject(fullPath));
+    // Non-UTF-8 files (UTF-16, Latin-1) are returned as bytes for the renderer to decode
+    if (!isUtf8(buffer)) {
+      return { data: Array.from(buffer) };
+    }
+    return { content: buffer.toString('utf8') };
+  } catch (error) {
+    if (error.code === 'ENOENT') {
+      return { notFound: true };
+    }
+    return { error: error.message, code: error.code };
+  }
+}
+
+ipcMain.handle('fs:readFile', async (_event, fullPath) => readTextFile(fullPath));
+
+// Batch read so views showing many artifacts need a single round-trip
+ipcMain.handle('fs:readFiles', async (_event, fullPaths) => {
+  try {
+    return { results: await Promise.all(validate.paths(fullPaths).map(readTextFile)) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+// Modification time and size, for caches of parsed files
+async function statFile(fullPath) {
+  try {
+    const stats = await fs.stat(await resolveFileInProject(fullPath));
+    return { mtime: stats.mtimeMs, size: stats.size };
+  } catch (error) {
+    if (error.code === 'ENOENT') {
+      return { notFound: true };
+    }
+    return { error: error.message, code: error.code };
+  }
+}
+
+ipcMain.handle('fs:statFiles', async (_event, fullPaths) => {
+  try {
+    return { results: await Promise.all(validate.paths(fullPaths).map(statFile)) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+ipcMain.handle('fs:readFileBinary', async (_event, fullPath) => {
+  try {
+    const buffer = await fs.readFile(await resolveFileInProject(fullPath));
+    return { data: Array.from(buffer) };
+  } catch (error) {
+    if (error.code === 'ENOENT') {
+      return { notFound: true };
+    }
+    return { error: error.message, code: error.code };
+  }
+});
+
+ipcMain.handle('fs:readFileChunk', async (_event, fullPath, offset, length) => {
+  let handle;
+  try {
+    validate.count(offset, 'offset');
+    validate.count(length, 'length');
+    handle = await fs.open(await resolveFileInProject(fullPath), 'r');
+    const { size } = await handle.stat();
+    const buffer = Buffer.alloc(Math.max(0, Math.min(length, size - offset)));
+    const { bytesRead } = await handle.read(buffer, 0, buffer.length, offset);
+    return { data: Array.from(buffer.subarray(0, bytesRead)), totalSize: size };
+  } catch (error) {
+    if (error.code === 'ENOENT') {
+      return { notFound: true };
+    }
+    return { error: error.message, code: error.code };
+  } finally {
+    await handle?.close();
+  }
+});
+
+async function writeTextFile(fullPath, content) {
+  try {
+    validate.text(content);
+    assertWritable('write files', fullPath);
+    markOwnChange(resolveInProject(fullPath));
+    const target = await resolveFileInProject(fullPath);
+    await fs.mkdir(path.dirname(target), { recursive: true });
+    await fs.writeFile(target, content, 'utf8');
+    return { ok: true };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+}
+
+ipcMain.handle('fs:writeFile', async (_event, fullPath, content) =>
+  writeTextFile(fullPath, content)
+);
+
 ipcMain.handle('fs:writeFileBinary', async (_event, fullPath, dataArray) => {
   try {
-    await fs.mkdir(path.dirname(fullPath), { recursive: true });
-    const buffer = Buffer.from(dataArray);
-    await fs.writeFile(fullPath, buffer);
+    if (!Array.isArray(dataArray) && !ArrayBuffer.isView(dataArray)) {
+      throw new InvalidArgumentError('content', 'must be a list of bytes');
+    }
+    assertWritable('write files', fullPath);
+    markOwnChange(resolveInProject(fullPath));
+    const target = await resolveFileInProject(fullPath);
+    await fs.mkdir(path.dirname(target), { recursive: true });
+    await fs.writeFile(target, Buffer.from(dataArray));
     return { ok: true };
   } catch (error) {
-    return { error: error.message };
+    return { error: error.message, code: error.code };
   }
 });
 
-ipcMain.handle('fs:deleteFile', async (_event, fullPath) => {
+async function deleteFile(fullPath) {
   try {
-    await fs.unlink(fullPath);
+    assertWritable('delete files', fullPath);
+    markOwnChange(resolveInProject(fullPath));
+    await fs.unlink(await resolveFileInProject(fullPath, { followLink: false }));
     return { ok: true };
   } catch (error) {
     if (error.code === 'ENOENT') {
       return { ok: true };
     }
-    return { error: error.message };
+    return { error: error.message, code: error.code };
   }
-});
+}
+
+ipcMain.handle('fs:deleteFile', async (_event, fullPath) => deleteFile(fullPath));
 
-ipcMain.handle('fs:listFiles', async (_event, dirPath) => {
+async function listFiles(dirPath) {
   try {
-    const entries = await fs.readdir(dirPath, { withFileTypes: true });
+    const entries = await fs.readdir(await resolveFileInProject(dirPath), { withFileTypes: true });
     const files = entries.filter((e) => e.isFile()).map((e) => e.name);
     return { files };
   } catch (error) {
     if (error.code === 'ENOENT') {
       return { files: [] };
     }
-    return { error: error.message };
+    return { error: error.message, code: error.code };
   }
-});
+}
+
+ipcMain.handle('fs:listFiles', async (_event, dirPath) => listFiles(dirPath));
 
 ipcMain.handle('fs:listEntries', async (_event, dirPath) => {
   try {
-    const entries = await fs.readdir(dirPath);
+    const entries = await fs.readdir(await resolveFileInProject(dirPath));
     return { entries };
   } catch (error) {
     if (error.code === 'ENOENT') {
       return { entries: [] };
     }
-    return { error: error.message };
+    return { error: error.message, code: error.code };
   }
 });
 
 ipcMain.handle('fs:checkExists', async (_event, fullPath) => {
   try {
-    await fs.access(fullPath);
+    await fs.access(await resolveFileInProject(fullPath));
     return { exists: true };
   } catch {
     return { exists: false };
@@ -359,17 +994,104 @@ ipcMain.handle('fs:checkExists', async (_event, fullPath) => {
 
 ipcMain.handle('fs:mkdir', async (_event, dirPath) => {
   try {
-    await fs.mkdir(dirPath, { recursive: true });
+    const target = await resolveFileInProject(dirPath);
+    // Folders that exist already are fine in a read-only project, e.g. when it is opened
+    const isDirectory = await fs.stat(target).then(
+      (stat) => stat.isDirectory(),
+      () => false
+    );
+    if (isDirectory) return { ok: true };
+    assertWritable('create folders', dirPath);
+    markOwnChange(resolveInProject(dirPath));
+    await fs.mkdir(target, { recursive: true });
     return { ok: true };
   } catch (error) {
-    return { error: error.message };
+    return { error: error.message, code: error.code };
   }
 });
 
+// Standalone link files (links/<id>.md) of the open project
+const LINKS_FOLDER = 'links';
+const LINK_NAME_PATTERN = /^[A-Za-z0-9][A-Za-z0-9._-]*$/;
+
+function validateLinkName(value, name) {
+  if (typeof value !== 'string' || !LINK_NAME_PATTERN.test(value)) {
+    throw new InvalidArgumentError(name, `not a valid ID: ${String(value).slice(0, 50)}`);
+  }
+  return value;
+}
+
+// Link files whose frontmatter names the artifact as source or target
+async function readArtifactLinks(artifactId) {
+  try {
+    validateLinkName(artifactId, 'artifact ID');
+    const listed = await listFiles(LINKS_FOLDER);
+    if (listed.error) return listed;
+    const id = artifactId.replace(/\./g, '\\.');
+    const endpoint = new RegExp(`^(sourceId|targetId):[ \\t]*["']?${id}["']?[ \\t]*\\r?$`, 'm');
+    const links = [];
+    for (const name of listed.files.filter((file) => file.endsWith('.md'))) {
+      const linkPath = `${LINKS_FOLDER}/${name}`;
+      const { content } = await readTextFile(linkPath);
+      if (content !== undefined && endpoint.test(content)) {
+        links.push({ path: linkPath, content });
+      }
+    }
+    return { links };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+}
+
+async function writeLinkFile(linkId, content) {
+  try {
+    validateLinkName(linkId, 'link ID');
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+  return writeTextFile(`${LINKS_FOLDER}/${linkId}.md`, content);
+}
+
+// Several file operations in one round-trip, so views loading an artifact
+// with its sidecar files and links do not wait on one IPC call after another.
+// They run in order and each gets its own result; a failed one does not stop
+// the rest. A link operation reads the links of an artifact or, given content,
+// writes one link file.
+const BATCH_OPERATIONS = {
+  read: (operation) => readTextFile(operation.path),
+  write: (operation) => writeTextFile(operation.path, String(operation.content ?? '')),
+  delete: (operation) => deleteFile(operation.path),
+  list: (operation) => listFiles(operation.path),
+  stat: (operation) => statFile(operation.path),
+  link: (operation) =>
+    operation.content === undefined
+      ? readArtifactLinks(operation.artifactId)
+      : writeLinkFile(operation.linkId, String(operation.content)),
+};
+
+ipcMain.handle('fs:batch', async (_event, operations) => {
+  if (!Array.isArray(operations)) {
+    const error = new InvalidArgumentError('operations', 'must be a list');
+    return { error: error.message, code: error.code };
+  }
+  const results = [];
+  for (const operation of operations) {
+    const run = Object.hasOwn(BATCH_OPERATIONS, operation?.type)
+      ? BATCH_OPERATIONS[operation.type]
+      : null;
+    results.push(run ? await run(operation) : { error: `Unknown operation: ${operation?.type}` });
+  }
+  return { results };
+});
+
 // ========== REMOTE GIT OPERATIONS ==========
 
 ipcMain.handle('git:addRemote', async (_event, dir, name, url) => {
   try {
+    dir = resolveInProject(dir);
+    validate.ref(name, 'remote name');
+    validate.url(url, 'remote URL');
+    assertWritable('change remotes');
     await git.addRemote({ fs, dir, remote: name, url });
     return { ok: true };
   } catch (error) {
@@ -379,6 +1101,9 @@ ipcMain.handle('git:addRemote', async (_event, dir, name, url) => {
 
 ipcMain.handle('git:removeRemote', async (_event, dir, name) => {
   try {
+    dir = resolveInProject(dir);
+    validate.ref(name, 'remote name');
+    assertWritable('change remotes');
     await git.deleteRemote({ fs, dir, remote: name });
     return { ok: true };
   } catch (error) {
@@ -388,6 +1113,7 @@ ipcMain.handle('git:removeRemote', async (_event, dir, name) => {
 
 ipcMain.handle('git:listRemotes', async (_event, dir) => {
   try {
+    dir = resolveInProject(dir);
     const remotes = await git.listRemotes({ fs, dir });
     return remotes.map((r) => ({ name: r.remote, url: r.url }));
   } catch (error) {
@@ -397,6 +1123,9 @@ ipcMain.handle('git:listRemotes', async (_event, dir) => {
 
 ipcMain.handle('git:fetch', async (_event, dir, remote, branch, token) => {
   try {
+    dir = resolveInProject(dir);
+    validate.optional(validate.ref, remote, 'remote name');
+    validate.optional(validate.ref, branch, 'branch');
     const http = await import('isomorphic-git/http/node').then((m) => m.default);
     await git.fetch({
       fs,
@@ -415,6 +1144,10 @@ ipcMain.handle('git:fetch', async (_event, dir, remote, branch, token) => {
 
 ipcMain.handle('git:push', async (_event, dir, remote, branch, token) => {
   try {
+    dir = resolveInProject(dir);
+    validate.optional(validate.ref, remote, 'remote name');
+    validate.optional(validate.ref, branch, 'branch');
+    assertWritable('push');
     const http = await import('isomorphic-git/http/node').then((m) => m.default);
     await git.push({
       fs,
@@ -432,16 +1165,23 @@ ipcMain.handle('git:push', async (_event, dir, remote, branch, token) => {
 
 ipcMain.handle('git:pull', async (_event, dir, remote, branch, token, author) => {
   try {
+    dir = resolveInProject(dir);
+    validate.optional(validate.ref, remote, 'remote name');
+    validate.optional(validate.ref, branch, 'branch');
+    validate.person(author);
+    assertWritable('pull');
     const http = await import('isomorphic-git/http/node').then((m) => m.default);
-    await git.pull({
-      fs,
-      http,
-      dir,
-      remote: remote || 'origin',
-      ref: branch || 'main',
-      author: author || { name: 'Tracyfy User', email: 'user@tracyfy.local' },
-      onAuth: token ? () => ({ username: 'x-access-token', password: token }) : undefined,
-    });
+    await withOwnBulkChange(() =>
+      git.pull({
+        fs,
+        http,
+        dir,
+        remote: remote || 'origin',
+        ref: branch || 'main',
+        author: author || { name: 'Tracyfy User', email: 'user@tracyfy.local' },
+        onAuth: token ? () => ({ username: 'x-access-token', password: token }) : undefined,
+      })
+    );
     return { ok: true, conflicts: [] };
   } catch (error) {
     if (error.code === 'MergeConflictError' || error.code === 'CheckoutConflictError') {
@@ -453,24 +1193,36 @@ ipcMain.handle('git:pull', async (_event, dir, remote, branch, token, author) =>
 
 // ========== SECURE STORAGE OPERATIONS ==========
 
-ipcMain.handle('secure:setToken', async (_event, token) => {
+// Tokens are stored per service (git, jira, ...); the git token keeps its original file name
+function tokenPath(name = 'git') {
+  if (!/^[a-z0-9-]+$/.test(name)) {
+    throw new Error(`Invalid token name: ${name}`);
+  }
+  return path.join(app.getPath('userData'), `${name}-token.bin`);
+}
+
+// On Linux without a keyring safeStorage falls back to a hardcoded key; treat that as unavailable
+function isKeychainAvailable() {
+  if (!safeStorage.isEncryptionAvailable()) return false;
+  return process.platform !== 'linux' || safeStorage.getSelectedStorageBackend() !== 'basic_text';
+}
+
+ipcMain.handle('secure:setToken', async (_event, token, name) => {
   try {
-    if (!safeStorage.isEncryptionAvailable()) {
-      throw new Error('Encryption is not available on this system');
+    if (!isKeychainAvailable()) {
+      throw new Error('No OS keychain is available to protect secrets on this system');
     }
     const encrypted = safeStorage.encryptString(token);
-    const tokenPath = path.join(app.getPath('userData'), 'git-token.bin');
-    await fs.writeFile(tokenPath, encrypted);
+    await fs.writeFile(tokenPath(name), encrypted);
     return { ok: true };
   } catch (error) {
     return { error: error.message };
   }
 });
 
-ipcMain.handle('secure:getToken', async () => {
+ipcMain.handle('secure:getToken', async (_event, name) => {
   try {
-    const tokenPath = path.join(app.getPath('userData'), 'git-token.bin');
-    const encrypted = await fs.readFile(tokenPath);
+    const encrypted = await fs.readFile(tokenPath(name));
     if (!safeStorage.isEncryptionAvailable()) {
       throw new Error('Encryption is not available');
     }
@@ -481,13 +1233,1029 @@ ipcMain.handle('secure:getToken', async () => {
   }
 });
 
-ipcMain.handle('secure:removeToken', async () => {
+ipcMain.handle('secure:removeToken', async (_event, name) => {
   try {
-    const tokenPath = path.join(app.getPath('userData'), 'git-token.bin');
-    await fs.unlink(tokenPath);
+    await fs.unlink(tokenPath(name));
     return { ok: true };
   } catch (error) {
     if (error.code === 'ENOENT') return { ok: true };
     return { error: error.message };
   }
 });
+
+// ========== SIGNING ==========
+
+// Ed25519 for minisign signatures. Electron's WebCrypto has no Ed25519, so the renderer signs
+// and verifies here. Keys and data travel as byte arrays; private keys as base64 PKCS #8.
+const ED25519_PUBLIC_KEY_BYTES = 32;
+const MINISIGN_KEY_ID_BYTES = 8;
+
+function toBytes(value, name) {
+  if (!Array.isArray(value) || !value.every((b) => Number.isInteger(b) && b >= 0 && b <= 255)) {
+    throw new InvalidArgumentError(name, 'must be a list of bytes');
+  }
+  return Buffer.from(value);
+}
+
+ipcMain.handle('signing:generateKey', async () => {
+  try {
+    const { publicKey, privateKey } = generateKeyPairSync('ed25519');
+    const { x } = publicKey.export({ format: 'jwk' });
+    return {
+      publicKey: Array.from(Buffer.from(x, 'base64url')),
+      privateKey: privateKey.export({ format: 'der', type: 'pkcs8' }).toString('base64'),
+    };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+ipcMain.handle('signing:sign', async (_event, privateKey, data) => {
+  try {
+    const key = createPrivateKey({
+      key: Buffer.from(requireString(privateKey, 'private key'), 'base64'),
+      format: 'der',
+      type: 'pkcs8',
+    });
+    if (key.asymmetricKeyType !== 'ed25519') {
+      throw new InvalidArgumentError('private key', 'not an Ed25519 key');
+    }
+    return { signature: Array.from(signData(null, toBytes(data, 'data'), key)) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+ipcMain.handle('signing:verify', async (_event, publicKey, signature, data) => {
+  try {
+    const raw = toBytes(publicKey, 'public key');
+    if (raw.length !== ED25519_PUBLIC_KEY_BYTES) {
+      throw new InvalidArgumentError('public key', `must be ${ED25519_PUBLIC_KEY_BYTES} bytes`);
+    }
+    const key = createPublicKey({
+      key: { kty: 'OKP', crv: 'Ed25519', x: raw.toString('base64url') },
+      format: 'jwk',
+    });
+    return { valid: verifyData(null, toBytes(data, 'data'), key, toBytes(signature, 'signature')) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+// ========== HTTP OPERATIONS ==========
+
+// Requests to issue trackers and webhooks go through the main process, which is not subject to
+// CORS. Each request names the integration making it and may only reach the servers set up for
+// that integration on this machine for the open project, so the renderer cannot use this as a
+// general proxy.
+const HTTP_METHODS = ['GET', 'POST', 'PUT'];
+const GITHUB_API_URL = 'https://api.github.com';
+const DEFAULT_GITLAB_URL = 'https://gitlab.com';
+// Hop-by-hop headers and Host are set by fetch itself
+const DROPPED_HEADERS = new Set([
+  'connection',
+  'host',
+  'keep-alive',
+  'proxy-authenticate',
+  'proxy-authorization',
+  'proxy-connection',
+  'te',
+  'trailer',
+  'transfer-encoding',
+  'upgrade',
+]);
+
+async function getIntegrationOrigins(integration) {
+  if (!projectRoot) return [];
+  const settings = await readSettings();
+  const urls = settings.integrationUrls[projectRoot] ?? {};
+  const hooks = settings.webhooks[projectRoot];
+  const configured = {
+    github: [GITHUB_API_URL],
+    jira: [urls.jira],
+    gitlab: [urls.gitlab || DEFAULT_GITLAB_URL],
+    webhook: Array.isArray(hooks) ? hooks.map((hook) => hook?.url) : [],
+  }[integration];
+  return (configured ?? []).flatMap((url) => {
+    try {
+      return typeof url === 'string' ? [new URL(url).origin] : [];
+    } catch {
+      return [];
+    }
+  });
+}
+
+ipcMain.handle('http:request', async (_event, request) => {
+  try {
+    const url = new URL(validate.url(request?.url));
+    const method = (request.method || 'GET').toUpperCase();
+    if (!HTTP_METHODS.includes(method)) {
+      throw new InvalidArgumentError('method', `must be one of ${HTTP_METHODS.join(', ')}`);
+    }
+    if (!(await getIntegrationOrigins(request.integration)).includes(url.origin)) {
+      throw new Error(`${url.origin} is not a server set up for ${request.integration}`);
+    }
+    const headers = Object.fromEntries(
+      Object.entries(request.headers ?? {}).filter(
+        ([name, value]) => typeof value === 'string' && !DROPPED_HEADERS.has(name.toLowerCase())
+      )
+    );
+    const response = await fetch(url, {
+      method,
+      headers,
+      body: validate.optional(validate.text, request.body, 'body'),
+      // Redirects come back as responses rather than leading to another host
+      redirect: 'manual',
+    });
+    return {
+      status: response.status,
+      headers: Object.fromEntries(response.headers.entries()),
+      body: await response.text(),
+    };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// ========== LOCAL API SERVER ==========
+
+// Optional read-only HTTP API for scripts and CI jobs. The server only listens on
+// localhost and requires the bearer token; the renderer answers the requests. The port must
+// be unprivileged and the token at least 32 random bytes, hex encoded.
+const API_TIMEOUT_MS = 30000;
+const API_MIN_PORT = 1024;
+const API_TOKEN_PATTERN = /^[0-9a-f]{64,}$/;
+let apiServer = null;
+const pendingApiRequests = new Map();
+
+function isAuthorized(header, token) {
+  const expected = Buffer.from(`Bearer ${token}`);
+  const actual = Buffer.from(header || '');
+  return actual.length === expected.length && timingSafeEqual(actual, expected);
+}
+
+function sendJson(res, status, body) {
+  res.writeHead(status, { 'Content-Type': 'application/json; charset=utf-8' });
+  res.end(JSON.stringify(body));
+}
+
+ipcMain.on('api:response', (_event, response) => {
+  const pending = pendingApiRequests.get(response.requestId);
+  if (pending) {
+    pendingApiRequests.delete(response.requestId);
+    pending(response);
+  }
+});
+
+ipcMain.handle('api:start', async (event, port, token) => {
+  try {
+    if (!Number.isInteger(port) || port < API_MIN_PORT || port > 65535) {
+      const range = `${API_MIN_PORT} to 65535`;
+      throw new InvalidArgumentError('port', `must be a whole number from ${range}`);
+    }
+    if (typeof token !== 'string' || !API_TOKEN_PATTERN.test(token)) {
+      throw new InvalidArgumentError('token', 'must be at least 32 random bytes in hex');
+    }
+    if (apiServer) apiServer.close();
+    const sender = event.sender;
+    apiServer = http.createServer(async (req, res) => {
+      if (!isAuthorized(req.headers.authorization, token)) {
+        return sendJson(res, 401, { error: 'Unauthorized' });
+      }
+      if (req.method !== 'GET') {
+        return sendJson(res, 405, { error: 'Only GET requests are supported' });
+      }
+      const url = new URL(req.url, 'http://localhost');
+      const requestId = randomUUID();
+      const response = await new Promise((resolve) => {
+        pendingApiRequests.set(requestId, resolve);
+        setTimeout(() => {
+          if (pendingApiRequests.delete(requestId)) {
+            resolve({ status: 504, body: { error: 'Request timed out' } });
+          }
+        }, API_TIMEOUT_MS);
+        sender.send('api:request', {
+          requestId,
+          path: url.pathname,
+          query: Object.fromEntries(url.searchParams.entries()),
+        });
+      });
+      sendJson(res, response.status, response.body);
+    });
+    await new Promise((resolve, reject) => {
+      apiServer.once('error', reject);
+      apiServer.listen(port, '127.0.0.1', resolve);
+    });
+    return { ok: true };
+  } catch (error) {
+    apiServer = null;
+    return { error: error.message, code: error.code };
+  }
+});
+
+ipcMain.handle('api:stop', async () => {
+  if (apiServer) {
+    apiServer.close();
+    apiServer = null;
+  }
+  return { ok: true };
+});
+
+// ========== PLUGINS ==========
+
+// Importer/exporter plugins: folders in <userData>/plugins with a plugin.json manifest naming
+// an executable. A request is written to its stdin as JSON; it answers with JSON on stdout.
+const PLUGIN_TIMEOUT_MS = 60000;
+const PLUGIN_CAPABILITIES = ['import', 'export'];
+
+function pluginsDir() {
+  return path.join(app.getPath('userData'), 'plugins');
+}
+
+async function readPluginManifests() {
+  let entries;
+  try {
+    entries = await fs.readdir(pluginsDir(), { withFileTypes: true });
+  } catch (error) {
+    if (error.code === 'ENOENT') return [];
+    throw error;
+  }
+
+  const plugins = [];
+  for (const entry of entries.filter((e) => e.isDirectory())) {
+    const dir = path.join(pluginsDir(), entry.name);
+    try {
+      const manifest = JSON.parse(await fs.readFile(path.join(dir, 'plugin.json'), 'utf-8'));
+      const capabilities = (manifest.capabilities || []).filter((c) =>
+        PLUGIN_CAPABILITIES.includes(c)
+      );
+      if (!manifest.name || !manifest.command || capabilities.length === 0) {
+        console.warn(`[plugins] Skipping ${entry.name}: incomplete plugin.json`);
+        continue;
+      }
+      plugins.push({ ...manifest, capabilities, dir });
+    } catch (error) {
+      console.warn(`[plugins] Skipping ${entry.name}: ${error.message}`);
+    }
+  }
+  return plugins;
+}
+
+// Run a command with optional stdin input; resolves with stdout as a Buffer
+function runProcess(command, args, { cwd, input, timeoutMs } = {}) {
+  return new Promise((resolve, reject) => {
+    const child = spawn(command, args, { cwd });
+    const stdout = [];
+    let stderr = '';
+    const timer = setTimeout(() => {
+      child.kill();
+      reject(new Error(`${path.basename(command)} timed out`));
+    }, timeoutMs);
+
+    child.stdout.on('data', (chunk) => stdout.push(chunk));
+    child.stderr.on('data', (chunk) => (stderr += chunk));
+    child.on('error', (error) => {
+      clearTimeout(timer);
+      reject(error);
+    });
+    child.on('close', (code) => {
+      clearTimeout(timer);
+      if (code !== 0) {
+        reject(new Error(`${path.basename(command)} exited with ${code}: ${stderr.trim()}`));
+        return;
+      }
+      resolve(Buffer.concat(stdout));
+    });
+    child.stdin.end(input ?? '');
+  });
+}
+
+async function runPlugin(plugin, request) {
+  // Relative commands (./convert) are resolved inside the plugin folder
+  const command = plugin.command.startsWith('.')
+    ? path.resolve(plugin.dir, plugin.command)
+    : plugin.command;
+  const stdout = await runProcess(command, plugin.args || [], {
+    cwd: plugin.dir,
+    input: JSON.stringify(request),
+    timeoutMs: PLUGIN_TIMEOUT_MS,
+  });
+  try {
+    return JSON.parse(stdout.toString('utf-8'));
+  } catch {
+    throw new Error(`Plugin ${plugin.name} did not return JSON`);
+  }
+}
+
+ipcMain.handle('plugins:list', async () => {
+  try {
+    const plugins = await readPluginManifests();
+    return { plugins: plugins.map(({ dir: _dir, ...manifest }) => manifest) };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+ipcMain.handle('plugins:run', async (_event, name, request) => {
+  try {
+    const plugin = (await readPluginManifests()).find((p) => p.name === name);
+    if (!plugin) throw new Error(`Plugin not found: ${name}`);
+    if (!plugin.capabilities.includes(request?.action)) {
+      throw new Error(`Plugin ${name} does not support ${request?.action}`);
+    }
+    if (request.action === 'export') {
+      Object.keys(request.artifacts ?? {}).forEach((type) => validate.artifactType(type));
+    }
+    return { response: await runPlugin(plugin, request) };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// ========== DIAGRAM RENDERING ==========
+
+// Mermaid and PlantUML diagrams are rendered with locally installed tools (mermaid-cli's mmdc,
+// a PlantUML jar run by java). Results are cached on disk by a hash of kind, format and source.
+// The programs to run come only from the app settings, which main sets from its own file
+// dialog (settings:chooseDiagramTool); the renderer and projects cannot name a program.
+const DIAGRAM_TIMEOUT_MS = 30000;
+const DIAGRAM_MIME_TYPES = { svg: 'image/svg+xml', png: 'image/png' };
+
+async function renderMermaid(source, format, tools) {
+  // A private folder, so other local users cannot swap the files mmdc reads and writes
+  const workDir = await fs.mkdtemp(path.join(os.tmpdir(), 'tracyfy-diagram-'));
+  const input = path.join(workDir, 'diagram.mmd');
+  const output = path.join(workDir, `diagram.${format}`);
+  try {
+    await fs.writeFile(input, source, 'utf-8');
+    await runProcess(tools.mermaidCli || 'mmdc', ['-i', input, '-o', output], {
+      timeoutMs: DIAGRAM_TIMEOUT_MS,
+    });
+    return await fs.readFile(output);
+  } finally {
+    await fs.rm(workDir, { recursive: true, force: true });
+  }
+}
+
+async function renderPlantUml(source, format, tools) {
+  if (!tools.plantumlJar) {
+    throw new Error('No PlantUML jar configured');
+  }
+  return runProcess(
+    tools.javaPath || 'java',
+    ['-Djava.awt.headless=true', '-jar', tools.plantumlJar, '-pipe', `-t${format}`],
+    { input: source, timeoutMs: DIAGRAM_TIMEOUT_MS }
+  );
+}
+
+ipcMain.handle('diagram:render', async (_event, kind, source, format) => {
+  try {
+    if (!DIAGRAM_MIME_TYPES[format]) throw new Error(`Unsupported format: ${format}`);
+    if (kind !== 'mermaid' && kind !== 'plantuml') throw new Error(`Unknown diagram: ${kind}`);
+
+    const hash = createHash('sha256').update(`${kind}\n${format}\n${source}`).digest('hex');
+    const cacheDir = path.join(app.getPath('userData'), 'diagram-cache');
+    const cachePath = path.join(cacheDir, `${hash}.${format}`);
+    const mimeType = DIAGRAM_MIME_TYPES[format];
+    try {
+      const cached = await fs.readFile(cachePath);
+      return { data: cached.toString('base64'), mimeType, cached: true };
+    } catch (error) {
+      if (error.code !== 'ENOENT') throw error;
+    }
+
+    const { diagrams: tools } = await readSettings();
+    const data =
+      kind === 'mermaid'
+        ? await renderMermaid(source, format, tools)
+        : await renderPlantUml(source, format, tools);
+    await fs.mkdir(cacheDir, { recursive: true });
+    await fs.writeFile(cachePath, data);
+    return { data: data.toString('base64'), mimeType, cached: false };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// ========== APP SETTINGS ==========
+
+// App-level preferences (not tied to a project) live in settings.json in the app's config
+// directory. Unknown keys and values of the wrong type are dropped when reading and updating;
+// lists and maps whose default is empty (author profiles, webhooks, tracker servers, trusted
+// signing keys) are kept as given. Diagram tools and trusted signing keys decide which programs
+// run and whose signatures count, so settings:update refuses them: they change only through a
+// dialog shown by main, where the user picks the file or confirms the key. The read-only
+// project folders are refused as well and change only through project:setReadOnly.
+const SETTINGS_FILE = 'settings.json';
+const MAIN_ONLY_SETTINGS = ['diagrams', 'trustedSigningKeys', 'readOnlyProjects'];
+const DIAGRAM_TOOLS = {
+  mermaidCli: 'Choose the mermaid-cli (mmdc) program',
+  plantumlJar: 'Choose plantuml.jar',
+  javaPath: 'Choose the Java program',
+};
+const DEFAULT_SETTINGS = {
+  defaultAuthor: '',
+  defaultProjectLocation: '',
+  autoSync: false,
+  notifyExternalChanges: false,
+  blockSecretCommits: true,
+  pollIntervals: { syncStatus: 30000, remoteStatus: 120000 },
+  authorProfiles: [],
+  activeAuthorProfiles: {},
+  webhooks: {},
+  integrationUrls: {},
+  diagrams: { mermaidCli: 'mmdc', plantumlJar: '', javaPath: 'java' },
+  trustedSigningKeys: [],
+  localApi: { enabled: false, port: 7411 },
+  readOnlyProjects: [],
+};
+
+function settingsPath() {
+  return path.join(app.getPath('userData'), SETTINGS_FILE);
+}
+
+function sanitizeSettings(value, defaults = DEFAULT_SETTINGS) {
+  const result = {};
+  for (const [key, fallback] of Object.entries(defaults)) {
+    const given = value?.[key];
+    if (Array.isArray(fallback)) {
+      result[key] = Array.isArray(given) ? given : fallback;
+    } else if (fallback && typeof fallback === 'object' && Object.keys(fallback).length === 0) {
+      const isMap = given && typeof given === 'object' && !Array.isArray(given);
+      result[key] = isMap ? given : fallback;
+    } else if (fallback && typeof fallback === 'object') {
+      result[key] = sanitizeSettings(given, fallback);
+    } else {
+      result[key] = typeof given === typeof fallback ? given : fallback;
+    }
+  }
+  return result;
+}
+
+async function readSettings() {
+  try {
+    return sanitizeSettings(JSON.parse(await fs.readFile(settingsPath(), 'utf-8')));
+  } catch (error) {
+    if (error.code !== 'ENOENT') console.warn('Could not read settings:', error.message);
+    return sanitizeSettings({});
+  }
+}
+
+ipcMain.handle('settings:get', async () => {
+  try {
+    return { settings: await readSettings() };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+async function writeSettings(settings) {
+  // Write to a temporary file first so a crash never leaves a truncated settings file
+  const target = settingsPath();
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(`${target}.tmp`, JSON.stringify(settings, null, 2), 'utf-8');
+  await fs.rename(`${target}.tmp`, target);
+  return settings;
+}
+
+ipcMain.handle('settings:update', async (_event, patch) => {
+  try {
+    for (const key of MAIN_ONLY_SETTINGS) {
+      if (patch?.[key] !== undefined) {
+        throw new InvalidArgumentError(key, 'can only be changed from a dialog of the app');
+      }
+    }
+    const current = await readSettings();
+    const settings = sanitizeSettings({
+      ...current,
+      ...patch,
+      pollIntervals: { ...current.pollIntervals, ...patch?.pollIntervals },
+      diagrams: current.diagrams,
+      trustedSigningKeys: current.trustedSigningKeys,
+      readOnlyProjects: current.readOnlyProjects,
+    });
+    return { settings: await writeSettings(settings) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+// Set one diagram tool to a file the user picks, or back to its default
+ipcMain.handle('settings:chooseDiagramTool', async (event, tool, reset) => {
+  try {
+    if (!Object.hasOwn(DIAGRAM_TOOLS, tool)) {
+      throw new InvalidArgumentError('tool', 'not a diagram tool');
+    }
+    let value = DEFAULT_SETTINGS.diagrams[tool];
+    if (!reset) {
+      const result = await dialog.showOpenDialog(BrowserWindow.fromWebContents(event.sender), {
+        title: DIAGRAM_TOOLS[tool],
+        properties: ['openFile'],
+      });
+      if (result.canceled || !result.filePaths.length) return { canceled: true };
+      value = result.filePaths[0];
+    }
+    const current = await readSettings();
+    const settings = { ...current, diagrams: { ...current.diagrams, [tool]: value } };
+    return { settings: await writeSettings(settings) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+// The key ID of a minisign public key file (see src/utils/minisignUtils)
+function getMinisignKeyId(publicKey) {
+  const line = requireString(publicKey, 'public key')
+    .split(/\r?\n/)
+    .map((l) => l.trim())
+    .find((l) => l && !l.startsWith('untrusted comment: '));
+  const bytes = Buffer.from(line ?? '', 'base64');
+  const isEd25519 = bytes.toString('latin1', 0, 2) === 'Ed';
+  if (!isEd25519 || bytes.length !== 2 + MINISIGN_KEY_ID_BYTES + ED25519_PUBLIC_KEY_BYTES) {
+    throw new InvalidArgumentError('public key', 'not a minisign Ed25519 public key');
+  }
+  // minisign shows the key ID as a little-endian 64-bit number
+  const keyId = Buffer.from(bytes.subarray(2, 2 + MINISIGN_KEY_ID_BYTES)).reverse();
+  return keyId.toString('hex').toUpperCase();
+}
+
+function getMinisignKeyIdOrNull(publicKey) {
+  try {
+    return getMinisignKeyId(publicKey);
+  } catch {
+    return null;
+  }
+}
+
+// Pin a signing key once the user confirms its ID
+ipcMain.handle('settings:trustSigningKey', async (event, publicKey) => {
+  try {
+    const keyId = getMinisignKeyId(publicKey);
+    const current = await readSettings();
+    if (current.trustedSigningKeys.some((key) => getMinisignKeyIdOrNull(key) === keyId)) {
+      return { settings: current };
+    }
+    const { response } = await dialog.showMessageBox(BrowserWindow.fromWebContents(event.sender), {
+      type: 'question',
+      buttons: ['Trust', 'Cancel'],
+      defaultId: 1,
+      cancelId: 1,
+      message: `Trust signing key ${keyId}?`,
+      detail: 'Baselines signed with this key will be reported as trusted on this machine.',
+    });
+    if (response !== 0) return { canceled: true };
+    const settings = {
+      ...current,
+      trustedSigningKeys: [...current.trustedSigningKeys, publicKey.trim()],
+    };
+    return { settings: await writeSettings(settings) };
+  } catch (error) {
+    return { error: error.message, code: error.code };
+  }
+});
+
+// ========== LOGGING ==========
+
+// Warnings and errors from the main process and the renderer are appended as JSON lines to
+// tracyfy.log in the app's log directory. The file rotates by size, keeping a few older files,
+// so logs from a packaged build can be attached to a bug report.
+const LOG_FILE = 'tracyfy.log';
+const LOG_MAX_BYTES = 1024 * 1024;
+const LOG_MAX_FILES = 5;
+const LOG_LEVELS = ['debug', 'info', 'warn', 'error'];
+const CONSOLE_LOG_LEVELS = ['debug', 'info', 'warn', 'error']; // Chromium console levels 0-3
+
+// Serializes writes so rotation never races an append
+let logQueue = Promise.resolve();
+
+function getLogDir() {
+  return path.join(app.getPath('userData'), 'logs');
+}
+
+// tracyfy.log, tracyfy.1.log, ... newest first
+function getLogFiles() {
+  return Array.from({ length: LOG_MAX_FILES }, (_, i) =>
+    path.join(getLogDir(), i === 0 ? LOG_FILE : LOG_FILE.replace('.log', `.${i}.log`))
+  );
+}
+
+async function rotateLogs() {
+  const files = getLogFiles();
+  for (let i = files.length - 1; i > 0; i--) {
+    await fs.rename(files[i - 1], files[i]).catch((error) => {
+      if (error.code !== 'ENOENT') throw error;
+    });
+  }
+}
+
+function writeLog(level, source, message) {
+  const entry = { time: new Date().toISOString(), level, source, message: String(message) };
+  logQueue = logQueue
+    .then(async () => {
+      const [current] = getLogFiles();
+      await fs.mkdir(getLogDir(), { recursive: true });
+      const size = await fs.stat(current).then(
+        (stat) => stat.size,
+        () => 0
+      );
+      if (size >= LOG_MAX_BYTES) await rotateLogs();
+      await fs.appendFile(current, JSON.stringify(entry) + '\n', 'utf-8');
+    })
+    .catch((error) => process.stderr.write(`Could not write log: ${error.message}\n`));
+  return logQueue;
+}
+
+function formatError(error) {
+  return error instanceof Error ? error.stack || error.message : String(error);
+}
+
+process.on('uncaughtException', (error) => {
+  console.error(error);
+  writeLog('error', 'main', formatError(error));
+});
+
+process.on('unhandledRejection', (reason) => {
+  console.error(reason);
+  writeLog('error', 'main', formatError(reason));
+});
+
+app.on('web-contents-created', (_event, contents) => {
+  contents.on('console-message', (_e, level, message, line, sourceId) => {
+    if (level < 2) return;
+    const location = sourceId ? ` (${sourceId}:${line})` : '';
+    writeLog(CONSOLE_LOG_LEVELS[level], 'renderer', `${message}${location}`);
+  });
+});
+
+app.on('render-process-gone', (_event, _contents, details) => {
+  writeLog('error', 'main', `Renderer process gone: ${details.reason} (${details.exitCode})`);
+});
+
+app.on('child-process-gone', (_event, details) => {
+  const { type, reason, exitCode } = details;
+  writeLog('error', 'main', `${type} process gone: ${reason} (${exitCode})`);
+});
+
+ipcMain.handle('log:write', async (_event, level, message) => {
+  try {
+    if (!LOG_LEVELS.includes(level)) throw new Error(`Unknown log level: ${level}`);
+    await writeLog(level, 'renderer', message);
+    return { ok: true };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// Entries from all log files, oldest first, limited to the last `limit`
+async function readLogEntries(limit) {
+  await logQueue;
+  const entries = [];
+  for (const file of getLogFiles().reverse()) {
+    const content = await fs.readFile(file, 'utf-8').catch((error) => {
+      if (error.code === 'ENOENT') return null;
+      throw error;
+    });
+    if (content === null) continue;
+    for (const line of content.split('\n')) {
+      if (!line.trim()) continue;
+      try {
+        entries.push(JSON.parse(line));
+      } catch {
+        entries.push({ time: '', level: 'info', source: 'unknown', message: line });
+      }
+    }
+  }
+  return limit ? entries.slice(-limit) : entries;
+}
+
+ipcMain.handle('log:getRecent', async (_event, limit = 200) => {
+  try {
+    return { entries: await readLogEntries(limit) };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+ipcMain.handle('log:export', async () => {
+  try {
+    const entries = await readLogEntries();
+    const header = {
+      app: app.getVersion(),
+      electron: process.versions.electron,
+      platform: `${process.platform} ${os.release()} ${process.arch}`,
+      exportedAt: new Date().toISOString(),
+    };
+    return {
+      content: [JSON.stringify(header), ...entries.map((e) => JSON.stringify(e))].join('\n') + '\n',
+    };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// ========== ASSET PROTOCOL ==========
+
+// Attachments and images referenced from artifacts are served to the renderer as
+// tracyfy-asset://project/<path> (working tree) and tracyfy-asset://commit/<oid>/<path>
+// (file as of a commit), so previews can use plain URLs instead of base64 round-trips.
+// Only files of the open project are served, never its .git directory.
+const ASSET_SCHEME = 'tracyfy-asset';
+const ASSET_MIME_TYPES = {
+  png: 'image/png',
+  jpg: 'image/jpeg',
+  jpeg: 'image/jpeg',
+  gif: 'image/gif',
+  webp: 'image/webp',
+  svg: 'image/svg+xml',
+  bmp: 'image/bmp',
+  tiff: 'image/tiff',
+  pdf: 'application/pdf',
+  txt: 'text/plain',
+};
+
+protocol.registerSchemesAsPrivileged([
+  { scheme: ASSET_SCHEME, privileges: { standard: true, secure: true, supportFetchAPI: true } },
+]);
+
+function assetResponse(status, body, mimeType = 'text/plain') {
+  return new Response(body, {
+    status,
+    headers: {
+      'Content-Type': mimeType,
+      // SVGs are documents too; never let them run scripts
+      'Content-Security-Policy': "default-src 'none'; style-src 'unsafe-inline'",
+      'X-Content-Type-Options': 'nosniff',
+    },
+  });
+}
+
+function isServableAssetPath(filepath) {
+  const segments = filepath.split('/');
+  return (
+    filepath.length > 0 &&
+    segments[0] !== '.git' &&
+    !segments.some((segment) => segment === '' || segment === '.' || segment === '..')
+  );
+}
+
+async function handleAssetRequest(request) {
+  if (!projectRoot) return assetResponse(404, 'No project open');
+  const url = new URL(request.url);
+  const segments = url.pathname.split('/').filter(Boolean).map(decodeURIComponent);
+  const isCommit = url.hostname === 'commit';
+  if (isCommit && !/^[0-9a-f]{40}$/.test(segments[0] || '')) {
+    return assetResponse(400, 'Invalid commit');
+  }
+  if (!isCommit && url.hostname !== 'project') return assetResponse(400, 'Invalid asset URL');
+  const filepath = segments.slice(isCommit ? 1 : 0).join('/');
+  if (!isServableAssetPath(filepath)) return assetResponse(403, 'Forbidden');
+
+  try {
+    let data;
+    if (isCommit) {
+      ({ blob: data } = await git.readBlob({ fs, dir: projectRoot, oid: segments[0], filepath }));
+    } else {
+      const fullPath = await resolveProjectPath(filepath);
+      if (!fullPath) return assetResponse(403, 'Forbidden');
+      data = await fs.readFile(fullPath);
+    }
+    const ext = path.extname(filepath).slice(1).toLowerCase();
+    return assetResponse(200, data, ASSET_MIME_TYPES[ext] || 'application/octet-stream');
+  } catch (error) {
+    if (error.code === 'ENOENT' || error.code === 'NotFoundError') {
+      return assetResponse(404, 'Not found');
+    }
+    writeLog('error', 'asset', `${request.url}: ${error.message}`);
+    return assetResponse(500, 'Could not read asset');
+  }
+}
+
+app.whenReady().then(() => {
+  protocol.handle(ASSET_SCHEME, handleAssetRequest);
+});
+
+// ========== DEEP LINKS AND LAUNCH ARGUMENTS ==========
+
+// tracyfy://open?project=<id or name>&artifact=REQ-012 launches or focuses the app and
+// asks the renderer to open the artifact. A project folder, or its project.tracyfy marker
+// file (opened through the file association), given on the command line opens that folder.
+// Requests are queued until the renderer subscribes.
+const DEEP_LINK_SCHEME = 'tracyfy';
+const PROJECT_MARKER_EXT = '.tracyfy';
+
+let pendingDeepLinks = [];
+let deepLinkRendererReady = false;
+
+if (process.defaultApp && process.argv.length >= 2) {
+  // Running unpackaged (electron .): register the script so the OS launches it with the link
+  app.setAsDefaultProtocolClient(DEEP_LINK_SCHEME, process.execPath, [
+    path.resolve(process.argv[1]),
+  ]);
+} else {
+  app.setAsDefaultProtocolClient(DEEP_LINK_SCHEME);
+}
+
+function parseDeepLink(rawUrl) {
+  try {
+    const url = new URL(rawUrl);
+    if (url.protocol !== `${DEEP_LINK_SCHEME}:` || url.hostname !== 'open') return null;
+    const project = url.searchParams.get('project') || undefined;
+    const artifact = url.searchParams.get('artifact') || undefined;
+    if (artifact && !/^[A-Z]+-\d+$/.test(artifact)) return null;
+    if (project && project.length > 200) return null;
+    return project || artifact ? { project, artifact } : null;
+  } catch {
+    return null;
+  }
+}
+
+function focusMainWindow() {
+  const [win] = BrowserWindow.getAllWindows();
+  if (!win) return null;
+  if (win.isMinimized()) win.restore();
+  win.focus();
+  return win;
+}
+
+function sendDeepLink(link) {
+  const win = focusMainWindow();
+  if (win && deepLinkRendererReady) {
+    win.webContents.send('deepLink:open', link);
+  } else {
+    pendingDeepLinks.push(link);
+  }
+}
+
+function openDeepLink(rawUrl) {
+  const link = parseDeepLink(rawUrl);
+  if (!link) {
+    writeLog('warn', 'deep-link', `Ignored invalid link: ${rawUrl}`);
+    return;
+  }
+  sendDeepLink(link);
+}
+
+// Project folder for a path argument: the folder itself, or the folder of a marker file
+async function resolveLaunchProject(arg, cwd) {
+  const fullPath = path.resolve(cwd, arg);
+  const stat = await fs.stat(fullPath).catch(() => null);
+  if (stat?.isDirectory()) return fullPath;
+  if (stat?.isFile() && path.extname(fullPath).toLowerCase() === PROJECT_MARKER_EXT) {
+    return path.dirname(fullPath);
+  }
+  return null;
+}
+
+// Handle the arguments of this launch or a second one; returns whether there was a request
+async function handleLaunchArgs(argv, cwd) {
+  // argv starts with the executable, and the app script when run unpackaged (electron .)
+  const args = argv.slice(process.defaultApp ? 2 : 1).filter((arg) => !arg.startsWith('-'));
+  const url = args.find((arg) => arg.startsWith(`${DEEP_LINK_SCHEME}://`));
+  if (url) {
+    openDeepLink(url);
+    return true;
+  }
+  for (const arg of args) {
+    const projectPath = await resolveLaunchProject(arg, cwd);
+    if (projectPath) {
+      approveProjectRoot(projectPath);
+      sendDeepLink({ projectPath });
+      return true;
+    }
+  }
+  return false;
+}
+
+handleLaunchArgs(process.argv, process.cwd());
+
+app.on('second-instance', async (_event, argv, workingDirectory) => {
+  if (!(await handleLaunchArgs(argv, workingDirectory))) focusMainWindow();
+});
+
+// macOS delivers links and opened files as events, also before the app is ready
+app.on('open-url', (event, url) => {
+  event.preventDefault();
+  openDeepLink(url);
+});
+
+app.on('open-file', async (event, filePath) => {
+  event.preventDefault();
+  const projectPath = await resolveLaunchProject(filePath, process.cwd());
+  if (projectPath) {
+    approveProjectRoot(projectPath);
+    sendDeepLink({ projectPath });
+  }
+});
+
+// The renderer subscribes first, then collects what arrived before it was listening
+ipcMain.handle('deepLink:takePending', async () => {
+  deepLinkRendererReady = true;
+  const links = pendingDeepLinks;
+  pendingDeepLinks = [];
+  return { links };
+});
+
+// A reload drops the renderer's subscription; queue again until it resubscribes
+app.on('web-contents-created', (_event, contents) => {
+  contents.on('did-start-navigation', (_navEvent, _url, isInPlace, isMainFrame) => {
+    if (isMainFrame && !isInPlace) deepLinkRendererReady = false;
+  });
+});
+
+// ========== NOTIFICATIONS ==========
+
+// Native notifications, e.g. for changes made outside the app. They are only shown while the
+// app is in the background; clicking one brings the window back.
+ipcMain.handle('notifications:show', async (_event, { title, body }) => {
+  try {
+    if (!Notification.isSupported() || BrowserWindow.getFocusedWindow()) {
+      return { shown: false };
+    }
+    const notification = new Notification({ title: String(title), body: String(body ?? '') });
+    notification.on('click', () => focusMainWindow());
+    notification.show();
+    return { shown: true };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// ========== API INFO ==========
+
+// Version and capabilities of the commands above, so the renderer (and later the CLI or the
+// local API server's clients) can check what this build supports before relying on it.
+// The version goes up when commands change incompatibly; the old forms keep working for
+// clients down to MIN_SUPPORTED_API_VERSION and are listed as deprecated.
+const API_VERSION = 2;
+const MIN_SUPPORTED_API_VERSION = 1;
+const API_CAPABILITIES = [
+  'assets:protocol',
+  'clipboard:table',
+  'deepLinks',
+  'diagram:render',
+  'fs',
+  'fs:batch',
+  'fs:stat',
+  'git',
+  'git:remote',
+  'git:snapshotRefs',
+  'http',
+  'localApi',
+  'log',
+  'notifications',
+  'plugins',
+  'project',
+  'project:externalChanges',
+  'project:readOnly',
+  'secure:namedTokens',
+  'settings',
+  'shell:reveal',
+  'signing',
+  'validation:typedErrors',
+];
+const DEPRECATED_COMMANDS = [
+  { command: 'assets:setProjectRoot(rootDir)', replacement: 'project:open(dir)', since: 2 },
+  {
+    command: 'shell:revealInFileManager(rootDir, relativePath)',
+    replacement: 'shell:revealInFileManager(relativePath)',
+    since: 2,
+  },
+];
+
+// Deprecated forms in use are logged once per run
+const reportedDeprecations = new Set();
+function warnDeprecated(command) {
+  if (reportedDeprecations.has(command)) return;
+  reportedDeprecations.add(command);
+  const replacement = DEPRECATED_COMMANDS.find((d) => d.command === command)?.replacement;
+  writeLog('warn', 'api', `Deprecated command ${command} used; use ${replacement} instead`);
+}
+
+ipcMain.handle('app:getApiInfo', async () => {
+  try {
+    return {
+      appVersion: app.getVersion(),
+      apiVersion: API_VERSION,
+      minSupportedApiVersion: MIN_SUPPORTED_API_VERSION,
+      capabilities: API_CAPABILITIES,
+      features: {
+        keychain: isKeychainAvailable(),
+        notifications: Notification.isSupported(),
+      },
+      deprecated: DEPRECATED_COMMANDS,
+    };
+  } catch (error) {
+    return { error: error.message };
+  }
+});
+
+// API version 1 set the project folder for the asset protocol only; it now opens the project
+ipcMain.handle('assets:setProjectRoot', async (_event, rootDir) => {
+  warnDeprecated('assets:setProjectRoot(rootDir)');
+  if (typeof rootDir !== 'string' || !rootDir) {
+    closeProject();
+    return { ok: true };
+  }
+  const result = await openProject(rootDir);
+  return result.error ? result : { ok: true };
+});
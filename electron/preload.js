@@ -6,7 +6,12 @@ contextBridge.exposeInMainWorld('electronAPI', {
   fs: {
     selectDirectory: () => ipcRenderer.invoke('fs:selectDirectory'),
     readFile: (path) => ipcRenderer.invoke('fs:readFile', path),
+    readFiles: (paths) => ipcRenderer.invoke('fs:readFiles', paths),
+    statFiles: (paths) => ipcRenderer.invoke('fs:statFiles', paths),
+    batch: (operations) => ipcRenderer.invoke('fs:batch', operations),
     readFileBinary: (path) => ipcRenderer.invoke('fs:readFileBinary', path),
+    readFileChunk: (path, offset, length) =>
+      ipcRenderer.invoke('fs:readFileChunk', path, offset, length),
     writeFile: (path, content) => ipcRenderer.invoke('fs:writeFile', path, content),
     writeFileBinary: (path, data) => ipcRenderer.invoke('fs:writeFileBinary', path, data),
     deleteFile: (path) => ipcRenderer.invoke('fs:deleteFile', path),
@@ -36,6 +41,9 @@ contextBridge.exposeInMainWorld('electronAPI', {
       ipcRenderer.invoke('git:annotatedTag', dir, ref, message, tagger),
     listTags: (dir) => ipcRenderer.invoke('git:listTags', dir),
     readTag: (dir, oid) => ipcRenderer.invoke('git:readTag', dir, oid),
+    writeBlob: (dir, content) => ipcRenderer.invoke('git:writeBlob', dir, content),
+    writeSnapshotRef: (dir, ref, entries, message) =>
+      ipcRenderer.invoke('git:writeSnapshotRef', dir, ref, entries, message),
 
     // Remote operations
     addRemote: (dir, name, url) => ipcRenderer.invoke('git:addRemote', dir, name, url),
@@ -51,9 +59,107 @@ contextBridge.exposeInMainWorld('electronAPI', {
 
   // Secure storage
   secure: {
-    setToken: (token) => ipcRenderer.invoke('secure:setToken', token),
-    getToken: () => ipcRenderer.invoke('secure:getToken'),
-    removeToken: () => ipcRenderer.invoke('secure:removeToken'),
+    setToken: (token, name) => ipcRenderer.invoke('secure:setToken', token, name),
+    getToken: (name) => ipcRenderer.invoke('secure:getToken', name),
+    removeToken: (name) => ipcRenderer.invoke('secure:removeToken', name),
+  },
+
+  // Ed25519 signing (minisign signatures)
+  signing: {
+    generateKey: () => ipcRenderer.invoke('signing:generateKey'),
+    sign: (privateKey, data) => ipcRenderer.invoke('signing:sign', privateKey, data),
+    verify: (publicKey, signature, data) =>
+      ipcRenderer.invoke('signing:verify', publicKey, signature, data),
+  },
+
+  // HTTP requests (issue tracker integrations)
+  http: {
+    request: (request) => ipcRenderer.invoke('http:request', request),
+  },
+
+  // Local API server; requests are answered by the handler passed to onRequest
+  api: {
+    start: (port, token) => ipcRenderer.invoke('api:start', port, token),
+    stop: () => ipcRenderer.invoke('api:stop'),
+    onRequest: (handler) => {
+      const listener = async (_event, request) => {
+        const response = await handler(request);
+        ipcRenderer.send('api:response', { requestId: request.requestId, ...response });
+      };
+      ipcRenderer.on('api:request', listener);
+      return () => ipcRenderer.removeListener('api:request', listener);
+    },
+  },
+
+  // Importer/exporter plugins
+  plugins: {
+    list: () => ipcRenderer.invoke('plugins:list'),
+    run: (name, request) => ipcRenderer.invoke('plugins:run', name, request),
+  },
+
+  // Diagram rendering (Mermaid, PlantUML)
+  diagram: {
+    render: (kind, source, format) => ipcRenderer.invoke('diagram:render', kind, source, format),
+  },
+
+  // App settings
+  settings: {
+    get: () => ipcRenderer.invoke('settings:get'),
+    update: (patch) => ipcRenderer.invoke('settings:update', patch),
+    chooseDiagramTool: (tool, reset) =>
+      ipcRenderer.invoke('settings:chooseDiagramTool', tool, reset),
+    trustSigningKey: (publicKey) => ipcRenderer.invoke('settings:trustSigningKey', publicKey),
+  },
+
+  // Open project folder; fs and git calls are only allowed inside it
+  project: {
+    open: (dir) => ipcRenderer.invoke('project:open', dir),
+    close: () => ipcRenderer.invoke('project:close'),
+    setReadOnly: (readOnly) => ipcRenderer.invoke('project:setReadOnly', readOnly),
+    onFilesChanged: (handler) => {
+      const listener = (_event, paths) => handler(paths);
+      ipcRenderer.on('project:filesChanged', listener);
+      return () => ipcRenderer.removeListener('project:filesChanged', listener);
+    },
+    onExternalChanges: (handler) => {
+      const listener = (_event, paths) => handler(paths);
+      ipcRenderer.on('project:externalChanges', listener);
+      return () => ipcRenderer.removeListener('project:externalChanges', listener);
+    },
+  },
+
+  // tracyfy:// links; subscribe with onOpen before collecting the pending ones
+  deepLink: {
+    onOpen: (handler) => {
+      const listener = (_event, link) => handler(link);
+      ipcRenderer.on('deepLink:open', listener);
+      return () => ipcRenderer.removeListener('deepLink:open', listener);
+    },
+    takePending: () => ipcRenderer.invoke('deepLink:takePending'),
+  },
+
+  // Desktop integration
+  shell: {
+    revealInFileManager: (relativePath) =>
+      ipcRenderer.invoke('shell:revealInFileManager', relativePath),
+  },
+  clipboard: {
+    writeTable: (table) => ipcRenderer.invoke('clipboard:writeTable', table),
+  },
+  notifications: {
+    show: (notification) => ipcRenderer.invoke('notifications:show', notification),
+  },
+
+  // Log file
+  log: {
+    write: (level, message) => ipcRenderer.invoke('log:write', level, message),
+    getRecent: (limit) => ipcRenderer.invoke('log:getRecent', limit),
+    export: () => ipcRenderer.invoke('log:export'),
+  },
+
+  // Version and capabilities of the commands above
+  app: {
+    getApiInfo: () => ipcRenderer.invoke('app:getApiInfo'),
   },
 
   // Platform info
@@ -0,0 +1,291 @@
+/**
+ * Node implementation of the desktop app's renderer API
+ *
+ * Gives the app's services the fs, git, project and settings calls they make
+ * through window.electronAPI, answered with node:fs and isomorphic-git in the
+ * current process instead of over IPC. Results have the shapes the main
+ * process returns (see electron/main.js), and every path must stay inside the
+ * open project. Used by the headless CLI; there is no file watching.
+ */
+
+import path from 'node:path';
+import { constants as fsConstants, promises as fs } from 'node:fs';
+import { isUtf8 } from 'node:buffer';
+import git from 'isomorphic-git';
+
+const DEFAULT_AUTHOR = { name: 'Tracyfy User', email: 'user@tracyfy.local' };
+
+function isInside(root, fullPath) {
+  const relative = path.relative(root, fullPath);
+  return relative !== '..' && !relative.startsWith(`..${path.sep}`) && !path.isAbsolute(relative);
+}
+
+/**
+ * Create the API for one process. settings are the app settings the
+ * services read, e.g. the author profile commits are made with.
+ */
+export function createNodeElectronApi({ settings = {} } = {}) {
+  let projectRoot = null;
+
+  function resolveInProject(target) {
+    if (typeof target !== 'string' || !target) throw new Error(`Invalid path: ${target}`);
+    if (!projectRoot) throw new Error('No project is open');
+    const fullPath = path.resolve(projectRoot, target);
+    if (!isInside(projectRoot, fullPath)) {
+      throw new Error(`Path is outside the open project: ${target}`);
+    }
+    return fullPath;
+  }
+
+  // Run a git or fs call, returning { error } instead of throwing like the IPC handlers
+  async function handle(operation) {
+    try {
+      return await operation();
+    } catch (error) {
+      return { error: error.message, code: error.code };
+    }
+  }
+
+  async function readTextFile(target) {
+    try {
+      const buffer = await fs.readFile(resolveInProject(target));
+      if (!isUtf8(buffer)) return { data: Array.from(buffer) };
+      return { content: buffer.toString('utf8') };
+    } catch (error) {
+      if (error.code === 'ENOENT') return { notFound: true };
+      return { error: error.message, code: error.code };
+    }
+  }
+
+  async function statFile(target) {
+    try {
+      const stats = await fs.stat(resolveInProject(target));
+      return { mtime: stats.mtimeMs, size: stats.size };
+    } catch (error) {
+      if (error.code === 'ENOENT') return { notFound: true };
+      return { error: error.message, code: error.code };
+    }
+  }
+
+  async function writeFile(target, content) {
+    return handle(async () => {
+      const fullPath = resolveInProject(target);
+      await fs.mkdir(path.dirname(fullPath), { recursive: true });
+      await fs.writeFile(fullPath, content);
+      return { ok: true };
+    });
+  }
+
+  async function deleteFile(target) {
+    try {
+      await fs.unlink(resolveInProject(target));
+      return { ok: true };
+    } catch (error) {
+      if (error.code === 'ENOENT') return { ok: true };
+      return { error: error.message, code: error.code };
+    }
+  }
+
+  async function listFiles(target) {
+    try {
+      const entries = await fs.readdir(resolveInProject(target), { withFileTypes: true });
+      return { files: entries.filter((e) => e.isFile()).map((e) => e.name) };
+    } catch (error) {
+      if (error.code === 'ENOENT') return { files: [] };
+      return { error: error.message, code: error.code };
+    }
+  }
+
+  // Link files whose frontmatter names the artifact as source or target
+  async function readArtifactLinks(artifactId) {
+    const listed = await listFiles('links');
+    if (listed.error) return listed;
+    const id = String(artifactId).replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+    const endpoint = new RegExp(`^(sourceId|targetId):[ \\t]*["']?${id}["']?[ \\t]*\\r?$`, 'm');
+    const links = [];
+    for (const name of listed.files.filter((file) => file.endsWith('.md'))) {
+      const { content } = await readTextFile(`links/${name}`);
+      if (content !== undefined && endpoint.test(content)) {
+        links.push({ path: `links/${name}`, content });
+      }
+    }
+    return { links };
+  }
+
+  const batchOperations = {
+    read: (operation) => readTextFile(operation.path),
+    write: (operation) => writeFile(operation.path, String(operation.content ?? '')),
+    delete: (operation) => deleteFile(operation.path),
+    list: (operation) => listFiles(operation.path),
+    stat: (operation) => statFile(operation.path),
+    link: (operation) =>
+      operation.content === undefined
+        ? readArtifactLinks(operation.artifactId)
+        : writeFile(`links/${operation.linkId}.md`, String(operation.content)),
+  };
+
+  const fsApi = {
+    readFile: readTextFile,
+    readFiles: async (targets) => ({ results: await Promise.all(targets.map(readTextFile)) }),
+    statFiles: async (targets) => ({ results: await Promise.all(targets.map(statFile)) }),
+    readFileBinary: (target) =>
+      handle(async () => ({ data: Array.from(await fs.readFile(resolveInProject(target))) })),
+    readFileChunk: (target, offset, length) =>
+      handle(async () => {
+        const file = await fs.open(resolveInProject(target), 'r');
+        try {
+          const { size } = await file.stat();
+          const buffer = Buffer.alloc(Math.max(0, Math.min(length, size - offset)));
+          const { bytesRead } = await file.read(buffer, 0, buffer.length, offset);
+          return { data: Array.from(buffer.subarray(0, bytesRead)), totalSize: size };
+        } finally {
+          await file.close();
+        }
+      }),
+    writeFile: (target, content) => writeFile(target, content),
+    writeFileBinary: (target, data) => writeFile(target, Buffer.from(data)),
+    deleteFile,
+    listFiles,
+    listEntries: async (target) => {
+      try {
+        return { entries: await fs.readdir(resolveInProject(target)) };
+      } catch (error) {
+        if (error.code === 'ENOENT') return { entries: [] };
+        return { error: error.message, code: error.code };
+      }
+    },
+    checkExists: async (target) => {
+      try {
+        await fs.access(resolveInProject(target));
+        return { exists: true };
+      } catch {
+        return { exists: false };
+      }
+    },
+    mkdir: (target) =>
+      handle(async () => {
+        await fs.mkdir(resolveInProject(target), { recursive: true });
+        return { ok: true };
+      }),
+    batch: async (operations) => {
+      const results = [];
+      for (const operation of operations) {
+        const run = Object.hasOwn(batchOperations, operation?.type)
+          ? batchOperations[operation.type]
+          : null;
+        results.push(
+          run ? await run(operation) : { error: `Unknown operation: ${operation?.type}` }
+        );
+      }
+      return { results };
+    },
+  };
+
+  // Git calls take the project folder (or a path inside it) as dir, like the IPC handlers
+  const withDir = (dir, operation) => handle(() => operation(resolveInProject(dir)));
+
+  const gitApi = {
+    status: (dir, filepath) => withDir(dir, (d) => git.status({ fs, dir: d, filepath })),
+    statusMatrix: (dir) => withDir(dir, (d) => git.statusMatrix({ fs, dir: d })),
+    add: (dir, filepath) =>
+      withDir(dir, async (d) => {
+        await git.add({ fs, dir: d, filepath });
+        return { ok: true };
+      }),
+    remove: (dir, filepath) =>
+      withDir(dir, async (d) => {
+        await git.remove({ fs, dir: d, filepath });
+        return { ok: true };
+      }),
+    commit: (dir, message, author) =>
+      withDir(dir, async (d) => ({
+        oid: await git.commit({ fs, dir: d, message, author: author || DEFAULT_AUTHOR }),
+      })),
+    log: (dir, depth, filepath, ref) =>
+      withDir(dir, async (d) => {
+        const commits = await git.log({
+          fs,
+          dir: d,
+          depth: depth || 100,
+          filepath,
+          ref: ref || 'HEAD',
+        });
+        return commits.map((c) => ({
+          hash: c.oid,
+          message: c.commit.message,
+          author: c.commit.author.name,
+          timestamp: c.commit.author.timestamp * 1000,
+          parent: c.commit.parent,
+        }));
+      }),
+    listFiles: (dir, ref) => withDir(dir, (d) => git.listFiles({ fs, dir: d, ref })),
+    readBlob: (dir, oid, filepath) =>
+      withDir(dir, async (d) => {
+        const { blob } = await git.readBlob({ fs, dir: d, oid, filepath });
+        return { blob: Array.from(blob) };
+      }),
+    resolveRef: (dir, ref) => withDir(dir, (d) => git.resolveRef({ fs, dir: d, ref })),
+    isDescendent: (dir, oid, ancestor, depth) =>
+      withDir(dir, (d) => git.isDescendent({ fs, dir: d, oid, ancestor, depth })),
+    currentBranch: (dir) => withDir(dir, (d) => git.currentBranch({ fs, dir: d })),
+    init: (dir) =>
+      withDir(dir, async (d) => {
+        await git.init({ fs, dir: d, defaultBranch: 'main' });
+        return { ok: true };
+      }),
+    annotatedTag: (dir, ref, message, tagger) =>
+      withDir(dir, async (d) => {
+        await git.annotatedTag({ fs, dir: d, ref, message, tagger: tagger || DEFAULT_AUTHOR });
+        return { ok: true };
+      }),
+    listTags: (dir) => withDir(dir, (d) => git.listTags({ fs, dir: d })),
+    readTag: (dir, oid) =>
+      withDir(dir, async (d) => {
+        const tag = await git.readTag({ fs, dir: d, oid });
+        return {
+          message: tag.tag.message,
+          timestamp: tag.tag.tagger.timestamp * 1000,
+          object: tag.tag.object,
+        };
+      }),
+  };
+
+  const projectApi = {
+    open: async (dir) => {
+      try {
+        const root = path.resolve(String(dir));
+        if (!(await fs.stat(root)).isDirectory()) return { error: `Not a folder: ${dir}` };
+        await fs.access(root, fsConstants.R_OK);
+        projectRoot = root;
+        const hasGit = await fs.access(path.join(root, '.git')).then(
+          () => true,
+          () => false
+        );
+        return { path: root, hasGit };
+      } catch (error) {
+        if (error.code === 'ENOENT') return { error: `Folder not found: ${dir}` };
+        return { error: error.message };
+      }
+    },
+    close: async () => {
+      projectRoot = null;
+      return { ok: true };
+    },
+    setReadOnly: async (readOnly) => ({ readOnly: !!readOnly }),
+    onFilesChanged: () => () => {},
+    onExternalChanges: () => () => {},
+  };
+
+  const settingsApi = {
+    get: async () => ({ settings }),
+    update: async () => ({ error: 'Settings cannot be changed from the command line' }),
+  };
+
+  return {
+    isElectron: true,
+    fs: fsApi,
+    git: gitApi,
+    project: projectApi,
+    settings: settingsApi,
+  };
+}
@@ -0,0 +1,135 @@
+/**
+ * Tracyfy MCP server
+ *
+ * Exposes the open project to AI assistants over the Model Context Protocol
+ * (JSON-RPC 2.0 over stdio). Runs as its own long-lived process and answers
+ * tool calls through the app's local API, which must be enabled:
+ *
+ *   TRACYFY_API_TOKEN=<token> npm run mcp
+ *
+ * TRACYFY_API_URL defaults to http://127.0.0.1:7411.
+ */
+
+import { createInterface } from 'node:readline';
+
+const API_URL = (process.env.TRACYFY_API_URL || 'http://127.0.0.1:7411').replace(/\/+$/, '');
+const API_TOKEN = process.env.TRACYFY_API_TOKEN;
+const PROTOCOL_VERSION = '2024-11-05';
+
+const TOOLS = [
+  {
+    name: 'read_artifact',
+    description: 'Read a requirement, use case, test case, risk, document or other artifact by ID',
+    inputSchema: {
+      type: 'object',
+      properties: { id: { type: 'string', description: 'Artifact ID, e.g. REQ-001' } },
+      required: ['id'],
+    },
+    path: ({ id }) => `/api/artifacts/${encodeURIComponent(id)}`,
+  },
+  {
+    name: 'search_artifacts',
+    description: 'Find artifacts whose ID, title or text contains all given words',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        query: { type: 'string' },
+        type: { type: 'string', description: 'Artifact type, e.g. requirements or testcases' },
+      },
+      required: ['query'],
+    },
+    path: ({ query, type = '' }) => `/api/search?${new URLSearchParams({ q: query, type })}`,
+  },
+  {
+    name: 'list_links',
+    description: 'List trace links, optionally only those touching one artifact',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        artifactId: { type: 'string' },
+        type: { type: 'string', description: 'Link type, e.g. verifies' },
+        projectId: { type: 'string' },
+      },
+    },
+    path: (args) => `/api/links?${new URLSearchParams(args)}`,
+  },
+  {
+    name: 'get_coverage_gaps',
+    description: 'Requirements without test cases and test cases without requirements',
+    inputSchema: {
+      type: 'object',
+      properties: { projectId: { type: 'string' } },
+    },
+    path: ({ projectId = '' }) => `/api/coverage-gaps?${new URLSearchParams({ projectId })}`,
+  },
+];
+
+async function callTool(name, args = {}) {
+  const tool = TOOLS.find((t) => t.name === name);
+  if (!tool) {
+    return { content: [{ type: 'text', text: `Unknown tool: ${name}` }], isError: true };
+  }
+  try {
+    const response = await fetch(`${API_URL}${tool.path(args)}`, {
+      headers: { Authorization: `Bearer ${API_TOKEN}` },
+    });
+    const text = await response.text();
+    return { content: [{ type: 'text', text }], isError: !response.ok };
+  } catch (error) {
+    const text = `Tracyfy local API not reachable at ${API_URL}: ${error.message}`;
+    return { content: [{ type: 'text', text }], isError: true };
+  }
+}
+
+async function handleMessage(message) {
+  switch (message.method) {
+    case 'initialize':
+      return {
+        protocolVersion: message.params?.protocolVersion || PROTOCOL_VERSION,
+        capabilities: { tools: {} },
+        serverInfo: { name: 'tracyfy', version: '1.0.0' },
+      };
+    case 'ping':
+      return {};
+    case 'tools/list':
+      return {
+        tools: TOOLS.map(({ name, description, inputSchema }) => ({
+          name,
+          description,
+          inputSchema,
+        })),
+      };
+    case 'tools/call':
+      return callTool(message.params?.name, message.params?.arguments);
+    default:
+      throw Object.assign(new Error(`Method not found: ${message.method}`), { code: -32601 });
+  }
+}
+
+function send(message) {
+  process.stdout.write(`${JSON.stringify({ jsonrpc: '2.0', ...message })}\n`);
+}
+
+if (!API_TOKEN) {
+  console.error('TRACYFY_API_TOKEN is not set; copy the token from the local API settings');
+  process.exit(1);
+}
+
+createInterface({ input: process.stdin }).on('line', async (line) => {
+  if (!line.trim()) return;
+  let message;
+  try {
+    message = JSON.parse(line);
+  } catch {
+    return send({ id: null, error: { code: -32700, message: 'Parse error' } });
+  }
+  // Notifications (no id) need no response
+  if (message.id === undefined) return;
+  try {
+    send({ id: message.id, result: await handleMessage(message) });
+  } catch (error) {
+    send({ id: message.id, error: { code: error.code || -32603, message: error.message } });
+  }
+});
+
+console.error(`Tracyfy MCP server using ${API_URL}`);
@@ -0,0 +1,195 @@
+#!/usr/bin/env node
+/**
+ * Tracyfy command line
+ *
+ * Runs the app's own services headless against a project folder, for CI
+ * pipelines and scripts. The commands are thin wrappers around
+ * src/services/headlessService.ts, which is loaded through Vite and reaches
+ * the disk and git through a Node version of the desktop app's API
+ * (scripts/cli/nodeElectronApi.mjs), so results match the app's.
+ *
+ *   npm run cli -- validate [--project <dir>]
+ *   npm run cli -- export <projectId> [--out <file>]
+ *   npm run cli -- trace-matrix <fromType> <toType> [--project-id <id>] [--format csv|json]
+ *   npm run cli -- baseline list [<projectId>]
+ *   npm run cli -- baseline create <projectId> <version> <name> [--description <text>]
+ *
+ * The project folder defaults to the current directory. Commits made by
+ * baseline create use TRACYFY_AUTHOR ("Name <email>") when set. validate
+ * exits with 1 when references are broken, every command with 2 on errors.
+ */
+
+import { writeFile } from 'node:fs/promises';
+import path from 'node:path';
+import { fileURLToPath } from 'node:url';
+import { parseArgs } from 'node:util';
+import { createServer } from 'vite';
+import { createNodeElectronApi } from './cli/nodeElectronApi.mjs';
+
+const APP_ROOT = path.resolve(path.dirname(fileURLToPath(import.meta.url)), '..');
+const CLI_PROFILE_ID = 'cli';
+
+const USAGE = `Usage: tracyfy-cli <command> [options]
+
+Commands:
+  validate                               Check artifact references and links
+  export <projectId>                     Write the project's artifacts and links as JSON
+  trace-matrix <fromType> <toType>       Print the trace matrix between two artifact types
+  baseline list [<projectId>]            List baselines
+  baseline create <projectId> <version> <name>
+                                         Create a baseline of the project
+
+Options:
+  --project <dir>        Project folder (default: current directory)
+  --out <file>           Write output to a file instead of stdout
+  --project-id <id>      trace-matrix: only the artifacts and links of this project
+  --format csv|json      trace-matrix output (default: csv)
+  --description <text>   baseline create: description of the baseline
+  --help                 Show this help`;
+
+// Author profile for commits, from TRACYFY_AUTHOR="Name <email>"
+function cliSettings(projectId) {
+  const match = /^\s*(.+?)\s*<([^>]+)>\s*$/.exec(process.env.TRACYFY_AUTHOR || '');
+  if (!match) return {};
+  return {
+    authorProfiles: [{ id: CLI_PROFILE_ID, name: match[1], email: match[2] }],
+    activeAuthorProfiles: projectId ? { [projectId]: CLI_PROFILE_ID } : {},
+  };
+}
+
+// Stand in for the renderer's globals the services use
+function installBrowserGlobals(electronAPI) {
+  const events = new EventTarget();
+  const storage = new Map();
+  globalThis.window = globalThis;
+  Object.assign(globalThis, {
+    electronAPI,
+    addEventListener: events.addEventListener.bind(events),
+    removeEventListener: events.removeEventListener.bind(events),
+    dispatchEvent: events.dispatchEvent.bind(events),
+    localStorage: {
+      getItem: (key) => (storage.has(key) ? storage.get(key) : null),
+      setItem: (key, value) => storage.set(key, String(value)),
+      removeItem: (key) => storage.delete(key),
+      clear: () => storage.clear(),
+    },
+  });
+}
+
+function csvCell(value) {
+  return /[",\n\r]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value;
+}
+
+async function output(text, outFile) {
+  if (outFile) {
+    await writeFile(outFile, text, 'utf-8');
+  } else {
+    process.stdout.write(text.endsWith('\n') ? text : `${text}\n`);
+  }
+}
+
+const COMMANDS = {
+  async validate({ core }) {
+    const report = await core.validateProject();
+    for (const ref of report.broken) {
+      const location = ref.line ? `${ref.sourcePath}:${ref.line}` : ref.sourcePath;
+      console.log(`${location}: ${ref.kind} ${ref.target} (${ref.reason})`);
+    }
+    console.log(
+      `${report.scannedFiles} files, ${report.checkedReferences} references, ` +
+        `${report.broken.length} broken`
+    );
+    return report.broken.length > 0 ? 1 : 0;
+  },
+
+  async export({ core, positionals: [projectId], values }) {
+    if (!projectId) throw new Error('export needs a project ID');
+    const data = await core.exportProject(projectId);
+    await output(JSON.stringify(data, null, 2), values.out);
+    return 0;
+  },
+
+  async 'trace-matrix'({ core, positionals: [fromType, toType], values }) {
+    if (!fromType || !toType) throw new Error('trace-matrix needs a from and a to type');
+    if (values.format && values.format !== 'csv' && values.format !== 'json') {
+      throw new Error(`Unknown format: ${values.format}`);
+    }
+    const { matrix, table } = await core.getTraceMatrix(fromType, toType, values['project-id']);
+    if (values.format === 'json') {
+      await output(JSON.stringify(matrix, null, 2), values.out);
+    } else {
+      await output(table.map((row) => row.map(csvCell).join(',')).join('\n'), values.out);
+    }
+    return 0;
+  },
+
+  async baseline({ core, positionals: [action, projectId, version, name], values }) {
+    if (action === 'list') {
+      const baselines = await core.getBaselines(projectId);
+      await output(JSON.stringify(baselines, null, 2), values.out);
+      return 0;
+    }
+    if (action === 'create') {
+      if (!projectId || !version || !name) {
+        throw new Error('baseline create needs a project ID, a version and a name');
+      }
+      const baseline = await core.createBaseline(projectId, version, name, values.description);
+      console.log(`Created baseline ${baseline.version} of ${projectId}`);
+      return 0;
+    }
+    throw new Error(`Unknown baseline action: ${action ?? '(none)'}`);
+  },
+};
+
+async function main() {
+  const { values, positionals } = parseArgs({
+    allowPositionals: true,
+    options: {
+      project: { type: 'string', default: process.cwd() },
+      out: { type: 'string' },
+      'project-id': { type: 'string' },
+      format: { type: 'string' },
+      description: { type: 'string' },
+      help: { type: 'boolean' },
+    },
+  });
+  const [command, ...args] = positionals;
+  if (values.help || !command) {
+    console.log(USAGE);
+    return values.help ? 0 : 2;
+  }
+  if (!Object.hasOwn(COMMANDS, command)) {
+    console.error(`Unknown command: ${command}\n\n${USAGE}`);
+    return 2;
+  }
+
+  const projectId = command === 'baseline' ? args[1] : args[0];
+  installBrowserGlobals(createNodeElectronApi({ settings: cliSettings(projectId) }));
+
+  const vite = await createServer({
+    root: APP_ROOT,
+    configFile: false,
+    logLevel: 'error',
+    appType: 'custom',
+    server: { middlewareMode: true, hmr: false, watch: null },
+    optimizeDeps: { noDiscovery: true },
+  });
+  try {
+    const { headlessService: core } = await vite.ssrLoadModule('/src/services/headlessService.ts');
+    await core.openProject(path.resolve(values.project));
+    return await COMMANDS[command]({ core, positionals: args, values });
+  } finally {
+    await vite.close();
+  }
+}
+
+// Exit codes are set rather than exiting at once, so piped output is flushed first
+main().then(
+  (code) => {
+    process.exitCode = code;
+  },
+  (error) => {
+    console.error(`tracyfy-cli: ${error.message}`);
+    process.exitCode = 2;
+  }
+);
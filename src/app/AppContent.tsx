@@ -1,10 +1,18 @@
 import { AppRoutes } from '../routes/AppRoutes';
 import { DirectorySelector, UserOnboardingModal } from '../components';
 import { useFileSystem, useUser } from './providers';
+import { useApiCompatibility } from '../hooks/useApiCompatibility';
 import { useArtifactDeepLink } from '../hooks/useArtifactDeepLink';
+import { useDesktopDeepLinks } from '../hooks/useDesktopDeepLinks';
+import { useProjectScan } from '../hooks/useProjectScan';
+import { useSecretScanWarnings } from '../hooks/useSecretScanWarnings';
 
 export function AppContent() {
+  useApiCompatibility();
   useArtifactDeepLink();
+  useDesktopDeepLinks();
+  useProjectScan();
+  useSecretScanWarnings();
   const fileSystem = useFileSystem();
   const { users, isLoading: isUserLoading, createUser, switchUser } = useUser();
 
@@ -4,6 +4,9 @@ import { useUser } from '../UserProvider';
 import { useToast } from '../ToastProvider';
 import { useBackgroundTasks } from '../BackgroundTasksProvider';
 import { incrementRevision } from '../../../utils/revisionUtils';
+import { syncMirroredLinks } from '../../../services/linkMirrorService';
+import { checkPermission } from '../../../services/permissionService';
+import type { ArtifactLink } from '../../../types';
 
 export interface BaseArtifact {
   id: string;
@@ -34,6 +37,18 @@ export interface CRUDOptions<T> {
   onBeforeAdd?: (data: Omit<T, 'id' | 'lastModified' | 'revision'>) => void;
 }
 
+const getLinkedArtifacts = (item: unknown): ArtifactLink[] =>
+  (item as { linkedArtifacts?: ArtifactLink[] }).linkedArtifacts || [];
+
+const getStatus = (item: unknown): string | undefined => (item as { status?: string }).status;
+
+// Whether an update changes anything besides the status
+const editsContent = <T>(existing: T, updatedData: Partial<T>): boolean =>
+  Object.entries(updatedData).some(
+    ([key, value]) =>
+      key !== 'status' && JSON.stringify(value) !== JSON.stringify(existing[key as keyof T])
+  );
+
 /**
  * Generic hook for artifact CRUD operations.
  * Consolidates common logic across different artifact providers.
@@ -48,11 +63,23 @@ export function useArtifactCRUD<T extends BaseArtifact>({
   onAfterUpdate,
   onBeforeAdd,
 }: CRUDOptions<T>) {
-  const { getNextId } = useFileSystem();
+  const { getNextId, changeArtifactStatus } = useFileSystem();
   const { currentUser } = useUser();
   const { showToast } = useToast();
   const { startTask, endTask } = useBackgroundTasks();
 
+  // Editing needs the author role where the project has roles assigned. Saving checks it
+  // too; asking first skips the optimistic update and shows why
+  const mayEdit = useCallback(async () => {
+    try {
+      await checkPermission('edit');
+      return true;
+    } catch (error) {
+      showToast(error instanceof Error ? error.message : String(error), 'error');
+      return false;
+    }
+  }, [showToast]);
+
   const handleAdd = useCallback(
     async (data: Omit<T, 'id' | 'lastModified' | 'revision'>) => {
       if (!currentUser) {
@@ -63,6 +90,7 @@ export function useArtifactCRUD<T extends BaseArtifact>({
         return null;
       }
 
+      if (!(await mayEdit())) return null;
       if (onBeforeAdd) onBeforeAdd(data);
 
       try {
@@ -89,7 +117,7 @@ export function useArtifactCRUD<T extends BaseArtifact>({
         return null;
       }
     },
-    [currentUser, getNextId, saveFn, setItems, showToast, type, onBeforeAdd]
+    [currentUser, mayEdit, getNextId, saveFn, setItems, showToast, type, onBeforeAdd]
   );
 
   const handleUpdate = useCallback(
@@ -97,15 +125,24 @@ export function useArtifactCRUD<T extends BaseArtifact>({
       const existing = items.find((item) => item.id === id);
       if (!existing) return;
 
+      // A new status is not saved with the other edits: it has to pass the workflow
+      const requestedStatus = getStatus(updatedData);
+      const newStatus = requestedStatus !== getStatus(existing) ? requestedStatus : undefined;
+      // A status change alone is up to the workflow, not an edit of the artifact
+      const saveEdits = newStatus === undefined || editsContent(existing, updatedData);
+      if (saveEdits && !(await mayEdit())) return;
+
       if (onBeforeUpdate) onBeforeUpdate(existing);
 
-      const newRevision = incrementRevision(existing.revision || '01');
-      const finalItem: T = {
-        ...existing,
-        ...updatedData,
-        revision: newRevision,
-        lastModified: Date.now(),
-      };
+      const finalItem: T = saveEdits
+        ? {
+            ...existing,
+            ...updatedData,
+            ...(newStatus !== undefined && { status: getStatus(existing) }),
+            revision: incrementRevision(existing.revision || '01'),
+            lastModified: Date.now(),
+          }
+        : existing;
 
       // Update state immediately for responsive UI
       setItems((prev) => prev.map((item) => (item.id === id ? finalItem : item)));
@@ -113,7 +150,32 @@ export function useArtifactCRUD<T extends BaseArtifact>({
       // Save to disk with task message
       const taskId = startTask(`Saving ${id}...`);
       try {
-        await saveFn(finalItem);
+        if (saveEdits) {
+          await saveFn(finalItem);
+
+          // Keep embedded links bidirectional by mirroring changes onto the linked artifacts
+          const mirrored = await syncMirroredLinks(
+            id,
+            getLinkedArtifacts(existing),
+            getLinkedArtifacts(finalItem)
+          );
+          if (mirrored.length > 0) {
+            const mirroredById = new Map(mirrored.map((a) => [a.id, a]));
+            setItems((prev) => prev.map((item) => (mirroredById.get(item.id) as T) ?? item));
+          }
+        }
+
+        if (newStatus !== undefined) {
+          try {
+            const moved = await changeArtifactStatus<T>(id, newStatus);
+            const statusItem = moved ?? { ...finalItem, status: newStatus };
+            setItems((prev) => prev.map((item) => (item.id === id ? statusItem : item)));
+          } catch (error) {
+            console.error(`Failed to change status of ${id}:`, error);
+            showToast(error instanceof Error ? error.message : String(error), 'error');
+          }
+        }
+
         if (onAfterUpdate) onAfterUpdate(finalItem);
       } catch (error) {
         console.error(`Failed to update ${type}:`, error);
@@ -122,13 +184,25 @@ export function useArtifactCRUD<T extends BaseArtifact>({
         endTask(taskId);
       }
     },
-    [items, saveFn, setItems, type, showToast, onBeforeUpdate, onAfterUpdate, startTask, endTask]
+    [
+      items,
+      mayEdit,
+      saveFn,
+      changeArtifactStatus,
+      setItems,
+      type,
+      showToast,
+      onBeforeUpdate,
+      onAfterUpdate,
+      startTask,
+      endTask,
+    ]
   );
 
   const handleDelete = useCallback(
-    (id: string) => {
+    async (id: string) => {
       const existing = items.find((item) => item.id === id);
-      if (!existing) return;
+      if (!existing || !(await mayEdit())) return;
 
       const deletedItem: T = {
         ...existing,
@@ -139,13 +213,13 @@ export function useArtifactCRUD<T extends BaseArtifact>({
       setItems((prev) => prev.map((item) => (item.id === id ? deletedItem : item)));
       saveFn(deletedItem).catch((err) => console.error(`Failed to soft-delete ${type}:`, err));
     },
-    [items, saveFn, setItems, type]
+    [items, mayEdit, saveFn, setItems, type]
   );
 
   const handleRestore = useCallback(
-    (id: string) => {
+    async (id: string) => {
       const existing = items.find((item) => item.id === id);
-      if (!existing) return;
+      if (!existing || !(await mayEdit())) return;
 
       const restoredItem: T = {
         ...existing,
@@ -157,15 +231,16 @@ export function useArtifactCRUD<T extends BaseArtifact>({
       setItems((prev) => prev.map((item) => (item.id === id ? restoredItem : item)));
       saveFn(restoredItem).catch((err) => console.error(`Failed to restore ${type}:`, err));
     },
-    [items, saveFn, setItems, type]
+    [items, mayEdit, saveFn, setItems, type]
   );
 
   const handlePermanentDelete = useCallback(
-    (id: string) => {
+    async (id: string) => {
+      if (!(await mayEdit())) return;
       setItems((prev) => prev.filter((item) => item.id !== id));
       deleteFn(id).catch((err) => console.error(`Failed to permanently delete ${type}:`, err));
     },
-    [deleteFn, setItems, type]
+    [mayEdit, deleteFn, setItems, type]
   );
 
   return {
@@ -18,6 +18,7 @@ interface BackgroundTasksContextValue {
   tasks: BackgroundTask[];
   isWorking: boolean;
   startTask: (message: string) => string;
+  updateTask: (id: string, message: string) => void;
   endTask: (id: string) => void;
 }
 
@@ -34,6 +35,10 @@ export const BackgroundTasksProvider: React.FC<{ children: React.ReactNode }> =
     return id;
   }, []);
 
+  const updateTask = useCallback((id: string, message: string) => {
+    setTasks((prev) => prev.map((t) => (t.id === id ? { ...t, message } : t)));
+  }, []);
+
   const endTask = useCallback((id: string) => {
     debug.log(`[BackgroundTasks] Ending task: ${id}`);
     setTasks((prev) => prev.filter((t) => t.id !== id));
@@ -42,7 +47,7 @@ export const BackgroundTasksProvider: React.FC<{ children: React.ReactNode }> =
   const isWorking = tasks.length > 0;
 
   return (
-    <BackgroundTasksContext.Provider value={{ tasks, isWorking, startTask, endTask }}>
+    <BackgroundTasksContext.Provider value={{ tasks, isWorking, startTask, updateTask, endTask }}>
       {children}
     </BackgroundTasksContext.Provider>
   );
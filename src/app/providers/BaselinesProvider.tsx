@@ -1,10 +1,10 @@
 import React, { createContext, useContext, useState, useEffect, useCallback, useRef } from 'react';
 import type { ReactNode } from 'react';
-import { diskBaselineService } from '../../services/diskBaselineService';
+import { baselineService } from '../../services/baselineService';
+import { exportBaselineSnapshot } from '../../utils/baselineExportUtils';
 import { useProject } from './ProjectProvider';
 import { useFileSystem } from './FileSystemProvider';
 import { useToast } from './ToastProvider';
-import { useRisks } from './ArtifactProviders';
 import type { ProjectBaseline } from '../../types';
 
 interface BaselinesContextValue {
@@ -13,7 +13,8 @@ interface BaselinesContextValue {
   createBaseline: (
     name: string,
     description: string,
-    version: string
+    version: string,
+    options?: { exportSnapshot?: boolean }
   ) => Promise<ProjectBaseline | null>;
   deleteBaseline: (id: string) => Promise<void>;
   refreshBaselines: () => Promise<void>;
@@ -25,9 +26,7 @@ export const BaselinesProvider: React.FC<{ children: ReactNode }> = ({ children
   const [baselines, setBaselines] = useState<ProjectBaseline[]>([]);
   const [loading, setLoading] = useState(false);
   const { currentProject } = useProject();
-  const { isReady, requirements, useCases, testCases, information, getArtifactHistory } =
-    useFileSystem();
-  const { risks, getRiskHistory } = useRisks();
+  const { isReady } = useFileSystem();
   const { showToast } = useToast();
   const hasLoadedInitial = useRef(false);
 
@@ -36,7 +35,7 @@ export const BaselinesProvider: React.FC<{ children: ReactNode }> = ({ children
 
     setLoading(true);
     try {
-      const loadedBaselines = await diskBaselineService.loadBaselines(currentProject?.id);
+      const loadedBaselines = await baselineService.listBaselines(currentProject?.id);
       setBaselines(loadedBaselines);
     } catch (err) {
       console.error('Failed to load baselines:', err);
@@ -53,101 +52,54 @@ export const BaselinesProvider: React.FC<{ children: ReactNode }> = ({ children
   }, [isReady, refreshBaselines]);
 
   const createBaseline = useCallback(
-    async (name: string, description: string, version: string) => {
+    async (
+      name: string,
+      description: string,
+      version: string,
+      options: { exportSnapshot?: boolean } = {}
+    ) => {
       if (!currentProject) return null;
 
+      let newBaseline: ProjectBaseline;
       try {
-        // 1. Get latest commit hash for all artifacts in project
-        const artifactCommits: {
-          [artifactId: string]: {
-            commitHash: string;
-            type: 'requirement' | 'usecase' | 'testcase' | 'information' | 'risk';
-          };
-        } = {};
-
-        const captureCommits = async (
-          items: { id: string }[],
-          type: 'requirements' | 'usecases' | 'testcases' | 'information' | 'risks'
-        ) => {
-          // Map long naming (provider) to short naming (git folder)
-          const gitType =
-            type === 'requirements'
-              ? 'requirement'
-              : type === 'usecases'
-                ? 'usecase'
-                : type === 'testcases'
-                  ? 'testcase'
-                  : type === 'risks'
-                    ? 'risk'
-                    : 'information';
-
-          for (const item of items) {
-            const history =
-              type === 'risks'
-                ? await getRiskHistory(item.id)
-                : await getArtifactHistory(
-                    type as 'requirements' | 'usecases' | 'testcases' | 'information',
-                    item.id
-                  );
-            if (history.length > 0) {
-              artifactCommits[item.id] = {
-                commitHash: history[0].hash,
-                type: gitType as 'requirement' | 'usecase' | 'testcase' | 'information' | 'risk',
-              };
-            }
-          }
-        };
-
-        await Promise.all([
-          captureCommits(requirements, 'requirements'),
-          captureCommits(useCases, 'usecases'),
-          captureCommits(testCases, 'testcases'),
-          captureCommits(information, 'information'),
-          captureCommits(risks, 'risks'),
-        ]);
-
-        const newBaseline: ProjectBaseline = {
-          id: `bl-${Date.now()}`,
+        newBaseline = await baselineService.createBaseline(name, description, {
           projectId: currentProject.id,
-          name,
-          description,
           version,
-          timestamp: Date.now(),
-          artifactCommits,
-        };
-
-        await diskBaselineService.saveBaseline(newBaseline);
+        });
         setBaselines((prev) => [newBaseline, ...prev]);
         showToast(`Baseline ${version} created successfully`, 'success');
-        return newBaseline;
       } catch (err) {
         console.error('Failed to create baseline:', err);
         showToast('Failed to create baseline', 'error');
         return null;
       }
+
+      if (options.exportSnapshot) {
+        try {
+          const contents = await baselineService.getBaselineContents(
+            newBaseline.id,
+            currentProject.id
+          );
+          await exportBaselineSnapshot(contents, currentProject.name);
+        } catch (err) {
+          console.error('Failed to export baseline snapshot:', err);
+          showToast('Baseline created, but the snapshot export failed', 'error');
+        }
+      }
+      return newBaseline;
     },
-    [
-      currentProject,
-      requirements,
-      useCases,
-      testCases,
-      information,
-      risks,
-      getArtifactHistory,
-      getRiskHistory,
-      showToast,
-    ]
+    [currentProject, showToast]
   );
 
   const deleteBaseline = useCallback(
     async (id: string) => {
       try {
-        await diskBaselineService.deleteBaseline(id);
+        await baselineService.deleteBaseline(id);
         setBaselines((prev) => prev.filter((b) => b.id !== id));
         showToast('Baseline deleted', 'success');
       } catch (err) {
         console.error('Failed to delete baseline:', err);
-        showToast('Failed to delete baseline', 'error');
+        showToast(err instanceof Error ? err.message : 'Failed to delete baseline', 'error');
       }
     },
     [showToast]
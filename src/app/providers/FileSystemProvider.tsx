@@ -1,6 +1,6 @@
 import React, { createContext, useContext, useState, useEffect, useCallback, useRef } from 'react';
 import { debug } from '../../utils/debug';
-import { fileSystemService } from '../../services/fileSystemService';
+import { fileSystemService, type DirectoryState } from '../../services/fileSystemService';
 import {
   realGitService,
   type FileStatus,
@@ -18,6 +18,17 @@ import {
   documentService,
 } from '../../services/artifactServices';
 import { diskLinkService } from '../../services/diskLinkService';
+import { startLocalApi, stopLocalApi } from '../../services/localApiService';
+import { startWebhooks } from '../../services/webhookService';
+import { notifyExternalChanges } from '../../services/changeNotificationService';
+import { loadEncryption } from '../../services/encryptionService';
+import { startUndoTracking } from '../../services/undoService';
+import { startParseCacheTracking } from '../../services/parseCacheService';
+import { startQuickFindTracking } from '../../services/quickFindService';
+import { recoverWriteJournal } from '../../services/writeJournalService';
+import { artifactTypeService } from '../../services/artifactTypeService';
+import { statusWorkflowService, type WorkflowArtifact } from '../../services/statusWorkflowService';
+import { formatMigrationReport, migrateProject } from '../../services/migrationService';
 import { useBackgroundTasks } from './BackgroundTasksProvider';
 import type {
   Requirement,
@@ -37,7 +48,10 @@ interface FileSystemContextValue {
   directoryName: string | null;
   error: string | null;
   selectDirectory: () => Promise<void>;
+  openDirectory: (path: string) => Promise<void>; // Desktop app: open a folder by path
   changeDirectory: () => Promise<void>;
+  isReadOnly: boolean; // Reviewer mode: the desktop app refuses changes to the project
+  setReadOnly: (readOnly: boolean) => Promise<boolean>; // Resolves to the mode afterwards
   // Loaded data from disk
   projects: Project[];
   currentProjectId: string;
@@ -71,6 +85,11 @@ interface FileSystemContextValue {
   deleteProject: (id: string) => Promise<void>;
   saveLink: (link: Link) => Promise<void>;
   deleteLink: (id: string) => Promise<void>;
+  // Move an artifact to a status through the workflow; null when nothing changed on disk
+  changeArtifactStatus: <T extends WorkflowArtifact>(
+    id: string,
+    status: string
+  ) => Promise<T | null>;
   createProject: (name: string, description: string) => Promise<Project>;
   setCurrentProject: (projectId: string) => Promise<void>;
   getNextId: (
@@ -103,6 +122,22 @@ interface FileSystemContextValue {
 
 export const FileSystemContext = createContext<FileSystemContextValue | undefined>(undefined);
 
+/**
+ * Bring a project written by an older app version to the current on-disk
+ * format, after showing what would change
+ */
+async function applyFormatMigrations(): Promise<void> {
+  const report = await migrateProject({ dryRun: true });
+  if (report.tooNew) {
+    window.alert(formatMigrationReport(report));
+    return;
+  }
+  if (report.fromVersion >= report.toVersion) return;
+  if (window.confirm(`${formatMigrationReport(report)}\n\nMigrate this project now?`)) {
+    await migrateProject();
+  }
+}
+
 // Check if we're in E2E test mode (skip disk operations)
 interface ExtendedWindow extends Window {
   __E2E_TEST_MODE__?: boolean;
@@ -119,6 +154,7 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
   const [isLoading, setIsLoading] = useState(!initialE2EMode);
 
   const [directoryName, setDirectoryName] = useState<string | null>(null);
+  const [readOnlyDirectory, setReadOnlyDirectory] = useState<string | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [pendingChanges, setPendingChanges] = useState<FileStatus[]>([]);
   const [information, setInformation] = useState<Information[]>([]);
@@ -186,6 +222,8 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     const taskId = startTask('Loading data...');
     try {
       debug.log('[reloadData] Loading all data from disk...');
+      // Custom artifact types first, so their IDs and folders are known
+      await artifactTypeService.loadCustomTypes();
       const data = await diskProjectService.loadAll();
       setProjects(data.projects);
       setCurrentProjectIdState(data.currentProjectId);
@@ -258,10 +296,17 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
             // Initialize git with the restored directory (handle for browser, path for Electron)
             const gitInitialized = await realGitService.init(result.handle);
             if (gitInitialized) {
-              setDirectoryName(fileSystemService.getDirectoryName());
+              const name = fileSystemService.getDirectoryName();
+              setDirectoryName(name);
+              // Reviewer mode is remembered by the desktop app for the folder
+              setReadOnlyDirectory(fileSystemService.isReadOnly() ? name : null);
 
               // Initialize disk project service directories
               await diskProjectService.initialize();
+              // Finish or undo a multi-file write interrupted by a crash
+              await recoverWriteJournal();
+              await loadEncryption();
+              await applyFormatMigrations();
 
               // Load data and git status in parallel (they're independent)
               const [, status] = await Promise.all([reloadData(), realGitService.getStatus()]);
@@ -283,61 +328,140 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     tryRestore();
   }, [reloadData, startTask, endTask]);
 
-  const selectDirectory = useCallback(async () => {
-    setIsLoading(true);
-    setError(null);
-    const taskId = startTask('Loading project...');
+  // Serve the local API while a directory is open, if enabled in its settings
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    startLocalApi().catch((err) => console.error('Failed to start local API:', err));
+    return () => {
+      stopLocalApi();
+    };
+  }, [isReady]);
 
-    try {
-      const result = await fileSystemService.selectDirectory();
-
-      // Check if directory is empty (excluding .git)
-      const { isEmpty, entryCount } = await fileSystemService.checkDirectoryEmpty();
-
-      // If directory is not empty, ask for confirmation
-      if (!isEmpty) {
-        const confirmed = window.confirm(
-          `This folder contains ${entryCount} ${entryCount === 1 ? 'item' : 'items'}.\n\n` +
-            `Tracyfy will create project files in this folder. Are you sure you want to use this folder?`
-        );
-
-        if (!confirmed) {
-          // User cancelled, clear the selection
-          await fileSystemService.clearDirectory();
-          setError('Directory selection was cancelled.');
+  // Fire configured webhooks on commits, baselines and approvals
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return startWebhooks();
+  }, [isReady]);
+
+  // Pick up files changed outside the app (editors, git on the command line)
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return fileSystemService.onFilesChanged(() => refreshStatus());
+  }, [isReady, refreshStatus]);
+
+  // Tell the user about those changes while the app is in the background
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return fileSystemService.onExternalChanges((paths) => {
+      void notifyExternalChanges({ changedPaths: paths });
+    });
+  }, [isReady]);
+
+  // Re-parse artifact files only when they change
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return startParseCacheTracking();
+  }, [isReady]);
+
+  // Keep the quick-open index of artifact IDs and titles current
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return startQuickFindTracking();
+  }, [isReady]);
+
+  // Show links flagged suspect by a commit (see suspectLinkService) without a reload
+  useEffect(() => {
+    const handleFlagged = (event: Event) => {
+      const { links: flagged } = (event as CustomEvent<{ links: Link[] }>).detail;
+      const flaggedById = new Map(flagged.map((link) => [link.id, link]));
+      setLinks((prev) => prev.map((link) => flaggedById.get(link.id) ?? link));
+    };
+    window.addEventListener('links-flagged-suspect', handleFlagged);
+    return () => window.removeEventListener('links-flagged-suspect', handleFlagged);
+  }, []);
+
+  // Snapshot artifact saves for undo/redo
+  useEffect(() => {
+    if (!isReady || isE2EMode()) return;
+    return startUndoTracking();
+  }, [isReady]);
+
+  // Open the directory returned by choose (a picker, or a path given from outside)
+  const openChosenDirectory = useCallback(
+    async (choose: () => Promise<DirectoryState>) => {
+      setIsLoading(true);
+      setError(null);
+      const taskId = startTask('Loading project...');
+
+      try {
+        const result = await choose();
+
+        // Check if directory is empty (excluding .git)
+        const { isEmpty, entryCount } = await fileSystemService.checkDirectoryEmpty();
+
+        // If directory is not empty, ask for confirmation
+        if (!isEmpty) {
+          const confirmed = window.confirm(
+            `This folder contains ${entryCount} ${entryCount === 1 ? 'item' : 'items'}.\n\n` +
+              `Tracyfy will create project files in this folder. Are you sure you want to use this folder?`
+          );
+
+          if (!confirmed) {
+            // User cancelled, clear the selection
+            await fileSystemService.clearDirectory();
+            setError('Directory selection was cancelled.');
+            setIsLoading(false);
+            endTask(taskId);
+            return;
+          }
+        }
+
+        // Initialize git (handle for browser, path for Electron)
+        const gitInitialized = await realGitService.init(result.handle);
+        if (!gitInitialized) {
+          setError('Git initialization was cancelled. A git repository is required.');
           setIsLoading(false);
           endTask(taskId);
           return;
         }
-      }
 
-      // Initialize git (handle for browser, path for Electron)
-      const gitInitialized = await realGitService.init(result.handle);
-      if (!gitInitialized) {
-        setError('Git initialization was cancelled. A git repository is required.');
-        setIsLoading(false);
-        endTask(taskId);
-        return;
-      }
+        const name = fileSystemService.getDirectoryName();
+        setDirectoryName(name);
+        // Reviewer mode is remembered by the desktop app for the folder
+        setReadOnlyDirectory(fileSystemService.isReadOnly() ? name : null);
 
-      setDirectoryName(fileSystemService.getDirectoryName());
+        // Initialize disk project service directories
+        await diskProjectService.initialize();
+        // Finish or undo a multi-file write interrupted by a crash
+        await recoverWriteJournal();
+        await loadEncryption();
+        await applyFormatMigrations();
 
-      // Initialize disk project service directories
-      await diskProjectService.initialize();
+        // Load data and git status in parallel (they're independent)
+        const [, status] = await Promise.all([reloadData(), realGitService.getStatus()]);
+        debug.log('[selectDirectory] Setting pendingChanges to:', status);
+        setPendingChanges(status);
 
-      // Load data and git status in parallel (they're independent)
-      const [, status] = await Promise.all([reloadData(), realGitService.getStatus()]);
-      debug.log('[selectDirectory] Setting pendingChanges to:', status);
-      setPendingChanges(status);
+        setIsReady(true);
+      } catch (err) {
+        setError((err as Error).message);
+      } finally {
+        endTask(taskId);
+        setIsLoading(false);
+      }
+    },
+    [reloadData, startTask, endTask]
+  );
 
-      setIsReady(true);
-    } catch (err) {
-      setError((err as Error).message);
-    } finally {
-      endTask(taskId);
-      setIsLoading(false);
-    }
-  }, [reloadData, startTask, endTask]);
+  const selectDirectory = useCallback(
+    () => openChosenDirectory(() => fileSystemService.selectDirectory()),
+    [openChosenDirectory]
+  );
+
+  const openDirectory = useCallback(
+    (path: string) => openChosenDirectory(() => fileSystemService.openDirectoryPath(path)),
+    [openChosenDirectory]
+  );
 
   // CRUD operations - Requirements
   const saveRequirement = useCallback(
@@ -646,6 +770,17 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     [isReady, refreshStatus]
   );
 
+  const changeArtifactStatus = useCallback(
+    async <T extends WorkflowArtifact>(id: string, status: string): Promise<T | null> => {
+      if (!isReady) throw new Error('Filesystem not ready');
+      if (isE2EMode()) return null;
+      const updated = await statusWorkflowService.changeArtifactStatus<T>(id, status);
+      refreshStatus(); // Fire-and-forget for responsive UI
+      return updated;
+    },
+    [isReady, refreshStatus]
+  );
+
   // Get next ID
   const getNextId = useCallback(
     async (
@@ -702,6 +837,7 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
         `[commitFile] Committing ${filepath} with message: ${message} by ${authorName || 'Tracyfy User'}`
       );
       await realGitService.commitFile(filepath, message, authorName);
+
       // Trigger sync status update
       window.dispatchEvent(new CustomEvent('git-check'));
     },
@@ -769,6 +905,7 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     setIsReady(false);
     setIsLoading(false); // Set to false so DirectorySelector shows selection UI, not loading spinner
     setDirectoryName(null);
+    setReadOnlyDirectory(null);
     setError(null);
     setPendingChanges([]);
     setProjects([]);
@@ -784,6 +921,12 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     setPreloadedCurrentUserId('');
   }, []);
 
+  const setReadOnly = useCallback(async (readOnly: boolean) => {
+    const enabled = await fileSystemService.setReadOnly(readOnly);
+    setReadOnlyDirectory(enabled ? fileSystemService.getDirectoryName() : null);
+    return enabled;
+  }, []);
+
   return (
     <FileSystemContext.Provider
       value={{
@@ -793,7 +936,11 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
         directoryName,
         error,
         selectDirectory,
+        openDirectory,
         changeDirectory,
+        // Read-only only lasts while the same folder is open
+        isReadOnly: readOnlyDirectory !== null && readOnlyDirectory === directoryName,
+        setReadOnly,
         // Data
         projects,
         currentProjectId,
@@ -827,6 +974,7 @@ export const FileSystemProvider: React.FC<{ children: React.ReactNode }> = ({ ch
         deleteProject,
         saveLink,
         deleteLink,
+        changeArtifactStatus,
         setCurrentProject,
         getNextId,
         reloadData,
@@ -5,6 +5,7 @@ import { useGlobalState } from './GlobalStateProvider';
 import { useProject } from './ProjectProvider';
 import { useBackgroundTasks } from './BackgroundTasksProvider';
 import { useDocuments } from './ArtifactProviders';
+import { runJob } from '../../services/jobService';
 
 interface ImportExportContextValue {
   handleExport: () => void;
@@ -43,11 +44,11 @@ export const ImportExportProvider: React.FC<{ children: ReactNode }> = ({ childr
     setDocuments: useDocuments().setDocuments,
   });
 
-  // Wrap handleExport with progress status
+  // Run the export as a background job, with progress status
   const handleExport = useCallback(async () => {
     const taskId = startTask('Exporting JSON...');
     try {
-      await importExportHook.handleExport();
+      await runJob('Export JSON', () => importExportHook.handleExport());
     } finally {
       endTask(taskId);
     }
@@ -0,0 +1,261 @@
+/**
+ * ArtifactTypesModal Component
+ *
+ * Lists the project's custom artifact types and lets users add a type,
+ * move a type's files to another folder, or delete a type.
+ */
+
+import React, { useCallback, useEffect, useState } from 'react';
+import { X, Trash2, FolderInput } from 'lucide-react';
+import { artifactTypeService, type CustomArtifactType } from '../services/artifactTypeService';
+import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+
+interface ArtifactTypesModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+const inputStyle: React.CSSProperties = {
+  width: '100%',
+  padding: '8px 12px',
+  borderRadius: '6px',
+  border: '1px solid var(--color-border)',
+  backgroundColor: 'var(--color-bg-app)',
+  color: 'var(--color-text-primary)',
+  outline: 'none',
+};
+
+const iconButtonStyle: React.CSSProperties = {
+  background: 'none',
+  border: 'none',
+  color: 'var(--color-text-muted)',
+  cursor: 'pointer',
+};
+
+export const ArtifactTypesModal: React.FC<ArtifactTypesModalProps> = ({ isOpen, onClose }) => {
+  const [types, setTypes] = useState<CustomArtifactType[]>([]);
+  const [label, setLabel] = useState('');
+  const [idPrefix, setIdPrefix] = useState('');
+  const [folder, setFolder] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [isSubmitting, setIsSubmitting] = useState(false);
+
+  const run = useCallback(async (action: () => Promise<unknown>) => {
+    setError(null);
+    setIsSubmitting(true);
+    try {
+      await action();
+      setTypes(await artifactTypeService.listCustomTypes());
+      return true;
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err));
+      return false;
+    } finally {
+      setIsSubmitting(false);
+    }
+  }, []);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    setError(null);
+    artifactTypeService
+      .listCustomTypes()
+      .then(setTypes)
+      .catch((err) => setError(err instanceof Error ? err.message : String(err)));
+  }, [isOpen]);
+
+  const handleCreate = async (e?: React.FormEvent) => {
+    if (e) e.preventDefault();
+    if (!label.trim() || !idPrefix.trim() || !folder.trim()) return;
+    const created = await run(() =>
+      artifactTypeService.createType({
+        key: folder.trim(),
+        label: label.trim(),
+        idPrefix: idPrefix.trim(),
+        folder: folder.trim(),
+      })
+    );
+    if (created) {
+      setLabel('');
+      setIdPrefix('');
+      setFolder('');
+    }
+  };
+
+  const handleRename = (type: CustomArtifactType) => {
+    const newFolder = window.prompt(`Move the files of ${type.label} to folder:`, type.folder);
+    if (!newFolder || newFolder.trim() === type.folder) return;
+    run(() => artifactTypeService.renameTypeFolder(type.key, newFolder.trim()));
+  };
+
+  const handleDelete = (type: CustomArtifactType) => {
+    const confirmed = window.confirm(
+      `Delete the artifact type ${type.label}?\n\n` +
+        `All files in ${type.folder}/ will be deleted in the same commit.`
+    );
+    if (confirmed) {
+      run(() => artifactTypeService.deleteType(type.key, true));
+    }
+  };
+
+  useKeyboardShortcuts({
+    onSave: handleCreate,
+    onClose: onClose,
+  });
+
+  if (!isOpen) return null;
+
+  return (
+    <div
+      style={{
+        position: 'fixed',
+        top: 0,
+        left: 0,
+        right: 0,
+        bottom: 0,
+        backgroundColor: 'var(--color-bg-overlay, #222)',
+        display: 'flex',
+        alignItems: 'center',
+        justifyContent: 'center',
+        zIndex: 1000,
+      }}
+    >
+      <div
+        style={{
+          backgroundColor: 'var(--color-bg-card)',
+          borderRadius: '8px',
+          border: '1px solid var(--color-border)',
+          width: '560px',
+          maxWidth: '90%',
+          maxHeight: '90vh',
+          display: 'flex',
+          flexDirection: 'column',
+          boxShadow: '0 20px 25px -5px rgba(0, 0, 0, 0.1), 0 10px 10px -5px rgba(0, 0, 0, 0.04)',
+        }}
+      >
+        <div
+          style={{
+            padding: 'var(--spacing-md)',
+            borderBottom: '1px solid var(--color-border)',
+            display: 'flex',
+            alignItems: 'center',
+            justifyContent: 'space-between',
+          }}
+        >
+          <h3 style={{ fontWeight: 600 }}>Artifact Types</h3>
+          <button onClick={onClose} style={iconButtonStyle} aria-label="Close">
+            <X size={20} />
+          </button>
+        </div>
+
+        <div style={{ padding: 'var(--spacing-lg)', overflowY: 'auto' }}>
+          {error && (
+            <div
+              role="alert"
+              style={{ color: 'var(--color-error)', marginBottom: 'var(--spacing-md)' }}
+            >
+              {error}
+            </div>
+          )}
+
+          {types.length === 0 ? (
+            <p style={{ color: 'var(--color-text-muted)', marginBottom: 'var(--spacing-md)' }}>
+              No custom artifact types yet.
+            </p>
+          ) : (
+            <ul style={{ listStyle: 'none', padding: 0, marginBottom: 'var(--spacing-md)' }}>
+              {types.map((type) => (
+                <li
+                  key={type.key}
+                  style={{
+                    display: 'flex',
+                    alignItems: 'center',
+                    gap: 'var(--spacing-sm)',
+                    padding: '8px 0',
+                    borderBottom: '1px solid var(--color-border)',
+                  }}
+                >
+                  <span style={{ flex: 1 }}>
+                    {type.label}{' '}
+                    <span style={{ color: 'var(--color-text-muted)' }}>
+                      ({type.idPrefix}-001, {type.folder}/)
+                    </span>
+                  </span>
+                  <button
+                    onClick={() => handleRename(type)}
+                    disabled={isSubmitting}
+                    style={iconButtonStyle}
+                    title={`Move ${type.label} to another folder`}
+                  >
+                    <FolderInput size={16} />
+                  </button>
+                  <button
+                    onClick={() => handleDelete(type)}
+                    disabled={isSubmitting}
+                    style={iconButtonStyle}
+                    title={`Delete ${type.label}`}
+                  >
+                    <Trash2 size={16} />
+                  </button>
+                </li>
+              ))}
+            </ul>
+          )}
+
+          <form onSubmit={handleCreate}>
+            <div
+              style={{
+                display: 'grid',
+                gridTemplateColumns: '2fr 1fr 1fr',
+                gap: 'var(--spacing-sm)',
+                marginBottom: 'var(--spacing-md)',
+              }}
+            >
+              <input
+                type="text"
+                value={label}
+                onChange={(e) => setLabel(e.target.value)}
+                style={inputStyle}
+                placeholder="Label, e.g. Specification"
+                aria-label="Label"
+              />
+              <input
+                type="text"
+                value={idPrefix}
+                onChange={(e) => setIdPrefix(e.target.value.toUpperCase())}
+                style={inputStyle}
+                placeholder="Prefix, e.g. SPEC"
+                aria-label="ID prefix"
+              />
+              <input
+                type="text"
+                value={folder}
+                onChange={(e) => setFolder(e.target.value)}
+                style={inputStyle}
+                placeholder="Folder, e.g. specs"
+                aria-label="Folder"
+              />
+            </div>
+            <div style={{ display: 'flex', justifyContent: 'flex-end' }}>
+              <button
+                type="submit"
+                disabled={isSubmitting || !label.trim() || !idPrefix.trim() || !folder.trim()}
+                style={{
+                  padding: '8px 16px',
+                  borderRadius: '6px',
+                  border: 'none',
+                  backgroundColor: 'var(--color-accent)',
+                  color: 'white',
+                  cursor: 'pointer',
+                  fontWeight: 500,
+                }}
+              >
+                Add Type
+              </button>
+            </div>
+          </form>
+        </div>
+      </div>
+    </div>
+  );
+};
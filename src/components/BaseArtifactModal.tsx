@@ -1,6 +1,7 @@
 import React from 'react';
 import type { ReactNode } from 'react';
-import { X, ArrowLeft } from 'lucide-react';
+import { X, ArrowLeft, FolderOpen } from 'lucide-react';
+import { revealService } from '../services/revealService';
 
 export interface ModalTab {
   id: string;
@@ -25,6 +26,7 @@ interface BaseArtifactModalProps {
   width?: string;
   maxHeight?: string;
   onBack?: () => void;
+  filePath?: string; // Project file of the artifact, offered in the file manager
   formId?: string;
   showDeleteConfirm?: boolean;
   onDeleteConfirm?: () => void;
@@ -51,6 +53,7 @@ export const BaseArtifactModal: React.FC<BaseArtifactModalProps> = ({
   width = '800px',
   maxHeight = '85vh',
   onBack,
+  filePath,
   formId = 'base-artifact-modal-form',
   showDeleteConfirm,
   onDeleteConfirm,
@@ -78,6 +81,13 @@ export const BaseArtifactModal: React.FC<BaseArtifactModalProps> = ({
 
   if (!isOpen) return null;
 
+  const handleReveal = () => {
+    if (!filePath) return;
+    revealService.revealInFileManager(filePath).catch((error) => {
+      alert(`Could not show the file: ${error instanceof Error ? error.message : error}`);
+    });
+  };
+
   return (
     <div
       style={{
@@ -154,25 +164,49 @@ export const BaseArtifactModal: React.FC<BaseArtifactModalProps> = ({
               {title}
             </h2>
           </div>
-          <button
-            type="button"
-            onClick={onClose}
-            className="btn-icon"
-            style={{
-              background: 'none',
-              border: 'none',
-              color: 'var(--color-text-muted)',
-              cursor: 'pointer',
-              padding: '4px',
-              borderRadius: '4px',
-              display: 'flex',
-              alignItems: 'center',
-              justifyContent: 'center',
-              transition: 'all 0.2s',
-            }}
-          >
-            <X size={20} />
-          </button>
+          <div style={{ display: 'flex', alignItems: 'center', gap: '4px' }}>
+            {filePath && revealService.canRevealInFileManager() && (
+              <button
+                type="button"
+                onClick={handleReveal}
+                className="btn-icon"
+                title="Show in file manager"
+                style={{
+                  background: 'none',
+                  border: 'none',
+                  color: 'var(--color-text-muted)',
+                  cursor: 'pointer',
+                  padding: '4px',
+                  borderRadius: '4px',
+                  display: 'flex',
+                  alignItems: 'center',
+                  justifyContent: 'center',
+                  transition: 'all 0.2s',
+                }}
+              >
+                <FolderOpen size={18} />
+              </button>
+            )}
+            <button
+              type="button"
+              onClick={onClose}
+              className="btn-icon"
+              style={{
+                background: 'none',
+                border: 'none',
+                color: 'var(--color-text-muted)',
+                cursor: 'pointer',
+                padding: '4px',
+                borderRadius: '4px',
+                display: 'flex',
+                alignItems: 'center',
+                justifyContent: 'center',
+                transition: 'all 0.2s',
+              }}
+            >
+              <X size={20} />
+            </button>
+          </div>
         </div>
 
         {/* Tab Navigation */}
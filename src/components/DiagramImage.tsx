@@ -0,0 +1,46 @@
+import { useState, useEffect } from 'react';
+import { renderDiagram, type DiagramKind } from '../services/diagramService';
+
+interface DiagramImageProps {
+  kind: DiagramKind;
+  source: string;
+  fallback: React.ReactNode;
+}
+
+/**
+ * Renders a Mermaid or PlantUML code block as an image
+ * Shows the fallback (the code block) while rendering or when it is unavailable
+ */
+export const DiagramImage: React.FC<DiagramImageProps> = ({ kind, source, fallback }) => {
+  const [dataUrl, setDataUrl] = useState<string | null>(null);
+
+  useEffect(() => {
+    let isMounted = true;
+    setDataUrl(null);
+    renderDiagram(kind, source, 'svg').then((url) => {
+      if (isMounted) setDataUrl(url);
+    });
+    return () => {
+      isMounted = false;
+    };
+  }, [kind, source]);
+
+  if (!dataUrl) {
+    return <>{fallback}</>;
+  }
+
+  return (
+    <img
+      src={dataUrl}
+      alt={`${kind} diagram`}
+      style={{
+        display: 'block',
+        maxWidth: '100%',
+        height: 'auto',
+        margin: '8px 0',
+        backgroundColor: 'white',
+        borderRadius: '4px',
+      }}
+    />
+  );
+};
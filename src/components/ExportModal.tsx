@@ -1,13 +1,14 @@
 import React, { useState } from 'react';
 import { FileText, FileSpreadsheet, Download, X } from 'lucide-react';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
-import type { ExportOptions, ProjectBaseline } from '../types';
+import type { ExportOptions, ProjectBaseline, RedactionProfile } from '../types';
 
 interface ExportModalProps {
   isOpen: boolean;
   onClose: () => void;
   baselines: ProjectBaseline[];
   onExport: (options: ExportOptions) => void;
+  redactionProfiles?: RedactionProfile[];
   artifactCounts?: {
     requirements: number;
     useCases: number;
@@ -26,10 +27,12 @@ export const ExportModal: React.FC<ExportModalProps> = ({
   onClose,
   baselines,
   onExport,
+  redactionProfiles = [],
   artifactCounts,
 }) => {
   const [format, setFormat] = useState<ExportFormat>('pdf');
   const [selectedBaselineId, setSelectedBaselineId] = useState<string>('current');
+  const [redactionProfileName, setRedactionProfileName] = useState<string>('');
 
   // Artifact toggles
   const [includeRequirements, setIncludeRequirements] = useState(true);
@@ -72,6 +75,7 @@ export const ExportModal: React.FC<ExportModalProps> = ({
       includeRevisionHistory,
       includeTraceability,
       includeVerificationMatrix,
+      redactionProfile: redactionProfiles.find((p) => p.name === redactionProfileName) || null,
     });
     onClose();
   };
@@ -253,6 +257,47 @@ export const ExportModal: React.FC<ExportModalProps> = ({
             </select>
           </section>
 
+          {/* Redaction Profile */}
+          {redactionProfiles.length > 0 && (
+            <section style={{ marginBottom: '32px' }}>
+              <h4
+                style={{
+                  fontSize: '0.875rem',
+                  fontWeight: 600,
+                  color: 'var(--color-text-muted)',
+                  textTransform: 'uppercase',
+                  letterSpacing: '0.05em',
+                  marginBottom: '16px',
+                }}
+              >
+                Redaction
+              </h4>
+              <select
+                id="redaction-select"
+                aria-label="Redaction profile"
+                value={redactionProfileName}
+                onChange={(e) => setRedactionProfileName(e.target.value)}
+                style={{
+                  width: '100%',
+                  padding: '12px 16px',
+                  borderRadius: '12px',
+                  border: '1px solid var(--color-border)',
+                  backgroundColor: 'var(--color-bg-card)',
+                  color: 'var(--color-text-primary)',
+                  fontSize: '0.925rem',
+                  outline: 'none',
+                }}
+              >
+                <option value="">No redaction (full content)</option>
+                {redactionProfiles.map((p) => (
+                  <option key={p.name} value={p.name}>
+                    {p.name}
+                  </option>
+                ))}
+              </select>
+            </section>
+          )}
+
           {/* Artifact Selection (not for JSON) */}
           {format !== 'json' && (
             <section style={{ marginBottom: '32px' }}>
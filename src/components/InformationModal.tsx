@@ -7,11 +7,13 @@ import { useLinkService } from '../hooks/useLinkService';
 import { useCustomAttributes } from '../hooks/useCustomAttributes';
 import { CustomAttributeEditor } from './CustomAttributeEditor';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+import { useWorkflowStatusOptions } from '../hooks/useWorkflowStatusOptions';
 import { useInformationForm } from '../hooks/useInformationForm';
 import { ArtifactOverviewFields } from './forms/ArtifactOverviewFields';
 import { ArtifactRelationshipsTab } from './forms/ArtifactRelationshipsTab';
 import { ArtifactDetailsSections } from './forms/ArtifactDetailsSections';
 import { FormField } from './forms/FormField';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
 
 interface InformationModalProps {
   isOpen: boolean;
@@ -98,6 +100,8 @@ export const InformationModal: React.FC<InformationModalProps> = ({
     []
   );
 
+  const statusOptions = useWorkflowStatusOptions(information, status);
+
   if (!isOpen) return null;
 
   const tabs: { id: Tab; label: string }[] = [
@@ -112,6 +116,11 @@ export const InformationModal: React.FC<InformationModalProps> = ({
       isOpen={isOpen}
       onClose={onClose}
       onBack={onBack}
+      filePath={
+        isEditMode && information
+          ? `${ARTIFACT_CONFIG.information.folder}/${information.id}.md`
+          : undefined
+      }
       title={isEditMode ? `Edit Information - ${information?.id}` : 'New Information'}
       tabs={tabs}
       activeTab={activeTab}
@@ -162,11 +171,7 @@ export const InformationModal: React.FC<InformationModalProps> = ({
               setPriority={setPriority}
               status={status}
               setStatus={setStatus}
-              statusOptions={[
-                { value: 'draft', label: 'Draft' },
-                { value: 'approved', label: 'Approved' },
-                { value: 'archived', label: 'Archived' },
-              ]}
+              statusOptions={statusOptions}
               author={author}
               setAuthor={setAuthor}
               currentUser={currentUser?.name}
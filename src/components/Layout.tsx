@@ -30,6 +30,7 @@ export interface LayoutProps {
   onNewWorkflow?: () => void;
   onNewLink?: () => void;
   onNewCustomAttribute?: () => void;
+  onManageArtifactTypes?: () => void;
   onNewDocument?: () => void;
   baselines?: ProjectBaseline[];
   rightPanel?: React.ReactNode;
@@ -38,6 +39,9 @@ export interface LayoutProps {
   onOpenAdvancedSearch?: () => void;
   onHelp?: () => void;
   onChangeFolder?: () => void;
+  isReadOnly?: boolean;
+  onToggleReadOnly?: () => void;
+  onDropFiles?: (files: File[]) => void;
 }
 
 /**
@@ -70,6 +74,7 @@ export const Layout: React.FC<LayoutProps> = ({
   onNewWorkflow,
   onNewLink,
   onNewCustomAttribute,
+  onManageArtifactTypes,
   onNewDocument,
   baselines = [],
   rightPanel,
@@ -78,7 +83,24 @@ export const Layout: React.FC<LayoutProps> = ({
   onOpenAdvancedSearch,
   onHelp,
   onChangeFolder,
+  isReadOnly,
+  onToggleReadOnly,
+  onDropFiles,
 }) => {
+  // Accept files dragged in from the OS file manager
+  const handleDragOver = (e: React.DragEvent) => {
+    if (onDropFiles && e.dataTransfer.types.includes('Files')) {
+      e.preventDefault();
+      e.dataTransfer.dropEffect = 'copy';
+    }
+  };
+
+  const handleDrop = (e: React.DragEvent) => {
+    if (!onDropFiles || e.dataTransfer.files.length === 0) return;
+    e.preventDefault();
+    onDropFiles(Array.from(e.dataTransfer.files));
+  };
+
   return (
     <div style={{ display: 'flex', height: '100vh', overflow: 'hidden' }}>
       <Sidebar
@@ -103,6 +125,7 @@ export const Layout: React.FC<LayoutProps> = ({
           onNewWorkflow={onNewWorkflow}
           onNewLink={onNewLink}
           onNewCustomAttribute={onNewCustomAttribute}
+          onManageArtifactTypes={onManageArtifactTypes}
           onNewDocument={onNewDocument}
           onImport={onImport}
           onImportExcel={onImportExcel}
@@ -117,10 +140,14 @@ export const Layout: React.FC<LayoutProps> = ({
           onOpenAdvancedSearch={onOpenAdvancedSearch}
           onHelp={onHelp}
           onChangeFolder={onChangeFolder}
+          isReadOnly={isReadOnly}
+          onToggleReadOnly={onToggleReadOnly}
         />
 
         <div style={{ flex: 1, overflow: 'hidden', display: 'flex' }}>
           <div
+            onDragOver={handleDragOver}
+            onDrop={handleDrop}
             style={{
               flex: 1,
               overflow: 'auto',
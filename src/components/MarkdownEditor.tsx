@@ -7,6 +7,8 @@ import rehypeRaw from 'rehype-raw';
 import { Eye, Edit2, ImagePlus } from 'lucide-react';
 import { assetService } from '../services/assetService';
 import { AssetImage } from './AssetImage';
+import { DiagramImage } from './DiagramImage';
+import { isDiagramKind } from '../services/diagramService';
 import { FileSystemContext } from '../app/providers/FileSystemProvider';
 
 interface MarkdownEditorProps {
@@ -403,35 +405,48 @@ export const MarkdownEditor: React.FC<MarkdownEditorProps> = ({
                 ),
                 code: ({ className, children, ...props }) => {
                   const inline = !className;
-                  return inline ? (
+                  const language = className?.replace(/^language-/, '');
+                  const block = (
                     <code
                       style={{
+                        display: 'block',
                         backgroundColor: 'var(--color-bg-secondary)',
-                        padding: '2px 6px',
-                        borderRadius: '3px',
+                        padding: '8px 12px',
+                        borderRadius: '4px',
                         fontSize: 'var(--font-size-sm)',
-                        color: 'var(--color-accent-light)',
+                        overflowX: 'auto',
+                        margin: '8px 0',
+                        color: 'var(--color-text-secondary)',
                       }}
                       {...props}
                     >
                       {children}
                     </code>
-                  ) : (
+                  );
+                  if (isDiagramKind(language)) {
+                    return (
+                      <DiagramImage
+                        kind={language}
+                        source={String(children).trim()}
+                        fallback={block}
+                      />
+                    );
+                  }
+                  return inline ? (
                     <code
                       style={{
-                        display: 'block',
                         backgroundColor: 'var(--color-bg-secondary)',
-                        padding: '8px 12px',
-                        borderRadius: '4px',
+                        padding: '2px 6px',
+                        borderRadius: '3px',
                         fontSize: 'var(--font-size-sm)',
-                        overflowX: 'auto',
-                        margin: '8px 0',
-                        color: 'var(--color-text-secondary)',
+                        color: 'var(--color-accent-light)',
                       }}
                       {...props}
                     >
                       {children}
                     </code>
+                  ) : (
+                    block
                   );
                 },
                 pre: ({ ...props }) => (
@@ -1,15 +1,115 @@
 import React, { useState, useEffect } from 'react';
-import { X, Globe, Key, Check, AlertCircle, Loader2, Trash2, RefreshCw } from 'lucide-react';
+import {
+  X,
+  Globe,
+  Key,
+  Check,
+  AlertCircle,
+  Loader2,
+  Trash2,
+  RefreshCw,
+  Bell,
+  ShieldAlert,
+} from 'lucide-react';
 import { realGitService } from '../services/realGitService';
+import { appSettingsService } from '../services/appSettingsService';
+import { isElectronEnv } from '../services/git/types';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
 
-const AUTO_SYNC_KEY = 'tracyfy-auto-sync';
-
 export interface RemoteSettingsModalProps {
   isOpen: boolean;
   onClose: () => void;
 }
 
+interface SettingToggleProps {
+  icon: React.ReactNode;
+  label: string;
+  description: string;
+  checked: boolean;
+  onChange: (checked: boolean) => void;
+}
+
+// An app setting switched on and off with a slider
+const SettingToggle: React.FC<SettingToggleProps> = ({
+  icon,
+  label,
+  description,
+  checked,
+  onChange,
+}) => (
+  <div
+    style={{
+      marginBottom: 'var(--spacing-md)',
+      padding: '12px',
+      backgroundColor: 'var(--color-bg-secondary)',
+      borderRadius: '8px',
+      display: 'flex',
+      alignItems: 'center',
+      justifyContent: 'space-between',
+      gap: '12px',
+    }}
+  >
+    <div>
+      <div style={{ fontWeight: 500, display: 'flex', alignItems: 'center', gap: '6px' }}>
+        {icon}
+        {label}
+      </div>
+      <div
+        style={{
+          fontSize: 'var(--font-size-xs)',
+          color: 'var(--color-text-muted)',
+          marginTop: '2px',
+        }}
+      >
+        {description}
+      </div>
+    </div>
+    <label
+      style={{
+        position: 'relative',
+        display: 'inline-block',
+        flexShrink: 0,
+        width: '44px',
+        height: '24px',
+        cursor: 'pointer',
+      }}
+    >
+      <input
+        type="checkbox"
+        aria-label={label}
+        checked={checked}
+        onChange={(e) => onChange(e.target.checked)}
+        style={{
+          opacity: 0,
+          width: 0,
+          height: 0,
+        }}
+      />
+      <span
+        style={{
+          position: 'absolute',
+          inset: 0,
+          backgroundColor: checked ? 'var(--color-accent)' : 'var(--color-border)',
+          borderRadius: '24px',
+          transition: 'background-color 0.2s',
+        }}
+      />
+      <span
+        style={{
+          position: 'absolute',
+          top: '2px',
+          left: checked ? '22px' : '2px',
+          width: '20px',
+          height: '20px',
+          backgroundColor: 'white',
+          borderRadius: '50%',
+          transition: 'left 0.2s',
+        }}
+      />
+    </label>
+  </div>
+);
+
 export const RemoteSettingsModal: React.FC<RemoteSettingsModalProps> = ({ isOpen, onClose }) => {
   const [remoteUrl, setRemoteUrl] = useState('');
   const [token, setToken] = useState('');
@@ -18,13 +118,9 @@ export const RemoteSettingsModal: React.FC<RemoteSettingsModalProps> = ({ isOpen
   const [error, setError] = useState<string | null>(null);
   const [success, setSuccess] = useState<string | null>(null);
   const [isTesting, setIsTesting] = useState(false);
-  const [autoSync, setAutoSync] = useState(() => {
-    try {
-      return localStorage.getItem(AUTO_SYNC_KEY) === 'true';
-    } catch {
-      return false;
-    }
-  });
+  const [autoSync, setAutoSync] = useState(false);
+  const [notifyExternalChanges, setNotifyExternalChanges] = useState(false);
+  const [blockSecretCommits, setBlockSecretCommits] = useState(true);
 
   // Close modal on Escape key
   useKeyboardShortcuts({ onClose });
@@ -33,6 +129,14 @@ export const RemoteSettingsModal: React.FC<RemoteSettingsModalProps> = ({ isOpen
   useEffect(() => {
     if (isOpen) {
       loadRemotes();
+      appSettingsService
+        .getAppSettings()
+        .then((settings) => {
+          setAutoSync(settings.autoSync);
+          setNotifyExternalChanges(settings.notifyExternalChanges);
+          setBlockSecretCommits(settings.blockSecretCommits);
+        })
+        .catch(() => setAutoSync(false));
       // Load saved token
       const savedToken = realGitService.getAuthToken();
       if (savedToken) {
@@ -41,15 +145,34 @@ export const RemoteSettingsModal: React.FC<RemoteSettingsModalProps> = ({ isOpen
     }
   }, [isOpen]);
 
-  // Persist autoSync setting
-  const handleAutoSyncChange = (enabled: boolean) => {
+  // Persist autoSync setting; the sidebar reacts to the app-settings-changed event
+  const handleAutoSyncChange = async (enabled: boolean) => {
     setAutoSync(enabled);
     try {
-      localStorage.setItem(AUTO_SYNC_KEY, String(enabled));
-      // Dispatch event so sidebar can react
-      window.dispatchEvent(new CustomEvent('auto-sync-changed', { detail: { enabled } }));
-    } catch {
-      // Ignore localStorage errors
+      await appSettingsService.updateAppSettings({ autoSync: enabled });
+    } catch (err) {
+      setAutoSync(!enabled);
+      setError(err instanceof Error ? err.message : 'Failed to save auto-sync setting');
+    }
+  };
+
+  const handleNotifyChange = async (enabled: boolean) => {
+    setNotifyExternalChanges(enabled);
+    try {
+      await appSettingsService.updateAppSettings({ notifyExternalChanges: enabled });
+    } catch (err) {
+      setNotifyExternalChanges(!enabled);
+      setError(err instanceof Error ? err.message : 'Failed to save notification setting');
+    }
+  };
+
+  const handleBlockSecretsChange = async (enabled: boolean) => {
+    setBlockSecretCommits(enabled);
+    try {
+      await appSettingsService.updateAppSettings({ blockSecretCommits: enabled });
+    } catch (err) {
+      setBlockSecretCommits(!enabled);
+      setError(err instanceof Error ? err.message : 'Failed to save secret scan setting');
     }
   };
 
@@ -345,77 +468,35 @@ export const RemoteSettingsModal: React.FC<RemoteSettingsModalProps> = ({ isOpen
 
         {/* Auto Sync Toggle */}
         {existingRemotes.length > 0 && (
-          <div
-            style={{
-              marginBottom: 'var(--spacing-md)',
-              padding: '12px',
-              backgroundColor: 'var(--color-bg-secondary)',
-              borderRadius: '8px',
-              display: 'flex',
-              alignItems: 'center',
-              justifyContent: 'space-between',
-              gap: '12px',
-            }}
-          >
-            <div>
-              <div style={{ fontWeight: 500, display: 'flex', alignItems: 'center', gap: '6px' }}>
-                <RefreshCw size={14} />
-                Auto Sync
-              </div>
-              <div
-                style={{
-                  fontSize: 'var(--font-size-xs)',
-                  color: 'var(--color-text-muted)',
-                  marginTop: '2px',
-                }}
-              >
-                Automatically sync with remote after every commit
-              </div>
-            </div>
-            <label
-              style={{
-                position: 'relative',
-                display: 'inline-block',
-                width: '44px',
-                height: '24px',
-                cursor: 'pointer',
-              }}
-            >
-              <input
-                type="checkbox"
-                checked={autoSync}
-                onChange={(e) => handleAutoSyncChange(e.target.checked)}
-                style={{
-                  opacity: 0,
-                  width: 0,
-                  height: 0,
-                }}
-              />
-              <span
-                style={{
-                  position: 'absolute',
-                  inset: 0,
-                  backgroundColor: autoSync ? 'var(--color-accent)' : 'var(--color-border)',
-                  borderRadius: '24px',
-                  transition: 'background-color 0.2s',
-                }}
-              />
-              <span
-                style={{
-                  position: 'absolute',
-                  top: '2px',
-                  left: autoSync ? '22px' : '2px',
-                  width: '20px',
-                  height: '20px',
-                  backgroundColor: 'white',
-                  borderRadius: '50%',
-                  transition: 'left 0.2s',
-                }}
-              />
-            </label>
-          </div>
+          <SettingToggle
+            icon={<RefreshCw size={14} />}
+            label="Auto Sync"
+            description="Automatically sync with remote after every commit"
+            checked={autoSync}
+            onChange={handleAutoSyncChange}
+          />
         )}
 
+        {/* Notification Toggle */}
+        {isElectronEnv() && (
+          <SettingToggle
+            icon={<Bell size={14} />}
+            label="Desktop Notifications"
+            description="Notify about outside changes on disk or upstream while in the background"
+            checked={notifyExternalChanges}
+            onChange={handleNotifyChange}
+          />
+        )}
+
+        {/* Secret Scan Toggle */}
+        <SettingToggle
+          icon={<ShieldAlert size={14} />}
+          label="Block Commits With Secrets"
+          description="Refuse commits with text that looks like credentials instead of warning"
+          checked={blockSecretCommits}
+          onChange={handleBlockSecretsChange}
+        />
+
         {/* Error/Success messages */}
         {error && (
           <div
@@ -7,10 +7,12 @@ import { useLinkService } from '../hooks/useLinkService';
 import { useCustomAttributes } from '../hooks/useCustomAttributes';
 import { CustomAttributeEditor } from './CustomAttributeEditor';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+import { useWorkflowStatusOptions } from '../hooks/useWorkflowStatusOptions';
 import { useRequirementForm } from '../hooks/useRequirementForm';
 import { ArtifactOverviewFields } from './forms/ArtifactOverviewFields';
 import { ArtifactRelationshipsTab } from './forms/ArtifactRelationshipsTab';
 import { ArtifactDetailsSections } from './forms/ArtifactDetailsSections';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
 
 interface RequirementModalProps {
   isOpen: boolean;
@@ -102,15 +104,7 @@ export const RequirementModal: React.FC<RequirementModalProps> = ({
     []
   );
 
-  const statusOptions = useMemo(
-    () => [
-      { value: 'draft', label: 'Draft' },
-      { value: 'approved', label: 'Approved' },
-      { value: 'implemented', label: 'Implemented' },
-      { value: 'verified', label: 'Verified' },
-    ],
-    []
-  );
+  const statusOptions = useWorkflowStatusOptions(requirement, status);
 
   const detailFields = useMemo(
     () => [
@@ -150,6 +144,11 @@ export const RequirementModal: React.FC<RequirementModalProps> = ({
       isOpen={isOpen}
       onClose={onClose}
       onBack={onBack}
+      filePath={
+        isEditMode && requirement
+          ? `${ARTIFACT_CONFIG.requirements.folder}/${requirement.id}.md`
+          : undefined
+      }
       title={modalTitle}
       tabs={allTabs}
       activeTab={activeTab}
@@ -7,11 +7,13 @@ import { useLinkService } from '../hooks/useLinkService';
 import { useCustomAttributes } from '../hooks/useCustomAttributes';
 import { CustomAttributeEditor } from './CustomAttributeEditor';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+import { useWorkflowStatusOptions } from '../hooks/useWorkflowStatusOptions';
 import { useRiskForm } from '../hooks/useRiskForm';
 import { ArtifactOverviewFields } from './forms/ArtifactOverviewFields';
 import { ArtifactRelationshipsTab } from './forms/ArtifactRelationshipsTab';
 import { ArtifactDetailsSections } from './forms/ArtifactDetailsSections';
 import { FormField } from './forms/FormField';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
 
 interface RiskModalProps {
   isOpen: boolean;
@@ -99,16 +101,7 @@ export const RiskModal: React.FC<RiskModalProps> = ({
     []
   );
 
-  const statusOptions = useMemo(
-    () => [
-      { value: 'identified', label: 'Identified' },
-      { value: 'analyzing', label: 'Analyzing' },
-      { value: 'mitigating', label: 'Mitigating' },
-      { value: 'resolved', label: 'Resolved' },
-      { value: 'accepted', label: 'Accepted' },
-    ],
-    []
-  );
+  const statusOptions = useWorkflowStatusOptions(risk, status);
 
   const probabilityOptions = useMemo(
     () => [
@@ -143,6 +136,7 @@ export const RiskModal: React.FC<RiskModalProps> = ({
       isOpen={isOpen}
       onClose={onClose}
       onBack={onBack}
+      filePath={isEditMode && risk ? `${ARTIFACT_CONFIG.risks.folder}/${risk.id}.md` : undefined}
       title={
         <div style={{ display: 'flex', alignItems: 'center', gap: 'var(--spacing-md)' }}>
           <span>{isEditMode ? `Edit Risk - ${risk?.id}` : 'New Risk'}</span>
@@ -5,6 +5,7 @@ import { formatDateTime } from '../utils/dateUtils';
 import { RevisionHistoryTab } from './RevisionHistoryTab';
 import { useUI } from '../app/providers';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+import { useWorkflowStatusOptions } from '../hooks/useWorkflowStatusOptions';
 import { useLinkService } from '../hooks/useLinkService';
 import { useCustomAttributes } from '../hooks/useCustomAttributes';
 import { CustomAttributeEditor } from './CustomAttributeEditor';
@@ -13,6 +14,7 @@ import { ArtifactOverviewFields } from './forms/ArtifactOverviewFields';
 import { ArtifactRelationshipsTab } from './forms/ArtifactRelationshipsTab';
 import { ArtifactDetailsSections } from './forms/ArtifactDetailsSections';
 import { FormField } from './forms/FormField';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
 
 interface TestCaseModalProps {
   isOpen: boolean;
@@ -100,16 +102,7 @@ export const TestCaseModal: React.FC<TestCaseModalProps> = ({
     []
   );
 
-  const statusOptions = useMemo(
-    () => [
-      { value: 'draft', label: 'Draft' },
-      { value: 'approved', label: 'Approved' },
-      { value: 'passed', label: 'Passed' },
-      { value: 'failed', label: 'Failed' },
-      { value: 'blocked', label: 'Blocked' },
-    ],
-    []
-  );
+  const statusOptions = useWorkflowStatusOptions(testCase, status);
 
   if (!isOpen) return null;
 
@@ -125,6 +118,11 @@ export const TestCaseModal: React.FC<TestCaseModalProps> = ({
       isOpen={isOpen}
       onClose={onClose}
       onBack={onBack}
+      filePath={
+        isEditMode && testCase
+          ? `${ARTIFACT_CONFIG.testcases.folder}/${testCase.id}.md`
+          : undefined
+      }
       title={isEditMode ? `Edit Test Case - ${testCase?.id}` : 'New Test Case'}
       tabs={allTabs}
       activeTab={activeTab}
@@ -7,11 +7,13 @@ import { useLinkService } from '../hooks/useLinkService';
 import { useCustomAttributes } from '../hooks/useCustomAttributes';
 import { CustomAttributeEditor } from './CustomAttributeEditor';
 import { useKeyboardShortcuts } from '../hooks/useKeyboardShortcuts';
+import { useWorkflowStatusOptions } from '../hooks/useWorkflowStatusOptions';
 import { useUseCaseForm } from '../hooks/useUseCaseForm';
 import { ArtifactOverviewFields } from './forms/ArtifactOverviewFields';
 import { ArtifactRelationshipsTab } from './forms/ArtifactRelationshipsTab';
 import { ArtifactDetailsSections } from './forms/ArtifactDetailsSections';
 import { FormField } from './forms/FormField';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
 
 interface UseCaseModalProps {
   isOpen: boolean;
@@ -102,15 +104,7 @@ export const UseCaseModal: React.FC<UseCaseModalProps> = ({
     []
   );
 
-  const statusOptions = useMemo(
-    () => [
-      { value: 'draft', label: 'Draft' },
-      { value: 'approved', label: 'Approved' },
-      { value: 'implemented', label: 'Implemented' },
-      { value: 'verified', label: 'Verified' },
-    ],
-    []
-  );
+  const statusOptions = useWorkflowStatusOptions(useCase, status);
 
   if (!isOpen) return null;
 
@@ -128,6 +122,11 @@ export const UseCaseModal: React.FC<UseCaseModalProps> = ({
       isOpen={isOpen}
       onClose={onClose}
       onBack={onBack}
+      filePath={
+        isEditMode && useCase
+          ? `${ARTIFACT_CONFIG.usecases.folder}/${useCase.id}.md`
+          : undefined
+      }
       title={isEditMode ? `Edit Use Case - ${useCase?.id}` : 'New Use Case'}
       tabs={tabs}
       activeTab={activeTab}
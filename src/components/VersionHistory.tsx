@@ -18,7 +18,7 @@ interface VersionHistoryProps {
   baselines: ProjectBaseline[];
   projectName: string | null;
   onClose: () => void;
-  onCreateBaseline: (name: string, message: string) => void;
+  onCreateBaseline: (name: string, message: string, exportSnapshot: boolean) => void;
   onSelectArtifact?: (artifactId: string, artifactType: string) => void;
 }
 
@@ -40,6 +40,8 @@ export const VersionHistory: React.FC<VersionHistoryProps> = ({
     setBaselineName,
     baselineMessage,
     setBaselineMessage,
+    exportSnapshot,
+    setExportSnapshot,
     handleStartCreating,
     handleCreateBaselineSubmit,
     projectBaselines,
@@ -175,9 +177,11 @@ export const VersionHistory: React.FC<VersionHistoryProps> = ({
               isCreating={isCreatingBaseline}
               baselineName={baselineName}
               baselineMessage={baselineMessage}
+              exportSnapshot={exportSnapshot}
               onStartCreating={handleStartCreating}
               onNameChange={setBaselineName}
               onMessageChange={setBaselineMessage}
+              onExportSnapshotChange={setExportSnapshot}
               onSubmit={handleCreateBaselineSubmit}
               onCancel={() => {
                 setIsCreatingBaseline(false);
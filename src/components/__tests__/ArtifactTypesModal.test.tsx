@@ -0,0 +1,57 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { ArtifactTypesModal } from '../ArtifactTypesModal';
+import { artifactTypeService } from '../../services/artifactTypeService';
+
+vi.mock('../../services/artifactTypeService', () => ({
+  artifactTypeService: {
+    listCustomTypes: vi.fn(),
+    createType: vi.fn(),
+    renameTypeFolder: vi.fn(),
+    deleteType: vi.fn(),
+  },
+}));
+
+const SPEC = { key: 'specs', label: 'Specification', idPrefix: 'SPEC', folder: 'specs' };
+
+describe('ArtifactTypesModal', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(artifactTypeService.listCustomTypes).mockResolvedValue([SPEC]);
+  });
+
+  it('should list the custom types', async () => {
+    render(<ArtifactTypesModal isOpen={true} onClose={vi.fn()} />);
+
+    expect(await screen.findByText('Specification')).toBeInTheDocument();
+  });
+
+  it('should create a type from the form', async () => {
+    render(<ArtifactTypesModal isOpen={true} onClose={vi.fn()} />);
+
+    fireEvent.change(screen.getByLabelText('Label'), { target: { value: 'Hazard' } });
+    fireEvent.change(screen.getByLabelText('ID prefix'), { target: { value: 'haz' } });
+    fireEvent.change(screen.getByLabelText('Folder'), { target: { value: 'hazards' } });
+    fireEvent.click(screen.getByText('Add Type'));
+
+    await waitFor(() =>
+      expect(artifactTypeService.createType).toHaveBeenCalledWith({
+        key: 'hazards',
+        label: 'Hazard',
+        idPrefix: 'HAZ',
+        folder: 'hazards',
+      })
+    );
+  });
+
+  it('should show errors from the service', async () => {
+    vi.mocked(artifactTypeService.deleteType).mockRejectedValue(new Error('Permission denied'));
+    vi.spyOn(window, 'confirm').mockReturnValue(true);
+    render(<ArtifactTypesModal isOpen={true} onClose={vi.fn()} />);
+
+    fireEvent.click(await screen.findByTitle('Delete Specification'));
+
+    expect(await screen.findByRole('alert')).toHaveTextContent('Permission denied');
+    expect(artifactTypeService.deleteType).toHaveBeenCalledWith('specs', true);
+  });
+});
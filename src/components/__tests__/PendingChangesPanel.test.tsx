@@ -21,6 +21,7 @@ vi.mock('../../app/providers/BackgroundTasksProvider', () => ({
     tasks: [],
     isWorking: false,
     startTask: vi.fn(() => 'task-1'),
+    updateTask: vi.fn(),
     endTask: vi.fn(),
   }),
 }));
@@ -19,6 +19,7 @@ describe('StatusBar', () => {
       tasks: [],
       isWorking: false,
       startTask: vi.fn(),
+      updateTask: vi.fn(),
       endTask: vi.fn(),
     });
 
@@ -32,6 +33,7 @@ describe('StatusBar', () => {
       tasks: [{ id: '1', message: 'Committing REQ-001...', startTime: Date.now() }],
       isWorking: true,
       startTask: vi.fn(),
+      updateTask: vi.fn(),
       endTask: vi.fn(),
     });
 
@@ -49,6 +51,7 @@ describe('StatusBar', () => {
       ],
       isWorking: true,
       startTask: vi.fn(),
+      updateTask: vi.fn(),
       endTask: vi.fn(),
     });
 
@@ -70,6 +73,7 @@ describe('StatusBar', () => {
       tasks,
       isWorking: true,
       startTask: vi.fn(),
+      updateTask: vi.fn(),
       endTask: vi.fn(),
     });
 
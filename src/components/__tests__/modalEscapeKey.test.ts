@@ -38,6 +38,7 @@ const MODAL_FILES = {
   ExportModal: () => import('../ExportModal.tsx?raw'),
   AdvancedSearchModal: () => import('../AdvancedSearchModal.tsx?raw'),
   CustomAttributeDefinitionModal: () => import('../CustomAttributeDefinitionModal.tsx?raw'),
+  ArtifactTypesModal: () => import('../ArtifactTypesModal.tsx?raw'),
 };
 
 describe('Modal Escape Key Pattern: All modals must close on Escape', () => {
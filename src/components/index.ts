@@ -38,6 +38,7 @@ export { RiskModal } from './RiskModal';
 export { UserSettingsModal } from './UserSettingsModal';
 export { AdvancedSearchModal } from './AdvancedSearchModal';
 export { CustomAttributeDefinitionModal } from './CustomAttributeDefinitionModal';
+export { ArtifactTypesModal } from './ArtifactTypesModal';
 export { WorkflowModal } from './WorkflowModal';
 export { DocumentList } from './DocumentList';
 export { DocumentModal } from './DocumentModal';
@@ -1,7 +1,9 @@
 import React, { useState, useEffect, useCallback } from 'react';
 import { Cloud, CloudUpload, CloudDownload, AlertCircle, RefreshCw } from 'lucide-react';
 import { realGitService } from '../../services/realGitService';
-import type { SyncStatus, CommitInfo } from '../../types';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../../services/appSettingsService';
+import { notifyExternalChanges } from '../../services/changeNotificationService';
+import type { AppSettings, SyncStatus, CommitInfo } from '../../types';
 import { headerButtonStyle } from './layoutStyles';
 import { debug } from '../../utils/debug';
 
@@ -14,7 +16,10 @@ export const GitSyncStatus: React.FC = () => {
   const [isSyncing, setIsSyncing] = useState(false);
   const [hasRemote, setHasRemote] = useState(false);
   const [showDetails, setShowDetails] = useState(false);
+  const [pollInterval, setPollInterval] = useState(DEFAULT_APP_SETTINGS.pollIntervals.remoteStatus);
   const containerRef = React.useRef<HTMLDivElement>(null);
+  // Upstream commits already seen; null until the first check
+  const seenCommitsRef = React.useRef<Set<string> | null>(null);
 
   const checkStatus = useCallback(async () => {
     if (!realGitService.isInitialized()) return;
@@ -28,6 +33,12 @@ export const GitSyncStatus: React.FC = () => {
 
     const newStatus = await realGitService.getSyncStatus();
     setStatus(newStatus);
+
+    const upstream = (newStatus.behindCommits ?? []).map((commit) => commit.hash);
+    const seen = seenCommitsRef.current;
+    const newCommits = seen ? upstream.filter((hash) => !seen.has(hash)).length : 0;
+    seenCommitsRef.current = new Set(upstream);
+    if (newCommits > 0) void notifyExternalChanges({ newCommits });
   }, []);
 
   useEffect(() => {
@@ -40,13 +51,25 @@ export const GitSyncStatus: React.FC = () => {
     };
     window.addEventListener('git-check', handleManualCheck);
 
-    // Poll every 2 minutes
-    const interval = setInterval(checkStatus, 120000);
+    // Poll at the interval from app settings (2 minutes by default)
+    const interval = setInterval(checkStatus, pollInterval);
     return () => {
       clearInterval(interval);
       window.removeEventListener('git-check', handleManualCheck);
     };
-  }, [checkStatus]);
+  }, [checkStatus, pollInterval]);
+
+  useEffect(() => {
+    appSettingsService
+      .getAppSettings()
+      .then((settings) => setPollInterval(settings.pollIntervals.remoteStatus))
+      .catch((err) => debug.warn('[GitSyncStatus] Failed to load app settings:', err));
+    const handleSettingsChanged = (e: Event) => {
+      setPollInterval((e as CustomEvent<AppSettings>).detail.pollIntervals.remoteStatus);
+    };
+    window.addEventListener('app-settings-changed', handleSettingsChanged);
+    return () => window.removeEventListener('app-settings-changed', handleSettingsChanged);
+  }, []);
 
   // Handle outside click to close details
   useEffect(() => {
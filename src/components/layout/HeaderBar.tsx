@@ -21,6 +21,9 @@ import {
   User,
   HelpCircle,
   SlidersHorizontal,
+  Lock,
+  LockOpen,
+  Shapes,
 } from 'lucide-react';
 import type { ProjectBaseline } from '../../types';
 import {
@@ -56,6 +59,7 @@ interface HeaderBarProps {
   onNewWorkflow?: () => void;
   onNewLink?: () => void;
   onNewCustomAttribute?: () => void;
+  onManageArtifactTypes?: () => void;
   onNewDocument?: () => void;
   onImport?: () => void;
   onImportExcel?: () => void;
@@ -70,6 +74,8 @@ interface HeaderBarProps {
   onOpenAdvancedSearch?: () => void;
   onHelp?: () => void;
   onChangeFolder?: () => void;
+  isReadOnly?: boolean;
+  onToggleReadOnly?: () => void;
 }
 
 export const HeaderBar: React.FC<HeaderBarProps> = ({
@@ -83,6 +89,7 @@ export const HeaderBar: React.FC<HeaderBarProps> = ({
   onNewWorkflow,
   onNewLink,
   onNewCustomAttribute,
+  onManageArtifactTypes,
   onImport,
   onImportExcel,
   onOpenGlobalLibrary,
@@ -97,6 +104,8 @@ export const HeaderBar: React.FC<HeaderBarProps> = ({
   onHelp,
   onNewDocument,
   onChangeFolder,
+  isReadOnly = false,
+  onToggleReadOnly,
 }) => {
   const {
     isExportMenuOpen,
@@ -335,10 +344,38 @@ export const HeaderBar: React.FC<HeaderBarProps> = ({
                   showBorder
                 />
               )}
+              {onManageArtifactTypes && (
+                <DropdownMenuItem
+                  onClick={() => {
+                    onManageArtifactTypes();
+                    setIsCreateMenuOpen(false);
+                  }}
+                  icon={Shapes}
+                  label="Artifact Types"
+                  showBorder
+                />
+              )}
             </div>
           )}
         </div>
 
+        {/* Read-only (Reviewer Mode) Toggle */}
+        {onToggleReadOnly && (
+          <button
+            onClick={onToggleReadOnly}
+            style={headerButtonStyle}
+            aria-pressed={isReadOnly}
+            title={
+              isReadOnly
+                ? 'Read-only: changes to the project are refused. Click to allow editing.'
+                : 'Open the project read-only for review'
+            }
+          >
+            {isReadOnly ? <Lock size={18} /> : <LockOpen size={18} />}
+            {isReadOnly ? 'Read-only' : 'Editable'}
+          </button>
+        )}
+
         {/* Change Folder Button */}
         {onChangeFolder && (
           <button
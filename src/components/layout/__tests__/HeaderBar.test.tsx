@@ -63,6 +63,22 @@ describe('HeaderBar', () => {
 
       expect(screen.getByText('User')).toBeInTheDocument();
     });
+
+    it('should show the read-only toggle only when onToggleReadOnly is provided', () => {
+      const onToggleReadOnly = vi.fn();
+      const { rerender } = render(<HeaderBar {...defaultProps} />);
+      expect(screen.queryByText('Editable')).not.toBeInTheDocument();
+
+      rerender(<HeaderBar {...defaultProps} onToggleReadOnly={onToggleReadOnly} />);
+      fireEvent.click(screen.getByText('Editable'));
+      expect(onToggleReadOnly).toHaveBeenCalled();
+
+      rerender(<HeaderBar {...defaultProps} onToggleReadOnly={onToggleReadOnly} isReadOnly />);
+      expect(screen.getByText('Read-only').closest('button')).toHaveAttribute(
+        'aria-pressed',
+        'true'
+      );
+    });
   });
 
   describe('search functionality', () => {
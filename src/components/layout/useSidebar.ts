@@ -4,18 +4,18 @@
  * Manages sidebar state including:
  * - Resizable width with localStorage persistence
  * - Collapsible sections with localStorage persistence
- * - Remote sync state (push/pull)
+ * - Remote sync state (push/pull), with auto-sync and polling from app settings
  */
 
 import { useState, useEffect, useCallback, useRef } from 'react';
 import { debug } from '../../utils/debug';
 import { useFileSystem } from '../../app/providers';
 import { realGitService } from '../../services/realGitService';
-import type { SyncStatus } from '../../types';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../../services/appSettingsService';
+import type { AppSettings, SyncStatus } from '../../types';
 
 const SIDEBAR_WIDTH_KEY = 'sidebar-width';
 const COLLAPSED_SECTIONS_KEY = 'sidebar-collapsed-sections';
-const AUTO_SYNC_KEY = 'tracyfy-auto-sync';
 const DEFAULT_WIDTH = 260;
 const MIN_WIDTH = 180;
 const MAX_WIDTH = 400;
@@ -44,14 +44,9 @@ export function useSidebar() {
     }
   });
 
-  // Auto sync state with localStorage persistence
-  const [autoSync, setAutoSync] = useState(() => {
-    try {
-      return localStorage.getItem(AUTO_SYNC_KEY) === 'true';
-    } catch {
-      return false;
-    }
-  });
+  // Auto sync and polling from app settings
+  const [autoSync, setAutoSync] = useState(DEFAULT_APP_SETTINGS.autoSync);
+  const [pollInterval, setPollInterval] = useState(DEFAULT_APP_SETTINGS.pollIntervals.syncStatus);
 
   // Remote sync state
   const [hasRemote, setHasRemote] = useState(false);
@@ -99,14 +94,21 @@ export function useSidebar() {
     return () => window.removeEventListener('git-check', handleGitCheck);
   }, [checkSyncStatus]);
 
-  // Listen for auto-sync-changed events from settings modal
+  // Load app settings and follow changes from the settings modal
   useEffect(() => {
-    const handleAutoSyncChanged = (e: Event) => {
-      const customEvent = e as CustomEvent<{ enabled: boolean }>;
-      setAutoSync(customEvent.detail.enabled);
+    const applySettings = (settings: AppSettings) => {
+      setAutoSync(settings.autoSync);
+      setPollInterval(settings.pollIntervals.syncStatus);
+    };
+    appSettingsService
+      .getAppSettings()
+      .then(applySettings)
+      .catch((err) => debug.warn('[useSidebar] Failed to load app settings:', err));
+    const handleSettingsChanged = (e: Event) => {
+      applySettings((e as CustomEvent<AppSettings>).detail);
     };
-    window.addEventListener('auto-sync-changed', handleAutoSyncChanged);
-    return () => window.removeEventListener('auto-sync-changed', handleAutoSyncChanged);
+    window.addEventListener('app-settings-changed', handleSettingsChanged);
+    return () => window.removeEventListener('app-settings-changed', handleSettingsChanged);
   }, []);
 
   // Listen for git-status-changed events (triggered after commits)
@@ -131,16 +133,16 @@ export function useSidebar() {
     return () => window.removeEventListener('git-status-changed', handleStatusChanged);
   }, [autoSync, hasRemote, push, checkSyncStatus]);
 
-  // Poll sync status every 30 seconds to detect new commits (only when not auto-syncing)
+  // Poll sync status to detect new commits (only when not auto-syncing)
   useEffect(() => {
     if (!hasRemote || autoSync) return;
 
     const interval = setInterval(() => {
       checkSyncStatus();
-    }, 30000); // 30 seconds
+    }, pollInterval);
 
     return () => clearInterval(interval);
-  }, [hasRemote, autoSync, checkSyncStatus]);
+  }, [hasRemote, autoSync, pollInterval, checkSyncStatus]);
 
   // Save collapsed sections to localStorage
   useEffect(() => {
@@ -7,6 +7,7 @@ import {
   ExportModal,
   AdvancedSearchModal,
   CustomAttributeDefinitionModal,
+  ArtifactTypesModal,
   WorkflowModal,
 } from '../';
 import {
@@ -24,10 +25,12 @@ import { diskCustomAttributeService } from '../../services/diskCustomAttributeSe
 import { exportProjectToPDF } from '../../utils/pdfExportUtils';
 import { exportProjectToExcel } from '../../utils/excelExportUtils';
 import { exportProjectToJSON } from '../../utils/jsonExportUtils';
+import { getRedactionProfiles, redactExportState } from '../../services/redactionService';
 import type {
   Link,
   LinkType,
   ExportOptions,
+  RedactionProfile,
   Requirement,
   UseCase,
   TestCase,
@@ -48,9 +51,10 @@ export const ManagementModals: React.FC = () => {
   const { risks } = useRisks();
   const { documents } = useDocuments();
   const { currentUser } = useUser();
-  const { startTask, endTask } = useBackgroundTasks();
+  const { startTask, updateTask, endTask } = useBackgroundTasks();
 
   const [projectLinks, setProjectLinks] = useState<Link[]>([]);
+  const [redactionProfiles, setRedactionProfiles] = useState<RedactionProfile[]>([]);
 
   useEffect(() => {
     if (currentProjectId) {
@@ -58,6 +62,17 @@ export const ManagementModals: React.FC = () => {
     }
   }, [currentProjectId]);
 
+  const isExportOpen = ui.activeModal.type === 'export';
+  useEffect(() => {
+    if (!isExportOpen) return;
+    getRedactionProfiles()
+      .then(setRedactionProfiles)
+      .catch((error) => {
+        console.error('Failed to load redaction profiles:', error);
+        setRedactionProfiles([]);
+      });
+  }, [isExportOpen]);
+
   const handleExport = useCallback(
     async (options: ExportOptions) => {
       if (!currentProject) return;
@@ -67,14 +82,17 @@ export const ManagementModals: React.FC = () => {
       const tcIds = options.includeTestCases ? currentProject.testCaseIds : [];
       const infoIds = options.includeInformation ? currentProject.informationIds : [];
 
-      const globalState = {
-        requirements: globalRequirements,
-        useCases: globalUseCases,
-        testCases: globalTestCases,
-        information: globalInformation,
-        risks: risks,
-        documents: documents,
-      };
+      const globalState = redactExportState(
+        {
+          requirements: globalRequirements,
+          useCases: globalUseCases,
+          testCases: globalTestCases,
+          information: globalInformation,
+          risks: risks,
+          documents: documents,
+        },
+        options.redactionProfile
+      );
 
       try {
         switch (options.format) {
@@ -92,12 +110,14 @@ export const ManagementModals: React.FC = () => {
                 options.baseline,
                 currentUser?.name,
                 options.includeDocuments,
-                documents.filter(
+                globalState.documents.filter(
                   (d) =>
                     !d.isDeleted &&
                     (d.projectId === currentProject.id ||
                       currentProject.documentIds?.includes(d.id))
-                )
+                ),
+                ({ completed, total }) =>
+                  updateTask(taskId, `Exporting PDF... (${completed + 1}/${total})`)
               );
             } finally {
               endTask(taskId);
@@ -155,6 +175,7 @@ export const ManagementModals: React.FC = () => {
       baselines,
       currentUser,
       startTask,
+      updateTask,
       endTask,
       documents,
     ]
@@ -221,9 +242,9 @@ export const ManagementModals: React.FC = () => {
   );
 
   const handleCreateBaselineWrapper = useCallback(
-    async (name: string, message: string) => {
+    async (name: string, message: string, exportSnapshot: boolean) => {
       const version = name.includes(']') ? name.split(']').pop()?.trim() || name : name;
-      await createBaseline(name, message, version);
+      await createBaseline(name, message, version, { exportSnapshot });
     },
     [createBaseline]
   );
@@ -268,10 +289,11 @@ export const ManagementModals: React.FC = () => {
       <UserSettingsModal isOpen={ui.activeModal.type === 'user-settings'} onClose={ui.closeModal} />
 
       <ExportModal
-        isOpen={ui.activeModal.type === 'export'}
+        isOpen={isExportOpen}
         onClose={ui.closeModal}
         baselines={baselines}
         onExport={handleExport}
+        redactionProfiles={redactionProfiles}
         artifactCounts={{
           requirements: globalRequirements.filter(
             (r: Requirement) => !r.isDeleted && currentProject?.requirementIds.includes(r.id)
@@ -368,6 +390,11 @@ export const ManagementModals: React.FC = () => {
         }}
       />
 
+      <ArtifactTypesModal
+        isOpen={ui.activeModal.type === 'artifact-types'}
+        onClose={ui.closeModal}
+      />
+
       <WorkflowModal isOpen={ui.activeModal.type === 'workflow'} onClose={ui.closeModal} />
     </>
   );
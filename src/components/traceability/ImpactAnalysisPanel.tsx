@@ -89,6 +89,7 @@ export const ImpactAnalysisPanel: React.FC<ImpactAnalysisPanelProps> = ({
                 return (
                   <div
                     key={node.artifactId}
+                    title={impactAnalysisService.getImpactPath(chain, node.artifactId).join(' → ')}
                     style={{
                       display: 'flex',
                       alignItems: 'center',
@@ -5,9 +5,13 @@
  * Extracted from TraceabilityDashboard for better maintainability.
  */
 
-import React from 'react';
+import React, { useState } from 'react';
+import { Copy } from 'lucide-react';
 import type { ArtifactType } from './index';
 import { TYPE_COLORS } from './index';
+import type { LinkCategory } from '../../utils/linkTypes';
+import { getLinkCategory } from '../../utils/linkTypes';
+import { clipboardService } from '../../services/clipboardService';
 
 export interface MatrixArtifact {
   id: string;
@@ -47,6 +51,21 @@ const LINK_SYMBOLS: Record<string, { symbol: string; color: string }> = {
   referenced_by: { symbol: '←', color: '#6366f1' },
 };
 
+// Fallback symbols for link types without a dedicated symbol, by semantic category
+const CATEGORY_SYMBOLS: Record<LinkCategory, { symbol: string; color: string }> = {
+  hierarchy: { symbol: '↕', color: '#3b82f6' },
+  derivation: { symbol: '⇢', color: '#0ea5e9' },
+  dependency: { symbol: '◆', color: '#f59e0b' },
+  refinement: { symbol: '≫', color: '#a855f7' },
+  satisfaction: { symbol: '✓', color: '#22c55e' },
+  verification: { symbol: '✔', color: '#10b981' },
+  constraint: { symbol: '⊏', color: '#ef4444' },
+  precondition: { symbol: '⊢', color: '#f97316' },
+  conflict: { symbol: '✕', color: '#dc2626' },
+  duplication: { symbol: '≡', color: '#64748b' },
+  association: { symbol: '↔', color: '#8b5cf6' },
+};
+
 const TYPE_ORDER: Record<string, number> = {
   useCase: 0,
   requirement: 1,
@@ -60,6 +79,7 @@ export const MatrixView: React.FC<MatrixViewProps> = ({
   selectedTypes,
   toggleType,
 }) => {
+  const [copied, setCopied] = useState(false);
   const unsortedArtifacts =
     selectedTypes.size === 4 ? allArtifacts : allArtifacts.filter((a) => selectedTypes.has(a.type));
 
@@ -93,13 +113,33 @@ export const MatrixView: React.FC<MatrixViewProps> = ({
     const isReverse = type.startsWith('←');
     const actualType = isReverse ? type.substring(1) : type;
 
-    const result = LINK_SYMBOLS[actualType] || {
-      symbol: '•',
-      color: 'var(--color-text-secondary)',
-    };
+    const category = getLinkCategory(actualType);
+    const fallback = { symbol: '•', color: 'var(--color-text-secondary)' };
+    const result = LINK_SYMBOLS[actualType] || (category ? CATEGORY_SYMBOLS[category] : fallback);
     return { ...result, isReverse };
   };
 
+  // Copy the matrix as shown, with link type names in place of symbols
+  const handleCopy = async () => {
+    const table = [
+      ['From / To', ...matrixArtifacts.map((a) => a.id)],
+      ...matrixArtifacts.map((row) => [
+        `${row.id} ${row.title}`,
+        ...matrixArtifacts.map((col) => {
+          const type = row.id === col.id ? undefined : linkMap.get(row.id)?.get(col.id);
+          return type ? type.replace(/^←(.*)$/, '$1 (reverse)') : '';
+        }),
+      ]),
+    ];
+    try {
+      await clipboardService.copyTable(table);
+      setCopied(true);
+      setTimeout(() => setCopied(false), 2000);
+    } catch (err) {
+      alert(`Could not copy the matrix: ${err instanceof Error ? err.message : String(err)}`);
+    }
+  };
+
   return (
     <div
       style={{
@@ -162,6 +202,28 @@ export const MatrixView: React.FC<MatrixViewProps> = ({
               {label}
             </button>
           ))}
+          <button
+            type="button"
+            onClick={handleCopy}
+            disabled={matrixArtifacts.length === 0}
+            title="Copy the matrix for Excel, Word or Confluence"
+            style={{
+              display: 'flex',
+              alignItems: 'center',
+              gap: '4px',
+              padding: '4px 8px',
+              borderRadius: '4px',
+              border: '2px solid var(--color-border)',
+              backgroundColor: 'transparent',
+              color: 'var(--color-text-secondary)',
+              cursor: matrixArtifacts.length === 0 ? 'default' : 'pointer',
+              fontSize: 'var(--font-size-xs)',
+              fontWeight: 600,
+            }}
+          >
+            <Copy size={12} />
+            {copied ? 'Copied' : 'Copy'}
+          </button>
         </div>
       </div>
 
@@ -11,9 +11,11 @@ interface CreateBaselineFormProps {
   isCreating: boolean;
   baselineName: string;
   baselineMessage: string;
+  exportSnapshot: boolean;
   onStartCreating: () => void;
   onNameChange: (name: string) => void;
   onMessageChange: (message: string) => void;
+  onExportSnapshotChange: (exportSnapshot: boolean) => void;
   onSubmit: (e: React.FormEvent) => void;
   onCancel: () => void;
 }
@@ -22,9 +24,11 @@ export const CreateBaselineForm: React.FC<CreateBaselineFormProps> = ({
   isCreating,
   baselineName,
   baselineMessage,
+  exportSnapshot,
   onStartCreating,
   onNameChange,
   onMessageChange,
+  onExportSnapshotChange,
   onSubmit,
   onCancel,
 }) => {
@@ -85,6 +89,24 @@ export const CreateBaselineForm: React.FC<CreateBaselineFormProps> = ({
           width: '200px',
         }}
       />
+      <label
+        title="Save a read-only HTML copy of the baseline outside the repository"
+        style={{
+          display: 'flex',
+          alignItems: 'center',
+          gap: '4px',
+          color: 'var(--color-text-muted)',
+          fontSize: 'var(--font-size-sm)',
+          whiteSpace: 'nowrap',
+        }}
+      >
+        <input
+          type="checkbox"
+          checked={exportSnapshot}
+          onChange={(e) => onExportSnapshotChange(e.target.checked)}
+        />
+        Export snapshot
+      </label>
       <button
         type="submit"
         disabled={!baselineName.trim()}
@@ -72,6 +72,18 @@ export const ARTIFACT_CONFIG: Record<string, ArtifactTypeConfig> = {
     label: 'Document',
     type: 'document',
   },
+  changeRequests: {
+    idPrefix: 'CR',
+    folder: 'change-requests',
+    label: 'Change Request',
+    type: 'changeRequest',
+  },
+  glossary: {
+    idPrefix: 'TERM',
+    folder: 'glossary',
+    label: 'Glossary Term',
+    type: 'glossaryTerm',
+  },
 };
 
 // Map of folder names to internal types
@@ -92,10 +104,64 @@ export const PREFIX_TO_TYPE: Record<string, string> = Object.entries(ARTIFACT_CO
   {} as Record<string, string>
 );
 
+// Keys of the project-defined types added by registerCustomArtifactTypes
+const customTypeKeys = new Set<string>();
+
+/**
+ * Whether a key names one of the types built into the app
+ */
+export function isBuiltInArtifactType(key: string): boolean {
+  return key in ARTIFACT_CONFIG && !customTypeKeys.has(key);
+}
+
+/**
+ * Add a project's own artifact types (see artifactTypeService) to the lookups
+ * above, replacing those registered before. Types clashing with a built-in
+ * key, folder or ID prefix are skipped.
+ */
+export function registerCustomArtifactTypes(
+  types: { key: string; label: string; idPrefix: string; folder: string }[]
+): void {
+  for (const key of customTypeKeys) {
+    delete FOLDER_TO_TYPE[ARTIFACT_CONFIG[key].folder];
+    delete PREFIX_TO_TYPE[ARTIFACT_CONFIG[key].idPrefix];
+    delete ARTIFACT_CONFIG[key];
+  }
+  customTypeKeys.clear();
+
+  for (const { key, label, idPrefix, folder } of types) {
+    if (key in ARTIFACT_CONFIG || folder in FOLDER_TO_TYPE || idPrefix in PREFIX_TO_TYPE) {
+      continue;
+    }
+    ARTIFACT_CONFIG[key] = { idPrefix, folder, label, type: key };
+    FOLDER_TO_TYPE[folder] = key;
+    PREFIX_TO_TYPE[idPrefix] = key;
+    customTypeKeys.add(key);
+  }
+}
+
+// Types of artifacts whose ID does not start with their type's prefix (renamed, e.g.
+// REQ-012 -> SYS-REQ-012), learned from the folder they were loaded from
+const ID_TO_TYPE: Record<string, string> = {};
+
+/**
+ * Record the type of an artifact found in that type's folder, so its ID
+ * resolves to it even when the ID prefix says otherwise
+ */
+export function registerArtifactIdType(id: string, typeKey: string): void {
+  if (PREFIX_TO_TYPE[id.split('-')[0]] === typeKey) {
+    delete ID_TO_TYPE[id];
+  } else {
+    ID_TO_TYPE[id] = typeKey;
+  }
+}
+
 /**
- * Identify artifact type from its ID (e.g., REQ-001 -> requirements)
+ * Identify artifact type from its ID (e.g., REQ-001 -> requirements), or from
+ * the folder it was loaded from for IDs without their type's prefix
  */
 export function getTypeFromId(id: string): string {
+  if (Object.hasOwn(ID_TO_TYPE, id)) return ID_TO_TYPE[id];
   const prefix = id.split('-')[0];
   return PREFIX_TO_TYPE[prefix] || 'unknown';
 }
@@ -0,0 +1,101 @@
+import { renderHook, act, waitFor } from '@testing-library/react';
+import { vi, describe, it, expect, beforeEach } from 'vitest';
+import { useSearchParams } from 'react-router-dom';
+import { useDesktopDeepLinks } from '../useDesktopDeepLinks';
+import { useFileSystem, useProject, useToast } from '../../app/providers';
+import { subscribeToDeepLinks } from '../../services/deepLinkService';
+import type { DeepLink } from '../../types';
+
+vi.mock('react-router-dom', () => ({
+  useSearchParams: vi.fn(),
+}));
+
+vi.mock('../../app/providers', () => ({
+  useFileSystem: vi.fn(),
+  useProject: vi.fn(),
+  useToast: vi.fn(),
+}));
+
+vi.mock('../../services/fileSystemService', () => ({
+  fileSystemService: { getRootPath: vi.fn(() => '/work/braking') },
+}));
+
+vi.mock('../../services/deepLinkService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../../services/deepLinkService')>()),
+  subscribeToDeepLinks: vi.fn(),
+}));
+
+const openDirectory = vi.fn();
+const fileSystem = (isReady: boolean) =>
+  ({ isReady, isLoading: false, openDirectory }) as unknown as ReturnType<typeof useFileSystem>;
+
+describe('useDesktopDeepLinks', () => {
+  const setSearchParams = vi.fn();
+  const switchProject = vi.fn();
+  const showToast = vi.fn();
+  let emit: (link: DeepLink) => void;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    switchProject.mockResolvedValue(undefined);
+    vi.mocked(subscribeToDeepLinks).mockImplementation((handler) => {
+      emit = handler;
+      return () => {};
+    });
+    vi.mocked(useSearchParams).mockReturnValue([new URLSearchParams(), setSearchParams]);
+    vi.mocked(useFileSystem).mockReturnValue(fileSystem(true));
+    vi.mocked(useProject).mockReturnValue({
+      projects: [
+        { id: 'proj-1', name: 'Braking' },
+        { id: 'proj-2', name: 'Steering' },
+      ],
+      currentProjectId: 'proj-1',
+      switchProject,
+    } as unknown as ReturnType<typeof useProject>);
+    vi.mocked(useToast).mockReturnValue({ showToast } as unknown as ReturnType<typeof useToast>);
+  });
+
+  it('should switch to the linked project and then open the artifact', async () => {
+    renderHook(() => useDesktopDeepLinks());
+
+    act(() => emit({ project: 'steering', artifact: 'REQ-012' }));
+
+    await waitFor(() => expect(setSearchParams).toHaveBeenCalled());
+    expect(switchProject).toHaveBeenCalledWith('proj-2');
+    expect(setSearchParams.mock.calls[0][0].get('id')).toBe('REQ-012');
+  });
+
+  it('should wait for an open project folder', () => {
+    vi.mocked(useFileSystem).mockReturnValue(fileSystem(false));
+    const { rerender } = renderHook(() => useDesktopDeepLinks());
+
+    act(() => emit({ artifact: 'RSK-003' }));
+    expect(setSearchParams).not.toHaveBeenCalled();
+
+    vi.mocked(useFileSystem).mockReturnValue(fileSystem(true));
+    rerender();
+
+    expect(setSearchParams.mock.calls[0][0].get('id')).toBe('RSK-003');
+    expect(switchProject).not.toHaveBeenCalled();
+  });
+
+  it('should report unknown projects', () => {
+    renderHook(() => useDesktopDeepLinks());
+
+    act(() => emit({ project: 'Missing', artifact: 'REQ-001' }));
+
+    expect(showToast).toHaveBeenCalledWith('Project not found: Missing', 'error');
+    expect(setSearchParams).not.toHaveBeenCalled();
+  });
+
+  it('should open a project folder from the command line, even before one is open', () => {
+    vi.mocked(useFileSystem).mockReturnValue(fileSystem(false));
+    renderHook(() => useDesktopDeepLinks());
+
+    act(() => emit({ projectPath: '/work/steering' }));
+    act(() => emit({ projectPath: '/work/braking' }));
+
+    expect(openDirectory).toHaveBeenCalledTimes(1);
+    expect(openDirectory).toHaveBeenCalledWith('/work/steering');
+  });
+});
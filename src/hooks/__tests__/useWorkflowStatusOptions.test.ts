@@ -0,0 +1,47 @@
+import { describe, it, expect, vi } from 'vitest';
+import { renderHook, waitFor } from '@testing-library/react';
+import { getStatusLabel, useWorkflowStatusOptions } from '../useWorkflowStatusOptions';
+import { getStatusWorkflow } from '../../services/statusWorkflowService';
+
+vi.mock('../../services/statusWorkflowService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../../services/statusWorkflowService')>()),
+  getStatusWorkflow: vi.fn(),
+}));
+
+describe('useWorkflowStatusOptions', () => {
+  it('should label statuses for display', () => {
+    expect(getStatusLabel('in-review')).toBe('In Review');
+    expect(getStatusLabel('approved')).toBe('Approved');
+  });
+
+  it('should offer the saved status and the statuses it may move to', async () => {
+    vi.mocked(getStatusWorkflow).mockResolvedValue({
+      statuses: ['new', 'accepted', 'done'],
+      initialStatus: 'new',
+      transitions: { new: ['accepted'], accepted: ['done'], done: [] },
+    });
+
+    const { result } = renderHook(() =>
+      useWorkflowStatusOptions({ status: 'accepted' }, 'accepted')
+    );
+
+    await waitFor(() =>
+      expect(result.current).toEqual([
+        { value: 'accepted', label: 'Accepted' },
+        { value: 'done', label: 'Done' },
+      ])
+    );
+  });
+
+  it('should start new artifacts at the initial status', async () => {
+    vi.mocked(getStatusWorkflow).mockResolvedValue({
+      statuses: ['new', 'done'],
+      initialStatus: 'new',
+      transitions: { new: ['done'], done: [] },
+    });
+
+    const { result } = renderHook(() => useWorkflowStatusOptions(null, 'new'));
+
+    await waitFor(() => expect(result.current).toEqual([{ value: 'new', label: 'New' }]));
+  });
+});
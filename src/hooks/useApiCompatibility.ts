@@ -0,0 +1,22 @@
+import { useEffect } from 'react';
+import { useToast } from '../app/providers';
+import { checkApiCompatibility, getApiInfo } from '../services/apiInfoService';
+
+/**
+ * Hook to warn once at startup when the desktop app's command API does not
+ * match the version this interface was written against
+ */
+export function useApiCompatibility() {
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    let cancelled = false;
+    getApiInfo().then((info) => {
+      const problem = info && checkApiCompatibility(info);
+      if (problem && !cancelled) showToast(problem, 'warning');
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [showToast]);
+}
@@ -3,6 +3,23 @@ import type { ArtifactLink } from '../types';
 import type { CustomAttributeValue } from '../types/customAttributes';
 import { useUser } from '../app/providers';
 import { useBaseArtifactForm } from './useBaseArtifactForm';
+import { draftService } from '../services/draftService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+const DRAFT_AUTOSAVE_MS = 1000;
+
+// Text fields kept in the autosaved draft of an artifact being edited
+interface DraftFields {
+  title: string;
+  description: string;
+  text: string;
+}
+
+function getDraftArtifactPath(id: string): string | null {
+  const config = ARTIFACT_CONFIG[getTypeFromId(id)];
+  return config ? `${config.folder}/${id}.md` : null;
+}
 
 export interface UseArtifactFormOptions<T extends { id: string }, TabType extends string> {
   isOpen: boolean;
@@ -83,6 +100,48 @@ export function useArtifactForm<T extends { id: string }, TabType extends string
     }
   }, [isOpen, artifact, currentUser?.name]);
 
+  // Drafts: edits to an existing artifact are autosaved apart from the file,
+  // restored if the app went away before saving, and dropped on save or close
+  const draftPath = artifact ? getDraftArtifactPath(artifact.id) : null;
+
+  useEffect(() => {
+    if (!isOpen || !artifact || !draftPath) return;
+    let cancelled = false;
+    draftService
+      .loadDraft(draftPath)
+      .then((draft) => {
+        const lastModified = (artifact as { lastModified?: number }).lastModified ?? 0;
+        if (cancelled || !draft || draft.savedAt <= lastModified) return;
+        const fields = JSON.parse(draft.content) as DraftFields;
+        setTitle(fields.title);
+        setDescription(fields.description);
+        setText(fields.text);
+        debug.info(`[useArtifactForm] Restored unsaved changes to ${artifact.id}`);
+      })
+      .catch((err) => debug.warn(`[useArtifactForm] Could not restore draft of ${draftPath}`, err));
+    return () => {
+      cancelled = true;
+      draftService.discardDraft(draftPath).catch(() => undefined);
+    };
+  }, [isOpen, artifact, draftPath]);
+
+  useEffect(() => {
+    if (!isOpen || !artifact || !draftPath) return;
+    const saved = artifact as Record<string, unknown>;
+    const unchanged =
+      title === (saved.title || '') &&
+      description === (saved.description || '') &&
+      text === (saved.text || '');
+    if (unchanged) return;
+    const timer = setTimeout(() => {
+      const fields: DraftFields = { title, description, text };
+      draftService
+        .saveDraft(draftPath, JSON.stringify(fields))
+        .catch((err) => debug.warn(`[useArtifactForm] Could not save draft of ${draftPath}`, err));
+    }, DRAFT_AUTOSAVE_MS);
+    return () => clearTimeout(timer);
+  }, [isOpen, artifact, draftPath, title, description, text]);
+
   const handleSubmit = useCallback(
     (e?: React.FormEvent, additionalData: Partial<T> = {}) => {
       if (e) e.preventDefault();
@@ -0,0 +1,71 @@
+import { useEffect, useState } from 'react';
+import { useSearchParams } from 'react-router-dom';
+import { useFileSystem, useProject, useToast } from '../app/providers';
+import { findLinkedProject, subscribeToDeepLinks } from '../services/deepLinkService';
+import { fileSystemService } from '../services/fileSystemService';
+import type { DeepLink } from '../types';
+
+/**
+ * Hook to handle tracyfy:// links and launch requests forwarded by the desktop
+ * app. A project folder path is opened as the directory; other links wait
+ * until a project folder is open, then switch to the linked project and
+ * hand the artifact to useArtifactDeepLink through the 'id' URL parameter.
+ */
+export function useDesktopDeepLinks() {
+  const [pending, setPending] = useState<DeepLink[]>([]);
+  const [searchParams, setSearchParams] = useSearchParams();
+  const { isReady, isLoading, openDirectory } = useFileSystem();
+  const { projects, currentProjectId, switchProject } = useProject();
+  const { showToast } = useToast();
+
+  useEffect(() => subscribeToDeepLinks((link) => setPending((prev) => [...prev, link])), []);
+
+  useEffect(() => {
+    if (isLoading || pending.length === 0) return;
+    const [link, ...rest] = pending;
+    if (link.projectPath) {
+      setPending(rest);
+      if (link.projectPath !== fileSystemService.getRootPath()) {
+        openDirectory(link.projectPath);
+      }
+      return;
+    }
+    if (!isReady) return;
+    setPending(rest);
+
+    const openArtifact = () => {
+      if (!link.artifact) return;
+      const params = new URLSearchParams(searchParams);
+      params.set('id', link.artifact);
+      setSearchParams(params);
+    };
+
+    if (!link.project) {
+      openArtifact();
+      return;
+    }
+    const project = findLinkedProject(projects, link.project);
+    if (!project) {
+      showToast(`Project not found: ${link.project}`, 'error');
+      return;
+    }
+    if (project.id === currentProjectId) {
+      openArtifact();
+      return;
+    }
+    switchProject(project.id)
+      .then(openArtifact)
+      .catch(() => showToast(`Could not open project ${project.name}`, 'error'));
+  }, [
+    isReady,
+    isLoading,
+    openDirectory,
+    pending,
+    projects,
+    currentProjectId,
+    switchProject,
+    searchParams,
+    setSearchParams,
+    showToast,
+  ]);
+}
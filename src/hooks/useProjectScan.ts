@@ -0,0 +1,55 @@
+import { useEffect } from 'react';
+import { useBackgroundTasks, useFileSystem, useToast } from '../app/providers';
+import { cancelJob, JOB_UPDATED_EVENT, waitForJob, type JobStatus } from '../services/jobService';
+import {
+  formatScanSummary,
+  hasScanDifferences,
+  startProjectScan,
+  type ProjectScanSummary,
+} from '../services/projectScanService';
+
+/**
+ * Hook to scan the project once it is open and report what changed on disk
+ * or in git while the app was closed. The scan runs as a background job and
+ * the pending changes are refreshed when it finds differences.
+ */
+export function useProjectScan() {
+  const { isReady, refreshStatus } = useFileSystem();
+  const { startTask, updateTask, endTask } = useBackgroundTasks();
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    const isE2E = (window as unknown as { __E2E_TEST_MODE__?: boolean }).__E2E_TEST_MODE__;
+    if (!isReady || isE2E) return;
+
+    const jobId = startProjectScan();
+    const taskId = startTask('Scanning project...');
+    let active = true;
+
+    const onUpdate = (event: Event) => {
+      const { id, progress } = (event as CustomEvent<JobStatus>).detail;
+      if (id === jobId && progress) {
+        updateTask(taskId, `Scanning project... (${progress.completed}/${progress.total})`);
+      }
+    };
+    window.addEventListener(JOB_UPDATED_EVENT, onUpdate);
+
+    waitForJob<ProjectScanSummary>(jobId).then((status) => {
+      window.removeEventListener(JOB_UPDATED_EVENT, onUpdate);
+      endTask(taskId);
+      if (!active || status.state !== 'completed' || !status.result) return;
+      if (!hasScanDifferences(status.result)) return;
+      refreshStatus();
+      showToast(formatScanSummary(status.result)!, 'info');
+    });
+
+    return () => {
+      active = false;
+      window.removeEventListener(JOB_UPDATED_EVENT, onUpdate);
+      cancelJob(jobId);
+      endTask(taskId);
+    };
+    // Scan once per opened project; the task and toast callbacks are stable
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [isReady]);
+}
@@ -0,0 +1,21 @@
+import { useEffect } from 'react';
+import { useToast } from '../app/providers';
+import { SECRETS_DETECTED_EVENT } from '../services/secretScanService';
+import { formatSecretFindings, type SecretFinding } from '../utils/secretScanUtils';
+
+/**
+ * Hook to warn when a commit went ahead with text that looks like
+ * credentials, which happens when blocking such commits is turned off
+ */
+export function useSecretScanWarnings() {
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    const onDetected = (event: Event) => {
+      const findings = (event as CustomEvent<SecretFinding[]>).detail;
+      showToast(`Committed possible credentials in ${formatSecretFindings(findings)}`, 'warning');
+    };
+    window.addEventListener(SECRETS_DETECTED_EVENT, onDetected);
+    return () => window.removeEventListener(SECRETS_DETECTED_EVENT, onDetected);
+  }, [showToast]);
+}
@@ -83,18 +83,9 @@ export function useTestCaseForm({
         steps,
         expectedResult,
       };
-
-      // Update lastRun when status changes to passed/failed
-      if (
-        (status === 'passed' || status === 'failed') &&
-        (!testCase || testCase.status !== status)
-      ) {
-        updates.lastRun = Date.now();
-      }
-
       baseHandleSubmit(e, updates);
     },
-    [baseHandleSubmit, status, testCase, steps, expectedResult]
+    [baseHandleSubmit, steps, expectedResult]
   );
 
   return {
@@ -66,7 +66,7 @@ interface UseVersionHistoryOptions {
   isOpen: boolean;
   baselines: ProjectBaseline[];
   projectName: string | null;
-  onCreateBaseline: (name: string, message: string) => void;
+  onCreateBaseline: (name: string, message: string, exportSnapshot: boolean) => void;
 }
 
 export function useVersionHistory({
@@ -82,6 +82,7 @@ export function useVersionHistory({
   const [isCreatingBaseline, setIsCreatingBaseline] = useState(false);
   const [baselineName, setBaselineName] = useState('');
   const [baselineMessage, setBaselineMessage] = useState('');
+  const [exportSnapshot, setExportSnapshot] = useState(false);
 
   // Commits state
   const [commits, setCommits] = useState<CommitInfo[]>([]);
@@ -247,6 +248,7 @@ export function useVersionHistory({
   const handleStartCreating = useCallback(() => {
     setBaselineName(`${projectBaselines.length + 1}.0`);
     setBaselineMessage('');
+    setExportSnapshot(false);
     setIsCreatingBaseline(true);
   }, [projectBaselines.length]);
 
@@ -258,14 +260,15 @@ export function useVersionHistory({
         const fullTagName = `${projectPrefix}${baselineName.trim()}`;
         onCreateBaseline(
           fullTagName,
-          baselineMessage.trim() || `Baseline ${baselineName.trim()} for ${projectName}`
+          baselineMessage.trim() || `Baseline ${baselineName.trim()} for ${projectName}`,
+          exportSnapshot
         );
         setBaselineName('');
         setBaselineMessage('');
         setIsCreatingBaseline(false);
       }
     },
-    [baselineName, baselineMessage, projectName, projectPrefix, onCreateBaseline]
+    [baselineName, baselineMessage, exportSnapshot, projectName, projectPrefix, onCreateBaseline]
   );
 
   return {
@@ -280,6 +283,8 @@ export function useVersionHistory({
     setBaselineName,
     baselineMessage,
     setBaselineMessage,
+    exportSnapshot,
+    setExportSnapshot,
     handleStartCreating,
     handleCreateBaselineSubmit,
 
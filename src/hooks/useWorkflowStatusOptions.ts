@@ -0,0 +1,57 @@
+import { useEffect, useMemo, useState } from 'react';
+import type { StatusWorkflowConfig } from '../types';
+import {
+  DEFAULT_STATUS_WORKFLOW,
+  getAllowedTransitions,
+  getStatusWorkflow,
+} from '../services/statusWorkflowService';
+import { debug } from '../utils/debug';
+
+export interface StatusOption {
+  value: string;
+  label: string;
+}
+
+/**
+ * Display label of a status, e.g. "in-review" -> "In Review"
+ */
+export function getStatusLabel(status: string): string {
+  return status
+    .split(/[-_\s]+/)
+    .filter(Boolean)
+    .map((word) => word[0].toUpperCase() + word.slice(1))
+    .join(' ');
+}
+
+/**
+ * Hook for the status choices of an artifact form, following the project's
+ * status workflow: the saved status and those it may move to. A new artifact
+ * starts at the workflow's initial status (or the form's default).
+ */
+export function useWorkflowStatusOptions(
+  artifact: { status?: string } | null,
+  formStatus: string
+): StatusOption[] {
+  const [workflow, setWorkflow] = useState<StatusWorkflowConfig>(DEFAULT_STATUS_WORKFLOW);
+
+  useEffect(() => {
+    let cancelled = false;
+    getStatusWorkflow()
+      .then((config) => {
+        if (!cancelled) setWorkflow(config);
+      })
+      .catch((err) => debug.warn('[useWorkflowStatusOptions] Could not load the workflow', err));
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const savedStatus = artifact ? artifact.status || workflow.initialStatus : null;
+  return useMemo(() => {
+    const statuses =
+      savedStatus === null
+        ? [formStatus, workflow.initialStatus]
+        : [savedStatus, ...getAllowedTransitions(workflow, savedStatus)];
+    return [...new Set(statuses)].map((value) => ({ value, label: getStatusLabel(value) }));
+  }, [workflow, savedStatus, formStatus]);
+}
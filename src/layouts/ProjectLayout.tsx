@@ -19,6 +19,9 @@ import {
 import { exportProjectToPDF } from '../utils/pdfExportUtils';
 import { exportProjectToExcel } from '../utils/excelExportUtils';
 import { createDemoProject } from '../services/demoDataService';
+import { importDroppedFiles } from '../services/dropImportService';
+import { runJob } from '../services/jobService';
+import { isElectronEnv } from '../services/git/types';
 import type {
   Project,
   ColumnVisibility,
@@ -43,7 +46,7 @@ export const ProjectLayout: React.FC = () => {
     useGlobalState();
 
   // FileSystem context
-  const { reloadData, refreshStatus, changeDirectory } = useFileSystem();
+  const { reloadData, refreshStatus, changeDirectory, isReadOnly, setReadOnly } = useFileSystem();
 
   // Baselines context
   const { baselines } = useBaselines();
@@ -198,7 +201,7 @@ export const ProjectLayout: React.FC = () => {
   const { currentUser } = useUser();
 
   // Background tasks
-  const { startTask, endTask } = useBackgroundTasks();
+  const { startTask, updateTask, endTask } = useBackgroundTasks();
 
   // Project action handlers
   const handleCreateProject = useCallback(() => {
@@ -255,6 +258,51 @@ export const ProjectLayout: React.FC = () => {
     }
   }, [reloadData, refreshStatus, switchProject, startTask, endTask, showToast]);
 
+  // Reviewer mode: the desktop app refuses changes to the project while on
+  const handleToggleReadOnly = useCallback(async () => {
+    try {
+      // Turning it off can be canceled in the desktop app's confirmation dialog
+      const readOnly = await setReadOnly(!isReadOnly);
+      if (readOnly === isReadOnly) return;
+      showToast(readOnly ? 'Project is now read-only' : 'Project is editable again', 'info');
+    } catch (err) {
+      showToast(`Could not change read-only mode: ${(err as Error).message}`, 'error');
+    }
+  }, [isReadOnly, setReadOnly, showToast]);
+
+  // Import files dropped onto the main area into the current project
+  const handleDropFiles = useCallback(
+    async (files: File[]) => {
+      if (!currentProjectId) {
+        showToast('Select a project before dropping files', 'error');
+        return;
+      }
+      const taskId = startTask(`Importing ${files.length} files...`);
+      try {
+        const dropped = await Promise.all(
+          files.map(async (file) => ({
+            name: file.name,
+            data: new Uint8Array(await file.arrayBuffer()),
+          }))
+        );
+        const { created, skipped } = await runJob(`Import ${files.length} files`, () =>
+          importDroppedFiles(dropped, currentProjectId)
+        );
+        await reloadData();
+        await refreshStatus();
+        const skippedText = skipped.length > 0 ? `, skipped ${skipped.length}` : '';
+        showToast(`Imported ${created.length} files${skippedText}`, 'success');
+      } catch (error) {
+        console.error('Failed to import dropped files:', error);
+        const errorMessage = error instanceof Error ? error.message : 'Unknown error';
+        showToast(`Failed to import files: ${errorMessage}`, 'error');
+      } finally {
+        endTask(taskId);
+      }
+    },
+    [currentProjectId, reloadData, refreshStatus, startTask, endTask, showToast]
+  );
+
   // Get page title from current route
   const getPageTitle = () => {
     if (location.pathname === '/requirements') return 'Requirements';
@@ -307,6 +355,7 @@ export const ProjectLayout: React.FC = () => {
       onNewWorkflow={() => ui.setIsWorkflowModalOpen(true)}
       onNewLink={() => navigate('/links')}
       onNewCustomAttribute={() => ui.setIsCustomAttributeModalOpen(true)}
+      onManageArtifactTypes={() => ui.openModal('artifact-types')}
       onNewDocument={() => ui.openModal('documents')}
       onExport={importExport.handleExport}
       onImport={importExport.handleImport}
@@ -319,6 +368,9 @@ export const ProjectLayout: React.FC = () => {
       onOpenUserSettings={() => ui.setIsUserSettingsModalOpen(true)}
       onHelp={() => navigate('/help')}
       onChangeFolder={changeDirectory}
+      isReadOnly={isReadOnly}
+      onToggleReadOnly={isElectronEnv() ? handleToggleReadOnly : undefined}
+      onDropFiles={handleDropFiles}
       currentUserName={currentUser?.name}
       baselines={baselines}
       onExportPDF={async (selectedBaseline) => {
@@ -329,23 +381,29 @@ export const ProjectLayout: React.FC = () => {
 
         const taskId = startTask('Exporting PDF...');
         try {
-          await exportProjectToPDF(
-            currentProject,
-            {
-              requirements: globalRequirements,
-              useCases: globalUseCases,
-              testCases: globalTestCases,
-              information: globalInformation,
-            },
-            currentProject.requirementIds,
-            currentProject.useCaseIds,
-            currentProject.testCaseIds,
-            currentProject.informationIds,
-            baselines,
-            selectedBaseline, // selectedBaseline: ProjectBaseline | null
-            currentUser?.name,
-            true,
-            documents
+          await runJob('Export PDF', ({ reportProgress }) =>
+            exportProjectToPDF(
+              currentProject,
+              {
+                requirements: globalRequirements,
+                useCases: globalUseCases,
+                testCases: globalTestCases,
+                information: globalInformation,
+              },
+              currentProject.requirementIds,
+              currentProject.useCaseIds,
+              currentProject.testCaseIds,
+              currentProject.informationIds,
+              baselines,
+              selectedBaseline, // selectedBaseline: ProjectBaseline | null
+              currentUser?.name,
+              true,
+              documents,
+              ({ stage, completed, total }) => {
+                reportProgress({ completed, total, message: stage });
+                updateTask(taskId, `Exporting PDF... (${completed + 1}/${total})`);
+              }
+            )
           );
         } finally {
           endTask(taskId);
@@ -359,33 +417,35 @@ export const ProjectLayout: React.FC = () => {
 
         const taskId = startTask('Exporting Excel...');
         try {
-          await exportProjectToExcel(
-            currentProject,
-            {
-              requirements: globalRequirements,
-              useCases: globalUseCases,
-              testCases: globalTestCases,
-              information: globalInformation,
-            },
-            currentProject.requirementIds,
-            currentProject.useCaseIds,
-            currentProject.testCaseIds,
-            currentProject.informationIds,
-            baselines,
-            {
-              format: 'excel',
-              baseline: null,
-              includeRequirements: true,
-              includeUseCases: true,
-              includeTestCases: true,
-              includeInformation: true,
-              includeRisks: true,
-              includeLinks: true,
-              includeTitlePage: true,
-              includeRevisionHistory: true,
-              includeTraceability: true,
-              includeVerificationMatrix: true,
-            } as ExportOptions
+          await runJob('Export Excel', () =>
+            exportProjectToExcel(
+              currentProject,
+              {
+                requirements: globalRequirements,
+                useCases: globalUseCases,
+                testCases: globalTestCases,
+                information: globalInformation,
+              },
+              currentProject.requirementIds,
+              currentProject.useCaseIds,
+              currentProject.testCaseIds,
+              currentProject.informationIds,
+              baselines,
+              {
+                format: 'excel',
+                baseline: null,
+                includeRequirements: true,
+                includeUseCases: true,
+                includeTestCases: true,
+                includeInformation: true,
+                includeRisks: true,
+                includeLinks: true,
+                includeTitlePage: true,
+                includeRevisionHistory: true,
+                includeTraceability: true,
+                includeVerificationMatrix: true,
+              } as ExportOptions
+            )
           );
         } finally {
           endTask(taskId);
@@ -0,0 +1,97 @@
+/**
+ * API Info Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import {
+  CLIENT_API_VERSION,
+  checkApiCompatibility,
+  getApiInfo,
+  hasCapability,
+  resetApiInfo,
+} from '../apiInfoService';
+import { isElectronEnv, type ApiInfo } from '../git/types';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+const INFO: ApiInfo = {
+  appVersion: '1.4.0',
+  apiVersion: 2,
+  minSupportedApiVersion: 1,
+  capabilities: ['fs:batch', 'git'],
+  features: { keychain: true, notifications: true },
+  deprecated: [
+    { command: 'assets:setProjectRoot(rootDir)', replacement: 'project:open(dir)', since: 2 },
+  ],
+};
+
+describe('apiInfoService', () => {
+  const app = { getApiInfo: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    resetApiInfo();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    app.getApiInfo.mockResolvedValue(INFO);
+    window.electronAPI = { app } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should report the backend version and capabilities', async () => {
+    await expect(getApiInfo()).resolves.toEqual(INFO);
+    await expect(hasCapability('fs:batch')).resolves.toBe(true);
+    await expect(hasCapability('fs:watch')).resolves.toBe(false);
+  });
+
+  it('should ask the backend only once', async () => {
+    await getApiInfo();
+    await hasCapability('fs:batch');
+
+    expect(app.getApiInfo).toHaveBeenCalledTimes(1);
+  });
+
+  it('should treat a backend without the command as API version 1', async () => {
+    window.electronAPI = {} as unknown as Window['electronAPI'];
+
+    const info = await getApiInfo();
+
+    expect(info?.apiVersion).toBe(1);
+    expect(info?.capabilities).toEqual([]);
+  });
+
+  it('should fall back to API version 1 when the backend fails', async () => {
+    app.getApiInfo.mockResolvedValue({ error: 'boom' });
+
+    await expect(getApiInfo()).resolves.toMatchObject({ apiVersion: 1 });
+
+    app.getApiInfo.mockResolvedValue(INFO);
+    await expect(getApiInfo()).resolves.toEqual(INFO);
+  });
+
+  it('should have no API info in the browser', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+
+    await expect(getApiInfo()).resolves.toBeNull();
+    await expect(hasCapability('fs:batch')).resolves.toBe(false);
+  });
+
+  it('should warn about older and newer backends', () => {
+    expect(checkApiCompatibility(INFO)).toBeNull();
+    expect(checkApiCompatibility({ ...INFO, apiVersion: CLIENT_API_VERSION - 1 })).toContain(
+      'older than this interface'
+    );
+    expect(
+      checkApiCompatibility({
+        ...INFO,
+        apiVersion: CLIENT_API_VERSION + 2,
+        minSupportedApiVersion: CLIENT_API_VERSION + 1,
+      })
+    ).toContain('no longer supports');
+  });
+});
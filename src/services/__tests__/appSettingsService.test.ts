@@ -0,0 +1,155 @@
+/**
+ * App Settings Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../appSettingsService';
+import { isElectronEnv } from '../git/types';
+import { fileSystemService } from '../fileSystemService';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => false),
+}));
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { getRootPath: vi.fn(() => '/work/a'), getDirectoryName: vi.fn() },
+}));
+
+// A minisign public key with key ID 0000000000000000
+const PUBLIC_KEY = `untrusted comment: minisign public key\n${btoa('Ed' + '\0'.repeat(40))}`;
+
+describe('appSettingsService', () => {
+  const settings = {
+    get: vi.fn(),
+    update: vi.fn(),
+    chooseDiagramTool: vi.fn(),
+    trustSigningKey: vi.fn(),
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+    window.electronAPI = { settings } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    localStorage.clear();
+  });
+
+  it('should return defaults when nothing is saved', async () => {
+    expect(await appSettingsService.getAppSettings()).toEqual(DEFAULT_APP_SETTINGS);
+  });
+
+  it('should merge updates, including single poll intervals, and announce them', async () => {
+    const listener = vi.fn();
+    window.addEventListener('app-settings-changed', listener);
+
+    await appSettingsService.updateAppSettings({ defaultAuthor: 'Ann' });
+    const updated = await appSettingsService.updateAppSettings({
+      pollIntervals: { syncStatus: 60000 },
+    });
+    window.removeEventListener('app-settings-changed', listener);
+
+    expect(updated).toEqual({
+      ...DEFAULT_APP_SETTINGS,
+      defaultAuthor: 'Ann',
+      pollIntervals: { syncStatus: 60000, remoteStatus: 120000 },
+    });
+    expect(await appSettingsService.getAppSettings()).toEqual(updated);
+    expect((listener.mock.calls[1][0] as CustomEvent).detail).toEqual(updated);
+  });
+
+  it('should change diagram tools and trusted keys only through main dialogs', async () => {
+    await expect(appSettingsService.chooseDiagramTool('mermaidCli')).rejects.toThrow(
+      'Diagram tools can only be set in the desktop app'
+    );
+
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    settings.chooseDiagramTool.mockResolvedValue({
+      settings: { ...DEFAULT_APP_SETTINGS, diagrams: { plantumlJar: '/opt/plantuml.jar' } },
+    });
+    settings.trustSigningKey.mockResolvedValue({ canceled: true });
+
+    const updated = await appSettingsService.chooseDiagramTool('plantumlJar');
+    expect(settings.chooseDiagramTool).toHaveBeenCalledWith('plantumlJar', false);
+    expect(updated?.diagrams).toEqual({
+      mermaidCli: 'mmdc',
+      plantumlJar: '/opt/plantuml.jar',
+      javaPath: 'java',
+    });
+    expect(await appSettingsService.trustSigningKey(PUBLIC_KEY)).toBe(false);
+    expect(settings.trustSigningKey).toHaveBeenCalledWith(PUBLIC_KEY);
+    expect(settings.update).not.toHaveBeenCalled();
+  });
+
+  it('should keep issue tracker servers per project folder', async () => {
+    await appSettingsService.setIntegrationUrl('jira', 'https://a.atlassian.net');
+    vi.mocked(fileSystemService.getRootPath).mockReturnValue('/work/b');
+    await appSettingsService.setIntegrationUrl('gitlab', 'https://gitlab.b.test');
+
+    expect(await appSettingsService.getIntegrationUrls()).toEqual({
+      gitlab: 'https://gitlab.b.test',
+    });
+    expect((await appSettingsService.getAppSettings()).integrationUrls).toEqual({
+      '/work/a': { jira: 'https://a.atlassian.net' },
+      '/work/b': { gitlab: 'https://gitlab.b.test' },
+    });
+  });
+
+  it('should reject poll intervals that are too short', async () => {
+    await expect(
+      appSettingsService.updateAppSettings({ pollIntervals: { remoteStatus: 100 } })
+    ).rejects.toThrow('Poll interval remoteStatus must be at least 5000 ms');
+  });
+
+  it('should reject local API ports outside 1024-65535', () => {
+    expect(
+      appSettingsService.validateAppSettings({ localApi: { enabled: true, port: 80 } })
+    ).toEqual(['Local API port must be between 1024 and 65535']);
+  });
+
+  it('should reject author profiles with invalid or duplicate IDs or bad emails', () => {
+    const profile = { id: 'work', name: 'Ann', email: 'ann@corp.example', initials: 'A' };
+
+    expect(appSettingsService.validateAppSettings({ authorProfiles: [profile] })).toEqual([]);
+    expect(
+      appSettingsService.validateAppSettings({
+        authorProfiles: [profile, profile, { ...profile, id: 'Home', email: 'ann' }],
+      })
+    ).toEqual([
+      'Duplicate author profile ID: work',
+      'Invalid author profile ID: Home',
+      'Author profile Home has an invalid email address',
+    ]);
+  });
+
+  it('should reject trusted signing keys that are not minisign public keys', async () => {
+    await expect(appSettingsService.trustSigningKey('not a key\nat all')).rejects.toThrow(
+      'Invalid trusted signing key: not a key'
+    );
+    expect(await appSettingsService.trustSigningKey(PUBLIC_KEY)).toBe(true);
+    expect(await appSettingsService.trustSigningKey(PUBLIC_KEY)).toBe(true);
+    expect((await appSettingsService.getAppSettings()).trustedSigningKeys).toEqual([PUBLIC_KEY]);
+  });
+
+  it('should move the legacy auto-sync flag into the settings', async () => {
+    localStorage.setItem('tracyfy-auto-sync', 'true');
+
+    expect((await appSettingsService.getAppSettings()).autoSync).toBe(true);
+    expect(localStorage.getItem('tracyfy-auto-sync')).toBeNull();
+  });
+
+  it('should read and update settings through the main process in Electron', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    settings.get.mockResolvedValue({ settings: { ...DEFAULT_APP_SETTINGS, autoSync: true } });
+    settings.update.mockResolvedValue({ error: 'EACCES' });
+
+    expect((await appSettingsService.getAppSettings()).autoSync).toBe(true);
+    await expect(appSettingsService.updateAppSettings({ autoSync: false })).rejects.toThrow(
+      'EACCES'
+    );
+    expect(settings.update).toHaveBeenCalledWith({ autoSync: false });
+    expect(localStorage.length).toBe(0);
+  });
+});
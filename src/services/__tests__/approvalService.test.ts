@@ -0,0 +1,132 @@
+// @vitest-environment node
+/**
+ * Approval Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { approvalService, getApprovalPayload } from '../approvalService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { requirementToMarkdown } from '../../utils/markdownUtils';
+import type { ApprovalRecord, Requirement } from '../../types';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    listFiles: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  userService: { load: vi.fn() },
+  ALL_ARTIFACT_SERVICES: {
+    requirements: { load: vi.fn(), save: vi.fn(), serialize: vi.fn() },
+  },
+}));
+
+vi.mock('../diskProjectService', () => ({
+  diskProjectService: { getCurrentUserId: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: {
+    getSection: vi.fn((_section: string, defaults: unknown) => Promise.resolve(defaults)),
+  },
+}));
+
+describe('approvalService', () => {
+  const requirement: Requirement = {
+    id: 'REQ-001',
+    title: 'Login',
+    description: '',
+    text: 'The system shall allow login.',
+    rationale: '',
+    category: 'functional',
+    status: 'in-review',
+    priority: 'high',
+    dateCreated: 1,
+    lastModified: 1,
+    revision: '01',
+  };
+  let approvalsFile: string | null;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    approvalsFile = null;
+    const requirements = ALL_ARTIFACT_SERVICES.requirements;
+    vi.mocked(requirements.serialize).mockImplementation(requirementToMarkdown);
+    vi.mocked(requirements.load).mockResolvedValue(requirement);
+    vi.mocked(fileSystemService.readFile).mockImplementation(async () => approvalsFile);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (_path, content) => {
+      approvalsFile = content as string;
+    });
+  });
+
+  it('appends a signed approval record and marks the artifact approved', async () => {
+    const sign = vi.fn().mockResolvedValue('sig');
+
+    const record = await approvalService.approveArtifact('requirements/REQ-001.md', 'Sam', {
+      comment: 'Looks good',
+      sign,
+    });
+
+    expect(record.contentHash).toMatch(/^[0-9a-f]{64}$/);
+    expect(record.signature).toBe('sig');
+    expect(sign).toHaveBeenCalledWith(getApprovalPayload(record));
+    expect(JSON.parse(approvalsFile!)).toEqual([record]);
+    expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+      expect.objectContaining({ status: 'approved', statusChangedBy: 'Sam' })
+    );
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      ['requirements/REQ-001.md', 'approvals/REQ-001.json'],
+      'Approve REQ-001',
+      'Sam'
+    );
+  });
+
+  it('rejects approval when the workflow does not allow it', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+      ...requirement,
+      status: 'obsolete',
+    });
+
+    await expect(approvalService.approveArtifact('requirements/REQ-001.md', 'Sam')).rejects.toThrow(
+      'Cannot approve REQ-001'
+    );
+    expect(realGitService.commitFiles).not.toHaveBeenCalled();
+  });
+
+  it('lists approvals of an artifact', async () => {
+    const records: ApprovalRecord[] = [
+      { artifactId: 'REQ-001', reviewer: 'Sam', timestamp: 1, contentHash: 'abc' },
+    ];
+    approvalsFile = JSON.stringify(records);
+
+    expect(await approvalService.listApprovals('requirements/REQ-001.md')).toEqual(records);
+  });
+
+  it('detects edits made after approval but ignores status changes', async () => {
+    const record = await approvalService.approveArtifact('requirements/REQ-001.md', 'Sam');
+    const approved = { ...requirement, status: 'approved', lastModified: record.timestamp };
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue(approved);
+
+    const unchanged = await approvalService.checkApproval('requirements/REQ-001.md');
+    expect(unchanged?.editedSinceApproval).toBe(false);
+
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+      ...approved,
+      text: 'The system shall allow login with SSO.',
+    });
+    vi.mocked(fileSystemService.listFiles).mockResolvedValue(['REQ-001.json']);
+
+    const edited = await approvalService.findEditedAfterApproval();
+    expect(edited.map((check) => check.artifactId)).toEqual(['REQ-001']);
+  });
+});
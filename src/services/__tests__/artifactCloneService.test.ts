@@ -0,0 +1,188 @@
+/**
+ * Artifact Clone Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { buildClone, cloneArtifact, parseArtifactPath } from '../artifactCloneService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import { idService } from '../idService';
+import { runJournaled } from '../writeJournalService';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+      projects: service(),
+    },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), save: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn((type: string, count: number) =>
+      Promise.resolve(type === 'links' ? ['LINK-030', 'LINK-031'].slice(0, count) : ['REQ-042'])
+    ),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+const original = {
+  id: 'REQ-007',
+  title: 'Export',
+  text: 'Export to CSV.',
+  status: 'approved',
+  approvalDate: 100,
+  priority: 'high',
+  tags: ['reports'],
+  linkedArtifacts: [{ targetId: 'TC-003', type: 'verified_by' }],
+  revision: '05',
+  dateCreated: 1,
+  lastModified: 1,
+};
+
+describe('artifactCloneService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue(original);
+    vi.mocked(ALL_ARTIFACT_SERVICES.projects.loadAll).mockResolvedValue([
+      { id: 'PROJ-001', requirementIds: ['REQ-007'], lastModified: 1 },
+    ]);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+      {
+        id: 'LINK-004',
+        sourceId: 'TC-003',
+        targetId: 'REQ-007',
+        type: 'verifies',
+        projectIds: [],
+        suspect: true,
+        dateCreated: 1,
+        lastModified: 1,
+        revision: '02',
+      },
+      {
+        id: 'LINK-005',
+        sourceId: 'TC-004',
+        targetId: 'REQ-008',
+        type: 'verifies',
+        projectIds: [],
+        dateCreated: 1,
+        lastModified: 1,
+        revision: '01',
+      },
+    ]);
+  });
+
+  describe('parseArtifactPath', () => {
+    it('reads the ID and type from the path', () => {
+      expect(parseArtifactPath('requirements/REQ-007.md')).toEqual({
+        id: 'REQ-007',
+        typeKey: 'requirements',
+      });
+      expect(parseArtifactPath('C:\\repo\\testcases\\TC-001.md').typeKey).toBe('testcases');
+    });
+
+    it('rejects paths that are not artifact files', () => {
+      expect(() => parseArtifactPath('links/LINK-001.md')).toThrow('Not an artifact file');
+      expect(() => parseArtifactPath('testcases/REQ-001.md')).toThrow('does not belong');
+    });
+  });
+
+  describe('buildClone', () => {
+    it('resets status, approval and dates', () => {
+      const clone = buildClone(original, 'requirements', 'REQ-042', 'Export (copy)', false, 50);
+
+      expect(clone).toMatchObject({
+        id: 'REQ-042',
+        title: 'Export (copy)',
+        text: 'Export to CSV.',
+        status: 'draft',
+        priority: 'high',
+        tags: ['reports'],
+        linkedArtifacts: [],
+        revision: '01',
+        dateCreated: 50,
+        lastModified: 50,
+      });
+      expect(clone.approvalDate).toBeUndefined();
+    });
+  });
+
+  describe('cloneArtifact', () => {
+    it('saves the clone and adds it to the projects of the original', async () => {
+      const result = await cloneArtifact('requirements/REQ-007.md');
+
+      expect(result).toEqual({
+        originalId: 'REQ-007',
+        cloneId: 'REQ-042',
+        path: 'requirements/REQ-042.md',
+        linkIds: [],
+        projectIds: ['PROJ-001'],
+      });
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'REQ-042', title: 'Export (copy)', linkedArtifacts: [] })
+      );
+      expect(ALL_ARTIFACT_SERVICES.projects.save).toHaveBeenCalledWith(
+        expect.objectContaining({ requirementIds: ['REQ-007', 'REQ-042'] })
+      );
+      expect(diskLinkService.getAllLinks).not.toHaveBeenCalled();
+      expect(idService.getNextIds).toHaveBeenCalledWith('requirements', 1, true);
+      expect(runJournaled).toHaveBeenCalledWith(
+        'Clone REQ-007',
+        expect.any(Function),
+        'Clone REQ-007'
+      );
+    });
+
+    it('copies the links of the original when asked', async () => {
+      const result = await cloneArtifact('requirements/REQ-007.md', {
+        copyLinks: true,
+        title: 'Export to Excel',
+      });
+
+      expect(result.linkIds).toEqual(['LINK-030']);
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          title: 'Export to Excel',
+          linkedArtifacts: [{ targetId: 'TC-003', type: 'verified_by' }],
+        })
+      );
+      expect(diskLinkService.save).toHaveBeenCalledTimes(1);
+      expect(diskLinkService.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'LINK-030',
+          sourceId: 'TC-003',
+          targetId: 'REQ-042',
+          suspect: undefined,
+          revision: '01',
+        })
+      );
+    });
+
+    it('fails for a deleted original', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValueOnce({
+        ...original,
+        isDeleted: true,
+      });
+
+      await expect(cloneArtifact('requirements/REQ-007.md')).rejects.toThrow(
+        'Artifact not found: REQ-007'
+      );
+      expect(runJournaled).not.toHaveBeenCalled();
+    });
+  });
+});
@@ -0,0 +1,88 @@
+/**
+ * Artifact History Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { describeChange, diffFrontmatter, getArtifactHistory } from '../artifactHistoryService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    getHistory: vi.fn(),
+    readFileAtCommit: vi.fn(),
+  },
+}));
+
+const version = (status: string, title = 'Login') =>
+  `---\nid: REQ-001\ntitle: ${title}\nstatus: ${status}\nrevision: "01"\n---\n\n# ${title}`;
+
+describe('artifactHistoryService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('diffFrontmatter', () => {
+    it('should report changed tracked fields only', () => {
+      expect(
+        diffFrontmatter(
+          { status: 'draft', title: 'A', text: 'x' },
+          { status: 'approved', title: 'A', text: 'y' }
+        )
+      ).toEqual([{ field: 'status', from: 'draft', to: 'approved' }]);
+    });
+  });
+
+  describe('describeChange', () => {
+    it('should describe set, changed and cleared values', () => {
+      expect(describeChange({ field: 'status', from: 'draft', to: 'approved' })).toBe(
+        'status changed from draft to approved'
+      );
+      expect(describeChange({ field: 'priority', from: null, to: 'high' })).toBe(
+        'priority set to high'
+      );
+      expect(describeChange({ field: 'priority', from: 'high', to: null })).toBe(
+        'priority cleared (was high)'
+      );
+    });
+  });
+
+  describe('getArtifactHistory', () => {
+    it('should return each version with its changes, newest first', async () => {
+      const contents: Record<string, string | null> = {
+        c3: null,
+        c2: version('approved', 'User login'),
+        c1: version('draft'),
+      };
+      vi.mocked(realGitService.getHistory).mockResolvedValue(
+        ['c3', 'c2', 'c1'].map((hash, i) => ({
+          hash,
+          message: `Commit ${hash}`,
+          author: 'Tracyfy User',
+          timestamp: 3000 - i * 1000,
+        }))
+      );
+      vi.mocked(realGitService.readFileAtCommit).mockImplementation(
+        async (_path, hash) => contents[hash]
+      );
+
+      const history = await getArtifactHistory('requirements/REQ-001.md');
+
+      expect(realGitService.getHistory).toHaveBeenCalledWith('requirements/REQ-001.md', undefined);
+      expect(history.map((v) => [v.commit.hash, v.exists, v.status])).toEqual([
+        ['c3', false, undefined],
+        ['c2', true, 'approved'],
+        ['c1', true, 'draft'],
+      ]);
+      expect(history[1].title).toBe('User login');
+      expect(history[1].changes).toEqual([
+        { field: 'title', from: 'Login', to: 'User login' },
+        { field: 'status', from: 'draft', to: 'approved' },
+      ]);
+      expect(history[2].changes.map(describeChange)).toEqual([
+        'title set to Login',
+        'status set to draft',
+        'revision set to 01',
+      ]);
+    });
+  });
+});
@@ -0,0 +1,117 @@
+/**
+ * Artifact Lock Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { artifactLockService, isLockStale, DEFAULT_LOCK_TTL_MS } from '../artifactLockService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    deleteFile: vi.fn(),
+    listFiles: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+    directoryExists: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn() },
+}));
+
+describe('artifactLockService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.useFakeTimers();
+    vi.setSystemTime(1_000_000);
+    files = { 'current-user.md': 'USER-001' };
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content as string;
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('locks an artifact without committing the lock record', async () => {
+    const lock = await artifactLockService.lockArtifact('requirements/REQ-001.md', 'USER-002');
+
+    expect(lock).toEqual({
+      artifactId: 'REQ-001',
+      path: 'requirements/REQ-001.md',
+      owner: 'USER-002',
+      acquiredAt: 1_000_000,
+      expiresAt: 1_000_000 + DEFAULT_LOCK_TTL_MS,
+    });
+    expect(files['.locks/REQ-001.json']).toBeDefined();
+    expect(realGitService.commitFile).not.toHaveBeenCalled();
+    expect(await artifactLockService.listLocks()).toEqual([lock]);
+  });
+
+  it('refuses locks held by others and refreshes own locks', async () => {
+    await artifactLockService.lockArtifact('requirements/REQ-001.md', 'USER-002');
+
+    await expect(
+      artifactLockService.lockArtifact('requirements/REQ-001.md', 'USER-003')
+    ).rejects.toThrow('REQ-001 is locked by USER-002');
+
+    vi.setSystemTime(1_000_500);
+    const refreshed = await artifactLockService.lockArtifact('requirements/REQ-001.md', 'USER-002');
+    expect(refreshed).toMatchObject({
+      acquiredAt: 1_000_000,
+      expiresAt: 1_000_500 + DEFAULT_LOCK_TTL_MS,
+    });
+  });
+
+  it('treats expired locks as released', async () => {
+    const lock = await artifactLockService.lockArtifact('usecases/UC-001.md', 'USER-002', 1000);
+    vi.setSystemTime(1_001_000);
+
+    expect(isLockStale(lock)).toBe(true);
+    expect(await artifactLockService.getLock('usecases/UC-001.md')).toBeNull();
+    const taken = await artifactLockService.lockArtifact('usecases/UC-001.md', 'USER-003');
+    expect(taken.owner).toBe('USER-003');
+  });
+
+  it('only lets the owner unlock unless forced', async () => {
+    const path = 'requirements/REQ-001.md';
+    await artifactLockService.lockArtifact(path, 'USER-002');
+
+    await expect(artifactLockService.unlockArtifact(path, 'USER-003')).rejects.toThrow(
+      'locked by USER-002'
+    );
+    expect(await artifactLockService.unlockArtifact(path, 'USER-003', true)).toBe(true);
+    expect(await artifactLockService.unlockArtifact(path, 'USER-002')).toBe(false);
+  });
+
+  it('reports conflicting locks for the current user', async () => {
+    await artifactLockService.lockArtifact('requirements/REQ-001.md', 'USER-002');
+
+    expect(await artifactLockService.getConflictingLock('REQ-001')).toMatchObject({
+      owner: 'USER-002',
+    });
+    expect(await artifactLockService.getConflictingLock('REQ-001', 'USER-002')).toBeNull();
+    expect(await artifactLockService.getConflictingLock('REQ-002')).toBeNull();
+  });
+
+  it('rejects paths that are not artifacts', async () => {
+    await expect(artifactLockService.lockArtifact('notes/todo.md', 'USER-002')).rejects.toThrow(
+      'Not an artifact path'
+    );
+  });
+});
@@ -0,0 +1,217 @@
+/**
+ * Artifact Merge Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  mergeArtifacts,
+  mergeTextFields,
+  retargetLinkedArtifacts,
+  validateArtifactMerge,
+} from '../artifactMergeService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import { runJournaled } from '../writeJournalService';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+      projects: service(),
+    },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), save: vi.fn(), delete: vi.fn() },
+}));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+const link = (id: string, sourceId: string, targetId: string, type = 'verifies') => ({
+  id,
+  sourceId,
+  targetId,
+  type,
+  projectIds: [],
+  dateCreated: 1,
+  lastModified: 1,
+  revision: '01',
+});
+
+describe('artifactMergeService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('validateArtifactMerge', () => {
+    it('rejects merging an artifact into itself or into another type', () => {
+      expect(validateArtifactMerge('REQ-001', 'REQ-001')).toContain('into itself');
+      expect(validateArtifactMerge('REQ-001', 'TC-001')).toContain('same type');
+      expect(validateArtifactMerge('REQ-001', 'REQ-002')).toBeNull();
+    });
+  });
+
+  describe('mergeTextFields', () => {
+    it('appends differing text and keeps the target title', () => {
+      const updates = mergeTextFields(
+        { id: 'REQ-001', title: 'Login', text: 'Users log in.', rationale: '', lastModified: 1 },
+        {
+          id: 'REQ-002',
+          title: 'Sign in',
+          text: 'Users sign in with SSO.',
+          rationale: 'Security',
+          description: 'Users log in.',
+          lastModified: 1,
+        }
+      );
+
+      expect(updates).toEqual({
+        text: 'Users log in.\n\nUsers sign in with SSO.',
+        rationale: 'Security',
+        description: 'Users log in.',
+      });
+    });
+
+    it('skips text the target already contains', () => {
+      expect(
+        mergeTextFields(
+          { id: 'REQ-001', text: 'Users log in. Sessions expire.', lastModified: 1 },
+          { id: 'REQ-002', text: 'Sessions expire.', lastModified: 1 }
+        )
+      ).toEqual({});
+    });
+  });
+
+  describe('retargetLinkedArtifacts', () => {
+    it('points entries at the target and drops self-links and duplicates', () => {
+      expect(
+        retargetLinkedArtifacts(
+          [
+            { targetId: 'REQ-002', type: 'relates_to' },
+            { targetId: 'REQ-001', type: 'relates_to' },
+            { targetId: 'TC-001', type: 'verified_by' },
+          ],
+          'TC-001',
+          'REQ-001',
+          'REQ-002'
+        )
+      ).toEqual([{ targetId: 'REQ-001', type: 'relates_to' }]);
+    });
+  });
+
+  describe('mergeArtifacts', () => {
+    beforeEach(() => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockImplementation(async (id: string) =>
+        id === 'REQ-001'
+          ? {
+              id: 'REQ-001',
+              title: 'Login',
+              text: 'Users log in.',
+              tags: ['auth'],
+              revision: '02',
+              lastModified: 1,
+            }
+          : {
+              id: 'REQ-002',
+              title: 'Sign in',
+              text: 'Users sign in with SSO.',
+              tags: ['auth', 'sso'],
+              aliases: ['REQ-009'],
+              revision: '01',
+              lastModified: 1,
+            }
+      );
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        { id: 'REQ-003', parentId: 'REQ-002', revision: '01', lastModified: 1 },
+      ]);
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([
+        {
+          id: 'TC-001',
+          linkedArtifacts: [
+            { targetId: 'REQ-001', type: 'verifies' },
+            { targetId: 'REQ-002', type: 'verifies' },
+          ],
+          revision: '01',
+          lastModified: 1,
+        },
+      ]);
+      vi.mocked(ALL_ARTIFACT_SERVICES.projects.loadAll).mockResolvedValue([
+        { id: 'PROJ-001', requirementIds: ['REQ-001', 'REQ-002'], lastModified: 1 },
+        { id: 'PROJ-002', requirementIds: ['REQ-004'], lastModified: 1 },
+      ]);
+      vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+        link('LINK-001', 'TC-001', 'REQ-001'),
+        link('LINK-002', 'TC-001', 'REQ-002'),
+        link('LINK-003', 'TC-002', 'REQ-002'),
+        link('LINK-004', 'REQ-001', 'REQ-002', 'relates_to'),
+      ]);
+    });
+
+    it('combines the artifacts and tombstones the source', async () => {
+      await mergeArtifacts('REQ-001', 'REQ-002');
+
+      const save = vi.mocked(ALL_ARTIFACT_SERVICES.requirements.save);
+      expect(save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'REQ-001',
+          title: 'Login',
+          text: 'Users log in.\n\nUsers sign in with SSO.',
+          tags: ['auth', 'sso'],
+          aliases: ['REQ-002', 'REQ-009'],
+          revision: '03',
+        })
+      );
+      expect(save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'REQ-002', isDeleted: true, linkedArtifacts: [] })
+      );
+    });
+
+    it('moves links and references to the target in one commit', async () => {
+      const result = await mergeArtifacts('REQ-001', 'REQ-002');
+
+      expect(result.movedLinks).toEqual(['LINK-003']);
+      expect(result.removedLinks).toEqual(['LINK-002', 'LINK-004']);
+      expect(result.updatedArtifacts).toEqual(['REQ-003', 'TC-001', 'PROJ-001']);
+      expect(diskLinkService.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'LINK-003', sourceId: 'TC-002', targetId: 'REQ-001' })
+      );
+      expect(diskLinkService.delete).toHaveBeenCalledWith('LINK-002');
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'REQ-003', parentId: 'REQ-001' })
+      );
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'TC-001',
+          linkedArtifacts: [{ targetId: 'REQ-001', type: 'verifies' }],
+        })
+      );
+      expect(ALL_ARTIFACT_SERVICES.projects.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'PROJ-001', requirementIds: ['REQ-001'] })
+      );
+      expect(runJournaled).toHaveBeenCalledTimes(1);
+      expect(vi.mocked(runJournaled).mock.calls[0][2]).toBe('Merge REQ-002 into REQ-001');
+    });
+
+    it('fails when the source was already deleted', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValueOnce({ id: 'REQ-001' });
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValueOnce({
+        id: 'REQ-002',
+        isDeleted: true,
+      });
+
+      await expect(mergeArtifacts('REQ-001', 'REQ-002')).rejects.toThrow(
+        'Artifact not found: REQ-002'
+      );
+      expect(runJournaled).not.toHaveBeenCalled();
+    });
+  });
+});
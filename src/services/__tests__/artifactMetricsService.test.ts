@@ -0,0 +1,60 @@
+/**
+ * Artifact Metrics Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { artifactMetricsService } from '../artifactMetricsService';
+import { fileSystemService } from '../fileSystemService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    listFiles: vi.fn(),
+    directoryExists: vi.fn(),
+  },
+}));
+
+describe('artifactMetricsService', () => {
+  const files: Record<string, string> = {
+    'requirements/REQ-001.md': '# Login\n\n## Requirement Text\nThe system shall lock the account.',
+    'requirements/REQ-002.md': '# Logout\n\n## Requirement Text\nThe system logs out users.',
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+  });
+
+  it('computes metrics of a single artifact', async () => {
+    const result = await artifactMetricsService.getArtifactMetrics('requirements/REQ-001.md');
+
+    expect(result.artifactId).toBe('REQ-001');
+    expect(result.metrics).toMatchObject({ wordCount: 6, sentenceCount: 1 });
+    expect(result.metrics.headings.map((h) => h.text)).toEqual(['Login', 'Requirement Text']);
+  });
+
+  it('rejects invalid and missing paths', async () => {
+    await expect(artifactMetricsService.getArtifactMetrics('notes/REQ-001.md')).rejects.toThrow(
+      'Not a measurable artifact path'
+    );
+    await expect(
+      artifactMetricsService.getArtifactMetrics('requirements/REQ-404.md')
+    ).rejects.toThrow('Artifact not found');
+  });
+
+  it('aggregates metrics per type', async () => {
+    const [requirements, usecases] = await artifactMetricsService.getTypeMetrics([
+      'requirements',
+      'usecases',
+    ]);
+
+    expect(requirements.artifacts.map((a) => a.artifactId)).toEqual(['REQ-001', 'REQ-002']);
+    expect(requirements.aggregate).toMatchObject({ artifactCount: 2, totalWords: 11 });
+    expect(usecases.aggregate).toMatchObject({ artifactCount: 0, totalWords: 0 });
+  });
+});
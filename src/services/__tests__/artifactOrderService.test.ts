@@ -0,0 +1,107 @@
+/**
+ * Artifact Order Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { artifactOrderService, applyOrder } from '../artifactOrderService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    commitFile: vi.fn(),
+  },
+}));
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: {
+      loadAll: vi.fn(),
+    },
+  },
+}));
+
+describe('artifactOrderService', () => {
+  const requirements = [{ id: 'REQ-001' }, { id: 'REQ-002' }, { id: 'REQ-003' }, { id: 'REQ-010' }];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue(requirements);
+    vi.mocked(fileSystemService.readFile).mockResolvedValue(JSON.stringify(['REQ-003', 'REQ-001']));
+  });
+
+  const writtenOrder = () =>
+    JSON.parse(vi.mocked(fileSystemService.writeFile).mock.calls[0][1] as string);
+
+  describe('applyOrder', () => {
+    it('puts ordered items first and appends the rest by ID', () => {
+      const sorted = applyOrder(requirements, ['REQ-003', 'REQ-404', 'REQ-001']);
+
+      expect(sorted.map((r) => r.id)).toEqual(['REQ-003', 'REQ-001', 'REQ-002', 'REQ-010']);
+    });
+  });
+
+  describe('getOrder', () => {
+    it('reads the order file of the folder', async () => {
+      const order = await artifactOrderService.getOrder('requirements');
+
+      expect(fileSystemService.readFile).toHaveBeenCalledWith('requirements/order.json');
+      expect(order).toEqual(['REQ-003', 'REQ-001']);
+    });
+
+    it('falls back to an empty order for invalid content', async () => {
+      vi.mocked(fileSystemService.readFile).mockResolvedValue('not json');
+
+      expect(await artifactOrderService.getOrder('requirements')).toEqual([]);
+    });
+
+    it('rejects types without manual ordering', async () => {
+      await expect(artifactOrderService.getOrder('links')).rejects.toThrow(
+        'does not support manual ordering'
+      );
+    });
+  });
+
+  describe('reordering', () => {
+    it('moves an artifact to an index and commits the order', async () => {
+      const order = await artifactOrderService.moveToIndex('requirements', 'REQ-010', 1);
+
+      expect(order).toEqual(['REQ-003', 'REQ-010', 'REQ-001', 'REQ-002']);
+      expect(writtenOrder()).toEqual(order);
+      expect(realGitService.commitFile).toHaveBeenCalledWith(
+        'requirements/order.json',
+        'Reorder requirements'
+      );
+    });
+
+    it('moves artifacts up and down', async () => {
+      expect(await artifactOrderService.moveUp('requirements', 'REQ-001')).toEqual([
+        'REQ-001',
+        'REQ-003',
+        'REQ-002',
+        'REQ-010',
+      ]);
+      expect(await artifactOrderService.moveDown('requirements', 'REQ-001')).toEqual([
+        'REQ-003',
+        'REQ-002',
+        'REQ-001',
+        'REQ-010',
+      ]);
+    });
+
+    it('does not write when the position is unchanged', async () => {
+      await artifactOrderService.moveUp('requirements', 'REQ-003');
+
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+    });
+  });
+});
@@ -0,0 +1,184 @@
+/**
+ * Artifact Rename Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  renameArtifactId,
+  replaceArtifactId,
+  validateArtifactRename,
+} from '../artifactRenameService';
+import { baseDiskService } from '../baseDiskService';
+import { idService } from '../idService';
+import { runJournaled } from '../writeJournalService';
+import { getTypeFromId } from '../../constants/artifactConfig';
+
+vi.mock('../baseDiskService', () => ({
+  BaseDiskService: class {},
+  baseDiskService: {
+    exists: vi.fn(),
+    listFiles: vi.fn(),
+    readTextFile: vi.fn(),
+    writeTextFile: vi.fn(),
+    deleteFile: vi.fn(),
+  },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getCounter: vi.fn().mockResolvedValue(14),
+    setCounter: vi.fn(),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../artifactServices', () => ({ ALL_ARTIFACT_SERVICES: {} }));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+const FILES: Record<string, string> = {
+  'requirements/REQ-012.md': '---\nid: "REQ-012"\ntitle: "Timeout"\n---\n# Timeout',
+  'requirements/REQ-013.md':
+    '---\nid: "REQ-013"\nparentId: "REQ-012"\n---\nRefines REQ-012, not REQ-0120. ' +
+    'See [REQ-012](REQ-012.md).',
+  'testcases/TC-001.md': '---\nid: "TC-001"\n---\nChecks the retry.',
+  'links/LINK-004.md':
+    '---\nid: LINK-004\nsourceId: TC-002\ntargetId: REQ-012\ntype: verifies\n---\n' +
+    'Links **TC-002** to **REQ-012** (verifies)',
+  'projects/PROJ-001.md': '---\nid: "PROJ-001"\nrequirementIds: ["REQ-012", "REQ-013"]\n---',
+};
+
+const SIDECAR_FILES: Record<string, string> = {
+  'approvals/REQ-012.json': '[{"artifactId":"REQ-012","reviewer":"Ana","contentHash":"abc"}]',
+  'comments/REQ-012.json': '[{"id":"c1","artifactId":"REQ-012","text":"Clarify REQ-012"}]',
+  'requirements/order.json': '["REQ-013","REQ-012"]',
+  'releases/REL-1.json': '{"id":"REL-1","items":[{"artifactId":"REQ-012"}]}',
+  'testruns/RUN-1.json': '{"id":"RUN-1","results":[{"testCaseId":"TC-001"}]}',
+};
+
+function useFiles(files: Record<string, string>) {
+  vi.mocked(baseDiskService.exists).mockImplementation(async (path) => path in files);
+  vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+    Object.keys(files)
+      .filter((path) => path.startsWith(`${folder}/`))
+      .map((path) => path.slice(folder.length + 1))
+  );
+  vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+}
+
+describe('artifactRenameService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    useFiles(FILES);
+  });
+
+  it('should replace whole mentions of an ID only', () => {
+    expect(
+      replaceArtifactId('REQ-012, REQ-0120, X-REQ-012, (REQ-012.md)', 'REQ-012', 'REQ-7')
+    ).toEqual({ content: 'REQ-7, REQ-0120, X-REQ-012, (REQ-7.md)', count: 2 });
+  });
+
+  it('should require the new ID to keep the type prefix', () => {
+    expect(validateArtifactRename('REQ-012', 'REQ-SYS-012')).toBeNull();
+    expect(validateArtifactRename('REQ-012', 'SYS-REQ-012')).toBeNull();
+    expect(validateArtifactRename('REQ-012', 'TC-012')).toBe(
+      'New ID TC-012 uses the prefix of another type (Test Case)'
+    );
+    expect(validateArtifactRename('REQ-012', 'REQ 12')).toBe(
+      'New ID must be letters and digits in dash-separated parts: REQ 12'
+    );
+    expect(validateArtifactRename('REQ-012', 'REQ-012')).toBe('REQ-012 already has this ID');
+  });
+
+  it('should move the file and rewrite every reference in one commit', async () => {
+    const result = await renameArtifactId('REQ-012', 'REQ-SYS-012');
+
+    expect(result).toEqual({
+      oldId: 'REQ-012',
+      newId: 'REQ-SYS-012',
+      oldPath: 'requirements/REQ-012.md',
+      newPath: 'requirements/REQ-SYS-012.md',
+      updatedFiles: ['requirements/REQ-013.md', 'links/LINK-004.md', 'projects/PROJ-001.md'],
+      referenceCount: 7,
+    });
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'requirements/REQ-SYS-012.md',
+      '---\nid: "REQ-SYS-012"\ntitle: "Timeout"\n---\n# Timeout'
+    );
+    expect(baseDiskService.deleteFile).toHaveBeenCalledWith('requirements/REQ-012.md');
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'requirements/REQ-013.md',
+      '---\nid: "REQ-013"\nparentId: "REQ-SYS-012"\n---\nRefines REQ-SYS-012, not REQ-0120. ' +
+        'See [REQ-SYS-012](REQ-SYS-012.md).'
+    );
+    expect(runJournaled).toHaveBeenCalledWith(
+      'Rename REQ-012',
+      expect.any(Function),
+      'Rename REQ-012 to REQ-SYS-012 (7 reference(s) in 3 file(s))'
+    );
+    expect(idService.setCounter).not.toHaveBeenCalled();
+  });
+
+  it('should give an artifact an ID with another prefix and keep its type', async () => {
+    const result = await renameArtifactId('REQ-012', 'SYS-REQ-012');
+
+    expect(result.newPath).toBe('requirements/SYS-REQ-012.md');
+    expect(getTypeFromId('SYS-REQ-012')).toBe('requirements');
+    expect(validateArtifactRename('SYS-REQ-012', 'SYS-REQ-12')).toBeNull();
+  });
+
+  it('should move comments and approvals and rewrite orders and releases', async () => {
+    useFiles({ ...FILES, ...SIDECAR_FILES });
+
+    const result = await renameArtifactId('REQ-012', 'REQ-SYS-012');
+
+    expect(result.updatedFiles).toEqual([
+      'requirements/REQ-013.md',
+      'requirements/order.json',
+      'links/LINK-004.md',
+      'projects/PROJ-001.md',
+      'releases/REL-1.json',
+      'comments/REQ-SYS-012.json',
+      'approvals/REQ-SYS-012.json',
+    ]);
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'comments/REQ-SYS-012.json',
+      '[{"id":"c1","artifactId":"REQ-SYS-012","text":"Clarify REQ-SYS-012"}]'
+    );
+    // Approval records stay as signed
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'approvals/REQ-SYS-012.json',
+      SIDECAR_FILES['approvals/REQ-012.json']
+    );
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'requirements/order.json',
+      '["REQ-013","REQ-SYS-012"]'
+    );
+    expect(baseDiskService.writeTextFile).toHaveBeenCalledWith(
+      'releases/REL-1.json',
+      '{"id":"REL-1","items":[{"artifactId":"REQ-SYS-012"}]}'
+    );
+    expect(baseDiskService.writeTextFile).not.toHaveBeenCalledWith(
+      'testruns/RUN-1.json',
+      expect.anything()
+    );
+    expect(baseDiskService.deleteFile).toHaveBeenCalledWith('comments/REQ-012.json');
+    expect(baseDiskService.deleteFile).toHaveBeenCalledWith('approvals/REQ-012.json');
+  });
+
+  it('should raise the counter past a higher numeric ID', async () => {
+    await renameArtifactId('REQ-012', 'REQ-100');
+
+    expect(idService.setCounter).toHaveBeenCalledWith('requirements', 100, true);
+  });
+
+  it('should refuse missing artifacts and taken IDs before writing', async () => {
+    await expect(renameArtifactId('REQ-099', 'REQ-100')).rejects.toThrow(
+      'Artifact not found: REQ-099'
+    );
+    await expect(renameArtifactId('REQ-012', 'REQ-013')).rejects.toThrow('REQ-013 already exists');
+    expect(runJournaled).not.toHaveBeenCalled();
+  });
+});
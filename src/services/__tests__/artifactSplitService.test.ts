@@ -0,0 +1,160 @@
+/**
+ * Artifact Split Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { buildSplitPart, splitArtifact, splitTextAtLines } from '../artifactSplitService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import { idService } from '../idService';
+import { runJournaled } from '../writeJournalService';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+      projects: service(),
+    },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { save: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn((type: string) =>
+      Promise.resolve(type === 'links' ? ['LINK-010', 'LINK-011'] : ['REQ-020', 'REQ-021'])
+    ),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+const original = {
+  id: 'REQ-005',
+  title: 'Payments',
+  text: 'Accept cards.\nRefunds within 5 days.\n\nStore receipts.',
+  rationale: 'Revenue',
+  status: 'approved',
+  approvalDate: 100,
+  priority: 'high',
+  tags: ['billing'],
+  parentId: 'REQ-001',
+  linkedArtifacts: [{ targetId: 'TC-001', type: 'verified_by' }],
+  revision: '04',
+  dateCreated: 1,
+  lastModified: 1,
+};
+
+describe('artifactSplitService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('splitTextAtLines', () => {
+    it('cuts the text before each boundary line', () => {
+      expect(splitTextAtLines('a\nb\n\nc', [3])).toEqual(['a\nb', 'c']);
+      expect(splitTextAtLines('a\nb\nc', [2, 3])).toEqual(['a', 'b', 'c']);
+    });
+
+    it('rejects boundaries out of order, out of range or leaving a part empty', () => {
+      expect(() => splitTextAtLines('a\nb\nc', [])).toThrow('At least one boundary');
+      expect(() => splitTextAtLines('a\nb\nc', [3, 2])).toThrow('increasing lines');
+      expect(() => splitTextAtLines('a\nb\nc', [4])).toThrow('between 2 and 3');
+      expect(() => splitTextAtLines('a\n\nc', [2, 3])).toThrow('Part 2 has no text');
+    });
+  });
+
+  describe('buildSplitPart', () => {
+    it('copies classification but not text, status or links', () => {
+      const part = buildSplitPart(original, 'text', 'REQ-020', 'Cards', 'Accept cards.', 50);
+
+      expect(part).toMatchObject({
+        id: 'REQ-020',
+        title: 'Cards',
+        text: 'Accept cards.',
+        priority: 'high',
+        tags: ['billing'],
+        parentId: 'REQ-001',
+        linkedArtifacts: [],
+        revision: '01',
+        dateCreated: 50,
+      });
+      expect(part.rationale).toBeUndefined();
+      expect(part.status).toBeUndefined();
+      expect(part.approvalDate).toBeUndefined();
+    });
+  });
+
+  describe('splitArtifact', () => {
+    beforeEach(() => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue(original);
+      vi.mocked(ALL_ARTIFACT_SERVICES.projects.loadAll).mockResolvedValue([
+        { id: 'PROJ-001', requirementIds: ['REQ-005'], lastModified: 1 },
+        { id: 'PROJ-002', requirementIds: [], lastModified: 1 },
+      ]);
+    });
+
+    it('creates the parts with links back to the original in one commit', async () => {
+      const split = await splitArtifact('REQ-005', { boundaries: [4] });
+
+      expect(split).toEqual({
+        originalId: 'REQ-005',
+        createdIds: ['REQ-020', 'REQ-021'],
+        linkIds: ['LINK-010', 'LINK-011'],
+        projectIds: ['PROJ-001'],
+      });
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'REQ-020',
+          title: 'Payments (1)',
+          text: 'Accept cards.\nRefunds within 5 days.',
+        })
+      );
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'REQ-021', title: 'Payments (2)', text: 'Store receipts.' })
+      );
+      expect(diskLinkService.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'LINK-011',
+          sourceId: 'REQ-021',
+          targetId: 'REQ-005',
+          type: 'derived_from',
+        })
+      );
+      expect(ALL_ARTIFACT_SERVICES.projects.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'PROJ-001',
+          requirementIds: ['REQ-005', 'REQ-020', 'REQ-021'],
+        })
+      );
+      expect(idService.getNextIds).toHaveBeenCalledWith('requirements', 2, true);
+      expect(runJournaled).toHaveBeenCalledTimes(1);
+      expect(vi.mocked(runJournaled).mock.calls[0][2]).toBe('Split REQ-005 into 2 artifact(s)');
+    });
+
+    it('needs one title per part when titles are given', async () => {
+      await expect(
+        splitArtifact('REQ-005', { boundaries: [4], titles: ['Cards'] })
+      ).rejects.toThrow('Expected 2 titles, got 1');
+      expect(runJournaled).not.toHaveBeenCalled();
+    });
+
+    it('rejects types without a splittable body', async () => {
+      await expect(splitArtifact('DOC-001', { boundaries: [2] })).rejects.toThrow(
+        'cannot be split'
+      );
+    });
+  });
+});
@@ -0,0 +1,173 @@
+/**
+ * Artifact Type Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { artifactTypeService, type CustomArtifactType } from '../artifactTypeService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { getTypeFromId, registerCustomArtifactTypes } from '../../constants/artifactConfig';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    readFileBinary: vi.fn(),
+    writeFile: vi.fn(),
+    writeFileBinary: vi.fn(),
+    deleteFile: vi.fn(),
+    listFiles: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+const SPEC: CustomArtifactType = {
+  key: 'specs',
+  label: 'Specification',
+  idPrefix: 'SPEC',
+  folder: 'specs',
+};
+
+describe('artifactTypeService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerCustomArtifactTypes([]);
+    files = {};
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.readFileBinary).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.writeFileBinary).mockImplementation(async (path, data) => {
+      files[path] = new TextDecoder().decode(data);
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+  });
+
+  describe('createType', () => {
+    it('should register the type and create its folder', async () => {
+      await artifactTypeService.createType(SPEC);
+
+      expect(fileSystemService.getOrCreateDirectory).toHaveBeenCalledWith('specs');
+      expect(await artifactTypeService.listCustomTypes()).toEqual([SPEC]);
+      expect(realGitService.commitFile).toHaveBeenCalledWith(
+        'config/artifact-types.json',
+        'Create artifact type Specification'
+      );
+      expect(getTypeFromId('SPEC-001')).toBe('specs');
+    });
+
+    it('should register the types of a loaded project', async () => {
+      files['config/artifact-types.json'] = JSON.stringify({ types: [SPEC] });
+
+      await artifactTypeService.loadCustomTypes();
+
+      expect(getTypeFromId('SPEC-001')).toBe('specs');
+      expect(getTypeFromId('REQ-001')).toBe('requirements');
+    });
+
+    it('should reject clashes with built-in types', async () => {
+      await expect(artifactTypeService.createType({ ...SPEC, idPrefix: 'REQ' })).rejects.toThrow(
+        'ID prefix already in use: REQ'
+      );
+      await expect(
+        artifactTypeService.createType({ ...SPEC, folder: 'requirements' })
+      ).rejects.toThrow('Folder already in use: requirements');
+      await expect(artifactTypeService.createType({ ...SPEC, key: 'risks' })).rejects.toThrow(
+        'Artifact type already exists: risks'
+      );
+    });
+
+    it('should reject folders used for sidecar files', async () => {
+      for (const folder of ['approvals', 'comments', 'keys', 'testruns']) {
+        await expect(artifactTypeService.createType({ ...SPEC, folder })).rejects.toThrow(
+          `Folder already in use: ${folder}`
+        );
+      }
+    });
+  });
+
+  describe('renameTypeFolder', () => {
+    it('should move files and the counter in one commit', async () => {
+      await artifactTypeService.createType(SPEC);
+      files['specs/SPEC-001.md'] = '# One';
+      files['counters/specs.md'] = '1';
+      vi.clearAllMocks();
+
+      const renamed = await artifactTypeService.renameTypeFolder('specs', 'specifications');
+
+      expect(renamed.folder).toBe('specifications');
+      expect(files['specifications/SPEC-001.md']).toBe('# One');
+      expect(files['counters/specifications.md']).toBe('1');
+      expect(files['specs/SPEC-001.md']).toBeUndefined();
+      expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        [
+          'config/artifact-types.json',
+          'specs/SPEC-001.md',
+          'specifications/SPEC-001.md',
+          'counters/specs.md',
+          'counters/specifications.md',
+        ],
+        'Rename Specification folder to specifications'
+      );
+      expect((await artifactTypeService.listCustomTypes())[0].folder).toBe('specifications');
+      expect(files['.tracyfy/journal.json']).toBeUndefined();
+    });
+
+    it('should restore the files when the commit fails', async () => {
+      await artifactTypeService.createType(SPEC);
+      files['specs/SPEC-001.md'] = '# One';
+      vi.mocked(realGitService.commitFiles).mockRejectedValueOnce(new Error('Secrets detected'));
+
+      await expect(artifactTypeService.renameTypeFolder('specs', 'specifications')).rejects.toThrow(
+        'Secrets detected'
+      );
+
+      expect(files['specs/SPEC-001.md']).toBe('# One');
+      expect(files['specifications/SPEC-001.md']).toBeUndefined();
+      expect((await artifactTypeService.listCustomTypes())[0].folder).toBe('specs');
+      expect(getTypeFromId('SPEC-001')).toBe('specs');
+    });
+
+    it('should refuse to change built-in types', async () => {
+      await expect(artifactTypeService.renameTypeFolder('requirements', 'reqs')).rejects.toThrow(
+        'built-in types cannot be changed'
+      );
+    });
+  });
+
+  describe('deleteType', () => {
+    it('should refuse to delete a folder with files unless forced', async () => {
+      await artifactTypeService.createType(SPEC);
+      files['specs/SPEC-001.md'] = '# One';
+
+      await expect(artifactTypeService.deleteType('specs')).rejects.toThrow(
+        'Folder specs still contains 1 file(s)'
+      );
+
+      await artifactTypeService.deleteType('specs', true);
+
+      expect(files['specs/SPEC-001.md']).toBeUndefined();
+      expect(await artifactTypeService.listCustomTypes()).toEqual([]);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['config/artifact-types.json', 'specs/SPEC-001.md'],
+        'Delete artifact type Specification'
+      );
+    });
+  });
+});
@@ -131,6 +131,21 @@ describe('AssetService', () => {
 
       expect(fileSystemService.readFileBinary).toHaveBeenCalledWith('assets/test.png');
     });
+
+    it('should use the asset protocol in the desktop app', async () => {
+      window.electronAPI = { isElectron: true } as unknown as Window['electronAPI'];
+      try {
+        expect(await assetService.getAssetUrl('./assets/my diagram.png')).toBe(
+          'tracyfy-asset://project/assets/my%20diagram.png'
+        );
+        expect(await assetService.getAssetUrl('assets/a.png', 'f'.repeat(40))).toBe(
+          `tracyfy-asset://commit/${'f'.repeat(40)}/assets/a.png`
+        );
+        expect(fileSystemService.readFileBinary).not.toHaveBeenCalled();
+      } finally {
+        delete (window as unknown as Record<string, unknown>).electronAPI;
+      }
+    });
   });
 
   describe('listAssets', () => {
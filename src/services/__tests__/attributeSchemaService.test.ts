@@ -0,0 +1,106 @@
+/**
+ * Attribute Schema Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  getFormFields,
+  listAttributeValues,
+  setAttributeSchema,
+  validateTypeAttributes,
+} from '../attributeSchemaService';
+import { projectConfigService } from '../projectConfigService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskCustomAttributeService } from '../diskCustomAttributeService';
+import type { AttributeSchema, CustomAttributeDefinition } from '../../types/customAttributes';
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: { requirements: { loadAll: vi.fn() } },
+}));
+
+vi.mock('../diskCustomAttributeService', () => ({
+  diskCustomAttributeService: { getDefinitionsForArtifactType: vi.fn() },
+}));
+
+const SCHEMA: AttributeSchema = {
+  requirements: [
+    { name: 'component', type: 'dropdown', options: ['UI', 'API'], required: true },
+    { name: 'effort', type: 'number' },
+  ],
+};
+
+describe('attributeSchemaService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockResolvedValue(SCHEMA);
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      {
+        id: 'REQ-001',
+        title: 'Login',
+        customAttributes: [{ attributeId: 'component', value: 'UI' }],
+      },
+      { id: 'REQ-002', title: 'Export', customAttributes: [{ attributeId: 'effort', value: 'x' }] },
+      { id: 'REQ-003', title: 'Old', isDeleted: true },
+    ]);
+  });
+
+  it('should store a valid schema in the project config', async () => {
+    await setAttributeSchema(SCHEMA);
+
+    expect(projectConfigService.setSection).toHaveBeenCalledWith(
+      'attribute-schema',
+      SCHEMA,
+      'Update attribute schema'
+    );
+  });
+
+  it('should reject invalid schemas and unsupported types', async () => {
+    await expect(
+      setAttributeSchema({ requirements: [{ name: 'area', type: 'dropdown' }] })
+    ).rejects.toThrow('Invalid attribute schema: requirements.area: dropdown needs allowed values');
+    await expect(setAttributeSchema({ links: [] })).rejects.toThrow(
+      'Artifact type does not support attributes: links'
+    );
+    expect(projectConfigService.setSection).not.toHaveBeenCalled();
+  });
+
+  it('should list form fields from the schema and global definitions', async () => {
+    vi.mocked(diskCustomAttributeService.getDefinitionsForArtifactType).mockResolvedValue([
+      { id: 'ATTR-001', name: 'Owner', type: 'text', appliesTo: ['requirement'] },
+    ] as CustomAttributeDefinition[]);
+
+    const fields = await getFormFields('requirements');
+
+    expect(diskCustomAttributeService.getDefinitionsForArtifactType).toHaveBeenCalledWith(
+      'requirement'
+    );
+    expect(fields.map((f) => [f.attributeId, f.label, f.required, f.source])).toEqual([
+      ['component', 'component', true, 'schema'],
+      ['effort', 'effort', false, 'schema'],
+      ['ATTR-001', 'Owner', false, 'definition'],
+    ]);
+  });
+
+  it('should validate artifacts of a type against the schema', async () => {
+    expect(await validateTypeAttributes('requirements')).toEqual([
+      {
+        artifactId: 'REQ-002',
+        violations: [
+          { attribute: 'component', message: 'component is required' },
+          { attribute: 'effort', message: 'effort must be a number' },
+        ],
+      },
+    ]);
+  });
+
+  it('should list schema attribute values per artifact', async () => {
+    expect(await listAttributeValues('requirements')).toEqual([
+      { id: 'REQ-001', title: 'Login', values: { component: 'UI', effort: null } },
+      { id: 'REQ-002', title: 'Export', values: { component: null, effort: 'x' } },
+    ]);
+  });
+});
@@ -0,0 +1,143 @@
+// @vitest-environment node
+/**
+ * Audit Package Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { auditPackageService, AUDIT_PACKAGE_FORMAT } from '../auditPackageService';
+import { baselineService } from '../baselineService';
+import { approvalService } from '../approvalService';
+import { realGitService } from '../realGitService';
+import { secretService } from '../secretService';
+import type { ProjectBaseline } from '../../types';
+import type { ZipEntry } from '../../utils/zipUtils';
+
+vi.mock('../baselineService', () => ({
+  baselineService: { getBaselineContents: vi.fn() },
+}));
+
+vi.mock('../approvalService', () => ({
+  approvalService: { listApprovals: vi.fn() },
+}));
+
+vi.mock('../traceMatrixService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../traceMatrixService')>()),
+  generateTraceMatrix: vi.fn().mockResolvedValue({
+    fromType: 'requirements',
+    toType: 'testcases',
+    columns: [{ id: 'TC-001', title: 'Login test' }],
+    rows: [
+      {
+        id: 'REQ-001',
+        title: 'Login, "secure"',
+        cells: [{ targetId: 'TC-001', linkTypes: ['verified_by'] }],
+        linkedCount: 1,
+      },
+    ],
+    coveredRows: 1,
+  }),
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { getHistory: vi.fn() },
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn() },
+}));
+
+const baseline = {
+  id: 'bl-1',
+  projectId: 'proj-1',
+  name: '[Demo] 1.0',
+  version: '1.0',
+  timestamp: 2000,
+  tag: 'baseline/proj-1/1.0',
+} as ProjectBaseline;
+
+const encode = (text: string) => new TextEncoder().encode(text);
+
+const unpack = (files: ZipEntry[]) =>
+  new Map(files.map((f) => [f.path, typeof f.data === 'string' ? encode(f.data) : f.data]));
+
+describe('auditPackageService', () => {
+  let secrets: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    secrets = {};
+    vi.mocked(secretService.getSecret).mockImplementation(async (name) => secrets[name] ?? null);
+    vi.mocked(secretService.storeSecret).mockImplementation(async (name, value) => {
+      secrets[name] = value;
+    });
+    vi.mocked(baselineService.getBaselineContents).mockResolvedValue({
+      baseline,
+      artifacts: [
+        {
+          id: 'REQ-001',
+          type: 'requirement',
+          path: 'requirements/REQ-001.md',
+          commitHash: 'c1',
+          content: '# Login',
+          intact: true,
+        },
+      ],
+    });
+    vi.mocked(approvalService.listApprovals).mockResolvedValue([
+      { artifactId: 'REQ-001', reviewer: 'Ann', timestamp: 1000, contentHash: 'h1' },
+      { artifactId: 'REQ-001', reviewer: 'Bob', timestamp: 3000, contentHash: 'h2' },
+    ]);
+    vi.mocked(realGitService.getHistory).mockResolvedValue([
+      { hash: 'c1', message: 'Add login', author: 'Ann', timestamp: 500 },
+    ]);
+  });
+
+  it('should bundle the evidence with a hash manifest', async () => {
+    const pkg = await auditPackageService.buildAuditPackage('1.0', 'proj-1');
+
+    expect(pkg.manifest.format).toBe(AUDIT_PACKAGE_FORMAT);
+    expect(pkg.manifest.files.map((f) => f.path)).toEqual([
+      'artifacts/requirements/REQ-001.md',
+      'baseline.json',
+      'trace-matrix.csv',
+      'approvals.json',
+      'audit-log.json',
+    ]);
+    const files = Object.fromEntries(pkg.files.map((f) => [f.path, f.data as string]));
+    expect(files['trace-matrix.csv']).toBe(
+      'ID,Title,TC-001\nREQ-001,"Login, ""secure""",Verified by\n'
+    );
+    expect(JSON.parse(files['approvals.json'])['REQ-001']).toEqual([
+      expect.objectContaining({ reviewer: 'Ann' }),
+    ]);
+    expect(realGitService.getHistory).toHaveBeenCalledWith('requirements/REQ-001.md', 1000, 'c1');
+    expect(Array.from(pkg.archive.slice(0, 4))).toEqual([0x50, 0x4b, 0x03, 0x04]);
+  });
+
+  it('should produce a package that verifies and reuse the signing key', async () => {
+    const first = await auditPackageService.buildAuditPackage('1.0', 'proj-1');
+    const second = await auditPackageService.buildAuditPackage('1.0', 'proj-1');
+
+    expect(await auditPackageService.verifyAuditPackage(unpack(first.files))).toEqual([]);
+    expect(second.manifest.signingKey).toBe(first.manifest.signingKey);
+    expect(secretService.storeSecret).toHaveBeenCalledTimes(1);
+  });
+
+  it('should detect tampered files and manifests', async () => {
+    const { files } = await auditPackageService.buildAuditPackage('1.0', 'proj-1');
+    const unpacked = unpack(files);
+
+    const editedFile = new Map(unpacked);
+    editedFile.set('artifacts/requirements/REQ-001.md', encode('# Changed'));
+    expect(await auditPackageService.verifyAuditPackage(editedFile)).toEqual([
+      'Hash mismatch: artifacts/requirements/REQ-001.md',
+    ]);
+
+    const manifest = new TextDecoder().decode(unpacked.get('manifest.json'));
+    const editedManifest = new Map(unpacked);
+    editedManifest.set('manifest.json', encode(manifest.replace('1.0', '2.0')));
+    expect(await auditPackageService.verifyAuditPackage(editedManifest)).toEqual([
+      'Manifest signature is invalid',
+    ]);
+  });
+});
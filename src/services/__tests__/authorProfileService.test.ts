@@ -0,0 +1,118 @@
+// @vitest-environment node
+/**
+ * Author Profile Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { authorProfileService, DEFAULT_AUTHOR, getInitials } from '../authorProfileService';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../appSettingsService';
+import { diskProjectService } from '../diskProjectService';
+import { secretService } from '../secretService';
+import type { AppSettings } from '../../types';
+
+vi.mock('../appSettingsService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../appSettingsService')>()),
+  appSettingsService: { getAppSettings: vi.fn(), updateAppSettings: vi.fn() },
+}));
+
+vi.mock('../diskProjectService', () => ({
+  diskProjectService: { getCurrentProjectId: vi.fn() },
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+describe('authorProfileService', () => {
+  let settings: AppSettings;
+  let secrets: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    settings = { ...DEFAULT_APP_SETTINGS };
+    secrets = {};
+    vi.mocked(appSettingsService.getAppSettings).mockImplementation(async () => settings);
+    vi.mocked(appSettingsService.updateAppSettings).mockImplementation(async (patch) => {
+      settings = { ...settings, ...patch } as AppSettings;
+      return settings;
+    });
+    vi.mocked(secretService.getSecret).mockImplementation(async (name) => secrets[name] ?? null);
+    vi.mocked(secretService.storeSecret).mockImplementation(async (name, value) => {
+      secrets[name] = value;
+    });
+    vi.mocked(diskProjectService.getCurrentProjectId).mockResolvedValue('proj-1');
+  });
+
+  it('should derive initials from a name', () => {
+    expect(getInitials('Ann-Marie Smith')).toBe('AMS');
+    expect(getInitials('Åsa Öberg')).toBe('ÅÖ');
+  });
+
+  it('should add profiles and update them by ID', async () => {
+    await authorProfileService.saveAuthorProfile({
+      id: 'work',
+      name: 'Ann Smith',
+      email: 'ann@corp.example',
+    });
+    await authorProfileService.saveAuthorProfile({
+      id: 'work',
+      name: 'Ann Smith',
+      email: 'ann.smith@corp.example',
+      initials: 'AS1',
+    });
+
+    expect(await authorProfileService.listAuthorProfiles()).toEqual([
+      { id: 'work', name: 'Ann Smith', email: 'ann.smith@corp.example', initials: 'AS1' },
+    ]);
+  });
+
+  it('should use the profile selected for the current project', async () => {
+    await authorProfileService.saveAuthorProfile({ id: 'work', name: 'Ann', email: 'a@corp.io' });
+    await authorProfileService.saveAuthorProfile({ id: 'oss', name: 'ann', email: 'a@oss.io' });
+    await authorProfileService.selectAuthorProfile('proj-1', 'work');
+    await authorProfileService.selectAuthorProfile('proj-2', 'oss');
+
+    expect((await authorProfileService.getActiveAuthorProfile())?.id).toBe('work');
+    expect((await authorProfileService.getActiveAuthorProfile('proj-2'))?.id).toBe('oss');
+
+    await authorProfileService.selectAuthorProfile('proj-1', null);
+    expect(await authorProfileService.getActiveAuthorProfile()).toBeNull();
+    expect(settings.activeAuthorProfiles).toEqual({ 'proj-2': 'oss' });
+    await expect(authorProfileService.selectAuthorProfile('proj-1', 'home')).rejects.toThrow(
+      'Author profile not found: home'
+    );
+  });
+
+  it('should commit as the active profile', async () => {
+    expect(await authorProfileService.getCommitAuthor()).toEqual(DEFAULT_AUTHOR);
+
+    await authorProfileService.saveAuthorProfile({ id: 'work', name: 'Ann', email: 'a@corp.io' });
+    await authorProfileService.selectAuthorProfile('proj-1', 'work');
+
+    expect(await authorProfileService.getCommitAuthor()).toEqual({
+      name: 'Ann',
+      email: 'a@corp.io',
+    });
+    expect(await authorProfileService.getCommitAuthor('Bob')).toEqual({
+      name: 'Bob',
+      email: DEFAULT_AUTHOR.email,
+    });
+  });
+
+  it('should keep signing keys in the secret store and remove them with the profile', async () => {
+    await authorProfileService.saveAuthorProfile({ id: 'work', name: 'Ann', email: 'a@corp.io' });
+    await authorProfileService.selectAuthorProfile('proj-1', 'work');
+
+    const profile = await authorProfileService.createProfileSigningKey('work');
+    const key = await authorProfileService.getProfileSigningKey(profile);
+
+    expect(profile.publicKey).toContain(`minisign public key ${profile.signingKeyId}`);
+    expect(key?.keyId).toBe(profile.signingKeyId);
+    expect(JSON.stringify(settings)).not.toContain(key!.privateKey);
+
+    await authorProfileService.deleteAuthorProfile('work');
+    expect(secretService.deleteSecret).toHaveBeenCalledWith('author-signing-key-work');
+    expect(settings.authorProfiles).toEqual([]);
+    expect(settings.activeAuthorProfiles).toEqual({});
+  });
+});
@@ -3,6 +3,7 @@ import { BaseArtifactService } from '../baseArtifactService';
 import type { ArtifactSerializer } from '../baseArtifactService';
 import { fileSystemService } from '../fileSystemService';
 import { realGitService } from '../realGitService';
+import { checkPermission } from '../permissionService';
 
 // Mock the services
 vi.mock('../fileSystemService', () => ({
@@ -11,6 +12,8 @@ vi.mock('../fileSystemService', () => ({
     writeFile: vi.fn(),
     deleteFile: vi.fn(),
     listFiles: vi.fn(),
+    statFiles: vi.fn(),
+    getRootPath: vi.fn(() => '/project'),
     getOrCreateDirectory: vi.fn(),
     directoryExists: vi.fn(),
   },
@@ -22,6 +25,10 @@ vi.mock('../realGitService', () => ({
   },
 }));
 
+vi.mock('../permissionService', () => ({
+  checkPermission: vi.fn(),
+}));
+
 // No need to mock ARTIFACT_CONFIG, we use the real one for fidelity
 
 interface TestItem {
@@ -39,6 +46,7 @@ describe('BaseArtifactService', () => {
 
   beforeEach(() => {
     vi.clearAllMocks();
+    vi.mocked(fileSystemService.statFiles).mockResolvedValue({});
     service = new BaseArtifactService<TestItem>('requirements', mockSerializer);
   });
 
@@ -65,6 +73,36 @@ describe('BaseArtifactService', () => {
         'feat: add test requirement'
       );
     });
+
+    it('should warn but still save when another user holds a lock', async () => {
+      const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+      const lock = { artifactId: 'REQ-003', owner: 'USER-002', expiresAt: Date.now() + 60_000 };
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['REQ-003.json']);
+      vi.mocked(fileSystemService.readFile).mockImplementation(async (path) =>
+        path === '.locks/REQ-003.json' ? JSON.stringify(lock) : 'USER-001'
+      );
+
+      await service.save({ id: 'REQ-003', name: 'Locked' });
+
+      expect(warn).toHaveBeenCalledWith(expect.stringContaining('locked by USER-002'));
+      expect(fileSystemService.writeFile).toHaveBeenCalled();
+      warn.mockRestore();
+      vi.mocked(fileSystemService.listFiles).mockReset();
+      vi.mocked(fileSystemService.readFile).mockReset();
+    });
+
+    it('should refuse to save or delete without the role for the action', async () => {
+      vi.mocked(checkPermission).mockRejectedValue(new Error('may not edit'));
+
+      await expect(service.save({ id: 'REQ-001', name: 'Test' })).rejects.toThrow('may not edit');
+      await expect(service.delete('REQ-001')).rejects.toThrow('may not edit');
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+      expect(fileSystemService.deleteFile).not.toHaveBeenCalled();
+
+      vi.mocked(checkPermission).mockReset();
+      await service.save({ id: 'REQ-001', name: 'Test' }, undefined, 'approve');
+      expect(checkPermission).toHaveBeenCalledWith('approve');
+    });
   });
 
   describe('delete', () => {
@@ -124,6 +162,31 @@ describe('BaseArtifactService', () => {
       expect(results).toContainEqual(item2);
     });
 
+    it('should only re-parse files that changed since the last load', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['REQ-001.md', 'REQ-002.md']);
+      vi.mocked(fileSystemService.readFile).mockImplementation(async (path) =>
+        JSON.stringify({ id: path.slice(13, 20), name: 'Item' })
+      );
+      const stats = {
+        'requirements/REQ-001.md': { mtime: 1000, size: 40 },
+        'requirements/REQ-002.md': { mtime: 1000, size: 40 },
+      };
+      vi.mocked(fileSystemService.statFiles).mockImplementation(async () => ({ ...stats }));
+
+      await service.loadAll();
+      expect(mockSerializer.deserialize).toHaveBeenCalledTimes(2);
+
+      vi.mocked(mockSerializer.deserialize).mockClear();
+      vi.mocked(fileSystemService.readFile).mockClear();
+      stats['requirements/REQ-002.md'] = { mtime: 2000, size: 41 };
+      const results = await service.loadAll();
+
+      expect(results.map((item) => item.id)).toEqual(['REQ-001', 'REQ-002']);
+      expect(fileSystemService.readFile).toHaveBeenCalledTimes(1);
+      expect(fileSystemService.readFile).toHaveBeenCalledWith('requirements/REQ-002.md');
+      expect(mockSerializer.deserialize).toHaveBeenCalledTimes(1);
+    });
+
     it('should handle empty directory gracefully', async () => {
       vi.mocked(fileSystemService.listFiles).mockRejectedValue(new Error('ENOENT'));
       const results = await service.loadAll();
@@ -0,0 +1,156 @@
+/**
+ * Base Disk Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { baseDiskService, setEncryptionKey } from '../baseDiskService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { generateEncryptionKey } from '../../utils/encryptionUtils';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    readFileBinary: vi.fn(),
+    writeFile: vi.fn(),
+    writeFileBinary: vi.fn(),
+    deleteFile: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn(), readFileAtCommit: vi.fn() },
+}));
+
+describe('baseDiskService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = { 'requirements/REQ-001.md': 'old 1' };
+    vi.mocked(fileSystemService.readFileBinary).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      if (path.includes('FAIL')) throw new Error('Disk full');
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.writeFileBinary).mockImplementation(async (path, data) => {
+      files[path] = new TextDecoder().decode(data);
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+  });
+
+  describe('writeTextFilesAndCommit', () => {
+    it('should write all files and commit them once', async () => {
+      await baseDiskService.writeTextFilesAndCommit(
+        [
+          { path: 'requirements/REQ-001.md', content: 'new 1' },
+          { path: 'requirements/REQ-002.md', content: 'new 2' },
+        ],
+        'Bulk status change'
+      );
+
+      expect(files).toEqual({
+        'requirements/REQ-001.md': 'new 1',
+        'requirements/REQ-002.md': 'new 2',
+      });
+      expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md', 'requirements/REQ-002.md'],
+        'Bulk status change'
+      );
+    });
+
+    it('should roll back written files when a write fails', async () => {
+      await expect(
+        baseDiskService.writeTextFilesAndCommit(
+          [
+            { path: 'requirements/REQ-001.md', content: 'new 1' },
+            { path: 'requirements/REQ-002.md', content: 'new 2' },
+            { path: 'requirements/FAIL.md', content: 'x' },
+          ],
+          'Bulk status change'
+        )
+      ).rejects.toThrow('Disk full');
+
+      expect(files).toEqual({ 'requirements/REQ-001.md': 'old 1' });
+      expect(realGitService.commitFiles).not.toHaveBeenCalled();
+    });
+
+    it('should reject duplicate paths before writing', async () => {
+      await expect(
+        baseDiskService.writeTextFilesAndCommit(
+          [
+            { path: 'requirements/REQ-001.md', content: 'a' },
+            { path: 'requirements/REQ-001.md', content: 'b' },
+          ],
+          'Duplicate'
+        )
+      ).rejects.toThrow('Duplicate path in batch write: requirements/REQ-001.md');
+
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('encryption at rest', () => {
+    afterEach(() => setEncryptionKey(null));
+
+    it('should encrypt artifact files and read them back transparently', async () => {
+      setEncryptionKey(generateEncryptionKey());
+
+      await baseDiskService.writeTextFile('requirements/REQ-002.md', 'secret spec');
+      await baseDiskService.writeTextFile('projects/PROJ-001.md', 'structure');
+
+      expect(files['requirements/REQ-002.md']).toMatch(/^tracyfy-encrypted:v1:/);
+      expect(files['projects/PROJ-001.md']).toBe('structure');
+      expect(await baseDiskService.readTextFile('requirements/REQ-002.md')).toBe('secret spec');
+      // Plaintext files written before encryption was enabled still read
+      expect(await baseDiskService.readTextFile('requirements/REQ-001.md')).toBe('old 1');
+    });
+
+    it('should encrypt comment sidecars but not approvals', async () => {
+      setEncryptionKey(generateEncryptionKey());
+      const comments = [{ id: 'c1', text: 'The limit is wrong' }];
+
+      await baseDiskService.writeJsonFile('comments/REQ-002.json', comments);
+      await baseDiskService.writeJsonFile('approvals/REQ-002.json', []);
+
+      expect(files['comments/REQ-002.json']).toMatch(/^tracyfy-encrypted:v1:/);
+      expect(files['approvals/REQ-002.json']).toBe('[]');
+      expect(await baseDiskService.readJsonFile('comments/REQ-002.json')).toEqual(comments);
+    });
+
+    it('should refuse to read encrypted files without the key', async () => {
+      setEncryptionKey(generateEncryptionKey());
+      await baseDiskService.writeTextFile('requirements/REQ-002.md', 'secret spec');
+      setEncryptionKey(null);
+
+      await expect(baseDiskService.readTextFile('requirements/REQ-002.md', '')).rejects.toThrow(
+        'requirements/REQ-002.md is encrypted and no encryption key is loaded'
+      );
+    });
+
+    it('should decrypt files read from history', async () => {
+      setEncryptionKey(generateEncryptionKey());
+      await baseDiskService.writeTextFile('requirements/REQ-002.md', '---\nstatus: draft\n---\n');
+      const committed: Record<string, string> = {
+        old: 'plain',
+        new: files['requirements/REQ-002.md'],
+      };
+      vi.mocked(realGitService.readFileAtCommit).mockImplementation(
+        async (_path, hash) => committed[hash] ?? null
+      );
+
+      const read = (hash: string) =>
+        baseDiskService.readTextFileAtCommit('requirements/REQ-002.md', hash);
+      expect(await read('new')).toBe('---\nstatus: draft\n---\n');
+      expect(await read('old')).toBe('plain');
+      expect(await read('missing')).toBeNull();
+    });
+  });
+});
@@ -0,0 +1,168 @@
+/**
+ * Baseline Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { baselineService, getBaselineTagName } from '../baselineService';
+import { diskBaselineService } from '../diskBaselineService';
+import { projectService } from '../artifactServices';
+import { realGitService } from '../realGitService';
+import { baseDiskService } from '../baseDiskService';
+import { checkPermission } from '../permissionService';
+import { computeContentHash } from '../../utils/contentHashUtils';
+import type { ProjectBaseline } from '../../types';
+
+vi.mock('../diskBaselineService', () => ({
+  diskBaselineService: { loadBaselines: vi.fn(), saveBaseline: vi.fn(), deleteBaseline: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  projectService: { load: vi.fn() },
+}));
+
+vi.mock('../permissionService', () => ({
+  checkPermission: vi.fn(),
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { getHistory: vi.fn(), createTag: vi.fn() },
+}));
+
+vi.mock('../baseDiskService', () => ({
+  BaseDiskService: class {},
+  baseDiskService: { readTextFileAtCommit: vi.fn() },
+}));
+
+const files: Record<string, string> = {
+  'requirements/REQ-001.md': '# REQ-001',
+  'testcases/TC-001.md': '# TC-001',
+};
+
+describe('baselineService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(diskBaselineService.loadBaselines).mockResolvedValue([]);
+    vi.mocked(projectService.load).mockResolvedValue({
+      id: 'proj-1',
+      name: 'Project',
+      requirementIds: ['REQ-001', 'REQ-002'],
+      testCaseIds: ['TC-001'],
+    } as unknown as Awaited<ReturnType<typeof projectService.load>>);
+    vi.mocked(realGitService.getHistory).mockImplementation(async (path) =>
+      path && path in files
+        ? [{ hash: `c-${path}`, message: 'm', author: 'a', timestamp: 1 }]
+        : []
+    );
+    vi.mocked(baseDiskService.readTextFileAtCommit).mockImplementation(
+      async (path) => files[path] ?? null
+    );
+  });
+
+  describe('createBaseline', () => {
+    it('should record committed artifacts with a hash manifest and tag it', async () => {
+      const baseline = await baselineService.createBaseline('Release 1', 'First', {
+        projectId: 'proj-1',
+        version: '1.0',
+        approver: 'Alice',
+      });
+
+      expect(baseline.artifactCommits).toEqual({
+        'REQ-001': { commitHash: 'c-requirements/REQ-001.md', type: 'requirement' },
+        'TC-001': { commitHash: 'c-testcases/TC-001.md', type: 'testcase' },
+      });
+      expect(baseline.manifest?.['REQ-001']).toBe(await computeContentHash('# REQ-001'));
+      expect(baseline.approver).toBe('Alice');
+      expect(baseline.tag).toBe(getBaselineTagName('proj-1', '1.0'));
+      expect(diskBaselineService.saveBaseline).toHaveBeenCalledWith(baseline);
+      expect(realGitService.createTag).toHaveBeenCalledWith(
+        'baseline/proj-1/1.0',
+        expect.stringContaining(`Baseline-Id: ${baseline.id}`)
+      );
+    });
+
+    it('should report changes against the previous baseline', async () => {
+      vi.mocked(diskBaselineService.loadBaselines).mockResolvedValue([
+        {
+          version: '0.9',
+          artifactCommits: { 'REQ-001': { commitHash: 'old', type: 'requirement' } },
+          removedArtifacts: [],
+          addedArtifacts: [],
+        } as unknown as ProjectBaseline,
+      ]);
+
+      const baseline = await baselineService.createBaseline('Release 1', '', {
+        projectId: 'proj-1',
+        version: '1.0',
+      });
+
+      expect(baseline.addedArtifacts).toEqual(['TC-001']);
+      expect(baseline.removedArtifacts).toEqual([]);
+    });
+
+    it('should reject duplicate and invalid versions', async () => {
+      vi.mocked(diskBaselineService.loadBaselines).mockResolvedValue([
+        { version: '1.0', artifactCommits: {} } as unknown as ProjectBaseline,
+      ]);
+      const options = { projectId: 'proj-1', version: '1.0' };
+
+      await expect(baselineService.createBaseline('R', '', options)).rejects.toThrow(
+        'already exists'
+      );
+      await expect(
+        baselineService.createBaseline('R', '', { ...options, version: '../x' })
+      ).rejects.toThrow('Invalid baseline version');
+      expect(realGitService.createTag).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('deleteBaseline', () => {
+    it("should check the permission in the baseline's project", async () => {
+      vi.mocked(diskBaselineService.loadBaselines).mockResolvedValue([
+        { id: 'bl-1', projectId: 'proj-1' } as unknown as ProjectBaseline,
+      ]);
+      vi.mocked(checkPermission).mockRejectedValueOnce(new Error('may not baseline'));
+
+      await expect(baselineService.deleteBaseline('bl-1')).rejects.toThrow('may not baseline');
+      expect(checkPermission).toHaveBeenCalledWith('baseline', 'proj-1');
+      expect(diskBaselineService.deleteBaseline).not.toHaveBeenCalled();
+
+      await baselineService.deleteBaseline('bl-1');
+      expect(diskBaselineService.deleteBaseline).toHaveBeenCalledWith('bl-1');
+      await expect(baselineService.deleteBaseline('bl-2')).rejects.toThrow('not found');
+    });
+  });
+
+  describe('getBaselineContents', () => {
+    it('should read artifacts at their commits and verify the manifest', async () => {
+      vi.mocked(diskBaselineService.loadBaselines).mockResolvedValue([
+        {
+          id: 'bl-1',
+          name: 'Release 1',
+          version: '1.0',
+          tag: 'baseline/proj-1/1.0',
+          artifactCommits: {
+            'REQ-001': { commitHash: 'c1', type: 'requirement' },
+            'TC-001': { commitHash: 'c2', type: 'testcase' },
+          },
+          manifest: {
+            'REQ-001': await computeContentHash('# REQ-001'),
+            'TC-001': await computeContentHash('# something else'),
+          },
+        } as unknown as ProjectBaseline,
+      ]);
+
+      const { artifacts } = await baselineService.getBaselineContents('baseline/proj-1/1.0');
+
+      expect(artifacts).toEqual([
+        expect.objectContaining({ id: 'REQ-001', path: 'requirements/REQ-001.md', intact: true }),
+        expect.objectContaining({ id: 'TC-001', content: '# TC-001', intact: false }),
+      ]);
+    });
+
+    it('should throw for an unknown baseline', async () => {
+      await expect(baselineService.getBaselineContents('nope')).rejects.toThrow(
+        'Baseline not found'
+      );
+    });
+  });
+});
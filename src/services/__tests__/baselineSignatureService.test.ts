@@ -0,0 +1,194 @@
+// @vitest-environment node
+/**
+ * Baseline Signature Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  BASELINE_SIGNING_SECRET,
+  getBaselineManifestText,
+  signBaseline,
+  verifyBaselineSignature,
+} from '../baselineSignatureService';
+import { baseDiskService } from '../baseDiskService';
+import { getBaselineContents } from '../baselineService';
+import { secretService } from '../secretService';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../appSettingsService';
+import { generateSigningKey } from '../../utils/minisignUtils';
+import type { ProjectBaseline } from '../../types';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { readTextFile: vi.fn(), writeTextFilesAndCommit: vi.fn() },
+}));
+
+vi.mock('../baselineService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../baselineService')>()),
+  getBaselineContents: vi.fn(),
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn() },
+}));
+
+vi.mock('../appSettingsService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../appSettingsService')>()),
+  appSettingsService: { getAppSettings: vi.fn(), trustSigningKey: vi.fn() },
+}));
+
+const baseline = {
+  id: 'bl-1',
+  projectId: 'proj-1',
+  name: 'Release 1',
+  version: '1.0',
+  tag: 'baseline/proj-1/1.0',
+  artifactCommits: {
+    'TC-001': { commitHash: 'c2', type: 'testcase' },
+    'REQ-001': { commitHash: 'c1', type: 'requirement' },
+  },
+  manifest: { 'REQ-001': 'hash-req', 'TC-001': 'hash-tc' },
+} as unknown as ProjectBaseline;
+
+describe('baselineSignatureService', () => {
+  let files: Record<string, string>;
+  let intact: boolean;
+  let trustedSigningKeys: string[];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {};
+    intact = true;
+    vi.mocked(getBaselineContents).mockImplementation(async () => ({
+      baseline,
+      artifacts: [
+        {
+          id: 'REQ-001',
+          type: 'requirement',
+          path: 'requirements/REQ-001.md',
+          commitHash: 'c1',
+          content: '# REQ-001',
+          intact,
+        },
+      ],
+    }));
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) =>
+      (files[path] ?? '').trim()
+    );
+    vi.mocked(baseDiskService.writeTextFilesAndCommit).mockImplementation(async (changes) => {
+      for (const { path, content } of changes) files[path] = content;
+    });
+    vi.mocked(secretService.getSecret).mockResolvedValue(null);
+    trustedSigningKeys = [];
+    vi.mocked(appSettingsService.getAppSettings).mockImplementation(async () => ({
+      ...DEFAULT_APP_SETTINGS,
+      trustedSigningKeys,
+    }));
+    vi.mocked(appSettingsService.trustSigningKey).mockImplementation(async (publicKey) => {
+      trustedSigningKeys = [...trustedSigningKeys, publicKey.trim()];
+      return true;
+    });
+  });
+
+  describe('getBaselineManifestText', () => {
+    it('should list artifact hashes by path after the baseline identity', () => {
+      expect(getBaselineManifestText(baseline)).toBe(
+        [
+          'baseline bl-1',
+          'project proj-1',
+          'version 1.0',
+          'hash-req  requirements/REQ-001.md',
+          'hash-tc  testcases/TC-001.md',
+        ].join('\n')
+      );
+    });
+
+    it('should refuse baselines without a manifest', () => {
+      expect(() => getBaselineManifestText({ ...baseline, manifest: undefined })).toThrow(
+        'no content manifest'
+      );
+    });
+  });
+
+  describe('signBaseline', () => {
+    it('should commit manifest, signature and public key with a new stored key', async () => {
+      const signature = await signBaseline('1.0', 'proj-1', { signer: 'Alice' });
+
+      expect(secretService.storeSecret).toHaveBeenCalledWith(
+        BASELINE_SIGNING_SECRET,
+        expect.stringContaining(signature.keyId)
+      );
+      expect(baseDiskService.writeTextFilesAndCommit).toHaveBeenCalledWith(
+        [
+          { path: 'baselines/baseline-bl-1.manifest', content: getBaselineManifestText(baseline) },
+          { path: 'baselines/baseline-bl-1.manifest.minisig', content: expect.any(String) },
+          { path: `keys/${signature.keyId}.pub`, content: expect.any(String) },
+        ],
+        'Sign baseline Release 1 (1.0)'
+      );
+      expect(files['baselines/baseline-bl-1.manifest.minisig']).toMatch(
+        /trusted comment: timestamp:\d+\tbaseline:baseline\/proj-1\/1\.0\tsigner:Alice/
+      );
+      expect(trustedSigningKeys).toEqual([files[`keys/${signature.keyId}.pub`].trim()]);
+    });
+  });
+
+  describe('verifyBaselineSignature', () => {
+    it('should accept an untouched signed baseline', async () => {
+      const { keyId } = await signBaseline('1.0', 'proj-1');
+
+      const check = await verifyBaselineSignature('1.0', 'proj-1');
+
+      expect(check).toMatchObject({
+        signed: true,
+        trusted: true,
+        valid: true,
+        keyId,
+        problems: [],
+      });
+    });
+
+    it('should not trust a key that is only in the repository', async () => {
+      const { keyId } = await signBaseline('1.0', 'proj-1');
+      trustedSigningKeys = [];
+
+      const check = await verifyBaselineSignature('1.0', 'proj-1');
+
+      expect(check).toMatchObject({ signed: true, trusted: false, valid: false, keyId });
+      expect(check.problems).toEqual([
+        `Key ${keyId} is not trusted; only the repository vouches for it`,
+      ]);
+    });
+
+    it('should report unsigned baselines', async () => {
+      const check = await verifyBaselineSignature('1.0', 'proj-1');
+
+      expect(check.signed).toBe(false);
+      expect(check.valid).toBe(false);
+      expect(check.problems).toEqual(['Baseline 1.0 is not signed']);
+    });
+
+    it('should report a changed manifest, record or content', async () => {
+      await signBaseline('1.0', 'proj-1');
+      files['baselines/baseline-bl-1.manifest'] += '\nhash-x  requirements/REQ-002.md';
+      intact = false;
+
+      const check = await verifyBaselineSignature('1.0', 'proj-1');
+
+      expect(check.valid).toBe(false);
+      expect(check.problems).toEqual([
+        'Signature is invalid: Signature does not match the message',
+        'Baseline record differs from the signed manifest',
+        'requirements/REQ-001.md no longer matches the signed manifest',
+      ]);
+    });
+
+    it('should check against a trusted key instead of the committed one', async () => {
+      await signBaseline('1.0', 'proj-1');
+      const trusted = await generateSigningKey();
+
+      const check = await verifyBaselineSignature('1.0', 'proj-1', trusted.publicKey);
+
+      expect(check.valid).toBe(false);
+      expect(check.problems[0]).toContain(`not ${trusted.keyId}`);
+    });
+  });
+});
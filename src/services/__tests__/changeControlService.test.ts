@@ -0,0 +1,149 @@
+/**
+ * Change Control Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  createChangeRequest,
+  setAffectedRequirements,
+  getChangeRequestsForRequirement,
+  transitionChangeRequest,
+  commitUnderChangeRequest,
+  getChangeRequestCommits,
+} from '../changeControlService';
+import { changeRequestService, requirementService } from '../artifactServices';
+import { idService } from '../idService';
+import { realGitService } from '../realGitService';
+import type { ChangeRequest, Requirement, StatusWorkflowConfig } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  changeRequestService: {
+    load: vi.fn(),
+    loadAll: vi.fn(),
+    save: vi.fn((item) => Promise.resolve(item)),
+  },
+  requirementService: { loadAll: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: { getNextIdWithSync: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: {
+    getSection: vi.fn((_section: string, defaults: unknown) => Promise.resolve(defaults)),
+  },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  getAllowedTransitions: (config: StatusWorkflowConfig, from: string) =>
+    config.transitions[from] || [],
+  getCurrentUserName: vi.fn().mockResolvedValue('Alex'),
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    commitFile: vi.fn(),
+    commitFiles: vi.fn(),
+    getHistory: vi.fn(),
+    getCommitFiles: vi.fn(),
+  },
+}));
+
+describe('changeControlService', () => {
+  const changeRequest: ChangeRequest = {
+    id: 'CR-001',
+    title: 'Add SSO',
+    description: '',
+    status: 'open',
+    affectedRequirementIds: ['REQ-001'],
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(requirementService.loadAll).mockResolvedValue([
+      { id: 'REQ-001' },
+      { id: 'REQ-002' },
+    ] as Requirement[]);
+    vi.mocked(changeRequestService.load).mockResolvedValue(changeRequest);
+  });
+
+  it('creates a change request in the initial status', async () => {
+    vi.mocked(idService.getNextIdWithSync).mockResolvedValue('CR-002');
+
+    const created = await createChangeRequest({
+      title: 'Tighten password rules',
+      affectedRequirementIds: ['REQ-002', 'REQ-002'],
+    });
+
+    expect(created).toMatchObject({
+      id: 'CR-002',
+      status: 'open',
+      affectedRequirementIds: ['REQ-002'],
+    });
+  });
+
+  it('rejects unknown affected requirements', async () => {
+    await expect(setAffectedRequirements('CR-001', ['REQ-404'])).rejects.toThrow(
+      'Unknown requirements: REQ-404'
+    );
+    expect(changeRequestService.save).not.toHaveBeenCalled();
+  });
+
+  it('finds change requests affecting a requirement', async () => {
+    vi.mocked(changeRequestService.loadAll).mockResolvedValue([
+      changeRequest,
+      { ...changeRequest, id: 'CR-002', affectedRequirementIds: ['REQ-002'] },
+    ]);
+
+    const result = await getChangeRequestsForRequirement('REQ-002');
+
+    expect(result.map((cr) => cr.id)).toEqual(['CR-002']);
+  });
+
+  it('enforces the change request workflow and commits transitions', async () => {
+    await expect(transitionChangeRequest('CR-001', 'implemented')).rejects.toThrow(
+      'Transition from "open" to "implemented" is not allowed'
+    );
+
+    const updated = await transitionChangeRequest('CR-001', 'in-review');
+
+    expect(updated).toMatchObject({ status: 'in-review', statusChangedBy: 'Alex' });
+    expect(realGitService.commitFile).toHaveBeenCalledWith(
+      'change-requests/CR-001.md',
+      'CR-001: open → in-review',
+      'Alex'
+    );
+  });
+
+  it('tags commits with the change request trailer', async () => {
+    await commitUnderChangeRequest('CR-001', ['requirements/REQ-001.md'], 'Update REQ-001', 'Sam');
+
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      ['requirements/REQ-001.md'],
+      'Update REQ-001\n\nChange-Request: CR-001',
+      'Sam'
+    );
+  });
+
+  it('reports the commits made under a change request', async () => {
+    vi.mocked(realGitService.getHistory).mockResolvedValue([
+      {
+        hash: 'b',
+        message: 'Update REQ-001\n\nChange-Request: CR-001',
+        author: 'Sam',
+        timestamp: 2,
+      },
+      { hash: 'a', message: 'Unrelated', author: 'Sam', timestamp: 1 },
+    ]);
+    vi.mocked(realGitService.getCommitFiles).mockResolvedValue(['requirements/REQ-001.md']);
+
+    const commits = await getChangeRequestCommits('CR-001');
+
+    expect(commits).toHaveLength(1);
+    expect(commits[0]).toMatchObject({ hash: 'b', files: ['requirements/REQ-001.md'] });
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * Change Notification Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { changeNotificationService } from '../changeNotificationService';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from '../appSettingsService';
+import { isElectronEnv } from '../git/types';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+vi.mock('../appSettingsService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../appSettingsService')>()),
+  appSettingsService: { getAppSettings: vi.fn() },
+}));
+
+describe('changeNotificationService', () => {
+  const notifications = { show: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    vi.mocked(appSettingsService.getAppSettings).mockResolvedValue({
+      ...DEFAULT_APP_SETTINGS,
+      notifyExternalChanges: true,
+    });
+    notifications.show.mockResolvedValue({ shown: true });
+    window.electronAPI = { notifications } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should count changed artifacts, other files and new commits', () => {
+    const { describeExternalChanges } = changeNotificationService;
+
+    expect(
+      describeExternalChanges({
+        changedPaths: ['requirements/REQ-001.md', 'requirements/REQ-001.md', 'risks/RISK-002.md'],
+        newCommits: 1,
+      })
+    ).toBe('2 artifacts changed on disk / 1 new commit upstream');
+    expect(describeExternalChanges({ changedPaths: ['counters/requirements.md'] })).toBe(
+      '1 file changed on disk'
+    );
+    expect(describeExternalChanges({ changedPaths: [], newCommits: 0 })).toBeNull();
+  });
+
+  it('should show a notification when the setting is on', async () => {
+    expect(await changeNotificationService.notifyExternalChanges({ newCommits: 2 })).toBe(true);
+
+    expect(notifications.show).toHaveBeenCalledWith({
+      title: 'Tracyfy',
+      body: '2 new commits upstream',
+    });
+  });
+
+  it('should stay quiet when the setting is off or outside the desktop app', async () => {
+    vi.mocked(appSettingsService.getAppSettings).mockResolvedValue(DEFAULT_APP_SETTINGS);
+    expect(await changeNotificationService.notifyExternalChanges({ newCommits: 2 })).toBe(false);
+
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+    expect(await changeNotificationService.notifyExternalChanges({ newCommits: 2 })).toBe(false);
+
+    expect(notifications.show).not.toHaveBeenCalled();
+  });
+});
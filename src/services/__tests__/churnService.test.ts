@@ -0,0 +1,78 @@
+/**
+ * Churn Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { churnService } from '../churnService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../realGitService', () => ({
+  realGitService: { resolveRef: vi.fn(), listFilesAtCommit: vi.fn(), readFileAtCommit: vi.fn() },
+}));
+
+const OLD = 'a'.repeat(40);
+const NEW = 'b'.repeat(40);
+
+const commits: Record<string, Record<string, string>> = {
+  [OLD]: {
+    'requirements/REQ-001.md': '---\nid: REQ-001\nstatus: draft\n---\n# Login\nUsers log in.',
+    'requirements/REQ-002.md': '---\nid: REQ-002\n---\n# Logout\nUsers log out.',
+    'requirements/REQ-003.md': '---\nid: REQ-003\n---\n# Old',
+    'README.md': 'readme',
+  },
+  [NEW]: {
+    'requirements/REQ-001.md': '---\nid: REQ-001\nstatus: approved\n---\n# Login\nUsers log in.',
+    'requirements/REQ-002.md': '---\nid: REQ-002\n---\n# Logout\nUsers log out.\nSessions end.',
+    'testcases/TC-001.md': '---\nid: TC-001\n---\n# Test\nStep 1\nStep 2',
+    'README.md': 'changed readme',
+  },
+};
+
+describe('churnService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(realGitService.resolveRef).mockImplementation(async (ref) =>
+      ref === 'v1' ? OLD : ref === 'v2' ? NEW : null
+    );
+    vi.mocked(realGitService.listFilesAtCommit).mockImplementation(async (hash) =>
+      Object.keys(commits[hash])
+    );
+    vi.mocked(realGitService.readFileAtCommit).mockImplementation(
+      async (path, hash) => commits[hash][path] ?? null
+    );
+  });
+
+  it('should rank changed artifacts between two tags by churn', async () => {
+    const report = await churnService.getChurnReport('v1', 'v2');
+
+    expect(report.from).toBe(OLD);
+    expect(report.artifacts.map((a) => [a.artifactId, a.change, a.churn])).toEqual([
+      ['TC-001', 'added', 4],
+      ['REQ-001', 'modified', 2],
+      ['REQ-003', 'removed', 2],
+      ['REQ-002', 'modified', 1],
+    ]);
+    expect(report.totalChurn).toBe(9);
+  });
+
+  it('should distinguish frontmatter-only changes', async () => {
+    const report = await churnService.getChurnReport('v1', NEW);
+    const byId = Object.fromEntries(report.artifacts.map((a) => [a.artifactId, a]));
+
+    expect(byId['REQ-001']).toMatchObject({
+      frontmatterOnly: true,
+      frontmatter: { added: 1, removed: 1 },
+      body: { added: 0, removed: 0 },
+    });
+    expect(byId['REQ-002']).toMatchObject({
+      frontmatterOnly: false,
+      body: { added: 1, removed: 0 },
+    });
+  });
+
+  it('should reject unknown refs', async () => {
+    await expect(churnService.getChurnReport('nope', 'v2')).rejects.toThrow(
+      'Unknown commit or tag: nope'
+    );
+  });
+});
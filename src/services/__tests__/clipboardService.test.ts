@@ -0,0 +1,80 @@
+/**
+ * Clipboard Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { clipboardService } from '../clipboardService';
+import { isElectronEnv } from '../git/types';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+describe('clipboardService', () => {
+  const clipboard = { writeTable: vi.fn() };
+  const table = [
+    ['ID', 'Title'],
+    ['REQ-001', 'Login'],
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    clipboard.writeTable.mockResolvedValue({ ok: true });
+    window.electronAPI = { clipboard } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+    vi.unstubAllGlobals();
+  });
+
+  it('should write HTML and TSV together in the desktop app', async () => {
+    await clipboardService.copyTable(table);
+
+    expect(clipboard.writeTable).toHaveBeenCalledWith({
+      html: expect.stringContaining('<td style="'),
+      text: 'ID\tTitle\nREQ-001\tLogin',
+    });
+  });
+
+  it('should surface errors from the main process', async () => {
+    clipboard.writeTable.mockResolvedValue({ error: 'Clipboard busy' });
+
+    await expect(clipboardService.copyTable(table)).rejects.toThrow('Clipboard busy');
+  });
+
+  it('should write both flavors through the Clipboard API in the browser', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+    const write = vi.fn();
+    vi.stubGlobal('navigator', { clipboard: { write, writeText: vi.fn() } });
+    vi.stubGlobal(
+      'ClipboardItem',
+      class {
+        items: Record<string, Blob>;
+        constructor(items: Record<string, Blob>) {
+          this.items = items;
+        }
+      }
+    );
+
+    await clipboardService.copyArtifactList([{ id: 'REQ-001', title: 'Login' }]);
+
+    const [[item]] = write.mock.calls[0];
+    expect(Object.keys(item.items)).toEqual(['text/html', 'text/plain']);
+    expect(await item.items['text/plain'].text()).toBe(
+      'ID\tTitle\tStatus\tPriority\tAuthor\nREQ-001\tLogin\t\t\t'
+    );
+  });
+
+  it('should fall back to plain text without HTML clipboard support', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+    const writeText = vi.fn();
+    vi.stubGlobal('navigator', { clipboard: { writeText } });
+
+    await clipboardService.copyTable(table);
+
+    expect(writeText).toHaveBeenCalledWith('ID\tTitle\nREQ-001\tLogin');
+  });
+});
@@ -0,0 +1,108 @@
+/**
+ * Comment Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { commentService, buildCommentThreads } from '../commentService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { checkPermission } from '../permissionService';
+import type { ArtifactComment } from '../../types';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn() },
+}));
+
+vi.mock('../permissionService', () => ({
+  checkPermission: vi.fn(),
+}));
+
+describe('commentService', () => {
+  const comment = (id: string, timestamp: number, parentId?: string): ArtifactComment => ({
+    id,
+    artifactId: 'REQ-001',
+    parentId,
+    author: 'Sam',
+    text: `Comment ${id}`,
+    timestamp,
+  });
+  let stored: ArtifactComment[];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    stored = [comment('C-001', 1), comment('C-002', 2, 'C-001'), comment('C-003', 3, 'C-002')];
+    vi.mocked(fileSystemService.readFile).mockImplementation(async () => JSON.stringify(stored));
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (_path, content) => {
+      stored = JSON.parse(content as string);
+    });
+  });
+
+  describe('buildCommentThreads', () => {
+    it('groups nested replies under their root comment', () => {
+      const threads = buildCommentThreads([...stored, comment('C-004', 4)]);
+
+      expect(threads.map((t) => t.comment.id)).toEqual(['C-001', 'C-004']);
+      expect(threads[0].replies.map((r) => r.id)).toEqual(['C-002', 'C-003']);
+    });
+  });
+
+  it('adds a reply and commits the sidecar file', async () => {
+    const reply = await commentService.addComment('REQ-001', 'Alex', ' Agreed ', 'C-001');
+
+    expect(reply).toMatchObject({ id: 'C-004', parentId: 'C-001', text: 'Agreed' });
+    expect(fileSystemService.writeFile).toHaveBeenCalledWith(
+      'comments/REQ-001.json',
+      expect.any(String)
+    );
+    expect(realGitService.commitFile).toHaveBeenCalledWith(
+      'comments/REQ-001.json',
+      'Reply to REQ-001'
+    );
+  });
+
+  it('rejects replies to unknown comments and empty text', async () => {
+    await expect(commentService.addComment('REQ-001', 'Alex', 'Hi', 'C-404')).rejects.toThrow(
+      'Comment not found: C-404'
+    );
+    await expect(commentService.addComment('REQ-001', 'Alex', '  ')).rejects.toThrow('empty');
+  });
+
+  it('needs a role that may comment', async () => {
+    vi.mocked(checkPermission).mockRejectedValue(new Error('may not comment'));
+
+    await expect(commentService.addComment('REQ-001', 'Alex', 'Hi')).rejects.toThrow(
+      'may not comment'
+    );
+    await expect(commentService.deleteComment('REQ-001', 'C-001')).rejects.toThrow(
+      'may not comment'
+    );
+    expect(checkPermission).toHaveBeenCalledWith('comment');
+    expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+  });
+
+  it('resolves the whole thread from any comment in it', async () => {
+    const root = await commentService.resolveComment('REQ-001', 'C-003', 'Alex');
+
+    expect(root).toMatchObject({ id: 'C-001', resolved: true, resolvedBy: 'Alex' });
+    expect(await commentService.listThreads('REQ-001', false)).toEqual([]);
+  });
+
+  it('deletes a comment with its replies', async () => {
+    const removed = await commentService.deleteComment('REQ-001', 'C-002');
+
+    expect(removed).toEqual(['C-002', 'C-003']);
+    expect(stored.map((c) => c.id)).toEqual(['C-001']);
+  });
+
+  it('rejects comments on unknown artifacts', async () => {
+    await expect(commentService.listComments('NOPE-1')).rejects.toThrow('Unknown artifact');
+  });
+});
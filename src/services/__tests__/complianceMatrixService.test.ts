@@ -0,0 +1,162 @@
+/**
+ * Compliance Matrix Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  complianceMatrixService,
+  BUILT_IN_COMPLIANCE_TEMPLATES,
+  type ComplianceTemplate,
+} from '../complianceMatrixService';
+import { projectConfigService } from '../projectConfigService';
+import type { CustomAttributeDefinition, Link, Requirement, TestCase } from '../../types';
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  requirementService: { loadAll: vi.fn() },
+  testCaseService: { loadAll: vi.fn() },
+  projectService: { load: vi.fn() },
+}));
+
+const requirement = (id: string, extra: Partial<Requirement> = {}): Requirement => ({
+  id,
+  title: `Title ${id}`,
+  text: `Text ${id}`,
+  status: 'approved',
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+  ...extra,
+});
+
+const testCase = (id: string, status: string): TestCase => ({
+  id,
+  title: `Test ${id}`,
+  description: '',
+  status,
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+});
+
+const link = (id: string, sourceId: string, targetId: string): Link => ({
+  id,
+  sourceId,
+  targetId,
+  type: 'verified_by',
+  projectIds: [],
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+});
+
+const asil: CustomAttributeDefinition = {
+  id: 'ATTR-001',
+  name: 'ASIL',
+  type: 'dropdown',
+  appliesTo: ['requirement'],
+};
+
+describe('complianceMatrixService', () => {
+  const requirements = [
+    requirement('REQ-002'),
+    requirement('REQ-001', { customAttributes: [{ attributeId: 'ATTR-001', value: 'B' }] }),
+  ];
+  const testCases = [testCase('TC-001', 'passed'), testCase('TC-002', 'failed')];
+  const links = [
+    link('LINK-001', 'REQ-001', 'TC-001'),
+    link('LINK-002', 'REQ-001', 'TC-002'),
+    link('LINK-003', 'RISK-001', 'REQ-002'),
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockImplementation(
+      async (_section, defaults) => defaults
+    );
+  });
+
+  describe('buildComplianceMatrix', () => {
+    it('should list each requirement/test case pair and keep gaps visible', () => {
+      const template: ComplianceTemplate = {
+        id: 'custom',
+        name: 'Custom',
+        layout: 'perTestCase',
+        columns: [
+          { header: 'Req', field: 'requirement.id' },
+          { header: 'ASIL', field: 'attribute:asil' },
+          { header: 'Test', field: 'testCase.id' },
+          { header: 'Result', field: 'testCase.status' },
+          { header: 'Verdict', field: 'verification.state' },
+        ],
+      };
+
+      expect(
+        complianceMatrixService.buildComplianceMatrix(template, requirements, testCases, links, [
+          asil,
+        ])
+      ).toEqual([
+        ['Req', 'ASIL', 'Test', 'Result', 'Verdict'],
+        ['REQ-001', 'B', 'TC-001', 'passed', 'failed'],
+        ['REQ-001', 'B', 'TC-002', 'failed', 'failed'],
+        ['REQ-002', '', '', '', 'untested'],
+      ]);
+    });
+
+    it('should join test cases and linked risks per requirement', () => {
+      const iec = BUILT_IN_COMPLIANCE_TEMPLATES.find((t) => t.id === 'iec-62304')!;
+
+      const [header, ...rows] = complianceMatrixService.buildComplianceMatrix(
+        iec,
+        requirements,
+        testCases,
+        links
+      );
+
+      expect(header[3]).toBe('Risk Control');
+      expect(rows[0].slice(4, 6)).toEqual(['TC-001, TC-002', 'passed, failed']);
+      expect(rows[1][3]).toBe('RISK-001');
+    });
+  });
+
+  describe('templates', () => {
+    it('should let project templates override built-ins and add new ones', async () => {
+      const override = { ...BUILT_IN_COMPLIANCE_TEMPLATES[0], name: 'Our ISO matrix' };
+      const extra: ComplianceTemplate = {
+        id: 'internal',
+        name: 'Internal',
+        layout: 'perRequirement',
+        columns: [{ header: 'ID', field: 'requirement.id' }],
+      };
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({
+        templates: [override, extra],
+      });
+
+      const templates = await complianceMatrixService.getComplianceTemplates();
+
+      expect(templates.map((t) => t.name)).toEqual([
+        'Our ISO matrix',
+        'DO-178C Requirements-Based Test Trace',
+        'IEC 62304 Software Requirements Traceability',
+        'Internal',
+      ]);
+    });
+
+    it('should reject templates with unknown fields', async () => {
+      const invalid: ComplianceTemplate = {
+        id: 'bad',
+        name: 'Bad',
+        layout: 'perRequirement',
+        columns: [{ header: 'X', field: 'requirement.nope' }],
+      };
+
+      await expect(complianceMatrixService.setComplianceTemplates([invalid])).rejects.toThrow(
+        'Unknown field "requirement.nope"'
+      );
+      expect(projectConfigService.setSection).not.toHaveBeenCalled();
+    });
+  });
+});
@@ -0,0 +1,112 @@
+/**
+ * Coverage Service Tests
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import { getCoverageReport } from '../coverageService';
+import type { Requirement, TestCase, Link } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  requirementService: { loadAll: vi.fn() },
+  testCaseService: { loadAll: vi.fn() },
+  projectService: { load: vi.fn() },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), getLinksForProject: vi.fn() },
+}));
+
+describe('coverageService', () => {
+  const createRequirement = (id: string, overrides: Partial<Requirement> = {}): Requirement => ({
+    id,
+    title: `Requirement ${id}`,
+    text: '',
+    status: 'draft',
+    priority: 'medium',
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+    ...overrides,
+  });
+
+  const createTestCase = (id: string, overrides: Partial<TestCase> = {}): TestCase => ({
+    id,
+    title: `Test ${id}`,
+    description: '',
+    status: 'draft',
+    priority: 'medium',
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+    ...overrides,
+  });
+
+  const createLink = (sourceId: string, targetId: string): Link => ({
+    id: `LINK-${sourceId}-${targetId}`,
+    sourceId,
+    targetId,
+    type: 'verifies',
+    projectIds: [],
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+  });
+
+  it('reports untested requirements and unlinked test cases', () => {
+    const report = getCoverageReport(
+      [createRequirement('REQ-001'), createRequirement('REQ-002')],
+      [createTestCase('TC-001'), createTestCase('TC-002')],
+      [createLink('TC-001', 'REQ-001')]
+    );
+
+    expect(report.untestedRequirements.map((r) => r.id)).toEqual(['REQ-002']);
+    expect(report.unlinkedTestCases.map((t) => t.id)).toEqual(['TC-002']);
+    expect(report.testedRequirements).toBe(1);
+    expect(report.coveragePercent).toBe(50);
+  });
+
+  it('counts legacy embedded links', () => {
+    const report = getCoverageReport(
+      [
+        createRequirement('REQ-001', {
+          linkedArtifacts: [{ targetId: 'TC-002', type: 'related_to' }],
+        }),
+        createRequirement('REQ-002'),
+      ],
+      [createTestCase('TC-001', { requirementIds: ['REQ-002'] }), createTestCase('TC-002')],
+      []
+    );
+
+    expect(report.untestedRequirements).toEqual([]);
+    expect(report.unlinkedTestCases).toEqual([]);
+    expect(report.coveragePercent).toBe(100);
+  });
+
+  it('ignores deleted artifacts and links', () => {
+    const report = getCoverageReport(
+      [createRequirement('REQ-001'), createRequirement('REQ-002', { isDeleted: true })],
+      [createTestCase('TC-001')],
+      [{ ...createLink('TC-001', 'REQ-001'), isDeleted: true }]
+    );
+
+    expect(report.totalRequirements).toBe(1);
+    expect(report.untestedRequirements.map((r) => r.id)).toEqual(['REQ-001']);
+    expect(report.unlinkedTestCases.map((t) => t.id)).toEqual(['TC-001']);
+  });
+
+  it('filters by status and priority', () => {
+    const report = getCoverageReport(
+      [
+        createRequirement('REQ-001', { status: 'approved', priority: 'high' }),
+        createRequirement('REQ-002', { status: 'approved', priority: 'low' }),
+        createRequirement('REQ-003', { status: 'draft', priority: 'high' }),
+      ],
+      [],
+      [],
+      { statuses: ['approved'], priorities: ['high'] }
+    );
+
+    expect(report.untestedRequirements.map((r) => r.id)).toEqual(['REQ-001']);
+    expect(report.totalRequirements).toBe(1);
+  });
+});
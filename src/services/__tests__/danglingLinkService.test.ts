@@ -0,0 +1,132 @@
+/**
+ * Dangling Link Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  findDanglingLinks,
+  getDanglingLinks,
+  removeDanglingLinks,
+  retargetLink,
+} from '../danglingLinkService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import type { Link } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+    },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    getAllLinks: vi.fn(),
+    getLinkById: vi.fn(),
+    deleteLink: vi.fn(),
+    updateLink: vi.fn(async (id: string, updates: Partial<Link>) => ({ id, ...updates })),
+  },
+}));
+
+describe('danglingLinkService', () => {
+  const createLink = (id: string, sourceId: string, targetId: string): Link => ({
+    id,
+    sourceId,
+    targetId,
+    type: 'verifies',
+    projectIds: [],
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+  });
+
+  const links = [
+    createLink('LINK-001', 'TC-001', 'REQ-001'),
+    createLink('LINK-002', 'TC-001', 'REQ-404'),
+    createLink('LINK-003', 'TC-404', 'REQ-405'),
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      { id: 'REQ-001' },
+      { id: 'REQ-002' },
+    ]);
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([{ id: 'TC-001' }]);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue(links);
+  });
+
+  describe('findDanglingLinks', () => {
+    it('reports which endpoints are missing', () => {
+      const dangling = findDanglingLinks(links, new Set(['TC-001', 'REQ-001']));
+
+      expect(dangling.map((d) => [d.link.id, d.missingEndpoints])).toEqual([
+        ['LINK-002', ['target']],
+        ['LINK-003', ['source', 'target']],
+      ]);
+    });
+  });
+
+  describe('getDanglingLinks', () => {
+    it('checks links against artifacts on disk', async () => {
+      const dangling = await getDanglingLinks();
+
+      expect(dangling.map((d) => d.link.id)).toEqual(['LINK-002', 'LINK-003']);
+    });
+  });
+
+  describe('removeDanglingLinks', () => {
+    it('removes all dangling links by default', async () => {
+      const removed = await removeDanglingLinks();
+
+      expect(removed).toEqual(['LINK-002', 'LINK-003']);
+      expect(diskLinkService.deleteLink).toHaveBeenCalledTimes(2);
+    });
+
+    it('only removes the requested links that are still dangling', async () => {
+      const removed = await removeDanglingLinks(['LINK-001', 'LINK-003']);
+
+      expect(removed).toEqual(['LINK-003']);
+      expect(diskLinkService.deleteLink).toHaveBeenCalledWith('LINK-003');
+    });
+  });
+
+  describe('retargetLink', () => {
+    beforeEach(() => {
+      vi.mocked(diskLinkService.getLinkById).mockResolvedValue(links[1]);
+    });
+
+    it('points the endpoint at an existing artifact', async () => {
+      const updated = await retargetLink('LINK-002', 'target', 'REQ-002');
+
+      expect(diskLinkService.updateLink).toHaveBeenCalledWith('LINK-002', { targetId: 'REQ-002' });
+      expect(updated.targetId).toBe('REQ-002');
+    });
+
+    it('rejects missing artifacts and self-links', async () => {
+      await expect(retargetLink('LINK-002', 'target', 'REQ-999')).rejects.toThrow(
+        'Artifact not found: REQ-999'
+      );
+      await expect(retargetLink('LINK-002', 'target', 'TC-001')).rejects.toThrow(
+        'Cannot link TC-001 to itself'
+      );
+      expect(diskLinkService.updateLink).not.toHaveBeenCalled();
+    });
+
+    it('rejects unknown links', async () => {
+      vi.mocked(diskLinkService.getLinkById).mockResolvedValue(null);
+
+      await expect(retargetLink('LINK-999', 'source', 'TC-001')).rejects.toThrow(
+        'Link not found: LINK-999'
+      );
+    });
+  });
+});
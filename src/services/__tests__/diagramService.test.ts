@@ -0,0 +1,83 @@
+/**
+ * Diagram Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { diagramService } from '../diagramService';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: () => true,
+}));
+
+
+const markdown = [
+  'Login flow:',
+  '',
+  '```mermaid',
+  'sequenceDiagram',
+  '  User->>App: sign in',
+  '```',
+  '',
+  '```ts',
+  'const x = 1;',
+  '```',
+  '',
+  '~~~plantuml',
+  '@startuml',
+  'A -> B',
+  '@enduml',
+  '~~~',
+].join('\n');
+
+describe('diagramService', () => {
+  const diagram = { render: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    diagramService.clearDiagramCache();
+    window.electronAPI = { diagram } as unknown as Window['electronAPI'];
+  });
+
+  describe('extractDiagrams', () => {
+    it('should find mermaid and plantuml blocks only', () => {
+      expect(diagramService.extractDiagrams(markdown)).toEqual([
+        { kind: 'mermaid', source: 'sequenceDiagram\n  User->>App: sign in' },
+        { kind: 'plantuml', source: '@startuml\nA -> B\n@enduml' },
+      ]);
+    });
+  });
+
+  describe('renderDiagram', () => {
+    it('should return a data URL and reuse it for the same source', async () => {
+      diagram.render.mockResolvedValue({ data: 'PHN2Zy8+', mimeType: 'image/svg+xml' });
+
+      const first = await diagramService.renderDiagram('mermaid', 'graph TD; A-->B');
+      const second = await diagramService.renderDiagram('mermaid', 'graph TD; A-->B');
+
+      expect(first).toBe('data:image/svg+xml;base64,PHN2Zy8+');
+      expect(second).toBe(first);
+      expect(diagram.render).toHaveBeenCalledTimes(1);
+      // The main process takes the tools to run from its own settings
+      expect(diagram.render).toHaveBeenCalledWith('mermaid', 'graph TD; A-->B', 'svg');
+    });
+
+    it('should return null when the renderer fails', async () => {
+      diagram.render.mockResolvedValue({ error: 'No PlantUML jar configured' });
+
+      expect(await diagramService.renderDiagram('plantuml', 'A -> B')).toBeNull();
+    });
+  });
+
+  describe('renderDiagramsInMarkdown', () => {
+    it('should render each diagram as PNG and skip failures', async () => {
+      diagram.render
+        .mockResolvedValueOnce({ data: 'AAA', mimeType: 'image/png' })
+        .mockResolvedValueOnce({ error: 'java not found' });
+
+      expect(await diagramService.renderDiagramsInMarkdown(markdown)).toEqual([
+        'data:image/png;base64,AAA',
+      ]);
+    });
+  });
+});
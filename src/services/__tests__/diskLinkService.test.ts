@@ -8,14 +8,51 @@ import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { diskLinkService } from '../diskLinkService';
 import { fileSystemService } from '../fileSystemService';
 import { idService } from '../idService';
+import { realGitService } from '../realGitService';
+import { registerCustomArtifactTypes } from '../../constants/artifactConfig';
 
 // Mock the idService
 vi.mock('../idService', () => ({
   idService: {
     getNextIdWithSync: vi.fn(),
+    getNextIds: vi.fn(),
+    getCounterPath: vi.fn(),
   },
 }));
 
+// Mock the git service
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    commitFile: vi.fn(),
+    commitFiles: vi.fn(),
+  },
+}));
+
+// Journaled writes commit the recorded files
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn(async (_operation, apply, commitMessage) => {
+    const recorded: string[] = [];
+    await apply({ record: async (path: string) => void recorded.push(path) });
+    await realGitService.commitFiles(recorded, commitMessage);
+  }),
+}));
+
+// Artifacts that bulk-created links may point at
+vi.mock('../artifactServices', () => {
+  const service = (...ids: string[]) => ({
+    loadAll: vi.fn(async () => ids.map((id) => ({ id }))),
+  });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service('REQ-001', 'REQ-002', 'REQ-003'),
+      usecases: service('UC-001'),
+      testcases: service('TC-010', 'TC-011'),
+      stakeholder: service('STK-001'),
+      system: service('SYS-001'),
+    },
+  };
+});
+
 // Mock the fileSystemService
 vi.mock('../fileSystemService', () => ({
   fileSystemService: {
@@ -64,6 +101,19 @@ lastModified: 1700000000000
 ---
 
 # LINK-002
+`;
+
+  const derivationLinkMarkdown = `---
+id: LINK-003
+sourceId: REQ-001
+targetId: SYS-001
+type: derived_from
+projectIds: 
+dateCreated: 1700000000000
+lastModified: 1700000000000
+---
+
+# LINK-003
 `;
 
   describe('initialize', () => {
@@ -165,7 +215,60 @@ lastModified: 1700000000000
     });
   });
 
+  describe('getLinksForArtifact', () => {
+    it('should return outgoing and incoming links for the artifact', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-001.md', 'LINK-002.md']);
+      vi.mocked(fileSystemService.readFile)
+        .mockResolvedValueOnce(sampleLinkMarkdown)
+        .mockResolvedValueOnce(projectScopedLinkMarkdown);
+
+      const links = await diskLinkService.getLinksForArtifact('UC-001');
+
+      expect(links.outgoing).toEqual([]);
+      expect(links.incoming).toHaveLength(1);
+      expect(links.incoming[0]).toMatchObject({ linkId: 'LINK-001', sourceId: 'REQ-001' });
+    });
+
+    it('should respect project visibility when a project is given', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-002.md']);
+      vi.mocked(fileSystemService.readFile).mockResolvedValue(projectScopedLinkMarkdown);
+
+      const visible = await diskLinkService.getLinksForArtifact('REQ-002', 'PRJ-001');
+      const hidden = await diskLinkService.getLinksForArtifact('REQ-002', 'PRJ-999');
+
+      expect(visible.outgoing).toHaveLength(1);
+      expect(hidden.outgoing).toEqual([]);
+    });
+  });
+
+  describe('queryLinks', () => {
+    it('should filter by artifact on either end and by type', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-001.md', 'LINK-002.md']);
+      vi.mocked(fileSystemService.readFile).mockImplementation(async (path: string) =>
+        path.endsWith('LINK-001.md') ? sampleLinkMarkdown : projectScopedLinkMarkdown
+      );
+
+      const byArtifact = await diskLinkService.queryLinks({ artifactId: 'TC-001' });
+      const byType = await diskLinkService.queryLinks({ types: ['satisfies'] });
+      const combined = await diskLinkService.queryLinks({
+        sourceId: 'REQ-002',
+        types: ['satisfies'],
+      });
+
+      expect(byArtifact.map((l) => l.id)).toEqual(['LINK-002']);
+      expect(byType.map((l) => l.id)).toEqual(['LINK-001']);
+      expect(combined).toEqual([]);
+    });
+  });
+
   describe('createLink', () => {
+    it('should reject linking an artifact to itself', async () => {
+      await expect(diskLinkService.createLink('REQ-001', 'REQ-001', 'related_to')).rejects.toThrow(
+        'Cannot link REQ-001 to itself'
+      );
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+    });
+
     it('should create a new link file with generated ID', async () => {
       vi.mocked(idService.getNextIdWithSync).mockResolvedValue('LINK-001');
       vi.mocked(fileSystemService.writeFile).mockResolvedValue(undefined);
@@ -186,6 +289,17 @@ lastModified: 1700000000000
       );
     });
 
+    it('should reject derivation links that would create a cycle', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-003.md']);
+      vi.mocked(fileSystemService.readFile).mockResolvedValue(derivationLinkMarkdown);
+
+      await expect(diskLinkService.createLink('SYS-001', 'REQ-001', 'refines')).rejects.toThrow(
+        'it would create a cycle SYS-001 → REQ-001 → SYS-001'
+      );
+      expect(idService.getNextIdWithSync).not.toHaveBeenCalled();
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+    });
+
     it('should set dateCreated and lastModified', async () => {
       vi.mocked(idService.getNextIdWithSync).mockResolvedValue('LINK-001');
       vi.mocked(fileSystemService.writeFile).mockResolvedValue(undefined);
@@ -203,6 +317,124 @@ lastModified: 1700000000000
     });
   });
 
+  describe('createLinks', () => {
+    beforeEach(() => {
+      registerCustomArtifactTypes([
+        { key: 'stakeholder', label: 'Stakeholder', idPrefix: 'STK', folder: 'stakeholder' },
+        { key: 'system', label: 'System', idPrefix: 'SYS', folder: 'system' },
+      ]);
+    });
+
+    afterEach(() => {
+      registerCustomArtifactTypes([]);
+    });
+
+    it('should create all links and commit them in a single commit', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue([]);
+      vi.mocked(idService.getNextIds).mockResolvedValue(['LINK-001', 'LINK-002']);
+      vi.mocked(idService.getCounterPath).mockReturnValue('counters/links.md');
+
+      const result = await diskLinkService.createLinks(
+        [
+          { sourceId: 'TC-010', targetId: 'REQ-003', type: 'verifies' },
+          { sourceId: 'TC-011', targetId: 'REQ-003', type: 'verifies' },
+        ],
+        'Link test suite'
+      );
+
+      expect(result.created.map((l) => l.id)).toEqual(['LINK-001', 'LINK-002']);
+      expect(result.skipped).toEqual([]);
+      expect(result.missing).toEqual([]);
+      expect(idService.getNextIds).toHaveBeenCalledWith('links', 2, true);
+      expect(fileSystemService.writeFile).toHaveBeenCalledTimes(2);
+      expect(realGitService.commitFile).not.toHaveBeenCalled();
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['counters/links.md', 'links/LINK-001.md', 'links/LINK-002.md'],
+        'Link test suite'
+      );
+    });
+
+    it('should skip existing and repeated links', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-001.md']);
+      vi.mocked(fileSystemService.readFile).mockResolvedValue(sampleLinkMarkdown);
+      vi.mocked(idService.getNextIds).mockResolvedValue(['LINK-002']);
+      vi.mocked(idService.getCounterPath).mockReturnValue('counters/links.md');
+
+      const result = await diskLinkService.createLinks([
+        { sourceId: 'REQ-001', targetId: 'UC-001', type: 'satisfies' },
+        { sourceId: 'REQ-002', targetId: 'UC-001', type: 'satisfies' },
+        { sourceId: 'REQ-002', targetId: 'UC-001', type: 'satisfies' },
+      ]);
+
+      expect(result.created).toHaveLength(1);
+      expect(result.created[0].sourceId).toBe('REQ-002');
+      expect(result.skipped).toHaveLength(2);
+      expect(idService.getNextIds).toHaveBeenCalledWith('links', 1, true);
+    });
+
+    it('should report links whose source or target does not exist', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue([]);
+      vi.mocked(idService.getNextIds).mockResolvedValue(['LINK-001']);
+      vi.mocked(idService.getCounterPath).mockReturnValue('counters/links.md');
+
+      const result = await diskLinkService.createLinks([
+        { sourceId: 'TC-010', targetId: 'REQ-003', type: 'verifies' },
+        { sourceId: 'TC-099', targetId: 'REQ-003', type: 'verifies' },
+        { sourceId: 'TC-011', targetId: 'XYZ-001', type: 'verifies' },
+      ]);
+
+      expect(result.created.map((l) => l.sourceId)).toEqual(['TC-010']);
+      expect(result.missing.map((spec) => `${spec.sourceId}:${spec.targetId}`)).toEqual([
+        'TC-099:REQ-003',
+        'TC-011:XYZ-001',
+      ]);
+      expect(idService.getNextIds).toHaveBeenCalledWith('links', 1, true);
+    });
+
+    it('should reject a batch that closes a cycle before writing anything', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-003.md']);
+      vi.mocked(fileSystemService.readFile).mockResolvedValue(derivationLinkMarkdown);
+
+      await expect(
+        diskLinkService.createLinks([
+          { sourceId: 'SYS-001', targetId: 'STK-001', type: 'derived_from' },
+          { sourceId: 'REQ-001', targetId: 'STK-001', type: 'derives_to' },
+        ])
+      ).rejects.toThrow('it would create a cycle STK-001 → REQ-001 → SYS-001 → STK-001');
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+      expect(realGitService.commitFiles).not.toHaveBeenCalled();
+    });
+
+    it('should reject self-links before writing anything', async () => {
+      await expect(
+        diskLinkService.createLinks([
+          { sourceId: 'TC-010', targetId: 'REQ-003', type: 'verifies' },
+          { sourceId: 'TC-011', targetId: 'TC-011', type: 'related_to' },
+        ])
+      ).rejects.toThrow('Cannot link TC-011 to itself');
+      expect(fileSystemService.writeFile).not.toHaveBeenCalled();
+      expect(realGitService.commitFiles).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('deleteLinks', () => {
+    it('should delete existing links in a single commit', async () => {
+      vi.mocked(fileSystemService.listFiles).mockResolvedValue(['LINK-001.md', 'LINK-002.md']);
+      vi.mocked(fileSystemService.readFile)
+        .mockResolvedValueOnce(sampleLinkMarkdown)
+        .mockResolvedValueOnce(projectScopedLinkMarkdown);
+
+      const deleted = await diskLinkService.deleteLinks(['LINK-001', 'LINK-002', 'LINK-999']);
+
+      expect(deleted).toEqual(['LINK-001', 'LINK-002']);
+      expect(fileSystemService.deleteFile).toHaveBeenCalledTimes(2);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['links/LINK-001.md', 'links/LINK-002.md'],
+        'Delete 2 link(s)'
+      );
+    });
+  });
+
   describe('deleteLink', () => {
     it('should delete the link file', async () => {
       vi.mocked(fileSystemService.deleteFile).mockResolvedValue(undefined);
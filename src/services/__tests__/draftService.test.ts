@@ -0,0 +1,79 @@
+/**
+ * Draft Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { draftService } from '../draftService';
+import { setEncryptionKey } from '../baseDiskService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { generateEncryptionKey } from '../../utils/encryptionUtils';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    deleteFile: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+const DRAFT_FILE = '.tracyfy/drafts/requirements%2FREQ-001.md.json';
+
+describe('draftService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {};
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+  });
+
+  afterEach(() => setEncryptionKey(null));
+
+  it('should keep drafts outside the artifact file and never commit them', async () => {
+    const saved = await draftService.saveDraft('requirements/REQ-001.md', 'half-written');
+
+    expect(Object.keys(files)).toEqual([DRAFT_FILE]);
+    expect(await draftService.loadDraft('requirements/REQ-001.md')).toEqual(saved);
+    expect(realGitService.commitFile).not.toHaveBeenCalled();
+
+    await draftService.discardDraft('requirements/REQ-001.md');
+
+    expect(files).toEqual({});
+    expect(await draftService.loadDraft('requirements/REQ-001.md')).toBeNull();
+  });
+
+  it('should not let a pending autosave outlive a discard', async () => {
+    const save = draftService.saveDraft('requirements/REQ-001.md', 'typing');
+    const discard = draftService.discardDraft('requirements/REQ-001.md');
+    await Promise.all([save, discard]);
+
+    expect(files).toEqual({});
+  });
+
+  it('should encrypt drafts of encrypted artifacts', async () => {
+    setEncryptionKey(generateEncryptionKey());
+
+    await draftService.saveDraft('requirements/REQ-001.md', 'secret spec');
+
+    expect(files[DRAFT_FILE]).toMatch(/^tracyfy-encrypted:v1:/);
+    expect((await draftService.loadDraft('requirements/REQ-001.md'))?.content).toBe('secret spec');
+  });
+
+  it('should ignore unreadable drafts', async () => {
+    files[DRAFT_FILE] = '{not json';
+
+    expect(await draftService.loadDraft('requirements/REQ-001.md')).toBeNull();
+  });
+});
@@ -0,0 +1,142 @@
+// @vitest-environment node
+/**
+ * Drop Import Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { dropImportService } from '../dropImportService';
+import { baseDiskService } from '../baseDiskService';
+import { projectService } from '../artifactServices';
+import { realGitService } from '../realGitService';
+import type { Project } from '../../types';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { writeTextFile: vi.fn(), writeBinaryFile: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    deserialize: vi.fn(() => ({ priority: 'medium' })),
+    serialize: vi.fn((item) => JSON.stringify(item)),
+  });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+    projectService: { load: vi.fn(), save: vi.fn() },
+  };
+});
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn(async (type: string, count: number) =>
+      Array.from({ length: count }, (_, i) => `${type.slice(0, 3).toUpperCase()}-00${i + 1}`)
+    ),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFiles: vi.fn() },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  getStatusWorkflow: vi.fn().mockResolvedValue({ initialStatus: 'draft' }),
+}));
+
+// The journal itself is covered by its own tests
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn(async (_operation, apply, commitMessage) => {
+    const recorded: string[] = [];
+    await apply({ record: async (path: string) => void recorded.push(path) });
+    await realGitService.commitFiles(recorded, commitMessage);
+  }),
+}));
+
+const file = (name: string, text: string) => ({ name, data: new TextEncoder().encode(text) });
+
+describe('dropImportService', () => {
+  let project: Project;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    project = {
+      id: 'proj-1',
+      name: 'Demo',
+      description: '',
+      requirementIds: ['REQ-000'],
+      useCaseIds: [],
+      testCaseIds: [],
+      informationIds: [],
+      riskIds: [],
+      lastModified: 0,
+    };
+    vi.mocked(projectService.load).mockResolvedValue(project);
+  });
+
+  it('should create artifacts from markdown and attachments from other files', async () => {
+    const report = await dropImportService.importDroppedFiles(
+      [
+        file('login.md', '# Login\n\nThe system shall lock accounts. Users must sign in.'),
+        file('diagram.PNG', 'png-bytes'),
+      ],
+      'proj-1'
+    );
+
+    expect(report.created).toEqual([
+      {
+        name: 'login.md',
+        kind: 'artifact',
+        id: 'REQ-001',
+        typeKey: 'requirements',
+        title: 'Login',
+      },
+      {
+        name: 'diagram.PNG',
+        kind: 'attachment',
+        path: expect.stringMatching(/^assets\/.+\.png$/),
+      },
+    ]);
+    const [path, content] = vi.mocked(baseDiskService.writeTextFile).mock.calls[0];
+    expect(path).toBe('requirements/REQ-001.md');
+    expect(JSON.parse(content)).toMatchObject({ id: 'REQ-001', title: 'Login', status: 'draft' });
+    expect(projectService.save).toHaveBeenCalledWith(
+      expect.objectContaining({ requirementIds: ['REQ-000', 'REQ-001'] })
+    );
+    expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      expect.arrayContaining(['requirements/REQ-001.md', 'projects/proj-1.md']),
+      'Import 2 dropped files'
+    );
+  });
+
+  it('should skip hidden and empty files without committing', async () => {
+    const report = await dropImportService.importDroppedFiles(
+      [file('.DS_Store', 'x'), file('empty.md', '')],
+      'proj-1'
+    );
+
+    expect(report).toEqual({
+      created: [],
+      skipped: [
+        { name: '.DS_Store', reason: 'hidden file' },
+        { name: 'empty.md', reason: 'empty file' },
+      ],
+    });
+    expect(realGitService.commitFiles).not.toHaveBeenCalled();
+  });
+
+  it('should leave the project file alone when only attachments are dropped', async () => {
+    await dropImportService.importDroppedFiles([file('spec.pdf', '%PDF')], 'proj-1');
+
+    expect(projectService.save).not.toHaveBeenCalled();
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      [expect.stringMatching(/^assets\/.+\.pdf$/)],
+      'Import spec.pdf'
+    );
+  });
+});
@@ -0,0 +1,72 @@
+/**
+ * Duplicate Detection Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { findNearDuplicates, getComparableText } from '../duplicateDetectionService';
+import { ALL_ARTIFACT_SERVICES, projectService } from '../artifactServices';
+import type { Project } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    loadAll: vi.fn().mockResolvedValue([]),
+    serialize: vi.fn((item: { body: string }) => item.body),
+  });
+  return {
+    projectService: { load: vi.fn() },
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+  };
+});
+
+describe('duplicateDetectionService', () => {
+  const text = 'The operator can export the audit log as a CSV file.';
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      { id: 'REQ-001', body: `# Export\n\n## Description\n${text}` },
+      { id: 'REQ-002', body: `# Audit export\n\n## Requirement Text\n${text}` },
+      { id: 'REQ-003', body: text, isDeleted: true },
+    ]);
+    vi.mocked(ALL_ARTIFACT_SERVICES.information.loadAll).mockResolvedValue([
+      { id: 'INFO-001', body: text },
+    ]);
+  });
+
+  it('compares bodies without frontmatter and headings', () => {
+    expect(getComparableText('---\nid: REQ-001\n---\n\n# Title\n\n## Description\nText')).toBe(
+      '\nText'
+    );
+  });
+
+  it('reports duplicates within a type by default', async () => {
+    const pairs = await findNearDuplicates();
+
+    expect(pairs).toEqual([{ a: 'REQ-001', b: 'REQ-002', score: 1 }]);
+  });
+
+  it('compares across types when asked', async () => {
+    const pairs = await findNearDuplicates({ crossType: true });
+
+    expect(pairs.map((p) => `${p.a}/${p.b}`)).toEqual([
+      'REQ-001/REQ-002',
+      'REQ-001/INFO-001',
+      'REQ-002/INFO-001',
+    ]);
+  });
+
+  it('restricts the scan to project members', async () => {
+    vi.mocked(projectService.load).mockResolvedValue({
+      id: 'PROJ-001',
+      requirementIds: ['REQ-001'],
+    } as unknown as Project);
+
+    expect(await findNearDuplicates({ projectId: 'PROJ-001' })).toEqual([]);
+  });
+});
@@ -0,0 +1,90 @@
+/**
+ * Encryption Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { encryptionService } from '../encryptionService';
+import { baseDiskService, setEncryptionKey } from '../baseDiskService';
+import { secretService } from '../secretService';
+import { projectConfigService } from '../projectConfigService';
+
+vi.mock('../baseDiskService', () => ({
+  setEncryptionKey: vi.fn(),
+  baseDiskService: {
+    listFiles: vi.fn(),
+    readTextFile: vi.fn(),
+    writeTextFilesAndCommit: vi.fn(),
+  },
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+describe('encryptionService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({ enabled: false, keyId: '' });
+    vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+      folder === 'requirements' ? ['REQ-001.md', '.gitkeep'] : []
+    );
+    vi.mocked(baseDiskService.readTextFile).mockResolvedValue('# Login');
+  });
+
+  describe('loadEncryption', () => {
+    it('should load the key from the keychain for encrypted projects', async () => {
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({ enabled: true, keyId: 'k1' });
+      vi.mocked(secretService.getSecret).mockResolvedValue('KEY');
+
+      expect(await encryptionService.loadEncryption()).toBe(true);
+      expect(secretService.getSecret).toHaveBeenCalledWith('encryption-k1');
+      expect(setEncryptionKey).toHaveBeenLastCalledWith('KEY');
+    });
+
+    it('should fail when the key is not on this machine', async () => {
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({ enabled: true, keyId: 'k1' });
+      vi.mocked(secretService.getSecret).mockResolvedValue(null);
+
+      await expect(encryptionService.loadEncryption()).rejects.toThrow('import it');
+      expect(setEncryptionKey).toHaveBeenLastCalledWith(null);
+    });
+  });
+
+  describe('enableEncryption', () => {
+    it('should store a new key, enable the mode and re-save artifacts encrypted', async () => {
+      const key = await encryptionService.enableEncryption();
+
+      const { keyId } = vi.mocked(projectConfigService.setSection).mock.calls[0][1] as {
+        keyId: string;
+      };
+      expect(secretService.storeSecret).toHaveBeenCalledWith(`encryption-${keyId}`, key);
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'encryption',
+        { enabled: true, keyId },
+        'Enable encryption at rest'
+      );
+      expect(setEncryptionKey).toHaveBeenLastCalledWith(key);
+      expect(baseDiskService.writeTextFilesAndCommit).toHaveBeenCalledWith(
+        [{ path: 'requirements/REQ-001.md', content: '# Login' }],
+        'Encrypt artifacts at rest'
+      );
+    });
+  });
+
+  describe('importEncryptionKey', () => {
+    it('should not store a key that cannot decrypt the files', async () => {
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({ enabled: true, keyId: 'k1' });
+      vi.mocked(baseDiskService.readTextFile).mockRejectedValue(new Error('Cannot decrypt'));
+
+      await expect(encryptionService.importEncryptionKey('WRONG')).rejects.toThrow(
+        'Cannot decrypt'
+      );
+      expect(secretService.storeSecret).not.toHaveBeenCalled();
+      expect(setEncryptionKey).toHaveBeenLastCalledWith(null);
+    });
+  });
+});
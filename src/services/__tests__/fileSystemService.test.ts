@@ -6,7 +6,7 @@
  * so we test what we can without it and document the expected behaviors.
  */
 
-import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 
 // We'll need to mock IndexedDB for some tests
 const mockIDBDatabase = {
@@ -85,6 +85,22 @@ describe('FileSystemService', () => {
       );
     });
 
+    it('readFiles should throw when no directory selected', async () => {
+      const { fileSystemService } = await import('../fileSystemService');
+
+      await expect(fileSystemService.readFiles(['a.md', 'b.md'])).rejects.toThrow(
+        'No directory selected'
+      );
+    });
+
+    it('readFileChunk should throw when no directory selected', async () => {
+      const { fileSystemService } = await import('../fileSystemService');
+
+      await expect(fileSystemService.readFileChunk('some/path', 0, 1024)).rejects.toThrow(
+        'No directory selected'
+      );
+    });
+
     it('getDirectory should return null when no handle set', async () => {
       const { fileSystemService } = await import('../fileSystemService');
 
@@ -271,3 +287,238 @@ describe('Directory Listing', () => {
     expect(filtered).toEqual(['REQ-001.md', 'REQ-002.md']);
   });
 });
+
+describe('Electron Project Folder', () => {
+  const project = { open: vi.fn(), close: vi.fn(), onFilesChanged: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    localStorage.clear();
+    window.electronAPI = { isElectron: true, project } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should open a folder through the main process and remember it', async () => {
+    const { fileSystemService } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+
+    const state = await fileSystemService.openDirectoryPath('/work/braking/');
+
+    expect(project.open).toHaveBeenCalledWith('/work/braking/');
+    expect(state).toEqual({ path: '/work/braking', hasGit: true });
+    expect(fileSystemService.getRootPath()).toBe('/work/braking');
+
+    await fileSystemService.clearDirectory();
+    expect(project.close).toHaveBeenCalled();
+    expect(fileSystemService.getRootPath()).toBeNull();
+  });
+
+  it('should not restore a folder the main process refuses to open', async () => {
+    const { fileSystemService } = await import('../fileSystemService');
+    localStorage.setItem('tracyfy-electron-dir', '/gone');
+    project.open.mockResolvedValue({ error: 'Folder not found: /gone' });
+
+    expect(await fileSystemService.restoreDirectory()).toBeNull();
+    expect(localStorage.getItem('tracyfy-electron-dir')).toBeNull();
+  });
+});
+
+describe('Batched Operations', () => {
+  const project = { open: vi.fn(), close: vi.fn() };
+  const fs = { batch: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    localStorage.clear();
+    window.electronAPI = { isElectron: true, project, fs } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should send the operations in one call and map each result', async () => {
+    const { fileSystemService } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+    fs.batch.mockResolvedValue({
+      results: [
+        { content: '\uFEFF# REQ-001' },
+        { notFound: true },
+        { ok: true },
+        { files: ['REQ-001.md'] },
+        { mtime: 1000, size: 12 },
+        { error: 'EACCES' },
+      ],
+    });
+
+    const results = await fileSystemService.batch([
+      { type: 'read', path: 'requirements/REQ-001.md' },
+      { type: 'read', path: 'comments/REQ-001.json' },
+      { type: 'write', path: 'requirements/REQ-002.md', content: '# REQ-002' },
+      { type: 'list', path: 'requirements' },
+      { type: 'stat', path: 'requirements/REQ-001.md' },
+      { type: 'delete', path: 'locked.md' },
+    ]);
+
+    expect(fs.batch).toHaveBeenCalledTimes(1);
+    expect(fs.batch.mock.calls[0][0][2]).toEqual({
+      type: 'write',
+      path: '/work/braking/requirements/REQ-002.md',
+      content: '# REQ-002',
+    });
+    expect(results).toEqual([
+      { content: '# REQ-001' },
+      { content: null },
+      {},
+      { files: ['REQ-001.md'] },
+      { stat: { mtime: 1000, size: 12 } },
+      { error: 'EACCES' },
+    ]);
+
+    await fileSystemService.clearDirectory();
+  });
+
+  it('should read and write links by ID rather than path', async () => {
+    const { fileSystemService } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+    const link = { path: 'links/LINK-001.md', content: '---\nsourceId: REQ-001\n---' };
+    fs.batch.mockResolvedValue({ results: [{ links: [link] }, { ok: true }] });
+
+    const operations = [
+      { type: 'link' as const, artifactId: 'REQ-001' },
+      { type: 'link' as const, linkId: 'LINK-002', content: '---\nsourceId: REQ-002\n---' },
+    ];
+    const results = await fileSystemService.batch(operations);
+
+    expect(fs.batch).toHaveBeenCalledWith(operations);
+    expect(results).toEqual([{ links: [link] }, {}]);
+
+    await fileSystemService.clearDirectory();
+  });
+});
+
+describe('Project Root Scoping', () => {
+  const project = { open: vi.fn(), close: vi.fn() };
+  const fs = { readFile: vi.fn(), deleteFile: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    localStorage.clear();
+    window.electronAPI = { isElectron: true, project, fs } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should raise a typed error for paths the main process refuses', async () => {
+    const { fileSystemService, PathOutsideProjectError } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+    const refused = {
+      error: 'Path is outside the open project: /work/braking/../secrets.txt',
+      code: 'EOUTSIDEPROJECT',
+    };
+    fs.readFile.mockResolvedValue(refused);
+    fs.deleteFile.mockResolvedValue(refused);
+
+    await expect(fileSystemService.readFile('../secrets.txt')).rejects.toBeInstanceOf(
+      PathOutsideProjectError
+    );
+    await expect(fileSystemService.deleteFile('../secrets.txt')).rejects.toThrow(
+      'outside the open project'
+    );
+
+    fs.readFile.mockResolvedValue({ error: 'EACCES: permission denied' });
+    await expect(fileSystemService.readFile('locked.md')).rejects.not.toBeInstanceOf(
+      PathOutsideProjectError
+    );
+
+    await fileSystemService.clearDirectory();
+  });
+});
+
+describe('Argument Validation', () => {
+  const project = { open: vi.fn(), close: vi.fn() };
+  const fs = { writeFile: vi.fn(), readFiles: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    localStorage.clear();
+    window.electronAPI = { isElectron: true, project, fs } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should raise a typed error for arguments the main process rejects', async () => {
+    const { fileSystemService, InvalidArgumentError } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+    const rejected = (error: string) => ({ error, code: 'EINVALIDARG' });
+    fs.writeFile.mockResolvedValue(rejected('Invalid content: must be a string'));
+    fs.readFiles.mockResolvedValue(rejected('Invalid paths: must be a list'));
+
+    await expect(
+      fileSystemService.writeFile('requirements/REQ-001.md', '# REQ-001')
+    ).rejects.toBeInstanceOf(InvalidArgumentError);
+    await expect(fileSystemService.readFiles(['requirements/REQ-001.md'])).rejects.toThrow(
+      'Invalid paths: must be a list'
+    );
+
+    await fileSystemService.clearDirectory();
+  });
+});
+
+describe('Read-only Mode', () => {
+  const project = { open: vi.fn(), close: vi.fn(), setReadOnly: vi.fn() };
+  const fs = { writeFile: vi.fn(), mkdir: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    localStorage.clear();
+    window.electronAPI = { isElectron: true, project, fs } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    delete (window as unknown as Record<string, unknown>).electronAPI;
+  });
+
+  it('should switch the main process to read-only and take the mode main keeps', async () => {
+    const { fileSystemService, ReadOnlyProjectError } = await import('../fileSystemService');
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true });
+    project.setReadOnly.mockResolvedValue({ readOnly: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+
+    expect(await fileSystemService.setReadOnly(true)).toBe(true);
+    expect(project.setReadOnly).toHaveBeenCalledWith(true);
+    expect(fileSystemService.isReadOnly()).toBe(true);
+
+    fs.writeFile.mockResolvedValue({
+      error: 'The project is open read-only, cannot write files',
+      code: 'EREADONLY',
+    });
+    await expect(
+      fileSystemService.writeFile('requirements/REQ-001.md', '# REQ-001')
+    ).rejects.toBeInstanceOf(ReadOnlyProjectError);
+
+    // Main remembers the mode for the folder and may keep it when asked to turn it off
+    project.open.mockResolvedValue({ path: '/work/braking', hasGit: true, readOnly: true });
+    await fileSystemService.openDirectoryPath('/work/braking');
+    expect(fileSystemService.isReadOnly()).toBe(true);
+    project.setReadOnly.mockResolvedValue({ readOnly: true, canceled: true });
+    expect(await fileSystemService.setReadOnly(false)).toBe(true);
+
+    project.open.mockResolvedValue({ path: '/work/other', hasGit: true });
+    await fileSystemService.openDirectoryPath('/work/other');
+    expect(fileSystemService.isReadOnly()).toBe(false);
+
+    await fileSystemService.clearDirectory();
+  });
+});
@@ -157,15 +157,19 @@ describe('RealGitService - Remote Operations', () => {
   });
 
   describe('setAuthToken / clearAuthToken', () => {
-    it('should store token in localStorage', () => {
-      realGitService.setAuthToken('ghp_testtoken123');
+    it('should store token in the secret store', async () => {
+      await realGitService.setAuthToken('ghp_testtoken123');
 
-      expect(localStorage.setItem).toHaveBeenCalledWith('git_pat_token', 'ghp_testtoken123');
+      expect(localStorage.setItem).toHaveBeenCalledWith(
+        'integration_token_git',
+        'ghp_testtoken123'
+      );
     });
 
-    it('should clear token from localStorage', () => {
-      realGitService.clearAuthToken();
+    it('should clear token from the secret store and legacy storage', async () => {
+      await realGitService.clearAuthToken();
 
+      expect(localStorage.removeItem).toHaveBeenCalledWith('integration_token_git');
       expect(localStorage.removeItem).toHaveBeenCalledWith('git_pat_token');
     });
   });
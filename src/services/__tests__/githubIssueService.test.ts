@@ -0,0 +1,193 @@
+/**
+ * GitHub Issue Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { githubIssueService, parseGitHubRemote, GITHUB_SYNC_EVENT } from '../githubIssueService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { secretService } from '../secretService';
+import { HttpError, requestJson } from '../httpClient';
+import { projectConfigService } from '../projectConfigService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../httpClient', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../httpClient')>()),
+  requestJson: vi.fn(),
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: { requirements: service(), testcases: service() },
+  };
+});
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    commitFiles: vi.fn(),
+    getRemotes: vi.fn(),
+    ensureTokenLoaded: vi.fn(),
+    getAuthToken: vi.fn(),
+  },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'testcases'],
+}));
+
+const config = {
+  owner: '',
+  repo: '',
+  stateMap: { closed: 'approved' },
+  syncIntervalMinutes: 0,
+};
+
+const requirement = (id: string, extra: object = {}) => ({
+  id,
+  title: `Title ${id}`,
+  description: `Description ${id}`,
+  status: 'draft',
+  tags: ['auth'],
+  lastModified: 0,
+  ...extra,
+});
+
+const issue = (number: number, state: string, labels: string[] = []) => ({
+  number,
+  html_url: `https://github.com/acme/app/issues/${number}`,
+  state,
+  labels: labels.map((name) => ({ name })),
+});
+
+describe('githubIssueService', () => {
+  const requirements = ALL_ARTIFACT_SERVICES.requirements;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockResolvedValue(config);
+    vi.mocked(secretService.getSecret).mockResolvedValue(null);
+    vi.mocked(realGitService.getAuthToken).mockReturnValue('git-token');
+    vi.mocked(realGitService.getRemotes).mockResolvedValue([
+      { name: 'origin', url: 'https://github.com/acme/app.git' },
+    ]);
+  });
+
+  describe('parseGitHubRemote', () => {
+    it('should read owner and repository from https and ssh remotes', () => {
+      expect(parseGitHubRemote('https://github.com/acme/app.git')).toEqual({
+        owner: 'acme',
+        repo: 'app',
+      });
+      expect(parseGitHubRemote('git@github.com:acme/app')).toEqual({ owner: 'acme', repo: 'app' });
+      expect(parseGitHubRemote('https://gitlab.com/acme/app.git')).toBeNull();
+    });
+  });
+
+  describe('createGitHubIssues', () => {
+    it('should create issues for unlinked artifacts using the git token', async () => {
+      vi.mocked(requirements.load).mockImplementation(async (id: string) =>
+        id === 'REQ-002'
+          ? requirement(id, { externalIssues: [{ tracker: 'github', key: 'acme/app#1' }] })
+          : requirement(id)
+      );
+      vi.mocked(requestJson).mockResolvedValueOnce(issue(5, 'open', ['auth']));
+
+      const results = await githubIssueService.createGitHubIssues(['REQ-001', 'REQ-002']);
+
+      expect(results).toEqual([
+        {
+          artifactId: 'REQ-001',
+          issueKey: 'acme/app#5',
+          url: 'https://github.com/acme/app/issues/5',
+        },
+      ]);
+      expect(requestJson).toHaveBeenCalledTimes(1);
+      expect(requestJson).toHaveBeenCalledWith({
+        integration: 'github',
+        url: 'https://api.github.com/repos/acme/app/issues',
+        method: 'POST',
+        headers: expect.objectContaining({ Authorization: 'Bearer git-token' }),
+        json: { title: '[REQ-001] Title REQ-001', body: 'Description REQ-001', labels: ['auth'] },
+      });
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          externalIssues: [
+            expect.objectContaining({ tracker: 'github', key: 'acme/app#5', labels: ['auth'] }),
+          ],
+        })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Create 1 GitHub issues'
+      );
+    });
+
+    it('should require a token', async () => {
+      vi.mocked(realGitService.getAuthToken).mockReturnValue(null);
+
+      await expect(githubIssueService.createGitHubIssues(['REQ-001'])).rejects.toThrow(
+        'No GitHub token stored'
+      );
+    });
+  });
+
+  describe('syncGitHubIssues', () => {
+    it('should pull state and labels, apply mapped statuses and emit the result', async () => {
+      vi.mocked(requirements.loadAll).mockResolvedValue([
+        requirement('REQ-001', {
+          externalIssues: [{ tracker: 'github', key: 'acme/app#1', status: 'open' }],
+        }),
+        requirement('REQ-002', {
+          externalIssues: [{ tracker: 'github', key: 'acme/app#2', status: 'open' }],
+        }),
+      ]);
+      vi.mocked(requestJson).mockImplementation(async ({ url }) => {
+        if (url.endsWith('/2')) throw new HttpError(404, 'not found');
+        return issue(1, 'closed', ['done']);
+      });
+      const listener = vi.fn();
+      window.addEventListener(GITHUB_SYNC_EVENT, listener);
+
+      const result = await githubIssueService.syncGitHubIssues();
+      window.removeEventListener(GITHUB_SYNC_EVENT, listener);
+
+      expect(result).toMatchObject({ checked: 1, updated: ['REQ-001'], missing: ['acme/app#2'] });
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'REQ-001',
+          status: 'approved',
+          statusChangedBy: 'GitHub',
+          externalIssues: [
+            expect.objectContaining({ key: 'acme/app#1', status: 'closed', labels: ['done'] }),
+          ],
+        })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Sync GitHub issues: 1 artifacts updated'
+      );
+      expect(listener.mock.calls[0][0].detail).toEqual({ result });
+    });
+
+    it('should emit failures', async () => {
+      vi.mocked(realGitService.getRemotes).mockResolvedValue([]);
+      const listener = vi.fn();
+      window.addEventListener(GITHUB_SYNC_EVENT, listener);
+
+      await expect(githubIssueService.syncGitHubIssues()).rejects.toThrow();
+      window.removeEventListener(GITHUB_SYNC_EVENT, listener);
+
+      expect(listener.mock.calls[0][0].detail).toEqual({
+        error: 'No GitHub repository configured',
+      });
+    });
+  });
+});
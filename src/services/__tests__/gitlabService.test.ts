@@ -0,0 +1,259 @@
+/**
+ * GitLab Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  gitlabService,
+  parseGitLabKey,
+  parseGitLabRemote,
+  getRemoteHost,
+  GITLAB_SYNC_EVENT,
+} from '../gitlabService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { secretService } from '../secretService';
+import { HttpError, requestJson } from '../httpClient';
+import { projectConfigService } from '../projectConfigService';
+import { realGitService } from '../realGitService';
+import { appSettingsService } from '../appSettingsService';
+
+vi.mock('../httpClient', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../httpClient')>()),
+  requestJson: vi.fn(),
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: { requirements: service(), testcases: service() },
+  };
+});
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    commitFiles: vi.fn(),
+    getRemotes: vi.fn(),
+    ensureTokenLoaded: vi.fn(),
+    getAuthToken: vi.fn(),
+  },
+}));
+
+vi.mock('../appSettingsService', () => ({
+  appSettingsService: { getIntegrationUrls: vi.fn(), setIntegrationUrl: vi.fn() },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'testcases'],
+}));
+
+const config = {
+  baseUrl: 'https://gitlab.acme.io',
+  projectPath: '',
+  stateMap: { merged: 'approved' },
+  syncIntervalMinutes: 0,
+};
+
+const api = 'https://gitlab.acme.io/api/v4/projects/qa%2Fapp';
+
+const requirement = (id: string, extra: object = {}) => ({
+  id,
+  title: `Title ${id}`,
+  description: `Description ${id}`,
+  status: 'draft',
+  tags: ['auth', 'login'],
+  lastModified: 0,
+  ...extra,
+});
+
+const item = (iid: number, state: string, labels: string[] = []) => ({
+  iid,
+  web_url: `https://gitlab.acme.io/qa/app/-/issues/${iid}`,
+  state,
+  labels,
+});
+
+describe('gitlabService', () => {
+  const requirements = ALL_ARTIFACT_SERVICES.requirements;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({
+      ...config,
+      baseUrl: 'https://evil.example',
+    });
+    vi.mocked(appSettingsService.getIntegrationUrls).mockResolvedValue({
+      gitlab: 'https://gitlab.acme.io',
+    });
+    vi.mocked(secretService.getSecret).mockResolvedValue('gl-token');
+    vi.mocked(realGitService.getRemotes).mockResolvedValue([
+      { name: 'origin', url: 'git@gitlab.acme.io:qa/app.git' },
+    ]);
+  });
+
+  describe('keys and remotes', () => {
+    it('should parse project paths and reference keys', () => {
+      expect(parseGitLabRemote('https://gitlab.acme.io/qa/sub/app.git', 'gitlab.acme.io')).toBe(
+        'qa/sub/app'
+      );
+      expect(parseGitLabRemote('https://github.com/qa/app.git', 'gitlab.acme.io')).toBeNull();
+      expect(parseGitLabKey('qa/app!7')).toEqual({
+        projectPath: 'qa/app',
+        kind: 'merge-request',
+        iid: 7,
+      });
+      expect(parseGitLabKey('APP-7')).toBeNull();
+      expect(getRemoteHost('git@GitLab.acme.io:qa/app.git')).toBe('gitlab.acme.io');
+      expect(getRemoteHost('ssh://git@gitlab.acme.io:2222/qa/app.git')).toBe('gitlab.acme.io');
+    });
+  });
+
+  describe('config', () => {
+    it('should take the server from this machine, not from the project config', async () => {
+      expect(await gitlabService.getGitLabConfig()).toEqual(config);
+    });
+
+    it('should require https and keep the server out of the project config', async () => {
+      await expect(
+        gitlabService.setGitLabConfig({ ...config, baseUrl: 'http://gitlab.acme.io' })
+      ).rejects.toThrow('Base URL must be an https URL');
+
+      await gitlabService.setGitLabConfig({ ...config, baseUrl: 'https://gitlab.acme.io/' });
+
+      expect(appSettingsService.setIntegrationUrl).toHaveBeenCalledWith(
+        'gitlab',
+        'https://gitlab.acme.io'
+      );
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'gitlab',
+        { projectPath: '', stateMap: { merged: 'approved' }, syncIntervalMinutes: 0 },
+        'Update GitLab settings'
+      );
+    });
+  });
+
+  describe('createGitLabIssues', () => {
+    it('should create issues in the project of the origin remote', async () => {
+      vi.mocked(requirements.load).mockResolvedValue(
+        requirement('REQ-001', { externalIssues: [{ tracker: 'gitlab', key: 'qa/app!3' }] })
+      );
+      vi.mocked(requestJson).mockResolvedValueOnce(item(4, 'opened', ['login', 'auth']));
+
+      const results = await gitlabService.createGitLabIssues(['REQ-001']);
+
+      expect(results).toEqual([
+        {
+          artifactId: 'REQ-001',
+          issueKey: 'qa/app#4',
+          url: 'https://gitlab.acme.io/qa/app/-/issues/4',
+        },
+      ]);
+      expect(requestJson).toHaveBeenCalledWith({
+        integration: 'gitlab',
+        url: `${api}/issues`,
+        method: 'POST',
+        headers: { 'PRIVATE-TOKEN': 'gl-token' },
+        json: {
+          title: '[REQ-001] Title REQ-001',
+          description: 'Description REQ-001',
+          labels: 'auth,login',
+        },
+      });
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          externalIssues: [
+            { tracker: 'gitlab', key: 'qa/app!3' },
+            expect.objectContaining({ key: 'qa/app#4', labels: ['auth', 'login'] }),
+          ],
+        })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Create 1 GitLab issues'
+      );
+    });
+  });
+
+  describe('tokens', () => {
+    it('should fall back to the git token only for the host of the origin remote', async () => {
+      vi.mocked(secretService.getSecret).mockResolvedValue(null);
+      vi.mocked(realGitService.getAuthToken).mockReturnValue('git-token');
+      vi.mocked(requirements.load).mockResolvedValue(requirement('REQ-001'));
+      vi.mocked(requestJson).mockResolvedValue(item(7, 'merged'));
+
+      await gitlabService.linkGitLabItem('REQ-001', 'merge-request', 7);
+      expect(requestJson).toHaveBeenCalledWith(
+        expect.objectContaining({ headers: { 'PRIVATE-TOKEN': 'git-token' } })
+      );
+
+      vi.mocked(realGitService.getRemotes).mockResolvedValue([
+        { name: 'origin', url: 'git@github.com:qa/app.git' },
+      ]);
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({
+        ...config,
+        projectPath: 'qa/app',
+      });
+      await expect(gitlabService.linkGitLabItem('REQ-001', 'merge-request', 7)).rejects.toThrow(
+        'No GitLab token stored'
+      );
+    });
+  });
+
+  describe('linkGitLabItem', () => {
+    it('should link a merge request', async () => {
+      vi.mocked(requirements.load).mockResolvedValue(requirement('REQ-001'));
+      vi.mocked(requestJson).mockResolvedValueOnce(item(7, 'merged'));
+
+      await gitlabService.linkGitLabItem('REQ-001', 'merge-request', 7);
+
+      expect(requestJson).toHaveBeenCalledWith(
+        expect.objectContaining({ url: `${api}/merge_requests/7` })
+      );
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          externalIssues: [expect.objectContaining({ key: 'qa/app!7', status: 'merged' })],
+        }),
+        'Link REQ-001 to GitLab merge request qa/app!7'
+      );
+    });
+  });
+
+  describe('syncGitLab', () => {
+    it('should pull item state, apply mapped statuses and emit the result', async () => {
+      vi.mocked(requirements.loadAll).mockResolvedValue([
+        requirement('REQ-001', {
+          externalIssues: [
+            { tracker: 'gitlab', key: 'qa/app#1', status: 'opened', labels: [] },
+            { tracker: 'gitlab', key: 'qa/app!2', status: 'opened', labels: [] },
+          ],
+        }),
+      ]);
+      vi.mocked(requestJson).mockImplementation(async ({ url }) => {
+        if (url.endsWith('/issues/1')) throw new HttpError(404, 'not found');
+        return item(2, 'merged');
+      });
+      const listener = vi.fn();
+      window.addEventListener(GITLAB_SYNC_EVENT, listener);
+
+      const result = await gitlabService.syncGitLab();
+      window.removeEventListener(GITLAB_SYNC_EVENT, listener);
+
+      expect(result).toMatchObject({ checked: 1, updated: ['REQ-001'], missing: ['qa/app#1'] });
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({ status: 'approved', statusChangedBy: 'GitLab' })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Sync GitLab: 1 artifacts updated'
+      );
+      expect(listener.mock.calls[0][0].detail).toEqual({ result });
+    });
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Glossary Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { listTerms, defineTerm, scanArtifact, scanArtifacts } from '../glossaryService';
+import { ALL_ARTIFACT_SERVICES, glossaryTermService } from '../artifactServices';
+import { idService } from '../idService';
+import type { GlossaryTerm } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const emptyService = { load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]) };
+  return {
+    glossaryTermService: { loadAll: vi.fn(), save: vi.fn((item) => Promise.resolve(item)) },
+    ALL_ARTIFACT_SERVICES: {
+      requirements: {
+        load: vi.fn(),
+        loadAll: vi.fn(),
+        serialize: vi.fn((item: { text: string }) => item.text),
+      },
+      usecases: emptyService,
+      testcases: emptyService,
+      information: emptyService,
+      risks: emptyService,
+    },
+  };
+});
+
+vi.mock('../idService', () => ({
+  idService: { getNextIdWithSync: vi.fn() },
+}));
+
+const term = (id: string, name: string, extra: Partial<GlossaryTerm> = {}): GlossaryTerm => ({
+  id,
+  term: name,
+  definition: '',
+  synonyms: [],
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+  ...extra,
+});
+
+describe('glossaryService', () => {
+  const requirements = ALL_ARTIFACT_SERVICES.requirements;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(glossaryTermService.loadAll).mockResolvedValue([
+      term('TERM-002', 'Watchdog'),
+      term('TERM-001', 'Electronic Control Unit', { abbreviation: 'ECU' }),
+      term('TERM-003', 'Legacy', { isDeleted: true }),
+    ]);
+  });
+
+  it('lists defined terms alphabetically', async () => {
+    const terms = await listTerms();
+
+    expect(terms.map((t) => t.term)).toEqual(['Electronic Control Unit', 'Watchdog']);
+  });
+
+  it('defines new terms and rejects duplicates', async () => {
+    vi.mocked(idService.getNextIdWithSync).mockResolvedValue('TERM-004');
+
+    const created = await defineTerm({
+      term: ' Brake Pedal ',
+      definition: 'Pedal that applies the brakes.',
+      synonyms: ['pedal', 'pedal'],
+    });
+
+    expect(created).toMatchObject({ id: 'TERM-004', term: 'Brake Pedal', synonyms: ['pedal'] });
+    await expect(
+      defineTerm({ term: 'Control', definition: '', abbreviation: 'ecu' })
+    ).rejects.toThrow('"ecu" is already defined by TERM-001');
+  });
+
+  it('scans a single artifact', async () => {
+    vi.mocked(requirements.load).mockResolvedValue({
+      id: 'REQ-001',
+      text: 'The ECU resets the Watchdog.\nThe Brake Pedal is sampled.',
+    });
+
+    const result = await scanArtifact('REQ-001');
+
+    expect(result.terms.map((o) => o.termId)).toEqual(['TERM-001', 'TERM-002']);
+    expect(result.undefinedTerms.map((o) => o.text)).toEqual(['Brake Pedal']);
+  });
+
+  it('rejects artifacts that are not scanned', async () => {
+    await expect(scanArtifact('LINK-001')).rejects.toThrow('not scanned');
+  });
+
+  it('returns only artifacts with findings when scanning all', async () => {
+    vi.mocked(requirements.loadAll).mockResolvedValue([
+      { id: 'REQ-001', text: 'Uses the watchdog.' },
+      { id: 'REQ-002', text: 'nothing to see here' },
+    ]);
+
+    const results = await scanArtifacts();
+
+    expect(results.map((r) => r.artifactId)).toEqual(['REQ-001']);
+  });
+});
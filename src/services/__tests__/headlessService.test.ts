@@ -0,0 +1,129 @@
+/**
+ * Headless Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { headlessService } from '../headlessService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import { createBaseline } from '../baselineService';
+import { generateTraceMatrix, type TraceMatrix } from '../traceMatrixService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { openDirectoryPath: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { init: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      projects: service(),
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+    },
+  };
+});
+
+vi.mock('../baselineService', () => ({
+  listBaselines: vi.fn(),
+  createBaseline: vi.fn(),
+}));
+
+vi.mock('../traceMatrixService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../traceMatrixService')>()),
+  generateTraceMatrix: vi.fn(),
+}));
+
+vi.mock('../referenceValidationService', () => ({
+  referenceValidationService: { validate: vi.fn() },
+}));
+
+vi.mock('../../utils/jsonExportUtils', () => ({
+  buildExportData: vi.fn(async (project, state, requirementIds) => ({
+    project: project.id,
+    requirements: state.requirements.filter((r: { id: string }) => requirementIds.includes(r.id)),
+  })),
+}));
+
+describe('headlessService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(realGitService.init).mockResolvedValue(true);
+  });
+
+  it('should open the folder and its repository, and fail without one', async () => {
+    await headlessService.openProject('/work/app');
+    expect(fileSystemService.openDirectoryPath).toHaveBeenCalledWith('/work/app');
+
+    vi.mocked(realGitService.init).mockResolvedValue(false);
+    await expect(headlessService.openProject('/work/app')).rejects.toThrow(
+      'Could not open the git repository in /work/app'
+    );
+  });
+
+  it('should export only the artifacts of the project', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.projects.load).mockResolvedValue({
+      id: 'proj-1',
+      requirementIds: ['REQ-001'],
+    });
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      { id: 'REQ-001' },
+      { id: 'REQ-002' },
+    ]);
+
+    expect(await headlessService.exportProject('proj-1')).toEqual({
+      project: 'proj-1',
+      requirements: [{ id: 'REQ-001' }],
+    });
+
+    vi.mocked(ALL_ARTIFACT_SERVICES.projects.load).mockResolvedValue(null);
+    await expect(headlessService.exportProject('proj-9')).rejects.toThrow(
+      'Project not found: proj-9'
+    );
+  });
+
+  it('should return the trace matrix with its table rows', async () => {
+    const matrix: TraceMatrix = {
+      fromType: 'requirements',
+      toType: 'testcases',
+      columns: [{ id: 'TC-001', title: 'Login' }],
+      rows: [
+        {
+          id: 'REQ-001',
+          title: 'Auth',
+          cells: [{ targetId: 'TC-001', linkTypes: [] }],
+          linkedCount: 0,
+        },
+      ],
+      coveredRows: 0,
+    };
+    vi.mocked(generateTraceMatrix).mockResolvedValue(matrix);
+
+    const result = await headlessService.getTraceMatrix('requirements', 'testcases', 'proj-1');
+
+    expect(generateTraceMatrix).toHaveBeenCalledWith('requirements', 'testcases', 'proj-1');
+    expect(result.matrix).toBe(matrix);
+    expect(result.table).toEqual([
+      ['ID', 'Title', 'TC-001'],
+      ['REQ-001', 'Auth', ''],
+    ]);
+  });
+
+  it('should create baselines with the project and version as options', async () => {
+    await headlessService.createBaseline('proj-1', '1.0', 'Release');
+
+    expect(createBaseline).toHaveBeenCalledWith('Release', '', {
+      projectId: 'proj-1',
+      version: '1.0',
+    });
+  });
+});
@@ -0,0 +1,116 @@
+/**
+ * Hierarchy Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  getHierarchyNumbers,
+  resolveHierarchyNumber,
+  renumberSubtree,
+  setParent,
+  unsetParent,
+  getChildren,
+  getHierarchyTree,
+} from '../hierarchyService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: {
+      loadAll: vi.fn(),
+      save: vi.fn(),
+    },
+  },
+}));
+
+describe('hierarchyService', () => {
+  const requirements = [
+    { id: 'REQ-001', lastModified: 0 },
+    { id: 'REQ-002', parentId: 'REQ-001', order: 5, lastModified: 0 },
+    { id: 'REQ-003', parentId: 'REQ-001', order: 9, lastModified: 0 },
+    { id: 'REQ-004', parentId: 'REQ-003', lastModified: 0 },
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue(requirements);
+  });
+
+  it('computes hierarchical numbers for a type', async () => {
+    const numbers = await getHierarchyNumbers('requirements');
+
+    expect(Object.fromEntries(numbers)).toEqual({
+      'REQ-001': '1',
+      'REQ-002': '1.1',
+      'REQ-003': '1.2',
+      'REQ-004': '1.2.1',
+    });
+  });
+
+  it('resolves an artifact by its hierarchical number', async () => {
+    expect((await resolveHierarchyNumber('requirements', '1.2.1'))?.id).toBe('REQ-004');
+    expect(await resolveHierarchyNumber('requirements', '3')).toBeNull();
+  });
+
+  it('rejects types without hierarchy support', async () => {
+    await expect(getHierarchyNumbers('links')).rejects.toThrow('does not support hierarchy');
+  });
+
+  it('compacts sibling order within a subtree', async () => {
+    const updated = await renumberSubtree('requirements', 'REQ-001');
+
+    expect(updated.map((a) => [a.id, a.order])).toEqual([
+      ['REQ-002', 1],
+      ['REQ-003', 2],
+      ['REQ-004', 1],
+    ]);
+    expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledTimes(3);
+  });
+
+  describe('parent management', () => {
+    it('sets a parent and appends after existing siblings', async () => {
+      const updated = await setParent('requirements', 'REQ-004', 'REQ-001');
+
+      expect(updated).toMatchObject({ id: 'REQ-004', parentId: 'REQ-001', order: 10 });
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(updated);
+    });
+
+    it('rejects parents that would create a cycle', async () => {
+      await expect(setParent('requirements', 'REQ-001', 'REQ-004')).rejects.toThrow(
+        'would create a cycle'
+      );
+      await expect(setParent('requirements', 'REQ-001', 'REQ-001')).rejects.toThrow(
+        'would create a cycle'
+      );
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).not.toHaveBeenCalled();
+    });
+
+    it('rejects unknown parents', async () => {
+      await expect(setParent('requirements', 'REQ-002', 'REQ-404')).rejects.toThrow(
+        'Artifact not found: REQ-404'
+      );
+    });
+
+    it('unsets a parent, making the artifact a root', async () => {
+      const updated = await unsetParent('requirements', 'REQ-003');
+
+      expect(updated.parentId).toBeUndefined();
+      expect(updated.order).toBe(2);
+    });
+
+    it('lists children in sibling order', async () => {
+      const children = await getChildren('requirements', 'REQ-001');
+
+      expect(children.map((c) => c.id)).toEqual(['REQ-002', 'REQ-003']);
+    });
+
+    it('builds the numbered tree', async () => {
+      const tree = await getHierarchyTree('requirements');
+
+      expect(tree).toHaveLength(1);
+      expect(tree[0].number).toBe('1');
+      expect(tree[0].children.map((c) => c.number)).toEqual(['1.1', '1.2']);
+      expect(tree[0].children[1].children[0].artifact.id).toBe('REQ-004');
+    });
+  });
+});
@@ -2,10 +2,25 @@
  * Impact Analysis Service Tests
  */
 
-import { describe, it, expect } from 'vitest';
-import { impactAnalysisService, getImpactChain, getImpactSummary } from '../impactAnalysisService';
+import { describe, it, expect, vi } from 'vitest';
+import {
+  impactAnalysisService,
+  getImpactChain,
+  getImpactSummary,
+  getImpactPath,
+  getAffectedArtifacts,
+  analyzeImpact,
+} from '../impactAnalysisService';
+import { diskLinkService } from '../diskLinkService';
 import type { Link } from '../../types';
 
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    getAllLinks: vi.fn(),
+    getLinksForProject: vi.fn(),
+  },
+}));
+
 describe('impactAnalysisService', () => {
   // Helper to create test links
   const createLink = (sourceId: string, targetId: string, type: string = 'depends_on'): Link => ({
@@ -148,6 +163,98 @@ describe('impactAnalysisService', () => {
     });
   });
 
+  describe('link categories', () => {
+    it('only follows links in the requested categories', () => {
+      const links: Link[] = [
+        createLink('REQ-001', 'REQ-002', 'derives_to'),
+        createLink('REQ-001', 'TC-001', 'verified_by'),
+        createLink('REQ-002', 'TC-002', 'verified_by'),
+      ];
+
+      const verification = getImpactChain('REQ-001', links, 'downstream', 0, ['verification']);
+      const derivation = getImpactChain('REQ-001', links, 'downstream', 0, ['derivation']);
+
+      expect(verification.nodes.map((n) => n.artifactId)).toEqual(['TC-001']);
+      expect(derivation.nodes.map((n) => n.artifactId)).toEqual(['REQ-002']);
+    });
+
+    it('does not propagate impact along association links unless asked to', () => {
+      const links: Link[] = [
+        createLink('REQ-001', 'REQ-002', 'related_to'),
+        createLink('REQ-001', 'TC-001', 'verified_by'),
+      ];
+
+      const chain = getImpactChain('REQ-001', links, 'downstream');
+      const associations = getImpactChain('REQ-001', links, 'downstream', 0, ['association']);
+
+      expect(chain.nodes.map((n) => n.artifactId)).toEqual(['TC-001']);
+      expect(associations.nodes.map((n) => n.artifactId)).toEqual(['REQ-002']);
+    });
+  });
+
+  describe('getImpactPath', () => {
+    it('reconstructs the path from the source to a transitive artifact', () => {
+      const links: Link[] = [
+        createLink('REQ-001', 'UC-001'),
+        createLink('UC-001', 'TC-001'),
+        createLink('REQ-001', 'UC-002'),
+      ];
+
+      const chain = getImpactChain('REQ-001', links, 'downstream');
+
+      expect(getImpactPath(chain, 'TC-001')).toEqual(['REQ-001', 'UC-001', 'TC-001']);
+      expect(getImpactPath(chain, 'UC-002')).toEqual(['REQ-001', 'UC-002']);
+      expect(getImpactPath(chain, 'TC-999')).toEqual([]);
+    });
+  });
+
+  describe('getAffectedArtifacts', () => {
+    it('returns each affected artifact with its path and hop link types', () => {
+      const links: Link[] = [
+        createLink('REQ-001', 'UC-001', 'derives_to'),
+        createLink('UC-001', 'TC-001', 'verified_by'),
+      ];
+
+      const affected = getAffectedArtifacts(getImpactChain('REQ-001', links, 'downstream'));
+
+      expect(affected).toContainEqual({
+        artifactId: 'TC-001',
+        artifactType: 'testCase',
+        level: 2,
+        direction: 'downstream',
+        path: ['REQ-001', 'UC-001', 'TC-001'],
+        linkTypes: ['derives_to', 'verified_by'],
+      });
+    });
+  });
+
+  describe('analyzeImpact', () => {
+    it('walks downstream only unless upstream is requested', async () => {
+      vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+        createLink('REQ-000', 'REQ-001'),
+        createLink('REQ-001', 'TC-001'),
+      ]);
+
+      const downstream = await analyzeImpact('REQ-001');
+      const both = await analyzeImpact('REQ-001', 0, true);
+
+      expect(downstream.map((a) => a.artifactId)).toEqual(['TC-001']);
+      expect(both.map((a) => a.artifactId).sort()).toEqual(['REQ-000', 'TC-001']);
+    });
+
+    it('respects the depth limit and project visibility', async () => {
+      vi.mocked(diskLinkService.getLinksForProject).mockResolvedValue([
+        createLink('REQ-001', 'UC-001'),
+        createLink('UC-001', 'TC-001'),
+      ]);
+
+      const affected = await analyzeImpact('REQ-001', 1, false, 'PROJ-001');
+
+      expect(diskLinkService.getLinksForProject).toHaveBeenCalledWith('PROJ-001');
+      expect(affected.map((a) => a.artifactId)).toEqual(['UC-001']);
+    });
+  });
+
   describe('service exports', () => {
     it('exports all required functions', () => {
       expect(impactAnalysisService.getImpactChain).toBeDefined();
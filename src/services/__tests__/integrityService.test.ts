@@ -0,0 +1,138 @@
+/**
+ * Integrity Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { verifyIntegrity } from '../integrityService';
+import { approvalService } from '../approvalService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { getBaselineContents, listBaselines } from '../baselineService';
+import type { ProjectBaseline } from '../../types';
+
+vi.mock('../approvalService', () => ({
+  approvalService: { checkApproval: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: { loadAll: vi.fn(), serialize: vi.fn(() => '# REQ') },
+    usecases: { loadAll: vi.fn(), serialize: vi.fn() },
+    testcases: { loadAll: vi.fn(), serialize: vi.fn() },
+    risks: { loadAll: vi.fn(), serialize: vi.fn() },
+  },
+}));
+
+vi.mock('../baselineService', () => ({
+  listBaselines: vi.fn(),
+  getBaselineContents: vi.fn(),
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'usecases', 'testcases', 'risks'],
+}));
+
+const baseline = {
+  id: 'bl-1',
+  projectId: 'proj-1',
+  version: '1.0',
+  tag: 'baseline/proj-1/1.0',
+  manifest: { 'REQ-001': 'hash-1', 'REQ-002': 'hash-2', 'REQ-003': 'hash-3' },
+} as unknown as ProjectBaseline;
+
+const baselineArtifact = (id: string, content: string | null, intact: boolean) => ({
+  id,
+  type: 'requirement' as const,
+  path: `requirements/${id}.md`,
+  commitHash: `c-${id}`,
+  content,
+  intact,
+});
+
+describe('integrityService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    for (const service of Object.values(ALL_ARTIFACT_SERVICES)) {
+      vi.mocked(service.loadAll).mockResolvedValue([]);
+    }
+    vi.mocked(listBaselines).mockResolvedValue([]);
+  });
+
+  describe('verifyIntegrity', () => {
+    it('should report approved artifacts edited after approval or never approved', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        { id: 'REQ-001', status: 'approved' },
+        { id: 'REQ-002', status: 'approved' },
+        { id: 'REQ-003', status: 'approved' },
+        { id: 'REQ-004', status: 'draft' },
+        { id: 'REQ-005', status: 'approved', isDeleted: true },
+      ]);
+      const approval = { artifactId: 'REQ-001', reviewer: 'Alice', timestamp: 0 };
+      vi.mocked(approvalService.checkApproval).mockImplementation(async (path) => {
+        if (path === 'requirements/REQ-001.md') {
+          return {
+            artifactId: 'REQ-001',
+            approval: { ...approval, contentHash: 'approved-hash' },
+            currentHash: 'edited-hash',
+            editedSinceApproval: true,
+          };
+        }
+        if (path === 'requirements/REQ-002.md') {
+          return {
+            artifactId: 'REQ-002',
+            approval: { ...approval, artifactId: 'REQ-002', contentHash: 'same' },
+            currentHash: 'same',
+            editedSinceApproval: false,
+          };
+        }
+        return null;
+      });
+
+      const report = await verifyIntegrity();
+
+      expect(report.checkedArtifacts).toBe(3);
+      expect(report.issues).toEqual([
+        {
+          kind: 'edited-after-approval',
+          artifactId: 'REQ-001',
+          path: 'requirements/REQ-001.md',
+          reference: 'approval by Alice at 1970-01-01T00:00:00.000Z',
+          expected: 'approved-hash',
+          actual: 'edited-hash',
+        },
+        expect.objectContaining({
+          kind: 'approved-without-record',
+          artifactId: 'REQ-003',
+          expected: null,
+        }),
+      ]);
+    });
+
+    it('should report baseline artifacts that no longer match the manifest', async () => {
+      vi.mocked(listBaselines).mockResolvedValue([baseline]);
+      vi.mocked(getBaselineContents).mockResolvedValue({
+        baseline,
+        artifacts: [
+          baselineArtifact('REQ-001', '# 1', true),
+          baselineArtifact('REQ-002', '# 2', false),
+          baselineArtifact('REQ-003', null, false),
+        ],
+      });
+
+      const report = await verifyIntegrity('proj-1');
+
+      expect(listBaselines).toHaveBeenCalledWith('proj-1');
+      expect(getBaselineContents).toHaveBeenCalledWith('bl-1', 'proj-1');
+      expect(report.checkedBaselines).toBe(1);
+      expect(report.issues.map(({ kind, artifactId }) => [kind, artifactId])).toEqual([
+        ['baseline-mismatch', 'REQ-002'],
+        ['baseline-missing', 'REQ-003'],
+      ]);
+      expect(report.issues[0]).toMatchObject({
+        reference: 'baseline baseline/proj-1/1.0',
+        expected: 'hash-2',
+      });
+      expect(report.issues[0].actual).toMatch(/^[0-9a-f]{64}$/);
+      expect(report.issues[1].actual).toBeNull();
+    });
+  });
+});
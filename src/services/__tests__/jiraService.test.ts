@@ -0,0 +1,222 @@
+/**
+ * Jira Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { jiraService, validateJiraConfig, DEFAULT_JIRA_CONFIG } from '../jiraService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { secretService } from '../secretService';
+import { HttpError, requestJson } from '../httpClient';
+import { projectConfigService } from '../projectConfigService';
+import { appSettingsService } from '../appSettingsService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../httpClient', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../httpClient')>()),
+  requestJson: vi.fn(),
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: { requirements: service(), testcases: service() },
+  };
+});
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../appSettingsService', () => ({
+  appSettingsService: { getIntegrationUrls: vi.fn(), setIntegrationUrl: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'testcases'],
+}));
+
+const config = {
+  baseUrl: 'https://acme.atlassian.net',
+  email: 'sam@acme.test',
+  projectKey: 'APP',
+  issueType: 'Story',
+  statusMap: { Done: 'approved' },
+};
+
+const requirement = (id: string, extra: object = {}) => ({
+  id,
+  title: `Title ${id}`,
+  description: `Description ${id}`,
+  status: 'draft',
+  lastModified: 0,
+  ...extra,
+});
+
+describe('jiraService', () => {
+  const requirements = ALL_ARTIFACT_SERVICES.requirements;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({ ...config, baseUrl: '' });
+    vi.mocked(appSettingsService.getIntegrationUrls).mockResolvedValue({ jira: config.baseUrl });
+    vi.mocked(secretService.getSecret).mockResolvedValue('secret');
+  });
+
+  describe('validateJiraConfig', () => {
+    it('should report missing settings', () => {
+      expect(validateJiraConfig(DEFAULT_JIRA_CONFIG)).toEqual([
+        'Base URL must be an https URL',
+        'Email is required',
+        'Invalid project key',
+      ]);
+      expect(validateJiraConfig(config)).toEqual([]);
+    });
+  });
+
+  describe('getJiraConfig', () => {
+    it('should take the server from this machine, not the project config', async () => {
+      vi.mocked(projectConfigService.getSection).mockResolvedValue({
+        ...config,
+        baseUrl: 'https://attacker.test',
+      });
+
+      expect((await jiraService.getJiraConfig()).baseUrl).toBe('https://acme.atlassian.net');
+
+      vi.mocked(appSettingsService.getIntegrationUrls).mockResolvedValue({});
+      await expect(jiraService.pushToJira(['REQ-001'])).rejects.toThrow('Jira is not configured');
+      expect(requestJson).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('setJiraConfig', () => {
+    it('should keep the server URL out of the committed config', async () => {
+      await jiraService.setJiraConfig({ ...config, baseUrl: 'https://acme.atlassian.net/' });
+
+      expect(appSettingsService.setIntegrationUrl).toHaveBeenCalledWith(
+        'jira',
+        'https://acme.atlassian.net'
+      );
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'jira',
+        { ...config, baseUrl: undefined },
+        'Update Jira configuration'
+      );
+    });
+  });
+
+  describe('pushToJira', () => {
+    it('should create issues for unlinked artifacts and update linked ones', async () => {
+      vi.mocked(requirements.load).mockImplementation(async (id: string) =>
+        id === 'REQ-002'
+          ? requirement(id, { externalIssues: [{ tracker: 'jira', key: 'APP-7' }] })
+          : requirement(id)
+      );
+      vi.mocked(requestJson).mockResolvedValueOnce({ key: 'APP-8' }).mockResolvedValueOnce(null);
+
+      const results = await jiraService.pushToJira(['REQ-001', 'REQ-002']);
+
+      expect(results).toEqual([
+        { artifactId: 'REQ-001', issueKey: 'APP-8', action: 'created' },
+        { artifactId: 'REQ-002', issueKey: 'APP-7', action: 'updated' },
+      ]);
+      expect(requestJson).toHaveBeenCalledWith({
+        integration: 'jira',
+        url: 'https://acme.atlassian.net/rest/api/2/issue',
+        method: 'POST',
+        headers: { Authorization: `Basic ${btoa('sam@acme.test:secret')}` },
+        json: {
+          fields: {
+            summary: '[REQ-001] Title REQ-001',
+            description: 'Description REQ-001',
+            project: { key: 'APP' },
+            issuetype: { name: 'Story' },
+          },
+        },
+      });
+      expect(requestJson).toHaveBeenCalledWith(
+        expect.objectContaining({
+          url: 'https://acme.atlassian.net/rest/api/2/issue/APP-7',
+          method: 'PUT',
+        })
+      );
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'REQ-001',
+          externalIssues: [
+            expect.objectContaining({
+              tracker: 'jira',
+              key: 'APP-8',
+              url: 'https://acme.atlassian.net/browse/APP-8',
+            }),
+          ],
+        })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Create 1 Jira issues'
+      );
+    });
+
+    it('should require a stored token', async () => {
+      vi.mocked(secretService.getSecret).mockResolvedValue(null);
+
+      await expect(jiraService.pushToJira(['REQ-001'])).rejects.toThrow('No Jira API token stored');
+    });
+  });
+
+  describe('pullFromJira', () => {
+    it('should record issue statuses and apply mapped statuses', async () => {
+      vi.mocked(requirements.loadAll).mockResolvedValue([
+        requirement('REQ-001', { externalIssues: [{ tracker: 'jira', key: 'APP-1' }] }),
+        requirement('REQ-002', { externalIssues: [{ tracker: 'jira', key: 'APP-2' }] }),
+        requirement('REQ-003'),
+      ]);
+      vi.mocked(requestJson).mockImplementation(async ({ url }) => {
+        if (url.includes('APP-2')) throw new HttpError(404, 'not found');
+        return { key: 'APP-1', fields: { status: { name: 'Done' } } };
+      });
+
+      const result = await jiraService.pullFromJira();
+
+      expect(result).toEqual({ checked: 1, updated: ['REQ-001'], missing: ['APP-2'] });
+      expect(requirements.save).toHaveBeenCalledTimes(1);
+      expect(requirements.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'REQ-001',
+          status: 'approved',
+          statusChangedBy: 'Jira',
+          externalIssues: [expect.objectContaining({ key: 'APP-1', status: 'Done' })],
+        })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md'],
+        'Sync Jira issue status: 1 artifacts updated'
+      );
+    });
+  });
+
+  describe('getJiraIssueMapping', () => {
+    it('should list linked issues per artifact', async () => {
+      vi.mocked(requirements.loadAll).mockResolvedValue([
+        requirement('REQ-001', {
+          externalIssues: [
+            { tracker: 'jira', key: 'APP-1', status: 'Done' },
+            { tracker: 'github', key: 'acme/app#3' },
+          ],
+        }),
+      ]);
+
+      expect(await jiraService.getJiraIssueMapping()).toEqual([
+        { artifactId: 'REQ-001', issueKey: 'APP-1', url: undefined, status: 'Done' },
+      ]);
+    });
+  });
+});
@@ -0,0 +1,95 @@
+/**
+ * Job Service Tests
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import { jobService, JOB_FINISHED_EVENT, type JobContext } from '../jobService';
+
+// A task that runs until released (or aborted) from the test
+function controllableTask() {
+  let release: (value: string) => void = () => {};
+  let context: JobContext | undefined;
+  const task = (ctx: JobContext) =>
+    new Promise<string>((resolve, reject) => {
+      context = ctx;
+      release = resolve;
+      ctx.signal.addEventListener('abort', () => reject(new Error('aborted')));
+    });
+  return { task, release: (value: string) => release(value), context: () => context! };
+}
+
+describe('jobService', () => {
+  it('should run a job and report progress and its result', async () => {
+    const job = controllableTask();
+    const id = jobService.submitJob('Export PDF', job.task);
+
+    expect(jobService.getJobStatus(id)?.state).toBe('running');
+    job.context().reportProgress({ completed: 1, total: 4, message: 'cover' });
+    expect(jobService.getJobStatus(id)?.progress).toEqual({
+      completed: 1,
+      total: 4,
+      message: 'cover',
+    });
+
+    job.release('done.pdf');
+    const status = await jobService.waitForJob<string>(id);
+    expect(status).toMatchObject({ state: 'completed', result: 'done.pdf' });
+  });
+
+  it('should queue jobs beyond the concurrency limit', async () => {
+    const jobs = [controllableTask(), controllableTask(), controllableTask()];
+    const ids = jobs.map((job, i) => jobService.submitJob(`Import ${i}`, job.task));
+
+    expect(ids.map((id) => jobService.getJobStatus(id)?.state)).toEqual([
+      'running',
+      'running',
+      'queued',
+    ]);
+
+    jobs[0].release('ok');
+    await jobService.waitForJob(ids[0]);
+    expect(jobService.getJobStatus(ids[2])?.state).toBe('running');
+
+    jobs[1].release('ok');
+    jobs[2].release('ok');
+    await Promise.all(ids.map((id) => jobService.waitForJob(id)));
+  });
+
+  it('should cancel a running job through its abort signal', async () => {
+    const job = controllableTask();
+    const listener = vi.fn();
+    window.addEventListener(JOB_FINISHED_EVENT, listener);
+    const id = jobService.submitJob('Clone', job.task);
+
+    expect(jobService.cancelJob(id)).toBe(true);
+
+    expect(job.context().signal.aborted).toBe(true);
+    expect(jobService.getJobStatus(id)?.state).toBe('cancelled');
+    expect(jobService.cancelJob(id)).toBe(false);
+    expect(listener).toHaveBeenCalledWith(
+      expect.objectContaining({ detail: expect.objectContaining({ id, state: 'cancelled' }) })
+    );
+    window.removeEventListener(JOB_FINISHED_EVENT, listener);
+  });
+
+  it('should record failures', async () => {
+    const id = jobService.submitJob('Rebuild index', async () => {
+      throw new Error('disk full');
+    });
+
+    expect(await jobService.waitForJob(id)).toMatchObject({ state: 'failed', error: 'disk full' });
+  });
+
+  it('should resolve with the result of a job run to completion, or reject', async () => {
+    expect(await jobService.runJob('Export JSON', async () => 'export.json')).toBe('export.json');
+    await expect(
+      jobService.runJob('Import files', async () => {
+        throw new Error('bad file');
+      })
+    ).rejects.toThrow('bad file');
+  });
+
+  it('should return null for unknown jobs', () => {
+    expect(jobService.getJobStatus('job-999')).toBeNull();
+  });
+});
@@ -0,0 +1,136 @@
+/**
+ * Link Mirror Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  findOneSidedLinks,
+  syncMirroredLinks,
+  repairOneSidedLinks,
+  type LinkableArtifact,
+} from '../linkMirrorService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    load: vi.fn(),
+    loadAll: vi.fn().mockResolvedValue([]),
+    save: vi.fn(),
+  });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+  };
+});
+
+describe('linkMirrorService', () => {
+  const artifact = (
+    id: string,
+    linkedArtifacts: LinkableArtifact['linkedArtifacts'] = []
+  ): LinkableArtifact => ({ id, linkedArtifacts, lastModified: 0 });
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('findOneSidedLinks', () => {
+    it('reports links whose target lacks the inverse entry', () => {
+      const oneSided = findOneSidedLinks([
+        artifact('REQ-001', [
+          { targetId: 'TC-001', type: 'verified_by' },
+          { targetId: 'REQ-002', type: 'related_to' },
+        ]),
+        artifact('TC-001', [{ targetId: 'REQ-001', type: 'verifies' }]),
+        artifact('REQ-002'),
+      ]);
+
+      expect(oneSided).toEqual([{ sourceId: 'REQ-001', targetId: 'REQ-002', type: 'related_to' }]);
+    });
+
+    it('ignores links to artifacts that are not loaded', () => {
+      const artifacts = [artifact('REQ-001', [{ targetId: 'TC-404', type: 'verified_by' }])];
+
+      expect(findOneSidedLinks(artifacts)).toEqual([]);
+    });
+  });
+
+  describe('syncMirroredLinks', () => {
+    it('adds the inverse entry to a newly linked artifact', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.load).mockResolvedValue(artifact('TC-001'));
+
+      const updated = await syncMirroredLinks(
+        'REQ-001',
+        [],
+        [{ targetId: 'TC-001', type: 'verified_by' }]
+      );
+
+      expect(updated).toHaveLength(1);
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'TC-001',
+          linkedArtifacts: [{ targetId: 'REQ-001', type: 'verifies' }],
+        })
+      );
+    });
+
+    it('removes the inverse entry when a link is removed', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.load).mockResolvedValue(
+        artifact('TC-001', [
+          { targetId: 'REQ-001', type: 'verifies' },
+          { targetId: 'REQ-002', type: 'verifies' },
+        ])
+      );
+
+      await syncMirroredLinks('REQ-001', [{ targetId: 'TC-001', type: 'verified_by' }], []);
+
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          linkedArtifacts: [{ targetId: 'REQ-002', type: 'verifies' }],
+        })
+      );
+    });
+
+    it('does not rewrite artifacts that already mirror the link', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.load).mockResolvedValue(
+        artifact('TC-001', [{ targetId: 'REQ-001', type: 'verifies' }])
+      );
+
+      const updated = await syncMirroredLinks(
+        'REQ-001',
+        [],
+        [{ targetId: 'TC-001', type: 'verified_by' }]
+      );
+
+      expect(updated).toEqual([]);
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('repairOneSidedLinks', () => {
+    it('adds all missing inverse entries, writing each target once', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        artifact('REQ-001', [{ targetId: 'TC-001', type: 'verified_by' }]),
+        artifact('REQ-002', [{ targetId: 'TC-001', type: 'verified_by' }]),
+      ]);
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([artifact('TC-001')]);
+
+      const repaired = await repairOneSidedLinks();
+
+      expect(repaired).toHaveLength(2);
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledTimes(1);
+      expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          linkedArtifacts: [
+            { targetId: 'REQ-001', type: 'verifies' },
+            { targetId: 'REQ-002', type: 'verifies' },
+          ],
+        })
+      );
+    });
+  });
+});
@@ -0,0 +1,203 @@
+/**
+ * Local API Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  handleApiRequest,
+  getLocalApiToken,
+  setLocalApiConfig,
+  DEFAULT_LOCAL_API_CONFIG,
+} from '../localApiService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import { generateTraceMatrix } from '../traceMatrixService';
+import { runTraceQuery } from '../traceQueryService';
+import { runSavedQuery } from '../savedQueryService';
+import { secretService } from '../secretService';
+import { appSettingsService } from '../appSettingsService';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    ALL_ARTIFACT_SERVICES: { requirements: service(), testcases: service(), users: service() },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { queryLinks: vi.fn().mockResolvedValue([]) },
+}));
+
+vi.mock('../traceMatrixService', () => ({
+  generateTraceMatrix: vi.fn(),
+}));
+
+vi.mock('../traceQueryService', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../traceQueryService')>()),
+  runTraceQuery: vi.fn().mockResolvedValue([]),
+}));
+
+vi.mock('../savedQueryService', () => ({
+  getSavedQueries: vi.fn().mockResolvedValue([]),
+  runSavedQuery: vi.fn().mockResolvedValue([]),
+}));
+
+vi.mock('../coverageService', () => ({
+  generateCoverageReport: vi.fn(),
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn() },
+}));
+
+vi.mock('../apiInfoService', () => ({
+  getApiInfo: vi.fn().mockResolvedValue({
+    appVersion: '1.4.0',
+    apiVersion: 2,
+    minSupportedApiVersion: 1,
+    capabilities: ['fs:batch'],
+    features: {},
+    deprecated: [],
+  }),
+}));
+
+vi.mock('../appSettingsService', () => ({
+  DEFAULT_APP_SETTINGS: { localApi: { enabled: false, port: 7411 } },
+  appSettingsService: { getAppSettings: vi.fn(), updateAppSettings: vi.fn() },
+}));
+
+const get = (path: string, query: Record<string, string> = {}) =>
+  handleApiRequest({ path, query });
+
+describe('localApiService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('handleApiRequest', () => {
+    it('should list artifacts of a type', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([{ id: 'REQ-001' }]);
+
+      expect(await get('/api/artifacts', { type: 'requirements' })).toEqual({
+        status: 200,
+        body: [{ id: 'REQ-001' }],
+      });
+      expect((await get('/api/artifacts', { type: 'users' })).status).toBe(400);
+    });
+
+    it('should return a single artifact or 404', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockImplementation(async (id: string) =>
+        id === 'REQ-001' ? { id } : null
+      );
+
+      expect(await get('/api/artifacts/REQ-001/')).toEqual({
+        status: 200,
+        body: { id: 'REQ-001' },
+      });
+      expect((await get('/api/artifacts/REQ-404')).status).toBe(404);
+      expect((await get('/api/artifacts/USER-001')).status).toBe(404);
+    });
+
+    it('should query links', async () => {
+      await get('/api/links', { artifactId: 'REQ-001', type: 'verifies' });
+
+      expect(diskLinkService.queryLinks).toHaveBeenCalledWith(
+        expect.objectContaining({ artifactId: 'REQ-001', types: ['verifies'] })
+      );
+      expect((await get('/api/links', { type: 'bogus' })).status).toBe(400);
+    });
+
+    it('should build trace matrices and map missing projects to 404', async () => {
+      vi.mocked(generateTraceMatrix).mockRejectedValueOnce(new Error('Project not found: p'));
+
+      const response = await get('/api/trace-matrix', {
+        from: 'requirements',
+        to: 'testcases',
+        projectId: 'p',
+      });
+
+      expect(generateTraceMatrix).toHaveBeenCalledWith('requirements', 'testcases', 'p');
+      expect(response).toEqual({ status: 404, body: { error: 'Project not found: p' } });
+    });
+
+    it('should run trace queries and reject invalid ones', async () => {
+      const response = await get('/api/trace', {
+        from: 'SYS-004',
+        relations: 'derives_to,verified_by',
+        type: 'testcases',
+        depth: '2',
+      });
+
+      expect(response.status).toBe(200);
+      expect(runTraceQuery).toHaveBeenCalledWith({
+        startId: 'SYS-004',
+        relations: ['derives_to', 'verified_by'],
+        targetType: 'testcases',
+        maxDepth: 2,
+        projectId: undefined,
+      });
+      expect((await get('/api/trace', { from: 'SYS-004', relations: 'bogus' })).status).toBe(400);
+      expect((await get('/api/trace', { from: 'SYS-004', depth: '99' })).status).toBe(400);
+      expect((await get('/api/trace', {})).status).toBe(400);
+    });
+
+    it('should list and run saved queries', async () => {
+      vi.mocked(runSavedQuery).mockRejectedValueOnce(new Error('Saved query not found: Bogus'));
+
+      expect(await get('/api/queries')).toEqual({ status: 200, body: [] });
+      await get('/api/queries/Untested%20requirements', { projectId: 'PRJ-001' });
+      expect(runSavedQuery).toHaveBeenCalledWith('Untested requirements', 'PRJ-001');
+      expect((await get('/api/queries/Bogus')).status).toBe(404);
+    });
+
+    it('should search artifacts for all words', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        { id: 'REQ-001', title: 'Login', description: 'Lock accounts after failures' },
+        { id: 'REQ-002', title: 'Logout' },
+      ]);
+
+      const response = await get('/api/search', { q: 'login LOCK', type: 'requirements' });
+
+      expect(response).toEqual({
+        status: 200,
+        body: [{ id: 'REQ-001', type: 'requirements', title: 'Login' }],
+      });
+      expect((await get('/api/search')).status).toBe(400);
+    });
+
+    it('should describe the API and the desktop app', async () => {
+      const response = await get('/api/info');
+
+      expect(response.status).toBe(200);
+      expect(response.body).toMatchObject({
+        apiVersion: 1,
+        endpoints: expect.arrayContaining(['artifacts', 'info']),
+        app: { version: '1.4.0', apiVersion: 2, capabilities: ['fs:batch'] },
+      });
+    });
+
+    it('should reject unknown endpoints', async () => {
+      expect((await get('/api/users')).status).toBe(404);
+      expect((await get('/other')).status).toBe(404);
+    });
+  });
+
+  describe('settings and token', () => {
+    it('should keep the settings on this machine, not in the project', async () => {
+      await setLocalApiConfig({ ...DEFAULT_LOCAL_API_CONFIG, port: 8000 });
+
+      expect(appSettingsService.updateAppSettings).toHaveBeenCalledWith({
+        localApi: { enabled: false, port: 8000 },
+      });
+    });
+
+    it('should replace tokens shorter than 32 random bytes', async () => {
+      vi.mocked(secretService.getSecret).mockResolvedValue('a'.repeat(32));
+
+      const token = await getLocalApiToken();
+
+      expect(token).toMatch(/^[0-9a-f]{64}$/);
+      expect(secretService.storeSecret).toHaveBeenCalledWith('local-api', token);
+    });
+  });
+});
@@ -0,0 +1,69 @@
+/**
+ * Log Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { logService, getLogExportFileName } from '../logService';
+import { isElectronEnv } from '../git/types';
+import { saveFile } from '../../utils/fileSaveUtils';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+vi.mock('../../utils/fileSaveUtils', () => ({
+  saveFile: vi.fn(),
+}));
+
+describe('logService', () => {
+  const log = { write: vi.fn(), getRecent: vi.fn(), export: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    window.electronAPI = { log } as unknown as Window['electronAPI'];
+  });
+
+  it('should write entries without throwing on failure', async () => {
+    log.write.mockResolvedValueOnce({ ok: true }).mockRejectedValueOnce(new Error('gone'));
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+
+    await logService.writeLog('error', 'Sync failed');
+    await expect(logService.writeLog('info', 'Second')).resolves.toBeUndefined();
+
+    expect(log.write).toHaveBeenCalledWith('error', 'Sync failed');
+    expect(warn).toHaveBeenCalledTimes(1);
+    warn.mockRestore();
+  });
+
+  it('should return recent entries and surface errors', async () => {
+    const entry = { time: '2024-01-01T00:00:00.000Z', level: 'warn', source: 'main', message: 'x' };
+    log.getRecent.mockResolvedValueOnce({ entries: [entry] });
+    log.getRecent.mockResolvedValueOnce({ error: 'EACCES' });
+
+    expect(await logService.getRecentLogs(10)).toEqual([entry]);
+    expect(log.getRecent).toHaveBeenCalledWith(10);
+    await expect(logService.getRecentLogs()).rejects.toThrow('EACCES');
+  });
+
+  it('should save the exported log file', async () => {
+    log.export.mockResolvedValue({ content: '{"app":"1.0"}\n' });
+
+    await logService.exportLogs();
+
+    const [blob, filename] = vi.mocked(saveFile).mock.calls[0];
+    expect((blob as Blob).size).toBe('{"app":"1.0"}\n'.length);
+    expect(filename).toBe(getLogExportFileName());
+  });
+
+  it('should keep no logs outside the desktop app', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+
+    await logService.writeLog('error', 'x');
+
+    expect(await logService.getRecentLogs()).toEqual([]);
+    await expect(logService.exportLogs()).rejects.toThrow('desktop app');
+    expect(log.write).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,213 @@
+/**
+ * Markdown Import Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { markdownImportService } from '../markdownImportService';
+import { baseDiskService } from '../baseDiskService';
+import { projectService } from '../artifactServices';
+import { diskProjectService } from '../diskProjectService';
+import { idService } from '../idService';
+import { realGitService } from '../realGitService';
+import { fileSystemService } from '../fileSystemService';
+import type { Project } from '../../types';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: {
+    listEntries: vi.fn(),
+    listFiles: vi.fn(),
+    readTextFile: vi.fn(),
+    writeTextFile: vi.fn(),
+    deleteFile: vi.fn(),
+  },
+}));
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    readFileBinary: vi.fn(),
+    writeFile: vi.fn(),
+    writeFileBinary: vi.fn(),
+    deleteFile: vi.fn(),
+  },
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    deserialize: vi.fn(() => ({ priority: 'medium' })),
+    serialize: vi.fn((item) => JSON.stringify(item)),
+  });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+    projectService: { save: vi.fn() },
+  };
+});
+
+vi.mock('../diskProjectService', () => ({
+  diskProjectService: { initialize: vi.fn(), createProject: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn(async (type: string, count: number) =>
+      Array.from({ length: count }, (_, i) => `${type.slice(0, 3).toUpperCase()}-00${i + 1}`)
+    ),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+vi.mock('../orphanFileService', () => ({ QUARANTINE_FOLDER: 'quarantine' }));
+
+vi.mock('../statusWorkflowService', () => ({
+  getStatusWorkflow: vi.fn().mockResolvedValue({
+    statuses: ['draft'],
+    initialStatus: 'draft',
+    transitions: {},
+  }),
+}));
+
+describe('markdownImportService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {
+      'README.md': '# Overview\n\nWhat this product is about.',
+      'current-user.md': 'USER-001',
+      'notes/login.md': '# Login\n\nThe system shall lock accounts. Users must sign in.',
+      'notes/empty.md': '  ',
+      'notes/diagram.png': '',
+      'notes/tests/login-test.md': '# Login works\n\n## Test Steps\n1. Sign in',
+      '.git/HEAD.md': 'ref',
+      'requirements/REQ-001.md': '---\nid: "REQ-001"\n---',
+    };
+    const childrenOf = (folder: string) => {
+      const prefix = folder ? `${folder}/` : '';
+      const names = Object.keys(files)
+        .filter((path) => path.startsWith(prefix))
+        .map((path) => path.slice(prefix.length).split('/'));
+      return {
+        files: names.filter((parts) => parts.length === 1).map((parts) => parts[0]),
+        entries: [...new Set(names.map((parts) => parts[0]))],
+      };
+    };
+    vi.mocked(baseDiskService.listFiles).mockImplementation(
+      async (folder) => childrenOf(folder).files
+    );
+    vi.mocked(baseDiskService.listEntries).mockImplementation(
+      async (folder) => childrenOf(folder).entries
+    );
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+    vi.mocked(baseDiskService.writeTextFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(baseDiskService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    // Raw file access used by the write journal
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.readFileBinary).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.writeFileBinary).mockImplementation(async (path, data) => {
+      files[path] = new TextDecoder().decode(data);
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    vi.mocked(diskProjectService.createProject).mockImplementation(
+      async (name, description): Promise<Project> => ({
+        id: 'proj-1',
+        name,
+        description,
+        requirementIds: [],
+        useCaseIds: [],
+        testCaseIds: [],
+        informationIds: [],
+        riskIds: [],
+        lastModified: 0,
+      })
+    );
+  });
+
+  describe('collectMarkdownFiles', () => {
+    it('should find markdown files outside hidden and app-managed folders', async () => {
+      expect(await markdownImportService.collectMarkdownFiles()).toEqual([
+        'README.md',
+        'notes/empty.md',
+        'notes/login.md',
+        'notes/tests/login-test.md',
+      ]);
+    });
+  });
+
+  describe('importMarkdownFolder', () => {
+    it('should classify files, move them into artifact folders and commit once', async () => {
+      const report = await markdownImportService.importMarkdownFolder('Legacy docs');
+
+      expect(report.imported).toEqual([
+        { sourcePath: 'README.md', id: 'INF-001', typeKey: 'information', title: 'Overview' },
+        { sourcePath: 'notes/login.md', id: 'REQ-001', typeKey: 'requirements', title: 'Login' },
+        {
+          sourcePath: 'notes/tests/login-test.md',
+          id: 'TES-001',
+          typeKey: 'testcases',
+          title: 'Login works',
+        },
+      ]);
+      expect(report.skipped).toEqual([{ path: 'notes/empty.md', reason: 'empty file' }]);
+      expect(report.counts).toMatchObject({ requirements: 1, testcases: 1, information: 1 });
+      expect(report.project).toMatchObject({
+        requirementIds: ['REQ-001'],
+        testCaseIds: ['TES-001'],
+        informationIds: ['INF-001'],
+      });
+
+      expect(JSON.parse(files['requirements/REQ-001.md'])).toMatchObject({
+        id: 'REQ-001',
+        title: 'Login',
+        description: 'The system shall lock accounts. Users must sign in.',
+        status: 'draft',
+        priority: 'medium',
+      });
+      expect(files['notes/login.md']).toBeUndefined();
+      expect(projectService.save).toHaveBeenCalledWith(report.project);
+      expect(idService.getNextIds).toHaveBeenCalledWith('requirements', 1, true);
+      expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        expect.arrayContaining([
+          'information/INF-001.md',
+          'README.md',
+          'counters/requirements.md',
+          'projects/proj-1.md',
+        ]),
+        'Import 3 markdown files as project Legacy docs'
+      );
+    });
+
+    it('should roll back moved files when the import fails', async () => {
+      const before = { ...files };
+      vi.mocked(projectService.save).mockRejectedValueOnce(new Error('Disk full'));
+
+      await expect(markdownImportService.importMarkdownFolder('Legacy docs')).rejects.toThrow(
+        'Disk full'
+      );
+
+      expect(files).toEqual(before);
+      expect(realGitService.commitFiles).not.toHaveBeenCalled();
+    });
+  });
+});
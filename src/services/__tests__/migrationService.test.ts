@@ -0,0 +1,225 @@
+/**
+ * Migration Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { migrationService, type Migration } from '../migrationService';
+import { baseDiskService } from '../baseDiskService';
+import { projectConfigService } from '../projectConfigService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: {
+    listFiles: vi.fn(),
+    readTextFile: vi.fn(),
+    writeTextFile: vi.fn(),
+    writeJsonFile: vi.fn(),
+    readBinaryFile: vi.fn(),
+    writeBinaryFile: vi.fn(),
+    deleteFile: vi.fn(),
+  },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFiles: vi.fn() },
+}));
+
+// The journal itself is covered by its own tests
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn(async (_operation, apply, commitMessage) => {
+    const recorded: string[] = [];
+    await apply({ record: async (path: string) => void recorded.push(path) });
+    await realGitService.commitFiles(recorded, commitMessage);
+  }),
+}));
+
+const MIGRATIONS: Migration[] = [
+  {
+    version: 3,
+    description: 'Rename owner to assignee',
+    steps: [{ kind: 'rename-field', folder: 'specs', from: 'owner', to: 'assignee' }],
+  },
+  {
+    version: 2,
+    description: 'Rename requirements folder',
+    steps: [{ kind: 'rename-folder', from: 'requirements', to: 'specs' }],
+  },
+];
+
+describe('migrationService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {
+      'requirements/REQ-001.md': '---\nid: REQ-001\nowner: Ann\n---\n# Login',
+      'requirements/REQ-002.md': '---\nid: REQ-002\nassignee: Bob\n---\n# Logout',
+    };
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({ version: 1 });
+    vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+    vi.mocked(baseDiskService.readBinaryFile).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(baseDiskService.writeTextFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(baseDiskService.writeBinaryFile).mockImplementation(async (path, data) => {
+      files[path] = new TextDecoder().decode(data);
+    });
+    vi.mocked(baseDiskService.writeJsonFile).mockImplementation(async (path, data) => {
+      files[path] = JSON.stringify(data);
+    });
+    vi.mocked(baseDiskService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+  });
+
+  it('should report planned changes in a dry run without touching files', async () => {
+    const before = { ...files };
+
+    const report = await migrationService.migrateProject({
+      dryRun: true,
+      migrations: MIGRATIONS,
+      targetVersion: 3,
+    });
+
+    expect(report.fromVersion).toBe(1);
+    expect(report.migrations.map((m) => m.version)).toEqual([2, 3]);
+    expect(report.migrations[0].changes).toHaveLength(2);
+    // Planned against the result of the folder rename; REQ-002 already has the new field
+    expect(report.migrations[1].changes).toEqual([
+      {
+        path: 'specs/REQ-001.md',
+        action: 'update',
+        detail: 'Rename field owner to assignee',
+        diff: ['- owner: Ann', '+ assignee: Ann'],
+      },
+    ]);
+    expect(files).toEqual(before);
+    expect(migrationService.formatMigrationReport(report)).toContain(
+      'requirements/REQ-001.md: Move to specs/'
+    );
+  });
+
+  it('should apply migrations in order, record the version and commit once', async () => {
+    await migrationService.migrateProject({ migrations: MIGRATIONS, targetVersion: 3 });
+
+    expect(files).toEqual({
+      'specs/REQ-001.md': '---\nid: REQ-001\nassignee: Ann\n---\n# Login',
+      'specs/REQ-002.md': '---\nid: REQ-002\nassignee: Bob\n---\n# Logout',
+      'config/format.json': '{"version":3}',
+    });
+    expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      expect.arrayContaining(['requirements/REQ-001.md', 'specs/REQ-001.md', 'config/format.json']),
+      'Migrate project format from version 1 to 3'
+    );
+  });
+
+  it('should leave up-to-date and newer projects alone', async () => {
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({ version: 4 });
+
+    const report = await migrationService.migrateProject({
+      migrations: MIGRATIONS,
+      targetVersion: 3,
+    });
+
+    expect(report.tooNew).toBe(true);
+    expect(report.migrations).toEqual([]);
+    expect(baseDiskService.writeJsonFile).not.toHaveBeenCalled();
+    expect(migrationService.formatMigrationReport(report)).toContain('Update Tracyfy');
+  });
+
+  describe('migrateFrontmatter', () => {
+    beforeEach(() => {
+      files = {
+        'requirements/REQ-001.md': '---\nid: REQ-001\nstatus: "review"\ntags:\n  - "ui"\n---\n# A',
+        'requirements/REQ-002.md': '---\nid: REQ-002\nstatus: approved\n---\n# B',
+        'testcases/TC-001.md': '---\nid: TC-001\nstatus: "review"\n---\n# C',
+      };
+    });
+
+    it('should list changed lines in a dry run without touching files', async () => {
+      const before = { ...files };
+
+      const changes = await migrationService.migrateFrontmatter(
+        [
+          {
+            kind: 'map-values',
+            folder: 'requirements',
+            field: 'status',
+            values: { review: 'in_review' },
+          },
+          { kind: 'map-values', folder: 'requirements', field: 'tags', values: { ui: 'frontend' } },
+        ],
+        { dryRun: true }
+      );
+
+      expect(changes).toEqual([
+        {
+          path: 'requirements/REQ-001.md',
+          action: 'update',
+          detail: 'Map values of status',
+          diff: ['- status: "review"', '+ status: "in_review"'],
+        },
+        {
+          path: 'requirements/REQ-001.md',
+          action: 'update',
+          detail: 'Map values of tags',
+          diff: ['-   - "ui"', '+   - "frontend"'],
+        },
+      ]);
+      expect(migrationService.formatMigrationChanges(changes)).toContain(
+        '    + status: "in_review"'
+      );
+      expect(files).toEqual(before);
+    });
+
+    it('should rewrite every matching file and commit once', async () => {
+      await migrationService.migrateFrontmatter(
+        [
+          { kind: 'rename-field', folder: 'requirements', from: 'status', to: 'state' },
+          {
+            kind: 'map-values',
+            folder: 'requirements',
+            field: 'state',
+            values: { approved: 'accepted' },
+          },
+        ],
+        { description: 'Rename status to state' }
+      );
+
+      expect(files['requirements/REQ-001.md']).toBe(
+        '---\nid: REQ-001\nstate: "review"\ntags:\n  - "ui"\n---\n# A'
+      );
+      expect(files['requirements/REQ-002.md']).toBe(
+        '---\nid: REQ-002\nstate: "accepted"\n---\n# B'
+      );
+      expect(files['testcases/TC-001.md']).toBe('---\nid: TC-001\nstatus: "review"\n---\n# C');
+      expect(realGitService.commitFiles).toHaveBeenCalledTimes(1);
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-001.md', 'requirements/REQ-002.md'],
+        'Migrate frontmatter: Rename status to state (2 file(s))'
+      );
+      expect(baseDiskService.writeJsonFile).not.toHaveBeenCalled();
+    });
+
+    it('should reject steps for unknown folders or without values', async () => {
+      await expect(
+        migrationService.migrateFrontmatter([
+          { kind: 'map-values', folder: 'specs', field: 'status', values: {} },
+        ])
+      ).rejects.toThrow('Step 1: unknown folder specs; Step 1: no values to map');
+      expect(baseDiskService.listFiles).not.toHaveBeenCalled();
+    });
+  });
+});
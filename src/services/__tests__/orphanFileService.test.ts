@@ -0,0 +1,161 @@
+/**
+ * Orphan File Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { orphanFileService, findOrphanFiles } from '../orphanFileService';
+import { baseDiskService } from '../baseDiskService';
+import { projectConfigService } from '../projectConfigService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../baseDiskService', () => ({
+  BaseDiskService: class {},
+  baseDiskService: {
+    listFiles: vi.fn(),
+    readTextFile: vi.fn(),
+    writeTextFile: vi.fn(),
+    readBinaryFile: vi.fn(),
+    writeBinaryFile: vi.fn(),
+    deleteFile: vi.fn(),
+    ensureDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: {
+      deserialize: vi.fn(() => ({ status: 'draft', revision: '01' })),
+      serialize: vi.fn((item) => JSON.stringify(item)),
+    },
+  },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn().mockResolvedValue(['REQ-007']),
+    getCounterPath: vi.fn(() => 'counters/requirements.md'),
+  },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+describe('orphanFileService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {
+      'requirements/REQ-001.md': '---\nid: "REQ-001"\n---\n# Login',
+      'requirements/meeting notes.md': '# Password rules\n\nPasswords need 12 characters.',
+      'requirements/diagram.png': '',
+    };
+    vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+    vi.mocked(baseDiskService.writeTextFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(baseDiskService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+    vi.mocked(baseDiskService.readBinaryFile).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(projectConfigService.getSection).mockResolvedValue({
+      ignored: ['requirements/diagram.png'],
+    });
+  });
+
+  describe('findOrphanFiles', () => {
+    it('should classify misnamed, non-markdown and frontmatter-less files', () => {
+      const orphans = findOrphanFiles(
+        [
+          { path: 'requirements/REQ-001.md', content: '---\nid: "REQ-001"\n---' },
+          { path: 'requirements/REQ-002.md', content: '# No frontmatter' },
+          { path: 'requirements/notes.md', content: '' },
+          { path: 'testcases/export.csv', content: '' },
+          { path: 'testcases/.gitkeep', content: '' },
+          { path: 'testcases/order.json', content: '' },
+          { path: 'projects/proj-1700000000000.md', content: '' },
+          { path: 'testcases/REQ-003.md', content: '---\nid: "REQ-003"\n---' },
+          { path: 'requirements/SYS-REQ-012.md', content: '---\nid: "SYS-REQ-012"\n---' },
+        ],
+        ['requirements/notes.md']
+      );
+
+      expect(orphans).toEqual([
+        { path: 'requirements/REQ-002.md', typeKey: 'requirements', reason: 'no-frontmatter' },
+        { path: 'testcases/export.csv', typeKey: 'testcases', reason: 'not-markdown' },
+        { path: 'testcases/REQ-003.md', typeKey: 'testcases', reason: 'misnamed' },
+      ]);
+    });
+  });
+
+  describe('scanOrphanFiles', () => {
+    it('should skip ignored files', async () => {
+      const orphans = await orphanFileService.scanOrphanFiles();
+
+      expect(orphans.map((o) => o.path)).toEqual(['requirements/meeting notes.md']);
+    });
+  });
+
+  describe('ignoreOrphanFile', () => {
+    it('should add the path to the ignore list', async () => {
+      await orphanFileService.ignoreOrphanFile('requirements/meeting notes.md');
+
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'orphan-files',
+        { ignored: ['requirements/diagram.png', 'requirements/meeting notes.md'] },
+        'Ignore orphan file requirements/meeting notes.md'
+      );
+    });
+  });
+
+  describe('quarantineOrphanFile', () => {
+    it('should move the file to the quarantine folder in one commit', async () => {
+      const target = await orphanFileService.quarantineOrphanFile('requirements/diagram.png');
+
+      expect(target).toBe('quarantine/requirements/diagram.png');
+      expect(baseDiskService.writeBinaryFile).toHaveBeenCalledWith(target, expect.any(Uint8Array));
+      expect(files['requirements/diagram.png']).toBeUndefined();
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/diagram.png', target],
+        'Quarantine requirements/diagram.png'
+      );
+    });
+  });
+
+  describe('adoptOrphanFile', () => {
+    it('should give misnamed files an ID and frontmatter', async () => {
+      const id = await orphanFileService.adoptOrphanFile('requirements/meeting notes.md');
+
+      expect(id).toBe('REQ-007');
+      expect(JSON.parse(files['requirements/REQ-007.md'])).toMatchObject({
+        id: 'REQ-007',
+        title: 'Password rules',
+        description: 'Passwords need 12 characters.',
+        status: 'draft',
+      });
+      expect(files['requirements/meeting notes.md']).toBeUndefined();
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['requirements/REQ-007.md', 'requirements/meeting notes.md', 'counters/requirements.md'],
+        'Adopt requirements/meeting notes.md as REQ-007'
+      );
+    });
+
+    it('should reject files that are not orphans', async () => {
+      await expect(orphanFileService.adoptOrphanFile('requirements/REQ-001.md')).rejects.toThrow(
+        'Not an orphan file: requirements/REQ-001.md'
+      );
+    });
+  });
+});
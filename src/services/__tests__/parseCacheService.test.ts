@@ -0,0 +1,71 @@
+/**
+ * Parse Cache Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { parseCacheService } from '../parseCacheService';
+import { fileSystemService } from '../fileSystemService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    getRootPath: vi.fn(() => '/project'),
+    getDirectoryName: vi.fn(() => 'project'),
+    onFilesChanged: vi.fn(() => () => {}),
+  },
+}));
+
+const STAT = { mtime: 1000, size: 42 };
+
+describe('parseCacheService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(fileSystemService.getRootPath).mockReturnValue('/project');
+    parseCacheService.invalidateParseCache();
+  });
+
+  it('should return a value only for the same modification time and size', () => {
+    parseCacheService.setCachedParse('requirements/REQ-001.md', STAT, { id: 'REQ-001' });
+
+    expect(parseCacheService.getCachedParse('requirements/REQ-001.md', STAT)).toEqual({
+      id: 'REQ-001',
+    });
+    expect(
+      parseCacheService.getCachedParse('requirements/REQ-001.md', { ...STAT, mtime: 2000 })
+    ).toBeUndefined();
+    expect(
+      parseCacheService.getCachedParse('requirements/REQ-001.md', { ...STAT, size: 43 })
+    ).toBeUndefined();
+  });
+
+  it('should remember files that parsed to nothing', () => {
+    parseCacheService.setCachedParse('requirements/broken.md', STAT, null);
+
+    expect(parseCacheService.getCachedParse('requirements/broken.md', STAT)).toBeNull();
+  });
+
+  it('should drop paths reported by the file watcher', () => {
+    let onChange: (paths: string[]) => void = () => {};
+    vi.mocked(fileSystemService.onFilesChanged).mockImplementation((handler) => {
+      onChange = handler;
+      return () => {};
+    });
+    const stop = parseCacheService.startParseCacheTracking();
+    parseCacheService.setCachedParse('requirements/REQ-001.md', STAT, 'one');
+    parseCacheService.setCachedParse('requirements/REQ-002.md', STAT, 'two');
+
+    onChange(['requirements/REQ-001.md']);
+
+    expect(parseCacheService.getCachedParse('requirements/REQ-001.md', STAT)).toBeUndefined();
+    expect(parseCacheService.getCachedParse('requirements/REQ-002.md', STAT)).toBe('two');
+
+    stop();
+    expect(parseCacheService.getCachedParse('requirements/REQ-002.md', STAT)).toBeUndefined();
+  });
+
+  it('should start over when another project folder is open', () => {
+    parseCacheService.setCachedParse('requirements/REQ-001.md', STAT, 'one');
+    vi.mocked(fileSystemService.getRootPath).mockReturnValue('/other');
+
+    expect(parseCacheService.getCachedParse('requirements/REQ-001.md', STAT)).toBeUndefined();
+  });
+});
@@ -0,0 +1,156 @@
+/**
+ * Permission Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { permissionService, PermissionError, type RoleConfig } from '../permissionService';
+import { projectConfigService } from '../projectConfigService';
+import { getActiveAuthorProfile } from '../authorProfileService';
+import { diskProjectService } from '../diskProjectService';
+import { userService } from '../artifactServices';
+import type { User } from '../../types';
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(), setSection: vi.fn() },
+}));
+
+vi.mock('../authorProfileService', () => ({
+  getActiveAuthorProfile: vi.fn(),
+}));
+
+vi.mock('../diskProjectService', () => ({
+  diskProjectService: { getCurrentProjectId: vi.fn(), getCurrentUserId: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  userService: { load: vi.fn() },
+}));
+
+describe('permissionService', () => {
+  let config: RoleConfig;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    config = {
+      projects: {
+        'proj-1': {
+          'ann@corp.example': ['approver'],
+          'USER-002': ['author'],
+          Admin: ['admin'],
+        },
+      },
+    };
+    vi.mocked(projectConfigService.getSection).mockImplementation(async () => config);
+    vi.mocked(diskProjectService.getCurrentProjectId).mockResolvedValue('proj-1');
+    vi.mocked(diskProjectService.getCurrentUserId).mockResolvedValue('');
+    vi.mocked(getActiveAuthorProfile).mockResolvedValue(null);
+  });
+
+  const actAs = (profile: { name: string; email: string } | null, userId = '') => {
+    vi.mocked(getActiveAuthorProfile).mockResolvedValue(
+      profile && { id: 'p', initials: '', ...profile }
+    );
+    vi.mocked(diskProjectService.getCurrentUserId).mockResolvedValue(userId);
+    vi.mocked(userService.load).mockResolvedValue(
+      userId ? ({ id: userId, name: 'Bob' } as User) : null
+    );
+  };
+
+  describe('getRolesOf', () => {
+    it('should collect the roles of every matching identity, ignoring case', () => {
+      const assignments = { 'ANN@corp.example': ['reviewer' as const], Ann: ['approver' as const] };
+
+      expect(permissionService.getRolesOf(assignments, ['ann@corp.example', 'ann'])).toEqual([
+        'reviewer',
+        'approver',
+      ]);
+      expect(permissionService.getRolesOf(assignments, ['bob'])).toEqual([]);
+    });
+  });
+
+  describe('checkPermission', () => {
+    it('should allow everything in projects without role assignments', async () => {
+      config = { projects: {} };
+      await expect(permissionService.checkPermission('approve')).resolves.toBeUndefined();
+
+      config = { projects: { 'proj-2': { Admin: ['admin'] } } };
+      await expect(permissionService.checkPermission('approve')).resolves.toBeUndefined();
+    });
+
+    it('should allow approvers to approve and create baselines', async () => {
+      actAs({ name: 'Ann', email: 'ann@corp.example' });
+
+      await expect(permissionService.checkPermission('approve')).resolves.toBeUndefined();
+      await expect(
+        permissionService.checkPermission('baseline', 'proj-1')
+      ).resolves.toBeUndefined();
+    });
+
+    it('should refuse authors with a typed permission error', async () => {
+      actAs(null, 'USER-002');
+
+      const error = await permissionService.checkPermission('approve').catch((err) => err);
+
+      expect(error).toBeInstanceOf(PermissionError);
+      expect(error).toMatchObject({
+        action: 'approve',
+        projectId: 'proj-1',
+        identity: 'Bob',
+        requiredRoles: ['approver', 'admin'],
+      });
+      expect(error.message).toBe(
+        'Bob may not approve in proj-1: requires the role approver or admin'
+      );
+    });
+
+    it('should refuse identities without any role', async () => {
+      await expect(permissionService.checkPermission('edit')).rejects.toThrow(
+        'The current user may not edit in proj-1'
+      );
+    });
+  });
+
+  describe('setProjectRoles', () => {
+    it('should require an admin among the assignments', async () => {
+      await expect(
+        permissionService.setProjectRoles('proj-1', { Ann: ['approver'] })
+      ).rejects.toThrow('At least one identity needs the admin role');
+    });
+
+    it('should only let admins change assigned roles', async () => {
+      actAs({ name: 'Ann', email: 'ann@corp.example' });
+      await expect(
+        permissionService.setProjectRoles('proj-1', { Ann: ['admin'] })
+      ).rejects.toBeInstanceOf(PermissionError);
+
+      actAs({ name: 'Admin', email: 'admin@corp.example' });
+      await permissionService.setProjectRoles('proj-1', {
+        ' Ann ': ['approver', 'approver'],
+        Admin: ['admin'],
+        Eve: [],
+      });
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'roles',
+        { projects: { 'proj-1': { Ann: ['approver'], Admin: ['admin'] } } },
+        'Update roles of proj-1'
+      );
+    });
+
+    it('should let anyone assign the first roles of a project and clear them again', async () => {
+      await permissionService.setProjectRoles('proj-2', { Ann: ['admin'] });
+      expect(projectConfigService.setSection).toHaveBeenLastCalledWith(
+        'roles',
+        { projects: { ...config.projects, 'proj-2': { Ann: ['admin'] } } },
+        'Update roles of proj-2'
+      );
+
+      actAs({ name: 'Admin', email: 'admin@corp.example' });
+      await permissionService.setProjectRoles('proj-1', {});
+      expect(projectConfigService.setSection).toHaveBeenLastCalledWith(
+        'roles',
+        { projects: {} },
+        'Update roles of proj-1'
+      );
+    });
+  });
+});
@@ -0,0 +1,180 @@
+/**
+ * Plugin Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { pluginService } from '../pluginService';
+import { ALL_ARTIFACT_SERVICES, projectService } from '../artifactServices';
+import { baseDiskService } from '../baseDiskService';
+import { diskLinkService } from '../diskLinkService';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: () => true,
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    loadAll: vi.fn().mockResolvedValue([]),
+    deserialize: vi.fn(() => ({ priority: 'medium' })),
+    serialize: vi.fn((item) => JSON.stringify(item)),
+  });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+    projectService: { load: vi.fn(), serialize: vi.fn((item) => JSON.stringify(item)) },
+  };
+});
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: {
+    readTextFile: vi.fn(async () => '1'),
+    writeTextFilesAndCommit: vi.fn(),
+  },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), getLinksForProject: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn(async (_type: string, count: number) =>
+      Array.from({ length: count }, (_, i) => `REQ-00${i + 1}`)
+    ),
+    getCounterPath: vi.fn((type: string) => `counters/${type}.md`),
+  },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'usecases', 'testcases', 'information', 'risks'],
+  getStatusWorkflow: vi.fn().mockResolvedValue({ initialStatus: 'draft' }),
+}));
+
+const project = {
+  id: 'proj-1',
+  name: 'Demo',
+  requirementIds: ['REQ-009'],
+  useCaseIds: [],
+  testCaseIds: [],
+  informationIds: [],
+  riskIds: [],
+};
+
+describe('pluginService', () => {
+  const plugins = { list: vi.fn(), run: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    window.electronAPI = { plugins } as unknown as Window['electronAPI'];
+  });
+
+  describe('listPlugins', () => {
+    it('should filter plugins by capability', async () => {
+      plugins.list.mockResolvedValue({
+        plugins: [
+          { name: 'reqif', command: './reqif', capabilities: ['import', 'export'] },
+          { name: 'doors', command: 'doors-export', capabilities: ['export'] },
+        ],
+      });
+
+      const importers = await pluginService.listPlugins('import');
+
+      expect(importers.map((p) => p.name)).toEqual(['reqif']);
+    });
+  });
+
+  describe('importWithPlugin', () => {
+    it('should create returned artifacts, add them to the project and commit once', async () => {
+      vi.mocked(projectService.load).mockResolvedValue({ ...project });
+      plugins.run.mockResolvedValue({
+        response: {
+          artifacts: [
+            {
+              type: 'requirements',
+              title: 'Login',
+              description: 'Users sign in',
+              status: 'approved',
+              id: 'REQ-999',
+              isDeleted: true,
+            },
+            { type: 'widgets', title: 'Unknown' },
+            { type: 'requirements', title: ' ' },
+          ],
+        },
+      });
+
+      const file = { name: 'spec.reqif', content: '<reqif/>', encoding: 'utf-8' as const };
+      const result = await pluginService.importWithPlugin('reqif', file, 'proj-1');
+
+      expect(plugins.run).toHaveBeenCalledWith('reqif', {
+        action: 'import',
+        fileName: 'spec.reqif',
+        content: '<reqif/>',
+        encoding: 'utf-8',
+      });
+      expect(result).toEqual({
+        created: [{ id: 'REQ-001', typeKey: 'requirements', title: 'Login' }],
+        skipped: [
+          { title: 'Unknown', reason: 'unknown type widgets' },
+          { title: '', reason: 'missing title' },
+        ],
+      });
+      const [changes, message] = vi.mocked(baseDiskService.writeTextFilesAndCommit).mock.calls[0];
+      expect(message).toBe('Import 1 artifacts with plugin reqif');
+      expect(changes.map((change) => change.path)).toEqual([
+        'counters/requirements.md',
+        'requirements/REQ-001.md',
+        'projects/proj-1.md',
+      ]);
+      expect(JSON.parse(changes[1].content)).toMatchObject({
+        id: 'REQ-001',
+        title: 'Login',
+        description: 'Users sign in',
+        status: 'draft',
+        isDeleted: false,
+        priority: 'medium',
+      });
+      expect(JSON.parse(changes[2].content)).toMatchObject({
+        requirementIds: ['REQ-009', 'REQ-001'],
+      });
+    });
+
+    it('should surface plugin errors', async () => {
+      plugins.run.mockResolvedValue({ error: 'Plugin reqif exited with 1: bad file' });
+
+      await expect(
+        pluginService.importWithPlugin('reqif', { name: 'x', content: '', encoding: 'utf-8' })
+      ).rejects.toThrow('bad file');
+    });
+  });
+
+  describe('exportWithPlugin', () => {
+    it('should send the project artifacts and links to the plugin', async () => {
+      vi.mocked(projectService.load).mockResolvedValue(project);
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        { id: 'REQ-009' },
+        { id: 'REQ-010' },
+      ]);
+      vi.mocked(diskLinkService.getLinksForProject).mockResolvedValue([]);
+      plugins.run.mockResolvedValue({ response: { fileName: 'out.reqif', content: '<x/>' } });
+
+      const result = await pluginService.exportWithPlugin('reqif', 'proj-1');
+
+      expect(result).toEqual({ fileName: 'out.reqif', content: '<x/>', encoding: 'utf-8' });
+      expect(plugins.run).toHaveBeenCalledWith(
+        'reqif',
+        expect.objectContaining({
+          action: 'export',
+          artifacts: expect.objectContaining({ requirements: [{ id: 'REQ-009' }] }),
+          links: [],
+        })
+      );
+    });
+  });
+});
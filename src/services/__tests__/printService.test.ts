@@ -0,0 +1,84 @@
+/**
+ * Print Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { printService } from '../printService';
+import { baseDiskService } from '../baseDiskService';
+import { diskLinkService } from '../diskLinkService';
+import { loadImageAsBase64 } from '../../utils/pdfExportUtils';
+import type { Link } from '../../types';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { readTextFile: vi.fn() },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn() },
+}));
+
+vi.mock('../../utils/pdfExportUtils', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../../utils/pdfExportUtils')>()),
+  loadImageAsBase64: vi.fn(),
+}));
+
+const link = (id: string, sourceId: string, targetId: string, isDeleted = false) =>
+  ({ id, sourceId, targetId, type: 'verifies', isDeleted }) as Link;
+
+describe('printService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    const files: Record<string, string> = {
+      'requirements/REQ-001.md': '---\ntitle: Login\n---\n## Flow\n![flow](./assets/flow.png)',
+      'testcases/TC-001.md': '---\ntitle: Sign in\n---\n## Steps\nCheck REQ-001.',
+    };
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+      link('LINK-001', 'TC-001', 'REQ-001'),
+      link('LINK-002', 'TC-002', 'REQ-001', true),
+    ]);
+    vi.mocked(loadImageAsBase64).mockResolvedValue('data:image/png;base64,AAAA');
+  });
+
+  it('should build the document from the artifact files, links and images', async () => {
+    const html = await printService.buildPrintDocument(['REQ-001', 'TC-001'], 'Release 1');
+
+    expect(html).toContain('<title>Release 1</title>');
+    expect(html).toContain('<h2>1. REQ-001 – Login</h2>');
+    expect(html).toContain('<img src="data:image/png;base64,AAAA"');
+    expect(loadImageAsBase64).toHaveBeenCalledWith('./assets/flow.png');
+    // Links are listed from each side; deleted links are left out
+    expect(html).toContain('<li>Verified by <a href="#TC-001">TC-001</a></li>');
+    expect(html).toContain('<li>Verifies <a href="#REQ-001">REQ-001</a></li>');
+    expect(html).not.toContain('TC-002');
+    expect(html).toContain('Check <a href="#REQ-001">REQ-001</a>.');
+  });
+
+  it('should keep missing and unknown artifacts in place with a warning', async () => {
+    const html = await printService.buildPrintDocument(['REQ-404', 'XYZ-1'], 'Missing');
+
+    expect(html).toContain(
+      '<h2>1. REQ-404</h2>\n<p class="warning">Content could not be read.</p>'
+    );
+    expect(html).toContain('<h2>2. XYZ-1</h2>');
+  });
+
+  it('should apply a redaction profile to the artifact content', async () => {
+    vi.mocked(baseDiskService.readTextFile).mockResolvedValue(
+      '---\ntitle: Login\nauthor: Jane\n---\n## Flow\nAs ACME asked.\n## Notes #internal\nCall Bob.'
+    );
+    const profile = {
+      name: 'Supplier',
+      fields: ['author'],
+      sectionTags: ['internal'],
+      patterns: ['ACME'],
+    };
+
+    const html = await printService.buildPrintDocument(['REQ-001'], 'Supplier', profile);
+
+    expect(html).toContain('<h2>1. REQ-001 – Login</h2>');
+    expect(html).toContain('As [redacted] asked.');
+    expect(html).not.toContain('Jane');
+    expect(html).not.toContain('Call Bob');
+  });
+});
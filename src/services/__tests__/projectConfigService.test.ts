@@ -0,0 +1,62 @@
+/**
+ * Project Config Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { projectConfigService } from '../projectConfigService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn() },
+}));
+
+describe('projectConfigService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('merges a stored section over the defaults', async () => {
+    vi.mocked(fileSystemService.readFile).mockResolvedValue('{"b": 3}');
+
+    const section = await projectConfigService.getSection('example', { a: 1, b: 2 });
+
+    expect(fileSystemService.readFile).toHaveBeenCalledWith('config/example.json');
+    expect(section).toEqual({ a: 1, b: 3 });
+  });
+
+  it('returns the defaults when the section is missing or invalid', async () => {
+    vi.mocked(fileSystemService.readFile).mockResolvedValue(null);
+    expect(await projectConfigService.getSection('example', { a: 1 })).toEqual({ a: 1 });
+
+    vi.mocked(fileSystemService.readFile).mockResolvedValue('[1, 2]');
+    expect(await projectConfigService.getSection('example', { a: 1 })).toEqual({ a: 1 });
+  });
+
+  it('writes and commits a section', async () => {
+    await projectConfigService.setSection('example', { a: 1 });
+
+    expect(fileSystemService.writeFile).toHaveBeenCalledWith(
+      'config/example.json',
+      JSON.stringify({ a: 1 }, null, 2)
+    );
+    expect(realGitService.commitFile).toHaveBeenCalledWith(
+      'config/example.json',
+      'Update example configuration'
+    );
+  });
+
+  it('rejects invalid section names', async () => {
+    await expect(projectConfigService.getSection('../secrets', {})).rejects.toThrow(
+      'Invalid config section name'
+    );
+  });
+});
@@ -0,0 +1,137 @@
+// @vitest-environment node
+/**
+ * Project Scan Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  projectScanService,
+  SCAN_INDEX_PATH,
+  type ProjectScanSummary,
+} from '../projectScanService';
+import { baseDiskService } from '../baseDiskService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { readFile: vi.fn() },
+}));
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { listFiles: vi.fn(), writeTextFile: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { resolveRef: vi.fn(), getHistory: vi.fn() },
+}));
+
+const commits = (...hashes: string[]) => hashes.map((hash) => ({ hash }));
+
+describe('projectScanService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {
+      'requirements/REQ-001.md': 'one',
+      'requirements/REQ-002.md': 'two',
+      'risks/RISK-001.md': 'risk',
+    };
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(baseDiskService.writeTextFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(realGitService.resolveRef).mockResolvedValue('c1');
+  });
+
+  it('should record an index on the first scan without reporting changes', async () => {
+    const summary = await projectScanService.scanProject();
+
+    expect(summary).toMatchObject({ firstScan: true, scannedFiles: 3, added: [], head: 'c1' });
+    expect(projectScanService.hasScanDifferences(summary)).toBe(false);
+    const index = JSON.parse(files[SCAN_INDEX_PATH]);
+    expect(index.head).toBe('c1');
+    expect(Object.keys(index.files)).toEqual([
+      'requirements/REQ-001.md',
+      'requirements/REQ-002.md',
+      'risks/RISK-001.md',
+    ]);
+  });
+
+  it('should find files and commits changed since the last scan', async () => {
+    await projectScanService.scanProject();
+    files['requirements/REQ-001.md'] = 'one, edited';
+    files['requirements/REQ-003.md'] = 'three';
+    delete files['risks/RISK-001.md'];
+    vi.mocked(realGitService.resolveRef).mockResolvedValue('c3');
+    vi.mocked(realGitService.getHistory).mockResolvedValue(
+      commits('c3', 'c2', 'c1') as Awaited<ReturnType<typeof realGitService.getHistory>>
+    );
+
+    const reportProgress = vi.fn();
+    const summary = await projectScanService.scanProject({ reportProgress });
+
+    expect(summary).toMatchObject({
+      firstScan: false,
+      added: ['requirements/REQ-003.md'],
+      modified: ['requirements/REQ-001.md'],
+      removed: ['risks/RISK-001.md'],
+      previousHead: 'c1',
+      newCommits: 2,
+      headRewritten: false,
+    });
+    expect(reportProgress).toHaveBeenLastCalledWith(expect.objectContaining({ completed: 3 }));
+
+    // The next scan compares against the state just recorded
+    const again = await projectScanService.scanProject();
+    expect(projectScanService.hasScanDifferences(again)).toBe(false);
+  });
+
+  it('should flag a HEAD that does not descend from the previous one', async () => {
+    await projectScanService.scanProject();
+    vi.mocked(realGitService.resolveRef).mockResolvedValue('other');
+    vi.mocked(realGitService.getHistory).mockResolvedValue(
+      commits('other', 'base') as Awaited<ReturnType<typeof realGitService.getHistory>>
+    );
+
+    const summary = await projectScanService.scanProject();
+
+    expect(summary).toMatchObject({ newCommits: 0, headRewritten: true });
+  });
+
+  it('should stop when cancelled', async () => {
+    const controller = new AbortController();
+    controller.abort();
+
+    await expect(projectScanService.scanProject({ signal: controller.signal })).rejects.toThrow(
+      'Scan cancelled'
+    );
+    expect(files[SCAN_INDEX_PATH]).toBeUndefined();
+  });
+
+  it('should describe the differences in one sentence', () => {
+    const summary: ProjectScanSummary = {
+      firstScan: false,
+      scannedFiles: 10,
+      added: ['a', 'b'],
+      removed: [],
+      modified: ['c'],
+      previousHead: 'c1',
+      head: 'c4',
+      newCommits: 3,
+      headRewritten: false,
+    };
+
+    expect(projectScanService.formatScanSummary(summary)).toBe(
+      'Since the project was last open: 2 artifact files added, 1 artifact file changed and 3 new commits.'
+    );
+    expect(
+      projectScanService.formatScanSummary({ ...summary, added: [], modified: [], newCommits: 0 })
+    ).toBeNull();
+  });
+});
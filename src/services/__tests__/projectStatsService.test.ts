@@ -0,0 +1,106 @@
+/**
+ * Project Statistics Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { computeProjectStats, getProjectStats } from '../projectStatsService';
+import { ALL_ARTIFACT_SERVICES, projectService } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import type { Link, Project } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    projectService: { load: vi.fn() },
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), getLinksForProject: vi.fn() },
+}));
+
+const link = (id: string, sourceId: string, targetId: string, extra: Partial<Link> = {}): Link => ({
+  id,
+  sourceId,
+  targetId,
+  type: 'verifies',
+  projectIds: [],
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+  ...extra,
+});
+
+describe('projectStatsService', () => {
+  const requirements = [
+    { id: 'REQ-001', status: 'approved', priority: 'high' },
+    { id: 'REQ-002', status: 'draft', priority: 'high' },
+    { id: 'REQ-003', status: 'draft', priority: 'low', isDeleted: true },
+  ];
+  const testCases = [{ id: 'TC-001', status: 'draft' }];
+  const links = [
+    link('LINK-001', 'TC-001', 'REQ-001', { suspect: true }),
+    link('LINK-002', 'REQ-002', 'UC-001', { type: 'related_to' }),
+    link('LINK-003', 'TC-001', 'REQ-002', { isDeleted: true }),
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue(requirements);
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue(testCases);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue(links);
+    vi.mocked(diskLinkService.getLinksForProject).mockResolvedValue(links.slice(0, 1));
+  });
+
+  it('counts artifacts by type, status and priority', () => {
+    const stats = computeProjectStats({ requirements, testcases: testCases }, links);
+
+    expect(stats.totalArtifacts).toBe(3);
+    expect(stats.byType.requirements).toEqual({
+      total: 2,
+      byStatus: { approved: 1, draft: 1 },
+      byPriority: { high: 2 },
+    });
+    expect(stats.byType.testcases).toEqual({ total: 1, byStatus: { draft: 1 }, byPriority: {} });
+  });
+
+  it('counts active links, suspect links and coverage', () => {
+    const stats = computeProjectStats({ requirements, testcases: testCases }, links);
+
+    expect(stats.links).toEqual({
+      total: 2,
+      byType: { verifies: 1, related_to: 1 },
+      suspect: 1,
+    });
+    expect(stats.coveragePercent).toBe(50);
+    expect(stats.testedRequirements).toBe(1);
+  });
+
+  it('restricts statistics to project members', async () => {
+    vi.mocked(projectService.load).mockResolvedValue({
+      id: 'PROJ-001',
+      requirementIds: ['REQ-001'],
+      testCaseIds: ['TC-001'],
+    } as unknown as Project);
+
+    const stats = await getProjectStats('PROJ-001');
+
+    expect(stats.projectId).toBe('PROJ-001');
+    expect(stats.byType.requirements.total).toBe(1);
+    expect(stats.coveragePercent).toBe(100);
+    expect(diskLinkService.getLinksForProject).toHaveBeenCalledWith('PROJ-001');
+  });
+
+  it('rejects unknown projects', async () => {
+    vi.mocked(projectService.load).mockResolvedValue(null);
+
+    await expect(getProjectStats('PROJ-404')).rejects.toThrow('Project not found: PROJ-404');
+  });
+});
@@ -0,0 +1,171 @@
+/**
+ * Project Validation Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { findDuplicateIds, validateProject } from '../projectValidationService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { baseDiskService } from '../baseDiskService';
+import { validateTypeAttributes } from '../attributeSchemaService';
+import { getDanglingLinks } from '../danglingLinkService';
+import { diskLinkService } from '../diskLinkService';
+import { referenceValidationService } from '../referenceValidationService';
+import type { Link } from '../../types';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { listFiles: vi.fn(), readTextFile: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: { getSection: vi.fn(async (_section, defaults) => defaults) },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+  };
+});
+
+vi.mock('../attributeSchemaService', () => ({
+  SCHEMA_TYPES: { requirements: 'requirement' },
+  getAttributeSchema: vi.fn().mockResolvedValue({}),
+  validateTypeAttributes: vi.fn(),
+}));
+
+vi.mock('../danglingLinkService', () => ({ getDanglingLinks: vi.fn() }));
+
+vi.mock('../diskLinkService', () => ({ diskLinkService: { getAllLinks: vi.fn() } }));
+
+vi.mock('../referenceValidationService', () => ({
+  REFERENCE_SOURCE_TYPES: ['requirements', 'testcases'],
+  referenceValidationService: { validate: vi.fn() },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'testcases'],
+  getStatusWorkflow: vi.fn().mockResolvedValue({
+    statuses: ['draft', 'approved'],
+    initialStatus: 'draft',
+    transitions: { draft: ['approved'], approved: [] },
+  }),
+  validateStatusWorkflow: vi.fn().mockReturnValue([]),
+}));
+
+const file = (path: string, id?: string) => ({
+  path,
+  content: id ? `---\nid: "${id}"\n---\n# ${id}` : '# Notes',
+});
+
+describe('projectValidationService', () => {
+  describe('findDuplicateIds', () => {
+    it('should report IDs used by several files and mismatched file names', () => {
+      const problems = findDuplicateIds([
+        file('requirements/REQ-001.md', 'REQ-001'),
+        file('requirements/REQ-002.md', 'REQ-001'),
+        file('requirements/REQ-003.md'),
+      ]);
+
+      expect(problems.map((p) => [p.severity, p.artifactId, p.path])).toEqual([
+        ['warning', 'REQ-001', 'requirements/REQ-002.md'],
+        ['error', 'REQ-001', 'requirements/REQ-001.md'],
+      ]);
+      expect(problems[1].message).toBe(
+        'ID REQ-001 is used by 2 files: requirements/REQ-001.md, requirements/REQ-002.md'
+      );
+    });
+  });
+
+  describe('validateProject', () => {
+    beforeEach(() => {
+      vi.clearAllMocks();
+      const files: Record<string, string> = {
+        'requirements/REQ-001.md': '---\nid: "REQ-001"\n---',
+        'requirements/draft.md': '# Draft',
+      };
+      vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder) =>
+        Object.keys(files)
+          .filter((path) => path.startsWith(`${folder}/`))
+          .map((path) => path.slice(folder.length + 1))
+      );
+      vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path) => files[path] ?? '');
+      vi.mocked(validateTypeAttributes).mockResolvedValue([
+        {
+          artifactId: 'REQ-001',
+          violations: [{ attribute: 'owner', message: 'owner is required' }],
+        },
+      ]);
+      vi.mocked(referenceValidationService.validate).mockResolvedValue({
+        scannedFiles: 1,
+        checkedReferences: 3,
+        broken: [
+          {
+            sourcePath: 'requirements/REQ-001.md',
+            sourceId: 'REQ-001',
+            kind: 'id',
+            target: 'REQ-404',
+            line: 4,
+            reason: 'unknown-id',
+          },
+          {
+            sourcePath: 'links/LINK-001.md',
+            sourceId: 'LINK-001',
+            kind: 'link',
+            target: 'TC-404',
+            reason: 'unknown-id',
+          },
+        ],
+      });
+      vi.mocked(getDanglingLinks).mockResolvedValue([
+        {
+          link: { id: 'LINK-001', sourceId: 'TC-404', targetId: 'REQ-001' } as Link,
+          missingEndpoints: ['source'],
+        },
+      ]);
+      vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+        { id: 'LINK-002', sourceId: 'REQ-001', targetId: 'SYS-001', type: 'derived_from' },
+        { id: 'LINK-003', sourceId: 'REQ-001', targetId: 'SYS-001', type: 'refined_by' },
+        { id: 'LINK-004', sourceId: 'TC-001', targetId: 'REQ-001', type: 'verifies' },
+      ] as Link[]);
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+        { id: 'REQ-001', status: 'approved' },
+        { id: 'REQ-002', status: 'legacy' },
+      ]);
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([
+        { id: 'TC-001', status: 'passed' },
+      ]);
+    });
+
+    it('should consolidate all checks into one categorized report', async () => {
+      const report = await validateProject();
+
+      expect(report.counts).toEqual({
+        schema: 1,
+        'duplicate-id': 0,
+        'broken-reference': 1,
+        'dangling-link': 1,
+        'trace-cycle': 1,
+        workflow: 1,
+        'orphan-file': 1,
+      });
+      expect(report.problems.map((p) => p.message)).toEqual([
+        'REQ-001: owner is required',
+        'REQ-001 references unknown artifact REQ-404',
+        'LINK-001 points at missing TC-404',
+        'Derivation/refinement links form a cycle: REQ-001 → SYS-001 → REQ-001',
+        'REQ-002 has status "legacy", which is not part of the workflow',
+        'requirements/draft.md: file name does not follow the artifact naming convention',
+      ]);
+    });
+  });
+});
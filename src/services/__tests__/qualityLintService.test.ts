@@ -0,0 +1,66 @@
+/**
+ * Quality Lint Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { qualityLintService } from '../qualityLintService';
+import { fileSystemService } from '../fileSystemService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    listFiles: vi.fn(),
+    directoryExists: vi.fn(),
+  },
+}));
+
+describe('qualityLintService', () => {
+  const files: Record<string, string> = {
+    'requirements/REQ-001.md': '# Login\n\n## Requirement Text\nThe system may lock accounts.',
+    'requirements/REQ-002.md':
+      '# Logout\n\n## Requirement Text\nThe system logs out idle users.\n\n' +
+      '## Acceptance Criteria\n- Idle for 15 minutes logs the user out',
+    'usecases/UC-001.md': '# Sign in\n\n## Description\nThe user signs in.',
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+  });
+
+  it('lints a requirement including the acceptance criteria check', async () => {
+    const report = await qualityLintService.lintArtifact('requirements/REQ-001.md');
+
+    expect(report.artifactId).toBe('REQ-001');
+    expect(report.findings.map((f) => [f.rule, f.line])).toEqual([
+      ['missing-acceptance-criteria', 1],
+      ['ambiguous-term', 4],
+    ]);
+  });
+
+  it('does not require acceptance criteria for other types', async () => {
+    const report = await qualityLintService.lintArtifact('usecases/UC-001.md');
+
+    expect(report.findings).toEqual([]);
+  });
+
+  it('rejects invalid and missing paths', async () => {
+    await expect(qualityLintService.lintArtifact('links/LINK-001.md')).rejects.toThrow(
+      'Not a lintable artifact path'
+    );
+    await expect(qualityLintService.lintArtifact('requirements/REQ-404.md')).rejects.toThrow(
+      'Artifact not found'
+    );
+  });
+
+  it('returns only artifacts with findings when linting all', async () => {
+    const reports = await qualityLintService.lintAll();
+
+    expect(reports.map((r) => r.path)).toEqual(['requirements/REQ-001.md']);
+  });
+});
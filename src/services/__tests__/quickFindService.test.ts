@@ -0,0 +1,89 @@
+/**
+ * Quick Find Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { loadQuickFindIndex, quickFind, startQuickFindTracking } from '../quickFindService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+
+vi.mock('../artifactServices', () => {
+  const service = () => {
+    const subscribers: (() => void)[] = [];
+    return {
+      loadAll: vi.fn().mockResolvedValue([]),
+      subscribe: vi.fn((callback: () => void) => {
+        subscribers.push(callback);
+        return () => subscribers.splice(subscribers.indexOf(callback), 1);
+      }),
+      notify: () => subscribers.forEach((callback) => callback()),
+    };
+  };
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+    },
+  };
+});
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { onFilesChanged: vi.fn(() => () => {}) },
+}));
+
+const requirements = ALL_ARTIFACT_SERVICES.requirements as unknown as {
+  loadAll: ReturnType<typeof vi.fn>;
+  notify: () => void;
+};
+
+describe('quickFindService', () => {
+  let stop: () => void;
+
+  beforeEach(async () => {
+    vi.clearAllMocks();
+    requirements.loadAll.mockResolvedValue([
+      { id: 'REQ-014', title: 'Retry after timeout' },
+      { id: 'REQ-002', title: 'Session timeout' },
+      { id: 'REQ-003', title: 'Password reset' },
+    ]);
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([
+      { id: 'TC-001', title: 'Timeout is retried' },
+    ]);
+    stop = startQuickFindTracking();
+    await loadQuickFindIndex();
+  });
+
+  afterEach(() => {
+    stop();
+  });
+
+  it('should find artifacts by fuzzy ID and title words, best first', () => {
+    expect(quickFind('REQ timout retry')).toEqual([
+      { id: 'REQ-014', type: 'requirements', title: 'Retry after timeout', score: 24 },
+    ]);
+    expect(quickFind('timeout').map((result) => result.id)).toEqual([
+      'REQ-002',
+      'REQ-014',
+      'TC-001',
+    ]);
+    expect(quickFind('tc1').map((result) => result.id)).toEqual(['TC-001']);
+  });
+
+  it('should limit the number of results', () => {
+    expect(quickFind('timeout', 2)).toHaveLength(2);
+    expect(quickFind('  ')).toEqual([]);
+  });
+
+  it('should rebuild the index after an artifact changes', async () => {
+    expect(quickFind('lockout')).toEqual([]);
+
+    requirements.loadAll.mockResolvedValue([{ id: 'REQ-020', title: 'Account lockout' }]);
+    requirements.notify();
+    await loadQuickFindIndex();
+
+    expect(quickFind('lockout').map((result) => result.id)).toEqual(['REQ-020']);
+  });
+});
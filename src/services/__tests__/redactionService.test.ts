@@ -0,0 +1,66 @@
+/**
+ * Redaction Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  getRedactionProfiles,
+  redactExportState,
+  setRedactionProfiles,
+} from '../redactionService';
+import { projectConfigService } from '../projectConfigService';
+import type { RedactionProfile } from '../../types';
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: {
+    getSection: vi.fn((_section: string, defaults: unknown) => Promise.resolve(defaults)),
+    setSection: vi.fn(),
+  },
+}));
+
+const profile: RedactionProfile = {
+  name: 'Supplier',
+  fields: ['author'],
+  sectionTags: [],
+  patterns: ['ACME'],
+};
+
+describe('redactionService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('should have no profiles until some are configured', async () => {
+    expect(await getRedactionProfiles()).toEqual([]);
+  });
+
+  it('should store valid profiles in the project config', async () => {
+    await setRedactionProfiles([profile]);
+
+    expect(projectConfigService.setSection).toHaveBeenCalledWith(
+      'redaction',
+      { profiles: [profile] },
+      'Update redaction profiles'
+    );
+  });
+
+  it('should refuse profiles with the same name', async () => {
+    await expect(setRedactionProfiles([profile, { ...profile }])).rejects.toThrow(
+      'Duplicate profile name: Supplier'
+    );
+    expect(projectConfigService.setSection).not.toHaveBeenCalled();
+  });
+
+  it('should apply a profile to every artifact list of an export', () => {
+    const state = {
+      requirements: [{ id: 'REQ-001', title: 'For ACME', author: 'Jane' }],
+      risks: [{ id: 'RISK-001', title: 'ACME leaves' }],
+    };
+
+    expect(redactExportState(state, profile)).toEqual({
+      requirements: [{ id: 'REQ-001', title: 'For [redacted]' }],
+      risks: [{ id: 'RISK-001', title: '[redacted] leaves' }],
+    });
+    expect(redactExportState(state, null)).toBe(state);
+  });
+});
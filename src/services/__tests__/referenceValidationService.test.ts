@@ -0,0 +1,96 @@
+/**
+ * ReferenceValidationService Tests
+ *
+ * Tests for detecting broken file links and unknown artifact IDs.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { referenceValidationService } from '../referenceValidationService';
+import { fileSystemService } from '../fileSystemService';
+import { diskLinkService } from '../diskLinkService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    listFiles: vi.fn(),
+    directoryExists: vi.fn(),
+  },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    getAllLinks: vi.fn(),
+  },
+}));
+
+describe('ReferenceValidationService', () => {
+  const files: Record<string, string> = {
+    'requirements/REQ-001.md':
+      '---\nid: REQ-001\n---\n# REQ-001\n\nSee [UC](../usecases/UC-001.md) and TC-404.',
+    'usecases/UC-001.md': '---\nid: UC-001\n---\n# UC-001\n\n![img](../assets/missing.png)',
+    'assets/present.png': 'binary',
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder: string) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(fileSystemService.readFile).mockImplementation(
+      async (path: string) => files[path] ?? null
+    );
+    vi.mocked(fileSystemService.directoryExists).mockResolvedValue(false);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([]);
+  });
+
+  it('should report unknown IDs and missing files', async () => {
+    const report = await referenceValidationService.validate();
+
+    expect(report.scannedFiles).toBe(2);
+    expect(report.broken).toHaveLength(2);
+    expect(report.broken).toContainEqual({
+      sourcePath: 'requirements/REQ-001.md',
+      sourceId: 'REQ-001',
+      kind: 'id',
+      target: 'TC-404',
+      line: 6,
+      reason: 'unknown-id',
+    });
+    expect(report.broken).toContainEqual({
+      sourcePath: 'usecases/UC-001.md',
+      sourceId: 'UC-001',
+      kind: 'path',
+      target: 'assets/missing.png',
+      line: 6,
+      reason: 'missing-file',
+    });
+  });
+
+  it('should report link files with dangling endpoints', async () => {
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+      {
+        id: 'LINK-001',
+        sourceId: 'REQ-001',
+        targetId: 'TC-999',
+        type: 'verified_by',
+        projectIds: [],
+        dateCreated: 0,
+        lastModified: 0,
+        revision: '01',
+      },
+    ]);
+
+    const report = await referenceValidationService.validate();
+
+    expect(report.broken).toContainEqual({
+      sourcePath: 'links/LINK-001.md',
+      sourceId: 'LINK-001',
+      kind: 'link',
+      target: 'TC-999',
+      reason: 'unknown-id',
+    });
+  });
+});
@@ -0,0 +1,137 @@
+/**
+ * Release Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { releaseService } from '../releaseService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    deleteFile: vi.fn(),
+    listFiles: vi.fn(),
+    directoryExists: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), readFileAtCommit: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: { load: vi.fn() },
+    testcases: { load: vi.fn() },
+  },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  WORKFLOW_TYPES: ['requirements', 'usecases', 'testcases', 'information', 'risks'],
+  getCurrentUserName: vi.fn().mockResolvedValue('Sam'),
+}));
+
+const artifacts: Record<string, { id: string; title: string; status: string }> = {
+  'REQ-001': { id: 'REQ-001', title: 'Login', status: 'approved' },
+  'REQ-002': { id: 'REQ-002', title: 'Logout', status: 'draft' },
+  'TC-001': { id: 'TC-001', title: 'Login test', status: 'passed' },
+};
+
+describe('releaseService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {
+      'requirements/REQ-001.md': '',
+      'requirements/REQ-002.md': '',
+      'testcases/TC-001.md': '',
+    };
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    for (const service of Object.values(ALL_ARTIFACT_SERVICES)) {
+      vi.mocked(service.load).mockImplementation(async (id: string) => artifacts[id] ?? null);
+    }
+  });
+
+  it('should create releases with sequential IDs and unique names', async () => {
+    const first = await releaseService.createRelease({ projectId: 'proj-1', name: '1.0' });
+    const second = await releaseService.createRelease({ projectId: 'proj-1', name: '2.0' });
+
+    expect(first.id).toBe('REL-001');
+    expect(second.id).toBe('REL-002');
+    expect(first.createdBy).toBe('Sam');
+    await expect(
+      releaseService.createRelease({ projectId: 'proj-1', name: '1.0' })
+    ).rejects.toThrow('already exists');
+    expect((await releaseService.listReleases('proj-1')).map((r) => r.name)).toEqual([
+      '1.0',
+      '2.0',
+    ]);
+  });
+
+  it('should assign, pin and unassign artifacts', async () => {
+    const { id } = await releaseService.createRelease({ projectId: 'proj-1', name: '1.0' });
+    vi.mocked(realGitService.readFileAtCommit).mockResolvedValue('---\nstatus: draft\n---\n');
+
+    await releaseService.assignArtifacts(id, ['REQ-001', 'REQ-002']);
+    await releaseService.assignArtifacts(id, ['REQ-002'], 'abc123');
+    const release = await releaseService.unassignArtifacts(id, ['REQ-001']);
+
+    expect(release.items).toEqual([{ artifactId: 'REQ-002', commitHash: 'abc123' }]);
+    expect(realGitService.commitFile).toHaveBeenLastCalledWith(
+      'releases/REL-001.json',
+      'REL-001: unassign REQ-001'
+    );
+  });
+
+  it('should reject unknown artifacts and versions', async () => {
+    const { id } = await releaseService.createRelease({ projectId: 'proj-1', name: '1.0' });
+    vi.mocked(realGitService.readFileAtCommit).mockResolvedValue(null);
+
+    await expect(releaseService.assignArtifacts(id, ['REQ-404'])).rejects.toThrow(
+      'Artifact not found: REQ-404'
+    );
+    await expect(releaseService.assignArtifacts(id, ['REQ-001'], 'abc123')).rejects.toThrow(
+      'does not exist at commit abc123'
+    );
+    await expect(releaseService.assignArtifacts(id, ['USR-001'])).rejects.toThrow(
+      'Not a releasable artifact'
+    );
+  });
+
+  it('should report scope and completeness per type', async () => {
+    const { id } = await releaseService.createRelease({ projectId: 'proj-1', name: '1.0' });
+    await releaseService.assignArtifacts(id, ['REQ-002', 'TC-001']);
+    vi.mocked(realGitService.readFileAtCommit).mockResolvedValue(
+      '---\ntitle: Login v1\nstatus: approved\n---\n'
+    );
+    await releaseService.assignArtifacts(id, ['REQ-001'], 'abc123');
+
+    const report = await releaseService.getReleaseReport(id);
+
+    expect(report.byType).toEqual({
+      requirements: { total: 2, complete: 1 },
+      testcases: { total: 1, complete: 1 },
+    });
+    expect(report.items.find((i) => i.artifactId === 'REQ-001')).toMatchObject({
+      title: 'Login v1',
+      status: 'approved',
+      commitHash: 'abc123',
+      found: true,
+      complete: true,
+    });
+    expect(report.completeness).toBeCloseTo(2 / 3);
+  });
+});
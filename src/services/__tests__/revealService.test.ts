@@ -0,0 +1,55 @@
+/**
+ * Reveal Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { revealService } from '../revealService';
+import { isElectronEnv } from '../git/types';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+describe('revealService', () => {
+  const shell = { revealInFileManager: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    shell.revealInFileManager.mockResolvedValue({ ok: true });
+    window.electronAPI = { shell } as unknown as Window['electronAPI'];
+  });
+
+  it('should reveal a project file by its project path', async () => {
+    await revealService.revealInFileManager('requirements/REQ-001.md');
+
+    expect(shell.revealInFileManager).toHaveBeenCalledWith('requirements/REQ-001.md');
+  });
+
+  it('should reject paths outside the project', async () => {
+    for (const path of ['../secret.md', 'requirements/../../x', '/etc/passwd', 'C:/x', '']) {
+      await expect(revealService.revealInFileManager(path)).rejects.toThrow(
+        'Not a path inside the project'
+      );
+    }
+    expect(shell.revealInFileManager).not.toHaveBeenCalled();
+  });
+
+  it('should surface errors from the main process', async () => {
+    shell.revealInFileManager.mockResolvedValue({ error: 'File not found: risks/RSK-9.md' });
+
+    await expect(revealService.revealInFileManager('risks/RSK-9.md')).rejects.toThrow(
+      'File not found'
+    );
+  });
+
+  it('should be unavailable in the browser', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+
+    expect(revealService.canRevealInFileManager()).toBe(false);
+    await expect(revealService.revealInFileManager('requirements/REQ-001.md')).rejects.toThrow(
+      'desktop app'
+    );
+  });
+});
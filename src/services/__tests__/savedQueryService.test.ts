@@ -0,0 +1,130 @@
+/**
+ * Saved Query Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  deleteSavedQuery,
+  getSavedQueries,
+  runQuery,
+  runSavedQuery,
+  saveQuery,
+} from '../savedQueryService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import { projectConfigService } from '../projectConfigService';
+import type { Link, SavedQuery } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]) });
+  return {
+    ALL_ARTIFACT_SERVICES: { requirements: service(), testcases: service(), projects: service() },
+  };
+});
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    getAllLinks: vi.fn().mockResolvedValue([]),
+    getLinksForProject: vi.fn().mockResolvedValue([]),
+  },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: {
+    getSection: vi.fn((_section: string, defaults: unknown) => Promise.resolve(defaults)),
+    setSection: vi.fn(),
+  },
+}));
+
+const UNTESTED: SavedQuery = {
+  name: 'Untested high-priority',
+  query: {
+    types: ['requirements'],
+    priority: ['high'],
+    excludeStatus: ['approved'],
+    links: [{ type: 'verified_by', present: false }],
+  },
+};
+
+describe('savedQueryService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      { id: 'REQ-001', title: 'Login', status: 'draft', priority: 'high' },
+      { id: 'REQ-002', title: 'Logout', status: 'draft', priority: 'high' },
+      { id: 'REQ-003', title: 'Audit', status: 'approved', priority: 'high' },
+    ]);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+      { id: 'LINK-001', sourceId: 'TC-001', targetId: 'REQ-001', type: 'verifies' },
+    ] as Link[]);
+  });
+
+  it('should add and replace queries by name in the project config', async () => {
+    expect(await getSavedQueries()).toEqual([]);
+
+    await saveQuery({ ...UNTESTED, name: ' Untested high-priority ' });
+
+    expect(projectConfigService.setSection).toHaveBeenCalledWith(
+      'saved-queries',
+      { queries: [UNTESTED] },
+      'Add saved query Untested high-priority'
+    );
+
+    vi.mocked(projectConfigService.getSection).mockResolvedValueOnce({ queries: [UNTESTED] });
+    const updated = { ...UNTESTED, description: 'Review weekly' };
+    await saveQuery(updated);
+
+    expect(projectConfigService.setSection).toHaveBeenLastCalledWith(
+      'saved-queries',
+      { queries: [updated] },
+      'Update saved query Untested high-priority'
+    );
+  });
+
+  it('should refuse invalid queries', async () => {
+    await expect(
+      saveQuery({ name: 'Broken', query: { types: ['requirements'], tags: 'NOT' } })
+    ).rejects.toThrow('Invalid query Broken');
+    await expect(saveQuery({ ...UNTESTED, name: ' ' })).rejects.toThrow(
+      'Query name must not be empty'
+    );
+    expect(projectConfigService.setSection).not.toHaveBeenCalled();
+  });
+
+  it('should delete queries by name', async () => {
+    vi.mocked(projectConfigService.getSection)
+      .mockResolvedValueOnce({ queries: [UNTESTED] })
+      .mockResolvedValueOnce({ queries: [UNTESTED] });
+
+    await deleteSavedQuery('Unknown');
+    expect(projectConfigService.setSection).not.toHaveBeenCalled();
+
+    await deleteSavedQuery(UNTESTED.name);
+    expect(projectConfigService.setSection).toHaveBeenCalledWith(
+      'saved-queries',
+      { queries: [] },
+      'Delete saved query Untested high-priority'
+    );
+  });
+
+  it('should run a saved query against the artifacts and links on disk', async () => {
+    vi.mocked(projectConfigService.getSection).mockResolvedValueOnce({ queries: [UNTESTED] });
+
+    expect(await runSavedQuery(UNTESTED.name)).toEqual([
+      { id: 'REQ-002', type: 'requirements', title: 'Logout', status: 'draft', priority: 'high' },
+    ]);
+    await expect(runSavedQuery('Unknown')).rejects.toThrow('Saved query not found: Unknown');
+  });
+
+  it('should restrict results to the artifacts of a project', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.projects.load).mockImplementation(async (id: string) =>
+      id === 'PRJ-001' ? { id, requirementIds: ['REQ-001'] } : null
+    );
+
+    const results = await runQuery(UNTESTED.query, 'PRJ-001');
+
+    expect(diskLinkService.getLinksForProject).toHaveBeenCalledWith('PRJ-001');
+    expect(results.map((result) => result.id)).toEqual(['REQ-001']);
+    await expect(runQuery(UNTESTED.query, 'PRJ-404')).rejects.toThrow('Project not found: PRJ-404');
+  });
+});
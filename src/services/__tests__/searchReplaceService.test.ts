@@ -0,0 +1,126 @@
+/**
+ * Search and Replace Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { compileSearchPattern, searchReplace } from '../searchReplaceService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { runJournaled } from '../writeJournalService';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({ load: vi.fn(), loadAll: vi.fn().mockResolvedValue([]), save: vi.fn() });
+  return {
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+      documents: service(),
+      projects: service(),
+    },
+  };
+});
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+describe('searchReplaceService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue([
+      {
+        id: 'REQ-001',
+        title: 'AcmeLink pairing',
+        text: 'The AcmeLink app pairs.\nNo change here.\nacmelink retries.',
+        status: 'acmelink',
+        revision: '02',
+        lastModified: 1,
+      },
+      { id: 'REQ-002', title: 'Logout', text: 'Nothing to see', revision: '01', lastModified: 1 },
+    ]);
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue([
+      {
+        id: 'TC-001',
+        title: 'Pair with AcmeLink',
+        description: '',
+        revision: '01',
+        lastModified: 1,
+      },
+    ]);
+  });
+
+  it('should preview the changed lines without writing anything', async () => {
+    const result = await searchReplace('acmelink', 'TracyLink');
+
+    expect(result.applied).toBe(false);
+    expect(result.matchCount).toBe(4);
+    expect(result.artifacts.map((a) => [a.artifactId, a.path, a.matchCount])).toEqual([
+      ['REQ-001', 'requirements/REQ-001.md', 3],
+      ['TC-001', 'testcases/TC-001.md', 1],
+    ]);
+    expect(result.artifacts[0].lines).toEqual([
+      { field: 'title', line: 1, before: 'AcmeLink pairing', after: 'TracyLink pairing' },
+      {
+        field: 'text',
+        line: 1,
+        before: 'The AcmeLink app pairs.',
+        after: 'The TracyLink app pairs.',
+      },
+      { field: 'text', line: 3, before: 'acmelink retries.', after: 'TracyLink retries.' },
+    ]);
+    expect(runJournaled).not.toHaveBeenCalled();
+    expect(ALL_ARTIFACT_SERVICES.requirements.save).not.toHaveBeenCalled();
+  });
+
+  it('should apply all replacements in one journaled commit', async () => {
+    const result = await searchReplace('Acme(\\w+)', 'Tracy$1', { caseSensitive: true }, false);
+
+    expect(result.applied).toBe(true);
+    expect(runJournaled).toHaveBeenCalledWith(
+      'Search and replace',
+      expect.any(Function),
+      'Replace "Acme(\\w+)" with "Tracy$1" in 2 artifact(s)'
+    );
+    expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+      expect.objectContaining({
+        id: 'REQ-001',
+        title: 'TracyLink pairing',
+        text: 'The TracyLink app pairs.\nNo change here.\nacmelink retries.',
+        status: 'acmelink',
+        revision: '03',
+      })
+    );
+    expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledWith(
+      expect.objectContaining({ id: 'TC-001', title: 'Pair with TracyLink' })
+    );
+  });
+
+  it('should restrict the search to the given types, fields and project', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.projects.load).mockResolvedValue({
+      id: 'PRJ-001',
+      requirementIds: ['REQ-002'],
+      testCaseIds: ['TC-001'],
+    });
+
+    const result = await searchReplace('acmelink', 'x', {
+      types: ['requirements', 'testcases'],
+      fields: ['title'],
+      projectId: 'PRJ-001',
+    });
+
+    expect(result.artifacts.map((a) => a.artifactId)).toEqual(['TC-001']);
+  });
+
+  it('should refuse invalid and empty-matching patterns and unknown fields', async () => {
+    expect(() => compileSearchPattern('(')).toThrow('Invalid pattern');
+    expect(() => compileSearchPattern('a*')).toThrow('Pattern must not match empty text: a*');
+    await expect(searchReplace('x', 'y', { fields: ['id'] })).rejects.toThrow(
+      'Fields cannot be replaced in: id'
+    );
+    await expect(searchReplace('x', 'y', { types: ['users'] })).rejects.toThrow(
+      'Unknown artifact type: users'
+    );
+  });
+});
@@ -0,0 +1,63 @@
+/**
+ * Secret Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { secretService } from '../secretService';
+import { isElectronEnv } from '../git/types';
+
+vi.mock('../git/types', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('../git/types')>()),
+  isElectronEnv: vi.fn(() => true),
+}));
+
+describe('secretService', () => {
+  const secure = { getToken: vi.fn(), setToken: vi.fn(), removeToken: vi.fn() };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(isElectronEnv).mockReturnValue(true);
+    window.electronAPI = { secure } as unknown as Window['electronAPI'];
+  });
+
+  afterEach(() => {
+    localStorage.clear();
+  });
+
+  it('should store, read and delete secrets in the keychain-backed store', async () => {
+    secure.setToken.mockResolvedValue({ ok: true });
+    secure.getToken.mockResolvedValue({ token: 'abc' });
+    secure.removeToken.mockResolvedValue({ ok: true });
+
+    await secretService.storeSecret('jira', 'abc');
+    expect(await secretService.getSecret('jira')).toBe('abc');
+    await secretService.deleteSecret('jira');
+
+    expect(secure.setToken).toHaveBeenCalledWith('abc', 'jira');
+    expect(secure.removeToken).toHaveBeenCalledWith('jira');
+    expect(localStorage.length).toBe(0);
+  });
+
+  it('should surface keychain errors instead of falling back to plaintext', async () => {
+    secure.setToken.mockResolvedValue({ error: 'No OS keychain is available' });
+
+    await expect(secretService.storeSecret('git', 'abc')).rejects.toThrow(
+      'No OS keychain is available'
+    );
+    expect(localStorage.length).toBe(0);
+  });
+
+  it('should reject names that are not safe file names', async () => {
+    await expect(secretService.getSecret('../git')).rejects.toThrow('Invalid secret name');
+    expect(secure.getToken).not.toHaveBeenCalled();
+  });
+
+  it('should use localStorage in the browser build', async () => {
+    vi.mocked(isElectronEnv).mockReturnValue(false);
+
+    await secretService.storeSecret('github', 'tok');
+
+    expect(await secretService.getSecret('github')).toBe('tok');
+    expect(secure.setToken).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,205 @@
+/**
+ * Status Workflow Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  DEFAULT_STATUS_WORKFLOW,
+  getAllowedTransitions,
+  validateStatusWorkflow,
+  setStatusWorkflow,
+  transitionArtifact,
+  changeArtifactStatus,
+} from '../statusWorkflowService';
+import { ALL_ARTIFACT_SERVICES, userService } from '../artifactServices';
+import { diskProjectService } from '../diskProjectService';
+import { projectConfigService } from '../projectConfigService';
+import { realGitService } from '../realGitService';
+import { checkPermission } from '../permissionService';
+import type { User } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  userService: { load: vi.fn() },
+  ALL_ARTIFACT_SERVICES: {
+    requirements: { load: vi.fn(), save: vi.fn() },
+    documents: { load: vi.fn(), save: vi.fn() },
+  },
+}));
+
+vi.mock('../diskProjectService', () => ({
+  diskProjectService: { getCurrentUserId: vi.fn() },
+}));
+
+vi.mock('../projectConfigService', () => ({
+  projectConfigService: {
+    getSection: vi.fn((_section: string, defaults: unknown) => Promise.resolve(defaults)),
+    setSection: vi.fn(),
+  },
+}));
+
+vi.mock('../permissionService', () => ({
+  checkPermission: vi.fn(),
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn() },
+}));
+
+describe('statusWorkflowService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(diskProjectService.getCurrentUserId).mockResolvedValue('USER-001');
+    vi.mocked(userService.load).mockResolvedValue({ id: 'USER-001', name: 'Alex' } as User);
+  });
+
+  describe('getAllowedTransitions', () => {
+    it('follows the configured transitions', () => {
+      expect(getAllowedTransitions(DEFAULT_STATUS_WORKFLOW, 'draft')).toEqual([
+        'in-review',
+        'obsolete',
+      ]);
+      expect(getAllowedTransitions(DEFAULT_STATUS_WORKFLOW, 'obsolete')).toEqual([]);
+    });
+
+    it('treats a missing status as the initial status', () => {
+      expect(getAllowedTransitions(DEFAULT_STATUS_WORKFLOW)).toEqual(['in-review', 'obsolete']);
+    });
+
+    it('lets legacy statuses move to any workflow status', () => {
+      expect(getAllowedTransitions(DEFAULT_STATUS_WORKFLOW, 'implemented')).toEqual(
+        DEFAULT_STATUS_WORKFLOW.statuses
+      );
+    });
+  });
+
+  describe('setStatusWorkflow', () => {
+    it('rejects workflows referring to unknown statuses', async () => {
+      const config = { ...DEFAULT_STATUS_WORKFLOW, transitions: { draft: ['done'] } };
+
+      expect(validateStatusWorkflow(config)).toEqual([
+        'Transition from "draft" to unknown status "done"',
+      ]);
+      await expect(setStatusWorkflow(config)).rejects.toThrow('Invalid workflow');
+      expect(projectConfigService.setSection).not.toHaveBeenCalled();
+    });
+
+    it('stores a valid workflow in the project config', async () => {
+      await setStatusWorkflow(DEFAULT_STATUS_WORKFLOW);
+
+      expect(projectConfigService.setSection).toHaveBeenCalledWith(
+        'workflow',
+        DEFAULT_STATUS_WORKFLOW,
+        'Update status workflow'
+      );
+    });
+  });
+
+  describe('transitionArtifact', () => {
+    it('applies a legal transition, records who/when and commits', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+        id: 'REQ-001',
+        status: 'draft',
+        lastModified: 0,
+      });
+
+      const updated = await transitionArtifact('requirements/REQ-001.md', 'in-review');
+
+      expect(updated.status).toBe('in-review');
+      expect(updated.statusChangedBy).toBe('Alex');
+      expect(updated.statusChangedAt).toBeGreaterThan(0);
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(
+        updated,
+        undefined,
+        'edit'
+      );
+      expect(realGitService.commitFile).toHaveBeenCalledWith(
+        'requirements/REQ-001.md',
+        'REQ-001: draft → in-review',
+        'Alex'
+      );
+    });
+
+    it('rejects illegal transitions', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+        id: 'REQ-001',
+        status: 'draft',
+        lastModified: 0,
+      });
+
+      await expect(transitionArtifact('requirements/REQ-001.md', 'approved')).rejects.toThrow(
+        'Transition from "draft" to "approved" is not allowed'
+      );
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).not.toHaveBeenCalled();
+      expect(realGitService.commitFile).not.toHaveBeenCalled();
+    });
+
+    it('rejects unknown statuses and paths', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+        id: 'REQ-001',
+        status: 'draft',
+        lastModified: 0,
+      });
+
+      await expect(transitionArtifact('requirements/REQ-001.md', 'done')).rejects.toThrow(
+        'Unknown status "done"'
+      );
+      await expect(transitionArtifact('links/LINK-001.md', 'approved')).rejects.toThrow(
+        'Not a workflow artifact path'
+      );
+    });
+  });
+
+  describe('changeArtifactStatus', () => {
+    it('moves workflow artifacts through the workflow', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+        id: 'REQ-001',
+        status: 'draft',
+        lastModified: 0,
+      });
+
+      const updated = await changeArtifactStatus('REQ-001', 'in-review');
+
+      expect(updated?.status).toBe('in-review');
+      expect(realGitService.commitFile).toHaveBeenCalledWith(
+        'requirements/REQ-001.md',
+        'REQ-001: draft → in-review',
+        'Alex'
+      );
+      await expect(changeArtifactStatus('REQ-001', 'approved')).rejects.toThrow(
+        'Transition from "draft" to "approved" is not allowed'
+      );
+    });
+
+    it('returns null when the status does not change', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue({
+        id: 'REQ-001',
+        lastModified: 0,
+      });
+
+      expect(await changeArtifactStatus('REQ-001', 'draft')).toBeNull();
+      expect(ALL_ARTIFACT_SERVICES.requirements.save).not.toHaveBeenCalled();
+    });
+
+    it('checks the approve permission for other artifacts', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.documents.load).mockResolvedValue({
+        id: 'DOC-001',
+        status: 'draft',
+        lastModified: 0,
+      });
+      vi.mocked(checkPermission).mockRejectedValueOnce(new Error('may not approve'));
+
+      await expect(changeArtifactStatus('DOC-001', 'approved')).rejects.toThrow('may not approve');
+      expect(ALL_ARTIFACT_SERVICES.documents.save).not.toHaveBeenCalled();
+
+      const updated = await changeArtifactStatus('DOC-001', 'approved');
+
+      expect(checkPermission).toHaveBeenCalledWith('approve');
+      expect(ALL_ARTIFACT_SERVICES.documents.save).toHaveBeenCalledWith(
+        updated,
+        undefined,
+        'approve'
+      );
+      expect(updated?.status).toBe('approved');
+    });
+  });
+});
@@ -0,0 +1,147 @@
+/**
+ * Suspect Link Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+  getTrackedArtifactId,
+  markLinksSuspect,
+  flagLinksOfModifiedFiles,
+  getSuspectLinks,
+  clearSuspect,
+  clearSuspectForArtifact,
+} from '../suspectLinkService';
+import { diskLinkService } from '../diskLinkService';
+import type { Link } from '../../types';
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    queryLinks: vi.fn(),
+    getLinkById: vi.fn(),
+    save: vi.fn(async (link: Link) => link),
+  },
+}));
+
+describe('suspectLinkService', () => {
+  const createLink = (id: string, overrides: Partial<Link> = {}): Link => ({
+    id,
+    sourceId: 'REQ-001',
+    targetId: 'TC-001',
+    type: 'verified_by',
+    projectIds: [],
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+    ...overrides,
+  });
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('getTrackedArtifactId', () => {
+    it('returns the artifact ID for tracked artifact files only', () => {
+      expect(getTrackedArtifactId('requirements/REQ-001.md')).toBe('REQ-001');
+      expect(getTrackedArtifactId('testcases/TC-010.md')).toBe('TC-010');
+      expect(getTrackedArtifactId('links/LINK-001.md')).toBeNull();
+      expect(getTrackedArtifactId('counters/requirements.md')).toBeNull();
+      expect(getTrackedArtifactId('assets/image.png')).toBeNull();
+    });
+  });
+
+  describe('markLinksSuspect', () => {
+    it('flags links touching the artifact that are not already suspect', async () => {
+      vi.mocked(diskLinkService.queryLinks).mockResolvedValue([
+        createLink('LINK-001'),
+        createLink('LINK-002', { suspect: true, suspectSince: 5 }),
+      ]);
+
+      const flagged = await markLinksSuspect('REQ-001');
+
+      expect(diskLinkService.queryLinks).toHaveBeenCalledWith({ artifactId: 'REQ-001' });
+      expect(flagged.map((l) => l.id)).toEqual(['LINK-001']);
+      expect(flagged[0].suspect).toBe(true);
+      expect(flagged[0].suspectSince).toBeGreaterThan(0);
+      expect(diskLinkService.save).toHaveBeenCalledTimes(1);
+    });
+
+    it('leaves links that do not propagate impact alone', async () => {
+      vi.mocked(diskLinkService.queryLinks).mockResolvedValue([
+        createLink('LINK-001', { type: 'related_to' }),
+      ]);
+
+      expect(await markLinksSuspect('REQ-001')).toEqual([]);
+      expect(diskLinkService.save).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('flagLinksOfModifiedFiles', () => {
+    it('flags links of modified artifacts and returns the link files to commit', async () => {
+      vi.mocked(diskLinkService.queryLinks).mockImplementation(async ({ artifactId }) =>
+        artifactId === 'REQ-001' ? [createLink('LINK-001'), createLink('LINK-002')] : []
+      );
+      const listener = vi.fn();
+      const journal = { record: vi.fn() };
+      window.addEventListener('links-flagged-suspect', listener);
+
+      const paths = await flagLinksOfModifiedFiles(
+        ['requirements/REQ-001.md', 'testcases/TC-009.md', 'projects/PROJ-001.md'],
+        journal
+      );
+
+      window.removeEventListener('links-flagged-suspect', listener);
+      expect(diskLinkService.queryLinks).toHaveBeenCalledTimes(2);
+      expect(paths).toEqual(['links/LINK-001.md', 'links/LINK-002.md']);
+      expect(journal.record.mock.calls).toEqual([['links/LINK-001.md'], ['links/LINK-002.md']]);
+      expect(listener).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe('getSuspectLinks', () => {
+    it('returns only suspect links for the query', async () => {
+      vi.mocked(diskLinkService.queryLinks).mockResolvedValue([
+        createLink('LINK-001'),
+        createLink('LINK-002', { suspect: true }),
+      ]);
+
+      const links = await getSuspectLinks({ projectId: 'PROJ-001' });
+
+      expect(diskLinkService.queryLinks).toHaveBeenCalledWith({ projectId: 'PROJ-001' });
+      expect(links.map((l) => l.id)).toEqual(['LINK-002']);
+    });
+  });
+
+  describe('clearSuspect', () => {
+    it('clears the suspect flag of a link', async () => {
+      vi.mocked(diskLinkService.getLinkById).mockResolvedValue(
+        createLink('LINK-001', { suspect: true, suspectSince: 5 })
+      );
+
+      const updated = await clearSuspect('LINK-001');
+
+      expect(updated!.suspect).toBeUndefined();
+      expect(updated!.suspectSince).toBeUndefined();
+      expect(diskLinkService.save).toHaveBeenCalledTimes(1);
+    });
+
+    it('does not rewrite links that are not suspect', async () => {
+      vi.mocked(diskLinkService.getLinkById).mockResolvedValue(createLink('LINK-001'));
+
+      await clearSuspect('LINK-001');
+
+      expect(diskLinkService.save).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('clearSuspectForArtifact', () => {
+    it('clears all suspect links touching the artifact', async () => {
+      const suspect = createLink('LINK-002', { suspect: true });
+      vi.mocked(diskLinkService.queryLinks).mockResolvedValue([createLink('LINK-001'), suspect]);
+      vi.mocked(diskLinkService.getLinkById).mockResolvedValue(suspect);
+
+      const cleared = await clearSuspectForArtifact('REQ-001');
+
+      expect(cleared.map((l) => l.id)).toEqual(['LINK-002']);
+    });
+  });
+});
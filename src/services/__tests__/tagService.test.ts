@@ -0,0 +1,85 @@
+/**
+ * Tag Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { listTags, addTags, removeTags, queryByTags } from '../tagService';
+import { ALL_ARTIFACT_SERVICES, projectService } from '../artifactServices';
+import type { Project } from '../../types';
+
+vi.mock('../artifactServices', () => {
+  const service = () => ({
+    load: vi.fn(),
+    loadAll: vi.fn().mockResolvedValue([]),
+    save: vi.fn(),
+  });
+  return {
+    projectService: { load: vi.fn() },
+    ALL_ARTIFACT_SERVICES: {
+      requirements: service(),
+      usecases: service(),
+      testcases: service(),
+      information: service(),
+      risks: service(),
+    },
+  };
+});
+
+describe('tagService', () => {
+  const requirements = [
+    { id: 'REQ-001', tags: ['safety', 'ui'], lastModified: 0 },
+    { id: 'REQ-002', tags: ['Safety'], lastModified: 0 },
+  ];
+  const testCases = [{ id: 'TC-001', tags: ['ui', 'legacy'], lastModified: 0 }];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue(requirements);
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue(testCases);
+  });
+
+  it('lists tags with usage counts', async () => {
+    expect(await listTags()).toEqual([
+      { tag: 'legacy', count: 1 },
+      { tag: 'safety', count: 2 },
+      { tag: 'ui', count: 2 },
+    ]);
+  });
+
+  it('restricts tags to the artifacts of a project', async () => {
+    vi.mocked(projectService.load).mockResolvedValue({
+      id: 'PROJ-001',
+      requirementIds: ['REQ-002'],
+      testCaseIds: [],
+    } as unknown as Project);
+
+    expect(await listTags('PROJ-001')).toEqual([{ tag: 'safety', count: 1 }]);
+  });
+
+  it('adds normalized tags without duplicates', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.requirements.load).mockResolvedValue(requirements[1]);
+
+    const updated = await addTags('REQ-002', ['safety', 'Field Test']);
+
+    expect(updated.tags).toEqual(['safety', 'field-test']);
+    expect(ALL_ARTIFACT_SERVICES.requirements.save).toHaveBeenCalledWith(updated);
+  });
+
+  it('removes tags and skips saving when nothing changes', async () => {
+    vi.mocked(ALL_ARTIFACT_SERVICES.testcases.load).mockResolvedValue(testCases[0]);
+
+    expect((await removeTags('TC-001', ['legacy'])).tags).toEqual(['ui']);
+    await removeTags('TC-001', ['unknown']);
+    expect(ALL_ARTIFACT_SERVICES.testcases.save).toHaveBeenCalledTimes(1);
+  });
+
+  it('rejects artifacts that cannot be tagged', async () => {
+    await expect(addTags('LINK-001', ['x'])).rejects.toThrow('does not support tags');
+  });
+
+  it('queries artifacts by tag expression', async () => {
+    const result = await queryByTags('ui AND NOT legacy OR safety');
+
+    expect(result.map((a) => a.id)).toEqual(['REQ-001', 'REQ-002']);
+  });
+});
@@ -0,0 +1,112 @@
+/**
+ * Test Result Import Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { importJUnitReport, matchTestResults } from '../testResultImportService';
+import { testCaseService } from '../artifactServices';
+import { realGitService } from '../realGitService';
+import type { TestCase } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  testCaseService: { loadAll: vi.fn(), save: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFiles: vi.fn() },
+}));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn(async (_operation, apply, commitMessage) => {
+    const recorded: string[] = [];
+    await apply({ record: async (path: string) => void recorded.push(path) });
+    await realGitService.commitFiles(recorded, commitMessage);
+  }),
+}));
+
+const testCase = (id: string, title: string, status = 'draft'): TestCase => ({
+  id,
+  title,
+  description: '',
+  status,
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+});
+
+const REPORT = `<testsuite name="auth">
+  <testcase classname="auth" name="TC-001 valid login"/>
+  <testcase classname="auth" name="TC-002 lockout"><failure message="not locked"/></testcase>
+  <testcase classname="auth" name="TC-002 unlock"/>
+  <testcase classname="auth" name="TC-003 reset"><skipped/></testcase>
+  <testcase classname="auth" name="smoke"/>
+</testsuite>`;
+
+describe('testResultImportService', () => {
+  const testCases = [
+    testCase('TC-001', 'Valid login', 'approved'),
+    testCase('TC-002', 'Lockout'),
+    testCase('TC-003', 'Password reset'),
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(testCaseService.loadAll).mockResolvedValue(testCases);
+    vi.mocked(testCaseService.save).mockImplementation(async (tc) => tc);
+  });
+
+  describe('matchTestResults', () => {
+    it('should fail a test case when any of its tests failed', () => {
+      const summary = matchTestResults(
+        [
+          { name: 'TC-002 lockout', className: 'auth', outcome: 'failed' },
+          { name: 'TC-002 unlock', className: 'auth', outcome: 'passed' },
+          { name: 'Valid login', className: '', outcome: 'passed' },
+        ],
+        testCases
+      );
+
+      expect(summary.updated).toEqual([
+        { testCaseId: 'TC-001', status: 'passed', tests: ['Valid login'] },
+        {
+          testCaseId: 'TC-002',
+          status: 'failed',
+          tests: ['auth.TC-002 lockout', 'auth.TC-002 unlock'],
+        },
+      ]);
+    });
+  });
+
+  describe('importJUnitReport', () => {
+    it('should record results on test cases and commit them once', async () => {
+      const summary = await importJUnitReport(REPORT, { timestamp: 5000 });
+
+      expect(summary.unmatched).toEqual(['auth.smoke']);
+      expect(summary.skipped).toEqual(['auth.TC-003 reset']);
+      expect(testCaseService.save).toHaveBeenCalledTimes(2);
+      expect(testCaseService.save).toHaveBeenCalledWith(
+        expect.objectContaining({
+          id: 'TC-002',
+          status: 'draft',
+          lastResult: 'failed',
+          lastRun: 5000,
+        })
+      );
+      expect(vi.mocked(testCaseService.save).mock.calls[0][0]).not.toHaveProperty(
+        'statusChangedBy'
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['testcases/TC-001.md', 'testcases/TC-002.md'],
+        'Import test results: 1 passed, 1 failed'
+      );
+    });
+
+    it('should not commit when nothing matched', async () => {
+      const summary = await importJUnitReport('<testsuite><testcase name="smoke"/></testsuite>');
+
+      expect(summary.updated).toEqual([]);
+      expect(testCaseService.save).not.toHaveBeenCalled();
+      expect(realGitService.commitFiles).not.toHaveBeenCalled();
+    });
+  });
+});
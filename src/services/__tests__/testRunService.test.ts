@@ -0,0 +1,156 @@
+/**
+ * Test Run Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { testRunService, summarizeTestRun } from '../testRunService';
+import { testCaseService } from '../artifactServices';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import type { TestCase, TestRun } from '../../types';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    writeFile: vi.fn(),
+    listFiles: vi.fn(),
+    getOrCreateDirectory: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFile: vi.fn(), commitFiles: vi.fn() },
+}));
+
+vi.mock('../artifactServices', () => ({
+  testCaseService: { loadAll: vi.fn(), save: vi.fn() },
+}));
+
+vi.mock('../statusWorkflowService', () => ({
+  getCurrentUserName: vi.fn().mockResolvedValue('Sam'),
+}));
+
+const testCase = (id: string, status = 'draft'): TestCase => ({
+  id,
+  title: `Test ${id}`,
+  description: '',
+  status,
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+});
+
+describe('testRunService', () => {
+  let files: Record<string, string>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = {};
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.listFiles).mockImplementation(async (folder) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(testCaseService.loadAll).mockResolvedValue([
+      testCase('TC-001'),
+      testCase('TC-002', 'passed'),
+      testCase('TC-003'),
+    ]);
+    vi.mocked(testCaseService.save).mockImplementation(async (tc) => tc);
+  });
+
+  describe('summarizeTestRun', () => {
+    it('should count outcomes and unexecuted test cases', () => {
+      const run = {
+        testCaseIds: ['TC-001', 'TC-002', 'TC-003'],
+        results: [{ testCaseId: 'TC-001', outcome: 'failed' }],
+      } as TestRun;
+
+      expect(summarizeTestRun(run)).toEqual({
+        passed: 0,
+        failed: 1,
+        blocked: 0,
+        skipped: 0,
+        notRun: 2,
+      });
+    });
+  });
+
+  describe('createRun', () => {
+    it('should number runs and commit the run file', async () => {
+      files['testruns/RUN-004.json'] = '{}';
+
+      const run = await testRunService.createRun({
+        name: ' Release 2.1 ',
+        environment: 'staging',
+        testCaseIds: ['TC-001', 'TC-002', 'TC-001'],
+      });
+
+      expect(run).toMatchObject({
+        id: 'RUN-005',
+        name: 'Release 2.1',
+        testCaseIds: ['TC-001', 'TC-002'],
+        status: 'open',
+        createdBy: 'Sam',
+      });
+      expect(realGitService.commitFile).toHaveBeenCalledWith(
+        'testruns/RUN-005.json',
+        'Create test run RUN-005: Release 2.1'
+      );
+    });
+
+    it('should reject unknown test cases', async () => {
+      await expect(
+        testRunService.createRun({ name: 'Smoke', testCaseIds: ['TC-001', 'TC-099'] })
+      ).rejects.toThrow('Unknown test cases: TC-099');
+    });
+  });
+
+  describe('recordResult and closeRun', () => {
+    it('should record results and apply them to test cases on close', async () => {
+      const run = await testRunService.createRun({
+        name: 'Smoke',
+        testCaseIds: ['TC-001', 'TC-002', 'TC-003'],
+      });
+      await testRunService.recordResult(run.id, 'TC-001', 'passed');
+      await testRunService.recordResult(run.id, 'TC-001', 'failed', 'Crash on submit');
+      await testRunService.recordResult(run.id, 'TC-002', 'passed');
+      await testRunService.recordResult(run.id, 'TC-003', 'skipped');
+
+      const stored = (await testRunService.getRun(run.id))!;
+      expect(stored.results.map((r) => [r.testCaseId, r.outcome, r.notes])).toEqual([
+        ['TC-001', 'failed', 'Crash on submit'],
+        ['TC-002', 'passed', undefined],
+        ['TC-003', 'skipped', undefined],
+      ]);
+
+      const closed = await testRunService.closeRun(run.id);
+
+      expect(closed.status).toBe('closed');
+      expect(testCaseService.save).toHaveBeenCalledTimes(2);
+      expect(testCaseService.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'TC-001', status: 'draft', lastResult: 'failed' })
+      );
+      expect(realGitService.commitFiles).toHaveBeenCalledWith(
+        ['testruns/RUN-001.json', 'testcases/TC-001.md', 'testcases/TC-002.md'],
+        'Close test run RUN-001: 1 passed, 1 failed',
+        'Sam'
+      );
+      await expect(testRunService.recordResult(run.id, 'TC-003', 'passed')).rejects.toThrow(
+        'Test run RUN-001 is closed'
+      );
+    });
+
+    it('should reject results for test cases outside the run', async () => {
+      const run = await testRunService.createRun({ name: 'Smoke', testCaseIds: ['TC-001'] });
+
+      await expect(testRunService.recordResult(run.id, 'TC-002', 'passed')).rejects.toThrow(
+        'TC-002 is not part of test run RUN-001'
+      );
+    });
+  });
+});
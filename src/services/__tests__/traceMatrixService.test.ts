@@ -0,0 +1,143 @@
+/**
+ * Trace Matrix Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { buildTraceMatrix, traceMatrixToTable, generateTraceMatrix } from '../traceMatrixService';
+import { ALL_ARTIFACT_SERVICES } from '../artifactServices';
+import { diskLinkService } from '../diskLinkService';
+import type { Link } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  ALL_ARTIFACT_SERVICES: {
+    requirements: { loadAll: vi.fn() },
+    testcases: { loadAll: vi.fn() },
+    projects: { load: vi.fn() },
+  },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: {
+    getAllLinks: vi.fn(),
+    getLinksForProject: vi.fn(),
+  },
+}));
+
+describe('traceMatrixService', () => {
+  const createLink = (sourceId: string, targetId: string, type: string): Link => ({
+    id: `LINK-${sourceId}-${targetId}`,
+    sourceId,
+    targetId,
+    type: type as Link['type'],
+    projectIds: [],
+    dateCreated: 0,
+    lastModified: 0,
+    revision: '01',
+  });
+
+  const requirements = [
+    { id: 'REQ-010', title: 'Export' },
+    { id: 'REQ-002', title: 'Login' },
+  ];
+  const testCases = [
+    { id: 'TC-001', title: 'Login works' },
+    { id: 'TC-002', title: 'Export works' },
+  ];
+
+  describe('buildTraceMatrix', () => {
+    it('orders rows and columns by ID number', () => {
+      const matrix = buildTraceMatrix('requirements', 'testcases', requirements, testCases, []);
+
+      expect(matrix.rows.map((r) => r.id)).toEqual(['REQ-002', 'REQ-010']);
+      expect(matrix.columns.map((c) => c.id)).toEqual(['TC-001', 'TC-002']);
+      expect(matrix.coveredRows).toBe(0);
+    });
+
+    it('fills cells from links in either direction using the row perspective', () => {
+      const links = [
+        createLink('TC-001', 'REQ-002', 'verifies'),
+        createLink('REQ-010', 'TC-002', 'verified_by'),
+        createLink('REQ-002', 'REQ-010', 'depends_on'), // Not part of this matrix
+      ];
+
+      const matrix = buildTraceMatrix('requirements', 'testcases', requirements, testCases, links);
+
+      expect(matrix.rows[0].cells).toEqual([
+        { targetId: 'TC-001', linkTypes: ['verified_by'] },
+        { targetId: 'TC-002', linkTypes: [] },
+      ]);
+      expect(matrix.rows[1].cells[1].linkTypes).toEqual(['verified_by']);
+      expect(matrix.rows.every((r) => r.linkedCount === 1)).toBe(true);
+      expect(matrix.coveredRows).toBe(2);
+    });
+
+    it('only uses links in the requested categories', () => {
+      const links = [
+        createLink('TC-001', 'REQ-002', 'verifies'),
+        createLink('REQ-010', 'TC-002', 'related_to'),
+      ];
+
+      const matrix = buildTraceMatrix('requirements', 'testcases', requirements, testCases, links, [
+        'verification',
+      ]);
+
+      expect(matrix.coveredRows).toBe(1);
+      expect(matrix.rows[1].linkedCount).toBe(0);
+    });
+
+    it('ignores deleted links', () => {
+      const link = { ...createLink('TC-001', 'REQ-002', 'verifies'), isDeleted: true };
+
+      const matrix = buildTraceMatrix('requirements', 'testcases', requirements, testCases, [link]);
+
+      expect(matrix.coveredRows).toBe(0);
+    });
+  });
+
+  describe('traceMatrixToTable', () => {
+    it('flattens the matrix with link labels', () => {
+      const matrix = buildTraceMatrix('requirements', 'testcases', requirements, testCases, [
+        createLink('TC-001', 'REQ-002', 'verifies'),
+      ]);
+
+      expect(traceMatrixToTable(matrix)).toEqual([
+        ['ID', 'Title', 'TC-001', 'TC-002'],
+        ['REQ-002', 'Login', 'Verified by', ''],
+        ['REQ-010', 'Export', '', ''],
+      ]);
+    });
+  });
+
+  describe('generateTraceMatrix', () => {
+    beforeEach(() => {
+      vi.clearAllMocks();
+      vi.mocked(ALL_ARTIFACT_SERVICES.requirements.loadAll).mockResolvedValue(requirements);
+      vi.mocked(ALL_ARTIFACT_SERVICES.testcases.loadAll).mockResolvedValue(testCases);
+    });
+
+    it('restricts artifacts and links to the given project', async () => {
+      vi.mocked(ALL_ARTIFACT_SERVICES.projects.load).mockResolvedValue({
+        id: 'PROJ-001',
+        requirementIds: ['REQ-002'],
+        testCaseIds: ['TC-001', 'TC-002'],
+      });
+      vi.mocked(diskLinkService.getLinksForProject).mockResolvedValue([
+        createLink('TC-001', 'REQ-002', 'verifies'),
+      ]);
+
+      const matrix = await generateTraceMatrix('requirements', 'testcases', 'PROJ-001');
+
+      expect(diskLinkService.getLinksForProject).toHaveBeenCalledWith('PROJ-001');
+      expect(matrix.rows.map((r) => r.id)).toEqual(['REQ-002']);
+      expect(matrix.coveredRows).toBe(1);
+    });
+
+    it('rejects unknown artifact types', async () => {
+      vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([]);
+
+      await expect(generateTraceMatrix('requirements', 'bogus')).rejects.toThrow(
+        'Unknown artifact type: bogus'
+      );
+    });
+  });
+});
@@ -0,0 +1,122 @@
+/**
+ * Trace Query Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { runTraceQuery, traceArtifacts, validateTraceQuery } from '../traceQueryService';
+import { diskLinkService } from '../diskLinkService';
+import type { Link } from '../../types';
+import type { LinkType } from '../../utils/linkTypes';
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), getLinksForProject: vi.fn() },
+}));
+
+const link = (sourceId: string, type: LinkType, targetId: string) =>
+  ({ id: `${sourceId}>${targetId}`, sourceId, targetId, type }) as Link;
+
+// SYS-004 is broken down into REQ-010 and REQ-011, which test cases verify
+const LINKS = [
+  link('REQ-010', 'derived_from', 'SYS-004'),
+  link('SYS-004', 'derives_to', 'REQ-011'),
+  link('TC-001', 'verifies', 'REQ-010'),
+  link('REQ-011', 'verified_by', 'TC-002'),
+  link('TC-003', 'verifies', 'SYS-004'),
+  link('TC-002', 'related_to', 'TC-009'),
+  link('SYS-004', 'derived_from', 'STK-001'),
+];
+
+describe('traceQueryService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('traceArtifacts', () => {
+    it('should find the test cases that ultimately verify a requirement', () => {
+      const hits = traceArtifacts(LINKS, {
+        startId: 'SYS-004',
+        relations: ['derives_to', 'verified_by'],
+        targetType: 'testcases',
+      });
+
+      expect(hits).toEqual([
+        {
+          artifactId: 'TC-003',
+          artifactType: 'testcases',
+          depth: 1,
+          path: ['SYS-004', 'TC-003'],
+          linkTypes: ['verified_by'],
+        },
+        {
+          artifactId: 'TC-001',
+          artifactType: 'testcases',
+          depth: 2,
+          path: ['SYS-004', 'REQ-010', 'TC-001'],
+          linkTypes: ['derives_to', 'verified_by'],
+        },
+        {
+          artifactId: 'TC-002',
+          artifactType: 'testcases',
+          depth: 2,
+          path: ['SYS-004', 'REQ-011', 'TC-002'],
+          linkTypes: ['derives_to', 'verified_by'],
+        },
+      ]);
+    });
+
+    it('should stop at the given depth', () => {
+      const hits = traceArtifacts(LINKS, {
+        startId: 'SYS-004',
+        relations: ['derives_to', 'verified_by'],
+        maxDepth: 1,
+      });
+
+      expect(hits.map((hit) => hit.artifactId)).toEqual(['REQ-010', 'REQ-011', 'TC-003']);
+    });
+
+    it('should follow every relation when none are given', () => {
+      const hits = traceArtifacts(LINKS, { startId: 'SYS-004' });
+
+      expect(hits.map((hit) => hit.artifactId)).toEqual([
+        'REQ-010',
+        'REQ-011',
+        'STK-001',
+        'TC-003',
+        'TC-001',
+        'TC-002',
+        'TC-009',
+      ]);
+    });
+  });
+
+  describe('validateTraceQuery', () => {
+    it('should reject unknown relations and depths out of range', () => {
+      expect(validateTraceQuery({ startId: 'SYS-004', relations: ['verified_by'] })).toBeNull();
+      expect(validateTraceQuery({ startId: '' })).toBe('A start artifact is required');
+      expect(validateTraceQuery({ startId: 'SYS-004', relations: ['bogus' as LinkType] })).toBe(
+        'Unknown link type: bogus'
+      );
+      expect(validateTraceQuery({ startId: 'SYS-004', maxDepth: 0 })).toBe(
+        'Depth must be between 1 and 10'
+      );
+    });
+  });
+
+  describe('runTraceQuery', () => {
+    it('should only follow links visible in the project', async () => {
+      vi.mocked(diskLinkService.getLinksForProject).mockResolvedValue(LINKS.slice(0, 3));
+
+      const hits = await runTraceQuery({ startId: 'SYS-004', projectId: 'PRJ-001', maxDepth: 5 });
+
+      expect(diskLinkService.getLinksForProject).toHaveBeenCalledWith('PRJ-001');
+      expect(diskLinkService.getAllLinks).not.toHaveBeenCalled();
+      expect(hits.map((hit) => hit.artifactId)).toEqual(['REQ-010', 'REQ-011', 'TC-001']);
+    });
+
+    it('should throw for invalid queries', async () => {
+      await expect(runTraceQuery({ startId: 'SYS-004', maxDepth: 11 })).rejects.toThrow(
+        'Depth must be between 1 and 10'
+      );
+    });
+  });
+});
@@ -0,0 +1,133 @@
+/**
+ * Undo Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { undoService, UNDO_REF } from '../undoService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { readFile: vi.fn(), writeFile: vi.fn(), deleteFile: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: {
+    writeBlob: vi.fn(),
+    writeSnapshotRef: vi.fn(),
+    resolveRef: vi.fn(),
+    readFileAtCommit: vi.fn(),
+  },
+}));
+
+const PATH = 'requirements/REQ-001.md';
+
+describe('undoService', () => {
+  let disk: Record<string, string>;
+  let blobs: Record<string, string>;
+  let refs: Record<string, { oid: string; entries: { path: string; oid: string }[] }>;
+  let stop: () => void;
+
+  // Simulate an edit the way BaseArtifactService.save does
+  const save = async (content: string) => {
+    await undoService.recordBeforeChange(PATH);
+    disk[PATH] = content;
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    disk = { [PATH]: 'v1' };
+    blobs = {};
+    refs = {};
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => disk[path] ?? null);
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      disk[path] = content;
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete disk[path];
+    });
+    vi.mocked(realGitService.writeBlob).mockImplementation(async (content) => {
+      const oid = `blob-${content}`;
+      blobs[oid] = content;
+      return oid;
+    });
+    vi.mocked(realGitService.writeSnapshotRef).mockImplementation(async (ref, entries) => {
+      refs[ref] = { oid: `commit-${Object.keys(refs).length}`, entries };
+      return refs[ref].oid;
+    });
+    vi.mocked(realGitService.resolveRef).mockImplementation(async (ref) => refs[ref]?.oid ?? null);
+    vi.mocked(realGitService.readFileAtCommit).mockImplementation(async (path, commit) => {
+      const ref = Object.values(refs).find((r) => r.oid === commit);
+      const entry = ref?.entries.find((e) => e.path === path);
+      return entry ? blobs[entry.oid] : null;
+    });
+    stop = undoService.startUndoTracking();
+  });
+
+  afterEach(() => stop());
+
+  it('should undo and redo saves', async () => {
+    await save('v2');
+    await save('v3');
+
+    expect(await undoService.undoArtifact(PATH)).toBe(true);
+    expect(disk[PATH]).toBe('v2');
+    expect(await undoService.undoArtifact(PATH)).toBe(true);
+    expect(disk[PATH]).toBe('v1');
+    expect(await undoService.undoArtifact(PATH)).toBe(false);
+
+    expect(await undoService.redoArtifact(PATH)).toBe(true);
+    expect(disk[PATH]).toBe('v2');
+    expect(await undoService.getUndoState(PATH)).toEqual({ canUndo: true, canRedo: true });
+  });
+
+  it('should clear the redo stack on a new save', async () => {
+    await save('v2');
+    await undoService.undoArtifact(PATH);
+    await save('v2b');
+
+    expect(await undoService.redoArtifact(PATH)).toBe(false);
+    expect(await undoService.undoArtifact(PATH)).toBe(true);
+    expect(disk[PATH]).toBe('v1');
+  });
+
+  it('should keep snapshots reachable from the shadow ref', async () => {
+    await save('v2');
+
+    const entries = refs[UNDO_REF].entries.map((e) => e.path);
+    expect(entries).toEqual(['blob-v1', 'stacks.json']);
+  });
+
+  it('should survive a restart by reading the stacks from the ref', async () => {
+    await save('v2');
+    stop();
+    stop = undoService.startUndoTracking();
+
+    expect(await undoService.undoArtifact(PATH)).toBe(true);
+    expect(disk[PATH]).toBe('v1');
+  });
+
+  it('should remove a file that did not exist before the save', async () => {
+    const created = 'requirements/REQ-002.md';
+    await undoService.recordBeforeChange(created);
+    disk[created] = 'new';
+
+    await undoService.undoArtifact(created);
+
+    expect(disk[created]).toBeUndefined();
+  });
+
+  it('should not block saves when snapshotting fails', async () => {
+    vi.mocked(realGitService.writeBlob).mockRejectedValue(new Error('not initialized'));
+
+    await expect(undoService.recordBeforeChange(PATH)).resolves.toBeUndefined();
+  });
+
+  it('should do nothing while tracking is off', async () => {
+    stop();
+
+    await undoService.recordBeforeChange(PATH);
+
+    expect(fileSystemService.readFile).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,175 @@
+/**
+ * Verification Service Tests
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import {
+  combineVerificationStates,
+  computeVerificationRollup,
+  getVerificationState,
+} from '../verificationService';
+import type { Link, Requirement, TestCase } from '../../types';
+
+vi.mock('../artifactServices', () => ({
+  requirementService: { loadAll: vi.fn() },
+  testCaseService: { loadAll: vi.fn() },
+  projectService: { load: vi.fn() },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), getLinksForProject: vi.fn() },
+}));
+
+const requirement = (id: string, extra: Partial<Requirement> = {}) =>
+  ({ id, title: id, lastModified: 0, revision: '01', ...extra }) as Requirement;
+
+const testCase = (
+  id: string,
+  lastResult: TestCase['lastResult'],
+  extra: Partial<TestCase> = {}
+): TestCase => ({
+  id,
+  title: id,
+  description: '',
+  status: 'approved',
+  lastResult,
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+  ...extra,
+});
+
+const link = (id: string, sourceId: string, targetId: string): Link => ({
+  id,
+  sourceId,
+  targetId,
+  type: 'verifies',
+  projectIds: [],
+  dateCreated: 0,
+  lastModified: 0,
+  revision: '01',
+});
+
+describe('verificationService', () => {
+  describe('getVerificationState', () => {
+    it('should derive the state from result counts', () => {
+      expect(getVerificationState(2, 0, 0)).toBe('passed');
+      expect(getVerificationState(2, 1, 0)).toBe('failed');
+      expect(getVerificationState(1, 0, 1)).toBe('partial');
+      expect(getVerificationState(0, 0, 2)).toBe('untested');
+      expect(getVerificationState(0, 0, 0)).toBe('untested');
+    });
+  });
+
+  describe('combineVerificationStates', () => {
+    it('should combine states of a hierarchy', () => {
+      expect(combineVerificationStates(['passed', 'passed'])).toBe('passed');
+      expect(combineVerificationStates(['passed', 'partial', 'failed'])).toBe('failed');
+      expect(combineVerificationStates(['passed', 'untested'])).toBe('partial');
+      expect(combineVerificationStates(['untested', 'untested'])).toBe('untested');
+    });
+  });
+
+  describe('computeVerificationRollup', () => {
+    it('should compute per-requirement states from linked test results', () => {
+      const rollup = computeVerificationRollup(
+        [
+          requirement('REQ-001'),
+          requirement('REQ-002'),
+          requirement('REQ-003'),
+          requirement('REQ-004'),
+          requirement('REQ-005', { isDeleted: true }),
+        ],
+        [
+          testCase('TC-001', 'passed'),
+          testCase('TC-002', 'failed'),
+          testCase('TC-003', 'blocked', { requirementIds: ['REQ-003'] }),
+          testCase('TC-004', 'passed', { requirementIds: ['REQ-003'] }),
+        ],
+        [
+          link('LINK-001', 'TC-001', 'REQ-001'),
+          link('LINK-002', 'REQ-002', 'TC-001'),
+          link('LINK-003', 'TC-002', 'REQ-002'),
+        ]
+      );
+
+      expect(rollup.requirements.map((r) => [r.requirementId, r.state])).toEqual([
+        ['REQ-001', 'passed'],
+        ['REQ-002', 'failed'],
+        ['REQ-003', 'partial'],
+        ['REQ-004', 'untested'],
+      ]);
+      expect(rollup.requirements[2]).toMatchObject({
+        testCaseIds: ['TC-003', 'TC-004'],
+        passed: 1,
+        failed: 0,
+        notRun: 1,
+      });
+      expect(rollup.counts).toEqual({ passed: 1, failed: 1, partial: 1, untested: 1 });
+    });
+
+    it('should roll child states up to parent requirements', () => {
+      const rollup = computeVerificationRollup(
+        [
+          requirement('REQ-001'),
+          requirement('REQ-002', { parentId: 'REQ-001' }),
+          requirement('REQ-003', { parentId: 'REQ-001' }),
+          requirement('REQ-004', { parentId: 'REQ-003' }),
+          requirement('REQ-010'),
+          requirement('REQ-011', { parentId: 'REQ-010' }),
+          requirement('REQ-012', { parentId: 'REQ-010' }),
+        ],
+        [testCase('TC-001', 'passed'), testCase('TC-002', 'passed'), testCase('TC-003', 'failed')],
+        [
+          link('LINK-001', 'TC-001', 'REQ-002'),
+          link('LINK-002', 'TC-001', 'REQ-004'),
+          link('LINK-003', 'TC-002', 'REQ-011'),
+          link('LINK-004', 'TC-003', 'REQ-010'),
+        ]
+      );
+
+      expect(rollup.requirements.map((r) => [r.requirementId, r.state, r.rolledUpState])).toEqual([
+        ['REQ-001', 'untested', 'passed'],
+        ['REQ-002', 'passed', 'passed'],
+        ['REQ-003', 'untested', 'passed'],
+        ['REQ-004', 'passed', 'passed'],
+        ['REQ-010', 'failed', 'failed'],
+        ['REQ-011', 'passed', 'passed'],
+        ['REQ-012', 'untested', 'untested'],
+      ]);
+    });
+
+    it('should report incomplete hierarchies as partial', () => {
+      const rollup = computeVerificationRollup(
+        [requirement('REQ-001'), requirement('REQ-002', { parentId: 'REQ-001' })],
+        [testCase('TC-001', 'passed')],
+        [link('LINK-001', 'TC-001', 'REQ-001')]
+      );
+
+      expect(rollup.requirements[0].rolledUpState).toBe('partial');
+    });
+
+    it('should terminate on parent cycles', () => {
+      const rollup = computeVerificationRollup(
+        [
+          requirement('REQ-001', { parentId: 'REQ-002' }),
+          requirement('REQ-002', { parentId: 'REQ-001' }),
+        ],
+        [testCase('TC-001', 'passed')],
+        [link('LINK-001', 'TC-001', 'REQ-001')]
+      );
+
+      expect(rollup.requirements.map((r) => r.rolledUpState)).toEqual(['passed', 'passed']);
+    });
+
+    it('should ignore deleted test cases', () => {
+      const rollup = computeVerificationRollup(
+        [requirement('REQ-001')],
+        [testCase('TC-001', 'failed', { isDeleted: true })],
+        [link('LINK-001', 'TC-001', 'REQ-001')]
+      );
+
+      expect(rollup.requirements[0]).toMatchObject({ state: 'untested', testCaseIds: [] });
+    });
+  });
+});
@@ -0,0 +1,96 @@
+/**
+ * Volatility Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { volatilityService, computeArtifactVolatility } from '../volatilityService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+import type { CommitInfo } from '../../types';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: { listFiles: vi.fn() },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { getHistory: vi.fn() },
+}));
+
+const DAY = 24 * 60 * 60 * 1000;
+const NOW = Date.UTC(2026, 5, 1);
+
+const commit = (daysAgo: number, author: string): CommitInfo => ({
+  hash: `${author}-${daysAgo}`,
+  message: 'Update',
+  author,
+  timestamp: NOW - daysAgo * DAY,
+});
+
+describe('volatilityService', () => {
+  const history: Record<string, CommitInfo[]> = {
+    'requirements/REQ-001.md': [commit(200, 'Ann')],
+    'requirements/REQ-002.md': [commit(1, 'Ann'), commit(5, 'Bob'), commit(40, 'Ann')],
+    'requirements/REQ-003.md': [commit(2, 'Ann'), commit(3, 'Ann'), commit(4, 'Ann')],
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.useFakeTimers();
+    vi.setSystemTime(NOW);
+    vi.mocked(fileSystemService.listFiles).mockResolvedValue([
+      'REQ-001.md',
+      'REQ-002.md',
+      'REQ-003.md',
+    ]);
+    vi.mocked(realGitService.getHistory).mockImplementation(async (path) => history[path!] ?? []);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  describe('computeArtifactVolatility', () => {
+    it('should count changes and authors within the window only', () => {
+      const result = computeArtifactVolatility(
+        'REQ-002',
+        'requirements/REQ-002.md',
+        history['requirements/REQ-002.md'],
+        NOW - 30 * DAY,
+        NOW
+      );
+
+      expect(result).toMatchObject({ changes: 2, authors: ['Ann', 'Bob'], daysSinceLastChange: 1 });
+    });
+
+    it('should handle artifacts without history', () => {
+      expect(computeArtifactVolatility('REQ-9', 'p', [], 0, NOW)).toMatchObject({
+        changes: 0,
+        lastChangedAt: null,
+        daysSinceLastChange: null,
+      });
+    });
+  });
+
+  describe('getVolatilityReport', () => {
+    it('should rank by changes, then authors', async () => {
+      const report = await volatilityService.getVolatilityReport({ days: 90 });
+
+      expect(report.artifacts.map((a) => [a.artifactId, a.changes])).toEqual([
+        ['REQ-002', 3],
+        ['REQ-003', 3],
+        ['REQ-001', 0],
+      ]);
+      expect(report.artifacts[2].daysSinceLastChange).toBe(200);
+      expect(report.since).toBe(NOW - 90 * DAY);
+    });
+
+    it('should apply the limit and reject invalid windows', async () => {
+      const report = await volatilityService.getVolatilityReport({ days: 30, limit: 1 });
+
+      expect(report.artifacts.map((a) => a.artifactId)).toEqual(['REQ-003']);
+      await expect(volatilityService.getVolatilityReport({ days: 0 })).rejects.toThrow(
+        'Invalid window'
+      );
+    });
+  });
+});
@@ -0,0 +1,130 @@
+/**
+ * Webhook Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { webhookService, signPayload } from '../webhookService';
+import { secretService } from '../secretService';
+import { httpRequest } from '../httpClient';
+import { appSettingsService } from '../appSettingsService';
+import { sha256Hex } from '../../utils/contentHashUtils';
+
+vi.mock('../httpClient', () => ({
+  httpRequest: vi.fn(),
+}));
+
+vi.mock('../secretService', () => ({
+  secretService: { getSecret: vi.fn(), storeSecret: vi.fn(), deleteSecret: vi.fn() },
+}));
+
+vi.mock('../appSettingsService', () => ({
+  appSettingsService: {
+    getAppSettings: vi.fn(),
+    updateAppSettings: vi.fn(),
+    getProjectSettingsKey: () => '/work/project',
+  },
+}));
+
+const hooks = [
+  { id: 'chat', url: 'https://chat.test/hook', events: ['commit', 'approval'], enabled: true },
+  { id: 'ci', url: 'https://ci.test/hook', events: ['baseline'], enabled: true },
+  { id: 'off', url: 'https://off.test/hook', events: ['commit'], enabled: false },
+];
+
+describe('webhookService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(appSettingsService.getAppSettings).mockResolvedValue({
+      webhooks: { '/work/project': hooks, '/work/other': [] },
+    } as never);
+    vi.mocked(secretService.getSecret).mockResolvedValue(null);
+    vi.mocked(httpRequest).mockResolvedValue({ status: 204, headers: {}, body: '' });
+  });
+
+  describe('signPayload', () => {
+    it('should compute the hex HMAC-SHA256', async () => {
+      expect(await signPayload('The quick brown fox jumps over the lazy dog', 'key')).toBe(
+        'f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8'
+      );
+    });
+  });
+
+  describe('getWebhooks', () => {
+    it('should only return the hooks set up for the open project', async () => {
+      vi.mocked(appSettingsService.getAppSettings).mockResolvedValue({
+        webhooks: { '/work/other': hooks },
+      } as never);
+
+      expect(await webhookService.getWebhooks()).toEqual([]);
+      expect(await webhookService.fireWebhooks('commit', {})).toEqual([]);
+      expect(httpRequest).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('saveWebhook', () => {
+    it('should validate the hook and keep the secret out of the settings', async () => {
+      await expect(
+        webhookService.saveWebhook({ id: 'x', url: 'ftp://x', events: ['commit'], enabled: true })
+      ).rejects.toThrow('Webhook URL must be an http(s) URL');
+
+      const hook = { id: 'new', url: 'https://x.test', events: ['commit' as const], enabled: true };
+      await webhookService.saveWebhook(hook, 's3cret');
+
+      const project = (await sha256Hex('/work/project')).slice(0, 16);
+      expect(secretService.storeSecret).toHaveBeenCalledWith(`webhook-${project}-new`, 's3cret');
+      expect(appSettingsService.updateAppSettings).toHaveBeenCalledWith({
+        webhooks: { '/work/project': [...hooks, hook], '/work/other': [] },
+      });
+    });
+  });
+
+  describe('deleteWebhook', () => {
+    it("should delete only this project's secret of the hook", async () => {
+      await webhookService.deleteWebhook('chat');
+
+      const project = (await sha256Hex('/work/project')).slice(0, 16);
+      expect(secretService.deleteSecret).toHaveBeenCalledWith(`webhook-${project}-chat`);
+      expect(appSettingsService.updateAppSettings).toHaveBeenCalledWith({
+        webhooks: { '/work/project': hooks.slice(1), '/work/other': [] },
+      });
+    });
+  });
+
+  describe('fireWebhooks', () => {
+    it('should post to enabled hooks subscribed to the event, signed with the secret', async () => {
+      vi.mocked(secretService.getSecret).mockResolvedValue('key');
+
+      const deliveries = await webhookService.fireWebhooks('commit', { oid: 'abc' });
+
+      expect(deliveries).toEqual([{ hookId: 'chat', status: 204 }]);
+      const request = vi.mocked(httpRequest).mock.calls[0][0];
+      expect(request.url).toBe('https://chat.test/hook');
+      expect(JSON.parse(request.body!)).toMatchObject({ event: 'commit', data: { oid: 'abc' } });
+      expect(request.headers!['X-Tracyfy-Signature-256']).toBe(
+        `sha256=${await signPayload(request.body!, 'key')}`
+      );
+    });
+
+    it('should report delivery failures without throwing', async () => {
+      vi.mocked(httpRequest).mockRejectedValue(new Error('connection refused'));
+
+      expect(await webhookService.fireWebhooks('baseline', {})).toEqual([
+        { hookId: 'ci', error: 'connection refused' },
+      ]);
+    });
+  });
+
+  describe('startWebhooks', () => {
+    it('should fire webhooks for window events until stopped', async () => {
+      const stop = webhookService.startWebhooks();
+
+      window.dispatchEvent(new CustomEvent('artifact-approved', { detail: { artifactId: 'R' } }));
+      await vi.waitFor(() => expect(httpRequest).toHaveBeenCalledTimes(1));
+
+      stop();
+      window.dispatchEvent(new CustomEvent('artifact-approved', { detail: {} }));
+      await new Promise((resolve) => setTimeout(resolve, 0));
+      expect(httpRequest).toHaveBeenCalledTimes(1);
+    });
+  });
+});
@@ -0,0 +1,124 @@
+/**
+ * Wiki Link Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { resolveWikiLinks, syncWikiLinks } from '../wikiLinkService';
+import { baseDiskService } from '../baseDiskService';
+import { diskLinkService } from '../diskLinkService';
+import { runJournaled } from '../writeJournalService';
+
+vi.mock('../baseDiskService', () => ({
+  baseDiskService: { listFiles: vi.fn(), readTextFile: vi.fn() },
+}));
+
+vi.mock('../diskLinkService', () => ({
+  diskLinkService: { getAllLinks: vi.fn(), save: vi.fn(), delete: vi.fn() },
+}));
+
+vi.mock('../idService', () => ({
+  idService: {
+    getNextIds: vi.fn((_type: string, count: number) =>
+      Promise.resolve(['LINK-050', 'LINK-051', 'LINK-052'].slice(0, count))
+    ),
+    getCounterPath: vi.fn(() => 'counters/links.md'),
+  },
+}));
+
+vi.mock('../writeJournalService', () => ({
+  runJournaled: vi.fn((_operation, apply) => apply({ record: vi.fn() })),
+}));
+
+const files: Record<string, string> = {
+  'requirements/REQ-001.md': '---\nid: REQ-001\n---\n\nRefines [[refines:REQ-002]] and [[UC-001]].',
+  'requirements/REQ-002.md': '---\nid: REQ-002\n---\n\nSee [[derived_from:REQ-001]], [[REQ-404]].',
+  'requirements/REQ-003.md': '---\nid: REQ-003\nisDeleted: true\n---\n\n[[UC-001]]',
+  'usecases/UC-001.md': '---\nid: UC-001\n---\n\nCovers [[REQ-001|login]].',
+};
+
+const link = (id: string, sourceId: string, targetId: string, type: string, origin?: string) => ({
+  id,
+  sourceId,
+  targetId,
+  type,
+  projectIds: [],
+  ...(origin && { origin }),
+  dateCreated: 1,
+  lastModified: 1,
+  revision: '01',
+});
+
+describe('wikiLinkService', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(baseDiskService.listFiles).mockImplementation(async (folder: string) =>
+      Object.keys(files)
+        .filter((path) => path.startsWith(`${folder}/`))
+        .map((path) => path.slice(folder.length + 1))
+    );
+    vi.mocked(baseDiskService.readTextFile).mockImplementation(async (path: string) => files[path]);
+    vi.mocked(diskLinkService.getAllLinks).mockResolvedValue([
+      link('LINK-001', 'UC-001', 'REQ-001', 'related_to'),
+      link('LINK-002', 'REQ-001', 'TC-009', 'related_to', 'wiki'),
+      link('LINK-003', 'REQ-001', 'REQ-002', 'refines', 'wiki'),
+    ]);
+  });
+
+  describe('resolveWikiLinks', () => {
+    it('skips unknown types, unknown IDs and self references', () => {
+      const markdown = '[[REQ-002]] [[verifies:TC-001]] [[linked:REQ-002]] [[REQ-009]] [[REQ-001]]';
+
+      const { specs, skipped } = resolveWikiLinks(
+        'REQ-001',
+        markdown,
+        new Set(['REQ-001', 'REQ-002', 'TC-001'])
+      );
+
+      expect(specs).toEqual([
+        { sourceId: 'REQ-001', targetId: 'REQ-002', type: 'related_to', line: 1 },
+        { sourceId: 'REQ-001', targetId: 'TC-001', type: 'verifies', line: 1 },
+      ]);
+      expect(skipped.map((entry) => `${entry.targetId}:${entry.reason}`)).toEqual([
+        'REQ-002:unknown-type',
+        'REQ-009:unknown-id',
+        'REQ-001:self',
+      ]);
+    });
+  });
+
+  describe('syncWikiLinks', () => {
+    it('creates missing links and removes stale wiki links in one commit', async () => {
+      const result = await syncWikiLinks();
+
+      expect(result.created).toEqual([
+        { sourceId: 'REQ-001', targetId: 'UC-001', type: 'related_to', line: 5 },
+      ]);
+      expect(result.removed.map((removed) => removed.id)).toEqual(['LINK-002']);
+      expect(result.skipped).toEqual([
+        { sourceId: 'REQ-002', targetId: 'REQ-404', line: 5, reason: 'unknown-id' },
+        {
+          sourceId: 'REQ-002',
+          targetId: 'REQ-001',
+          linkType: 'derived_from',
+          line: 5,
+          reason: 'cycle',
+        },
+      ]);
+      expect(diskLinkService.save).toHaveBeenCalledTimes(1);
+      expect(diskLinkService.save).toHaveBeenCalledWith(
+        expect.objectContaining({ id: 'LINK-050', sourceId: 'REQ-001', origin: 'wiki' })
+      );
+      expect(diskLinkService.delete).toHaveBeenCalledWith('LINK-002');
+      expect(vi.mocked(runJournaled).mock.calls[0][2]).toBe('Sync wiki links (1 added, 1 removed)');
+    });
+
+    it('writes nothing on a dry run', async () => {
+      const result = await syncWikiLinks(true);
+
+      expect(result.applied).toBe(false);
+      expect(result.created).toHaveLength(1);
+      expect(runJournaled).not.toHaveBeenCalled();
+      expect(diskLinkService.save).not.toHaveBeenCalled();
+    });
+  });
+});
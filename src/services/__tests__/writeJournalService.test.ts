@@ -0,0 +1,140 @@
+/**
+ * Write Journal Service Tests
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { writeJournalService, JOURNAL_PATH } from '../writeJournalService';
+import { fileSystemService } from '../fileSystemService';
+import { realGitService } from '../realGitService';
+
+vi.mock('../fileSystemService', () => ({
+  fileSystemService: {
+    readFile: vi.fn(),
+    readFileBinary: vi.fn(),
+    writeFile: vi.fn(),
+    writeFileBinary: vi.fn(),
+    deleteFile: vi.fn(),
+  },
+}));
+
+vi.mock('../realGitService', () => ({
+  realGitService: { commitFiles: vi.fn() },
+}));
+
+describe('writeJournalService', () => {
+  let files: Record<string, string>;
+
+  // Write two files under the journal, stopping after the first when `crash` is set
+  const twoFileWrite = (crash?: () => Promise<void>) =>
+    writeJournalService.runJournaled(
+      'Batch write',
+      async (journal) => {
+        await journal.record('requirements/REQ-001.md');
+        files['requirements/REQ-001.md'] = 'new 1';
+        if (crash) await crash();
+        await journal.record('requirements/REQ-002.md');
+        files['requirements/REQ-002.md'] = 'new 2';
+      },
+      'Bulk edit'
+    );
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    files = { 'requirements/REQ-001.md': 'old 1' };
+    vi.mocked(fileSystemService.readFile).mockImplementation(async (path) => files[path] ?? null);
+    vi.mocked(fileSystemService.readFileBinary).mockImplementation(async (path) =>
+      path in files ? new TextEncoder().encode(files[path]) : null
+    );
+    vi.mocked(fileSystemService.writeFile).mockImplementation(async (path, content) => {
+      files[path] = content;
+    });
+    vi.mocked(fileSystemService.writeFileBinary).mockImplementation(async (path, data) => {
+      files[path] = new TextDecoder().decode(data);
+    });
+    vi.mocked(fileSystemService.deleteFile).mockImplementation(async (path) => {
+      delete files[path];
+    });
+  });
+
+  it('should commit the recorded files and remove the journal', async () => {
+    await twoFileWrite();
+
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      ['requirements/REQ-001.md', 'requirements/REQ-002.md'],
+      'Bulk edit',
+      undefined,
+      expect.objectContaining({ record: expect.any(Function) })
+    );
+    expect(files).toEqual({
+      'requirements/REQ-001.md': 'new 1',
+      'requirements/REQ-002.md': 'new 2',
+    });
+  });
+
+  it('should restore recorded files when the operation fails', async () => {
+    await expect(
+      twoFileWrite(async () => {
+        throw new Error('Disk full');
+      })
+    ).rejects.toThrow('Disk full');
+
+    expect(files).toEqual({ 'requirements/REQ-001.md': 'old 1' });
+    expect(realGitService.commitFiles).not.toHaveBeenCalled();
+  });
+
+  it('should restore recorded files when the commit fails', async () => {
+    vi.mocked(realGitService.commitFiles).mockRejectedValueOnce(new Error('Secrets detected'));
+
+    await expect(twoFileWrite()).rejects.toThrow('Secrets detected');
+
+    expect(files).toEqual({ 'requirements/REQ-001.md': 'old 1' });
+  });
+
+  it('should roll back an operation interrupted while applying', async () => {
+    // Simulate a crash: the journal is left behind and the operation never finishes
+    let snapshot: Record<string, string> = {};
+    await expect(
+      twoFileWrite(async () => {
+        snapshot = { ...files };
+        throw new Error('crash');
+      })
+    ).rejects.toThrow('crash');
+    files = snapshot;
+
+    const recovery = await writeJournalService.recoverWriteJournal();
+
+    expect(recovery).toEqual({
+      operation: 'Batch write',
+      action: 'rolled-back',
+      paths: ['requirements/REQ-001.md'],
+    });
+    expect(files).toEqual({ 'requirements/REQ-001.md': 'old 1' });
+  });
+
+  it('should roll an applied operation forward by committing it', async () => {
+    files[JOURNAL_PATH] = JSON.stringify({
+      operation: 'Import markdown',
+      startedAt: 1,
+      state: 'applied',
+      commitMessage: 'Import 1 markdown files',
+      entries: [{ path: 'requirements/REQ-001.md', original: null }],
+    });
+
+    const recovery = await writeJournalService.recoverWriteJournal();
+
+    expect(recovery?.action).toBe('rolled-forward');
+    expect(realGitService.commitFiles).toHaveBeenCalledWith(
+      ['requirements/REQ-001.md'],
+      'Import 1 markdown files'
+    );
+    expect(files).toEqual({ 'requirements/REQ-001.md': 'old 1' });
+    expect(await writeJournalService.recoverWriteJournal()).toBeNull();
+  });
+
+  it('should not start while an interrupted operation is unrecovered', async () => {
+    files[JOURNAL_PATH] = JSON.stringify({ operation: 'x', state: 'applying', entries: [] });
+
+    await expect(twoFileWrite()).rejects.toThrow('not been recovered');
+    expect(files['requirements/REQ-001.md']).toBe('old 1');
+  });
+});
@@ -0,0 +1,85 @@
+/**
+ * API Info Service
+ *
+ * Asks the desktop app's main process once which command API version and
+ * capabilities it has, so features can be turned on only where the backend
+ * supports them (see app:getApiInfo in electron/main.js). Backends from before
+ * the command existed report API version 1 with no capabilities listed. The
+ * browser build has no backend and gets null.
+ */
+
+import { isElectronEnv, type ApiInfo } from './git/types';
+import { debug } from '../utils/debug';
+
+// Command API version this renderer is written against
+export const CLIENT_API_VERSION = 2;
+
+const LEGACY_API_INFO: ApiInfo = {
+  appVersion: 'unknown',
+  apiVersion: 1,
+  minSupportedApiVersion: 1,
+  capabilities: [],
+  features: {},
+  deprecated: [],
+};
+
+let apiInfo: Promise<ApiInfo | null> | null = null;
+
+async function loadApiInfo(): Promise<ApiInfo | null> {
+  if (!isElectronEnv()) return null;
+  const api = window.electronAPI!.app;
+  if (!api) return LEGACY_API_INFO;
+  const result = await api.getApiInfo();
+  if (result.error) throw new Error(result.error);
+  return { ...LEGACY_API_INFO, ...result };
+}
+
+/**
+ * Version and capabilities of the backend, asked once per session; null in
+ * the browser
+ */
+export function getApiInfo(): Promise<ApiInfo | null> {
+  apiInfo ??= loadApiInfo().catch((err) => {
+    debug.warn('[ApiInfoService] Could not get API info:', err);
+    apiInfo = null;
+    return LEGACY_API_INFO;
+  });
+  return apiInfo;
+}
+
+/**
+ * Whether the backend lists the capability, e.g. "fs:batch"
+ */
+export async function hasCapability(capability: string): Promise<boolean> {
+  const info = await getApiInfo();
+  return !!info?.capabilities.includes(capability);
+}
+
+/**
+ * Problem with running this renderer against the backend, or null when they
+ * are compatible
+ */
+export function checkApiCompatibility(info: ApiInfo): string | null {
+  const versions = `desktop app API ${info.apiVersion}, interface API ${CLIENT_API_VERSION}`;
+  if (info.apiVersion < CLIENT_API_VERSION) {
+    return `The desktop app is older than this interface (${versions}); some features are off.`;
+  }
+  if (info.minSupportedApiVersion > CLIENT_API_VERSION) {
+    return `The desktop app no longer supports this interface (${versions}).`;
+  }
+  return null;
+}
+
+/**
+ * Forget the cached answer, e.g. after the backend was restarted
+ */
+export function resetApiInfo(): void {
+  apiInfo = null;
+}
+
+export const apiInfoService = {
+  getApiInfo,
+  hasCapability,
+  checkApiCompatibility,
+  resetApiInfo,
+};
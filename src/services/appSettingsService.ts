@@ -0,0 +1,242 @@
+/**
+ * App Settings Service
+ *
+ * App-level preferences that are not part of any project: default author,
+ * default project location, auto-sync, polling intervals, webhooks, issue
+ * tracker servers and trusted signing keys. The desktop app persists them in settings.json in
+ * its config directory through the main process; the browser build falls
+ * back to localStorage. Changes are announced with an app-settings-changed
+ * event. Diagram tools and trusted signing keys are not part of a normal
+ * update: in the desktop app the main process sets them from a file dialog
+ * or after the user confirms the key.
+ */
+
+import type { AppSettings, AppSettingsUpdate, DiagramSettings, IntegrationUrls } from '../types';
+import { isElectronEnv } from './git/types';
+import { fileSystemService } from './fileSystemService';
+import { getPublicKeyId } from '../utils/minisignUtils';
+
+const STORAGE_KEY = 'tracyfy-app-settings';
+const LEGACY_AUTO_SYNC_KEY = 'tracyfy-auto-sync';
+const MIN_POLL_INTERVAL = 5000;
+const PROFILE_ID_PATTERN = /^[a-z0-9][a-z0-9-]*$/;
+const MIN_API_PORT = 1024;
+
+export const DEFAULT_APP_SETTINGS: AppSettings = {
+  defaultAuthor: '',
+  defaultProjectLocation: '',
+  autoSync: false,
+  notifyExternalChanges: false,
+  blockSecretCommits: true,
+  pollIntervals: { syncStatus: 30000, remoteStatus: 120000 },
+  authorProfiles: [],
+  activeAuthorProfiles: {},
+  webhooks: {},
+  integrationUrls: {},
+  diagrams: { mermaidCli: 'mmdc', plantumlJar: '', javaPath: 'java' },
+  trustedSigningKeys: [],
+  localApi: { enabled: false, port: 7411 },
+  readOnlyProjects: [],
+};
+
+// Settings as saved, including those a normal update cannot change
+type SavedSettings = AppSettingsUpdate &
+  Partial<Pick<AppSettings, 'diagrams' | 'trustedSigningKeys' | 'readOnlyProjects'>>;
+
+function withDefaults(settings: SavedSettings | null | undefined): AppSettings {
+  return {
+    ...DEFAULT_APP_SETTINGS,
+    ...settings,
+    pollIntervals: { ...DEFAULT_APP_SETTINGS.pollIntervals, ...settings?.pollIntervals },
+    diagrams: { ...DEFAULT_APP_SETTINGS.diagrams, ...settings?.diagrams },
+    localApi: { ...DEFAULT_APP_SETTINGS.localApi, ...settings?.localApi },
+  };
+}
+
+/**
+ * Problems with a settings update; empty when it can be saved
+ */
+export function validateAppSettings(patch: AppSettingsUpdate): string[] {
+  const errors: string[] = [];
+  for (const [name, interval] of Object.entries(patch.pollIntervals ?? {})) {
+    if (interval !== undefined && (!Number.isInteger(interval) || interval < MIN_POLL_INTERVAL)) {
+      errors.push(`Poll interval ${name} must be at least ${MIN_POLL_INTERVAL} ms`);
+    }
+  }
+  const port = patch.localApi?.port;
+  if (port !== undefined && (!Number.isInteger(port) || port < MIN_API_PORT || port > 65535)) {
+    errors.push(`Local API port must be between ${MIN_API_PORT} and 65535`);
+  }
+  if (patch.defaultAuthor !== undefined && patch.defaultAuthor.length > 100) {
+    errors.push('Default author must be at most 100 characters');
+  }
+  const profileIds = new Set<string>();
+  for (const profile of patch.authorProfiles ?? []) {
+    if (!PROFILE_ID_PATTERN.test(profile.id)) {
+      errors.push(`Invalid author profile ID: ${profile.id}`);
+    } else if (profileIds.has(profile.id)) {
+      errors.push(`Duplicate author profile ID: ${profile.id}`);
+    }
+    profileIds.add(profile.id);
+    if (!profile.name.trim()) errors.push(`Author profile ${profile.id} has no name`);
+    if (!/^[^\s@]+@[^\s@]+$/.test(profile.email)) {
+      errors.push(`Author profile ${profile.id} has an invalid email address`);
+    }
+  }
+  return errors;
+}
+
+function readLocal(): SavedSettings | null {
+  try {
+    const saved = localStorage.getItem(STORAGE_KEY);
+    return saved ? (JSON.parse(saved) as SavedSettings) : null;
+  } catch {
+    return null;
+  }
+}
+
+async function load(): Promise<AppSettings> {
+  if (!isElectronEnv()) return withDefaults(readLocal());
+  const result = await window.electronAPI!.settings.get();
+  if (result.error) throw new Error(result.error);
+  return withDefaults(result.settings);
+}
+
+function saveLocal(patch: SavedSettings): AppSettings {
+  const current = withDefaults(readLocal());
+  const settings = withDefaults({
+    ...current,
+    ...patch,
+    pollIntervals: { ...current.pollIntervals, ...patch.pollIntervals },
+    diagrams: { ...current.diagrams, ...patch.diagrams },
+  });
+  localStorage.setItem(STORAGE_KEY, JSON.stringify(settings));
+  return settings;
+}
+
+async function save(patch: AppSettingsUpdate): Promise<AppSettings> {
+  if (!isElectronEnv()) return saveLocal(patch);
+  const result = await window.electronAPI!.settings.update(patch);
+  if (result.error) throw new Error(result.error);
+  return withDefaults(result.settings);
+}
+
+function announce(settings: AppSettings): AppSettings {
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent('app-settings-changed', { detail: settings }));
+  }
+  return settings;
+}
+
+/**
+ * Current app settings, with defaults for anything not set
+ */
+export async function getAppSettings(): Promise<AppSettings> {
+  let settings = await load();
+  // The auto-sync toggle used to be kept in localStorage on its own
+  const legacyAutoSync =
+    typeof localStorage !== 'undefined' ? localStorage.getItem(LEGACY_AUTO_SYNC_KEY) : null;
+  if (legacyAutoSync !== null) {
+    settings = await save({ autoSync: legacyAutoSync === 'true' });
+    localStorage.removeItem(LEGACY_AUTO_SYNC_KEY);
+  }
+  return settings;
+}
+
+/**
+ * Change some settings and return the result; nested poll intervals are merged
+ */
+export async function updateAppSettings(patch: AppSettingsUpdate): Promise<AppSettings> {
+  const errors = validateAppSettings(patch);
+  if (errors.length > 0) {
+    throw new Error(`Invalid settings: ${errors.join('; ')}`);
+  }
+  return announce(await save(patch));
+}
+
+/**
+ * Let the user pick the program or file for a diagram tool in a dialog of the
+ * desktop app, or reset it to its default. Null when the dialog is canceled.
+ */
+export async function chooseDiagramTool(
+  tool: keyof DiagramSettings,
+  reset = false
+): Promise<AppSettings | null> {
+  if (!isElectronEnv()) {
+    throw new Error('Diagram tools can only be set in the desktop app');
+  }
+  const result = await window.electronAPI!.settings.chooseDiagramTool(tool, reset);
+  if (result.error) throw new Error(result.error);
+  return result.canceled ? null : announce(withDefaults(result.settings));
+}
+
+/**
+ * Pin a minisign public key, so signatures made with it are trusted. The
+ * desktop app asks the user to confirm the key ID first; false when they
+ * decline.
+ */
+export async function trustSigningKey(publicKey: string): Promise<boolean> {
+  let keyId: string;
+  try {
+    keyId = getPublicKeyId(publicKey);
+  } catch {
+    throw new Error(`Invalid trusted signing key: ${publicKey.trim().split('\n')[0]}`);
+  }
+  if (!isElectronEnv()) {
+    const { trustedSigningKeys } = withDefaults(readLocal());
+    if (!trustedSigningKeys.some((key) => getPublicKeyId(key) === keyId)) {
+      announce(saveLocal({ trustedSigningKeys: [...trustedSigningKeys, publicKey.trim()] }));
+    }
+    return true;
+  }
+  const result = await window.electronAPI!.settings.trustSigningKey(publicKey);
+  if (result.error) throw new Error(result.error);
+  if (result.canceled) return false;
+  announce(withDefaults(result.settings));
+  return true;
+}
+
+/**
+ * Key of the open project in per-project settings: its folder (the folder
+ * name in the browser build)
+ */
+export function getProjectSettingsKey(): string {
+  const key = fileSystemService.getRootPath() ?? fileSystemService.getDirectoryName();
+  if (!key) {
+    throw new Error('No project is open');
+  }
+  return key;
+}
+
+/**
+ * Issue tracker servers set up on this machine for the open project
+ */
+export async function getIntegrationUrls(): Promise<IntegrationUrls> {
+  const { integrationUrls } = await getAppSettings();
+  return integrationUrls[getProjectSettingsKey()] ?? {};
+}
+
+/**
+ * Set the server of one issue tracker for the open project
+ */
+export async function setIntegrationUrl(
+  integration: keyof IntegrationUrls,
+  url: string
+): Promise<void> {
+  const { integrationUrls } = await getAppSettings();
+  const key = getProjectSettingsKey();
+  await updateAppSettings({
+    integrationUrls: { ...integrationUrls, [key]: { ...integrationUrls[key], [integration]: url } },
+  });
+}
+
+export const appSettingsService = {
+  getAppSettings,
+  updateAppSettings,
+  chooseDiagramTool,
+  trustSigningKey,
+  validateAppSettings,
+  getProjectSettingsKey,
+  getIntegrationUrls,
+  setIntegrationUrl,
+};
@@ -0,0 +1,219 @@
+/**
+ * Approval Service
+ *
+ * Review sign-off for artifacts. Each approval appends a record (reviewer,
+ * timestamp, content hash, optional signature) to approvals/<artifactId>.json
+ * and marks the artifact approved; both files are committed together. The
+ * reviewer and signing key default to the active author profile.
+ * Comparing the stored hash with the current content reveals edits made
+ * after approval.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { ApprovalRecord } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { computeContentHash } from '../utils/contentHashUtils';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { getActiveAuthorProfile, getProfileSigningKey } from './authorProfileService';
+import { checkPermission } from './permissionService';
+import {
+  getAllowedTransitions,
+  getCurrentUserName,
+  getStatusWorkflow,
+  WORKFLOW_TYPES,
+} from './statusWorkflowService';
+import { realGitService } from './realGitService';
+import { signMinisign } from '../utils/minisignUtils';
+import { debug } from '../utils/debug';
+
+const APPROVALS_DIR = 'approvals';
+const APPROVED_STATUS = 'approved';
+
+export interface ApproveOptions {
+  comment?: string;
+  sign?: (payload: string) => Promise<string>; // Defaults to the active author profile's key
+}
+
+export interface ApprovalCheck {
+  artifactId: string;
+  approval: ApprovalRecord; // Latest approval
+  currentHash: string;
+  editedSinceApproval: boolean;
+}
+
+interface ApprovableArtifact {
+  id: string;
+  status?: string;
+  statusChangedBy?: string;
+  statusChangedAt?: number;
+  approvalDate?: number;
+  lastModified: number;
+}
+
+/**
+ * The string a signature is computed over
+ */
+export function getApprovalPayload(
+  record: Pick<ApprovalRecord, 'artifactId' | 'reviewer' | 'timestamp' | 'contentHash'>
+): string {
+  return [record.artifactId, record.reviewer, record.timestamp, record.contentHash].join('\n');
+}
+
+function resolveArtifactPath(path: string): { id: string; typeKey: string; filePath: string } {
+  const id = (path.split('/').pop() || '').replace(/\.md$/, '');
+  const typeKey = getTypeFromId(id);
+  const filePath = ARTIFACT_CONFIG[typeKey] && `${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`;
+  if (!WORKFLOW_TYPES.includes(typeKey) || path.replace(/^\/+/, '') !== filePath) {
+    throw new Error(`Not an approvable artifact path: ${path}`);
+  }
+  return { id, typeKey, filePath };
+}
+
+class ApprovalService extends BaseDiskService {
+  private getApprovalsPath(artifactId: string): string {
+    return `${APPROVALS_DIR}/${artifactId}.json`;
+  }
+
+  private async hashArtifact(typeKey: string, artifact: ApprovableArtifact): Promise<string> {
+    return computeContentHash(ALL_ARTIFACT_SERVICES[typeKey].serialize(artifact));
+  }
+
+  /**
+   * Signs with the key of the active author profile, as a minisign signature
+   * whose trusted comment names the artifact and reviewer; null without a key
+   */
+  private async getProfileSigner(
+    record: ApprovalRecord
+  ): Promise<((payload: string) => Promise<string>) | null> {
+    const profile = await getActiveAuthorProfile();
+    const key = profile && (await getProfileSigningKey(profile));
+    if (!key) return null;
+    const comment = [
+      `timestamp:${Math.floor(record.timestamp / 1000)}`,
+      `artifact:${record.artifactId}`,
+      `reviewer:${record.reviewer.replace(/\s+/g, ' ')}`,
+    ].join('\t');
+    return (payload) => signMinisign(payload, key, comment);
+  }
+
+  /**
+   * All approval records of an artifact, oldest first
+   */
+  async listApprovals(path: string): Promise<ApprovalRecord[]> {
+    const { id } = resolveArtifactPath(path);
+    const records = await this.readJsonFile<ApprovalRecord[]>(this.getApprovalsPath(id), []);
+    return Array.isArray(records) ? records : [];
+  }
+
+  /**
+   * Approve an artifact: append a signed record and mark it approved.
+   * Requires the approver role where roles are assigned. Artifacts that are
+   * not approved yet must be allowed to move to "approved" by the status
+   * workflow.
+   */
+  async approveArtifact(
+    path: string,
+    reviewer: string,
+    options: ApproveOptions = {}
+  ): Promise<ApprovalRecord> {
+    const { id, typeKey, filePath } = resolveArtifactPath(path);
+    const service = ALL_ARTIFACT_SERVICES[typeKey];
+    const artifact: ApprovableArtifact | null = await service.load(id);
+    if (!artifact) {
+      throw new Error(`Artifact not found: ${path}`);
+    }
+
+    await checkPermission('approve');
+    if (artifact.status !== APPROVED_STATUS) {
+      const workflow = await getStatusWorkflow();
+      const from = artifact.status || workflow.initialStatus;
+      if (!getAllowedTransitions(workflow, from).includes(APPROVED_STATUS)) {
+        throw new Error(`Cannot approve ${id}: transition from "${from}" is not allowed`);
+      }
+    }
+
+    const timestamp = Date.now();
+    const contentHash = await this.hashArtifact(typeKey, artifact);
+    const record: ApprovalRecord = {
+      artifactId: id,
+      reviewer: reviewer.trim() || (await getCurrentUserName()),
+      comment: options.comment || undefined,
+      timestamp,
+      contentHash,
+    };
+    const sign = options.sign ?? (await this.getProfileSigner(record));
+    if (sign) {
+      record.signature = await sign(getApprovalPayload(record));
+    }
+
+    const approvalsPath = this.getApprovalsPath(id);
+    const records = [...(await this.listApprovals(filePath)), record];
+    await this.writeJsonFile(approvalsPath, records);
+
+    await service.save({
+      ...artifact,
+      status: APPROVED_STATUS,
+      statusChangedBy: record.reviewer,
+      statusChangedAt: timestamp,
+      approvalDate: typeKey === 'requirements' ? timestamp : artifact.approvalDate,
+      lastModified: timestamp,
+    });
+
+    await realGitService.commitFiles([filePath, approvalsPath], `Approve ${id}`, record.reviewer);
+    debug.log(`[ApprovalService] ${id} approved by ${record.reviewer}`);
+    if (typeof window !== 'undefined') {
+      window.dispatchEvent(new CustomEvent('artifact-approved', { detail: record }));
+    }
+    return record;
+  }
+
+  /**
+   * Compare the latest approval of an artifact with its current content.
+   * Returns null when the artifact has never been approved.
+   */
+  async checkApproval(path: string): Promise<ApprovalCheck | null> {
+    const { id, typeKey } = resolveArtifactPath(path);
+    const approvals = await this.listApprovals(path);
+    if (approvals.length === 0) return null;
+
+    const artifact: ApprovableArtifact | null = await ALL_ARTIFACT_SERVICES[typeKey].load(id);
+    if (!artifact) {
+      throw new Error(`Artifact not found: ${path}`);
+    }
+
+    const approval = approvals[approvals.length - 1];
+    const currentHash = await this.hashArtifact(typeKey, artifact);
+    return {
+      artifactId: id,
+      approval,
+      currentHash,
+      editedSinceApproval: currentHash !== approval.contentHash,
+    };
+  }
+
+  /**
+   * All approved artifacts whose content changed after their latest approval
+   */
+  async findEditedAfterApproval(): Promise<ApprovalCheck[]> {
+    const files = await this.listFiles(APPROVALS_DIR);
+    const edited: ApprovalCheck[] = [];
+
+    for (const file of files) {
+      if (!file.endsWith('.json')) continue;
+      const id = file.slice(0, -'.json'.length);
+      const typeKey = getTypeFromId(id);
+      if (!WORKFLOW_TYPES.includes(typeKey)) continue;
+
+      try {
+        const check = await this.checkApproval(`${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`);
+        if (check?.editedSinceApproval) edited.push(check);
+      } catch (err) {
+        debug.log(`[ApprovalService] Skipping approvals of ${id}:`, err);
+      }
+    }
+
+    return edited;
+  }
+}
+
+export const approvalService = new ApprovalService();
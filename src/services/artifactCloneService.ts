@@ -0,0 +1,185 @@
+/**
+ * Artifact Clone Service
+ *
+ * Duplicates an artifact file under a fresh ID as a starting point for a
+ * similar one. The copy keeps the text and classification, starts over in
+ * the type's initial status with a new creation date, and joins the
+ * projects of the original. Its links are either dropped or copied with the
+ * copy in place of the original. The ID is taken from the counter inside
+ * the write journal, so the counter, the new file and any links are
+ * committed together.
+ */
+
+import type { ArtifactLink, Link, Project } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { LINKABLE_TYPES } from './danglingLinkService';
+import { diskLinkService } from './diskLinkService';
+import { idService } from './idService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { runJournaled } from './writeJournalService';
+import { debug } from '../utils/debug';
+
+// Status a copy starts in; types without a status keep none
+export const INITIAL_STATUS: Record<string, string> = {
+  requirements: 'draft',
+  usecases: 'draft',
+  testcases: 'draft',
+  risks: 'identified',
+};
+
+export interface CloneOptions {
+  copyLinks?: boolean; // Copy the original's links to the clone; dropped by default
+  title?: string; // "<original title> (copy)" when omitted
+}
+
+export interface ArtifactCloneResult {
+  originalId: string;
+  cloneId: string;
+  path: string;
+  linkIds: string[]; // Links created for the clone
+  projectIds: string[]; // Projects the clone was added to
+}
+
+interface CloneableArtifact {
+  id: string;
+  title?: string;
+  linkedArtifacts?: ArtifactLink[];
+  isDeleted?: boolean;
+  [field: string]: unknown;
+}
+
+/**
+ * The artifact ID and type of a repo-relative artifact path such as
+ * requirements/REQ-001.md
+ */
+export function parseArtifactPath(path: string): { id: string; typeKey: string } {
+  const match = path.replace(/\\/g, '/').match(/(?:^|\/)([^/]+)\/([^/]+)\.md$/);
+  const typeKey = match ? getTypeFromId(match[2]) : 'unknown';
+  if (!match || !LINKABLE_TYPES.includes(typeKey)) {
+    throw new Error(`Not an artifact file: ${path}`);
+  }
+  if (ARTIFACT_CONFIG[typeKey].folder !== match[1]) {
+    throw new Error(`${match[2]} does not belong in ${match[1]}/`);
+  }
+  return { id: match[2], typeKey };
+}
+
+/**
+ * The copy of an artifact under a new ID; linked artifact entries are kept
+ * only with copyLinks
+ */
+export function buildClone(
+  original: CloneableArtifact,
+  typeKey: string,
+  cloneId: string,
+  title: string,
+  copyLinks: boolean,
+  now: number
+): CloneableArtifact {
+  return {
+    ...original,
+    id: cloneId,
+    title,
+    status: INITIAL_STATUS[typeKey] ?? original.status,
+    statusChangedBy: undefined,
+    statusChangedAt: undefined,
+    approvalDate: undefined,
+    lastRun: undefined,
+    linkedArtifacts: copyLinks ? original.linkedArtifacts || [] : [],
+    externalIssues: undefined,
+    aliases: undefined,
+    revision: '01',
+    dateCreated: now,
+    lastModified: now,
+  };
+}
+
+/**
+ * Copy the artifact at the path under a fresh ID, as one commit
+ */
+export async function cloneArtifact(
+  path: string,
+  options: CloneOptions = {}
+): Promise<ArtifactCloneResult> {
+  const { copyLinks = false } = options;
+  const { id: originalId, typeKey } = parseArtifactPath(path);
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const original: CloneableArtifact | null = await service.load(originalId);
+  if (!original || original.isDeleted) throw new Error(`Artifact not found: ${originalId}`);
+  const title = options.title?.trim() || `${original.title ?? originalId} (copy)`;
+
+  const links: Link[] = copyLinks
+    ? (await diskLinkService.getAllLinks()).filter(
+        (link) => link.sourceId === originalId || link.targetId === originalId
+      )
+    : [];
+
+  const membershipField = PROJECT_MEMBERSHIP[typeKey];
+  const projects: Project[] = membershipField
+    ? (await ALL_ARTIFACT_SERVICES.projects.loadAll()).filter((project: Project) =>
+        ((project[membershipField] as string[]) || []).includes(originalId)
+      )
+    : [];
+
+  const folder = ARTIFACT_CONFIG[typeKey].folder;
+  const linkFolder = ARTIFACT_CONFIG.links.folder;
+  const linkIds: string[] = [];
+  let cloneId = '';
+  const now = Date.now();
+
+  await runJournaled(
+    `Clone ${originalId}`,
+    async (journal) => {
+      await journal.record(idService.getCounterPath(typeKey));
+      [cloneId] = await idService.getNextIds(typeKey, 1, true);
+      await journal.record(`${folder}/${cloneId}.md`);
+      await service.save(buildClone(original, typeKey, cloneId, title, copyLinks, now));
+
+      if (links.length > 0) {
+        await journal.record(idService.getCounterPath('links'));
+        linkIds.push(...(await idService.getNextIds('links', links.length, true)));
+      }
+      for (let i = 0; i < links.length; i++) {
+        const link = links[i];
+        await journal.record(`${linkFolder}/${linkIds[i]}.md`);
+        await diskLinkService.save({
+          ...link,
+          id: linkIds[i],
+          sourceId: link.sourceId === originalId ? cloneId : link.sourceId,
+          targetId: link.targetId === originalId ? cloneId : link.targetId,
+          suspect: undefined,
+          suspectSince: undefined,
+          dateCreated: now,
+          lastModified: now,
+          revision: '01',
+        });
+      }
+
+      for (const project of projects) {
+        await journal.record(`${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`);
+        await ALL_ARTIFACT_SERVICES.projects.save({
+          ...project,
+          [membershipField!]: [...(project[membershipField!] as string[]), cloneId],
+          lastModified: now,
+        });
+      }
+    },
+    `Clone ${originalId}`
+  );
+  debug.info(`[ArtifactCloneService] Cloned ${originalId} as ${cloneId}`);
+
+  return {
+    originalId,
+    cloneId,
+    path: `${folder}/${cloneId}.md`,
+    linkIds,
+    projectIds: projects.map((project) => project.id),
+  };
+}
+
+export const artifactCloneService = {
+  parseArtifactPath,
+  buildClone,
+  cloneArtifact,
+};
@@ -0,0 +1,96 @@
+/**
+ * Artifact History Service
+ *
+ * The commit history of a single artifact file together with the frontmatter
+ * of each version, so the history panel can show what changed in a commit
+ * (e.g. "status changed from draft to approved") without reading every
+ * version itself.
+ */
+
+import type { CommitInfo } from '../types';
+import { realGitService } from './realGitService';
+import { baseDiskService } from './baseDiskService';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+
+// Frontmatter fields compared between versions
+export const TRACKED_FIELDS = ['title', 'status', 'priority', 'revision'];
+
+export interface FieldChange {
+  field: string;
+  from: string | null;
+  to: string | null;
+}
+
+export interface ArtifactVersion {
+  commit: CommitInfo;
+  exists: boolean; // False for the commit that deleted the file
+  frontmatter: Record<string, unknown>;
+  title?: string;
+  status?: string;
+  changes: FieldChange[]; // Tracked fields that differ from the previous version
+}
+
+function fieldValue(frontmatter: Record<string, unknown>, field: string): string | null {
+  const value = frontmatter[field];
+  return value === undefined || value === null || value === '' ? null : String(value);
+}
+
+/**
+ * Tracked fields whose values differ between two versions of the frontmatter
+ */
+export function diffFrontmatter(
+  previous: Record<string, unknown>,
+  current: Record<string, unknown>,
+  fields: string[] = TRACKED_FIELDS
+): FieldChange[] {
+  return fields
+    .map((field) => ({ field, from: fieldValue(previous, field), to: fieldValue(current, field) }))
+    .filter((change) => change.from !== change.to);
+}
+
+/**
+ * Human-readable description of a field change
+ */
+export function describeChange(change: FieldChange): string {
+  if (change.from === null) return `${change.field} set to ${change.to}`;
+  if (change.to === null) return `${change.field} cleared (was ${change.from})`;
+  return `${change.field} changed from ${change.from} to ${change.to}`;
+}
+
+/**
+ * All versions of an artifact file, newest first
+ */
+export async function getArtifactHistory(
+  filePath: string,
+  depth?: number
+): Promise<ArtifactVersion[]> {
+  const commits = await realGitService.getHistory(filePath, depth);
+  const contents = await Promise.all(
+    commits.map((commit) => baseDiskService.readTextFileAtCommit(filePath, commit.hash))
+  );
+  const frontmatters = contents.map((content) =>
+    content === null ? null : parseYamlFrontmatter(content).frontmatter
+  );
+
+  return commits.map((commit, i) => {
+    const frontmatter = frontmatters[i];
+    if (!frontmatter) {
+      return { commit, exists: false, frontmatter: {}, changes: [] };
+    }
+    const previous = frontmatters[i + 1] ?? {};
+    return {
+      commit,
+      exists: true,
+      frontmatter,
+      title: fieldValue(frontmatter, 'title') ?? undefined,
+      status: fieldValue(frontmatter, 'status') ?? undefined,
+      changes: diffFrontmatter(previous, frontmatter),
+    };
+  });
+}
+
+export const artifactHistoryService = {
+  diffFrontmatter,
+  describeChange,
+  getArtifactHistory,
+};
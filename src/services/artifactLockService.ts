@@ -0,0 +1,143 @@
+/**
+ * Artifact Lock Service
+ *
+ * Advisory locks for teams sharing a project folder (e.g. over a synced
+ * drive). A lock record in .locks/<artifactId>.json names the user editing an
+ * artifact; it expires after a while so abandoned locks do not block others.
+ * Locks are advisory: saving a locked artifact only raises a warning.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { ArtifactLock } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { LOCKS_DIR } from './git/types';
+import { debug } from '../utils/debug';
+
+const CURRENT_USER_FILE = 'current-user.md'; // Written by diskProjectService
+
+export const DEFAULT_LOCK_TTL_MS = 30 * 60 * 1000;
+
+/**
+ * Whether a lock has expired
+ */
+export function isLockStale(lock: ArtifactLock, now: number = Date.now()): boolean {
+  return lock.expiresAt <= now;
+}
+
+function resolveArtifactPath(path: string): { id: string; filePath: string } {
+  const id = (path.split('/').pop() || '').replace(/\.md$/, '');
+  const config = ARTIFACT_CONFIG[getTypeFromId(id)];
+  const filePath = config && `${config.folder}/${id}.md`;
+  if (!filePath || path.replace(/^\/+/, '') !== filePath) {
+    throw new Error(`Not an artifact path: ${path}`);
+  }
+  return { id, filePath };
+}
+
+function isArtifactLock(value: unknown): value is ArtifactLock {
+  const lock = value as ArtifactLock | null;
+  return !!lock && typeof lock.owner === 'string' && typeof lock.expiresAt === 'number';
+}
+
+class ArtifactLockService extends BaseDiskService {
+  private getLockPath(artifactId: string): string {
+    return `${LOCKS_DIR}/${artifactId}.json`;
+  }
+
+  private async readLock(artifactId: string): Promise<ArtifactLock | null> {
+    const lock = await this.readJsonFile<ArtifactLock>(this.getLockPath(artifactId));
+    return isArtifactLock(lock) && !isLockStale(lock) ? lock : null;
+  }
+
+  /**
+   * ID of the current user, used as owner when none is given
+   */
+  async getCurrentOwner(): Promise<string> {
+    return this.readTextFile(CURRENT_USER_FILE);
+  }
+
+  /**
+   * The active (non-stale) lock of an artifact, if any
+   */
+  async getLock(path: string): Promise<ArtifactLock | null> {
+    return this.readLock(resolveArtifactPath(path).id);
+  }
+
+  /**
+   * All active locks
+   */
+  async listLocks(): Promise<ArtifactLock[]> {
+    const files = await this.listFiles(LOCKS_DIR);
+    const locks = await Promise.all(
+      files
+        .filter((file) => file.endsWith('.json'))
+        .map((file) => this.readLock(file.slice(0, -'.json'.length)))
+    );
+    return locks.filter((lock): lock is ArtifactLock => lock !== null);
+  }
+
+  /**
+   * Lock an artifact for editing. Fails if another user holds an active lock;
+   * the owner's own lock is refreshed.
+   */
+  async lockArtifact(
+    path: string,
+    owner: string,
+    ttlMs: number = DEFAULT_LOCK_TTL_MS
+  ): Promise<ArtifactLock> {
+    const { id, filePath } = resolveArtifactPath(path);
+    const existing = await this.readLock(id);
+    if (existing && existing.owner !== owner) {
+      const until = new Date(existing.expiresAt).toISOString();
+      throw new Error(`${id} is locked by ${existing.owner} until ${until}`);
+    }
+
+    const now = Date.now();
+    const lock: ArtifactLock = {
+      artifactId: id,
+      path: filePath,
+      owner,
+      acquiredAt: existing?.acquiredAt ?? now,
+      expiresAt: now + ttlMs,
+    };
+    await this.writeJsonFile(this.getLockPath(id), lock);
+    debug.log(`[ArtifactLockService] ${id} locked by ${owner}`);
+    return lock;
+  }
+
+  /**
+   * Release a lock. Only the owner may release an active lock unless forced;
+   * stale locks can be removed by anyone. Returns false if there was no lock.
+   */
+  async unlockArtifact(path: string, owner: string, force: boolean = false): Promise<boolean> {
+    const { id } = resolveArtifactPath(path);
+    const lockPath = this.getLockPath(id);
+    if (!(await this.exists(lockPath))) return false;
+
+    const active = await this.readLock(id);
+    if (active && active.owner !== owner && !force) {
+      throw new Error(`${id} is locked by ${active.owner}`);
+    }
+
+    await this.deleteFile(lockPath);
+    debug.log(`[ArtifactLockService] ${id} unlocked by ${owner}`);
+    return true;
+  }
+
+  /**
+   * The active lock on an artifact held by someone other than the owner
+   * (the current user by default), or null when writing is uncontested
+   */
+  async getConflictingLock(artifactId: string, owner?: string): Promise<ArtifactLock | null> {
+    // Listing first avoids a failed read for every save of an unlocked artifact
+    const files = await this.listFiles(LOCKS_DIR);
+    if (!files.includes(`${artifactId}.json`)) return null;
+
+    const lock = await this.readLock(artifactId);
+    if (!lock) return null;
+    const writer = owner ?? (await this.getCurrentOwner());
+    return lock.owner !== writer ? lock : null;
+  }
+}
+
+export const artifactLockService = new ArtifactLockService();
@@ -0,0 +1,272 @@
+/**
+ * Artifact Merge Service
+ *
+ * Consolidates a duplicate into the artifact that is kept: the duplicate's
+ * text is appended to the kept artifact's text fields, tags and linked
+ * artifacts are combined, and its ID is recorded in the kept artifact's
+ * aliases. Links, child artifacts, project membership and document entries
+ * that pointed at the duplicate are moved to the kept artifact, so the trace
+ * stays intact, and the duplicate is soft-deleted. All files change under
+ * the write journal and are committed together.
+ */
+
+import type { ArtifactDocument, ArtifactLink, Link, Project } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { LINKABLE_TYPES } from './danglingLinkService';
+import { diskLinkService } from './diskLinkService';
+import { REPLACEABLE_FIELDS } from './searchReplaceService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { runJournaled } from './writeJournalService';
+import { incrementRevision } from '../utils/revisionUtils';
+import { debug } from '../utils/debug';
+
+// Text of both artifacts is kept, one after the other
+const TEXT_SEPARATOR = '\n\n';
+
+export interface ArtifactMergeResult {
+  targetId: string;
+  sourceId: string;
+  mergedFields: string[]; // Text fields that took content from the source
+  movedLinks: string[]; // Links now pointing at the target
+  removedLinks: string[]; // Links that would have become self-links or duplicates
+  updatedArtifacts: string[]; // Other artifacts and projects that referenced the source
+}
+
+interface MergeableArtifact {
+  id: string;
+  revision?: string;
+  lastModified: number;
+  tags?: string[];
+  linkedArtifacts?: ArtifactLink[];
+  aliases?: string[];
+  parentId?: string;
+  isDeleted?: boolean;
+  [field: string]: unknown;
+}
+
+/**
+ * Problem with merging, or null when the source can be merged into the target
+ */
+export function validateArtifactMerge(targetId: string, sourceId: string): string | null {
+  if (targetId === sourceId) return `Cannot merge ${targetId} into itself`;
+  const typeKey = getTypeFromId(targetId);
+  if (!LINKABLE_TYPES.includes(typeKey)) return `Unknown artifact type for ${targetId}`;
+  if (getTypeFromId(sourceId) !== typeKey) {
+    return `Only artifacts of the same type can be merged: ${sourceId} into ${targetId}`;
+  }
+  return null;
+}
+
+/**
+ * The text fields of the target with the source's text appended where the
+ * two differ; titles stay those of the target
+ */
+export function mergeTextFields(
+  target: MergeableArtifact,
+  source: MergeableArtifact
+): Record<string, string> {
+  const updates: Record<string, string> = {};
+  for (const field of REPLACEABLE_FIELDS) {
+    if (field === 'title') continue;
+    const added = source[field];
+    if (typeof added !== 'string' || !added.trim()) continue;
+    const kept = typeof target[field] === 'string' ? (target[field] as string) : '';
+    if (kept.includes(added.trim())) continue;
+    updates[field] = kept.trim() ? `${kept.trimEnd()}${TEXT_SEPARATOR}${added}` : added;
+  }
+  return updates;
+}
+
+/**
+ * Linked artifact entries with the source's ID replaced by the target's,
+ * dropping self-links and duplicates
+ */
+export function retargetLinkedArtifacts(
+  entries: ArtifactLink[],
+  ownerId: string,
+  targetId: string,
+  sourceId: string
+): ArtifactLink[] {
+  const seen = new Set<string>();
+  const result: ArtifactLink[] = [];
+  for (const entry of entries) {
+    const linkTarget = entry.targetId === sourceId ? targetId : entry.targetId;
+    const key = `${linkTarget}:${entry.type}`;
+    if (linkTarget === ownerId || seen.has(key)) continue;
+    seen.add(key);
+    result.push({ ...entry, targetId: linkTarget });
+  }
+  return result;
+}
+
+function planLinkChanges(
+  links: Link[],
+  targetId: string,
+  sourceId: string
+): { moved: Link[]; removed: Link[] } {
+  const touches = (link: Link) => link.sourceId === sourceId || link.targetId === sourceId;
+  const keys = new Set(
+    links
+      .filter((link) => !touches(link))
+      .map((link) => `${link.sourceId}:${link.targetId}:${link.type}`)
+  );
+  const moved: Link[] = [];
+  const removed: Link[] = [];
+  for (const link of links.filter(touches)) {
+    const from = link.sourceId === sourceId ? targetId : link.sourceId;
+    const to = link.targetId === sourceId ? targetId : link.targetId;
+    const key = `${from}:${to}:${link.type}`;
+    if (from === to || keys.has(key)) {
+      removed.push(link);
+      continue;
+    }
+    keys.add(key);
+    moved.push({ ...link, sourceId: from, targetId: to });
+  }
+  return { moved, removed };
+}
+
+function replaceMember(ids: string[], targetId: string, sourceId: string): string[] {
+  const replaced = ids.map((id) => (id === sourceId ? targetId : id));
+  return replaced.filter((id, i) => replaced.indexOf(id) === i);
+}
+
+/**
+ * Merge the source artifact into the target and soft-delete the source, as
+ * one commit
+ */
+export async function mergeArtifacts(
+  targetId: string,
+  sourceId: string
+): Promise<ArtifactMergeResult> {
+  const problem = validateArtifactMerge(targetId, sourceId);
+  if (problem) throw new Error(problem);
+
+  const typeKey = getTypeFromId(targetId);
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const target: MergeableArtifact | null = await service.load(targetId);
+  const source: MergeableArtifact | null = await service.load(sourceId);
+  if (!target || target.isDeleted) throw new Error(`Artifact not found: ${targetId}`);
+  if (!source || source.isDeleted) throw new Error(`Artifact not found: ${sourceId}`);
+
+  // Plan every change before touching anything
+  const textUpdates = mergeTextFields(target, source);
+  const merged: MergeableArtifact = {
+    ...target,
+    ...textUpdates,
+    tags: [...new Set([...(target.tags || []), ...(source.tags || [])])],
+    linkedArtifacts: retargetLinkedArtifacts(
+      [...(target.linkedArtifacts || []), ...(source.linkedArtifacts || [])],
+      targetId,
+      targetId,
+      sourceId
+    ),
+    aliases: [...new Set([...(target.aliases || []), sourceId, ...(source.aliases || [])])],
+    parentId: target.parentId === sourceId ? source.parentId : target.parentId,
+  };
+  const { moved, removed } = planLinkChanges(
+    await diskLinkService.getAllLinks(),
+    targetId,
+    sourceId
+  );
+
+  const referencing: { type: string; artifact: MergeableArtifact }[] = [];
+  for (const type of LINKABLE_TYPES) {
+    const artifacts: MergeableArtifact[] = await ALL_ARTIFACT_SERVICES[type].loadAll();
+    for (const artifact of artifacts) {
+      if (artifact.id === targetId || artifact.id === sourceId) continue;
+      const entries = artifact.linkedArtifacts || [];
+      const structure = (artifact as unknown as ArtifactDocument).structure;
+      const linksSource = entries.some((entry) => entry.targetId === sourceId);
+      const listsSource = Array.isArray(structure) && structure.some((e) => e.id === sourceId);
+      if (!linksSource && !listsSource && artifact.parentId !== sourceId) continue;
+
+      const updated: MergeableArtifact = {
+        ...artifact,
+        linkedArtifacts: retargetLinkedArtifacts(entries, artifact.id, targetId, sourceId),
+        parentId: artifact.parentId === sourceId ? targetId : artifact.parentId,
+      };
+      if (listsSource) {
+        const listsTarget = structure.some((e) => e.id === targetId);
+        updated.structure = structure
+          .filter((e) => !(listsTarget && e.id === sourceId))
+          .map((e) => (e.id === sourceId ? { ...e, id: targetId } : e));
+      }
+      referencing.push({ type, artifact: updated });
+    }
+  }
+
+  const membershipField = PROJECT_MEMBERSHIP[typeKey];
+  const projects: Project[] = membershipField
+    ? (await ALL_ARTIFACT_SERVICES.projects.loadAll()).filter((project: Project) =>
+        ((project[membershipField] as string[]) || []).includes(sourceId)
+      )
+    : [];
+
+  const folder = ARTIFACT_CONFIG[typeKey].folder;
+  const linkFolder = ARTIFACT_CONFIG.links.folder;
+  const now = Date.now();
+  const bump = <T extends { revision?: string }>(item: T): T => ({
+    ...item,
+    revision: incrementRevision(item.revision || '01'),
+    lastModified: now,
+  });
+
+  await runJournaled(
+    `Merge ${sourceId} into ${targetId}`,
+    async (journal) => {
+      await journal.record(`${folder}/${targetId}.md`);
+      await service.save(bump(merged));
+      await journal.record(`${folder}/${sourceId}.md`);
+      await service.save(
+        bump({ ...source, linkedArtifacts: [], isDeleted: true, deletedAt: now })
+      );
+      for (const link of moved) {
+        await journal.record(`${linkFolder}/${link.id}.md`);
+        await diskLinkService.save(bump(link));
+      }
+      for (const link of removed) {
+        await journal.record(`${linkFolder}/${link.id}.md`);
+        await diskLinkService.delete(link.id);
+      }
+      for (const { type, artifact } of referencing) {
+        await journal.record(`${ARTIFACT_CONFIG[type].folder}/${artifact.id}.md`);
+        await ALL_ARTIFACT_SERVICES[type].save(bump(artifact));
+      }
+      for (const project of projects) {
+        await journal.record(`${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`);
+        await ALL_ARTIFACT_SERVICES.projects.save({
+          ...project,
+          [membershipField!]: replaceMember(
+            project[membershipField!] as string[],
+            targetId,
+            sourceId
+          ),
+          lastModified: now,
+        });
+      }
+    },
+    `Merge ${sourceId} into ${targetId}`
+  );
+  debug.info(`[ArtifactMergeService] Merged ${sourceId} into ${targetId}`);
+
+  return {
+    targetId,
+    sourceId,
+    mergedFields: Object.keys(textUpdates),
+    movedLinks: moved.map((link) => link.id),
+    removedLinks: removed.map((link) => link.id),
+    updatedArtifacts: [
+      ...referencing.map(({ artifact }) => artifact.id),
+      ...projects.map((project) => project.id),
+    ],
+  };
+}
+
+export const artifactMergeService = {
+  validateArtifactMerge,
+  mergeTextFields,
+  retargetLinkedArtifacts,
+  mergeArtifacts,
+};
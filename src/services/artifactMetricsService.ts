@@ -0,0 +1,85 @@
+/**
+ * Artifact Metrics Service
+ *
+ * Size and readability metrics per artifact file and aggregated per artifact
+ * type, to spot bloated or underspecified artifacts.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import {
+  aggregateTextMetrics,
+  computeTextMetrics,
+  type AggregateMetrics,
+  type TextMetrics,
+} from '../utils/textMetricsUtils';
+import { debug } from '../utils/debug';
+
+// Artifact types metrics are computed for
+export const METRIC_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface ArtifactMetrics {
+  path: string;
+  artifactId: string;
+  metrics: TextMetrics;
+}
+
+export interface TypeMetrics {
+  typeKey: string;
+  aggregate: AggregateMetrics;
+  artifacts: ArtifactMetrics[];
+}
+
+function resolveArtifactPath(path: string): { id: string; filePath: string } {
+  const id = (path.split('/').pop() || '').replace(/\.md$/, '');
+  const typeKey = getTypeFromId(id);
+  const filePath = ARTIFACT_CONFIG[typeKey] && `${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`;
+  if (!METRIC_TYPES.includes(typeKey) || path.replace(/^\/+/, '') !== filePath) {
+    throw new Error(`Not a measurable artifact path: ${path}`);
+  }
+  return { id, filePath };
+}
+
+class ArtifactMetricsService extends BaseDiskService {
+  /**
+   * Metrics of one artifact file (repo-relative path, e.g. requirements/REQ-001.md)
+   */
+  async getArtifactMetrics(path: string): Promise<ArtifactMetrics> {
+    const { id, filePath } = resolveArtifactPath(path);
+    if (!(await this.exists(filePath))) {
+      throw new Error(`Artifact not found: ${path}`);
+    }
+
+    const metrics = computeTextMetrics(await this.readTextFile(filePath));
+    return { path: filePath, artifactId: id, metrics };
+  }
+
+  /**
+   * Metrics of every artifact of the given types, with per-type aggregates
+   */
+  async getTypeMetrics(typeKeys: string[] = METRIC_TYPES): Promise<TypeMetrics[]> {
+    const result: TypeMetrics[] = [];
+
+    for (const typeKey of typeKeys.filter((key) => METRIC_TYPES.includes(key))) {
+      const folder = ARTIFACT_CONFIG[typeKey].folder;
+      const artifacts: ArtifactMetrics[] = [];
+      for (const file of (await this.listFiles(folder)).filter((f) => f.endsWith('.md'))) {
+        try {
+          artifacts.push(await this.getArtifactMetrics(`${folder}/${file}`));
+        } catch (err) {
+          debug.log(`[ArtifactMetricsService] Skipping ${folder}/${file}:`, err);
+        }
+      }
+
+      result.push({
+        typeKey,
+        aggregate: aggregateTextMetrics(artifacts.map((a) => a.metrics)),
+        artifacts,
+      });
+    }
+
+    return result;
+  }
+}
+
+export const artifactMetricsService = new ArtifactMetricsService();
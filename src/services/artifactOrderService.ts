@@ -0,0 +1,115 @@
+/**
+ * Artifact Order Service
+ *
+ * Persists the author's manual reading order of artifacts per folder in
+ * `<folder>/order.json` (a JSON array of IDs). Artifacts missing from the
+ * file are appended in ID order, so new artifacts show up at the end.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+// Artifact types whose lists can be manually ordered
+export const ORDERED_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export const ORDER_FILE = 'order.json';
+
+/**
+ * Sort items by a stored order; unlisted items follow in ID order
+ */
+export function applyOrder<T extends { id: string }>(items: T[], order: string[]): T[] {
+  const positions = new Map(order.map((id, index) => [id, index]));
+  return [...items].sort((a, b) => {
+    const posA = positions.get(a.id) ?? Number.POSITIVE_INFINITY;
+    const posB = positions.get(b.id) ?? Number.POSITIVE_INFINITY;
+    if (posA !== posB) return posA < posB ? -1 : 1;
+    return a.id.localeCompare(b.id, undefined, { numeric: true });
+  });
+}
+
+class ArtifactOrderService extends BaseDiskService {
+  private getOrderPath(typeKey: string): string {
+    if (!ORDERED_TYPES.includes(typeKey)) {
+      throw new Error(`Artifact type does not support manual ordering: ${typeKey}`);
+    }
+    return `${ARTIFACT_CONFIG[typeKey].folder}/${ORDER_FILE}`;
+  }
+
+  /**
+   * Get the stored order of a type (may be partial or empty)
+   */
+  async getOrder(typeKey: string): Promise<string[]> {
+    const order = await this.readJsonFile<string[]>(this.getOrderPath(typeKey), []);
+    return Array.isArray(order) ? order.filter((id) => typeof id === 'string') : [];
+  }
+
+  /**
+   * Sort loaded artifacts of a type by the stored order
+   */
+  async sortByOrder<T extends { id: string }>(typeKey: string, items: T[]): Promise<T[]> {
+    return applyOrder(items, await this.getOrder(typeKey));
+  }
+
+  /**
+   * Store a complete order for a type and commit it
+   */
+  async setOrder(typeKey: string, ids: string[]): Promise<void> {
+    await this.writeJsonFile(this.getOrderPath(typeKey), ids, `Reorder ${typeKey}`);
+    debug.log(`[ArtifactOrderService] Saved order of ${ids.length} ${typeKey}`);
+  }
+
+  /**
+   * Move an artifact to a position (0-based, clamped) in its list,
+   * e.g. after a drag and drop. Returns the new order.
+   */
+  async moveToIndex(typeKey: string, id: string, index: number): Promise<string[]> {
+    const ids = await this.loadOrderedIds(typeKey);
+    return this.move(typeKey, ids, this.indexOf(ids, id), index);
+  }
+
+  /**
+   * Move an artifact one position up in its list
+   */
+  async moveUp(typeKey: string, id: string): Promise<string[]> {
+    const ids = await this.loadOrderedIds(typeKey);
+    const from = this.indexOf(ids, id);
+    return this.move(typeKey, ids, from, from - 1);
+  }
+
+  /**
+   * Move an artifact one position down in its list
+   */
+  async moveDown(typeKey: string, id: string): Promise<string[]> {
+    const ids = await this.loadOrderedIds(typeKey);
+    const from = this.indexOf(ids, id);
+    return this.move(typeKey, ids, from, from + 1);
+  }
+
+  private async loadOrderedIds(typeKey: string): Promise<string[]> {
+    const order = await this.getOrder(typeKey);
+    const items: { id: string }[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+    return applyOrder(items, order).map((item) => item.id);
+  }
+
+  private indexOf(ids: string[], id: string): number {
+    const index = ids.indexOf(id);
+    if (index === -1) {
+      throw new Error(`Artifact not found: ${id}`);
+    }
+    return index;
+  }
+
+  private async move(typeKey: string, ids: string[], from: number, index: number) {
+    const to = Math.max(0, Math.min(index, ids.length - 1));
+    if (from === to) return ids;
+
+    const [id] = ids.splice(from, 1);
+    ids.splice(to, 0, id);
+    await this.setOrder(typeKey, ids);
+    return ids;
+  }
+}
+
+export const artifactOrderService = new ArtifactOrderService();
@@ -0,0 +1,185 @@
+/**
+ * Artifact Rename Service
+ *
+ * Changes an artifact's ID everywhere at once: its file is moved to the new
+ * name with the new ID in its frontmatter, and every mention of the old ID in
+ * other files (link files, linked artifact lists, parent IDs, project and
+ * document membership, inline mentions and relative links in text) is
+ * rewritten, as are the manual orders, releases and test runs that list it.
+ * Its comments and approvals move to the new ID. Approval records keep the
+ * ID they were signed for, so the artifact shows as changed since approval.
+ * All files change under the write journal and are committed together.
+ *
+ * The new ID may use another prefix (REQ-012 → SYS-REQ-012): the artifact
+ * stays in its type's folder, and the app takes the type of such IDs from
+ * that folder (see registerArtifactIdType). A prefix that belongs to another
+ * type is refused, as it would make the ID read as that type.
+ */
+
+import {
+  ARTIFACT_CONFIG,
+  PREFIX_TO_TYPE,
+  getTypeFromId,
+  registerArtifactIdType,
+} from '../constants/artifactConfig';
+import { baseDiskService } from './baseDiskService';
+import { idService } from './idService';
+import { ORDER_FILE } from './artifactOrderService';
+import { runJournaled } from './writeJournalService';
+import { debug } from '../utils/debug';
+
+export interface ArtifactRenameResult {
+  oldId: string;
+  newId: string;
+  oldPath: string;
+  newPath: string;
+  updatedFiles: string[]; // Other files written: rewritten references, moved comments and approvals
+  referenceCount: number;
+}
+
+// Folders of JSON files that list artifact IDs
+const REFERENCE_DIRS = ['releases', 'testruns'];
+
+const ID_PATTERN = /^[A-Za-z0-9]+(?:-[A-Za-z0-9]+)+$/;
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+/**
+ * Content with every whole mention of the ID replaced, and how many there
+ * were; "REQ-012" does not match inside "REQ-0120" or "X-REQ-012"
+ */
+export function replaceArtifactId(
+  content: string,
+  oldId: string,
+  newId: string
+): { content: string; count: number } {
+  const pattern = new RegExp(`(?<![\\w-])${escapeRegExp(oldId)}(?![\\w]|-\\w)`, 'g');
+  let count = 0;
+  const replaced = content.replace(pattern, () => {
+    count++;
+    return newId;
+  });
+  return { content: replaced, count };
+}
+
+/**
+ * Problem with renaming, or null when the new ID can be used
+ */
+export function validateArtifactRename(oldId: string, newId: string): string | null {
+  const typeKey = getTypeFromId(oldId);
+  const config = ARTIFACT_CONFIG[typeKey];
+  if (!config) return `Unknown artifact type for ${oldId}`;
+  if (newId === oldId) return `${oldId} already has this ID`;
+  if (!ID_PATTERN.test(newId)) {
+    return `New ID must be letters and digits in dash-separated parts: ${newId}`;
+  }
+  const prefix = newId.split('-')[0];
+  const prefixType = Object.hasOwn(PREFIX_TO_TYPE, prefix) ? PREFIX_TO_TYPE[prefix] : typeKey;
+  if (prefixType !== typeKey) {
+    return `New ID ${newId} uses the prefix of another type (${ARTIFACT_CONFIG[prefixType].label})`;
+  }
+  return null;
+}
+
+// Artifacts, manual orders, releases and test runs
+async function listReferencingFiles(): Promise<string[]> {
+  const paths: string[] = [];
+  for (const { folder } of Object.values(ARTIFACT_CONFIG)) {
+    for (const name of await baseDiskService.listFiles(folder)) {
+      if (name.endsWith('.md') || name === ORDER_FILE) paths.push(`${folder}/${name}`);
+    }
+  }
+  for (const folder of REFERENCE_DIRS) {
+    for (const name of await baseDiskService.listFiles(folder)) {
+      if (name.endsWith('.json')) paths.push(`${folder}/${name}`);
+    }
+  }
+  return paths;
+}
+
+/**
+ * Give an artifact a new ID and rewrite every reference to it, as one
+ * commit. Returns which files were touched.
+ */
+export async function renameArtifactId(
+  oldId: string,
+  newId: string
+): Promise<ArtifactRenameResult> {
+  const problem = validateArtifactRename(oldId, newId);
+  if (problem) throw new Error(problem);
+
+  const typeKey = getTypeFromId(oldId);
+  const folder = ARTIFACT_CONFIG[typeKey].folder;
+  const oldPath = `${folder}/${oldId}.md`;
+  const newPath = `${folder}/${newId}.md`;
+  if (!(await baseDiskService.exists(oldPath))) {
+    throw new Error(`Artifact not found: ${oldId}`);
+  }
+  if (await baseDiskService.exists(newPath)) {
+    throw new Error(`${newId} already exists`);
+  }
+
+  // Plan every change before touching anything
+  const changes = new Map<string, string>();
+  let referenceCount = 0;
+  for (const path of await listReferencingFiles()) {
+    const original = await baseDiskService.readTextFile(path);
+    const { content, count } = replaceArtifactId(original, oldId, newId);
+    if (count === 0) continue;
+    changes.set(path === oldPath ? newPath : path, content);
+    if (path !== oldPath) referenceCount += count;
+  }
+  if (!changes.has(newPath)) {
+    throw new Error(`${oldPath} does not declare ID ${oldId}`);
+  }
+
+  // Sidecar files named after the artifact move with it
+  const moved = [oldPath];
+  const comments = `comments/${oldId}.json`;
+  if (await baseDiskService.exists(comments)) {
+    const original = await baseDiskService.readTextFile(comments);
+    changes.set(`comments/${newId}.json`, replaceArtifactId(original, oldId, newId).content);
+    moved.push(comments);
+  }
+  const approvals = `approvals/${oldId}.json`;
+  if (await baseDiskService.exists(approvals)) {
+    changes.set(`approvals/${newId}.json`, await baseDiskService.readTextFile(approvals));
+    moved.push(approvals);
+  }
+
+  // Keep new IDs from being handed out again
+  const number = newId.match(new RegExp(`^${ARTIFACT_CONFIG[typeKey].idPrefix}-(\\d+)$`))?.[1];
+  const counter = number ? parseInt(number, 10) : 0;
+  const raiseCounter = counter > (await idService.getCounter(typeKey));
+
+  const updatedFiles = [...changes.keys()].filter((path) => path !== newPath);
+  await runJournaled(
+    `Rename ${oldId}`,
+    async (journal) => {
+      for (const [path, content] of changes) {
+        await journal.record(path);
+        await baseDiskService.writeTextFile(path, content);
+      }
+      for (const path of moved) {
+        await journal.record(path);
+        await baseDiskService.deleteFile(path);
+      }
+      if (raiseCounter) {
+        await journal.record(idService.getCounterPath(typeKey));
+        await idService.setCounter(typeKey, counter, true);
+      }
+    },
+    `Rename ${oldId} to ${newId} (${referenceCount} reference(s) in ${updatedFiles.length} file(s))`
+  );
+  registerArtifactIdType(newId, typeKey);
+  debug.info(`[ArtifactRenameService] Renamed ${oldId} to ${newId}`);
+  return { oldId, newId, oldPath, newPath, updatedFiles, referenceCount };
+}
+
+export const artifactRenameService = {
+  replaceArtifactId,
+  validateArtifactRename,
+  renameArtifactId,
+};
@@ -5,7 +5,17 @@
  */
 
 import { BaseArtifactService, type ArtifactSerializer } from './baseArtifactService';
-import type { Requirement, UseCase, TestCase, Information, Risk, Project, User } from '../types';
+import type {
+  Requirement,
+  UseCase,
+  TestCase,
+  Information,
+  Risk,
+  Project,
+  User,
+  ChangeRequest,
+  GlossaryTerm,
+} from '../types';
 import {
   requirementToMarkdown,
   markdownToRequirement,
@@ -21,6 +31,10 @@ import {
   markdownToProject,
   userToMarkdown,
   markdownToUser,
+  changeRequestToMarkdown,
+  markdownToChangeRequest,
+  glossaryTermToMarkdown,
+  markdownToGlossaryTerm,
 } from '../utils/markdownUtils';
 
 // Requirements Service
@@ -78,6 +92,26 @@ const userSerializer: ArtifactSerializer<User> = {
 };
 export const userService = new BaseArtifactService<User>('users', userSerializer);
 
+// Change Requests Service
+const changeRequestSerializer: ArtifactSerializer<ChangeRequest> = {
+  serialize: changeRequestToMarkdown,
+  deserialize: markdownToChangeRequest,
+};
+export const changeRequestService = new BaseArtifactService<ChangeRequest>(
+  'changeRequests',
+  changeRequestSerializer
+);
+
+// Glossary Service
+const glossaryTermSerializer: ArtifactSerializer<GlossaryTerm> = {
+  serialize: glossaryTermToMarkdown,
+  deserialize: markdownToGlossaryTerm,
+};
+export const glossaryTermService = new BaseArtifactService<GlossaryTerm>(
+  'glossary',
+  glossaryTermSerializer
+);
+
 // Registry of all artifact services for generic iteration
 import { diskLinkService } from './diskLinkService';
 import { diskWorkflowService } from './diskWorkflowService';
@@ -98,4 +132,6 @@ export const ALL_ARTIFACT_SERVICES: Record<string, BaseArtifactService<any>> = {
   workflows: diskWorkflowService,
   customAttributes: diskCustomAttributeService,
   documents: documentService,
+  changeRequests: changeRequestService,
+  glossary: glossaryTermService,
 };
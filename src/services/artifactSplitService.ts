@@ -0,0 +1,200 @@
+/**
+ * Artifact Split Service
+ *
+ * Breaks an oversized artifact apart: its main text is cut at the given
+ * lines and each part becomes a new artifact of the same type with a fresh
+ * ID. The parts copy the original's classification (priority, category,
+ * tags, custom attributes, parent) but start over in the initial status, and
+ * each gets a derived_from link back to the original, which is left as it
+ * is. New artifacts join every project the original belongs to. All files
+ * change under the write journal and are committed together.
+ */
+
+import type { Link, LinkType, Project } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { idService } from './idService';
+import { REPLACEABLE_FIELDS } from './searchReplaceService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { runJournaled } from './writeJournalService';
+import { debug } from '../utils/debug';
+
+// The field holding the body that is split, per artifact type
+export const SPLIT_FIELDS: Record<string, string> = {
+  requirements: 'text',
+  usecases: 'description',
+  testcases: 'description',
+  information: 'text',
+  risks: 'description',
+};
+
+const SPLIT_LINK_TYPE: LinkType = 'derived_from';
+
+export interface ArtifactSplitSpec {
+  boundaries: number[]; // 1-based lines of the text where a new part starts
+  titles?: string[]; // One per part; "<original title> (n)" when omitted
+}
+
+export interface ArtifactSplitResult {
+  originalId: string;
+  createdIds: string[];
+  linkIds: string[];
+  projectIds: string[]; // Projects the new artifacts were added to
+}
+
+interface SplittableArtifact {
+  id: string;
+  title?: string;
+  [field: string]: unknown;
+}
+
+/**
+ * The text cut before each boundary line, trimmed; fails for boundaries out
+ * of order or out of range and for parts without text
+ */
+export function splitTextAtLines(text: string, boundaries: number[]): string[] {
+  const lines = text.split('\n');
+  if (boundaries.length === 0) throw new Error('At least one boundary is needed to split');
+  const starts = [1, ...boundaries];
+  for (let i = 1; i < starts.length; i++) {
+    const line = starts[i];
+    if (!Number.isInteger(line) || line <= starts[i - 1] || line > lines.length) {
+      throw new Error(`Boundaries must be increasing lines between 2 and ${lines.length}`);
+    }
+  }
+  const parts = starts.map((start, i) =>
+    lines
+      .slice(start - 1, (starts[i + 1] ?? lines.length + 1) - 1)
+      .join('\n')
+      .trim()
+  );
+  const empty = parts.findIndex((part) => !part);
+  if (empty !== -1) throw new Error(`Part ${empty + 1} has no text`);
+  return parts;
+}
+
+/**
+ * A new artifact for one part: classification is copied, text, status,
+ * approval and history are not
+ */
+export function buildSplitPart(
+  original: SplittableArtifact,
+  field: string,
+  id: string,
+  title: string,
+  text: string,
+  now: number
+): SplittableArtifact {
+  const part: SplittableArtifact = { ...original };
+  for (const textField of REPLACEABLE_FIELDS) delete part[textField];
+  return {
+    ...part,
+    id,
+    title,
+    [field]: text,
+    status: undefined,
+    statusChangedBy: undefined,
+    statusChangedAt: undefined,
+    approvalDate: undefined,
+    linkedArtifacts: [],
+    externalIssues: undefined,
+    aliases: undefined,
+    order: undefined,
+    revision: '01',
+    dateCreated: now,
+    lastModified: now,
+  };
+}
+
+/**
+ * Split an artifact's text into new artifacts linked back to it, as one
+ * commit
+ */
+export async function splitArtifact(
+  artifactId: string,
+  spec: ArtifactSplitSpec
+): Promise<ArtifactSplitResult> {
+  const typeKey = getTypeFromId(artifactId);
+  const field = SPLIT_FIELDS[typeKey];
+  if (!field) throw new Error(`Artifacts of this type cannot be split: ${artifactId}`);
+
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const original: (SplittableArtifact & { isDeleted?: boolean }) | null =
+    await service.load(artifactId);
+  if (!original || original.isDeleted) throw new Error(`Artifact not found: ${artifactId}`);
+
+  const parts = splitTextAtLines(String(original[field] ?? ''), spec.boundaries);
+  if (spec.titles && spec.titles.length !== parts.length) {
+    throw new Error(`Expected ${parts.length} titles, got ${spec.titles.length}`);
+  }
+  const titles = parts.map(
+    (_, i) => spec.titles?.[i]?.trim() || `${original.title ?? artifactId} (${i + 1})`
+  );
+
+  const membershipField = PROJECT_MEMBERSHIP[typeKey];
+  const projects: Project[] = membershipField
+    ? (await ALL_ARTIFACT_SERVICES.projects.loadAll()).filter((project: Project) =>
+        ((project[membershipField] as string[]) || []).includes(artifactId)
+      )
+    : [];
+
+  const folder = ARTIFACT_CONFIG[typeKey].folder;
+  const linkFolder = ARTIFACT_CONFIG.links.folder;
+  const createdIds: string[] = [];
+  const linkIds: string[] = [];
+  const now = Date.now();
+
+  await runJournaled(
+    `Split ${artifactId}`,
+    async (journal) => {
+      await journal.record(idService.getCounterPath(typeKey));
+      createdIds.push(...(await idService.getNextIds(typeKey, parts.length, true)));
+      await journal.record(idService.getCounterPath('links'));
+      linkIds.push(...(await idService.getNextIds('links', parts.length, true)));
+
+      for (let i = 0; i < parts.length; i++) {
+        const id = createdIds[i];
+        await journal.record(`${folder}/${id}.md`);
+        await service.save(buildSplitPart(original, field, id, titles[i], parts[i], now));
+
+        const link: Link = {
+          id: linkIds[i],
+          sourceId: id,
+          targetId: artifactId,
+          type: SPLIT_LINK_TYPE,
+          projectIds: [],
+          dateCreated: now,
+          lastModified: now,
+          revision: '01',
+        };
+        await journal.record(`${linkFolder}/${link.id}.md`);
+        await diskLinkService.save(link);
+      }
+
+      for (const project of projects) {
+        await journal.record(`${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`);
+        await ALL_ARTIFACT_SERVICES.projects.save({
+          ...project,
+          [membershipField!]: [...(project[membershipField!] as string[]), ...createdIds],
+          lastModified: now,
+        });
+      }
+    },
+    `Split ${artifactId} into ${parts.length} artifact(s)`
+  );
+  debug.info(`[ArtifactSplitService] Split ${artifactId} into ${createdIds.join(', ')}`);
+
+  return {
+    originalId: artifactId,
+    createdIds,
+    linkIds,
+    projectIds: projects.map((project) => project.id),
+  };
+}
+
+export const artifactSplitService = {
+  splitTextAtLines,
+  buildSplitPart,
+  splitArtifact,
+};
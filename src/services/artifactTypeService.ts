@@ -0,0 +1,206 @@
+/**
+ * Artifact Type Service
+ *
+ * Manages project-defined artifact types on top of the built-in ones in
+ * ARTIFACT_CONFIG. Custom types are listed in config/artifact-types.json and
+ * each stores its artifacts in its own folder; once loaded they are
+ * registered in ARTIFACT_CONFIG, so IDs, counters and lookups by folder or
+ * prefix work for them as for built-in types. Renaming or deleting a type
+ * moves or removes the folder's files as one journaled change in a single
+ * commit.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { projectConfigService } from './projectConfigService';
+import { runJournaled } from './writeJournalService';
+import {
+  ARTIFACT_CONFIG,
+  isBuiltInArtifactType,
+  registerCustomArtifactTypes,
+} from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+const TYPES_SECTION = 'artifact-types';
+const FOLDER_PATTERN = /^[a-z0-9][a-z0-9-]*$/;
+const PREFIX_PATTERN = /^[A-Z][A-Z0-9]*$/;
+
+// Folders of sidecar files and project data next to the artifact folders
+const RESERVED_FOLDERS = [
+  'approvals',
+  'assets',
+  'baselines',
+  'comments',
+  'config',
+  'counters',
+  'keys',
+  'quarantine',
+  'releases',
+  'saved-filters',
+  'testruns',
+];
+
+export interface CustomArtifactType {
+  key: string;
+  label: string;
+  idPrefix: string; // e.g. 'SPEC' for SPEC-001
+  folder: string;
+}
+
+interface ArtifactTypesConfig {
+  types: CustomArtifactType[];
+}
+
+const builtInConfigs = () =>
+  Object.entries(ARTIFACT_CONFIG)
+    .filter(([key]) => isBuiltInArtifactType(key))
+    .map(([, config]) => config);
+
+class ArtifactTypeService extends BaseDiskService {
+  /**
+   * All project-defined artifact types
+   */
+  async listCustomTypes(): Promise<CustomArtifactType[]> {
+    const config = await projectConfigService.getSection<ArtifactTypesConfig>(TYPES_SECTION, {
+      types: [],
+    });
+    return Array.isArray(config.types) ? config.types : [];
+  }
+
+  /**
+   * Read the project's custom types and register them in ARTIFACT_CONFIG;
+   * call when a project is (re)loaded
+   */
+  async loadCustomTypes(): Promise<CustomArtifactType[]> {
+    const types = await this.listCustomTypes();
+    registerCustomArtifactTypes(types);
+    return types;
+  }
+
+  private async getCustomType(key: string): Promise<CustomArtifactType> {
+    const type = (await this.listCustomTypes()).find((t) => t.key === key);
+    if (!type) {
+      const builtIn = isBuiltInArtifactType(key) ? ' (built-in types cannot be changed)' : '';
+      throw new Error(`Unknown custom artifact type: ${key}${builtIn}`);
+    }
+    return type;
+  }
+
+  private validateFolder(folder: string, types: CustomArtifactType[]): void {
+    if (!FOLDER_PATTERN.test(folder)) {
+      throw new Error(`Invalid folder name: ${folder}`);
+    }
+    if (
+      builtInConfigs().some((config) => config.folder === folder) ||
+      RESERVED_FOLDERS.includes(folder) ||
+      types.some((t) => t.folder === folder)
+    ) {
+      throw new Error(`Folder already in use: ${folder}`);
+    }
+  }
+
+  /**
+   * Register a new artifact type and create its folder
+   */
+  async createType(type: CustomArtifactType): Promise<CustomArtifactType> {
+    const types = await this.listCustomTypes();
+    if (!type.key || isBuiltInArtifactType(type.key) || types.some((t) => t.key === type.key)) {
+      throw new Error(`Artifact type already exists: ${type.key}`);
+    }
+    if (!PREFIX_PATTERN.test(type.idPrefix)) {
+      throw new Error(`Invalid ID prefix: ${type.idPrefix}`);
+    }
+    const prefixes = [...builtInConfigs(), ...types].map((t) => t.idPrefix);
+    if (prefixes.includes(type.idPrefix)) {
+      throw new Error(`ID prefix already in use: ${type.idPrefix}`);
+    }
+    this.validateFolder(type.folder, types);
+
+    await this.ensureDirectory(type.folder);
+    await projectConfigService.setSection<ArtifactTypesConfig>(
+      TYPES_SECTION,
+      { types: [...types, type] },
+      `Create artifact type ${type.label}`
+    );
+    registerCustomArtifactTypes([...types, type]);
+    debug.log(`[ArtifactTypeService] Created type ${type.key} in ${type.folder}/`);
+    return type;
+  }
+
+  /**
+   * Move a custom type's files to a new folder. The ID counter moves along so
+   * numbering continues; the emptied folder is left for the user to remove.
+   */
+  async renameTypeFolder(key: string, newFolder: string): Promise<CustomArtifactType> {
+    const types = await this.listCustomTypes();
+    const type = await this.getCustomType(key);
+    this.validateFolder(newFolder, types);
+
+    const renamed = { ...type, folder: newFolder };
+    const moves = (await this.listFiles(type.folder)).map((name) => [
+      `${type.folder}/${name}`,
+      `${newFolder}/${name}`,
+    ]);
+    moves.push([this.counterPath(type), this.counterPath(renamed)]);
+
+    const updated = types.map((t) => (t.key === key ? renamed : t));
+    await this.ensureDirectory(newFolder);
+    await runJournaled(
+      'Rename artifact type folder',
+      async (journal) => {
+        await journal.record(projectConfigService.getSectionPath(TYPES_SECTION));
+        await projectConfigService.writeSection<ArtifactTypesConfig>(TYPES_SECTION, {
+          types: updated,
+        });
+        for (const [source, target] of moves) {
+          const content = await this.readBinaryFile(source);
+          if (content === null) continue;
+          await journal.record(source);
+          await journal.record(target);
+          await this.writeBinaryFile(target, content);
+          await this.deleteFile(source);
+        }
+      },
+      `Rename ${type.label} folder to ${newFolder}`
+    );
+    registerCustomArtifactTypes(updated);
+    debug.log(`[ArtifactTypeService] Moved ${type.folder}/ to ${newFolder}/`);
+    return renamed;
+  }
+
+  /**
+   * Remove a custom type. Refuses while its folder still holds files unless
+   * forced, in which case the files are deleted in the same commit.
+   */
+  async deleteType(key: string, force: boolean = false): Promise<void> {
+    const types = await this.listCustomTypes();
+    const type = await this.getCustomType(key);
+    const files = (await this.listFiles(type.folder)).map((name) => `${type.folder}/${name}`);
+    if (files.length > 0 && !force) {
+      throw new Error(`Folder ${type.folder} still contains ${files.length} file(s)`);
+    }
+
+    const remaining = types.filter((t) => t.key !== key);
+    await runJournaled(
+      'Delete artifact type',
+      async (journal) => {
+        await journal.record(projectConfigService.getSectionPath(TYPES_SECTION));
+        await projectConfigService.writeSection<ArtifactTypesConfig>(TYPES_SECTION, {
+          types: remaining,
+        });
+        for (const file of files) {
+          await journal.record(file);
+          await this.deleteFile(file);
+        }
+      },
+      `Delete artifact type ${type.label}`
+    );
+    registerCustomArtifactTypes(remaining);
+    debug.log(`[ArtifactTypeService] Deleted type ${key}`);
+  }
+
+  private counterPath(type: CustomArtifactType): string {
+    return `counters/${type.folder}.md`;
+  }
+}
+
+export const artifactTypeService = new ArtifactTypeService();
@@ -1,8 +1,22 @@
 import { debug } from '../utils/debug';
 import { BaseDiskService } from './baseDiskService';
+import { isElectronEnv } from './git/types';
 
 const ASSETS_DIR = 'assets';
 
+// Served by the desktop app's main process, scoped to the open project
+const ASSET_PROTOCOL = 'tracyfy-asset:';
+
+/**
+ * URL of a project file on the desktop asset protocol, optionally as of a commit
+ */
+export function getAssetProtocolUrl(path: string, commitHash?: string): string {
+  const encoded = path.split('/').map(encodeURIComponent).join('/');
+  return commitHash
+    ? `${ASSET_PROTOCOL}//commit/${commitHash}/${encoded}`
+    : `${ASSET_PROTOCOL}//project/${encoded}`;
+}
+
 /**
  * Generate a UUID v4
  */
@@ -100,14 +114,24 @@ class AssetService extends BaseDiskService {
   }
 
   /**
-   * Get a blob URL for displaying an asset
+   * Get a URL for displaying an asset
    * @param relativePath - The relative path (e.g., ./assets/uuid.png)
-   * @returns A blob URL that can be used in img src
+   * @param commitHash - Show the asset as of this commit (desktop app only)
+   * @returns An asset protocol URL in the desktop app, otherwise a blob URL
    */
-  async getAssetUrl(relativePath: string): Promise<string | null> {
+  async getAssetUrl(relativePath: string, commitHash?: string): Promise<string | null> {
     // Normalize the path (remove leading ./)
     const normalizedPath = relativePath.replace(/^\.\//, '');
 
+    // Desktop: the main process serves the file directly
+    if (isElectronEnv()) {
+      return getAssetProtocolUrl(normalizedPath, commitHash);
+    }
+    if (commitHash) {
+      debug.warn(`[AssetService] Historical assets need the desktop app: ${relativePath}`);
+      return null;
+    }
+
     // Read the binary data
     const uint8Array = await this.readBinaryFile(normalizedPath);
     if (!uint8Array) {
@@ -0,0 +1,180 @@
+/**
+ * Attribute Schema Service
+ *
+ * Project-defined attributes per artifact type, kept in the project config
+ * (config/attribute-schema.json). Values live in the artifact's
+ * customAttributes, so they round-trip through the existing serializers.
+ * Provides validation, attribute listings and the field list forms are
+ * generated from (schema fields plus global custom attribute definitions).
+ */
+
+import type {
+  ApplicableArtifactType,
+  AttributeSchema,
+  AttributeSchemaField,
+  AttributeType,
+  CustomAttributeValue,
+} from '../types/customAttributes';
+import { projectConfigService } from './projectConfigService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { diskCustomAttributeService } from './diskCustomAttributeService';
+import {
+  validateAttributeSchema,
+  validateAttributeValues,
+  type AttributeViolation,
+} from '../utils/attributeSchemaUtils';
+
+const SCHEMA_SECTION = 'attribute-schema';
+
+// Artifact type keys and the custom attribute type names they correspond to
+export const SCHEMA_TYPES: Record<string, ApplicableArtifactType> = {
+  requirements: 'requirement',
+  usecases: 'useCase',
+  testcases: 'testCase',
+  information: 'information',
+  risks: 'risk',
+};
+
+export interface AttributeFormField {
+  attributeId: string; // Key in customAttributes
+  label: string;
+  type: AttributeType;
+  options?: string[];
+  required: boolean;
+  description?: string;
+  source: 'schema' | 'definition';
+}
+
+export interface ArtifactAttributeValues {
+  id: string;
+  title?: string;
+  values: Record<string, CustomAttributeValue['value']>; // null when not set
+}
+
+export interface ArtifactAttributeViolations {
+  artifactId: string;
+  violations: AttributeViolation[];
+}
+
+interface SchemaArtifact {
+  id: string;
+  title?: string;
+  isDeleted?: boolean;
+  customAttributes?: CustomAttributeValue[];
+}
+
+function assertSchemaType(typeKey: string): void {
+  if (!SCHEMA_TYPES[typeKey]) {
+    throw new Error(`Artifact type does not support attributes: ${typeKey}`);
+  }
+}
+
+/**
+ * The project's attribute schema
+ */
+export async function getAttributeSchema(): Promise<AttributeSchema> {
+  return projectConfigService.getSection<AttributeSchema>(SCHEMA_SECTION, {});
+}
+
+/**
+ * Replace the attribute schema after checking it
+ */
+export async function setAttributeSchema(schema: AttributeSchema): Promise<void> {
+  Object.keys(schema).forEach(assertSchemaType);
+  const errors = validateAttributeSchema(schema);
+  if (errors.length > 0) {
+    throw new Error(`Invalid attribute schema: ${errors.join('; ')}`);
+  }
+  await projectConfigService.setSection(SCHEMA_SECTION, schema, 'Update attribute schema');
+}
+
+/**
+ * Schema fields declared for an artifact type
+ */
+export async function getSchemaFields(typeKey: string): Promise<AttributeSchemaField[]> {
+  assertSchemaType(typeKey);
+  const schema = await getAttributeSchema();
+  return Array.isArray(schema[typeKey]) ? schema[typeKey] : [];
+}
+
+/**
+ * All attribute fields a form for the artifact type should show
+ */
+export async function getFormFields(typeKey: string): Promise<AttributeFormField[]> {
+  const [fields, definitions] = await Promise.all([
+    getSchemaFields(typeKey),
+    diskCustomAttributeService.getDefinitionsForArtifactType(SCHEMA_TYPES[typeKey]),
+  ]);
+
+  return [
+    ...fields.map(
+      (field): AttributeFormField => ({
+        attributeId: field.name,
+        label: field.name,
+        type: field.type,
+        options: field.options,
+        required: !!field.required,
+        description: field.description,
+        source: 'schema',
+      })
+    ),
+    ...definitions.map(
+      (def): AttributeFormField => ({
+        attributeId: def.id,
+        label: def.name,
+        type: def.type,
+        options: def.options,
+        required: !!def.required,
+        description: def.description,
+        source: 'definition',
+      })
+    ),
+  ];
+}
+
+/**
+ * Artifacts of a type whose attribute values violate the schema
+ */
+export async function validateTypeAttributes(
+  typeKey: string
+): Promise<ArtifactAttributeViolations[]> {
+  const fields = await getSchemaFields(typeKey);
+  if (fields.length === 0) return [];
+
+  const artifacts: SchemaArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+  return artifacts
+    .filter((a) => !a.isDeleted)
+    .map((a) => ({
+      artifactId: a.id,
+      violations: validateAttributeValues(a.customAttributes, fields),
+    }))
+    .filter((result) => result.violations.length > 0);
+}
+
+/**
+ * Schema attribute values of every artifact of a type, keyed by attribute name
+ */
+export async function listAttributeValues(typeKey: string): Promise<ArtifactAttributeValues[]> {
+  const fields = await getSchemaFields(typeKey);
+  const artifacts: SchemaArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+
+  return artifacts
+    .filter((a) => !a.isDeleted)
+    .map((a) => {
+      const byName = new Map((a.customAttributes || []).map((v) => [v.attributeId, v.value]));
+      return {
+        id: a.id,
+        title: a.title,
+        values: Object.fromEntries(fields.map((f) => [f.name, byName.get(f.name) ?? null])),
+      };
+    });
+}
+
+export const attributeSchemaService = {
+  getAttributeSchema,
+  setAttributeSchema,
+  getSchemaFields,
+  getFormFields,
+  validateTypeAttributes,
+  listAttributeValues,
+};
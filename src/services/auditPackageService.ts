@@ -0,0 +1,244 @@
+/**
+ * Audit Package Service
+ *
+ * Bundles the evidence for a baseline into one ZIP archive for external
+ * auditors: the artifacts as baselined, the requirements × test cases trace
+ * matrix, the approval records and the commit history of every artifact up
+ * to its baseline commit. manifest.json lists the SHA-256 hash of every file
+ * and is signed with an ECDSA P-256 key kept in the secret store; the public
+ * key ships with the package, so any later change to a file or the manifest
+ * is detectable.
+ */
+
+import type { ApprovalRecord, CommitInfo } from '../types';
+import { baselineService } from './baselineService';
+import { approvalService } from './approvalService';
+import { generateTraceMatrix, traceMatrixToTable } from './traceMatrixService';
+import { realGitService } from './realGitService';
+import { secretService } from './secretService';
+import { sha256Hex } from '../utils/contentHashUtils';
+import { createZip, type ZipEntry } from '../utils/zipUtils';
+import { saveFile } from '../utils/fileSaveUtils';
+import { debug } from '../utils/debug';
+
+export const AUDIT_PACKAGE_FORMAT = 'tracyfy-audit-package/1';
+export const AUDIT_SIGNING_SECRET = 'audit-signing-key';
+const MANIFEST_FILE = 'manifest.json';
+const SIGNATURE_FILE = 'manifest.sig';
+const PUBLIC_KEY_FILE = 'public-key.jwk';
+const MAX_AUDIT_LOG_DEPTH = 1000;
+
+const SIGNING_ALGORITHM = { name: 'ECDSA', namedCurve: 'P-256' };
+const SIGNATURE_ALGORITHM = { name: 'ECDSA', hash: 'SHA-256' };
+
+export interface AuditManifest {
+  format: string;
+  baseline: { id: string; name: string; version: string; tag?: string };
+  projectId: string;
+  createdAt: string;
+  signingKey: string; // SHA-256 fingerprint of the public key
+  files: { path: string; sha256: string; size: number }[];
+}
+
+export interface AuditPackage {
+  manifest: AuditManifest;
+  files: ZipEntry[]; // Evidence files followed by manifest, signature and public key
+  archive: Uint8Array;
+}
+
+function toBase64(bytes: Uint8Array): string {
+  return btoa(String.fromCharCode(...bytes));
+}
+
+function fromBase64(text: string): Uint8Array {
+  return Uint8Array.from(atob(text), (char) => char.charCodeAt(0));
+}
+
+async function hashBytes(data: Uint8Array): Promise<string> {
+  const digest = await globalThis.crypto.subtle.digest('SHA-256', data as BufferSource);
+  return Array.from(new Uint8Array(digest))
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('');
+}
+
+function toCsv(table: string[][]): string {
+  const escape = (value: string) =>
+    /[",\n]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value;
+  return table.map((row) => row.map(escape).join(',')).join('\n') + '\n';
+}
+
+async function fingerprint(publicJwk: JsonWebKey): Promise<string> {
+  const { crv, kty, x, y } = publicJwk;
+  return sha256Hex(JSON.stringify({ crv, kty, x, y }));
+}
+
+/**
+ * The package signing key, created and stored on first use
+ */
+async function getSigningKey(): Promise<{ privateKey: CryptoKey; publicJwk: JsonWebKey }> {
+  let privateJwk: JsonWebKey;
+  const stored = await secretService.getSecret(AUDIT_SIGNING_SECRET);
+  if (stored) {
+    privateJwk = JSON.parse(stored) as JsonWebKey;
+  } else {
+    const pair = (await globalThis.crypto.subtle.generateKey(SIGNING_ALGORITHM, true, [
+      'sign',
+      'verify',
+    ])) as CryptoKeyPair;
+    privateJwk = await globalThis.crypto.subtle.exportKey('jwk', pair.privateKey);
+    await secretService.storeSecret(AUDIT_SIGNING_SECRET, JSON.stringify(privateJwk));
+    debug.log('[AuditPackageService] Created audit signing key');
+  }
+
+  const { crv, kty, x, y } = privateJwk;
+  const privateKey = await globalThis.crypto.subtle.importKey(
+    'jwk',
+    privateJwk,
+    SIGNING_ALGORITHM,
+    false,
+    ['sign']
+  );
+  return { privateKey, publicJwk: { crv, kty, x, y, key_ops: ['verify'], ext: true } };
+}
+
+/**
+ * Collect, hash and sign the evidence for a baseline (by id, name, version
+ * or tag) and pack it into a ZIP archive
+ */
+export async function buildAuditPackage(
+  baselineName: string,
+  projectId: string
+): Promise<AuditPackage> {
+  const { baseline, artifacts } = await baselineService.getBaselineContents(
+    baselineName,
+    projectId
+  );
+  const encoder = new TextEncoder();
+  const files: ZipEntry[] = [];
+  const json = (value: unknown) => JSON.stringify(value, null, 2);
+
+  const approvals: Record<string, ApprovalRecord[]> = {};
+  const auditLog: Record<string, CommitInfo[]> = {};
+  for (const artifact of artifacts) {
+    if (artifact.content === null) continue;
+    files.push({ path: `artifacts/${artifact.path}`, data: artifact.content });
+
+    const records = await approvalService.listApprovals(artifact.path);
+    const before = records.filter((record) => record.timestamp <= baseline.timestamp);
+    if (before.length > 0) approvals[artifact.id] = before;
+    auditLog[artifact.id] = await realGitService.getHistory(
+      artifact.path,
+      MAX_AUDIT_LOG_DEPTH,
+      artifact.commitHash
+    );
+  }
+
+  const matrix = await generateTraceMatrix('requirements', 'testcases', projectId);
+  files.push(
+    { path: 'baseline.json', data: json(baseline) },
+    { path: 'trace-matrix.csv', data: toCsv(traceMatrixToTable(matrix)) },
+    { path: 'approvals.json', data: json(approvals) },
+    { path: 'audit-log.json', data: json(auditLog) }
+  );
+
+  const { privateKey, publicJwk } = await getSigningKey();
+  const hashed = await Promise.all(
+    files.map(async (file) => {
+      const data = typeof file.data === 'string' ? encoder.encode(file.data) : file.data;
+      return { path: file.path, sha256: await hashBytes(data), size: data.length };
+    })
+  );
+  const manifest: AuditManifest = {
+    format: AUDIT_PACKAGE_FORMAT,
+    baseline: {
+      id: baseline.id,
+      name: baseline.name,
+      version: baseline.version,
+      tag: baseline.tag,
+    },
+    projectId,
+    createdAt: new Date().toISOString(),
+    signingKey: await fingerprint(publicJwk),
+    files: hashed,
+  };
+  const manifestJson = json(manifest);
+  const signature = await globalThis.crypto.subtle.sign(
+    SIGNATURE_ALGORITHM,
+    privateKey,
+    encoder.encode(manifestJson)
+  );
+  files.push(
+    { path: MANIFEST_FILE, data: manifestJson },
+    { path: SIGNATURE_FILE, data: toBase64(new Uint8Array(signature)) },
+    { path: PUBLIC_KEY_FILE, data: json(publicJwk) }
+  );
+
+  debug.log(`[AuditPackageService] Packed ${hashed.length} files for ${baseline.name}`);
+  return { manifest, files, archive: createZip(files) };
+}
+
+/**
+ * Check an unpacked audit package: the manifest signature against the
+ * included public key and every listed file against its hash. Returns the
+ * problems found; an empty list means the package is intact.
+ */
+export async function verifyAuditPackage(files: Map<string, Uint8Array>): Promise<string[]> {
+  const decoder = new TextDecoder();
+  const manifestBytes = files.get(MANIFEST_FILE);
+  const signature = files.get(SIGNATURE_FILE);
+  const publicKey = files.get(PUBLIC_KEY_FILE);
+  if (!manifestBytes || !signature || !publicKey) {
+    return ['Manifest, signature or public key is missing'];
+  }
+
+  const key = await globalThis.crypto.subtle.importKey(
+    'jwk',
+    JSON.parse(decoder.decode(publicKey)) as JsonWebKey,
+    SIGNING_ALGORITHM,
+    false,
+    ['verify']
+  );
+  const valid = await globalThis.crypto.subtle.verify(
+    SIGNATURE_ALGORITHM,
+    key,
+    fromBase64(decoder.decode(signature)),
+    manifestBytes as BufferSource
+  );
+  if (!valid) return ['Manifest signature is invalid'];
+
+  const manifest = JSON.parse(decoder.decode(manifestBytes)) as AuditManifest;
+  const problems: string[] = [];
+  for (const entry of manifest.files) {
+    const data = files.get(entry.path);
+    if (!data) {
+      problems.push(`Missing file: ${entry.path}`);
+    } else if ((await hashBytes(data)) !== entry.sha256) {
+      problems.push(`Hash mismatch: ${entry.path}`);
+    }
+  }
+  return problems;
+}
+
+/**
+ * Build the audit package of a baseline and save it outside the repository
+ */
+export async function exportAuditPackage(
+  baselineName: string,
+  projectId: string,
+  projectName: string
+): Promise<AuditManifest> {
+  const { manifest, archive } = await buildAuditPackage(baselineName, projectId);
+  const safe = (text: string) => text.replace(/[^a-z0-9.-]/gi, '_');
+  await saveFile(
+    new Blob([archive as BlobPart], { type: 'application/zip' }),
+    `${safe(projectName)}_audit_${safe(manifest.baseline.version)}.zip`,
+    { description: 'ZIP Archive', accept: { 'application/zip': ['.zip'] } }
+  );
+  return manifest;
+}
+
+export const auditPackageService = {
+  buildAuditPackage,
+  verifyAuditPackage,
+  exportAuditPackage,
+};
@@ -0,0 +1,170 @@
+/**
+ * Author Profile Service
+ *
+ * Several identities (name, email, initials, signing key) for people who
+ * work on projects for different employers or customers. Profiles are kept
+ * in the app settings, so they are personal and never committed; each
+ * project remembers which profile is active. The active profile is who
+ * commits, approvals, comments and workflow transitions are attributed to,
+ * and its key signs approvals. Private keys stay in the secret store.
+ */
+
+import type { AuthorProfile } from '../types';
+import { appSettingsService } from './appSettingsService';
+import { diskProjectService } from './diskProjectService';
+import { secretService } from './secretService';
+import { generateSigningKey, type SigningKey } from '../utils/minisignUtils';
+import { debug } from '../utils/debug';
+
+export const DEFAULT_AUTHOR = { name: 'Tracyfy User', email: 'user@tracyfy.local' };
+
+export type AuthorProfileInput = Pick<AuthorProfile, 'id' | 'name' | 'email'> &
+  Partial<Pick<AuthorProfile, 'initials'>>;
+
+function getSigningSecretName(profileId: string): string {
+  return `author-signing-key-${profileId}`;
+}
+
+/**
+ * Initials from a name, e.g. "Ann-Marie Smith" -> "AMS"
+ */
+export function getInitials(name: string): string {
+  return (name.match(/\p{L}+/gu) || [])
+    .map((word) => word[0].toUpperCase())
+    .join('')
+    .slice(0, 3);
+}
+
+export async function listAuthorProfiles(): Promise<AuthorProfile[]> {
+  return (await appSettingsService.getAppSettings()).authorProfiles;
+}
+
+/**
+ * Add a profile, or update the one with the same ID; initials default to
+ * those of the name
+ */
+export async function saveAuthorProfile(input: AuthorProfileInput): Promise<AuthorProfile> {
+  const profiles = await listAuthorProfiles();
+  const existing = profiles.find((p) => p.id === input.id);
+  const profile: AuthorProfile = {
+    ...existing,
+    id: input.id,
+    name: input.name.trim(),
+    email: input.email.trim(),
+    initials: input.initials?.trim() || getInitials(input.name),
+  };
+  await appSettingsService.updateAppSettings({
+    authorProfiles: existing
+      ? profiles.map((p) => (p.id === profile.id ? profile : p))
+      : [...profiles, profile],
+  });
+  return profile;
+}
+
+/**
+ * Remove a profile, its signing key and its selection in every project
+ */
+export async function deleteAuthorProfile(profileId: string): Promise<void> {
+  const { authorProfiles, activeAuthorProfiles } = await appSettingsService.getAppSettings();
+  const profile = authorProfiles.find((p) => p.id === profileId);
+  if (!profile) throw new Error(`Author profile not found: ${profileId}`);
+
+  if (profile.signingKeyId) await secretService.deleteSecret(getSigningSecretName(profileId));
+  await appSettingsService.updateAppSettings({
+    authorProfiles: authorProfiles.filter((p) => p.id !== profileId),
+    activeAuthorProfiles: Object.fromEntries(
+      Object.entries(activeAuthorProfiles).filter(([, id]) => id !== profileId)
+    ),
+  });
+}
+
+/**
+ * Give a profile a new Ed25519 signing key, replacing any previous one
+ */
+export async function createProfileSigningKey(profileId: string): Promise<AuthorProfile> {
+  const profiles = await listAuthorProfiles();
+  const profile = profiles.find((p) => p.id === profileId);
+  if (!profile) throw new Error(`Author profile not found: ${profileId}`);
+
+  const key = await generateSigningKey();
+  await secretService.storeSecret(getSigningSecretName(profileId), JSON.stringify(key));
+  const updated = { ...profile, signingKeyId: key.keyId, publicKey: key.publicKey };
+  await appSettingsService.updateAppSettings({
+    authorProfiles: profiles.map((p) => (p.id === profileId ? updated : p)),
+  });
+  debug.log(`[AuthorProfileService] Created signing key ${key.keyId} for ${profileId}`);
+  return updated;
+}
+
+/**
+ * The signing key of a profile; null when it has none
+ */
+export async function getProfileSigningKey(profile: AuthorProfile): Promise<SigningKey | null> {
+  if (!profile.signingKeyId) return null;
+  const stored = await secretService.getSecret(getSigningSecretName(profile.id));
+  return stored ? (JSON.parse(stored) as SigningKey) : null;
+}
+
+/**
+ * Make a profile the active one of a project, or clear the selection with
+ * null so the project falls back to the current user
+ */
+export async function selectAuthorProfile(
+  projectId: string,
+  profileId: string | null
+): Promise<void> {
+  const { authorProfiles, activeAuthorProfiles } = await appSettingsService.getAppSettings();
+  if (profileId && !authorProfiles.some((p) => p.id === profileId)) {
+    throw new Error(`Author profile not found: ${profileId}`);
+  }
+  const selected = { ...activeAuthorProfiles };
+  if (profileId) selected[projectId] = profileId;
+  else delete selected[projectId];
+  await appSettingsService.updateAppSettings({ activeAuthorProfiles: selected });
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(
+      new CustomEvent('author-profile-changed', { detail: { projectId, profileId } })
+    );
+  }
+}
+
+/**
+ * The profile active in a project (the current one by default); null when
+ * none is selected
+ */
+export async function getActiveAuthorProfile(projectId?: string): Promise<AuthorProfile | null> {
+  const { authorProfiles, activeAuthorProfiles } = await appSettingsService.getAppSettings();
+  if (authorProfiles.length === 0) return null;
+  const project = projectId ?? (await diskProjectService.getCurrentProjectId());
+  const profileId = project ? activeAuthorProfiles[project] : undefined;
+  return authorProfiles.find((p) => p.id === profileId) ?? null;
+}
+
+/**
+ * Name and email a commit is made with: the active profile's, or the
+ * built-in identity. An author name that is not the profile's keeps the
+ * built-in email.
+ */
+export async function getCommitAuthor(
+  authorName?: string
+): Promise<{ name: string; email: string }> {
+  let profile: AuthorProfile | null = null;
+  try {
+    profile = await getActiveAuthorProfile();
+  } catch (err) {
+    debug.log('[AuthorProfileService] Could not read the active profile:', err);
+  }
+  const name = authorName || profile?.name || DEFAULT_AUTHOR.name;
+  return { name, email: profile && name === profile.name ? profile.email : DEFAULT_AUTHOR.email };
+}
+
+export const authorProfileService = {
+  listAuthorProfiles,
+  saveAuthorProfile,
+  deleteAuthorProfile,
+  createProfileSigningKey,
+  getProfileSigningKey,
+  selectAuthorProfile,
+  getActiveAuthorProfile,
+  getCommitAuthor,
+};
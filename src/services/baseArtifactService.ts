@@ -1,11 +1,18 @@
 /**
  * Base Artifact Service
  *
- * Provides generic CRUD operations for all artifact types.
+ * Provides generic CRUD operations for all artifact types. Saving and
+ * deleting check the acting identity's project role (see permissionService),
+ * so every caller is held to it, not only the editing UI.
  */
 
 import { BaseDiskService } from './baseDiskService';
-import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { getCachedParse, setCachedParse } from './parseCacheService';
+import { mapWithConcurrency } from '../utils/concurrencyUtils';
+import { ARTIFACT_CONFIG, registerArtifactIdType } from '../constants/artifactConfig';
+import { artifactLockService } from './artifactLockService';
+import { recordBeforeChange } from './undoService';
+import type { PermissionAction } from './permissionService';
 import { debug } from '../utils/debug';
 
 export interface ArtifactSerializer<T> {
@@ -13,6 +20,9 @@ export interface ArtifactSerializer<T> {
   deserialize: (content: string) => T | null;
 }
 
+// Types that describe the workspace rather than project content; roles do not cover them
+const UNGUARDED_TYPES = ['users', 'projects'];
+
 export class BaseArtifactService<T extends { id: string }> extends BaseDiskService {
   private typeKey: string;
   private serializer: ArtifactSerializer<T>;
@@ -64,7 +74,7 @@ export class BaseArtifactService<T extends { id: string }> extends BaseDiskServi
     });
   }
 
-  private getFilePath(id: string): string {
+  protected getFilePath(id: string): string {
     const folder = this.config.folder;
     // Standardize on .md for all text-based storage including counters
     return `${folder}/${id}.md`;
@@ -78,11 +88,43 @@ export class BaseArtifactService<T extends { id: string }> extends BaseDiskServi
   }
 
   /**
-   * Save an artifact to disk and optionally commit to git
+   * Warn when writing an artifact another user has locked.
+   * Locks are advisory, so the write still goes ahead.
    */
-  async save(item: T, commitMessage?: string): Promise<T> {
+  private async warnIfLocked(id: string): Promise<void> {
+    try {
+      const lock = await artifactLockService.getConflictingLock(id);
+      if (!lock) return;
+      console.warn(`[BaseArtifactService] Saving ${id} while it is locked by ${lock.owner}`);
+      if (typeof window !== 'undefined') {
+        window.dispatchEvent(new CustomEvent('artifact-lock-conflict', { detail: lock }));
+      }
+    } catch (err) {
+      debug.log(`[BaseArtifactService] Could not check lock of ${id}:`, err);
+    }
+  }
+
+  /**
+   * Fail with a PermissionError unless the acting identity may take the
+   * action on artifacts of this type
+   */
+  private async checkPermission(action: PermissionAction): Promise<void> {
+    if (UNGUARDED_TYPES.includes(this.typeKey)) return;
+    // Loaded lazily: permissionService uses the artifact services built on this class
+    const { checkPermission } = await import('./permissionService');
+    await checkPermission(action);
+  }
+
+  /**
+   * Save an artifact to disk and optionally commit to git. Needs the role for
+   * the action: editing by default, approving for a change to approved.
+   */
+  async save(item: T, commitMessage?: string, action: PermissionAction = 'edit'): Promise<T> {
+    await this.checkPermission(action);
     const path = this.getFilePath(item.id);
     const content = this.serializer.serialize(item);
+    await this.warnIfLocked(item.id);
+    await recordBeforeChange(path);
     await this.writeTextFile(path, content, commitMessage);
     debug.log(`[BaseArtifactService] Saved ${this.typeKey}: ${item.id}`);
     this.notify();
@@ -93,7 +135,9 @@ export class BaseArtifactService<T extends { id: string }> extends BaseDiskServi
    * Delete an artifact from disk and optionally commit to git
    */
   async delete(id: string, commitMessage?: string): Promise<void> {
+    await this.checkPermission('edit');
     const path = this.getFilePath(id);
+    await recordBeforeChange(path);
     await this.deleteFile(path, commitMessage);
     debug.log(`[BaseArtifactService] Deleted ${this.typeKey}: ${id}`);
     this.notify();
@@ -126,21 +170,30 @@ export class BaseArtifactService<T extends { id: string }> extends BaseDiskServi
 
     try {
       const files = await this.listFiles(folder);
-      const mdFiles = files.filter((file) => file.endsWith(extension));
-
-      // Read all files in parallel for speed
-      const contents = await Promise.all(
-        mdFiles.map((file) => this.readTextFile(`${folder}/${file}`))
-      );
+      const paths = files
+        .filter((file) => file.endsWith(extension))
+        .map((file) => `${folder}/${file}`);
+
+      // Files unchanged since they were last parsed come from the cache; the
+      // rest are read several at a time for speed
+      const stats = await this.statFiles(paths);
+      const parsed = await mapWithConcurrency(paths, async (path) => {
+        const stat = stats[path];
+        const cached = stat ? getCachedParse<T | null>(path, stat) : undefined;
+        if (cached !== undefined) return cached;
+        const content = await this.readTextFile(path);
+        const item = content ? this.serializer.deserialize(content) : null;
+        if (stat) setCachedParse(path, stat, item);
+        return item;
+      });
 
       const items: T[] = [];
-      for (const content of contents) {
-        if (content) {
-          const item = this.serializer.deserialize(content);
-          if (item) {
-            if (!includeDeleted && (item as { isDeleted?: boolean }).isDeleted) continue;
-            items.push(item);
-          }
+      for (const item of parsed) {
+        if (item) {
+          // The folder, not the ID prefix, says what type an artifact is
+          registerArtifactIdType(item.id, this.typeKey);
+          if (!includeDeleted && (item as { isDeleted?: boolean }).isDeleted) continue;
+          items.push(item);
         }
       }
 
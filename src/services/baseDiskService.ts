@@ -5,9 +5,31 @@
  * Serves as a base for all other services that require disk storage.
  */
 
-import { fileSystemService } from './fileSystemService';
+import { fileSystemService, type FileStat } from './fileSystemService';
+import { invalidateParseCache } from './parseCacheService';
 import { realGitService } from './realGitService';
+import { runJournaled } from './writeJournalService';
 import { debug } from '../utils/debug';
+import {
+  decryptText,
+  encryptText,
+  isEncryptedContent,
+  isEncryptedPath,
+} from '../utils/encryptionUtils';
+
+export interface FileChange {
+  path: string;
+  content: string;
+}
+
+// Key for encryption at rest of the open project (see encryptionService); null when off
+let encryptionKey: string | null = null;
+
+export function setEncryptionKey(key: string | null): void {
+  encryptionKey = key;
+  // Files parsed with the old key would now read differently
+  invalidateParseCache();
+}
 
 export class BaseDiskService {
   /**
@@ -60,22 +82,62 @@ export class BaseDiskService {
   }
 
   /**
-   * Read a text file with a default value
+   * Modification time and size of files, keyed by path; empty when unavailable
+   */
+  async statFiles(paths: string[]): Promise<Record<string, FileStat | null>> {
+    try {
+      return await fileSystemService.statFiles(paths);
+    } catch (err) {
+      debug.log('[BaseDiskService] Failed to stat files:', err);
+      return {};
+    }
+  }
+
+  /**
+   * Read a text file with a default value, decrypting encrypted files
    */
   async readTextFile(path: string, defaultValue: string = ''): Promise<string> {
+    let content: string | null;
     try {
-      const content = await fileSystemService.readFile(path);
-      return content !== null ? content.trim() : defaultValue;
+      content = await fileSystemService.readFile(path);
     } catch (err) {
       debug.log(`[BaseDiskService] Failed to read file ${path}, using default.`, err);
       return defaultValue;
     }
+    if (content === null) return defaultValue;
+    if (!isEncryptedContent(content)) return content.trim();
+
+    // Never fall back to the default here: saving it would overwrite the encrypted file
+    if (!encryptionKey) {
+      throw new Error(`${path} is encrypted and no encryption key is loaded`);
+    }
+    return (await decryptText(content, encryptionKey)).trim();
   }
 
   /**
-   * Write a text file and optionally commit it to Git
+   * Read a file as of a commit, decrypting it when it was committed
+   * encrypted; null when it did not exist then
    */
-  async writeTextFile(path: string, content: string, commitMessage?: string): Promise<void> {
+  async readTextFileAtCommit(path: string, commitHash: string): Promise<string | null> {
+    const content = await realGitService.readFileAtCommit(path, commitHash);
+    if (content === null || !isEncryptedContent(content)) return content;
+    if (!encryptionKey) {
+      throw new Error(`${path} is encrypted and no encryption key is loaded`);
+    }
+    return decryptText(content, encryptionKey);
+  }
+
+  /**
+   * Write a text file and optionally commit it to Git. Artifact files are
+   * encrypted while an encryption key is set; a copy of an artifact kept
+   * elsewhere passes the artifact's path as encryptAs to be encrypted alike.
+   */
+  async writeTextFile(
+    path: string,
+    content: string,
+    commitMessage?: string,
+    encryptAs: string = path
+  ): Promise<void> {
     try {
       // Ensure parent directory exists
       const lastSlash = path.lastIndexOf('/');
@@ -84,7 +146,8 @@ export class BaseDiskService {
         await this.ensureDirectory(dir);
       }
 
-      await fileSystemService.writeFile(path, content);
+      const key = isEncryptedPath(encryptAs) ? encryptionKey : null;
+      await fileSystemService.writeFile(path, key ? await encryptText(content, key) : content);
 
       if (commitMessage) {
         await realGitService.commitFile(path, commitMessage);
@@ -95,6 +158,32 @@ export class BaseDiskService {
     }
   }
 
+  /**
+   * Write several text files as one unit and commit them in a single commit.
+   * The write is journaled: if any write or the commit fails, or the app
+   * crashes midway, files already written are restored (or removed if they
+   * were new), so nothing is half-updated.
+   */
+  async writeTextFilesAndCommit(changes: FileChange[], commitMessage: string): Promise<void> {
+    if (changes.length === 0) return;
+    const paths = changes.map((change) => change.path);
+    const duplicate = paths.find((path, i) => paths.indexOf(path) !== i);
+    if (duplicate) {
+      throw new Error(`Duplicate path in batch write: ${duplicate}`);
+    }
+
+    await runJournaled(
+      'Batch write',
+      async (journal) => {
+        for (const change of changes) {
+          await journal.record(change.path);
+          await this.writeTextFile(change.path, change.content);
+        }
+      },
+      commitMessage
+    );
+  }
+
   /**
    * Delete a file and optionally commit the deletion
    */
@@ -112,12 +201,13 @@ export class BaseDiskService {
   }
 
   /**
-   * Read a JSON file
+   * Read a JSON file, decrypting encrypted files (e.g. comment sidecars)
    */
   async readJsonFile<T>(path: string, defaultValue: T | null = null): Promise<T | null> {
+    // Outside the try: an encrypted file without a key must not read as the default
+    const content = await this.readTextFile(path);
+    if (!content) return defaultValue;
     try {
-      const content = await fileSystemService.readFile(path);
-      if (content === null) return defaultValue;
       return JSON.parse(content) as T;
     } catch (err) {
       debug.log(`[BaseDiskService] Failed to read JSON file ${path}:`, err);
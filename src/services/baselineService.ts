@@ -0,0 +1,181 @@
+/**
+ * Baseline Service
+ *
+ * Baselines as a first-class concept on top of git tags. Creating a baseline
+ * records which artifacts of a project it includes, the commit each was last
+ * changed in and a content hash manifest in baselines/baseline-<id>.json,
+ * commits that file and marks the commit with an annotated tag
+ * (baseline/<projectId>/<version>). The contents of a baseline are read back
+ * from those commits and checked against the manifest. Artifacts are read
+ * decrypted, so in an encrypted project the manifest hashes the plain text.
+ */
+
+import type { ProjectBaseline } from '../types';
+import { baseDiskService } from './baseDiskService';
+import { diskBaselineService } from './diskBaselineService';
+import { projectService } from './artifactServices';
+import { PROJECT_ID_FIELDS } from './markdownImportService';
+import { checkPermission } from './permissionService';
+import { realGitService } from './realGitService';
+import { WORKFLOW_TYPES } from './statusWorkflowService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { addTrailer } from '../utils/commitTrailerUtils';
+import { computeContentHash } from '../utils/contentHashUtils';
+import type { ImportTypeKey } from '../utils/markdownImportUtils';
+import { debug } from '../utils/debug';
+
+export const BASELINE_TRAILER = 'Baseline-Id';
+
+type BaselineArtifactType = ProjectBaseline['artifactCommits'][string]['type'];
+
+export interface CreateBaselineOptions {
+  projectId: string;
+  version: string;
+  approver?: string;
+}
+
+export interface BaselineArtifact {
+  id: string;
+  type: BaselineArtifactType;
+  path: string;
+  commitHash: string;
+  content: string | null; // Null when the file cannot be read at its commit
+  intact: boolean; // Content still matches the hash recorded in the manifest
+}
+
+export interface BaselineContents {
+  baseline: ProjectBaseline;
+  artifacts: BaselineArtifact[];
+}
+
+export function getBaselineTagName(projectId: string, version: string): string {
+  return `baseline/${projectId}/${version}`;
+}
+
+export function getArtifactPath(id: string, type: string): string {
+  const config = Object.values(ARTIFACT_CONFIG).find((c) => c.type === type);
+  if (!config) {
+    throw new Error(`Unknown artifact type in baseline: ${type}`);
+  }
+  return `${config.folder}/${id}.md`;
+}
+
+/**
+ * Baselines of a project (or all), newest first
+ */
+export async function listBaselines(projectId?: string): Promise<ProjectBaseline[]> {
+  return diskBaselineService.loadBaselines(projectId);
+}
+
+/**
+ * Record the committed state of a project's artifacts as a baseline and tag
+ * it; requires the approver role where roles are assigned
+ */
+export async function createBaseline(
+  name: string,
+  description: string,
+  options: CreateBaselineOptions
+): Promise<ProjectBaseline> {
+  const { projectId, version, approver } = options;
+  if (!/^[A-Za-z0-9][A-Za-z0-9._-]*$/.test(version) || version.includes('..')) {
+    throw new Error(`Invalid baseline version: ${version}`);
+  }
+  const project = await projectService.load(projectId);
+  if (!project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+  await checkPermission('baseline', projectId);
+  const existing = await listBaselines(projectId);
+  if (existing.some((b) => b.version === version)) {
+    throw new Error(`Baseline ${version} already exists`);
+  }
+
+  const artifactCommits: ProjectBaseline['artifactCommits'] = {};
+  const manifest: Record<string, string> = {};
+  for (const typeKey of WORKFLOW_TYPES) {
+    const { folder, type } = ARTIFACT_CONFIG[typeKey];
+    for (const id of project[PROJECT_ID_FIELDS[typeKey as ImportTypeKey]] || []) {
+      const path = `${folder}/${id}.md`;
+      const [commit] = await realGitService.getHistory(path, 1);
+      // Never committed, or deleted in its last commit
+      const content = commit ? await baseDiskService.readTextFileAtCommit(path, commit.hash) : null;
+      if (!commit || content === null) continue;
+
+      artifactCommits[id] = { commitHash: commit.hash, type: type as BaselineArtifactType };
+      manifest[id] = await computeContentHash(content);
+    }
+  }
+
+  const previous = existing[0]?.artifactCommits ?? {};
+  const tag = getBaselineTagName(projectId, version);
+  const baseline: ProjectBaseline = {
+    id: `bl-${Date.now()}`,
+    projectId,
+    name,
+    description,
+    version,
+    timestamp: Date.now(),
+    artifactCommits,
+    addedArtifacts: Object.keys(artifactCommits).filter((id) => !(id in previous)),
+    removedArtifacts: Object.keys(previous).filter((id) => !(id in artifactCommits)),
+    ...(approver ? { approver } : {}),
+    tag,
+    manifest,
+  };
+
+  await diskBaselineService.saveBaseline(baseline);
+  const message = description.trim()
+    ? `Baseline ${name} (${version})\n\n${description.trim()}`
+    : `Baseline ${name} (${version})`;
+  await realGitService.createTag(tag, addTrailer(message, BASELINE_TRAILER, baseline.id));
+  debug.log(`[BaselineService] Created ${tag} with ${Object.keys(manifest).length} artifacts`);
+  return baseline;
+}
+
+/**
+ * Delete a baseline's record; like creating one, this requires the approver
+ * role where roles are assigned. The tag of the baseline is kept.
+ */
+export async function deleteBaseline(id: string): Promise<void> {
+  const baseline = (await listBaselines()).find((b) => b.id === id);
+  if (!baseline) {
+    throw new Error(`Baseline not found: ${id}`);
+  }
+  await checkPermission('baseline', baseline.projectId);
+  await diskBaselineService.deleteBaseline(id);
+}
+
+/**
+ * The artifacts of a baseline (found by id, name, version or tag) as they
+ * were when it was created
+ */
+export async function getBaselineContents(
+  name: string,
+  projectId?: string
+): Promise<BaselineContents> {
+  const baseline = (await listBaselines(projectId)).find((b) =>
+    [b.id, b.name, b.version, b.tag].includes(name)
+  );
+  if (!baseline) {
+    throw new Error(`Baseline not found: ${name}`);
+  }
+
+  const artifacts = await Promise.all(
+    Object.entries(baseline.artifactCommits).map(async ([id, { commitHash, type }]) => {
+      const path = getArtifactPath(id, type);
+      const content = await baseDiskService.readTextFileAtCommit(path, commitHash);
+      const expected = baseline.manifest?.[id];
+      const intact =
+        content !== null && (!expected || expected === (await computeContentHash(content)));
+      return { id, type, path, commitHash, content, intact };
+    })
+  );
+  return { baseline, artifacts };
+}
+
+export const baselineService = {
+  listBaselines,
+  createBaseline,
+  deleteBaseline,
+  getBaselineContents,
+};
@@ -0,0 +1,222 @@
+/**
+ * Baseline Signature Service
+ *
+ * Signs the manifest of a baseline (the path and content hash of every
+ * artifact in it) with an Ed25519 key, so a delivered baseline can be proven
+ * authentic long after the fact. Signing writes the manifest text, its
+ * minisign signature and the signer's public key to the repository and
+ * commits them together; the three files are all the minisign tool needs to
+ * check the signature without the app. Verifying checks the signature, that
+ * the baseline record still matches the signed manifest and that the
+ * baselined content still matches its hashes.
+ *
+ * Anyone who can commit can also replace the public key in the repository,
+ * so a signature only counts when its key is trusted: given by the caller or
+ * pinned in the app settings. Signing pins the signer's own key.
+ */
+
+import type { ProjectBaseline } from '../types';
+import { baseDiskService } from './baseDiskService';
+import { getArtifactPath, getBaselineContents } from './baselineService';
+import { secretService } from './secretService';
+import { appSettingsService } from './appSettingsService';
+import {
+  generateSigningKey,
+  getPublicKeyId,
+  getSignatureKeyId,
+  signMinisign,
+  verifyMinisign,
+  type SigningKey,
+} from '../utils/minisignUtils';
+import { debug } from '../utils/debug';
+
+export const BASELINE_SIGNING_SECRET = 'baseline-signing-key';
+const BASELINES_DIR = 'baselines';
+const KEYS_DIR = 'keys';
+
+export interface SignBaselineOptions {
+  signer?: string; // Recorded in the signed trusted comment
+  key?: SigningKey; // The stored baseline signing key when omitted
+}
+
+export interface BaselineSignature {
+  baselineId: string;
+  keyId: string;
+  manifestPath: string;
+  signaturePath: string;
+  publicKeyPath: string;
+}
+
+export interface BaselineSignatureCheck {
+  baselineId: string;
+  signed: boolean;
+  trusted: boolean; // Signed with a given or pinned key, not just the one in the repository
+  valid: boolean; // Signed with a trusted key, and no problems found
+  keyId: string | null;
+  trustedComment: string | null;
+  problems: string[];
+}
+
+export function getManifestPath(baselineId: string): string {
+  return `${BASELINES_DIR}/baseline-${baselineId}.manifest`;
+}
+
+export function getSignaturePath(baselineId: string): string {
+  return `${getManifestPath(baselineId)}.minisig`;
+}
+
+export function getPublicKeyPath(keyId: string): string {
+  return `${KEYS_DIR}/${keyId}.pub`;
+}
+
+/**
+ * The text that is signed: the baseline's identity, then one
+ * "<content hash>  <path>" line per artifact, sorted by path
+ */
+export function getBaselineManifestText(baseline: ProjectBaseline): string {
+  if (!baseline.manifest) {
+    throw new Error(`Baseline ${baseline.version} has no content manifest to sign`);
+  }
+  const entries = Object.entries(baseline.artifactCommits)
+    .map(([id, { type }]) => ({ path: getArtifactPath(id, type), hash: baseline.manifest?.[id] }))
+    .sort((a, b) => (a.path < b.path ? -1 : 1));
+  return [
+    `baseline ${baseline.id}`,
+    `project ${baseline.projectId}`,
+    `version ${baseline.version}`,
+    ...entries.map(({ path, hash }) => `${hash ?? '-'}  ${path}`),
+  ].join('\n');
+}
+
+/**
+ * The baseline signing key, created and stored on first use
+ */
+async function getSigningKey(): Promise<SigningKey> {
+  const stored = await secretService.getSecret(BASELINE_SIGNING_SECRET);
+  if (stored) return JSON.parse(stored) as SigningKey;
+
+  const key = await generateSigningKey();
+  await secretService.storeSecret(BASELINE_SIGNING_SECRET, JSON.stringify(key));
+  debug.log(`[BaselineSignatureService] Created baseline signing key ${key.keyId}`);
+  return key;
+}
+
+/**
+ * Pin a minisign public key in the app settings, so signatures made with it
+ * are trusted. Returns its key ID, or null when the user declines to trust it.
+ */
+export async function trustSigningKey(publicKey: string): Promise<string | null> {
+  const keyId = getPublicKeyId(publicKey);
+  if (await findTrustedKey(keyId)) return keyId;
+  return (await appSettingsService.trustSigningKey(publicKey)) ? keyId : null;
+}
+
+// The pinned public key with the ID, or null
+async function findTrustedKey(keyId: string): Promise<string | null> {
+  const { trustedSigningKeys } = await appSettingsService.getAppSettings();
+  return trustedSigningKeys.find((key) => getPublicKeyId(key) === keyId) ?? null;
+}
+
+/**
+ * Sign the manifest of a baseline (by id, name, version or tag) and commit
+ * manifest, signature and public key
+ */
+export async function signBaseline(
+  name: string,
+  projectId?: string,
+  options: SignBaselineOptions = {}
+): Promise<BaselineSignature> {
+  const { baseline } = await getBaselineContents(name, projectId);
+  const manifest = getBaselineManifestText(baseline);
+  const key = options.key ?? (await getSigningKey());
+
+  const comment = [
+    `timestamp:${Math.floor(Date.now() / 1000)}`,
+    `baseline:${baseline.tag ?? baseline.id}`,
+    ...(options.signer ? [`signer:${options.signer.replace(/\s+/g, ' ')}`] : []),
+  ].join('\t');
+  const signature = await signMinisign(manifest, key, comment);
+
+  const result: BaselineSignature = {
+    baselineId: baseline.id,
+    keyId: key.keyId,
+    manifestPath: getManifestPath(baseline.id),
+    signaturePath: getSignaturePath(baseline.id),
+    publicKeyPath: getPublicKeyPath(key.keyId),
+  };
+  await baseDiskService.writeTextFilesAndCommit(
+    [
+      { path: result.manifestPath, content: manifest },
+      { path: result.signaturePath, content: signature },
+      { path: result.publicKeyPath, content: key.publicKey },
+    ],
+    `Sign baseline ${baseline.name} (${baseline.version})`
+  );
+  await trustSigningKey(key.publicKey);
+  debug.log(`[BaselineSignatureService] Signed ${baseline.id} with key ${key.keyId}`);
+  return result;
+}
+
+/**
+ * Check the signature of a baseline against the given trusted key, else the
+ * pinned key with the signature's key ID. Without either, the key committed
+ * under that ID is used to check the signature, but the baseline is reported
+ * as not trusted and not valid.
+ */
+export async function verifyBaselineSignature(
+  name: string,
+  projectId?: string,
+  trustedPublicKey?: string
+): Promise<BaselineSignatureCheck> {
+  const { baseline, artifacts } = await getBaselineContents(name, projectId);
+  const manifest = await baseDiskService.readTextFile(getManifestPath(baseline.id));
+  const signature = await baseDiskService.readTextFile(getSignaturePath(baseline.id));
+  const check: BaselineSignatureCheck = {
+    baselineId: baseline.id,
+    signed: Boolean(manifest && signature),
+    trusted: false,
+    valid: false,
+    keyId: null,
+    trustedComment: null,
+    problems: [],
+  };
+  if (!check.signed) {
+    check.problems.push(`Baseline ${baseline.version} is not signed`);
+    return check;
+  }
+
+  const signatureKeyId = getSignatureKeyId(signature);
+  let publicKey = trustedPublicKey ?? (signatureKeyId && (await findTrustedKey(signatureKeyId)));
+  check.trusted = Boolean(publicKey);
+  if (!publicKey) {
+    publicKey = signatureKeyId
+      ? await baseDiskService.readTextFile(getPublicKeyPath(signatureKeyId))
+      : '';
+  }
+  const result = await verifyMinisign(manifest, signature, publicKey);
+  check.keyId = result.keyId;
+  check.trustedComment = result.trustedComment;
+  if (!result.valid) check.problems.push(`Signature is invalid: ${result.error}`);
+  if (!check.trusted && signatureKeyId) {
+    check.problems.push(`Key ${signatureKeyId} is not trusted; only the repository vouches for it`);
+  }
+
+  if (!baseline.manifest || getBaselineManifestText(baseline) !== manifest) {
+    check.problems.push('Baseline record differs from the signed manifest');
+  }
+  for (const artifact of artifacts) {
+    if (!artifact.intact) {
+      check.problems.push(`${artifact.path} no longer matches the signed manifest`);
+    }
+  }
+
+  check.valid = check.problems.length === 0;
+  return check;
+}
+
+export const baselineSignatureService = {
+  getBaselineManifestText,
+  signBaseline,
+  trustSigningKey,
+  verifyBaselineSignature,
+};
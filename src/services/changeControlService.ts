@@ -0,0 +1,211 @@
+/**
+ * Change Control Service
+ *
+ * Change requests (CR-xxx) describe a planned modification of requirements.
+ * They carry the requirements they affect, follow their own configurable
+ * status workflow, and commits made under a CR are tagged with a
+ * `Change-Request: CR-xxx` trailer so the CR's changes can be reported.
+ */
+
+import type { ChangeRequest, CommitInfo, StatusWorkflowConfig } from '../types';
+import { changeRequestService, requirementService } from './artifactServices';
+import { idService } from './idService';
+import { projectConfigService } from './projectConfigService';
+import { getAllowedTransitions, getCurrentUserName } from './statusWorkflowService';
+import { realGitService } from './realGitService';
+import {
+  addTrailer,
+  CHANGE_REQUEST_TRAILER,
+  getChangeRequestIds,
+} from '../utils/commitTrailerUtils';
+import { debug } from '../utils/debug';
+
+const CHANGE_REQUEST_WORKFLOW_SECTION = 'change-request-workflow';
+
+export const DEFAULT_CHANGE_REQUEST_WORKFLOW: StatusWorkflowConfig = {
+  statuses: ['open', 'in-review', 'approved', 'rejected', 'implemented', 'closed'],
+  initialStatus: 'open',
+  transitions: {
+    open: ['in-review', 'rejected'],
+    'in-review': ['approved', 'rejected', 'open'],
+    approved: ['implemented'],
+    rejected: ['open'],
+    implemented: ['closed'],
+    closed: [],
+  },
+};
+
+export interface NewChangeRequest {
+  title: string;
+  description?: string;
+  reason?: string;
+  priority?: string;
+  author?: string;
+  affectedRequirementIds?: string[];
+}
+
+export interface ChangeRequestCommit extends CommitInfo {
+  files: string[];
+}
+
+async function loadChangeRequest(id: string): Promise<ChangeRequest> {
+  const changeRequest = await changeRequestService.load(id);
+  if (!changeRequest || changeRequest.isDeleted) {
+    throw new Error(`Change request not found: ${id}`);
+  }
+  return changeRequest;
+}
+
+async function assertRequirementsExist(requirementIds: string[]): Promise<void> {
+  const existing = new Set((await requirementService.loadAll()).map((r) => r.id));
+  const missing = requirementIds.filter((id) => !existing.has(id));
+  if (missing.length > 0) {
+    throw new Error(`Unknown requirements: ${missing.join(', ')}`);
+  }
+}
+
+/**
+ * Load the change request workflow from the project config
+ */
+export async function getChangeRequestWorkflow(): Promise<StatusWorkflowConfig> {
+  return projectConfigService.getSection(
+    CHANGE_REQUEST_WORKFLOW_SECTION,
+    DEFAULT_CHANGE_REQUEST_WORKFLOW
+  );
+}
+
+/**
+ * Create a change request in the initial workflow status
+ */
+export async function createChangeRequest(input: NewChangeRequest): Promise<ChangeRequest> {
+  const affectedRequirementIds = [...new Set(input.affectedRequirementIds || [])];
+  await assertRequirementsExist(affectedRequirementIds);
+
+  const workflow = await getChangeRequestWorkflow();
+  const now = Date.now();
+  const changeRequest: ChangeRequest = {
+    id: await idService.getNextIdWithSync('changeRequests'),
+    title: input.title,
+    description: input.description || '',
+    reason: input.reason,
+    priority: input.priority || 'medium',
+    author: input.author,
+    status: workflow.initialStatus,
+    affectedRequirementIds,
+    dateCreated: now,
+    lastModified: now,
+    revision: '01',
+  };
+  return changeRequestService.save(changeRequest);
+}
+
+/**
+ * Replace the list of requirements a change request modifies
+ */
+export async function setAffectedRequirements(
+  changeRequestId: string,
+  requirementIds: string[]
+): Promise<ChangeRequest> {
+  const changeRequest = await loadChangeRequest(changeRequestId);
+  const affectedRequirementIds = [...new Set(requirementIds)];
+  await assertRequirementsExist(affectedRequirementIds);
+
+  return changeRequestService.save({
+    ...changeRequest,
+    affectedRequirementIds,
+    lastModified: Date.now(),
+  });
+}
+
+/**
+ * Change requests that affect a requirement
+ */
+export async function getChangeRequestsForRequirement(
+  requirementId: string
+): Promise<ChangeRequest[]> {
+  const changeRequests = await changeRequestService.loadAll();
+  return changeRequests.filter((cr) => cr.affectedRequirementIds.includes(requirementId));
+}
+
+/**
+ * Move a change request to a new status, enforcing its workflow, and commit it
+ */
+export async function transitionChangeRequest(
+  changeRequestId: string,
+  newStatus: string,
+  changedBy?: string
+): Promise<ChangeRequest> {
+  const changeRequest = await loadChangeRequest(changeRequestId);
+  const workflow = await getChangeRequestWorkflow();
+  const from = changeRequest.status || workflow.initialStatus;
+  if (!getAllowedTransitions(workflow, from).includes(newStatus)) {
+    throw new Error(
+      `Transition from "${from}" to "${newStatus}" is not allowed for ${changeRequestId}`
+    );
+  }
+
+  const author = changedBy || (await getCurrentUserName());
+  const now = Date.now();
+  const updated = await changeRequestService.save({
+    ...changeRequest,
+    status: newStatus,
+    statusChangedBy: author,
+    statusChangedAt: now,
+    lastModified: now,
+  });
+  await realGitService.commitFile(
+    `change-requests/${changeRequestId}.md`,
+    `${changeRequestId}: ${from} → ${newStatus}`,
+    author
+  );
+  return updated;
+}
+
+/**
+ * Commit files as part of a change request (adds the Change-Request trailer)
+ */
+export async function commitUnderChangeRequest(
+  changeRequestId: string,
+  paths: string[],
+  message: string,
+  author?: string
+): Promise<void> {
+  await loadChangeRequest(changeRequestId);
+  await realGitService.commitFiles(
+    paths,
+    addTrailer(message, CHANGE_REQUEST_TRAILER, changeRequestId),
+    author
+  );
+}
+
+/**
+ * All commits made under a change request, newest first, with their files
+ */
+export async function getChangeRequestCommits(
+  changeRequestId: string,
+  depth: number = 1000
+): Promise<ChangeRequestCommit[]> {
+  const history = await realGitService.getHistory(undefined, depth);
+  const commits = history.filter((commit) =>
+    getChangeRequestIds(commit.message).includes(changeRequestId)
+  );
+
+  const result = await Promise.all(
+    commits.map(async (commit) => ({
+      ...commit,
+      files: await realGitService.getCommitFiles(commit.hash),
+    }))
+  );
+  debug.log(`[ChangeControlService] ${changeRequestId}: ${result.length} commit(s)`);
+  return result;
+}
+
+export const changeControlService = {
+  getChangeRequestWorkflow,
+  createChangeRequest,
+  setAffectedRequirements,
+  getChangeRequestsForRequirement,
+  transitionChangeRequest,
+  commitUnderChangeRequest,
+  getChangeRequestCommits,
+};
@@ -0,0 +1,70 @@
+/**
+ * Change Notification Service
+ *
+ * Raises a desktop notification when the project changes outside the app:
+ * files edited on disk by other programs (reported by the main process's
+ * file watcher) or new commits found upstream by the remote status poller,
+ * e.g. "3 artifacts changed on disk / 2 new commits upstream". Off unless the
+ * notifyExternalChanges app setting is on; desktop app only, and the main
+ * process shows them only while the app is in the background.
+ */
+
+import { appSettingsService } from './appSettingsService';
+import { isElectronEnv } from './git/types';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+export interface ExternalChanges {
+  changedPaths?: string[]; // Project-relative paths changed on disk
+  newCommits?: number; // Commits newly found on the remote branch
+}
+
+const ARTIFACT_FOLDERS = new Set(Object.values(ARTIFACT_CONFIG).map((config) => config.folder));
+
+function isArtifactPath(path: string): boolean {
+  const [folder, file, ...rest] = path.split('/');
+  return rest.length === 0 && !!file?.endsWith('.md') && ARTIFACT_FOLDERS.has(folder);
+}
+
+const count = (n: number, noun: string) => `${n} ${noun}${n === 1 ? '' : 's'}`;
+
+/**
+ * One-line summary of the changes, or null when there is nothing to report
+ */
+export function describeExternalChanges(changes: ExternalChanges): string | null {
+  const parts: string[] = [];
+  const paths = [...new Set(changes.changedPaths ?? [])];
+  if (paths.length > 0) {
+    const artifacts = paths.filter(isArtifactPath).length;
+    const changed = artifacts > 0 ? count(artifacts, 'artifact') : count(paths.length, 'file');
+    parts.push(`${changed} changed on disk`);
+  }
+  if (changes.newCommits) {
+    parts.push(`${count(changes.newCommits, 'new commit')} upstream`);
+  }
+  return parts.length > 0 ? parts.join(' / ') : null;
+}
+
+/**
+ * Notify about external changes if the setting is on; resolves to whether a
+ * notification was shown
+ */
+export async function notifyExternalChanges(changes: ExternalChanges): Promise<boolean> {
+  const body = describeExternalChanges(changes);
+  if (!body || !isElectronEnv()) return false;
+  try {
+    const { notifyExternalChanges: enabled } = await appSettingsService.getAppSettings();
+    if (!enabled) return false;
+    const result = await window.electronAPI!.notifications.show({ title: 'Tracyfy', body });
+    if (result.error) throw new Error(result.error);
+    return !!result.shown;
+  } catch (err) {
+    debug.warn('[ChangeNotificationService] Could not show notification:', err);
+    return false;
+  }
+}
+
+export const changeNotificationService = {
+  describeExternalChanges,
+  notifyExternalChanges,
+};
@@ -0,0 +1,112 @@
+/**
+ * Churn Service
+ *
+ * Compares the artifact files of two commits or tags and ranks the changed
+ * artifacts by churn (lines added plus removed). Frontmatter and body lines
+ * are counted separately, so artifacts whose text actually changed stand out
+ * from those where only metadata such as status or links moved.
+ */
+
+import { realGitService } from './realGitService';
+import { baseDiskService } from './baseDiskService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { countLineChanges, splitFrontmatter, type LineChanges } from '../utils/lineDiffUtils';
+
+const ARTIFACT_FOLDERS = new Set(Object.values(ARTIFACT_CONFIG).map((config) => config.folder));
+
+export type ChurnChange = 'added' | 'removed' | 'modified';
+
+export interface ArtifactChurn {
+  artifactId: string;
+  path: string;
+  change: ChurnChange;
+  frontmatter: LineChanges;
+  body: LineChanges;
+  churn: number; // All lines added plus removed
+  frontmatterOnly: boolean; // Modified, but the body is unchanged
+}
+
+export interface ChurnReport {
+  from: string; // Resolved commit of the older ref
+  to: string;
+  artifacts: ArtifactChurn[]; // Highest churn first
+  totalChurn: number;
+}
+
+function isArtifactFile(path: string): boolean {
+  const [folder, ...rest] = path.split('/');
+  return rest.length > 0 && path.endsWith('.md') && ARTIFACT_FOLDERS.has(folder);
+}
+
+async function resolveCommit(ref: string): Promise<string> {
+  const oid = /^[0-9a-f]{40}$/.test(ref) ? ref : await realGitService.resolveRef(ref);
+  if (!oid) {
+    throw new Error(`Unknown commit or tag: ${ref}`);
+  }
+  return oid;
+}
+
+/**
+ * Churn of one artifact between two versions; null stands for "does not exist"
+ */
+export function computeArtifactChurn(
+  path: string,
+  before: string | null,
+  after: string | null
+): ArtifactChurn {
+  const old = splitFrontmatter(before ?? '');
+  const current = splitFrontmatter(after ?? '');
+  const frontmatter = countLineChanges(old.frontmatter, current.frontmatter);
+  const body = countLineChanges(old.body, current.body);
+  const change: ChurnChange = before === null ? 'added' : after === null ? 'removed' : 'modified';
+  const bodyChurn = body.added + body.removed;
+  return {
+    artifactId: (path.split('/').pop() || '').replace(/\.md$/, ''),
+    path,
+    change,
+    frontmatter,
+    body,
+    churn: frontmatter.added + frontmatter.removed + bodyChurn,
+    frontmatterOnly: change === 'modified' && bodyChurn === 0,
+  };
+}
+
+/**
+ * Artifacts changed between two commits or tags, sorted by churn
+ */
+export async function getChurnReport(fromRef: string, toRef: string): Promise<ChurnReport> {
+  const [from, to] = await Promise.all([resolveCommit(fromRef), resolveCommit(toRef)]);
+  const [oldFiles, newFiles] = await Promise.all([
+    realGitService.listFilesAtCommit(from),
+    realGitService.listFilesAtCommit(to),
+  ]);
+  const paths = [...new Set([...oldFiles, ...newFiles])].filter(isArtifactFile);
+  const oldSet = new Set(oldFiles);
+  const newSet = new Set(newFiles);
+
+  const artifacts: ArtifactChurn[] = [];
+  for (const path of paths) {
+    const [before, after] = await Promise.all([
+      oldSet.has(path) ? baseDiskService.readTextFileAtCommit(path, from) : null,
+      newSet.has(path) ? baseDiskService.readTextFileAtCommit(path, to) : null,
+    ]);
+    if (before === after) continue;
+    const result = computeArtifactChurn(path, before, after);
+    if (result.churn > 0) artifacts.push(result);
+  }
+
+  artifacts.sort(
+    (a, b) => b.churn - a.churn || a.path.localeCompare(b.path, undefined, { numeric: true })
+  );
+  return {
+    from,
+    to,
+    artifacts,
+    totalChurn: artifacts.reduce((sum, a) => sum + a.churn, 0),
+  };
+}
+
+export const churnService = {
+  computeArtifactChurn,
+  getChurnReport,
+};
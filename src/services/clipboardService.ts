@@ -0,0 +1,66 @@
+/**
+ * Clipboard Service
+ *
+ * Copies trace matrices and artifact lists to the system clipboard as HTML
+ * and tab-separated text together, so pasting into Excel, Word or Confluence
+ * keeps the table structure. The desktop app writes both flavors through the
+ * main process; the browser uses the async Clipboard API, falling back to
+ * text only where HTML clipboard items are unsupported.
+ */
+
+import { isElectronEnv } from './git/types';
+import { traceMatrixToTable, type TraceMatrix } from './traceMatrixService';
+import {
+  artifactsToTable,
+  tableToClipboard,
+  type ArtifactListItem,
+} from '../utils/clipboardTableUtils';
+
+/**
+ * Place a table (header row plus data rows) on the clipboard
+ */
+export async function copyTable(table: string[][]): Promise<void> {
+  const { html, text } = tableToClipboard(table);
+
+  if (isElectronEnv()) {
+    const result = await window.electronAPI!.clipboard.writeTable({ html, text });
+    if (result.error) {
+      throw new Error(result.error);
+    }
+    return;
+  }
+
+  if (!navigator.clipboard) {
+    throw new Error('Clipboard is not available');
+  }
+  if (typeof ClipboardItem !== 'undefined' && navigator.clipboard.write) {
+    await navigator.clipboard.write([
+      new ClipboardItem({
+        'text/html': new Blob([html], { type: 'text/html' }),
+        'text/plain': new Blob([text], { type: 'text/plain' }),
+      }),
+    ]);
+    return;
+  }
+  await navigator.clipboard.writeText(text);
+}
+
+/**
+ * Copy a trace matrix, one row per "from" artifact
+ */
+export async function copyTraceMatrix(matrix: TraceMatrix): Promise<void> {
+  await copyTable(traceMatrixToTable(matrix));
+}
+
+/**
+ * Copy a list of artifacts with their ID, title, status, priority and author
+ */
+export async function copyArtifactList(artifacts: ArtifactListItem[]): Promise<void> {
+  await copyTable(artifactsToTable(artifacts));
+}
+
+export const clipboardService = {
+  copyTable,
+  copyTraceMatrix,
+  copyArtifactList,
+};
@@ -0,0 +1,185 @@
+/**
+ * Comment Service
+ *
+ * Threaded review comments attached to artifacts. Each artifact's comments
+ * live in a sidecar file (comments/<artifactId>.json) that is committed on
+ * every change, so discussions are versioned alongside the artifact.
+ * Changing comments requires a role that may comment where roles are assigned.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { ArtifactComment, CommentThread } from '../types';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { checkPermission } from './permissionService';
+import { getCurrentUserName } from './statusWorkflowService';
+import { debug } from '../utils/debug';
+
+const COMMENTS_DIR = 'comments';
+
+/**
+ * Group comments into threads: root comments with all their (nested) replies
+ */
+export function buildCommentThreads(comments: ArtifactComment[]): CommentThread[] {
+  const byId = new Map(comments.map((c) => [c.id, c]));
+  const rootOf = (comment: ArtifactComment): ArtifactComment => {
+    const seen = new Set<string>();
+    let current = comment;
+    while (current.parentId && byId.has(current.parentId) && !seen.has(current.id)) {
+      seen.add(current.id);
+      current = byId.get(current.parentId)!;
+    }
+    return current;
+  };
+
+  const threads = new Map<string, CommentThread>();
+  const sorted = [...comments].sort((a, b) => a.timestamp - b.timestamp);
+  sorted.forEach((comment) => {
+    const root = rootOf(comment);
+    if (!threads.has(root.id)) threads.set(root.id, { comment: root, replies: [] });
+    if (root.id !== comment.id) threads.get(root.id)!.replies.push(comment);
+  });
+
+  return [...threads.values()];
+}
+
+function nextCommentId(comments: ArtifactComment[]): string {
+  const max = comments.reduce((highest, c) => {
+    const number = parseInt(c.id.replace(/^C-/, ''), 10);
+    return Number.isNaN(number) ? highest : Math.max(highest, number);
+  }, 0);
+  return `C-${String(max + 1).padStart(3, '0')}`;
+}
+
+class CommentService extends BaseDiskService {
+  private getCommentsPath(artifactId: string): string {
+    if (getTypeFromId(artifactId) === 'unknown') {
+      throw new Error(`Unknown artifact: ${artifactId}`);
+    }
+    return `${COMMENTS_DIR}/${artifactId}.json`;
+  }
+
+  private async writeComments(
+    artifactId: string,
+    comments: ArtifactComment[],
+    commitMessage: string
+  ): Promise<void> {
+    await this.writeJsonFile(this.getCommentsPath(artifactId), comments, commitMessage);
+  }
+
+  /**
+   * All comments of an artifact, oldest first
+   */
+  async listComments(artifactId: string): Promise<ArtifactComment[]> {
+    const comments = await this.readJsonFile<ArtifactComment[]>(
+      this.getCommentsPath(artifactId),
+      []
+    );
+    return Array.isArray(comments) ? [...comments].sort((a, b) => a.timestamp - b.timestamp) : [];
+  }
+
+  /**
+   * Comments of an artifact grouped into threads
+   */
+  async listThreads(artifactId: string, includeResolved: boolean = true): Promise<CommentThread[]> {
+    const threads = buildCommentThreads(await this.listComments(artifactId));
+    return includeResolved ? threads : threads.filter((t) => !t.comment.resolved);
+  }
+
+  /**
+   * Add a comment, or a reply when parentId is given. Without an author the
+   * comment is by the current user (the active author profile, if any).
+   */
+  async addComment(
+    artifactId: string,
+    author: string,
+    text: string,
+    parentId?: string
+  ): Promise<ArtifactComment> {
+    if (!text.trim()) {
+      throw new Error('Comment text is empty');
+    }
+    await checkPermission('comment');
+
+    const comments = await this.listComments(artifactId);
+    if (parentId && !comments.some((c) => c.id === parentId)) {
+      throw new Error(`Comment not found: ${parentId}`);
+    }
+
+    const comment: ArtifactComment = {
+      id: nextCommentId(comments),
+      artifactId,
+      parentId,
+      author: author.trim() || (await getCurrentUserName()),
+      text: text.trim(),
+      timestamp: Date.now(),
+    };
+    await this.writeComments(
+      artifactId,
+      [...comments, comment],
+      `${parentId ? 'Reply to' : 'Comment on'} ${artifactId}`
+    );
+    debug.log(`[CommentService] ${comment.author} commented on ${artifactId} (${comment.id})`);
+    return comment;
+  }
+
+  /**
+   * Mark the thread containing a comment as resolved (or reopen it)
+   */
+  async resolveComment(
+    artifactId: string,
+    commentId: string,
+    resolvedBy: string,
+    resolved: boolean = true
+  ): Promise<ArtifactComment> {
+    await checkPermission('comment');
+    const comments = await this.listComments(artifactId);
+    const thread = buildCommentThreads(comments).find(
+      (t) => t.comment.id === commentId || t.replies.some((r) => r.id === commentId)
+    );
+    if (!thread) {
+      throw new Error(`Comment not found: ${commentId}`);
+    }
+
+    const root: ArtifactComment = resolved
+      ? { ...thread.comment, resolved: true, resolvedBy, resolvedAt: Date.now() }
+      : { ...thread.comment, resolved: false, resolvedBy: undefined, resolvedAt: undefined };
+    await this.writeComments(
+      artifactId,
+      comments.map((c) => (c.id === root.id ? root : c)),
+      `${resolved ? 'Resolve' : 'Reopen'} comment ${root.id} on ${artifactId}`
+    );
+    return root;
+  }
+
+  /**
+   * Delete a comment together with all replies to it
+   */
+  async deleteComment(artifactId: string, commentId: string): Promise<string[]> {
+    await checkPermission('comment');
+    const comments = await this.listComments(artifactId);
+    if (!comments.some((c) => c.id === commentId)) {
+      throw new Error(`Comment not found: ${commentId}`);
+    }
+
+    const removed = new Set([commentId]);
+    let grew = true;
+    while (grew) {
+      grew = false;
+      comments.forEach((c) => {
+        if (c.parentId && removed.has(c.parentId) && !removed.has(c.id)) {
+          removed.add(c.id);
+          grew = true;
+        }
+      });
+    }
+
+    await this.writeComments(
+      artifactId,
+      comments.filter((c) => !removed.has(c.id)),
+      `Delete comment ${commentId} on ${artifactId}`
+    );
+    return [...removed];
+  }
+}
+
+export const commentService = new CommentService();
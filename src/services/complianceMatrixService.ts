@@ -0,0 +1,276 @@
+/**
+ * Compliance Matrix Service
+ *
+ * Tabular requirements-to-verification evidence in the shape auditors expect
+ * for ISO 26262, DO-178C and IEC 62304 style reviews. A template maps columns
+ * to fields of the requirement, its linked test cases, its verification state
+ * or its custom attributes, and lays out one row per requirement or per
+ * requirement/test case pair. Built-in templates can be overridden and new
+ * ones added in the project config (config/compliance.json).
+ */
+
+import type { CustomAttributeDefinition, Link, Project, Requirement, TestCase } from '../types';
+import { requirementService, testCaseService, projectService } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { diskCustomAttributeService } from './diskCustomAttributeService';
+import { projectConfigService } from './projectConfigService';
+import { getTestCasesByRequirement } from './coverageService';
+import { computeVerificationRollup, type RequirementVerification } from './verificationService';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { formatDate } from '../utils/dateUtils';
+
+const COMPLIANCE_SECTION = 'compliance';
+const ATTRIBUTE_PREFIX = 'attribute:';
+
+export type ComplianceLayout = 'perRequirement' | 'perTestCase';
+
+export interface ComplianceColumn {
+  header: string;
+  field: string; // A key of COMPLIANCE_FIELDS or "attribute:<custom attribute name>"
+}
+
+export interface ComplianceTemplate {
+  id: string;
+  name: string;
+  layout: ComplianceLayout;
+  columns: ComplianceColumn[];
+}
+
+interface ComplianceRowContext {
+  requirement: Requirement;
+  testCases: TestCase[]; // The test case of the row, or all of the requirement's
+  verification?: RequirementVerification;
+  linkedIds: string[]; // Artifacts linked to the requirement in either direction
+  attributes: CustomAttributeDefinition[];
+}
+
+const joined = (values: (string | undefined)[]) => values.filter(Boolean).join(', ');
+
+export const COMPLIANCE_FIELDS: Record<string, (row: ComplianceRowContext) => string> = {
+  'requirement.id': ({ requirement }) => requirement.id,
+  'requirement.title': ({ requirement }) => requirement.title,
+  'requirement.text': ({ requirement }) => requirement.text,
+  'requirement.status': ({ requirement }) => requirement.status || '',
+  'requirement.priority': ({ requirement }) => requirement.priority || '',
+  'requirement.revision': ({ requirement }) => requirement.revision,
+  'requirement.parent': ({ requirement }) => requirement.parentId || '',
+  'requirement.verificationMethod': ({ requirement }) => requirement.verificationMethod || '',
+  'testCase.id': ({ testCases }) => joined(testCases.map((t) => t.id)),
+  'testCase.title': ({ testCases }) => joined(testCases.map((t) => t.title)),
+  'testCase.status': ({ testCases }) => joined(testCases.map((t) => t.status)),
+  'testCase.lastRun': ({ testCases }) =>
+    joined(testCases.map((t) => (t.lastRun ? formatDate(t.lastRun) : undefined))),
+  'verification.state': ({ verification }) => verification?.state || '',
+  'verification.rolledUpState': ({ verification }) => verification?.rolledUpState || '',
+  'links.risks': ({ linkedIds }) =>
+    joined(linkedIds.filter((id) => getTypeFromId(id) === 'risks')),
+  'links.useCases': ({ linkedIds }) =>
+    joined(linkedIds.filter((id) => getTypeFromId(id) === 'usecases')),
+};
+
+export const BUILT_IN_COMPLIANCE_TEMPLATES: ComplianceTemplate[] = [
+  {
+    id: 'iso-26262',
+    name: 'ISO 26262 Verification Matrix',
+    layout: 'perTestCase',
+    columns: [
+      { header: 'Safety Requirement ID', field: 'requirement.id' },
+      { header: 'Safety Requirement', field: 'requirement.text' },
+      { header: 'ASIL', field: 'attribute:ASIL' },
+      { header: 'Requirement Status', field: 'requirement.status' },
+      { header: 'Verification Method', field: 'requirement.verificationMethod' },
+      { header: 'Test Case ID', field: 'testCase.id' },
+      { header: 'Test Case', field: 'testCase.title' },
+      { header: 'Test Result', field: 'testCase.status' },
+      { header: 'Verification Status', field: 'verification.rolledUpState' },
+    ],
+  },
+  {
+    id: 'do-178c',
+    name: 'DO-178C Requirements-Based Test Trace',
+    layout: 'perTestCase',
+    columns: [
+      { header: 'Requirement ID', field: 'requirement.id' },
+      { header: 'Parent (HLR)', field: 'requirement.parent' },
+      { header: 'Requirement Text', field: 'requirement.text' },
+      { header: 'DAL', field: 'attribute:DAL' },
+      { header: 'Test Case ID', field: 'testCase.id' },
+      { header: 'Test Procedure', field: 'testCase.title' },
+      { header: 'Result', field: 'testCase.status' },
+      { header: 'Last Executed', field: 'testCase.lastRun' },
+    ],
+  },
+  {
+    id: 'iec-62304',
+    name: 'IEC 62304 Software Requirements Traceability',
+    layout: 'perRequirement',
+    columns: [
+      { header: 'Software Requirement ID', field: 'requirement.id' },
+      { header: 'Software Requirement', field: 'requirement.title' },
+      { header: 'Software Safety Class', field: 'attribute:Safety Class' },
+      { header: 'Risk Control', field: 'links.risks' },
+      { header: 'Verification (Test IDs)', field: 'testCase.id' },
+      { header: 'Test Results', field: 'testCase.status' },
+      { header: 'Verification Status', field: 'verification.state' },
+    ],
+  },
+];
+
+/**
+ * Check a template for unknown fields and missing columns; returns a list of
+ * problems
+ */
+export function validateComplianceTemplate(template: ComplianceTemplate): string[] {
+  const errors: string[] = [];
+  if (!template.id.trim()) errors.push('Template has no ID');
+  if (template.columns.length === 0) errors.push(`Template ${template.id} has no columns`);
+  template.columns
+    .filter(
+      (column) => !(column.field in COMPLIANCE_FIELDS) && !column.field.startsWith(ATTRIBUTE_PREFIX)
+    )
+    .forEach((column) =>
+      errors.push(`Unknown field "${column.field}" in column "${column.header}"`)
+    );
+  return errors;
+}
+
+/**
+ * Built-in templates, with those from the project config overriding or
+ * extending them
+ */
+export async function getComplianceTemplates(): Promise<ComplianceTemplate[]> {
+  const { templates } = await projectConfigService.getSection(COMPLIANCE_SECTION, {
+    templates: [] as ComplianceTemplate[],
+  });
+  const custom = new Map(templates.map((t) => [t.id, t]));
+  return [
+    ...BUILT_IN_COMPLIANCE_TEMPLATES.map((t) => custom.get(t.id) ?? t),
+    ...templates.filter((t) => !BUILT_IN_COMPLIANCE_TEMPLATES.some((b) => b.id === t.id)),
+  ];
+}
+
+/**
+ * Validate and store the project's own templates
+ */
+export async function setComplianceTemplates(templates: ComplianceTemplate[]): Promise<void> {
+  const errors = templates.flatMap(validateComplianceTemplate);
+  if (errors.length > 0) {
+    throw new Error(`Invalid compliance templates: ${errors.join('; ')}`);
+  }
+  await projectConfigService.setSection(
+    COMPLIANCE_SECTION,
+    { templates },
+    'Update compliance matrix templates'
+  );
+}
+
+function getAttributeValue(
+  requirement: Requirement,
+  name: string,
+  attributes: CustomAttributeDefinition[]
+): string {
+  const definition = attributes.find((a) => a.name.toLowerCase() === name.toLowerCase());
+  const value = definition
+    ? requirement.customAttributes?.find((v) => v.attributeId === definition.id)?.value
+    : undefined;
+  if (value === undefined || value === null) return '';
+  if (typeof value === 'boolean') return value ? 'Yes' : 'No';
+  if (definition?.type === 'date' && typeof value === 'number') return formatDate(value);
+  return String(value);
+}
+
+function getCell(field: string, row: ComplianceRowContext): string {
+  if (field.startsWith(ATTRIBUTE_PREFIX)) {
+    return getAttributeValue(row.requirement, field.slice(ATTRIBUTE_PREFIX.length), row.attributes);
+  }
+  return COMPLIANCE_FIELDS[field]?.(row) ?? '';
+}
+
+/**
+ * Build the matrix of a template as a header row plus data rows. In the
+ * per-test-case layout, requirements without test cases still get a row so
+ * verification gaps stay visible.
+ */
+export function buildComplianceMatrix(
+  template: ComplianceTemplate,
+  requirements: Requirement[],
+  testCases: TestCase[],
+  links: Link[],
+  attributes: CustomAttributeDefinition[] = []
+): string[][] {
+  const activeRequirements = requirements
+    .filter((r) => !r.isDeleted)
+    .sort((a, b) => a.id.localeCompare(b.id, undefined, { numeric: true }));
+  const activeTestCases = testCases.filter((t) => !t.isDeleted);
+  const testCasesById = new Map(activeTestCases.map((t) => [t.id, t]));
+  const byRequirement = getTestCasesByRequirement(activeRequirements, activeTestCases, links);
+  const rollup = computeVerificationRollup(activeRequirements, activeTestCases, links);
+  const verification = new Map(rollup.requirements.map((v) => [v.requirementId, v]));
+
+  const rows: string[][] = [template.columns.map((column) => column.header)];
+  for (const requirement of activeRequirements) {
+    const linkedIds = links
+      .filter((l) => !l.isDeleted && [l.sourceId, l.targetId].includes(requirement.id))
+      .map((l) => (l.sourceId === requirement.id ? l.targetId : l.sourceId));
+    const ownTestCases = [...(byRequirement.get(requirement.id) ?? [])]
+      .sort((a, b) => a.localeCompare(b, undefined, { numeric: true }))
+      .map((id) => testCasesById.get(id)!);
+    const groups =
+      template.layout === 'perTestCase' && ownTestCases.length > 0
+        ? ownTestCases.map((testCase) => [testCase])
+        : [ownTestCases];
+
+    for (const group of groups) {
+      const context: ComplianceRowContext = {
+        requirement,
+        testCases: group,
+        verification: verification.get(requirement.id),
+        linkedIds: [...new Set(linkedIds)].sort(),
+        attributes,
+      };
+      rows.push(template.columns.map((column) => getCell(column.field, context)));
+    }
+  }
+  return rows;
+}
+
+/**
+ * Load the trace data and build the matrix of a template, optionally
+ * restricted to a single project
+ */
+export async function generateComplianceMatrix(
+  templateId: string,
+  projectId?: string
+): Promise<string[][]> {
+  const template = (await getComplianceTemplates()).find((t) => t.id === templateId);
+  if (!template) {
+    throw new Error(`Compliance template not found: ${templateId}`);
+  }
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const [requirements, testCases, links, attributes] = await Promise.all([
+    requirementService.loadAll(),
+    testCaseService.loadAll(),
+    projectId ? diskLinkService.getLinksForProject(projectId) : diskLinkService.getAllLinks(),
+    diskCustomAttributeService.getAllDefinitions(),
+  ]);
+
+  return buildComplianceMatrix(
+    template,
+    project ? requirements.filter((r) => project.requirementIds.includes(r.id)) : requirements,
+    project ? testCases.filter((t) => project.testCaseIds.includes(t.id)) : testCases,
+    links,
+    attributes
+  );
+}
+
+export const complianceMatrixService = {
+  validateComplianceTemplate,
+  getComplianceTemplates,
+  setComplianceTemplates,
+  buildComplianceMatrix,
+  generateComplianceMatrix,
+};
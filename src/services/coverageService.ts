@@ -0,0 +1,147 @@
+/**
+ * Coverage Service
+ *
+ * Reports test coverage gaps: requirements without any linked test case and
+ * test cases that do not trace back to a requirement. Links are taken from
+ * standalone link files as well as the legacy embedded fields
+ * (`linkedArtifacts`, `TestCase.requirementIds`).
+ */
+
+import type { Requirement, TestCase, Link, Project } from '../types';
+import { requirementService, testCaseService, projectService } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+
+export interface CoverageFilter {
+  statuses?: string[]; // Only include artifacts with one of these statuses
+  priorities?: string[]; // Only include artifacts with one of these priorities
+}
+
+export interface CoverageGapItem {
+  id: string;
+  title: string;
+  status?: string;
+  priority?: string;
+}
+
+export interface CoverageReport {
+  untestedRequirements: CoverageGapItem[];
+  unlinkedTestCases: CoverageGapItem[];
+  totalRequirements: number;
+  testedRequirements: number;
+  totalTestCases: number;
+  coveragePercent: number; // Share of (filtered) requirements with at least one test case
+}
+
+function matchesFilter(item: { status?: string; priority?: string }, filter: CoverageFilter) {
+  if (filter.statuses?.length && !filter.statuses.includes(item.status ?? '')) return false;
+  if (filter.priorities?.length && !filter.priorities.includes(item.priority ?? '')) return false;
+  return true;
+}
+
+function toGapItem(item: Requirement | TestCase): CoverageGapItem {
+  return { id: item.id, title: item.title, status: item.status, priority: item.priority };
+}
+
+function byIdNumber(a: CoverageGapItem, b: CoverageGapItem): number {
+  return a.id.localeCompare(b.id, undefined, { numeric: true });
+}
+
+/**
+ * The test cases linked to each requirement, from link files and the legacy
+ * embedded fields. Requirements without test cases have no entry.
+ */
+export function getTestCasesByRequirement(
+  requirements: Requirement[],
+  testCases: TestCase[],
+  links: Link[]
+): Map<string, Set<string>> {
+  const requirementIds = new Set(requirements.map((r) => r.id));
+  const testCaseIds = new Set(testCases.map((t) => t.id));
+  const byRequirement = new Map<string, Set<string>>();
+
+  const connect = (a: string, b: string) => {
+    const [reqId, tcId] = requirementIds.has(a) ? [a, b] : [b, a];
+    if (!requirementIds.has(reqId) || !testCaseIds.has(tcId)) return;
+    if (!byRequirement.has(reqId)) byRequirement.set(reqId, new Set());
+    byRequirement.get(reqId)!.add(tcId);
+  };
+
+  links.forEach((link) => {
+    if (!link.isDeleted) connect(link.sourceId, link.targetId);
+  });
+  [...requirements, ...testCases].forEach((artifact) => {
+    artifact.linkedArtifacts?.forEach((link) => connect(artifact.id, link.targetId));
+  });
+  testCases.forEach((tc) => tc.requirementIds?.forEach((reqId) => connect(tc.id, reqId)));
+
+  return byRequirement;
+}
+
+/**
+ * Compute coverage gaps from already-loaded artifacts and links
+ */
+export function getCoverageReport(
+  requirements: Requirement[],
+  testCases: TestCase[],
+  links: Link[],
+  filter: CoverageFilter = {}
+): CoverageReport {
+  const byRequirement = getTestCasesByRequirement(requirements, testCases, links);
+  const testedRequirementIds = new Set(byRequirement.keys());
+  const linkedTestCaseIds = new Set([...byRequirement.values()].flatMap((ids) => [...ids]));
+
+  const activeRequirements = requirements.filter((r) => !r.isDeleted && matchesFilter(r, filter));
+  const activeTestCases = testCases.filter((t) => !t.isDeleted && matchesFilter(t, filter));
+  const testedRequirements = activeRequirements.filter((r) => testedRequirementIds.has(r.id));
+
+  return {
+    untestedRequirements: activeRequirements
+      .filter((r) => !testedRequirementIds.has(r.id))
+      .map(toGapItem)
+      .sort(byIdNumber),
+    unlinkedTestCases: activeTestCases
+      .filter((t) => !linkedTestCaseIds.has(t.id))
+      .map(toGapItem)
+      .sort(byIdNumber),
+    totalRequirements: activeRequirements.length,
+    testedRequirements: testedRequirements.length,
+    totalTestCases: activeTestCases.length,
+    coveragePercent:
+      activeRequirements.length > 0
+        ? Math.round((testedRequirements.length / activeRequirements.length) * 100)
+        : 100,
+  };
+}
+
+/**
+ * Load artifacts from disk and compute coverage gaps,
+ * optionally restricted to a single project
+ */
+export async function generateCoverageReport(
+  projectId?: string,
+  filter: CoverageFilter = {}
+): Promise<CoverageReport> {
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const [requirements, testCases, links] = await Promise.all([
+    requirementService.loadAll(),
+    testCaseService.loadAll(),
+    projectId ? diskLinkService.getLinksForProject(projectId) : diskLinkService.getAllLinks(),
+  ]);
+
+  return getCoverageReport(
+    project ? requirements.filter((r) => project.requirementIds.includes(r.id)) : requirements,
+    project ? testCases.filter((t) => project.testCaseIds.includes(t.id)) : testCases,
+    links,
+    filter
+  );
+}
+
+export const coverageService = {
+  getTestCasesByRequirement,
+  getCoverageReport,
+  generateCoverageReport,
+};
@@ -0,0 +1,123 @@
+/**
+ * Dangling Link Service
+ *
+ * Finds links whose source or target artifact no longer exists (file removed,
+ * renamed, or soft-deleted) and repairs them by removal or retargeting.
+ */
+
+import type { Link } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { debug } from '../utils/debug';
+
+// Artifact types that can appear as link endpoints
+export const LINKABLE_TYPES = [
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+  'documents',
+];
+
+export type LinkEndpoint = 'source' | 'target';
+
+export interface DanglingLink {
+  link: Link;
+  missingEndpoints: LinkEndpoint[];
+}
+
+/**
+ * Find links with at least one endpoint that is not in the set of existing artifact IDs
+ */
+export function findDanglingLinks(links: Link[], existingIds: Set<string>): DanglingLink[] {
+  const dangling: DanglingLink[] = [];
+
+  links.forEach((link) => {
+    const missingEndpoints: LinkEndpoint[] = [];
+    if (!existingIds.has(link.sourceId)) missingEndpoints.push('source');
+    if (!existingIds.has(link.targetId)) missingEndpoints.push('target');
+    if (missingEndpoints.length > 0) {
+      dangling.push({ link, missingEndpoints });
+    }
+  });
+
+  return dangling;
+}
+
+/**
+ * Collect the IDs of all live (non-deleted) linkable artifacts on disk
+ */
+export async function loadExistingArtifactIds(): Promise<Set<string>> {
+  const lists = await Promise.all(
+    LINKABLE_TYPES.map((typeKey) => ALL_ARTIFACT_SERVICES[typeKey].loadAll())
+  );
+  return new Set(lists.flat().map((item: { id: string }) => item.id));
+}
+
+/**
+ * Report all dangling links in the repository
+ */
+export async function getDanglingLinks(): Promise<DanglingLink[]> {
+  const [links, existingIds] = await Promise.all([
+    diskLinkService.getAllLinks(),
+    loadExistingArtifactIds(),
+  ]);
+  return findDanglingLinks(links, existingIds);
+}
+
+/**
+ * Delete dangling links. When linkIds is given, only those links are removed
+ * (and only if they are still dangling). Returns the IDs of removed links.
+ */
+export async function removeDanglingLinks(linkIds?: string[]): Promise<string[]> {
+  const dangling = await getDanglingLinks();
+  const toRemove = dangling
+    .map((d) => d.link.id)
+    .filter((id) => !linkIds || linkIds.includes(id));
+
+  for (const id of toRemove) {
+    await diskLinkService.deleteLink(id);
+  }
+
+  debug.log(`[DanglingLinkService] Removed ${toRemove.length} dangling link(s)`);
+  return toRemove;
+}
+
+/**
+ * Point a link endpoint at a different artifact, e.g. after a rename.
+ * The new endpoint must exist.
+ */
+export async function retargetLink(
+  linkId: string,
+  endpoint: LinkEndpoint,
+  newArtifactId: string
+): Promise<Link> {
+  const link = await diskLinkService.getLinkById(linkId);
+  if (!link) {
+    throw new Error(`Link not found: ${linkId}`);
+  }
+
+  const existingIds = await loadExistingArtifactIds();
+  if (!existingIds.has(newArtifactId)) {
+    throw new Error(`Artifact not found: ${newArtifactId}`);
+  }
+
+  const otherEnd = endpoint === 'source' ? link.targetId : link.sourceId;
+  if (otherEnd === newArtifactId) {
+    throw new Error(`Cannot link ${newArtifactId} to itself`);
+  }
+
+  const updated = await diskLinkService.updateLink(
+    linkId,
+    endpoint === 'source' ? { sourceId: newArtifactId } : { targetId: newArtifactId }
+  );
+  return updated!;
+}
+
+export const danglingLinkService = {
+  findDanglingLinks,
+  getDanglingLinks,
+  removeDanglingLinks,
+  retargetLink,
+};
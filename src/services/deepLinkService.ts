@@ -0,0 +1,46 @@
+/**
+ * Deep Link Service
+ *
+ * tracyfy://open?project=...&artifact=... links from chat, email or issue
+ * trackers. The desktop app registers the scheme and forwards each link,
+ * including one the app was launched with; the browser build has none.
+ */
+
+import type { DeepLink, Project } from '../types';
+import { isElectronEnv } from './git/types';
+import { debug } from '../utils/debug';
+
+/**
+ * Call handler for every deep link, starting with those that arrived before
+ * subscribing. Returns the unsubscribe function.
+ */
+export function subscribeToDeepLinks(handler: (link: DeepLink) => void): () => void {
+  if (!isElectronEnv()) return () => {};
+  const api = window.electronAPI!.deepLink;
+  const unsubscribe = api.onOpen(handler);
+  api
+    .takePending()
+    .then((result) => {
+      if (result.error) throw new Error(result.error);
+      result.links?.forEach(handler);
+    })
+    .catch((error) => debug.warn('[DeepLinkService] Could not read pending links:', error));
+  return unsubscribe;
+}
+
+/**
+ * Project a link refers to, by ID or else by name (case-insensitive)
+ */
+export function findLinkedProject(projects: Project[], reference: string): Project | null {
+  const active = projects.filter((p) => !p.isDeleted);
+  return (
+    active.find((p) => p.id === reference) ||
+    active.find((p) => p.name.toLowerCase() === reference.toLowerCase()) ||
+    null
+  );
+}
+
+export const deepLinkService = {
+  subscribeToDeepLinks,
+  findLinkedProject,
+};
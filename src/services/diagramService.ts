@@ -0,0 +1,127 @@
+/**
+ * Diagram Service
+ *
+ * Renders Mermaid and PlantUML diagrams written as fenced code blocks
+ * (```mermaid / ```plantuml) in artifact text. Rendering happens in the
+ * Electron main process with locally installed tools, which caches results
+ * by content hash, so previews and PDF exports show the diagram instead of
+ * its source. Tool locations are app settings of this machine, which the
+ * main process reads itself and sets only from its own file dialog; neither
+ * a project nor the renderer can choose programs to run.
+ */
+
+import type { DiagramSettings } from '../types';
+import { appSettingsService } from './appSettingsService';
+import { isElectronEnv } from './git/types';
+import { debug } from '../utils/debug';
+
+export type { DiagramSettings };
+
+export type DiagramKind = 'mermaid' | 'plantuml';
+export type DiagramFormat = 'svg' | 'png';
+
+export interface DiagramBlock {
+  kind: DiagramKind;
+  source: string;
+}
+
+// Rendered data URLs (or null for failures) for this session
+const renderCache = new Map<string, Promise<string | null>>();
+
+export function isDiagramKind(language: string | undefined): language is DiagramKind {
+  return language === 'mermaid' || language === 'plantuml';
+}
+
+/**
+ * Diagram code blocks in markdown, in document order
+ */
+export function extractDiagrams(markdown: string): DiagramBlock[] {
+  const blocks: DiagramBlock[] = [];
+  const fence = /^(`{3,}|~{3,})[ \t]*(mermaid|plantuml)[ \t]*\r?\n([\s\S]*?)\r?\n\1[ \t]*$/gm;
+  let match;
+  while ((match = fence.exec(markdown)) !== null) {
+    const source = match[3].trim();
+    if (source) blocks.push({ kind: match[2] as DiagramKind, source });
+  }
+  return blocks;
+}
+
+export async function getDiagramSettings(): Promise<DiagramSettings> {
+  return (await appSettingsService.getAppSettings()).diagrams;
+}
+
+/**
+ * Pick the program or file for a diagram tool in a file dialog, or reset it
+ * to its default; false when the dialog is canceled
+ */
+export async function chooseDiagramTool(
+  tool: keyof DiagramSettings,
+  reset = false
+): Promise<boolean> {
+  const settings = await appSettingsService.chooseDiagramTool(tool, reset);
+  // Diagrams that failed with the old settings may render now
+  if (settings) renderCache.clear();
+  return settings !== null;
+}
+
+async function render(
+  kind: DiagramKind,
+  source: string,
+  format: DiagramFormat
+): Promise<string | null> {
+  const result = await window.electronAPI!.diagram.render(kind, source, format);
+  if (result.error || !result.data) {
+    debug.warn(`[DiagramService] Rendering ${kind} diagram failed:`, result.error);
+    return null;
+  }
+  return `data:${result.mimeType};base64,${result.data}`;
+}
+
+/**
+ * Render a diagram to a data URL; null outside Electron or when the
+ * renderer is missing or rejects the source
+ */
+export async function renderDiagram(
+  kind: DiagramKind,
+  source: string,
+  format: DiagramFormat = 'svg'
+): Promise<string | null> {
+  if (!isElectronEnv()) return null;
+
+  const key = `${kind}:${format}:${source}`;
+  let pending = renderCache.get(key);
+  if (!pending) {
+    pending = render(kind, source, format).catch((err) => {
+      debug.warn(`[DiagramService] Rendering ${kind} diagram failed:`, err);
+      return null;
+    });
+    renderCache.set(key, pending);
+  }
+  return pending;
+}
+
+/**
+ * Render every diagram in markdown, skipping those that fail
+ */
+export async function renderDiagramsInMarkdown(
+  markdown: string,
+  format: DiagramFormat = 'png'
+): Promise<string[]> {
+  const rendered = await Promise.all(
+    extractDiagrams(markdown).map((block) => renderDiagram(block.kind, block.source, format))
+  );
+  return rendered.filter((url): url is string => url !== null);
+}
+
+export function clearDiagramCache(): void {
+  renderCache.clear();
+}
+
+export const diagramService = {
+  extractDiagrams,
+  getDiagramSettings,
+  chooseDiagramTool,
+  renderDiagram,
+  renderDiagramsInMarkdown,
+  clearDiagramCache,
+};
@@ -48,6 +48,9 @@ export class DiskBaselineService extends BaseDiskService {
       baseline,
       `Baseline created: ${baseline.name} (${baseline.version})`
     );
+    if (typeof window !== 'undefined') {
+      window.dispatchEvent(new CustomEvent('baseline-created', { detail: baseline }));
+    }
   }
 
   /**
@@ -3,8 +3,14 @@ import type { Link } from '../types';
 import type { LinkType } from '../utils/linkTypes';
 import { getInverseType } from '../utils/linkTypes';
 import { linkToMarkdown, parseMarkdownLink } from '../utils/linkMarkdownUtils';
+import {
+  findCycleThroughLink,
+  formatCyclePath,
+  isDecompositionLink,
+} from '../utils/traceCycleUtils';
 import { getTypeFromId } from '../constants/artifactConfig';
 import { idService } from './idService';
+import { runJournaled } from './writeJournalService';
 
 /**
  * Incoming link representation (from target's perspective)
@@ -16,6 +22,68 @@ export interface IncomingLink {
   linkType: LinkType; // The inverse type
 }
 
+/**
+ * All links touching a single artifact, from its perspective
+ */
+export interface ArtifactLinks {
+  outgoing: Link[];
+  incoming: IncomingLink[];
+}
+
+/**
+ * Filter criteria for link queries (all criteria are combined with AND)
+ */
+export interface LinkQuery {
+  sourceId?: string;
+  targetId?: string;
+  artifactId?: string; // Matches either end of the link
+  types?: LinkType[];
+  projectId?: string; // Restrict to links visible in this project
+}
+
+/**
+ * A link to create as part of a bulk operation
+ */
+export interface LinkSpec {
+  sourceId: string;
+  targetId: string;
+  type: LinkType;
+  projectIds?: string[];
+}
+
+/**
+ * Outcome of a bulk link creation
+ */
+export interface BulkLinkResult {
+  created: Link[];
+  skipped: LinkSpec[]; // Already linked, or repeated within the batch
+  missing: LinkSpec[]; // Source or target artifact does not exist
+}
+
+function toIncomingLink(link: Link): IncomingLink {
+  return {
+    linkId: link.id,
+    sourceId: link.sourceId,
+    sourceType: getTypeFromId(link.sourceId),
+    linkType: getInverseType(link.type),
+  };
+}
+
+// Derivation and refinement links must not lead back to where they started
+function assertNoCycle(links: LinkSpec[], spec: LinkSpec): void {
+  const cycle = findCycleThroughLink(links, spec);
+  if (cycle) {
+    throw new Error(
+      `Cannot link ${spec.sourceId} to ${spec.targetId} (${spec.type}): ` +
+        `it would create a cycle ${formatCyclePath(cycle)}`
+    );
+  }
+}
+
+function isVisibleInProject(link: Link, projectId: string): boolean {
+  return !link.projectIds || link.projectIds.length === 0 || link.projectIds.includes(projectId);
+}
+
 class DiskLinkService extends BaseArtifactService<Link> {
   constructor() {
     super('links', {
@@ -52,14 +120,7 @@ class DiskLinkService extends BaseArtifactService<Link> {
    */
   async getIncomingLinks(artifactId: string): Promise<IncomingLink[]> {
     const allLinks = await this.getAllLinks();
-    return allLinks
-      .filter((link) => link.targetId === artifactId)
-      .map((link) => ({
-        linkId: link.id,
-        sourceId: link.sourceId,
-        sourceType: getTypeFromId(link.sourceId),
-        linkType: getInverseType(link.type),
-      }));
+    return allLinks.filter((link) => link.targetId === artifactId).map(toIncomingLink);
   }
 
   /**
@@ -81,10 +142,7 @@ class DiskLinkService extends BaseArtifactService<Link> {
    */
   async getLinksForProject(projectId: string): Promise<Link[]> {
     const allLinks = await this.getAllLinks();
-    return allLinks.filter(
-      (link) =>
-        !link.projectIds || link.projectIds.length === 0 || link.projectIds.includes(projectId)
-    );
+    return allLinks.filter((link) => isVisibleInProject(link, projectId));
   }
 
   /**
@@ -108,18 +166,45 @@ class DiskLinkService extends BaseArtifactService<Link> {
    */
   async getIncomingLinksForProject(artifactId: string, projectId: string): Promise<IncomingLink[]> {
     const allLinks = await this.getLinksForProject(projectId);
-    return allLinks
-      .filter((link) => link.targetId === artifactId)
-      .map((link) => ({
-        linkId: link.id,
-        sourceId: link.sourceId,
-        sourceType: getTypeFromId(link.sourceId),
-        linkType: getInverseType(link.type),
-      }));
+    return allLinks.filter((link) => link.targetId === artifactId).map(toIncomingLink);
+  }
+
+  /**
+   * Get incoming and outgoing links for an artifact in a single pass,
+   * optionally restricted to links visible in a project
+   */
+  async getLinksForArtifact(artifactId: string, projectId?: string): Promise<ArtifactLinks> {
+    const allLinks = projectId
+      ? await this.getLinksForProject(projectId)
+      : await this.getAllLinks();
+
+    return {
+      outgoing: allLinks.filter((link) => link.sourceId === artifactId),
+      incoming: allLinks.filter((link) => link.targetId === artifactId).map(toIncomingLink),
+    };
   }
 
   /**
-   * Create a new link
+   * Query active (not deleted) links by endpoint, type and project visibility
+   */
+  async queryLinks(query: LinkQuery): Promise<Link[]> {
+    const allLinks = await this.getAllLinks();
+    return allLinks.filter(
+      (link) =>
+        !link.isDeleted &&
+        (query.sourceId === undefined || link.sourceId === query.sourceId) &&
+        (query.targetId === undefined || link.targetId === query.targetId) &&
+        (query.artifactId === undefined ||
+          link.sourceId === query.artifactId ||
+          link.targetId === query.artifactId) &&
+        (query.types === undefined || query.types.includes(link.type)) &&
+        (query.projectId === undefined || isVisibleInProject(link, query.projectId))
+    );
+  }
+
+  /**
+   * Create a new link. Derivation and refinement links that would close a
+   * cycle are rejected.
    */
   async createLink(
     sourceId: string,
@@ -127,6 +212,13 @@ class DiskLinkService extends BaseArtifactService<Link> {
     type: LinkType,
     projectIds: string[] = []
   ): Promise<Link> {
+    if (sourceId === targetId) {
+      throw new Error(`Cannot link ${sourceId} to itself`);
+    }
+    if (isDecompositionLink(type)) {
+      assertNoCycle(await this.getAllLinks(), { sourceId, targetId, type });
+    }
+
     const nextId = await idService.getNextIdWithSync('links');
     const link: Link = {
       id: nextId,
@@ -141,6 +233,119 @@ class DiskLinkService extends BaseArtifactService<Link> {
     return this.save(link);
   }
 
+  /**
+   * IDs of the artifacts that exist among the endpoints of some link specs
+   */
+  private async findExistingEndpoints(specs: LinkSpec[]): Promise<Set<string>> {
+    // Loaded lazily: the artifact services include this one
+    const { ALL_ARTIFACT_SERVICES } = await import('./artifactServices');
+    const ids = specs.flatMap((spec) => [spec.sourceId, spec.targetId]);
+    const existing = new Set<string>();
+    for (const type of new Set(ids.map(getTypeFromId))) {
+      const service = ALL_ARTIFACT_SERVICES[type];
+      if (!service) continue;
+      for (const artifact of await service.loadAll()) existing.add(artifact.id);
+    }
+    return existing;
+  }
+
+  /**
+   * Create many links at once (e.g. TC-010..TC-025 → REQ-003) and commit them
+   * together with the counter update as a single git commit, under the write
+   * journal. Links whose source or target does not exist are reported as
+   * missing, and links that already exist with the same endpoints and type
+   * are skipped; a derivation or refinement link that would close a cycle
+   * fails the whole batch before anything is written.
+   */
+  async createLinks(specs: LinkSpec[], commitMessage?: string): Promise<BulkLinkResult> {
+    const selfLink = specs.find((spec) => spec.sourceId === spec.targetId);
+    if (selfLink) {
+      throw new Error(`Cannot link ${selfLink.sourceId} to itself`);
+    }
+
+    const allLinks = await this.getAllLinks();
+    const existingKeys = new Set(
+      allLinks.map((link) => `${link.sourceId}:${link.targetId}:${link.type}`)
+    );
+    const endpoints = await this.findExistingEndpoints(specs);
+    const toCreate: LinkSpec[] = [];
+    const skipped: LinkSpec[] = [];
+    const missing: LinkSpec[] = [];
+    specs.forEach((spec) => {
+      const key = `${spec.sourceId}:${spec.targetId}:${spec.type}`;
+      if (!endpoints.has(spec.sourceId) || !endpoints.has(spec.targetId)) {
+        missing.push(spec);
+      } else if (existingKeys.has(key)) {
+        skipped.push(spec);
+      } else {
+        existingKeys.add(key);
+        toCreate.push(spec);
+      }
+    });
+
+    if (toCreate.length === 0) {
+      return { created: [], skipped, missing };
+    }
+
+    const accepted: LinkSpec[] = [...allLinks];
+    toCreate.forEach((spec) => {
+      assertNoCycle(accepted, spec);
+      accepted.push(spec);
+    });
+
+    const now = Date.now();
+    const created: Link[] = [];
+    await runJournaled(
+      'Create links',
+      async (journal) => {
+        await journal.record(idService.getCounterPath('links'));
+        const ids = await idService.getNextIds('links', toCreate.length, true);
+        for (let i = 0; i < toCreate.length; i++) {
+          const spec = toCreate[i];
+          await journal.record(this.getFilePath(ids[i]));
+          created.push(
+            await this.save({
+              id: ids[i],
+              sourceId: spec.sourceId,
+              targetId: spec.targetId,
+              type: spec.type,
+              projectIds: spec.projectIds ?? [],
+              dateCreated: now,
+              lastModified: now,
+              revision: '01',
+            })
+          );
+        }
+      },
+      commitMessage ?? `Create ${toCreate.length} link(s)`
+    );
+
+    return { created, skipped, missing };
+  }
+
+  /**
+   * Delete many links at once as a single git commit, under the write journal.
+   * Unknown IDs are ignored; returns the IDs that were deleted.
+   */
+  async deleteLinks(ids: string[], commitMessage?: string): Promise<string[]> {
+    const existingIds = new Set((await this.getAllLinks()).map((link) => link.id));
+    const toDelete = [...new Set(ids)].filter((id) => existingIds.has(id));
+    if (toDelete.length === 0) return [];
+
+    await runJournaled(
+      'Delete links',
+      async (journal) => {
+        for (const id of toDelete) {
+          await journal.record(this.getFilePath(id));
+          await this.delete(id);
+        }
+      },
+      commitMessage ?? `Delete ${toDelete.length} link(s)`
+    );
+
+    return toDelete;
+  }
+
   /**
    * Delete a link
    */
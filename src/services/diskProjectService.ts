@@ -25,6 +25,7 @@
  *   ...
  * current-project.md   # current project ID
  * current-user.md      # current user ID
+ * project.tracyfy      # marker; opening it (file association) opens the folder
  */
 
 import { BaseDiskService } from './baseDiskService';
@@ -52,9 +53,11 @@ import {
   documentService,
 } from './artifactServices';
 import { diskLinkService } from './diskLinkService';
+import { artifactOrderService } from './artifactOrderService';
 
 const CURRENT_PROJECT_FILE = 'current-project.md';
 const CURRENT_USER_FILE = 'current-user.md';
+export const PROJECT_MARKER_FILE = 'project.tracyfy';
 
 class DiskProjectService extends BaseDiskService {
   /**
@@ -66,6 +69,12 @@ class DiskProjectService extends BaseDiskService {
       await this.ensureDirectory(config.folder);
     }
     await this.ensureDirectory('counters');
+    if (!(await this.exists(PROJECT_MARKER_FILE))) {
+      await this.writeTextFile(
+        PROJECT_MARKER_FILE,
+        'Tracyfy project. Open this file to open the folder in the Tracyfy desktop app.\n'
+      );
+    }
   }
 
   // ============ COUNTER OPERATIONS (Delegated to IdService) ============
@@ -310,14 +319,15 @@ class DiskProjectService extends BaseDiskService {
       this.getCurrentUserId(),
     ]);
 
+    // Lists follow the manual reading order of each folder
     return {
       projects,
       currentProjectId,
-      requirements,
-      useCases,
-      testCases,
-      information,
-      risks,
+      requirements: await artifactOrderService.sortByOrder('requirements', requirements),
+      useCases: await artifactOrderService.sortByOrder('usecases', useCases),
+      testCases: await artifactOrderService.sortByOrder('testcases', testCases),
+      information: await artifactOrderService.sortByOrder('information', information),
+      risks: await artifactOrderService.sortByOrder('risks', risks),
       documents,
       links,
       users,
@@ -0,0 +1,73 @@
+/**
+ * Draft Service
+ *
+ * Autosaved editor content, kept apart from the artifact file until the user
+ * saves. Drafts live in .tracyfy/drafts, one JSON file per artifact path,
+ * which is never committed; drafts of encrypted artifacts are encrypted the
+ * same way.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { APP_STATE_DIR } from './git/types';
+import { debug } from '../utils/debug';
+
+const DRAFTS_DIR = `${APP_STATE_DIR}/drafts`;
+
+export interface Draft {
+  path: string; // Artifact file the draft belongs to
+  content: string;
+  savedAt: number;
+}
+
+// One flat file per artifact, e.g. requirements%2FREQ-001.md.json
+function getDraftPath(artifactPath: string): string {
+  return `${DRAFTS_DIR}/${encodeURIComponent(artifactPath)}.json`;
+}
+
+class DraftService extends BaseDiskService {
+  // Serializes draft writes, so an autosave still in flight cannot bring back
+  // a draft that was just discarded
+  private queue: Promise<unknown> = Promise.resolve();
+
+  private enqueue<T>(operation: () => Promise<T>): Promise<T> {
+    const result = this.queue.then(operation);
+    this.queue = result.catch(() => undefined);
+    return result;
+  }
+
+  /**
+   * Store in-progress content for an artifact, replacing any earlier draft
+   */
+  async saveDraft(artifactPath: string, content: string): Promise<Draft> {
+    const draft: Draft = { path: artifactPath, content, savedAt: Date.now() };
+    await this.enqueue(() =>
+      this.writeTextFile(getDraftPath(artifactPath), JSON.stringify(draft), undefined, artifactPath)
+    );
+    return draft;
+  }
+
+  /**
+   * The artifact's draft, or null when there is none (or it is unreadable)
+   */
+  async loadDraft(artifactPath: string): Promise<Draft | null> {
+    const text = await this.readTextFile(getDraftPath(artifactPath));
+    if (!text) return null;
+    try {
+      const draft = JSON.parse(text) as Draft;
+      if (draft.path !== artifactPath || typeof draft.content !== 'string') return null;
+      return draft;
+    } catch {
+      debug.warn(`[DraftService] Ignoring unreadable draft for ${artifactPath}`);
+      return null;
+    }
+  }
+
+  /**
+   * Drop the artifact's draft, after saving or abandoning the edit
+   */
+  async discardDraft(artifactPath: string): Promise<void> {
+    await this.enqueue(() => this.deleteFile(getDraftPath(artifactPath)));
+  }
+}
+
+export const draftService = new DraftService();
@@ -0,0 +1,135 @@
+/**
+ * Drop Import Service
+ *
+ * Imports files dropped onto the app into the current project: markdown
+ * files become artifacts (type guessed as in the markdown folder import,
+ * with new IDs and frontmatter), other files are copied to assets/ as
+ * attachments. Everything is written under the write journal and committed
+ * in one commit. Files are passed with their contents, as a drop event
+ * delivers them in both the browser and the desktop app.
+ */
+
+import { baseDiskService } from './baseDiskService';
+import { projectService } from './artifactServices';
+import { idService } from './idService';
+import { buildImportedArtifact, PROJECT_ID_FIELDS } from './markdownImportService';
+import { getStatusWorkflow } from './statusWorkflowService';
+import { runJournaled } from './writeJournalService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { classifyMarkdown, type ImportTypeKey } from '../utils/markdownImportUtils';
+import { debug } from '../utils/debug';
+
+const ASSETS_DIR = 'assets';
+const MAX_FILE_SIZE = 25 * 1024 * 1024;
+const MARKDOWN_PATTERN = /\.md$/i;
+
+export interface DroppedFile {
+  name: string;
+  data: Uint8Array;
+}
+
+export type DropImportItem =
+  | { name: string; kind: 'artifact'; id: string; typeKey: ImportTypeKey; title: string }
+  | { name: string; kind: 'attachment'; path: string };
+
+export interface DropImportReport {
+  created: DropImportItem[];
+  skipped: { name: string; reason: string }[];
+}
+
+function getSkipReason(file: DroppedFile): string | null {
+  if (file.name.startsWith('.')) return 'hidden file';
+  if (file.data.length === 0) return 'empty file';
+  if (file.data.length > MAX_FILE_SIZE) return 'larger than 25 MB';
+  return null;
+}
+
+// Attachment file name: random, keeping the extension (e.g. 9f1c...e2.pdf)
+function getAttachmentPath(name: string): string {
+  const dot = name.lastIndexOf('.');
+  const ext = dot > 0 ? name.slice(dot + 1).toLowerCase() : '';
+  const id = globalThis.crypto.randomUUID();
+  return `${ASSETS_DIR}/${/^[a-z0-9]{1,10}$/.test(ext) ? `${id}.${ext}` : id}`;
+}
+
+/**
+ * Add dropped files to a project and report what was created and skipped
+ */
+export async function importDroppedFiles(
+  files: DroppedFile[],
+  projectId: string
+): Promise<DropImportReport> {
+  const project = await projectService.load(projectId);
+  if (!project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const report: DropImportReport = { created: [], skipped: [] };
+  const markdown = new Map<ImportTypeKey, { name: string; text: string }[]>();
+  const attachments: DroppedFile[] = [];
+  const accepted: string[] = [];
+  for (const file of files) {
+    const reason = getSkipReason(file);
+    if (reason) {
+      report.skipped.push({ name: file.name, reason });
+      continue;
+    }
+    accepted.push(file.name);
+    if (MARKDOWN_PATTERN.test(file.name)) {
+      const text = new TextDecoder().decode(file.data);
+      const typeKey = classifyMarkdown(file.name, text);
+      markdown.set(typeKey, [...(markdown.get(typeKey) || []), { name: file.name, text }]);
+    } else {
+      attachments.push(file);
+    }
+  }
+  if (accepted.length === 0) return report;
+
+  const { initialStatus } = await getStatusWorkflow();
+  const now = Date.now();
+  await runJournaled(
+    'Import dropped files',
+    async (journal) => {
+      for (const [typeKey, entries] of markdown) {
+        await journal.record(idService.getCounterPath(typeKey));
+        const ids = await idService.getNextIds(typeKey, entries.length, true);
+        for (const [i, { name, text }] of entries.entries()) {
+          const { title, content } = buildImportedArtifact(typeKey, name, text, {
+            id: ids[i],
+            status: initialStatus,
+            now,
+          });
+          const path = `${ARTIFACT_CONFIG[typeKey].folder}/${ids[i]}.md`;
+          await journal.record(path);
+          await baseDiskService.writeTextFile(path, content);
+          report.created.push({ name, kind: 'artifact', id: ids[i], typeKey, title });
+        }
+        const field = PROJECT_ID_FIELDS[typeKey];
+        project[field] = [...(project[field] || []), ...ids];
+      }
+
+      for (const file of attachments) {
+        const path = getAttachmentPath(file.name);
+        await journal.record(path);
+        await baseDiskService.writeBinaryFile(path, file.data);
+        report.created.push({ name: file.name, kind: 'attachment', path });
+      }
+
+      if (markdown.size > 0) {
+        project.lastModified = now;
+        await journal.record(`${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`);
+        await projectService.save(project);
+      }
+    },
+    accepted.length === 1 ? `Import ${accepted[0]}` : `Import ${accepted.length} dropped files`
+  );
+
+  debug.log(
+    `[DropImportService] Created ${report.created.length} items, skipped ${report.skipped.length}`
+  );
+  return report;
+}
+
+export const dropImportService = {
+  importDroppedFiles,
+};
@@ -0,0 +1,90 @@
+/**
+ * Duplicate Detection Service
+ *
+ * Finds pairs of artifacts with highly similar text, e.g. the same requirement
+ * imported from two source documents. Only the markdown body is compared;
+ * frontmatter and section headings are ignored.
+ */
+
+import type { Project } from '../types';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+import {
+  findSimilarPairs,
+  type SimilarityDocument,
+  type SimilarityOptions,
+  type SimilarPair,
+} from '../utils/similarityUtils';
+import { ALL_ARTIFACT_SERVICES, projectService } from './artifactServices';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { debug } from '../utils/debug';
+
+// Artifact types scanned for duplicates
+export const DUPLICATE_SCAN_TYPES = [
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+];
+
+export interface DuplicateScanOptions extends SimilarityOptions {
+  typeKeys?: string[];
+  projectId?: string;
+  crossType?: boolean; // Also compare artifacts of different types
+}
+
+interface ScannedArtifact {
+  id: string;
+  isDeleted?: boolean;
+}
+
+/**
+ * The comparable text of an artifact file: its body without headings
+ */
+export function getComparableText(markdown: string): string {
+  const { body } = parseYamlFrontmatter(markdown);
+  return body
+    .split('\n')
+    .filter((line) => !/^#{1,6}\s/.test(line.trim()))
+    .join('\n');
+}
+
+/**
+ * Report pairs of artifacts whose text similarity reaches the threshold.
+ * By default only artifacts of the same type are compared.
+ */
+export async function findNearDuplicates(
+  options: DuplicateScanOptions = {}
+): Promise<SimilarPair[]> {
+  const { typeKeys = DUPLICATE_SCAN_TYPES, projectId, crossType = false } = options;
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const groups = await Promise.all(
+    typeKeys
+      .filter((typeKey) => DUPLICATE_SCAN_TYPES.includes(typeKey))
+      .map(async (typeKey): Promise<SimilarityDocument[]> => {
+        const service = ALL_ARTIFACT_SERVICES[typeKey];
+        const membershipField = PROJECT_MEMBERSHIP[typeKey];
+        const memberIds =
+          project && membershipField ? new Set((project[membershipField] as string[]) || []) : null;
+        const artifacts: ScannedArtifact[] = await service.loadAll();
+        return artifacts
+          .filter((a) => !a.isDeleted && (!memberIds || memberIds.has(a.id)))
+          .map((a) => ({ id: a.id, text: getComparableText(service.serialize(a)) }));
+      })
+  );
+
+  const pairs = crossType
+    ? findSimilarPairs(groups.flat(), options)
+    : groups.flatMap((documents) => findSimilarPairs(documents, options));
+  debug.log(`[DuplicateDetectionService] ${pairs.length} near-duplicate pair(s) found`);
+  return pairs.sort((x, y) => y.score - x.score);
+}
+
+export const duplicateDetectionService = {
+  getComparableText,
+  findNearDuplicates,
+};
@@ -0,0 +1,161 @@
+/**
+ * Encryption Service
+ *
+ * Optional encryption at rest for sensitive projects. When enabled, artifact
+ * files and comments (see getEncryptedFolders) are stored AES-256-GCM
+ * encrypted and decrypted transparently by BaseDiskService; project
+ * structure, links, approvals and config stay plaintext. The key never
+ * enters the repository: it is kept in the OS keychain through
+ * secretService, and must be exported and imported to open the project on
+ * another machine.
+ *
+ * Enabling encryption does not rewrite history, so content committed before
+ * stays readable in git.
+ */
+
+import { baseDiskService, setEncryptionKey, type FileChange } from './baseDiskService';
+import { secretService } from './secretService';
+import { projectConfigService } from './projectConfigService';
+import { generateEncryptionKey, getEncryptedFolders } from '../utils/encryptionUtils';
+import { debug } from '../utils/debug';
+
+const ENCRYPTION_SECTION = 'encryption';
+
+export interface EncryptionSettings {
+  enabled: boolean;
+  keyId: string; // Identifies the project's key in the keychain
+}
+
+const DEFAULT_SETTINGS: EncryptionSettings = { enabled: false, keyId: '' };
+
+function keyName(keyId: string): string {
+  return `encryption-${keyId}`;
+}
+
+export async function getEncryptionSettings(): Promise<EncryptionSettings> {
+  return projectConfigService.getSection(ENCRYPTION_SECTION, DEFAULT_SETTINGS);
+}
+
+/**
+ * Load the open project's key from the keychain; call before reading
+ * artifacts. Throws when the project is encrypted and the key is missing.
+ */
+export async function loadEncryption(): Promise<boolean> {
+  setEncryptionKey(null);
+  const settings = await getEncryptionSettings();
+  if (!settings.enabled) return false;
+
+  const key = await secretService.getSecret(keyName(settings.keyId));
+  if (!key) {
+    throw new Error('This project is encrypted and its key is not on this machine; import it');
+  }
+  setEncryptionKey(key);
+  return true;
+}
+
+// Current contents of all artifact and comment files, decrypted with the loaded key
+async function readArtifactFiles(): Promise<FileChange[]> {
+  const changes: FileChange[] = [];
+  for (const folder of getEncryptedFolders()) {
+    for (const file of await baseDiskService.listFiles(folder)) {
+      if (!/\.(md|json)$/.test(file)) continue;
+      const path = `${folder}/${file}`;
+      changes.push({ path, content: await baseDiskService.readTextFile(path) });
+    }
+  }
+  return changes;
+}
+
+// Re-save files with the current key setting in one commit
+async function rewriteArtifactFiles(files: FileChange[], commitMessage: string): Promise<void> {
+  if (files.length > 0) {
+    await baseDiskService.writeTextFilesAndCommit(files, commitMessage);
+  }
+  debug.log(`[EncryptionService] Rewrote ${files.length} artifact files`);
+}
+
+/**
+ * Turn on encryption: create a key, store it in the keychain and encrypt all
+ * artifact files. Returns the key so the user can back it up.
+ */
+export async function enableEncryption(): Promise<string> {
+  const settings = await getEncryptionSettings();
+  if (settings.enabled) {
+    throw new Error('Encryption is already enabled');
+  }
+
+  const files = await readArtifactFiles();
+  const keyId = globalThis.crypto.randomUUID();
+  const key = generateEncryptionKey();
+  await secretService.storeSecret(keyName(keyId), key);
+  await projectConfigService.setSection(
+    ENCRYPTION_SECTION,
+    { enabled: true, keyId },
+    'Enable encryption at rest'
+  );
+  setEncryptionKey(key);
+  await rewriteArtifactFiles(files, 'Encrypt artifacts at rest');
+  return key;
+}
+
+/**
+ * Turn off encryption: decrypt all artifact files and forget the key
+ */
+export async function disableEncryption(): Promise<void> {
+  const settings = await getEncryptionSettings();
+  if (!settings.enabled) return;
+
+  // Reading with the key loaded decrypts; writing without it stores plaintext
+  await loadEncryption();
+  const files = await readArtifactFiles();
+  setEncryptionKey(null);
+  await rewriteArtifactFiles(files, 'Decrypt artifacts');
+  await projectConfigService.setSection(
+    ENCRYPTION_SECTION,
+    DEFAULT_SETTINGS,
+    'Disable encryption at rest'
+  );
+  await secretService.deleteSecret(keyName(settings.keyId));
+}
+
+/**
+ * The project's key, for backing it up or moving it to another machine
+ */
+export async function exportEncryptionKey(): Promise<string> {
+  const settings = await getEncryptionSettings();
+  const key = settings.enabled ? await secretService.getSecret(keyName(settings.keyId)) : null;
+  if (!key) {
+    throw new Error('No encryption key for this project');
+  }
+  return key;
+}
+
+/**
+ * Store an exported key in this machine's keychain, after checking that it
+ * decrypts the project's files
+ */
+export async function importEncryptionKey(key: string): Promise<void> {
+  const settings = await getEncryptionSettings();
+  if (!settings.enabled) {
+    throw new Error('This project is not encrypted');
+  }
+
+  // Reading every file fails on a wrong key
+  setEncryptionKey(key.trim());
+  try {
+    await readArtifactFiles();
+  } catch (err) {
+    setEncryptionKey(null);
+    throw err;
+  }
+  await secretService.storeSecret(keyName(settings.keyId), key.trim());
+}
+
+export const encryptionService = {
+  getEncryptionSettings,
+  loadEncryption,
+  enableEncryption,
+  disableEncryption,
+  exportEncryptionKey,
+  importEncryptionKey,
+};
@@ -0,0 +1,105 @@
+/**
+ * External Issue Service
+ *
+ * Artifact side of the issue tracker integrations (Jira, GitHub, GitLab): loading
+ * the artifacts that can be synced, listing the artifact-to-issue mapping
+ * kept in their externalIssues frontmatter, and unlinking issues.
+ */
+
+import type { ExternalIssueRef, IssueTracker } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { WORKFLOW_TYPES } from './statusWorkflowService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { ISSUE_TRACKER_LABELS, getIssueRefs, removeIssueRef } from '../utils/externalIssueUtils';
+
+export interface SyncArtifact {
+  id: string;
+  title: string;
+  description?: string;
+  text?: string;
+  status?: string;
+  tags?: string[];
+  statusChangedBy?: string;
+  statusChangedAt?: number;
+  lastModified: number;
+  externalIssues?: ExternalIssueRef[];
+}
+
+export interface LinkedArtifact {
+  typeKey: string;
+  artifact: SyncArtifact;
+}
+
+export interface ExternalIssueMapping {
+  artifactId: string;
+  issueKey: string;
+  url?: string;
+  status?: string;
+}
+
+/**
+ * Repo-relative path of a synced artifact
+ */
+export function getSyncArtifactPath(typeKey: string, id: string): string {
+  return `${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`;
+}
+
+/**
+ * Load an artifact that can be linked to issues
+ */
+export async function loadSyncArtifact(id: string): Promise<LinkedArtifact> {
+  const typeKey = getTypeFromId(id);
+  if (!WORKFLOW_TYPES.includes(typeKey)) {
+    throw new Error(`Artifact type cannot be synced: ${id}`);
+  }
+  const artifact: SyncArtifact | null = await ALL_ARTIFACT_SERVICES[typeKey].load(id);
+  if (!artifact) {
+    throw new Error(`Artifact not found: ${id}`);
+  }
+  return { typeKey, artifact };
+}
+
+/**
+ * All artifacts linked to at least one issue of the tracker
+ */
+export async function loadLinkedArtifacts(tracker: IssueTracker): Promise<LinkedArtifact[]> {
+  const lists = await Promise.all(
+    WORKFLOW_TYPES.map(async (typeKey) => {
+      const artifacts: SyncArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+      return artifacts.map((artifact) => ({ typeKey, artifact }));
+    })
+  );
+  return lists
+    .flat()
+    .filter(({ artifact }) => getIssueRefs(artifact.externalIssues, tracker).length > 0);
+}
+
+/**
+ * The artifact-to-issue links of a tracker
+ */
+export async function getIssueMapping(tracker: IssueTracker): Promise<ExternalIssueMapping[]> {
+  const linked = await loadLinkedArtifacts(tracker);
+  return linked.flatMap(({ artifact }) =>
+    getIssueRefs(artifact.externalIssues, tracker).map((ref) => ({
+      artifactId: artifact.id,
+      issueKey: ref.key,
+      url: ref.url,
+      status: ref.status,
+    }))
+  );
+}
+
+/**
+ * Remove the link between an artifact and an issue
+ */
+export async function unlinkIssue(
+  tracker: IssueTracker,
+  artifactId: string,
+  issueKey: string
+): Promise<void> {
+  const { typeKey, artifact } = await loadSyncArtifact(artifactId);
+  await ALL_ARTIFACT_SERVICES[typeKey].save(
+    { ...artifact, externalIssues: removeIssueRef(artifact.externalIssues, tracker, issueKey) },
+    `Unlink ${artifactId} from ${ISSUE_TRACKER_LABELS[tracker]} issue ${issueKey}`
+  );
+}
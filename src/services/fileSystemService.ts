@@ -7,34 +7,97 @@
  */
 
 import { debug } from '../utils/debug';
+import {
+  decodeText,
+  encodeText,
+  type DecodedText,
+  type TextEncodingName,
+} from '../utils/encodingUtils';
 
 // Store the directory handle in IndexedDB for persistence
 const DB_NAME = 'tracyfy-fs-handles';
 const STORE_NAME = 'handles';
 
-interface DirectoryState {
+export interface DirectoryState {
   handle?: FileSystemDirectoryHandle;
   hasGit: boolean;
   path?: string;
 }
 
+// Result of one batched operation as sent by the main process
+interface RawOperationResult {
+  content?: string;
+  data?: number[];
+  files?: string[];
+  links?: LinkFile[];
+  mtime?: number;
+  size?: number;
+  notFound?: boolean;
+  error?: string;
+  code?: string;
+}
+
 // Electron API interface for file system operations
 interface ElectronFsAPI {
   isElectron: boolean;
   fs: {
     selectDirectory: () => Promise<{ canceled: boolean; path: string }>;
     checkExists: (path: string) => Promise<{ exists: boolean }>;
-    readFile: (path: string) => Promise<{ content?: string; notFound?: boolean; error?: string }>;
+    readFile: (path: string) => Promise<{
+      content?: string;
+      data?: number[];
+      notFound?: boolean;
+      error?: string;
+      code?: string;
+    }>;
+    readFiles: (paths: string[]) => Promise<{
+      results?: { content?: string; data?: number[]; notFound?: boolean; error?: string }[];
+      error?: string;
+      code?: string;
+    }>;
+    statFiles: (paths: string[]) => Promise<{
+      results?: { mtime?: number; size?: number; notFound?: boolean; error?: string }[];
+      error?: string;
+      code?: string;
+    }>;
+    batch: (
+      operations: FileOperation[]
+    ) => Promise<{ results?: RawOperationResult[]; error?: string; code?: string }>;
     readFileBinary: (
       path: string
-    ) => Promise<{ data?: number[]; notFound?: boolean; error?: string }>;
-    writeFile: (path: string, content: string) => Promise<{ error?: string }>;
-    writeFileBinary: (path: string, data: number[]) => Promise<{ error?: string }>;
-    deleteFile: (path: string) => Promise<void>;
+    ) => Promise<{ data?: number[]; notFound?: boolean; error?: string; code?: string }>;
+    readFileChunk: (
+      path: string,
+      offset: number,
+      length: number
+    ) => Promise<{
+      data?: number[];
+      totalSize?: number;
+      notFound?: boolean;
+      error?: string;
+      code?: string;
+    }>;
+    writeFile: (path: string, content: string) => Promise<{ error?: string; code?: string }>;
+    writeFileBinary: (
+      path: string,
+      data: number[]
+    ) => Promise<{ error?: string; code?: string }>;
+    deleteFile: (path: string) => Promise<{ error?: string; code?: string } | undefined>;
     listFiles: (path: string) => Promise<{ files: string[]; error?: string }>;
     listEntries: (path: string) => Promise<{ entries: string[]; error?: string }>;
     mkdir: (path: string) => Promise<void>;
   };
+  project: {
+    open: (
+      dir: string
+    ) => Promise<{ path?: string; hasGit?: boolean; readOnly?: boolean; error?: string }>;
+    close: () => Promise<{ ok?: boolean; error?: string }>;
+    setReadOnly: (
+      readOnly: boolean
+    ) => Promise<{ readOnly?: boolean; canceled?: boolean; error?: string }>;
+    onFilesChanged: (handler: (paths: string[]) => void) => () => void;
+    onExternalChanges: (handler: (paths: string[]) => void) => () => void;
+  };
 }
 
 // Check if running in Electron
@@ -54,13 +117,120 @@ function getElectronAPI(): ElectronFsAPI {
   return api;
 }
 
+// Error codes of the main process for paths leading outside the open project,
+// for changes refused while the project is read-only and for malformed arguments
+const PATH_OUTSIDE_PROJECT = 'EOUTSIDEPROJECT';
+const READ_ONLY_PROJECT = 'EREADONLY';
+const INVALID_ARGUMENT = 'EINVALIDARG';
+
+/**
+ * Thrown when the desktop app refuses access to a path outside the open project
+ */
+export class PathOutsideProjectError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'PathOutsideProjectError';
+  }
+}
+
+/**
+ * Thrown when the desktop app refuses a change because the project is read-only
+ */
+export class ReadOnlyProjectError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'ReadOnlyProjectError';
+  }
+}
+
+/**
+ * Thrown when the desktop app refuses a command because an argument is
+ * malformed, e.g. an empty path
+ */
+export class InvalidArgumentError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'InvalidArgumentError';
+  }
+}
+
+// Error for a file operation the main process failed
+function fileAccessError(result: { error?: string; code?: string }): Error {
+  if (result.code === PATH_OUTSIDE_PROJECT) return new PathOutsideProjectError(result.error!);
+  if (result.code === READ_ONLY_PROJECT) return new ReadOnlyProjectError(result.error!);
+  if (result.code === INVALID_ARGUMENT) return new InvalidArgumentError(result.error!);
+  return new Error(result.error);
+}
+
+// Text read as UTF-8 by the main process may still start with a BOM
+function decodeContent(content: string): DecodedText {
+  return content.startsWith('\uFEFF')
+    ? { content: content.slice(1), encoding: 'utf-8-bom' }
+    : { content, encoding: 'utf-8' };
+}
+
 // LocalStorage key for persisting Electron directory path
 const ELECTRON_DIR_KEY = 'tracyfy-electron-dir';
 
+// Result of reading one file in a batch; content is null if the file does not exist
+export interface BatchReadEntry {
+  content: string | null;
+  encoding?: TextEncodingName;
+  error?: string;
+}
+
+// Chunk size for reading large files piecewise (1 MiB)
+export const DEFAULT_CHUNK_SIZE = 1024 * 1024;
+
+export type FileOperation =
+  | { type: 'read'; path: string }
+  | { type: 'write'; path: string; content: string }
+  | { type: 'delete'; path: string }
+  | { type: 'list'; path: string }
+  | { type: 'stat'; path: string }
+  | { type: 'link'; artifactId: string } // Read the link files touching an artifact
+  | { type: 'link'; linkId: string; content: string }; // Write one link file
+
+// A standalone link file (links/<id>.md) with its text
+export interface LinkFile {
+  path: string;
+  content: string;
+}
+
+export interface FileOperationResult {
+  content?: string | null; // read: null when the file does not exist
+  files?: string[]; // list: file names in the directory
+  links?: LinkFile[]; // link: the files of links from or to the artifact
+  stat?: FileStat | null; // stat: null when the file does not exist
+  error?: string;
+}
+
+const LINKS_FOLDER = 'links';
+
+// Whether a link file's frontmatter names the artifact as source or target
+function linkTouches(content: string, artifactId: string): boolean {
+  const id = artifactId.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  return new RegExp(`^(sourceId|targetId):[ \\t]*["']?${id}["']?[ \\t]*\\r?$`, 'm').test(content);
+}
+
+export interface FileStat {
+  mtime: number; // Last modification time in ms
+  size: number; // Size in bytes
+}
+
+export interface FileChunk {
+  data: Uint8Array;
+  offset: number;
+  totalSize: number;
+  done: boolean; // True for the last chunk of the file
+}
+
 class FileSystemService {
   private directoryHandle: FileSystemDirectoryHandle | null = null;
   private db: IDBDatabase | null = null;
   private rootPath: string | null = null; // For Electron: absolute path to root dir
+  private fileEncodings = new Map<string, TextEncodingName>(); // Non-UTF-8 files read so far
+  private readOnly = false; // Reviewer mode of the open project (Electron only)
 
   private get isE2EMode(): boolean {
     return (
@@ -155,20 +325,7 @@ class FileSystemService {
         throw new Error('Directory selection was cancelled');
       }
 
-      this.rootPath = result.path;
-
-      // Persist the directory path for later restoration
-      try {
-        localStorage.setItem(ELECTRON_DIR_KEY, result.path);
-      } catch {
-        debug.warn('[selectDirectory] Could not persist directory path');
-      }
-
-      // Check if .git exists
-      const gitPath = `${this.rootPath}/.git`;
-      const gitExists = await api.fs.checkExists(gitPath);
-
-      return { path: this.rootPath ?? undefined, hasGit: gitExists.exists };
+      return this.setElectronDirectory(result.path);
     }
 
     // Browser path: use File System Access API
@@ -199,6 +356,75 @@ class FileSystemService {
     }
   }
 
+  /**
+   * Open a directory given by path, e.g. from the command line (Electron only)
+   */
+  async openDirectoryPath(path: string): Promise<DirectoryState> {
+    if (!isElectron()) {
+      throw new Error('Opening a directory by path is only available in the desktop app');
+    }
+    return this.setElectronDirectory(path);
+  }
+
+  // Open path as the project in the main process, which validates it and from
+  // then on only allows file access inside it; remember it for the next start
+  private async setElectronDirectory(path: string): Promise<DirectoryState> {
+    const result = await getElectronAPI().project.open(path);
+    if (result.error || !result.path) {
+      throw new Error(result.error || `Could not open ${path}`);
+    }
+    this.rootPath = result.path;
+    this.readOnly = !!result.readOnly;
+
+    // Persist the directory path for later restoration
+    try {
+      localStorage.setItem(ELECTRON_DIR_KEY, result.path);
+    } catch {
+      debug.warn('[selectDirectory] Could not persist directory path');
+    }
+
+    return { path: result.path, hasGit: !!result.hasGit };
+  }
+
+  /**
+   * Turn reviewer mode of the open project on or off (Electron only). While on,
+   * the main process refuses writes, deletes, commits and other changes to the
+   * project; reads and exports keep working. Main remembers it for the project
+   * folder and asks the user in a dialog before turning it off. Returns
+   * whether the project is read-only afterwards.
+   */
+  async setReadOnly(readOnly: boolean): Promise<boolean> {
+    if (!isElectron()) {
+      throw new Error('Read-only mode is only available in the desktop app');
+    }
+    const result = await getElectronAPI().project.setReadOnly(readOnly);
+    if (result.error) throw new Error(result.error);
+    this.readOnly = !!result.readOnly;
+    return this.readOnly;
+  }
+
+  isReadOnly(): boolean {
+    return this.readOnly;
+  }
+
+  /**
+   * Call handler with the project paths changed on disk, by the app or other
+   * programs (Electron only). Returns the unsubscribe function.
+   */
+  onFilesChanged(handler: (paths: string[]) => void): () => void {
+    if (!isElectron()) return () => {};
+    return getElectronAPI().project.onFilesChanged(handler);
+  }
+
+  /**
+   * Call handler with the project paths changed on disk by other programs
+   * only, not by the app itself (Electron only). Returns the unsubscribe function.
+   */
+  onExternalChanges(handler: (paths: string[]) => void): () => void {
+    if (!isElectron()) return () => {};
+    return getElectronAPI().project.onExternalChanges(handler);
+  }
+
   /**
    * Try to restore previously selected directory
    * Returns null if no permission or handle not found
@@ -212,21 +438,16 @@ class FileSystemService {
           return null;
         }
 
-        const api = getElectronAPI();
-        // Verify the directory still exists
-        const exists = await api.fs.checkExists(storedPath);
-        if (!exists.exists) {
+        // Opening fails if the directory no longer exists
+        const result = await getElectronAPI().project.open(storedPath);
+        if (result.error || !result.path) {
           localStorage.removeItem(ELECTRON_DIR_KEY);
           return null;
         }
+        this.rootPath = result.path;
+        this.readOnly = !!result.readOnly;
 
-        this.rootPath = storedPath;
-
-        // Check if .git exists
-        const gitPath = `${storedPath}/.git`;
-        const gitExists = await api.fs.checkExists(gitPath);
-
-        return { path: storedPath, hasGit: gitExists.exists };
+        return { path: result.path, hasGit: !!result.hasGit };
       } catch {
         return null;
       }
@@ -372,10 +593,238 @@ class FileSystemService {
     }
   }
 
+  /**
+   * Encoding a file had when it was last read or written (UTF-8 if unknown)
+   */
+  getFileEncoding(path: string): TextEncodingName {
+    return this.fileEncodings.get(path) ?? 'utf-8';
+  }
+
+  private rememberEncoding(path: string, decoded: DecodedText): DecodedText {
+    if (decoded.encoding === 'utf-8') {
+      this.fileEncodings.delete(path);
+    } else {
+      debug.log(`[readFile] ${path} is ${decoded.encoding}, transcoded to UTF-8`);
+      this.fileEncodings.set(path, decoded.encoding);
+    }
+    return decoded;
+  }
+
   /**
    * Read a file as text
    */
   async readFile(path: string): Promise<string | null> {
+    const decoded = await this.readFileWithEncoding(path);
+    return decoded ? decoded.content : null;
+  }
+
+  /**
+   * Read several files in one call, keyed by path. A failure to read one file
+   * is reported in its entry instead of failing the whole batch.
+   */
+  async readFiles(paths: string[]): Promise<Record<string, BatchReadEntry>> {
+    const entries: Record<string, BatchReadEntry> = {};
+    if (this.isE2EMode) return entries;
+
+    if (isElectron()) {
+      if (!this.rootPath) {
+        throw new Error('No directory selected');
+      }
+      const api = getElectronAPI();
+      const response = await api.fs.readFiles(paths.map((path) => `${this.rootPath}/${path}`));
+      if (response.error) throw fileAccessError(response);
+      const results = response.results ?? [];
+      paths.forEach((path, i) => {
+        const result = results[i] || {};
+        if (result.error) {
+          entries[path] = { content: null, error: result.error };
+        } else if (result.notFound) {
+          entries[path] = { content: null };
+        } else {
+          const decoded = result.data
+            ? decodeText(new Uint8Array(result.data))
+            : decodeContent(result.content ?? '');
+          entries[path] = this.rememberEncoding(path, decoded);
+        }
+      });
+      return entries;
+    }
+
+    if (!this.directoryHandle) {
+      throw new Error('No directory selected');
+    }
+    await Promise.all(
+      paths.map(async (path) => {
+        try {
+          const decoded = await this.readFileWithEncoding(path);
+          entries[path] = decoded ?? { content: null };
+        } catch (error) {
+          entries[path] = { content: null, error: (error as Error).message };
+        }
+      })
+    );
+    return entries;
+  }
+
+  /**
+   * Modification time and size of several files, keyed by path. Missing or
+   * unreadable files map to null.
+   */
+  async statFiles(paths: string[]): Promise<Record<string, FileStat | null>> {
+    const stats: Record<string, FileStat | null> = {};
+    if (this.isE2EMode) return stats;
+
+    if (isElectron()) {
+      if (!this.rootPath) {
+        throw new Error('No directory selected');
+      }
+      const api = getElectronAPI();
+      const response = await api.fs.statFiles(paths.map((path) => `${this.rootPath}/${path}`));
+      if (response.error) throw fileAccessError(response);
+      const results = response.results ?? [];
+      paths.forEach((path, i) => {
+        const { mtime, size } = results[i] || {};
+        stats[path] = mtime !== undefined && size !== undefined ? { mtime, size } : null;
+      });
+      return stats;
+    }
+
+    if (!this.directoryHandle) {
+      throw new Error('No directory selected');
+    }
+    await Promise.all(
+      paths.map(async (path) => {
+        try {
+          const parts = path.split('/');
+          const fileName = parts.pop()!;
+          const dirPath = parts.join('/');
+          const dir = dirPath ? await this.getDirectory(dirPath) : this.directoryHandle;
+          if (!dir) {
+            stats[path] = null;
+            return;
+          }
+          const file = await (await dir.getFileHandle(fileName)).getFile();
+          stats[path] = { mtime: file.lastModified, size: file.size };
+        } catch {
+          stats[path] = null;
+        }
+      })
+    );
+    return stats;
+  }
+
+  /**
+   * Run several reads, writes, deletes, listings, stats and link reads or
+   * writes in order with a single round-trip to the main process. Each
+   * operation gets its own result at the same index; a failed one does not
+   * stop the rest. Writes are UTF-8.
+   */
+  async batch(operations: FileOperation[]): Promise<FileOperationResult[]> {
+    if (this.isE2EMode) return operations.map(() => ({}));
+
+    if (isElectron()) {
+      if (!this.rootPath) {
+        throw new Error('No directory selected');
+      }
+      const response = await getElectronAPI().fs.batch(
+        // Link operations name links and artifacts, not paths
+        operations.map((operation) =>
+          operation.type === 'link'
+            ? operation
+            : { ...operation, path: `${this.rootPath}/${operation.path}` }
+        )
+      );
+      if (response.error) throw fileAccessError(response);
+      const results = response.results ?? [];
+      return operations.map((operation, i) => {
+        const result = results[i] || {};
+        if (result.error) return { error: result.error };
+        switch (operation.type) {
+          case 'read': {
+            if (result.notFound) return { content: null };
+            const decoded = result.data
+              ? decodeText(new Uint8Array(result.data))
+              : decodeContent(result.content ?? '');
+            return { content: this.rememberEncoding(operation.path, decoded).content };
+          }
+          case 'write':
+            this.fileEncodings.delete(operation.path);
+            return {};
+          case 'list':
+            return { files: result.files ?? [] };
+          case 'link':
+            return 'content' in operation ? {} : { links: result.links ?? [] };
+          case 'stat':
+            return {
+              stat:
+                result.mtime !== undefined && result.size !== undefined
+                  ? { mtime: result.mtime, size: result.size }
+                  : null,
+            };
+          default:
+            return {};
+        }
+      });
+    }
+
+    // Browser: no process boundary to save, so run them one by one
+    const results: FileOperationResult[] = [];
+    for (const operation of operations) {
+      try {
+        switch (operation.type) {
+          case 'read':
+            results.push({ content: await this.readFile(operation.path) });
+            break;
+          case 'write':
+            await this.writeFile(operation.path, operation.content);
+            results.push({});
+            break;
+          case 'delete':
+            await this.deleteFile(operation.path);
+            results.push({});
+            break;
+          case 'list':
+            results.push({ files: await this.listFiles(operation.path) });
+            break;
+          case 'stat':
+            results.push({ stat: (await this.statFiles([operation.path]))[operation.path] });
+            break;
+          case 'link':
+            results.push(await this.runLinkOperation(operation));
+            break;
+        }
+      } catch (error) {
+        results.push({ error: (error as Error).message });
+      }
+    }
+    return results;
+  }
+
+  // Browser version of the main process's link operation
+  private async runLinkOperation(
+    operation: Extract<FileOperation, { type: 'link' }>
+  ): Promise<FileOperationResult> {
+    if ('content' in operation) {
+      await this.writeFile(`${LINKS_FOLDER}/${operation.linkId}.md`, operation.content);
+      return {};
+    }
+    const links: LinkFile[] = [];
+    for (const name of await this.listFiles(LINKS_FOLDER)) {
+      if (!name.endsWith('.md')) continue;
+      const path = `${LINKS_FOLDER}/${name}`;
+      const content = await this.readFile(path);
+      if (content !== null && linkTouches(content, operation.artifactId)) {
+        links.push({ path, content });
+      }
+    }
+    return { links };
+  }
+
+  /**
+   * Read a file as text, transcoding UTF-16 and Latin-1 content to a string
+   * and reporting the file's original encoding
+   */
+  async readFileWithEncoding(path: string): Promise<DecodedText | null> {
     // Electron path: use IPC
     if (this.isE2EMode) return null;
     if (isElectron()) {
@@ -387,8 +836,11 @@ class FileSystemService {
       const result = await api.fs.readFile(fullPath);
 
       if (result.notFound) return null;
-      if (result.error) throw new Error(result.error);
-      return result.content ?? null;
+      if (result.error) throw fileAccessError(result);
+      // Non-UTF-8 files come back as raw bytes
+      if (result.data) return this.rememberEncoding(path, decodeText(new Uint8Array(result.data)));
+      if (result.content === undefined) return null;
+      return this.rememberEncoding(path, decodeContent(result.content));
     }
 
     // Browser path: use FSA
@@ -411,7 +863,7 @@ class FileSystemService {
 
       const fileHandle = await dir.getFileHandle(fileName);
       const file = await fileHandle.getFile();
-      return await file.text();
+      return this.rememberEncoding(path, decodeText(new Uint8Array(await file.arrayBuffer())));
     } catch {
       return null;
     }
@@ -434,7 +886,7 @@ class FileSystemService {
       const result = await api.fs.readFileBinary(fullPath);
 
       if (result.notFound) return null;
-      if (result.error) throw new Error(result.error);
+      if (result.error) throw fileAccessError(result);
       if (!result.data) return null;
       return new Uint8Array(result.data);
     }
@@ -474,13 +926,96 @@ class FileSystemService {
   }
 
   /**
-   * Write text to a file
+   * Read a byte range of a file, so oversized files (imported logs, appendices)
+   * don't have to be transferred or held in memory at once
    */
-  async writeFile(path: string, content: string): Promise<void> {
+  async readFileChunk(path: string, offset: number, length: number): Promise<FileChunk | null> {
+    if (this.isE2EMode) return null;
+    if (isElectron()) {
+      if (!this.rootPath) {
+        throw new Error('No directory selected');
+      }
+      const api = getElectronAPI();
+      const fullPath = `${this.rootPath}/${path}`;
+      const result = await api.fs.readFileChunk(fullPath, offset, length);
+
+      if (result.notFound) return null;
+      if (result.error) throw fileAccessError(result);
+      const data = new Uint8Array(result.data || []);
+      const totalSize = result.totalSize ?? 0;
+      return { data, offset, totalSize, done: offset + data.length >= totalSize };
+    }
+
+    // Browser path: use FSA
+    if (!this.directoryHandle) {
+      throw new Error('No directory selected');
+    }
+
+    try {
+      const parts = path.split('/');
+      const fileName = parts.pop()!;
+      const dirPath = parts.join('/');
+
+      const dir = dirPath ? await this.getDirectory(dirPath) : this.directoryHandle;
+      if (!dir) return null;
+
+      const fileHandle = await dir.getFileHandle(fileName);
+      const file = await fileHandle.getFile();
+      const data = new Uint8Array(await file.slice(offset, offset + length).arrayBuffer());
+      return { data, offset, totalSize: file.size, done: offset + data.length >= file.size };
+    } catch (e) {
+      debug.log('[readFileChunk] Error reading file:', path, e);
+      return null;
+    }
+  }
+
+  /**
+   * Iterate over a file in chunks of the given size
+   */
+  async *readFileChunks(
+    path: string,
+    chunkSize: number = DEFAULT_CHUNK_SIZE
+  ): AsyncGenerator<FileChunk> {
+    let offset = 0;
+    for (;;) {
+      const chunk = await this.readFileChunk(path, offset, chunkSize);
+      if (!chunk) return;
+      yield chunk;
+      if (chunk.done || chunk.data.length === 0) return;
+      offset += chunk.data.length;
+    }
+  }
+
+  /**
+   * Iterate over a UTF-8 text file in pieces. Multi-byte characters split
+   * across chunk boundaries are carried over to the next piece.
+   */
+  async *readTextChunks(
+    path: string,
+    chunkSize: number = DEFAULT_CHUNK_SIZE
+  ): AsyncGenerator<string> {
+    const decoder = new TextDecoder('utf-8');
+    for await (const chunk of this.readFileChunks(path, chunkSize)) {
+      const text = decoder.decode(chunk.data, { stream: !chunk.done });
+      if (text) yield text;
+    }
+  }
+
+  /**
+   * Write text to a file. Text is written as UTF-8 unless another encoding is
+   * given, e.g. getFileEncoding(path) to preserve the encoding the file had.
+   */
+  async writeFile(path: string, content: string, encoding?: TextEncodingName): Promise<void> {
     debug.log(`[writeFile] Called for path: ${path}`);
 
     // Electron path: use IPC
     if (this.isE2EMode) return;
+    if (encoding && encoding !== 'utf-8') {
+      await this.writeFileBinary(path, encodeText(content, encoding));
+      this.fileEncodings.set(path, encoding);
+      return;
+    }
+    this.fileEncodings.delete(path);
     if (isElectron()) {
       if (!this.rootPath) {
         console.error('[writeFile] No directory selected');
@@ -500,7 +1035,7 @@ class FileSystemService {
 
       if (result.error) {
         console.error(`[writeFile] Error writing file: ${path}`, result.error);
-        throw new Error(result.error);
+        throw fileAccessError(result);
       }
       debug.log(`[writeFile] Successfully wrote file: ${path}`);
       return;
@@ -581,7 +1116,7 @@ class FileSystemService {
 
       if (result.error) {
         console.error(`[writeFileBinary] Error writing binary file: ${path}`, result.error);
-        throw new Error(result.error);
+        throw fileAccessError(result);
       }
       debug.log(`[writeFileBinary] Successfully wrote binary file: ${path}`);
       return;
@@ -652,7 +1187,8 @@ class FileSystemService {
       }
       const api = getElectronAPI();
       const fullPath = `${this.rootPath}/${path}`;
-      await api.fs.deleteFile(fullPath);
+      const result = await api.fs.deleteFile(fullPath);
+      if (result?.error) throw fileAccessError(result);
       return;
     }
 
@@ -846,6 +1382,8 @@ class FileSystemService {
         debug.warn('[clearDirectory] Could not remove localStorage entry');
       }
       this.rootPath = null;
+      this.readOnly = false;
+      await getElectronAPI().project.close();
       return;
     }
 
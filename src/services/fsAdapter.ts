@@ -122,9 +122,12 @@ class FSAdapter {
           return;
         }
 
-        // For regular files, convert to string and write to filesystem
-        const content = typeof data === 'string' ? data : new TextDecoder().decode(data);
-        await fileSystemService.writeFile(normalizedPath, content);
+        // Regular files: write bytes as-is so checkouts keep non-UTF-8 encodings intact
+        if (typeof data === 'string') {
+          await fileSystemService.writeFile(normalizedPath, data);
+        } else {
+          await fileSystemService.writeFileBinary(normalizedPath, data);
+        }
       } catch (err) {
         console.error('[FSAdapter.writeFile] ERROR writing:', normalizedPath, err);
         throw err;
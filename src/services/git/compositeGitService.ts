@@ -12,8 +12,10 @@ import {
   type ArtifactFolder,
   type TagDetails,
   type PullResult,
+  type SnapshotEntry,
 } from './types';
 import type { Requirement, UseCase, TestCase, Information } from '../../types';
+import type { WriteJournal } from '../writeJournalService';
 
 class CompositeGitService {
   private _initialized = false;
@@ -117,10 +119,31 @@ class CompositeGitService {
     return gitCoreService.commitFile(path, msg, author);
   }
 
+  async commitFiles(
+    paths: string[],
+    msg: string,
+    author?: string,
+    journal?: WriteJournal
+  ): Promise<void> {
+    return gitCoreService.commitFiles(paths, msg, author, journal);
+  }
+
   async revertFile(path: string): Promise<void> {
     return gitCoreService.revertFile(path);
   }
 
+  async writeBlob(content: string): Promise<string> {
+    return gitCoreService.writeBlob(content);
+  }
+
+  async writeSnapshotRef(ref: string, entries: SnapshotEntry[], msg: string): Promise<string> {
+    return gitCoreService.writeSnapshotRef(ref, entries, msg);
+  }
+
+  async resolveRef(ref: string): Promise<string | null> {
+    return gitCoreService.resolveRef(ref);
+  }
+
   async loadAllArtifacts(): Promise<{
     requirements: Requirement[];
     useCases: UseCase[];
@@ -8,7 +8,7 @@ import { debug } from '../../utils/debug';
 import git from 'isomorphic-git';
 import { fileSystemService } from '../fileSystemService';
 import { fsAdapter } from '../fsAdapter';
-import { isElectronEnv, type TagDetails } from './types';
+import { getCommitAuthor, isElectronEnv, type TagDetails } from './types';
 
 /**
  * Get the root directory path (Electron uses absolute path, browser uses '.')
@@ -36,7 +36,7 @@ class GitBaselineService {
       throw new Error('Git service not initialized');
     }
 
-    const author = { name: 'Tracyfy User', email: 'user@tracyfy.local' };
+    const author = await getCommitAuthor();
 
     if (isElectronEnv()) {
       const result = await window.electronAPI!.git.annotatedTag(
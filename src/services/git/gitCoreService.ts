@@ -8,7 +8,16 @@ import { debug } from '../../utils/debug';
 import git from 'isomorphic-git';
 import { fileSystemService } from '../fileSystemService';
 import { fsAdapter } from '../fsAdapter';
-import { isElectronEnv, parseStatusMatrix, type FileStatus, type ArtifactFolder } from './types';
+import { checkCommitForSecrets } from '../secretScanService';
+import type { WriteJournal } from '../writeJournalService';
+import {
+  getCommitAuthor,
+  isElectronEnv,
+  parseStatusMatrix,
+  type FileStatus,
+  type ArtifactFolder,
+  type SnapshotEntry,
+} from './types';
 import type {
   Requirement,
   UseCase,
@@ -371,7 +380,7 @@ class GitCoreService {
     await git.remove({ fs: fsAdapter, dir: getRootDir(), filepath: oldPath, cache });
     await git.add({ fs: fsAdapter, dir: getRootDir(), filepath: newPath, cache });
 
-    const author = { name: 'Tracyfy User', email: 'user@tracyfy.local' };
+    const author = await getCommitAuthor();
     await git.commit({
       fs: fsAdapter,
       dir: getRootDir(),
@@ -511,40 +520,73 @@ class GitCoreService {
 
   /**
    * Commit a single file (Atomic Commit)
-   * @param authorName - Optional author name, defaults to 'Tracyfy User'
+   * @param authorName - Optional author name, defaults to the active author profile
    */
   async commitFile(filepath: string, message: string, authorName?: string): Promise<void> {
+    return this.commitFiles([filepath], message, authorName);
+  }
+
+  /**
+   * Commit several files as a single commit (e.g. bulk operations).
+   * Files that no longer exist on disk are staged as deletions.
+   * @param authorName - Optional author name, defaults to the active author profile
+   * @param journal - Journal of the operation being committed; link files flagged
+   *   suspect are recorded in it, so they are rolled back with the operation
+   */
+  async commitFiles(
+    filepaths: string[],
+    message: string,
+    authorName?: string,
+    journal?: WriteJournal
+  ): Promise<void> {
     if (!this.initialized) {
       throw new Error('Git service not initialized');
     }
-
-    // Queue the commit to ensure serialized execution
-    this.commitQueue = this.commitQueue.then(async () => {
+    if (filepaths.length === 0) return;
+
+    const label = filepaths.length === 1 ? filepaths[0] : `${filepaths.length} files`;
+
+    // Queue the commit to ensure serialized execution. The secret check and the
+    // suspect link flags run in the queue too, on the files as they are committed.
+    const task = this.commitQueue.then(async () => {
+      // Without a journal, flagged link files are restored here if the commit fails
+      const originals = new Map<string, Uint8Array | null>();
+      const record = async (path: string) => {
+        if (!originals.has(path)) originals.set(path, await fileSystemService.readFileBinary(path));
+      };
       try {
-        debug.log(`[commitFile] Starting commit for ${filepath}...`);
-        const fileExists = (await fileSystemService.readFileBinary(filepath)) !== null;
-        debug.log(`[commitFile] File exists: ${fileExists}`);
-        const authorNameToUse = authorName || 'Tracyfy User';
-        const author = { name: authorNameToUse, email: 'user@tracyfy.local' };
+        // Refuses the commit, or warns, when the files look like they hold credentials
+        await checkCommitForSecrets(filepaths);
+
+        // An edited artifact's links need review again; they are committed with the edit
+        const linkFiles = await this.flagSuspectLinks(filepaths, journal ?? { record });
+        filepaths = [...filepaths, ...linkFiles.filter((path) => !filepaths.includes(path))];
+
+        debug.log(`[commitFile] Starting commit for ${label}...`);
+        const author = await getCommitAuthor(authorName);
+        const authorNameToUse = author.name;
 
         let commitOid: string;
 
         if (isElectronEnv()) {
           const rootDir = getRootDir();
           debug.log(`[commitFile] Electron mode, rootDir: ${rootDir}`);
-          if (fileExists) {
-            debug.log(`[commitFile] Adding file: ${filepath}`);
-            const res = await window.electronAPI!.git.add(rootDir, filepath);
-            if (res.error) {
-              debug.warn(`[commitFile] git.add failed: ${res.error}`);
-              throw new Error(`git.add failed: ${res.error}`);
-            }
-          } else {
-            debug.log(`[commitFile] Removing file: ${filepath}`);
-            const res = await window.electronAPI!.git.remove(rootDir, filepath);
-            if (res.error) {
-              debug.warn(`[commitFile] git.remove failed: ${res.error}`);
-              throw new Error(`git.remove failed: ${res.error}`);
+          for (const filepath of filepaths) {
+            const fileExists = (await fileSystemService.readFileBinary(filepath)) !== null;
+            if (fileExists) {
+              debug.log(`[commitFile] Adding file: ${filepath}`);
+              const res = await window.electronAPI!.git.add(rootDir, filepath);
+              if (res.error) {
+                debug.warn(`[commitFile] git.add failed: ${res.error}`);
+                throw new Error(`git.add failed: ${res.error}`);
+              }
+            } else {
+              debug.log(`[commitFile] Removing file: ${filepath}`);
+              const res = await window.electronAPI!.git.remove(rootDir, filepath);
+              if (res.error) {
+                debug.warn(`[commitFile] git.remove failed: ${res.error}`);
+                throw new Error(`git.remove failed: ${res.error}`);
+              }
             }
           }
 
@@ -562,12 +604,15 @@ class GitCoreService {
 
           const cache = {};
 
-          if (fileExists) {
-            debug.log(`[commitFile] Browser: calling git.add for ${filepath}`);
-            await git.add({ fs: fsAdapter, dir: getRootDir(), filepath, cache });
-          } else {
-            debug.log(`[commitFile] Browser: calling git.remove for ${filepath}`);
-            await git.remove({ fs: fsAdapter, dir: getRootDir(), filepath, cache });
+          for (const filepath of filepaths) {
+            const fileExists = (await fileSystemService.readFileBinary(filepath)) !== null;
+            if (fileExists) {
+              debug.log(`[commitFile] Browser: calling git.add for ${filepath}`);
+              await git.add({ fs: fsAdapter, dir: getRootDir(), filepath, cache });
+            } else {
+              debug.log(`[commitFile] Browser: calling git.remove for ${filepath}`);
+              await git.remove({ fs: fsAdapter, dir: getRootDir(), filepath, cache });
+            }
           }
 
           debug.log(`[commitFile] Browser: calling git.commit with message: "${message}"`);
@@ -583,31 +628,76 @@ class GitCoreService {
         }
 
         debug.log(
-          `[commitFile] Successfully committed ${filepath} by ${authorNameToUse}, SHA: ${commitOid}`
+          `[commitFile] Successfully committed ${label} by ${authorNameToUse}, SHA: ${commitOid}`
         );
 
-        // Track this file as recently committed to filter stale statusMatrix results
-        this.recentlyCommittedFiles.set(filepath, Date.now());
+        // Track these files as recently committed to filter stale statusMatrix results
+        const now = Date.now();
+        filepaths.forEach((filepath) => this.recentlyCommittedFiles.set(filepath, now));
 
         // Invalidate status cache so next getStatus returns fresh data
         this.statusCache = null;
 
-        // Proactively cache the file for this commit
-        this.addToCacheFn(commitOid, [filepath]);
+        // Proactively cache the files for this commit
+        this.addToCacheFn(commitOid, [...filepaths]);
 
-        // Dispatch event to notify UI of status change
+        // Dispatch events to notify UI of status change and listeners (webhooks) of the commit
         if (typeof window !== 'undefined') {
           window.dispatchEvent(new CustomEvent('git-status-changed'));
+          window.dispatchEvent(
+            new CustomEvent('git-commit-created', {
+              detail: { oid: commitOid, message, files: [...filepaths], author: authorNameToUse },
+            })
+          );
         }
       } catch (error) {
+        for (const [path, bytes] of originals) {
+          await (bytes
+            ? fileSystemService.writeFileBinary(path, bytes)
+            : fileSystemService.deleteFile(path));
+        }
         // Log the error with full details before rethrowing
-        console.error(`[commitFile] Failed to commit ${filepath}:`, error);
+        console.error(`[commitFile] Failed to commit ${label}:`, error);
         throw error;
       }
     });
 
-    // Wait for our commit to complete
-    await this.commitQueue;
+    // A failed commit must not block the ones queued after it
+    this.commitQueue = task.catch(() => undefined);
+    await task;
+  }
+
+  /**
+   * Flag the links of tracked artifacts that a commit modifies (not adds or
+   * deletes) as suspect. Returns the link files that changed.
+   */
+  private async flagSuspectLinks(filepaths: string[], journal: WriteJournal): Promise<string[]> {
+    // Imported lazily: the suspect link service reaches git through the disk services
+    const { suspectLinkService } = await import('../suspectLinkService');
+    const modified: string[] = [];
+    for (const filepath of filepaths) {
+      if (!suspectLinkService.getTrackedArtifactId(filepath)) continue;
+      if ((await this.getFileStatus(filepath)) === 'modified') modified.push(filepath);
+    }
+    return modified.length > 0
+      ? suspectLinkService.flagLinksOfModifiedFiles(modified, journal)
+      : [];
+  }
+
+  /**
+   * Working tree status of a single file relative to HEAD, e.g. "modified",
+   * "added" or "deleted" (unstaged marks are dropped); empty when unknown
+   */
+  private async getFileStatus(filepath: string): Promise<string> {
+    try {
+      const status = isElectronEnv()
+        ? await window.electronAPI!.git.status(getRootDir(), filepath)
+        : await git.status({ fs: fsAdapter, dir: getRootDir(), filepath });
+      return typeof status === 'string' ? status.replace(/^\*/, '') : '';
+    } catch (err) {
+      debug.log(`[getFileStatus] Could not get status of ${filepath}:`, err);
+      return '';
+    }
   }
 
   /**
@@ -683,6 +773,80 @@ class GitCoreService {
     }
   }
 
+  /**
+   * Store text as a git blob and return its oid
+   */
+  async writeBlob(content: string): Promise<string> {
+    if (!this.initialized) {
+      throw new Error('Git service not initialized');
+    }
+    if (isElectronEnv()) {
+      const res = await window.electronAPI!.git.writeBlob(getRootDir(), content);
+      if (res.error) throw new Error(res.error);
+      return res.oid!;
+    }
+    return git.writeBlob({
+      fs: fsAdapter,
+      dir: getRootDir(),
+      blob: new TextEncoder().encode(content),
+    });
+  }
+
+  /**
+   * Point a ref under refs/tracyfy (e.g. refs/tracyfy/undo) at a parentless
+   * commit whose flat tree holds the given blobs. Keeps the blobs reachable
+   * so git gc does not prune them; branches and history are untouched.
+   */
+  async writeSnapshotRef(ref: string, entries: SnapshotEntry[], message: string): Promise<string> {
+    if (!this.initialized) {
+      throw new Error('Git service not initialized');
+    }
+    if (!ref.startsWith('refs/tracyfy/')) {
+      throw new Error(`Snapshot refs must be under refs/tracyfy/: ${ref}`);
+    }
+    if (isElectronEnv()) {
+      const api = window.electronAPI!.git;
+      const res = await api.writeSnapshotRef(getRootDir(), ref, entries, message);
+      if (res.error) throw new Error(res.error);
+      return res.oid!;
+    }
+
+    const dir = getRootDir();
+    const tree = await git.writeTree({
+      fs: fsAdapter,
+      dir,
+      tree: entries.map(({ path, oid }) => ({ mode: '100644', path, oid, type: 'blob' as const })),
+    });
+    const signature = {
+      name: 'Tracyfy',
+      email: 'user@tracyfy.local',
+      timestamp: Math.floor(Date.now() / 1000),
+      timezoneOffset: new Date().getTimezoneOffset(),
+    };
+    const oid = await git.writeCommit({
+      fs: fsAdapter,
+      dir,
+      commit: { message, tree, parent: [], author: signature, committer: signature },
+    });
+    await git.writeRef({ fs: fsAdapter, dir, ref, value: oid, force: true });
+    return oid;
+  }
+
+  /**
+   * Commit a ref points at; null when the ref does not exist
+   */
+  async resolveRef(ref: string): Promise<string | null> {
+    try {
+      if (isElectronEnv()) {
+        const oid = await window.electronAPI!.git.resolveRef(getRootDir(), ref);
+        return typeof oid === 'string' ? oid : null;
+      }
+      return await git.resolveRef({ fs: fsAdapter, dir: getRootDir(), ref });
+    } catch {
+      return null;
+    }
+  }
+
   /**
    * Load all artifacts from disk
    */
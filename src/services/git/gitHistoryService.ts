@@ -5,6 +5,7 @@
  */
 
 import { debug } from '../../utils/debug';
+import { decodeText } from '../../utils/encodingUtils';
 import git from 'isomorphic-git';
 import { fileSystemService } from '../fileSystemService';
 import { fsAdapter } from '../fsAdapter';
@@ -256,7 +257,7 @@ class GitHistoryService {
           throw new Error(result.error);
         }
         if (!result.blob) return null;
-        return new TextDecoder().decode(new Uint8Array(result.blob));
+        return decodeText(new Uint8Array(result.blob)).content;
       } else {
         const result = await git.readBlob({
           fs: fsAdapter,
@@ -270,7 +271,7 @@ class GitHistoryService {
           return null;
         }
 
-        return new TextDecoder().decode(blob);
+        return decodeText(blob).content;
       }
     } catch (error: unknown) {
       const err = error as { code?: string };
@@ -8,7 +8,12 @@ import { debug } from '../../utils/debug';
 import git from 'isomorphic-git';
 import { fileSystemService } from '../fileSystemService';
 import { fsAdapter } from '../fsAdapter';
-import { isElectronEnv, type Remote, type PullResult } from './types';
+import { secretService } from '../secretService';
+import { getCommitAuthor, isElectronEnv, type Remote, type PullResult } from './types';
+
+// Tokens saved before the secret store existed
+const LEGACY_TOKEN_KEY = 'git_pat_token';
+const TOKEN_SECRET = 'git';
 
 /**
  * Get the root directory path (Electron uses absolute path, browser uses '.')
@@ -107,31 +112,22 @@ class GitRemoteService {
   }
 
   /**
-   * Ensure authentication token is loaded from disk/localStorage
+   * Ensure authentication token is loaded from the secret store
    */
   async ensureTokenLoaded(): Promise<void> {
     if (this.tokenLoaded) return;
 
     try {
-      if (isElectronEnv()) {
-        const result = await window.electronAPI!.secure.getToken();
-        if (result.token) {
-          this.authToken = result.token;
-          this.tokenLoaded = true;
-          return;
-        }
-
-        // Migration from localStorage if exists
-        const oldToken =
-          typeof localStorage !== 'undefined' ? localStorage.getItem('git_pat_token') : null;
-        if (oldToken) {
-          debug.log('[ensureTokenLoaded] Migrating token to secure storage');
-          await this.setAuthToken(oldToken);
-          localStorage.removeItem('git_pat_token');
-          return;
-        }
-      } else if (typeof localStorage !== 'undefined') {
-        this.authToken = localStorage.getItem('git_pat_token');
+      this.authToken = await secretService.getSecret(TOKEN_SECRET);
+
+      // Migration from plain localStorage if exists
+      const oldToken =
+        typeof localStorage !== 'undefined' ? localStorage.getItem(LEGACY_TOKEN_KEY) : null;
+      if (!this.authToken && oldToken) {
+        debug.log('[ensureTokenLoaded] Migrating token to secure storage');
+        await this.setAuthToken(oldToken);
+        localStorage.removeItem(LEGACY_TOKEN_KEY);
+        return;
       }
     } catch (err) {
       debug.warn('[ensureTokenLoaded] Failed:', err);
@@ -144,13 +140,6 @@ class GitRemoteService {
    * Note: ensureTokenLoaded() must be called once before this (is called in init)
    */
   getAuthToken(): string | null {
-    if (!this.tokenLoaded) {
-      // Fallback for immediate access, though unreliable in Electron if not yet initialized
-      return (
-        this.authToken ||
-        (typeof localStorage !== 'undefined' ? localStorage.getItem('git_pat_token') : null)
-      );
-    }
     return this.authToken;
   }
 
@@ -160,23 +149,7 @@ class GitRemoteService {
   async setAuthToken(token: string): Promise<void> {
     this.authToken = token;
     this.tokenLoaded = true;
-
-    try {
-      if (isElectronEnv()) {
-        const result = await window.electronAPI!.secure.setToken(token);
-        if (result.error) throw new Error(result.error);
-      } else {
-        localStorage.setItem('git_pat_token', token);
-      }
-    } catch (err) {
-      debug.warn('[setAuthToken] Failed:', err);
-      // Fallback to localStorage even in Electron if secure storage fails
-      try {
-        localStorage.setItem('git_pat_token', token);
-      } catch {
-        // Ignore
-      }
-    }
+    await secretService.storeSecret(TOKEN_SECRET, token);
   }
 
   /**
@@ -187,10 +160,8 @@ class GitRemoteService {
     this.tokenLoaded = true;
 
     try {
-      if (isElectronEnv()) {
-        await window.electronAPI!.secure.removeToken();
-      }
-      localStorage.removeItem('git_pat_token');
+      await secretService.deleteSecret(TOKEN_SECRET);
+      localStorage.removeItem(LEGACY_TOKEN_KEY);
     } catch {
       // Ignore
     }
@@ -294,11 +265,15 @@ class GitRemoteService {
     }
 
     try {
+      const author = await getCommitAuthor();
       if (isElectronEnv()) {
-        const result = await window.electronAPI!.git.pull(getRootDir(), remote, branch, token, {
-          name: 'Tracyfy User',
-          email: 'user@tracyfy.local',
-        });
+        const result = await window.electronAPI!.git.pull(
+          getRootDir(),
+          remote,
+          branch,
+          token,
+          author
+        );
         if (result.error) throw new Error(result.error);
         if (!result.ok) {
           return { success: false, conflicts: result.conflicts || [] };
@@ -315,7 +290,7 @@ class GitRemoteService {
         corsProxy: 'https://corsproxy.io/?',
         remote,
         ref: branch,
-        author: { name: 'Tracyfy User', email: 'user@tracyfy.local' },
+        author,
         ...auth,
       });
       debug.log(`[pull] Pulled from ${remote}/${branch}`);
@@ -9,5 +9,13 @@ import { compositeGitService } from './compositeGitService';
 export { compositeGitService as realGitService };
 
 // Re-export types
-export type { FileStatus, Remote, TagDetails, PullResult, CommitInfo, SyncStatus } from './types';
+export type {
+  FileStatus,
+  Remote,
+  TagDetails,
+  PullResult,
+  CommitInfo,
+  SyncStatus,
+  SnapshotEntry,
+} from './types';
 export { isElectronEnv, parseStatusMatrix } from './types';
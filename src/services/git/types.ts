@@ -4,7 +4,16 @@
  * Shared types used across the git service modules.
  */
 
-import type { CommitInfo, SyncStatus } from '../../types';
+import type {
+  AppSettings,
+  AppSettingsUpdate,
+  CommitInfo,
+  DeepLink,
+  DiagramSettings,
+  PluginCapability,
+  PluginManifest,
+  SyncStatus,
+} from '../../types';
 
 export type { CommitInfo, SyncStatus };
 
@@ -45,6 +54,22 @@ export interface PullResult {
   conflicts: string[];
 }
 
+// A blob in a snapshot ref's tree (see gitCoreService.writeSnapshotRef)
+export interface SnapshotEntry {
+  path: string;
+  oid: string;
+}
+
+// Command API of the desktop app's main process (see apiInfoService)
+export interface ApiInfo {
+  appVersion: string;
+  apiVersion: number;
+  minSupportedApiVersion: number; // Oldest client API version whose commands still work
+  capabilities: string[]; // e.g. "fs:batch", "project:readOnly"
+  features: Record<string, boolean>; // What the system offers, e.g. keychain, notifications
+  deprecated: { command: string; replacement: string; since: number }[];
+}
+
 // Type for electron API
 declare global {
   interface Window {
@@ -92,6 +117,13 @@ declare global {
           dir: string,
           oid: string
         ) => Promise<{ message: string; timestamp: number; object: string }>;
+        writeBlob: (dir: string, content: string) => Promise<{ oid?: string; error?: string }>;
+        writeSnapshotRef: (
+          dir: string,
+          ref: string,
+          entries: SnapshotEntry[],
+          message: string
+        ) => Promise<{ oid?: string; error?: string }>;
         // Remote operations
         addRemote: (
           dir: string,
@@ -126,9 +158,117 @@ declare global {
         ) => Promise<{ ok?: boolean; error?: string }>;
       };
       secure: {
-        setToken: (token: string) => Promise<{ ok?: boolean; error?: string }>;
-        getToken: () => Promise<{ token?: string | null; error?: string }>;
-        removeToken: () => Promise<{ ok?: boolean; error?: string }>;
+        // name selects the stored token (default: the git token)
+        setToken: (token: string, name?: string) => Promise<{ ok?: boolean; error?: string }>;
+        getToken: (name?: string) => Promise<{ token?: string | null; error?: string }>;
+        removeToken: (name?: string) => Promise<{ ok?: boolean; error?: string }>;
+      };
+      signing: {
+        // Ed25519 in the main process; keys and data as byte arrays, private keys as
+        // base64 PKCS #8
+        generateKey: () => Promise<{ publicKey?: number[]; privateKey?: string; error?: string }>;
+        sign: (
+          privateKey: string,
+          data: number[]
+        ) => Promise<{ signature?: number[]; error?: string }>;
+        verify: (
+          publicKey: number[],
+          signature: number[],
+          data: number[]
+        ) => Promise<{ valid?: boolean; error?: string }>;
+      };
+      http: {
+        request: (request: {
+          integration: string; // github, gitlab, jira or webhook; limits the hosts
+          url: string;
+          method?: string;
+          headers?: Record<string, string>;
+          body?: string;
+        }) => Promise<{
+          status?: number;
+          headers?: Record<string, string>;
+          body?: string;
+          error?: string;
+        }>;
+      };
+      api: {
+        start: (port: number, token: string) => Promise<{ ok?: boolean; error?: string }>;
+        stop: () => Promise<{ ok?: boolean; error?: string }>;
+        onRequest: (
+          handler: (request: {
+            requestId: string;
+            path: string;
+            query: Record<string, string>;
+          }) => Promise<{ status: number; body: unknown }>
+        ) => () => void;
+      };
+      plugins: {
+        list: () => Promise<{ plugins?: PluginManifest[]; error?: string }>;
+        run: (
+          name: string,
+          request: { action: PluginCapability; [field: string]: unknown }
+        ) => Promise<{ response?: unknown; error?: string }>;
+      };
+      diagram: {
+        render: (
+          kind: string,
+          source: string,
+          format: string
+        ) => Promise<{ data?: string; mimeType?: string; cached?: boolean; error?: string }>;
+      };
+      settings: {
+        get: () => Promise<{ settings?: AppSettings; error?: string }>;
+        update: (
+          patch: AppSettingsUpdate
+        ) => Promise<{ settings?: AppSettings; error?: string }>;
+        chooseDiagramTool: (
+          tool: keyof DiagramSettings,
+          reset?: boolean
+        ) => Promise<{ settings?: AppSettings; canceled?: boolean; error?: string }>;
+        trustSigningKey: (
+          publicKey: string
+        ) => Promise<{ settings?: AppSettings; canceled?: boolean; error?: string }>;
+      };
+      project: {
+        open: (
+          dir: string
+        ) => Promise<{ path?: string; hasGit?: boolean; readOnly?: boolean; error?: string }>;
+        close: () => Promise<{ ok?: boolean; error?: string }>;
+        setReadOnly: (
+          readOnly: boolean
+        ) => Promise<{ readOnly?: boolean; canceled?: boolean; error?: string }>;
+        onFilesChanged: (handler: (paths: string[]) => void) => () => void;
+        onExternalChanges: (handler: (paths: string[]) => void) => () => void;
+      };
+      deepLink: {
+        onOpen: (handler: (link: DeepLink) => void) => () => void;
+        takePending: () => Promise<{ links?: DeepLink[]; error?: string }>;
+      };
+      shell: {
+        revealInFileManager: (relativePath: string) => Promise<{ ok?: boolean; error?: string }>;
+      };
+      clipboard: {
+        writeTable: (table: {
+          html: string;
+          text: string;
+        }) => Promise<{ ok?: boolean; error?: string }>;
+      };
+      notifications: {
+        show: (notification: {
+          title: string;
+          body: string;
+        }) => Promise<{ shown?: boolean; error?: string }>;
+      };
+      log: {
+        write: (level: string, message: string) => Promise<{ ok?: boolean; error?: string }>;
+        getRecent: (limit?: number) => Promise<{
+          entries?: { time: string; level: string; source: string; message: string }[];
+          error?: string;
+        }>;
+        export: () => Promise<{ content?: string; error?: string }>;
+      };
+      app?: {
+        getApiInfo: () => Promise<Partial<ApiInfo> & { error?: string }>;
       };
     };
   }
@@ -140,6 +280,22 @@ declare global {
 export const isElectronEnv = () =>
   typeof window !== 'undefined' && !!window.electronAPI?.isElectron;
 
+// Folder for app state kept in the project folder but outside version control
+export const APP_STATE_DIR = '.tracyfy';
+// Folder of advisory edit locks, shared with others editing the folder but never committed
+export const LOCKS_DIR = '.locks';
+
+/**
+ * Name and email commits and tags are made with: the active author profile,
+ * else the built-in identity. Loaded on demand, as the profile service
+ * depends on the disk services that commit through git.
+ */
+export async function getCommitAuthor(
+  authorName?: string
+): Promise<{ name: string; email: string }> {
+  return (await import('../authorProfileService')).getCommitAuthor(authorName);
+}
+
 /**
  * Parse status matrix into FileStatus array
  */
@@ -151,6 +307,10 @@ export function parseStatusMatrix(matrix: [string, number, number, number][]): {
   const allFiles = new Set<string>();
 
   for (const [filepath, headStatus, workdirStatus, stageStatus] of matrix) {
+    // App state (caches, journal, drafts) and edit locks are never committed
+    if (filepath.startsWith(`${APP_STATE_DIR}/`) || filepath.startsWith(`${LOCKS_DIR}/`)) {
+      continue;
+    }
     allFiles.add(filepath);
 
     // Debug: log the raw status for REQ-001
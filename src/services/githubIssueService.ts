@@ -0,0 +1,340 @@
+/**
+ * GitHub Issue Service
+ *
+ * Syncs artifacts with GitHub issues through the REST API, authenticated
+ * with the stored token (a dedicated GitHub token, or else the git token).
+ * Issues can be created from artifacts or linked to them; a sync pulls
+ * issue state and labels into the artifacts and can run periodically.
+ * Each sync emits a 'github-sync-result' window event.
+ */
+
+import type { ExternalIssueRef } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { secretService } from './secretService';
+import { HttpError, requestJson } from './httpClient';
+import { projectConfigService } from './projectConfigService';
+import { realGitService } from './realGitService';
+import {
+  getIssueMapping,
+  getSyncArtifactPath,
+  loadLinkedArtifacts,
+  loadSyncArtifact,
+  unlinkIssue,
+  type ExternalIssueMapping,
+} from './externalIssueService';
+import { getIssueRefs, upsertIssueRef } from '../utils/externalIssueUtils';
+import { debug } from '../utils/debug';
+
+const GITHUB_SECTION = 'github-issues';
+const TOKEN_NAME = 'github';
+const INTEGRATION = 'github';
+const API_URL = 'https://api.github.com';
+const NAME_PATTERN = /^[\w.-]+$/;
+const ISSUE_KEY_PATTERN = /^([\w.-]+)\/([\w.-]+)#(\d+)$/;
+export const GITHUB_SYNC_EVENT = 'github-sync-result';
+
+export interface GitHubIssuesConfig {
+  owner: string; // Empty: taken from the origin remote
+  repo: string;
+  stateMap: { open?: string; closed?: string }; // Issue state -> artifact status
+  syncIntervalMinutes: number; // 0 disables periodic sync
+}
+
+export const DEFAULT_GITHUB_CONFIG: GitHubIssuesConfig = {
+  owner: '',
+  repo: '',
+  stateMap: {},
+  syncIntervalMinutes: 0,
+};
+
+export interface GitHubCreateResult {
+  artifactId: string;
+  issueKey: string; // owner/repo#number
+  url: string;
+}
+
+export interface GitHubSyncResult {
+  checked: number;
+  updated: string[]; // Artifacts whose status or issue labels changed
+  missing: string[]; // Linked issues that no longer exist
+  syncedAt: number;
+}
+
+export interface GitHubSyncEventDetail {
+  result?: GitHubSyncResult;
+  error?: string;
+}
+
+interface GitHubIssue {
+  number: number;
+  html_url: string;
+  state: 'open' | 'closed';
+  labels: ({ name: string } | string)[];
+}
+
+interface GitHubConnection {
+  owner: string;
+  repo: string;
+  config: GitHubIssuesConfig;
+  headers: Record<string, string>;
+}
+
+/**
+ * Owner and repository of a GitHub remote URL (https or ssh)
+ */
+export function parseGitHubRemote(url: string): { owner: string; repo: string } | null {
+  const match = url.match(/github\.com[/:]([\w.-]+)\/([\w.-]+?)(\.git)?\/?$/);
+  return match ? { owner: match[1], repo: match[2] } : null;
+}
+
+/**
+ * The stored GitHub issue settings
+ */
+export async function getGitHubConfig(): Promise<GitHubIssuesConfig> {
+  return projectConfigService.getSection<GitHubIssuesConfig>(GITHUB_SECTION, DEFAULT_GITHUB_CONFIG);
+}
+
+/**
+ * Save the GitHub issue settings after checking them
+ */
+export async function setGitHubConfig(config: GitHubIssuesConfig): Promise<void> {
+  const validRepo = NAME_PATTERN.test(config.owner) && NAME_PATTERN.test(config.repo);
+  if ((config.owner || config.repo) && !validRepo) {
+    throw new Error('Invalid GitHub repository');
+  }
+  if (!(config.syncIntervalMinutes >= 0)) {
+    throw new Error('Sync interval must not be negative');
+  }
+  await projectConfigService.setSection(GITHUB_SECTION, config, 'Update GitHub issue settings');
+}
+
+/**
+ * Store a token used only for GitHub issues (instead of the git token)
+ */
+export async function setGitHubToken(token: string): Promise<void> {
+  await secretService.storeSecret(TOKEN_NAME, token);
+}
+
+async function getConnection(): Promise<GitHubConnection> {
+  const config = await getGitHubConfig();
+  let { owner, repo } = config;
+  if (!owner || !repo) {
+    const origin = (await realGitService.getRemotes()).find((r) => r.name === 'origin');
+    const parsed = origin ? parseGitHubRemote(origin.url) : null;
+    if (!parsed) {
+      throw new Error('No GitHub repository configured');
+    }
+    ({ owner, repo } = parsed);
+  }
+
+  let token = await secretService.getSecret(TOKEN_NAME);
+  if (!token) {
+    await realGitService.ensureTokenLoaded();
+    token = realGitService.getAuthToken();
+  }
+  if (!token) {
+    throw new Error('No GitHub token stored');
+  }
+
+  return {
+    owner,
+    repo,
+    config,
+    headers: {
+      Authorization: `Bearer ${token}`,
+      Accept: 'application/vnd.github+json',
+      'X-GitHub-Api-Version': '2022-11-28',
+    },
+  };
+}
+
+function labelNames(issue: GitHubIssue): string[] {
+  return issue.labels.map((label) => (typeof label === 'string' ? label : label.name)).sort();
+}
+
+function toIssueRef(owner: string, repo: string, issue: GitHubIssue): ExternalIssueRef {
+  return {
+    tracker: 'github',
+    key: `${owner}/${repo}#${issue.number}`,
+    url: issue.html_url,
+    status: issue.state,
+    labels: labelNames(issue),
+    syncedAt: Date.now(),
+  };
+}
+
+function emitSyncEvent(detail: GitHubSyncEventDetail): void {
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent(GITHUB_SYNC_EVENT, { detail }));
+  }
+}
+
+/**
+ * Create a GitHub issue for each artifact that has none yet, with the
+ * artifact's tags as labels. Committed in one commit.
+ */
+export async function createGitHubIssues(artifactIds: string[]): Promise<GitHubCreateResult[]> {
+  const { owner, repo, headers } = await getConnection();
+  const results: GitHubCreateResult[] = [];
+  const changed: string[] = [];
+
+  for (const id of artifactIds) {
+    const { typeKey, artifact } = await loadSyncArtifact(id);
+    if (getIssueRefs(artifact.externalIssues, 'github').length > 0) continue;
+
+    const issue = await requestJson<GitHubIssue>({
+      integration: INTEGRATION,
+      url: `${API_URL}/repos/${owner}/${repo}/issues`,
+      method: 'POST',
+      headers,
+      json: {
+        title: `[${artifact.id}] ${artifact.title}`,
+        body: [artifact.description, artifact.text].filter(Boolean).join('\n\n'),
+        labels: artifact.tags || [],
+      },
+    });
+    const ref = toIssueRef(owner, repo, issue);
+    await ALL_ARTIFACT_SERVICES[typeKey].save({
+      ...artifact,
+      externalIssues: upsertIssueRef(artifact.externalIssues, ref),
+    });
+    changed.push(getSyncArtifactPath(typeKey, id));
+    results.push({ artifactId: id, issueKey: ref.key, url: issue.html_url });
+  }
+
+  if (changed.length > 0) {
+    await realGitService.commitFiles(changed, `Create ${changed.length} GitHub issues`);
+  }
+  return results;
+}
+
+/**
+ * Link an existing issue of the configured repository to an artifact
+ */
+export async function linkGitHubIssue(artifactId: string, issueNumber: number): Promise<void> {
+  const { owner, repo, headers } = await getConnection();
+  const { typeKey, artifact } = await loadSyncArtifact(artifactId);
+  const issue = await requestJson<GitHubIssue>({
+    integration: INTEGRATION,
+    url: `${API_URL}/repos/${owner}/${repo}/issues/${issueNumber}`,
+    headers,
+  });
+
+  const ref = toIssueRef(owner, repo, issue);
+  await ALL_ARTIFACT_SERVICES[typeKey].save(
+    { ...artifact, externalIssues: upsertIssueRef(artifact.externalIssues, ref) },
+    `Link ${artifactId} to GitHub issue ${ref.key}`
+  );
+}
+
+export async function unlinkGitHubIssue(artifactId: string, issueKey: string): Promise<void> {
+  await unlinkIssue('github', artifactId, issueKey);
+}
+
+export async function getGitHubIssueMapping(): Promise<ExternalIssueMapping[]> {
+  return getIssueMapping('github');
+}
+
+/**
+ * Pull state and labels of every linked issue into the artifacts, applying
+ * config.stateMap to their status. Committed in one commit; the result is
+ * also emitted as a GITHUB_SYNC_EVENT.
+ */
+export async function syncGitHubIssues(): Promise<GitHubSyncResult> {
+  try {
+    const { config, headers } = await getConnection();
+    const result: GitHubSyncResult = { checked: 0, updated: [], missing: [], syncedAt: Date.now() };
+    const changed: string[] = [];
+
+    for (const { typeKey, artifact } of await loadLinkedArtifacts('github')) {
+      const linked = getIssueRefs(artifact.externalIssues, 'github');
+      let refs = artifact.externalIssues;
+      let status = artifact.status;
+
+      for (const ref of linked) {
+        const [, owner, repo, number] = ref.key.match(ISSUE_KEY_PATTERN) || [];
+        if (!number) continue;
+        let issue: GitHubIssue;
+        try {
+          issue = await requestJson<GitHubIssue>({
+            integration: INTEGRATION,
+            url: `${API_URL}/repos/${owner}/${repo}/issues/${number}`,
+            headers,
+          });
+        } catch (err) {
+          if (err instanceof HttpError && (err.status === 404 || err.status === 410)) {
+            result.missing.push(ref.key);
+            continue;
+          }
+          throw err;
+        }
+        result.checked++;
+        const synced = toIssueRef(owner, repo, issue);
+        refs = upsertIssueRef(refs, { ...synced, syncedAt: result.syncedAt });
+        status = config.stateMap[issue.state] || status;
+      }
+
+      const statusChanged = status !== artifact.status;
+      const refsChanged = linked.some((ref) => {
+        const updated = getIssueRefs(refs, 'github').find((r) => r.key === ref.key);
+        return (
+          updated?.status !== ref.status ||
+          (updated?.labels || []).join(',') !== (ref.labels || []).join(',')
+        );
+      });
+      if (!statusChanged && !refsChanged) continue;
+
+      const now = result.syncedAt;
+      await ALL_ARTIFACT_SERVICES[typeKey].save({
+        ...artifact,
+        externalIssues: refs,
+        ...(statusChanged
+          ? { status, statusChangedBy: 'GitHub', statusChangedAt: now, lastModified: now }
+          : {}),
+      });
+      changed.push(getSyncArtifactPath(typeKey, artifact.id));
+      result.updated.push(artifact.id);
+    }
+
+    if (changed.length > 0) {
+      await realGitService.commitFiles(
+        changed,
+        `Sync GitHub issues: ${result.updated.length} artifacts updated`
+      );
+    }
+    debug.log(`[GitHubIssueService] Synced ${result.checked} issues`);
+    emitSyncEvent({ result });
+    return result;
+  } catch (err) {
+    emitSyncEvent({ error: err instanceof Error ? err.message : String(err) });
+    throw err;
+  }
+}
+
+/**
+ * Run syncGitHubIssues every config.syncIntervalMinutes; returns a function
+ * that stops the periodic sync. Does nothing when the interval is 0.
+ */
+export async function startGitHubAutoSync(): Promise<() => void> {
+  const { syncIntervalMinutes } = await getGitHubConfig();
+  if (!syncIntervalMinutes) return () => {};
+
+  const interval = setInterval(() => {
+    // Failures are reported through the sync event
+    syncGitHubIssues().catch((err) => debug.warn('[GitHubIssueService] Sync failed:', err));
+  }, syncIntervalMinutes * 60_000);
+  return () => clearInterval(interval);
+}
+
+export const githubIssueService = {
+  parseGitHubRemote,
+  getGitHubConfig,
+  setGitHubConfig,
+  setGitHubToken,
+  createGitHubIssues,
+  linkGitHubIssue,
+  unlinkGitHubIssue,
+  getGitHubIssueMapping,
+  syncGitHubIssues,
+  startGitHubAutoSync,
+};
@@ -0,0 +1,402 @@
+/**
+ * GitLab Service
+ *
+ * Syncs artifacts with GitLab (gitlab.com or self-hosted) through the REST
+ * API v4. Issues can be created from artifacts, and existing issues and
+ * merge requests can be linked to them. A sync pulls issue and merge request
+ * state and labels into the artifacts and can run periodically; each sync
+ * emits a 'gitlab-sync-result' window event.
+ *
+ * Keys follow GitLab's reference syntax: group/app#12 for issues and
+ * group/app!7 for merge requests.
+ *
+ * The server URL is an app setting of this machine, not part of the project
+ * config, so a commit cannot redirect the token to another host. The git
+ * token is only used when the origin remote is on the same host.
+ */
+
+import type { ExternalIssueRef } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { secretService } from './secretService';
+import { HttpError, requestJson } from './httpClient';
+import { projectConfigService } from './projectConfigService';
+import { appSettingsService } from './appSettingsService';
+import { realGitService } from './realGitService';
+import {
+  getIssueMapping,
+  getSyncArtifactPath,
+  loadLinkedArtifacts,
+  loadSyncArtifact,
+  unlinkIssue,
+  type ExternalIssueMapping,
+} from './externalIssueService';
+import { getIssueRefs, upsertIssueRef } from '../utils/externalIssueUtils';
+import { debug } from '../utils/debug';
+
+const GITLAB_SECTION = 'gitlab';
+const TOKEN_NAME = 'gitlab';
+const INTEGRATION = 'gitlab';
+const PROJECT_PATH_PATTERN = /^[\w.-]+(\/[\w.-]+)+$/;
+const REF_KEY_PATTERN = /^(.+)([#!])(\d+)$/;
+export const GITLAB_SYNC_EVENT = 'gitlab-sync-result';
+
+export type GitLabItemKind = 'issue' | 'merge-request';
+
+export interface GitLabConfig {
+  baseUrl: string; // e.g. https://gitlab.com or https://gitlab.acme.internal
+  projectPath: string; // group/app; empty: taken from the origin remote
+  stateMap: Record<string, string>; // opened/closed/merged -> artifact status
+  syncIntervalMinutes: number; // 0 disables periodic sync
+}
+
+export const DEFAULT_GITLAB_CONFIG: GitLabConfig = {
+  baseUrl: 'https://gitlab.com',
+  projectPath: '',
+  stateMap: {},
+  syncIntervalMinutes: 0,
+};
+
+export interface GitLabCreateResult {
+  artifactId: string;
+  issueKey: string; // group/app#iid
+  url: string;
+}
+
+export interface GitLabSyncResult {
+  checked: number;
+  updated: string[]; // Artifacts whose status or linked item state changed
+  missing: string[]; // Linked issues or merge requests that no longer exist
+  syncedAt: number;
+}
+
+export interface GitLabSyncEventDetail {
+  result?: GitLabSyncResult;
+  error?: string;
+}
+
+interface GitLabItem {
+  iid: number;
+  web_url: string;
+  state: string; // opened, closed, merged or locked
+  labels: string[];
+}
+
+interface GitLabConnection {
+  api: string;
+  projectPath: string;
+  config: GitLabConfig;
+  headers: Record<string, string>;
+}
+
+/**
+ * Project path of a remote URL (https or ssh) on the given GitLab host
+ */
+export function parseGitLabRemote(url: string, host: string): string | null {
+  const escaped = host.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  const match = url.match(new RegExp(`${escaped}(?::\\d+)?[/:](.+?)(\\.git)?/?$`));
+  return match && PROJECT_PATH_PATTERN.test(match[1]) ? match[1] : null;
+}
+
+/**
+ * Host name of a remote URL (https, ssh:// or scp-like git@host:path)
+ */
+export function getRemoteHost(url: string): string | null {
+  const scpLike = url.match(/^(?:[\w.-]+@)?([\w.-]+):(?!\/\/)/);
+  if (scpLike) return scpLike[1].toLowerCase();
+  try {
+    return new URL(url).hostname.toLowerCase() || null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Issue or merge request key in GitLab reference syntax
+ */
+export function formatGitLabKey(projectPath: string, kind: GitLabItemKind, iid: number): string {
+  return `${projectPath}${kind === 'issue' ? '#' : '!'}${iid}`;
+}
+
+/**
+ * Project path, kind and iid of a key, or null if it is not a GitLab key
+ */
+export function parseGitLabKey(
+  key: string
+): { projectPath: string; kind: GitLabItemKind; iid: number } | null {
+  const match = key.match(REF_KEY_PATTERN);
+  if (!match) return null;
+  const kind = match[2] === '#' ? 'issue' : 'merge-request';
+  return { projectPath: match[1], kind, iid: Number(match[3]) };
+}
+
+/**
+ * The GitLab settings of the project, with the server set up on this machine
+ */
+export async function getGitLabConfig(): Promise<GitLabConfig> {
+  const shared = await projectConfigService.getSection<GitLabConfig>(
+    GITLAB_SECTION,
+    DEFAULT_GITLAB_CONFIG
+  );
+  const { gitlab } = await appSettingsService.getIntegrationUrls();
+  return { ...shared, baseUrl: gitlab ?? DEFAULT_GITLAB_CONFIG.baseUrl };
+}
+
+/**
+ * Save the GitLab settings after checking them: the server URL on this
+ * machine, the rest in the project config
+ */
+export async function setGitLabConfig(config: GitLabConfig): Promise<void> {
+  if (!/^https:\/\/[^/\s]+/.test(config.baseUrl)) {
+    throw new Error('Base URL must be an https URL');
+  }
+  if (config.projectPath && !PROJECT_PATH_PATTERN.test(config.projectPath)) {
+    throw new Error('Invalid GitLab project path');
+  }
+  if (!(config.syncIntervalMinutes >= 0)) {
+    throw new Error('Sync interval must not be negative');
+  }
+  const { baseUrl, ...shared } = config;
+  await appSettingsService.setIntegrationUrl('gitlab', baseUrl.replace(/\/+$/, ''));
+  await projectConfigService.setSection(GITLAB_SECTION, shared, 'Update GitLab settings');
+}
+
+/**
+ * Store a token used only for GitLab (instead of the git token)
+ */
+export async function setGitLabToken(token: string): Promise<void> {
+  await secretService.storeSecret(TOKEN_NAME, token);
+}
+
+async function getConnection(): Promise<GitLabConnection> {
+  const config = await getGitLabConfig();
+  const url = new URL(config.baseUrl);
+  if (url.protocol !== 'https:') {
+    throw new Error('GitLab base URL must be an https URL');
+  }
+  const origin = (await realGitService.getRemotes()).find((r) => r.name === 'origin');
+  let projectPath: string | null = config.projectPath;
+  if (!projectPath) {
+    projectPath = origin ? parseGitLabRemote(origin.url, url.host) : null;
+    if (!projectPath) {
+      throw new Error('No GitLab project configured');
+    }
+  }
+
+  let token = await secretService.getSecret(TOKEN_NAME);
+  // The git token belongs to the remote's host and is sent nowhere else
+  if (!token && origin && getRemoteHost(origin.url) === url.hostname.toLowerCase()) {
+    await realGitService.ensureTokenLoaded();
+    token = realGitService.getAuthToken();
+  }
+  if (!token) {
+    throw new Error('No GitLab token stored');
+  }
+
+  return {
+    api: `${config.baseUrl}/api/v4`,
+    projectPath,
+    config,
+    headers: { 'PRIVATE-TOKEN': token },
+  };
+}
+
+function itemUrl(api: string, projectPath: string, kind: GitLabItemKind, iid: number): string {
+  const collection = kind === 'issue' ? 'issues' : 'merge_requests';
+  return `${api}/projects/${encodeURIComponent(projectPath)}/${collection}/${iid}`;
+}
+
+function toIssueRef(projectPath: string, kind: GitLabItemKind, item: GitLabItem): ExternalIssueRef {
+  return {
+    tracker: 'gitlab',
+    key: formatGitLabKey(projectPath, kind, item.iid),
+    url: item.web_url,
+    status: item.state,
+    labels: [...item.labels].sort(),
+    syncedAt: Date.now(),
+  };
+}
+
+function emitSyncEvent(detail: GitLabSyncEventDetail): void {
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent(GITLAB_SYNC_EVENT, { detail }));
+  }
+}
+
+/**
+ * Create a GitLab issue for each artifact that has no linked issue yet,
+ * with the artifact's tags as labels. Committed in one commit.
+ */
+export async function createGitLabIssues(artifactIds: string[]): Promise<GitLabCreateResult[]> {
+  const { api, projectPath, headers } = await getConnection();
+  const results: GitLabCreateResult[] = [];
+  const changed: string[] = [];
+
+  for (const id of artifactIds) {
+    const { typeKey, artifact } = await loadSyncArtifact(id);
+    const linked = getIssueRefs(artifact.externalIssues, 'gitlab');
+    if (linked.some((ref) => parseGitLabKey(ref.key)?.kind === 'issue')) continue;
+
+    const item = await requestJson<GitLabItem>({
+      integration: INTEGRATION,
+      url: `${api}/projects/${encodeURIComponent(projectPath)}/issues`,
+      method: 'POST',
+      headers,
+      json: {
+        title: `[${artifact.id}] ${artifact.title}`,
+        description: [artifact.description, artifact.text].filter(Boolean).join('\n\n'),
+        labels: (artifact.tags || []).join(','),
+      },
+    });
+    const ref = toIssueRef(projectPath, 'issue', item);
+    await ALL_ARTIFACT_SERVICES[typeKey].save({
+      ...artifact,
+      externalIssues: upsertIssueRef(artifact.externalIssues, ref),
+    });
+    changed.push(getSyncArtifactPath(typeKey, id));
+    results.push({ artifactId: id, issueKey: ref.key, url: item.web_url });
+  }
+
+  if (changed.length > 0) {
+    await realGitService.commitFiles(changed, `Create ${changed.length} GitLab issues`);
+  }
+  return results;
+}
+
+/**
+ * Link an existing issue or merge request of the configured project to an
+ * artifact
+ */
+export async function linkGitLabItem(
+  artifactId: string,
+  kind: GitLabItemKind,
+  iid: number
+): Promise<void> {
+  const { api, projectPath, headers } = await getConnection();
+  const { typeKey, artifact } = await loadSyncArtifact(artifactId);
+  const item = await requestJson<GitLabItem>({
+    integration: INTEGRATION,
+    url: itemUrl(api, projectPath, kind, iid),
+    headers,
+  });
+
+  const ref = toIssueRef(projectPath, kind, item);
+  const label = kind === 'issue' ? 'issue' : 'merge request';
+  await ALL_ARTIFACT_SERVICES[typeKey].save(
+    { ...artifact, externalIssues: upsertIssueRef(artifact.externalIssues, ref) },
+    `Link ${artifactId} to GitLab ${label} ${ref.key}`
+  );
+}
+
+export async function unlinkGitLabItem(artifactId: string, key: string): Promise<void> {
+  await unlinkIssue('gitlab', artifactId, key);
+}
+
+export async function getGitLabMapping(): Promise<ExternalIssueMapping[]> {
+  return getIssueMapping('gitlab');
+}
+
+/**
+ * Pull state and labels of every linked issue and merge request into the
+ * artifacts, applying config.stateMap to their status. Committed in one
+ * commit; the result is also emitted as a GITLAB_SYNC_EVENT.
+ */
+export async function syncGitLab(): Promise<GitLabSyncResult> {
+  try {
+    const { api, config, headers } = await getConnection();
+    const result: GitLabSyncResult = { checked: 0, updated: [], missing: [], syncedAt: Date.now() };
+    const changed: string[] = [];
+
+    for (const { typeKey, artifact } of await loadLinkedArtifacts('gitlab')) {
+      const linked = getIssueRefs(artifact.externalIssues, 'gitlab');
+      let refs = artifact.externalIssues;
+      let status = artifact.status;
+
+      for (const ref of linked) {
+        const parsed = parseGitLabKey(ref.key);
+        if (!parsed) continue;
+        let item: GitLabItem;
+        try {
+          item = await requestJson<GitLabItem>({
+            integration: INTEGRATION,
+            url: itemUrl(api, parsed.projectPath, parsed.kind, parsed.iid),
+            headers,
+          });
+        } catch (err) {
+          if (err instanceof HttpError && err.status === 404) {
+            result.missing.push(ref.key);
+            continue;
+          }
+          throw err;
+        }
+        result.checked++;
+        const synced = toIssueRef(parsed.projectPath, parsed.kind, item);
+        refs = upsertIssueRef(refs, { ...synced, syncedAt: result.syncedAt });
+        status = config.stateMap[item.state] || status;
+      }
+
+      const statusChanged = status !== artifact.status;
+      const refsChanged = linked.some((ref) => {
+        const updated = getIssueRefs(refs, 'gitlab').find((r) => r.key === ref.key);
+        return (
+          updated?.status !== ref.status ||
+          (updated?.labels || []).join(',') !== (ref.labels || []).join(',')
+        );
+      });
+      if (!statusChanged && !refsChanged) continue;
+
+      const now = result.syncedAt;
+      await ALL_ARTIFACT_SERVICES[typeKey].save({
+        ...artifact,
+        externalIssues: refs,
+        ...(statusChanged
+          ? { status, statusChangedBy: 'GitLab', statusChangedAt: now, lastModified: now }
+          : {}),
+      });
+      changed.push(getSyncArtifactPath(typeKey, artifact.id));
+      result.updated.push(artifact.id);
+    }
+
+    if (changed.length > 0) {
+      await realGitService.commitFiles(
+        changed,
+        `Sync GitLab: ${result.updated.length} artifacts updated`
+      );
+    }
+    debug.log(`[GitLabService] Synced ${result.checked} issues and merge requests`);
+    emitSyncEvent({ result });
+    return result;
+  } catch (err) {
+    emitSyncEvent({ error: err instanceof Error ? err.message : String(err) });
+    throw err;
+  }
+}
+
+/**
+ * Run syncGitLab every config.syncIntervalMinutes; returns a function that
+ * stops the periodic sync. Does nothing when the interval is 0.
+ */
+export async function startGitLabAutoSync(): Promise<() => void> {
+  const { syncIntervalMinutes } = await getGitLabConfig();
+  if (!syncIntervalMinutes) return () => {};
+
+  const interval = setInterval(() => {
+    // Failures are reported through the sync event
+    syncGitLab().catch((err) => debug.warn('[GitLabService] Sync failed:', err));
+  }, syncIntervalMinutes * 60_000);
+  return () => clearInterval(interval);
+}
+
+export const gitlabService = {
+  parseGitLabRemote,
+  formatGitLabKey,
+  parseGitLabKey,
+  getGitLabConfig,
+  setGitLabConfig,
+  setGitLabToken,
+  createGitLabIssues,
+  linkGitLabItem,
+  unlinkGitLabItem,
+  getGitLabMapping,
+  syncGitLab,
+  startGitLabAutoSync,
+};
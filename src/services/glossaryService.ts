@@ -0,0 +1,144 @@
+/**
+ * Glossary Service
+ *
+ * Glossary terms (TERM-xxx) define the project vocabulary. Artifact files can
+ * be scanned for the terms they use, and for capitalized terms that are not
+ * defined yet, so the UI can highlight and link them.
+ */
+
+import type { GlossaryTerm } from '../types';
+import { getTypeFromId } from '../constants/artifactConfig';
+import {
+  findTermOccurrences,
+  findUndefinedTerms,
+  type TermOccurrence,
+} from '../utils/glossaryUtils';
+import { ALL_ARTIFACT_SERVICES, glossaryTermService } from './artifactServices';
+import { idService } from './idService';
+import { debug } from '../utils/debug';
+
+// Artifact types whose content is scanned for glossary terms
+export const SCANNED_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface NewGlossaryTerm {
+  term: string;
+  definition: string;
+  abbreviation?: string;
+  synonyms?: string[];
+}
+
+export interface GlossaryScanResult {
+  artifactId: string;
+  terms: TermOccurrence[]; // Occurrences of defined terms
+  undefinedTerms: TermOccurrence[]; // Capitalized terms without a glossary entry
+}
+
+interface ScannableArtifact {
+  id: string;
+  isDeleted?: boolean;
+}
+
+/**
+ * All defined glossary terms, sorted alphabetically
+ */
+export async function listTerms(): Promise<GlossaryTerm[]> {
+  const terms = await glossaryTermService.loadAll();
+  return terms.filter((t) => !t.isDeleted).sort((a, b) => a.term.localeCompare(b.term));
+}
+
+/**
+ * Add a term to the glossary. Terms, synonyms and abbreviations must be unique.
+ */
+export async function defineTerm(input: NewGlossaryTerm): Promise<GlossaryTerm> {
+  const term = input.term.trim();
+  if (!term) {
+    throw new Error('Glossary term is empty');
+  }
+
+  const synonyms = [...new Set((input.synonyms || []).map((s) => s.trim()).filter(Boolean))];
+  const names = [term, input.abbreviation?.trim(), ...synonyms].filter(Boolean) as string[];
+  const existing = new Map<string, string>();
+  (await listTerms()).forEach((t) => {
+    [t.term, t.abbreviation, ...t.synonyms]
+      .filter(Boolean)
+      .forEach((name) => existing.set(name!.toLowerCase(), t.id));
+  });
+  const duplicate = names.find((name) => existing.has(name.toLowerCase()));
+  if (duplicate) {
+    const owner = existing.get(duplicate.toLowerCase());
+    throw new Error(`"${duplicate}" is already defined by ${owner}`);
+  }
+
+  const now = Date.now();
+  const glossaryTerm: GlossaryTerm = {
+    id: await idService.getNextIdWithSync('glossary'),
+    term,
+    definition: input.definition.trim(),
+    abbreviation: input.abbreviation?.trim() || undefined,
+    synonyms,
+    dateCreated: now,
+    lastModified: now,
+    revision: '01',
+  };
+  return glossaryTermService.save(glossaryTerm);
+}
+
+function scanMarkdown(
+  artifactId: string,
+  markdown: string,
+  terms: GlossaryTerm[]
+): GlossaryScanResult {
+  return {
+    artifactId,
+    terms: findTermOccurrences(markdown, terms),
+    undefinedTerms: findUndefinedTerms(markdown, terms),
+  };
+}
+
+/**
+ * Scan one artifact for glossary terms and undefined capitalized terms.
+ * Line and column positions refer to the artifact's markdown file.
+ */
+export async function scanArtifact(artifactId: string): Promise<GlossaryScanResult> {
+  const typeKey = getTypeFromId(artifactId);
+  if (!SCANNED_TYPES.includes(typeKey)) {
+    throw new Error(`Artifact type is not scanned for glossary terms: ${artifactId}`);
+  }
+
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const artifact: ScannableArtifact | null = await service.load(artifactId);
+  if (!artifact) {
+    throw new Error(`Artifact not found: ${artifactId}`);
+  }
+
+  return scanMarkdown(artifactId, service.serialize(artifact), await listTerms());
+}
+
+/**
+ * Scan all artifacts of the given types; only artifacts with findings are returned
+ */
+export async function scanArtifacts(
+  typeKeys: string[] = SCANNED_TYPES
+): Promise<GlossaryScanResult[]> {
+  const terms = await listTerms();
+  const results: GlossaryScanResult[] = [];
+
+  for (const typeKey of typeKeys.filter((key) => SCANNED_TYPES.includes(key))) {
+    const service = ALL_ARTIFACT_SERVICES[typeKey];
+    const artifacts: ScannableArtifact[] = await service.loadAll();
+    for (const artifact of artifacts.filter((a) => !a.isDeleted)) {
+      const result = scanMarkdown(artifact.id, service.serialize(artifact), terms);
+      if (result.terms.length > 0 || result.undefinedTerms.length > 0) results.push(result);
+    }
+  }
+
+  debug.log(`[GlossaryService] Scanned ${typeKeys.join(', ')}: ${results.length} with findings`);
+  return results;
+}
+
+export const glossaryService = {
+  listTerms,
+  defineTerm,
+  scanArtifact,
+  scanArtifacts,
+};
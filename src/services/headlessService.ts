@@ -0,0 +1,111 @@
+/**
+ * Headless Service
+ *
+ * The app's git and artifact logic as plain functions for callers outside
+ * the React app, such as the tracyfy-cli command line. Each function takes
+ * and returns data and runs on the same services the UI uses, so the caller
+ * only parses its input and prints results. Outside the desktop app the
+ * caller provides window.electronAPI (see scripts/cli/nodeElectronApi.mjs).
+ */
+
+import { fileSystemService } from './fileSystemService';
+import { realGitService } from './realGitService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import {
+  referenceValidationService,
+  type ReferenceValidationReport,
+} from './referenceValidationService';
+import { generateTraceMatrix, traceMatrixToTable, type TraceMatrix } from './traceMatrixService';
+import { createBaseline as createProjectBaseline, listBaselines } from './baselineService';
+import { buildExportData, type ExportData } from '../utils/jsonExportUtils';
+import type { Project, ProjectBaseline } from '../types';
+
+// Types exported with the project, in the order buildExportData takes them
+const EXPORTED_TYPES = [
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+  'documents',
+];
+
+/**
+ * Open a project folder and its git repository
+ */
+export async function openProject(dir: string): Promise<void> {
+  await fileSystemService.openDirectoryPath(dir);
+  if (!(await realGitService.init())) {
+    throw new Error(`Could not open the git repository in ${dir}`);
+  }
+}
+
+/**
+ * Check all artifact references and links of the open project
+ */
+export async function validateProject(): Promise<ReferenceValidationReport> {
+  return referenceValidationService.validate();
+}
+
+/**
+ * A project's artifacts and links as written by the JSON export
+ */
+export async function exportProject(projectId: string): Promise<ExportData> {
+  const project = (await ALL_ARTIFACT_SERVICES.projects.load(projectId)) as Project | null;
+  if (!project) throw new Error(`Project not found: ${projectId}`);
+
+  const [requirements, useCases, testCases, information, risks, documents] = await Promise.all(
+    EXPORTED_TYPES.map((type) => ALL_ARTIFACT_SERVICES[type].loadAll())
+  );
+  return buildExportData(
+    project,
+    { requirements, useCases, testCases, information, risks, documents },
+    project.requirementIds || [],
+    project.useCaseIds || [],
+    project.testCaseIds || [],
+    project.informationIds || [],
+    project.riskIds || [],
+    project.documentIds || []
+  );
+}
+
+/**
+ * The trace matrix between two artifact types, with its table rows (header
+ * first) for CSV output
+ */
+export async function getTraceMatrix(
+  fromType: string,
+  toType: string,
+  projectId?: string
+): Promise<{ matrix: TraceMatrix; table: string[][] }> {
+  const matrix = await generateTraceMatrix(fromType, toType, projectId);
+  return { matrix, table: traceMatrixToTable(matrix) };
+}
+
+/**
+ * Baselines of a project, or of all projects
+ */
+export async function getBaselines(projectId?: string): Promise<ProjectBaseline[]> {
+  return listBaselines(projectId);
+}
+
+/**
+ * Record and tag a baseline of a project
+ */
+export async function createBaseline(
+  projectId: string,
+  version: string,
+  name: string,
+  description = ''
+): Promise<ProjectBaseline> {
+  return createProjectBaseline(name, description, { projectId, version });
+}
+
+export const headlessService = {
+  openProject,
+  validateProject,
+  exportProject,
+  getTraceMatrix,
+  getBaselines,
+  createBaseline,
+};
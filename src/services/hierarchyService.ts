@@ -0,0 +1,198 @@
+/**
+ * Hierarchy Service
+ *
+ * Parent-child hierarchy of artifacts within a type (e.g. system requirements
+ * decomposed into component requirements). Provides parent management with
+ * cycle prevention, tree queries, hierarchical numbering, resolving an
+ * artifact by its number and renumbering a subtree.
+ */
+
+import type { HierarchyNode, HierarchyTreeNode } from '../utils/hierarchyUtils';
+import {
+  buildHierarchyTree,
+  computeHierarchyNumbers,
+  getSubtreeIds,
+  groupChildren,
+  wouldCreateCycle,
+} from '../utils/hierarchyUtils';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { debug } from '../utils/debug';
+
+// Artifact types that support a parent-child hierarchy
+export const HIERARCHY_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface HierarchicalArtifact extends HierarchyNode {
+  lastModified: number;
+}
+
+function getService(typeKey: string) {
+  if (!HIERARCHY_TYPES.includes(typeKey)) {
+    throw new Error(`Artifact type does not support hierarchy: ${typeKey}`);
+  }
+  return ALL_ARTIFACT_SERVICES[typeKey];
+}
+
+async function loadNodes(typeKey: string): Promise<HierarchicalArtifact[]> {
+  return getService(typeKey).loadAll();
+}
+
+function findNode(nodes: HierarchicalArtifact[], id: string): HierarchicalArtifact {
+  const node = nodes.find((n) => n.id === id);
+  if (!node) {
+    throw new Error(`Artifact not found: ${id}`);
+  }
+  return node;
+}
+
+// Next free sibling position under a parent (null for roots)
+function nextOrder(nodes: HierarchicalArtifact[], parentId: string | null, excludeId: string) {
+  const siblings = groupChildren(nodes).get(parentId) || [];
+  return (
+    siblings
+      .filter((n) => n.id !== excludeId)
+      .reduce((max, n, index) => Math.max(max, n.order ?? index + 1), 0) + 1
+  );
+}
+
+/**
+ * Make `parentId` the parent of an artifact. Both must be of the same type and
+ * the new parent must not be the artifact itself or one of its descendants.
+ * The artifact is appended after its new siblings.
+ */
+export async function setParent(
+  typeKey: string,
+  id: string,
+  parentId: string
+): Promise<HierarchicalArtifact> {
+  const nodes = await loadNodes(typeKey);
+  const node = findNode(nodes, id);
+  findNode(nodes, parentId);
+
+  if (wouldCreateCycle(nodes, id, parentId)) {
+    throw new Error(`Cannot make ${parentId} the parent of ${id}: it would create a cycle`);
+  }
+  if (node.parentId === parentId) return node;
+
+  const updated = {
+    ...node,
+    parentId,
+    order: nextOrder(nodes, parentId, id),
+    lastModified: Date.now(),
+  };
+  await getService(typeKey).save(updated);
+  debug.log(`[HierarchyService] Set parent of ${id} to ${parentId}`);
+  return updated;
+}
+
+/**
+ * Detach an artifact from its parent, making it a root (its subtree moves along)
+ */
+export async function unsetParent(typeKey: string, id: string): Promise<HierarchicalArtifact> {
+  const nodes = await loadNodes(typeKey);
+  const node = findNode(nodes, id);
+  if (!node.parentId) return node;
+
+  const updated = {
+    ...node,
+    parentId: undefined,
+    order: nextOrder(nodes, null, id),
+    lastModified: Date.now(),
+  };
+  await getService(typeKey).save(updated);
+  debug.log(`[HierarchyService] Removed parent of ${id}`);
+  return updated;
+}
+
+/**
+ * Direct children of an artifact in sibling order
+ */
+export async function getChildren<T extends HierarchicalArtifact>(
+  typeKey: string,
+  id: string
+): Promise<T[]> {
+  const nodes = await loadNodes(typeKey);
+  findNode(nodes, id);
+  return (groupChildren(nodes).get(id) || []) as T[];
+}
+
+/**
+ * Full numbered tree of all artifacts of a type
+ */
+export async function getHierarchyTree<T extends HierarchicalArtifact>(
+  typeKey: string
+): Promise<HierarchyTreeNode<T>[]> {
+  return buildHierarchyTree((await loadNodes(typeKey)) as T[]);
+}
+
+/**
+ * Hierarchical numbers (1, 1.1, 1.1.2) for all artifacts of a type, keyed by ID
+ */
+export async function getHierarchyNumbers(typeKey: string): Promise<Map<string, string>> {
+  return computeHierarchyNumbers(await loadNodes(typeKey));
+}
+
+/**
+ * Find the artifact of a type with the given hierarchical number
+ */
+export async function resolveHierarchyNumber<T extends HierarchicalArtifact>(
+  typeKey: string,
+  number: string
+): Promise<T | null> {
+  const wanted = number.trim().replace(/\.$/, '');
+  const nodes = await loadNodes(typeKey);
+  const numbers = computeHierarchyNumbers(nodes);
+
+  for (const [id, value] of numbers) {
+    if (value === wanted) {
+      return (nodes.find((n) => n.id === id) as T) ?? null;
+    }
+  }
+  return null;
+}
+
+/**
+ * Rewrite the stored sibling `order` below an artifact (or the whole type when
+ * no root is given) so it matches the displayed numbering without gaps.
+ * Returns the artifacts that were updated.
+ */
+export async function renumberSubtree(
+  typeKey: string,
+  rootId?: string
+): Promise<HierarchicalArtifact[]> {
+  const service = getService(typeKey);
+  const nodes = await loadNodes(typeKey);
+  if (rootId && !nodes.some((n) => n.id === rootId)) {
+    throw new Error(`Artifact not found: ${rootId}`);
+  }
+
+  const children = groupChildren(nodes);
+  const parents: (string | null)[] = rootId
+    ? getSubtreeIds(nodes, rootId)
+    : [null, ...nodes.map((n) => n.id)];
+  const updated: HierarchicalArtifact[] = [];
+
+  for (const parentId of parents) {
+    const siblings = children.get(parentId) || [];
+    for (let i = 0; i < siblings.length; i++) {
+      if (siblings[i].order === i + 1) continue;
+      const artifact = { ...siblings[i], order: i + 1, lastModified: Date.now() };
+      await service.save(artifact);
+      updated.push(artifact);
+    }
+  }
+
+  debug.log(
+    `[HierarchyService] Renumbered ${rootId ?? typeKey}: ${updated.length} artifact(s) updated`
+  );
+  return updated;
+}
+
+export const hierarchyService = {
+  setParent,
+  unsetParent,
+  getChildren,
+  getHierarchyTree,
+  getHierarchyNumbers,
+  resolveHierarchyNumber,
+  renumberSubtree,
+};
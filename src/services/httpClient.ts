@@ -0,0 +1,84 @@
+/**
+ * HTTP Client
+ *
+ * JSON requests to external services (issue trackers, webhooks). In
+ * Electron requests are sent by the main process to avoid CORS, and only to
+ * the servers set up on this machine for the integration named in the
+ * request; the browser build uses fetch.
+ */
+
+import { isElectronEnv } from './git/types';
+
+export type HttpIntegration = 'github' | 'gitlab' | 'jira' | 'webhook';
+
+export interface HttpRequest {
+  integration: HttpIntegration; // Which integration's servers the URL must be on
+  url: string;
+  method?: string;
+  headers?: Record<string, string>;
+  body?: string;
+}
+
+export interface HttpResponse {
+  status: number;
+  headers: Record<string, string>;
+  body: string;
+}
+
+export class HttpError extends Error {
+  status: number;
+
+  constructor(status: number, message: string) {
+    super(message);
+    this.name = 'HttpError';
+    this.status = status;
+  }
+}
+
+/**
+ * Send a request and return the raw response
+ */
+export async function httpRequest(request: HttpRequest): Promise<HttpResponse> {
+  if (isElectronEnv()) {
+    const result = await window.electronAPI!.http.request(request);
+    if (result.error) throw new Error(result.error);
+    return { status: result.status!, headers: result.headers || {}, body: result.body || '' };
+  }
+
+  const response = await fetch(request.url, {
+    method: request.method || 'GET',
+    headers: request.headers,
+    body: request.body,
+  });
+  return {
+    status: response.status,
+    headers: Object.fromEntries(response.headers.entries()),
+    body: await response.text(),
+  };
+}
+
+/**
+ * Send a JSON request and parse the JSON response; throws HttpError for
+ * non-2xx responses
+ */
+export async function requestJson<T>(request: HttpRequest & { json?: unknown }): Promise<T> {
+  const { json, ...rest } = request;
+  const response = await httpRequest({
+    ...rest,
+    headers: {
+      Accept: 'application/json',
+      ...(json !== undefined ? { 'Content-Type': 'application/json' } : {}),
+      ...rest.headers,
+    },
+    body: json !== undefined ? JSON.stringify(json) : rest.body,
+  });
+
+  if (response.status < 200 || response.status >= 300) {
+    const detail = response.body.slice(0, 200);
+    throw new HttpError(
+      response.status,
+      `${rest.method || 'GET'} ${rest.url} failed with ${response.status}: ${detail}`
+    );
+  }
+  return (response.body ? JSON.parse(response.body) : null) as T;
+}
@@ -58,8 +58,9 @@ export class IdService extends BaseDiskService {
 
   /**
    * Get multiple artifact IDs at once (batch allocation)
+   * @param skipCommit - Leave the counter update uncommitted (caller commits it)
    */
-  async getNextIds(type: string, count: number): Promise<string[]> {
+  async getNextIds(type: string, count: number, skipCommit: boolean = false): Promise<string[]> {
     if (count <= 0) return [];
 
     const config = ARTIFACT_CONFIG[type];
@@ -69,7 +70,7 @@ export class IdService extends BaseDiskService {
 
     const current = await this.getCounter(type);
     const nextEnd = current + count;
-    await this.setCounter(type, nextEnd);
+    await this.setCounter(type, nextEnd, skipCommit);
 
     const ids: string[] = [];
     for (let i = current + 1; i <= nextEnd; i++) {
@@ -79,6 +80,17 @@ export class IdService extends BaseDiskService {
     return ids;
   }
 
+  /**
+   * Repo-relative path of the counter file for a type
+   */
+  getCounterPath(type: string): string {
+    const config = ARTIFACT_CONFIG[type];
+    if (!config) {
+      throw new Error(`[IdService] No config found for type: ${type}`);
+    }
+    return `counters/${config.folder}.md`;
+  }
+
   /**
    * Get next artifact ID with remote sync (for collaboration)
    */
@@ -6,6 +6,9 @@
  */
 
 import type { Link } from '../types';
+import type { LinkCategory } from '../utils/linkTypes';
+import { isLinkInCategories, propagatesImpact } from '../utils/linkTypes';
+import { diskLinkService } from './diskLinkService';
 
 export type ImpactDirection = 'upstream' | 'downstream' | 'both';
 
@@ -24,6 +27,15 @@ export interface ImpactChain {
   byType: Map<string, ImpactNode[]>; // Grouped by artifact type prefix (REQ, UC, TC, etc.)
 }
 
+export interface AffectedArtifact {
+  artifactId: string;
+  artifactType: string;
+  level: number;
+  direction: 'upstream' | 'downstream';
+  path: string[]; // Artifact IDs from the source to this artifact (inclusive)
+  linkTypes: string[]; // Link type of each hop along the path
+}
+
 export interface ImpactSummary {
   total: number;
   upstream: number;
@@ -78,14 +90,20 @@ function getArtifactTypeFromId(id: string): string {
  * @param links - All links in the system
  * @param direction - Which direction to traverse
  * @param maxDepth - Maximum depth to traverse (0 = unlimited)
+ * @param categories - Only follow links in these categories (default: all
+ *   link types that propagate impact, i.e. not mere associations)
  */
 export function getImpactChain(
   sourceId: string,
   links: Link[],
   direction: ImpactDirection = 'both',
-  maxDepth: number = 0
+  maxDepth: number = 0,
+  categories?: LinkCategory[]
 ): ImpactChain {
-  const { outgoing, incoming } = buildAdjacencyMap(links);
+  const followedLinks = links.filter((link) =>
+    categories ? isLinkInCategories(link.type, categories) : propagatesImpact(link.type)
+  );
+  const { outgoing, incoming } = buildAdjacencyMap(followedLinks);
   const visited = new Set<string>();
   const nodes: ImpactNode[] = [];
   const byLevel = new Map<number, ImpactNode[]>();
@@ -201,6 +219,63 @@ export function getImpactSummary(chain: ImpactChain): ImpactSummary {
   };
 }
 
+/**
+ * Reconstruct the path from the chain's source to an artifact by following parentId.
+ * Returns an empty array if the artifact is not part of the chain.
+ */
+export function getImpactPath(chain: ImpactChain, artifactId: string): string[] {
+  const nodesById = new Map(chain.nodes.map((n) => [n.artifactId, n]));
+  if (!nodesById.has(artifactId)) return [];
+
+  const path: string[] = [];
+  let current: ImpactNode | undefined = nodesById.get(artifactId);
+  while (current) {
+    path.unshift(current.artifactId);
+    current = current.parentId ? nodesById.get(current.parentId) : undefined;
+  }
+  path.unshift(chain.sourceId);
+  return path;
+}
+
+/**
+ * List every artifact in the chain together with the path that connects it to the source
+ */
+export function getAffectedArtifacts(chain: ImpactChain): AffectedArtifact[] {
+  const nodesById = new Map(chain.nodes.map((n) => [n.artifactId, n]));
+
+  return chain.nodes.map((node) => {
+    const path = getImpactPath(chain, node.artifactId);
+    return {
+      artifactId: node.artifactId,
+      artifactType: getArtifactTypeFromId(node.artifactId),
+      level: node.level,
+      direction: node.direction,
+      path,
+      linkTypes: path.slice(1).map((id) => nodesById.get(id)!.linkType),
+    };
+  });
+}
+
+/**
+ * Analyze the impact of changing an artifact using the links stored on disk.
+ * Walks downstream by default; set includeUpstream to also walk incoming links.
+ * Pass categories to follow only e.g. derivation and verification links.
+ */
+export async function analyzeImpact(
+  artifactId: string,
+  maxDepth: number = 0,
+  includeUpstream: boolean = false,
+  projectId?: string,
+  categories?: LinkCategory[]
+): Promise<AffectedArtifact[]> {
+  const links = projectId
+    ? await diskLinkService.getLinksForProject(projectId)
+    : await diskLinkService.getAllLinks();
+  const direction: ImpactDirection = includeUpstream ? 'both' : 'downstream';
+  const chain = getImpactChain(artifactId, links, direction, maxDepth, categories);
+  return getAffectedArtifacts(chain);
+}
+
 /**
  * Get list of affected artifact IDs
  */
@@ -220,4 +295,7 @@ export const impactAnalysisService = {
   getImpactSummary,
   getAffectedArtifactIds,
   getNodesAtLevel,
+  getImpactPath,
+  getAffectedArtifacts,
+  analyzeImpact,
 };
@@ -0,0 +1,126 @@
+/**
+ * Integrity Service
+ *
+ * Finds artifacts that changed outside the sanctioned flow. Approvals record
+ * the content hash of an artifact and baselines a hash manifest of theirs;
+ * verifying compares those hashes with the content they stand for. An
+ * artifact that is still approved but whose content no longer matches its
+ * latest approval was edited without being reviewed again (for example
+ * directly on disk), one marked approved without any approval record skipped
+ * the review, and a baseline artifact whose committed content no longer
+ * matches the manifest points at rewritten history.
+ */
+
+import type { ProjectBaseline } from '../types';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { approvalService } from './approvalService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { getBaselineContents, listBaselines } from './baselineService';
+import { WORKFLOW_TYPES } from './statusWorkflowService';
+import { computeContentHash } from '../utils/contentHashUtils';
+import { debug } from '../utils/debug';
+
+const APPROVED_STATUS = 'approved';
+
+export type IntegrityIssueKind =
+  | 'edited-after-approval' // Still approved, content differs from the latest approval
+  | 'approved-without-record' // Marked approved but never approved through the app
+  | 'baseline-mismatch' // Committed content differs from the baseline manifest
+  | 'baseline-missing'; // File cannot be read at the commit the baseline recorded
+
+export interface IntegrityIssue {
+  kind: IntegrityIssueKind;
+  artifactId: string;
+  path: string;
+  reference: string; // Approval or baseline the content was checked against
+  expected: string | null; // Recorded hash
+  actual: string | null; // Hash of the content now
+}
+
+export interface IntegrityReport {
+  checkedArtifacts: number; // Approved artifacts compared with their approvals
+  checkedBaselines: number;
+  issues: IntegrityIssue[];
+}
+
+interface WorkflowArtifact {
+  id: string;
+  status?: string;
+  isDeleted?: boolean;
+}
+
+async function verifyApprovals(): Promise<{ checked: number; issues: IntegrityIssue[] }> {
+  let checked = 0;
+  const issues: IntegrityIssue[] = [];
+  for (const typeKey of WORKFLOW_TYPES) {
+    const artifacts: WorkflowArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+    for (const artifact of artifacts) {
+      if (artifact.isDeleted || artifact.status !== APPROVED_STATUS) continue;
+      checked++;
+      const path = `${ARTIFACT_CONFIG[typeKey].folder}/${artifact.id}.md`;
+      const check = await approvalService.checkApproval(path);
+      if (!check) {
+        issues.push({
+          kind: 'approved-without-record',
+          artifactId: artifact.id,
+          path,
+          reference: 'approval',
+          expected: null,
+          actual: await computeContentHash(ALL_ARTIFACT_SERVICES[typeKey].serialize(artifact)),
+        });
+      } else if (check.editedSinceApproval) {
+        const { approval } = check;
+        const approvedAt = new Date(approval.timestamp).toISOString();
+        issues.push({
+          kind: 'edited-after-approval',
+          artifactId: artifact.id,
+          path,
+          reference: `approval by ${approval.reviewer} at ${approvedAt}`,
+          expected: approval.contentHash,
+          actual: check.currentHash,
+        });
+      }
+    }
+  }
+  return { checked, issues };
+}
+
+async function verifyBaseline(baseline: ProjectBaseline): Promise<IntegrityIssue[]> {
+  const { artifacts } = await getBaselineContents(baseline.id, baseline.projectId);
+  const reference = `baseline ${baseline.tag ?? baseline.version}`;
+  const issues: IntegrityIssue[] = [];
+  for (const { id, path, content, intact } of artifacts) {
+    if (intact) continue;
+    issues.push({
+      kind: content === null ? 'baseline-missing' : 'baseline-mismatch',
+      artifactId: id,
+      path,
+      reference,
+      expected: baseline.manifest?.[id] ?? null,
+      actual: content === null ? null : await computeContentHash(content),
+    });
+  }
+  return issues;
+}
+
+/**
+ * Check approved artifacts against their approvals and baselines (of one
+ * project, or all) against their manifests
+ */
+export async function verifyIntegrity(projectId?: string): Promise<IntegrityReport> {
+  const approvals = await verifyApprovals();
+  const baselines = await listBaselines(projectId);
+  const issues = [...approvals.issues];
+  for (const baseline of baselines) {
+    issues.push(...(await verifyBaseline(baseline)));
+  }
+  debug.log(
+    `[IntegrityService] Checked ${approvals.checked} artifact(s) and ${baselines.length} ` +
+      `baseline(s): ${issues.length} issue(s)`
+  );
+  return { checkedArtifacts: approvals.checked, checkedBaselines: baselines.length, issues };
+}
+
+export const integrityService = {
+  verifyIntegrity,
+};
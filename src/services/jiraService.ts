@@ -0,0 +1,319 @@
+/**
+ * Jira Service
+ *
+ * Connects artifacts to Jira issues over the REST API (API token auth).
+ * Artifacts can be pushed as issues, existing issues can be linked, and
+ * issue statuses are pulled back into the artifacts' frontmatter. The
+ * artifact-to-issue mapping lives in each artifact's externalIssues list.
+ *
+ * The project, issue type and status map are shared in the project config.
+ * The server URL is an app setting of this machine instead: the API token
+ * would otherwise go to whatever host someone committed to the repository.
+ */
+
+import type { ExternalIssueRef } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { secretService } from './secretService';
+import { HttpError, requestJson } from './httpClient';
+import { projectConfigService } from './projectConfigService';
+import { appSettingsService } from './appSettingsService';
+import { realGitService } from './realGitService';
+import {
+  getIssueMapping,
+  getSyncArtifactPath,
+  loadLinkedArtifacts,
+  loadSyncArtifact,
+  unlinkIssue,
+  type ExternalIssueMapping,
+} from './externalIssueService';
+import { getIssueRefs, upsertIssueRef } from '../utils/externalIssueUtils';
+import { debug } from '../utils/debug';
+
+const JIRA_SECTION = 'jira';
+const TOKEN_NAME = 'jira';
+const INTEGRATION = 'jira';
+const PROJECT_KEY_PATTERN = /^[A-Z][A-Z0-9_]*$/;
+const ISSUE_KEY_PATTERN = /^[A-Z][A-Z0-9_]*-\d+$/;
+
+export interface JiraConfig {
+  baseUrl: string; // e.g. https://acme.atlassian.net
+  email: string; // Account the API token belongs to
+  projectKey: string; // Project new issues are created in
+  issueType: string;
+  statusMap: Record<string, string>; // Jira status name -> artifact status
+}
+
+export const DEFAULT_JIRA_CONFIG: JiraConfig = {
+  baseUrl: '',
+  email: '',
+  projectKey: '',
+  issueType: 'Task',
+  statusMap: {},
+};
+
+export interface JiraPushResult {
+  artifactId: string;
+  issueKey: string;
+  action: 'created' | 'updated';
+}
+
+export interface JiraPullResult {
+  checked: number; // Linked issues that were fetched
+  updated: string[]; // Artifacts whose status changed
+  missing: string[]; // Issue keys Jira no longer knows
+}
+
+interface JiraIssue {
+  key: string;
+  fields: { status?: { name: string } };
+}
+
+/**
+ * The Jira settings of the project, with the server set up on this machine
+ */
+export async function getJiraConfig(): Promise<JiraConfig> {
+  const shared = await projectConfigService.getSection<JiraConfig>(
+    JIRA_SECTION,
+    DEFAULT_JIRA_CONFIG
+  );
+  const { jira } = await appSettingsService.getIntegrationUrls();
+  return { ...shared, baseUrl: jira ?? '' };
+}
+
+/**
+ * Problems with a Jira configuration
+ */
+export function validateJiraConfig(config: JiraConfig): string[] {
+  const errors: string[] = [];
+  if (!/^https:\/\/[^/\s]+/.test(config.baseUrl)) errors.push('Base URL must be an https URL');
+  if (!config.email.includes('@')) errors.push('Email is required');
+  if (!PROJECT_KEY_PATTERN.test(config.projectKey)) errors.push('Invalid project key');
+  if (!config.issueType.trim()) errors.push('Issue type is required');
+  return errors;
+}
+
+/**
+ * Save the Jira settings after checking them: the server URL on this
+ * machine, the rest in the project config
+ */
+export async function setJiraConfig(config: JiraConfig): Promise<void> {
+  const errors = validateJiraConfig(config);
+  if (errors.length > 0) {
+    throw new Error(`Invalid Jira configuration: ${errors.join('; ')}`);
+  }
+  const { baseUrl, ...shared } = config;
+  await appSettingsService.setIntegrationUrl('jira', baseUrl.replace(/\/+$/, ''));
+  await projectConfigService.setSection(JIRA_SECTION, shared, 'Update Jira configuration');
+}
+
+/**
+ * Store the Jira API token in secure storage
+ */
+export async function setJiraToken(token: string): Promise<void> {
+  await secretService.storeSecret(TOKEN_NAME, token);
+}
+
+export async function clearJiraToken(): Promise<void> {
+  await secretService.deleteSecret(TOKEN_NAME);
+}
+
+async function getConnection(): Promise<{ config: JiraConfig; headers: Record<string, string> }> {
+  const config = await getJiraConfig();
+  if (validateJiraConfig(config).length > 0) {
+    throw new Error('Jira is not configured');
+  }
+  const token = await secretService.getSecret(TOKEN_NAME);
+  if (!token) {
+    throw new Error('No Jira API token stored');
+  }
+  return { config, headers: { Authorization: `Basic ${btoa(`${config.email}:${token}`)}` } };
+}
+
+function issueUrl(config: JiraConfig, key: string): string {
+  return `${config.baseUrl}/browse/${key}`;
+}
+
+/**
+ * Create Jira issues for artifacts that have none, and update the summary
+ * and description of issues already linked. Committed in one commit.
+ */
+export async function pushToJira(artifactIds: string[]): Promise<JiraPushResult[]> {
+  const { config, headers } = await getConnection();
+  const api = `${config.baseUrl}/rest/api/2`;
+  const results: JiraPushResult[] = [];
+  const changed: string[] = [];
+
+  for (const id of artifactIds) {
+    const { typeKey, artifact } = await loadSyncArtifact(id);
+    const fields = {
+      summary: `[${artifact.id}] ${artifact.title}`,
+      description: artifact.description || artifact.text || '',
+    };
+    const linked = getIssueRefs(artifact.externalIssues, 'jira');
+
+    if (linked.length > 0) {
+      for (const ref of linked) {
+        await requestJson({
+          integration: INTEGRATION,
+          url: `${api}/issue/${ref.key}`,
+          method: 'PUT',
+          headers,
+          json: { fields },
+        });
+        results.push({ artifactId: id, issueKey: ref.key, action: 'updated' });
+      }
+      continue;
+    }
+
+    const created = await requestJson<{ key: string }>({
+      integration: INTEGRATION,
+      url: `${api}/issue`,
+      method: 'POST',
+      headers,
+      json: {
+        fields: {
+          ...fields,
+          project: { key: config.projectKey },
+          issuetype: { name: config.issueType },
+        },
+      },
+    });
+    const ref: ExternalIssueRef = {
+      tracker: 'jira',
+      key: created.key,
+      url: issueUrl(config, created.key),
+      syncedAt: Date.now(),
+    };
+    await ALL_ARTIFACT_SERVICES[typeKey].save({
+      ...artifact,
+      externalIssues: upsertIssueRef(artifact.externalIssues, ref),
+    });
+    changed.push(getSyncArtifactPath(typeKey, id));
+    results.push({ artifactId: id, issueKey: created.key, action: 'created' });
+  }
+
+  if (changed.length > 0) {
+    await realGitService.commitFiles(changed, `Create ${changed.length} Jira issues`);
+  }
+  debug.log(`[JiraService] Pushed ${results.length} artifacts to Jira`);
+  return results;
+}
+
+/**
+ * Fetch the status of every linked issue, record it on the artifacts and
+ * apply mapped statuses (config.statusMap). Committed in one commit.
+ */
+export async function pullFromJira(): Promise<JiraPullResult> {
+  const { config, headers } = await getConnection();
+  const result: JiraPullResult = { checked: 0, updated: [], missing: [] };
+  const changed: string[] = [];
+  const now = Date.now();
+
+  for (const { typeKey, artifact } of await loadLinkedArtifacts('jira')) {
+    const linked = getIssueRefs(artifact.externalIssues, 'jira');
+    let refs = artifact.externalIssues;
+    let status = artifact.status;
+    for (const ref of linked) {
+      let issue: JiraIssue;
+      try {
+        issue = await requestJson<JiraIssue>({
+          integration: INTEGRATION,
+          url: `${config.baseUrl}/rest/api/2/issue/${ref.key}?fields=status`,
+          headers,
+        });
+      } catch (err) {
+        if (err instanceof HttpError && err.status === 404) {
+          result.missing.push(ref.key);
+          continue;
+        }
+        throw err;
+      }
+      result.checked++;
+      const issueStatus = issue.fields.status?.name;
+      refs = upsertIssueRef(refs, { ...ref, status: issueStatus, syncedAt: now });
+      if (issueStatus && config.statusMap[issueStatus]) {
+        status = config.statusMap[issueStatus];
+      }
+    }
+
+    const statusChanged = status !== artifact.status;
+    const refsChanged = linked.some((ref) => {
+      const updated = getIssueRefs(refs, 'jira').find((r) => r.key === ref.key);
+      return updated?.status !== ref.status;
+    });
+    if (!statusChanged && !refsChanged) continue;
+
+    await ALL_ARTIFACT_SERVICES[typeKey].save({
+      ...artifact,
+      externalIssues: refs,
+      ...(statusChanged
+        ? { status, statusChangedBy: 'Jira', statusChangedAt: now, lastModified: now }
+        : {}),
+    });
+    changed.push(getSyncArtifactPath(typeKey, artifact.id));
+    if (statusChanged) result.updated.push(artifact.id);
+  }
+
+  if (changed.length > 0) {
+    await realGitService.commitFiles(
+      changed,
+      `Sync Jira issue status: ${result.updated.length} artifacts updated`
+    );
+  }
+  return result;
+}
+
+/**
+ * Link an existing Jira issue to an artifact
+ */
+export async function linkJiraIssue(artifactId: string, issueKey: string): Promise<void> {
+  if (!ISSUE_KEY_PATTERN.test(issueKey)) {
+    throw new Error(`Invalid Jira issue key: ${issueKey}`);
+  }
+  const { config, headers } = await getConnection();
+  const { typeKey, artifact } = await loadSyncArtifact(artifactId);
+  const issue = await requestJson<JiraIssue>({
+    integration: INTEGRATION,
+    url: `${config.baseUrl}/rest/api/2/issue/${issueKey}?fields=status`,
+    headers,
+  });
+
+  const ref: ExternalIssueRef = {
+    tracker: 'jira',
+    key: issue.key,
+    url: issueUrl(config, issue.key),
+    status: issue.fields.status?.name,
+    syncedAt: Date.now(),
+  };
+  await ALL_ARTIFACT_SERVICES[typeKey].save(
+    { ...artifact, externalIssues: upsertIssueRef(artifact.externalIssues, ref) },
+    `Link ${artifactId} to Jira issue ${issue.key}`
+  );
+}
+
+/**
+ * Remove the link between an artifact and a Jira issue
+ */
+export async function unlinkJiraIssue(artifactId: string, issueKey: string): Promise<void> {
+  await unlinkIssue('jira', artifactId, issueKey);
+}
+
+/**
+ * All artifact-to-issue links
+ */
+export async function getJiraIssueMapping(): Promise<ExternalIssueMapping[]> {
+  return getIssueMapping('jira');
+}
+
+export const jiraService = {
+  getJiraConfig,
+  validateJiraConfig,
+  setJiraConfig,
+  setJiraToken,
+  clearJiraToken,
+  pushToJira,
+  pullFromJira,
+  linkJiraIssue,
+  unlinkJiraIssue,
+  getJiraIssueMapping,
+};
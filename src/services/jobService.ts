@@ -0,0 +1,199 @@
+/**
+ * Job Service
+ *
+ * Runs long operations as background jobs: the project scan on opening,
+ * the JSON, PDF and Excel exports and imports of dropped files. Submitting
+ * returns a job ID right away; callers poll getJobStatus or listen for the
+ * job events, and can cancel a job, which aborts the signal passed to its
+ * task. Jobs run a few at a time in submission order; finished jobs are
+ * kept for a while so their result can still be collected. Other bulk
+ * operations (markdown and test result imports, artifact clones) still run
+ * directly in their callers.
+ */
+
+import { debug } from '../utils/debug';
+
+export type JobState = 'queued' | 'running' | 'completed' | 'failed' | 'cancelled';
+
+export interface JobProgress {
+  completed: number;
+  total: number;
+  message?: string;
+}
+
+export interface JobContext {
+  signal: AbortSignal; // Aborted when the job is cancelled
+  reportProgress: (progress: JobProgress) => void;
+}
+
+export type JobTask<T> = (context: JobContext) => Promise<T>;
+
+export interface JobStatus<T = unknown> {
+  id: string;
+  name: string;
+  state: JobState;
+  progress?: JobProgress;
+  result?: T;
+  error?: string;
+  submittedAt: number;
+  startedAt?: number;
+  finishedAt?: number;
+}
+
+// Event names dispatched on window with the job status as detail
+export const JOB_UPDATED_EVENT = 'job-updated';
+export const JOB_FINISHED_EVENT = 'job-finished';
+
+const MAX_CONCURRENT_JOBS = 2;
+const MAX_FINISHED_JOBS = 50;
+
+interface Job {
+  status: JobStatus;
+  task: JobTask<unknown>;
+  controller: AbortController;
+}
+
+const jobs = new Map<string, Job>();
+const queue: string[] = [];
+let running = 0;
+let nextId = 1;
+
+function isFinished(state: JobState): boolean {
+  return state === 'completed' || state === 'failed' || state === 'cancelled';
+}
+
+function notify(job: Job): void {
+  if (typeof window === 'undefined') return;
+  const detail = { ...job.status };
+  window.dispatchEvent(new CustomEvent(JOB_UPDATED_EVENT, { detail }));
+  if (isFinished(job.status.state)) {
+    window.dispatchEvent(new CustomEvent(JOB_FINISHED_EVENT, { detail }));
+  }
+}
+
+function finish(job: Job, update: Partial<JobStatus>): void {
+  if (isFinished(job.status.state)) return;
+  job.status = { ...job.status, ...update, finishedAt: Date.now() };
+  debug.log(`[JobService] ${job.status.id} (${job.status.name}) ${job.status.state}`);
+  notify(job);
+
+  // Forget the oldest finished jobs
+  const finished = [...jobs.values()].filter((j) => isFinished(j.status.state));
+  finished
+    .slice(0, Math.max(0, finished.length - MAX_FINISHED_JOBS))
+    .forEach((j) => jobs.delete(j.status.id));
+}
+
+async function run(job: Job): Promise<void> {
+  running++;
+  job.status = { ...job.status, state: 'running', startedAt: Date.now() };
+  notify(job);
+  try {
+    const result = await job.task({
+      signal: job.controller.signal,
+      reportProgress: (progress) => {
+        if (job.status.state !== 'running') return;
+        job.status = { ...job.status, progress };
+        notify(job);
+      },
+    });
+    finish(job, { state: 'completed', result });
+  } catch (err) {
+    finish(job, { state: 'failed', error: err instanceof Error ? err.message : String(err) });
+  } finally {
+    running--;
+    startNext();
+  }
+}
+
+function startNext(): void {
+  while (running < MAX_CONCURRENT_JOBS && queue.length > 0) {
+    const job = jobs.get(queue.shift()!);
+    if (job && job.status.state === 'queued') void run(job);
+  }
+}
+
+/**
+ * Queue a task as a background job and return its ID
+ */
+export function submitJob<T>(name: string, task: JobTask<T>): string {
+  const id = `job-${nextId++}`;
+  const job: Job = {
+    status: { id, name, state: 'queued', submittedAt: Date.now() },
+    task,
+    controller: new AbortController(),
+  };
+  jobs.set(id, job);
+  queue.push(id);
+  notify(job);
+  startNext();
+  return id;
+}
+
+/**
+ * Current status of a job, or null for unknown (or long finished) jobs
+ */
+export function getJobStatus<T = unknown>(id: string): JobStatus<T> | null {
+  const job = jobs.get(id);
+  return job ? ({ ...job.status } as JobStatus<T>) : null;
+}
+
+/**
+ * All known jobs, oldest first
+ */
+export function listJobs(): JobStatus[] {
+  return [...jobs.values()].map((job) => ({ ...job.status }));
+}
+
+/**
+ * Cancel a queued or running job. Running tasks are told through their abort
+ * signal; the job counts as cancelled right away. Returns false when the job
+ * is unknown or already finished.
+ */
+export function cancelJob(id: string): boolean {
+  const job = jobs.get(id);
+  if (!job || isFinished(job.status.state)) return false;
+  job.controller.abort();
+  finish(job, { state: 'cancelled' });
+  return true;
+}
+
+/**
+ * Resolve with the final status of a job once it has finished
+ */
+export function waitForJob<T = unknown>(id: string): Promise<JobStatus<T>> {
+  const status = getJobStatus<T>(id);
+  if (!status) return Promise.reject(new Error(`Unknown job: ${id}`));
+  if (isFinished(status.state)) return Promise.resolve(status);
+
+  return new Promise((resolve) => {
+    const listener = (event: Event) => {
+      const detail = (event as CustomEvent<JobStatus<T>>).detail;
+      if (detail.id !== id) return;
+      window.removeEventListener(JOB_FINISHED_EVENT, listener);
+      resolve(detail);
+    };
+    window.addEventListener(JOB_FINISHED_EVENT, listener);
+  });
+}
+
+/**
+ * Run a task as a job and resolve with its result once it has finished;
+ * rejects when the job fails or is cancelled
+ */
+export async function runJob<T>(name: string, task: JobTask<T>): Promise<T> {
+  const status = await waitForJob<T>(submitJob(name, task));
+  if (status.state !== 'completed') {
+    throw new Error(status.error ?? `${name} was cancelled`);
+  }
+  return status.result as T;
+}
+
+export const jobService = {
+  submitJob,
+  getJobStatus,
+  listJobs,
+  cancelJob,
+  waitForJob,
+  runJob,
+};
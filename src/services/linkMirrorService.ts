@@ -0,0 +1,147 @@
+/**
+ * Link Mirror Service
+ *
+ * Keeps embedded links (`linkedArtifacts` in artifact frontmatter) bidirectional:
+ * when A links to B, B carries the inverse entry back to A. Provides incremental
+ * mirroring on write and a repair pass that reconciles one-sided links.
+ */
+
+import type { ArtifactLink } from '../types';
+import { getInverseType } from '../utils/linkTypes';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { debug } from '../utils/debug';
+
+// Artifact types that carry embedded links
+export const MIRRORED_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface LinkableArtifact {
+  id: string;
+  linkedArtifacts?: ArtifactLink[];
+  lastModified: number;
+}
+
+export interface OneSidedLink {
+  sourceId: string;
+  targetId: string;
+  type: ArtifactLink['type'];
+}
+
+const linkKey = (link: ArtifactLink) => `${link.targetId}:${link.type}`;
+
+function hasEntry(artifact: LinkableArtifact, entry: ArtifactLink): boolean {
+  return (artifact.linkedArtifacts || []).some(
+    (l) => l.targetId === entry.targetId && l.type === entry.type
+  );
+}
+
+function getService(artifactId: string) {
+  const typeKey = getTypeFromId(artifactId);
+  return MIRRORED_TYPES.includes(typeKey) ? ALL_ARTIFACT_SERVICES[typeKey] : null;
+}
+
+/**
+ * Find embedded links whose target does not carry the inverse entry.
+ * Links to artifacts that are not in the list are ignored (see dangling link cleanup).
+ */
+export function findOneSidedLinks(artifacts: LinkableArtifact[]): OneSidedLink[] {
+  const byId = new Map(artifacts.map((a) => [a.id, a]));
+  const oneSided: OneSidedLink[] = [];
+
+  artifacts.forEach((artifact) => {
+    (artifact.linkedArtifacts || []).forEach((link) => {
+      const target = byId.get(link.targetId);
+      if (!target || target.id === artifact.id) return;
+      if (!hasEntry(target, { targetId: artifact.id, type: getInverseType(link.type) })) {
+        oneSided.push({ sourceId: artifact.id, targetId: link.targetId, type: link.type });
+      }
+    });
+  });
+
+  return oneSided;
+}
+
+/**
+ * Apply the inverse of link changes on an artifact to the linked artifacts on disk.
+ * Call after saving an artifact whose linkedArtifacts went from `previous` to `next`.
+ * Returns the linked artifacts that were updated.
+ */
+export async function syncMirroredLinks(
+  artifactId: string,
+  previous: ArtifactLink[] = [],
+  next: ArtifactLink[] = []
+): Promise<LinkableArtifact[]> {
+  const previousKeys = new Set(previous.map(linkKey));
+  const nextKeys = new Set(next.map(linkKey));
+  const added = next.filter((l) => !previousKeys.has(linkKey(l)));
+  const removed = previous.filter((l) => !nextKeys.has(linkKey(l)));
+  const touched: LinkableArtifact[] = [];
+
+  for (const link of [...added, ...removed]) {
+    if (link.targetId === artifactId) continue;
+    const service = getService(link.targetId);
+    const target: LinkableArtifact | null = service ? await service.load(link.targetId) : null;
+    if (!service || !target) continue;
+
+    const mirror: ArtifactLink = { targetId: artifactId, type: getInverseType(link.type) };
+    const isAdd = nextKeys.has(linkKey(link));
+    const current = target.linkedArtifacts || [];
+
+    if (isAdd === hasEntry(target, mirror)) continue;
+
+    const linkedArtifacts = isAdd
+      ? [...current, mirror]
+      : current.filter((l) => !(l.targetId === mirror.targetId && l.type === mirror.type));
+    const updated = { ...target, linkedArtifacts, lastModified: Date.now() };
+    await service.save(updated);
+    touched.push(updated);
+  }
+
+  if (touched.length > 0) {
+    const ids = touched.map((a) => a.id).join(', ');
+    debug.log(`[LinkMirrorService] Mirrored links of ${artifactId} onto ${ids}`);
+  }
+  return touched;
+}
+
+/**
+ * Reconcile all one-sided embedded links by adding the missing inverse entries.
+ * Returns the links that were repaired.
+ */
+export async function repairOneSidedLinks(): Promise<OneSidedLink[]> {
+  const lists = await Promise.all(
+    MIRRORED_TYPES.map((typeKey) => ALL_ARTIFACT_SERVICES[typeKey].loadAll())
+  );
+  const artifacts: LinkableArtifact[] = lists.flat();
+  const byId = new Map(artifacts.map((a) => [a.id, a]));
+  const oneSided = findOneSidedLinks(artifacts);
+
+  // Group the missing entries per target so each file is written once
+  const missingByTarget = new Map<string, ArtifactLink[]>();
+  oneSided.forEach((link) => {
+    const entries = missingByTarget.get(link.targetId) || [];
+    const mirror = { targetId: link.sourceId, type: getInverseType(link.type) };
+    if (!entries.some((e) => e.targetId === mirror.targetId && e.type === mirror.type)) {
+      entries.push(mirror);
+    }
+    missingByTarget.set(link.targetId, entries);
+  });
+
+  for (const [targetId, entries] of missingByTarget) {
+    const target = byId.get(targetId)!;
+    await getService(targetId)!.save({
+      ...target,
+      linkedArtifacts: [...(target.linkedArtifacts || []), ...entries],
+      lastModified: Date.now(),
+    });
+  }
+
+  debug.log(`[LinkMirrorService] Repaired ${oneSided.length} one-sided link(s)`);
+  return oneSided;
+}
+
+export const linkMirrorService = {
+  findOneSidedLinks,
+  syncMirroredLinks,
+  repairOneSidedLinks,
+};
@@ -0,0 +1,303 @@
+/**
+ * Local API Service
+ *
+ * Optional read-only REST API for CI jobs and scripts. The Electron main
+ * process runs the HTTP server (localhost only, bearer token) and forwards
+ * each request here; responses are built from the same services the UI uses.
+ * Whether it runs and on which port are app settings of this machine, so a
+ * cloned project cannot turn it on; the token is 32 random bytes kept in the
+ * secret store.
+ *
+ * Endpoints (all GET):
+ *   /api/artifacts?type=requirements   Artifacts of one type
+ *   /api/artifacts/REQ-001             A single artifact
+ *   /api/links?artifactId=&sourceId=&targetId=&type=&projectId=
+ *   /api/trace-matrix?from=requirements&to=testcases&projectId=
+ *   /api/trace?from=SYS-004&relations=derives_to,verified_by&type=testcases&depth=3
+ *                                           Artifacts reached over several
+ *                                           link hops, with their paths
+ *   /api/search?q=login&type=               Artifacts matching all words
+ *   /api/coverage-gaps?projectId=           Untested requirements and
+ *                                           unlinked test cases
+ *   /api/queries                            Saved queries
+ *   /api/queries/<name>?projectId=          Artifacts matching a saved query
+ *   /api/info                               API version, endpoints and the
+ *                                           desktop app's capabilities
+ */
+
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { getApiInfo } from './apiInfoService';
+import { generateCoverageReport } from './coverageService';
+import { secretService } from './secretService';
+import { diskLinkService } from './diskLinkService';
+import { REFERENCE_SOURCE_TYPES } from './referenceValidationService';
+import { getSavedQueries, runSavedQuery } from './savedQueryService';
+import { generateTraceMatrix } from './traceMatrixService';
+import { runTraceQuery, validateTraceQuery, type TraceQuery } from './traceQueryService';
+import { appSettingsService, DEFAULT_APP_SETTINGS } from './appSettingsService';
+import { isElectronEnv } from './git/types';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { LINK_TYPE_LABELS, type LinkType } from '../utils/linkTypes';
+import { debug } from '../utils/debug';
+import type { LocalApiSettings } from '../types';
+
+const TOKEN_NAME = 'local-api';
+const TOKEN_BYTES = 32;
+const API_TYPES: readonly string[] = REFERENCE_SOURCE_TYPES;
+const MAX_SEARCH_RESULTS = 50;
+// Version of the REST endpoints; goes up when a response changes incompatibly
+const LOCAL_API_VERSION = 1;
+const ENDPOINTS = [
+  'artifacts',
+  'links',
+  'trace-matrix',
+  'trace',
+  'search',
+  'coverage-gaps',
+  'queries',
+  'info',
+];
+
+export type LocalApiConfig = LocalApiSettings;
+
+export const DEFAULT_LOCAL_API_CONFIG: LocalApiConfig = DEFAULT_APP_SETTINGS.localApi;
+
+export interface LocalApiRequest {
+  path: string;
+  query: Record<string, string>;
+}
+
+export interface LocalApiResponse {
+  status: number;
+  body: unknown;
+}
+
+export interface LocalApiSearchResult {
+  id: string;
+  type: string;
+  title: string;
+}
+
+interface SearchableArtifact {
+  id: string;
+  title?: string;
+  name?: string;
+  description?: string;
+  text?: string;
+}
+
+let stopListening: (() => void) | null = null;
+
+function notFound(message: string): LocalApiResponse {
+  return { status: 404, body: { error: message } };
+}
+
+function badRequest(message: string): LocalApiResponse {
+  return { status: 400, body: { error: message } };
+}
+
+async function getArtifacts(query: Record<string, string>): Promise<LocalApiResponse> {
+  if (!API_TYPES.includes(query.type)) {
+    return badRequest(`type must be one of: ${API_TYPES.join(', ')}`);
+  }
+  return { status: 200, body: await ALL_ARTIFACT_SERVICES[query.type].loadAll() };
+}
+
+async function getArtifact(id: string): Promise<LocalApiResponse> {
+  const typeKey = getTypeFromId(id);
+  const artifact = API_TYPES.includes(typeKey)
+    ? await ALL_ARTIFACT_SERVICES[typeKey].load(id)
+    : null;
+  return artifact ? { status: 200, body: artifact } : notFound(`Artifact not found: ${id}`);
+}
+
+async function getLinks(query: Record<string, string>): Promise<LocalApiResponse> {
+  if (query.type && !(query.type in LINK_TYPE_LABELS)) {
+    return badRequest(`Unknown link type: ${query.type}`);
+  }
+  const links = await diskLinkService.queryLinks({
+    artifactId: query.artifactId,
+    sourceId: query.sourceId,
+    targetId: query.targetId,
+    types: query.type ? [query.type as LinkType] : undefined,
+    projectId: query.projectId,
+  });
+  return { status: 200, body: links };
+}
+
+async function getTraceMatrix(query: Record<string, string>): Promise<LocalApiResponse> {
+  if (!API_TYPES.includes(query.from) || !API_TYPES.includes(query.to)) {
+    return badRequest(`from and to must be one of: ${API_TYPES.join(', ')}`);
+  }
+  return {
+    status: 200,
+    body: await generateTraceMatrix(query.from, query.to, query.projectId || undefined),
+  };
+}
+
+async function getTrace(query: Record<string, string>): Promise<LocalApiResponse> {
+  if (query.type && !API_TYPES.includes(query.type)) {
+    return badRequest(`type must be one of: ${API_TYPES.join(', ')}`);
+  }
+  const traceQuery: TraceQuery = {
+    startId: query.from,
+    relations: query.relations ? (query.relations.split(',') as LinkType[]) : undefined,
+    targetType: query.type || undefined,
+    maxDepth: query.depth ? Number(query.depth) : undefined,
+    projectId: query.projectId || undefined,
+  };
+  const problem = validateTraceQuery(traceQuery);
+  if (problem) return badRequest(problem);
+  return { status: 200, body: await runTraceQuery(traceQuery) };
+}
+
+async function search(query: Record<string, string>): Promise<LocalApiResponse> {
+  const words = (query.q || '').toLowerCase().split(/\s+/).filter(Boolean);
+  if (words.length === 0) return badRequest('q is required');
+  if (query.type && !API_TYPES.includes(query.type)) {
+    return badRequest(`type must be one of: ${API_TYPES.join(', ')}`);
+  }
+
+  const types = query.type ? [query.type] : API_TYPES;
+  const lists = await Promise.all(
+    types.map(async (type) => {
+      const artifacts: SearchableArtifact[] = await ALL_ARTIFACT_SERVICES[type].loadAll();
+      return artifacts.map((artifact) => ({ type, artifact }));
+    })
+  );
+  const results: LocalApiSearchResult[] = lists
+    .flat()
+    .filter(({ artifact }) => {
+      const { id, title, name, description, text } = artifact;
+      const haystack = [id, title, name, description, text].join('\n').toLowerCase();
+      return words.every((word) => haystack.includes(word));
+    })
+    .slice(0, MAX_SEARCH_RESULTS)
+    .map(({ type, artifact }) => ({
+      id: artifact.id,
+      type,
+      title: artifact.title ?? artifact.name ?? artifact.id,
+    }));
+  return { status: 200, body: results };
+}
+
+async function getCoverageGaps(query: Record<string, string>): Promise<LocalApiResponse> {
+  return { status: 200, body: await generateCoverageReport(query.projectId || undefined) };
+}
+
+async function getInfo(): Promise<LocalApiResponse> {
+  const app = await getApiInfo();
+  return {
+    status: 200,
+    body: {
+      apiVersion: LOCAL_API_VERSION,
+      endpoints: ENDPOINTS,
+      app: app && {
+        version: app.appVersion,
+        apiVersion: app.apiVersion,
+        capabilities: app.capabilities,
+      },
+    },
+  };
+}
+
+/**
+ * Answer one API request
+ */
+export async function handleApiRequest(request: LocalApiRequest): Promise<LocalApiResponse> {
+  const [root, resource, id, ...rest] = request.path.replace(/\/+$/, '').split('/').slice(1);
+  const { query } = request;
+
+  try {
+    if (root === 'api' && rest.length === 0) {
+      if (resource === 'artifacts') {
+        return await (id ? getArtifact(decodeURIComponent(id)) : getArtifacts(query));
+      }
+      if (resource === 'links' && !id) return await getLinks(query);
+      if (resource === 'trace-matrix' && !id) return await getTraceMatrix(query);
+      if (resource === 'trace' && !id) return await getTrace(query);
+      if (resource === 'search' && !id) return await search(query);
+      if (resource === 'coverage-gaps' && !id) return await getCoverageGaps(query);
+      if (resource === 'queries') {
+        return id
+          ? { status: 200, body: await runSavedQuery(decodeURIComponent(id), query.projectId) }
+          : { status: 200, body: await getSavedQueries() };
+      }
+      if (resource === 'info' && !id) return await getInfo();
+    }
+    return notFound(`Unknown endpoint: ${request.path}`);
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    return message.includes('not found')
+      ? notFound(message)
+      : { status: 500, body: { error: message } };
+  }
+}
+
+/**
+ * The local API settings of this machine
+ */
+export async function getLocalApiConfig(): Promise<LocalApiConfig> {
+  return (await appSettingsService.getAppSettings()).localApi;
+}
+
+/**
+ * Save the local API settings and start or stop the server accordingly
+ */
+export async function setLocalApiConfig(config: LocalApiConfig): Promise<void> {
+  await appSettingsService.updateAppSettings({ localApi: config });
+  await (config.enabled ? startLocalApi() : stopLocalApi());
+}
+
+/**
+ * The token clients must send as "Authorization: Bearer <token>", created
+ * on first use. Shorter tokens from older versions are replaced.
+ */
+export async function getLocalApiToken(): Promise<string> {
+  const token = await secretService.getSecret(TOKEN_NAME);
+  return token && token.length >= TOKEN_BYTES * 2 ? token : regenerateLocalApiToken();
+}
+
+/**
+ * Replace the token, invalidating the previous one
+ */
+export async function regenerateLocalApiToken(): Promise<string> {
+  const bytes = crypto.getRandomValues(new Uint8Array(TOKEN_BYTES));
+  const token = Array.from(bytes, (byte) => byte.toString(16).padStart(2, '0')).join('');
+  await secretService.storeSecret(TOKEN_NAME, token);
+  if (stopListening) await startLocalApi();
+  return token;
+}
+
+/**
+ * Start the server if it is enabled; only available in Electron
+ */
+export async function startLocalApi(): Promise<boolean> {
+  const config = await getLocalApiConfig();
+  if (!config.enabled || !isElectronEnv()) return false;
+
+  const api = window.electronAPI!.api;
+  const result = await api.start(config.port, await getLocalApiToken());
+  if (result.error) throw new Error(`Local API could not start: ${result.error}`);
+  stopListening?.();
+  stopListening = api.onRequest(handleApiRequest);
+  debug.log(`[LocalApiService] Listening on http://127.0.0.1:${config.port}`);
+  return true;
+}
+
+export async function stopLocalApi(): Promise<void> {
+  if (!isElectronEnv()) return;
+  stopListening?.();
+  stopListening = null;
+  await window.electronAPI!.api.stop();
+}
+
+export const localApiService = {
+  handleApiRequest,
+  getLocalApiConfig,
+  setLocalApiConfig,
+  getLocalApiToken,
+  regenerateLocalApiToken,
+  startLocalApi,
+  stopLocalApi,
+};
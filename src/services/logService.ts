@@ -0,0 +1,70 @@
+/**
+ * Log Service
+ *
+ * Access to the desktop app's log file. The main process appends warnings
+ * and errors from both processes (uncaught exceptions, console.warn/error in
+ * the renderer, crashed processes) to a size-rotated file in the app's log
+ * directory; this service adds explicit entries, reads the recent ones back
+ * and exports them for a bug report. The browser build keeps no log file.
+ */
+
+import { isElectronEnv } from './git/types';
+import { saveFile } from '../utils/fileSaveUtils';
+
+export type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+
+export interface LogEntry {
+  time: string; // ISO timestamp
+  level: LogLevel;
+  source: 'main' | 'renderer' | 'unknown';
+  message: string;
+}
+
+/**
+ * Append an entry to the log file; never throws, since failing to log must
+ * not break the caller
+ */
+export async function writeLog(level: LogLevel, message: string): Promise<void> {
+  if (!isElectronEnv()) return;
+  try {
+    const result = await window.electronAPI!.log.write(level, message);
+    if (result.error) console.warn('[LogService] Could not write log:', result.error);
+  } catch (err) {
+    console.warn('[LogService] Could not write log:', err);
+  }
+}
+
+/**
+ * The last `limit` log entries, oldest first; empty outside the desktop app
+ */
+export async function getRecentLogs(limit = 200): Promise<LogEntry[]> {
+  if (!isElectronEnv()) return [];
+  const result = await window.electronAPI!.log.getRecent(limit);
+  if (result.error) throw new Error(result.error);
+  return (result.entries ?? []) as LogEntry[];
+}
+
+export function getLogExportFileName(date = new Date()): string {
+  return `tracyfy-logs-${date.toISOString().slice(0, 10)}.log`;
+}
+
+/**
+ * Save all rotated log files, with app and platform details, as one file
+ */
+export async function exportLogs(): Promise<void> {
+  if (!isElectronEnv()) {
+    throw new Error('Log files are only kept by the desktop app');
+  }
+  const result = await window.electronAPI!.log.export();
+  if (result.error) throw new Error(result.error);
+  await saveFile(new Blob([result.content ?? ''], { type: 'text/plain' }), getLogExportFileName(), {
+    description: 'Log file',
+    accept: { 'text/plain': ['.log'] },
+  });
+}
+
+export const logService = {
+  writeLog,
+  getRecentLogs,
+  exportLogs,
+};
@@ -0,0 +1,193 @@
+/**
+ * Markdown Import Service
+ *
+ * Migrates a plain folder of markdown files (the opened directory) into a
+ * project: files are classified heuristically, given IDs and frontmatter,
+ * moved into the standard artifact folders and committed in one import
+ * commit. An import interrupted by a crash is rolled back on the next start.
+ * The returned report lists what happened to every file.
+ */
+
+import type { Project } from '../types';
+import { baseDiskService } from './baseDiskService';
+import { ALL_ARTIFACT_SERVICES, projectService } from './artifactServices';
+import { diskProjectService } from './diskProjectService';
+import { idService } from './idService';
+import { runJournaled } from './writeJournalService';
+import { QUARANTINE_FOLDER } from './orphanFileService';
+import { getStatusWorkflow } from './statusWorkflowService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { classifyMarkdown, splitTitle, type ImportTypeKey } from '../utils/markdownImportUtils';
+import { debug } from '../utils/debug';
+
+// Folders and files the app manages itself
+const SKIPPED_FOLDERS = new Set([
+  ...Object.values(ARTIFACT_CONFIG).map((config) => config.folder),
+  'config',
+  'counters',
+  'testruns',
+  'node_modules',
+  QUARANTINE_FOLDER,
+]);
+const SKIPPED_FILES = new Set(['current-project.md', 'current-user.md']);
+
+// Project fields holding the artifact IDs of each imported type
+export const PROJECT_ID_FIELDS: Record<
+  ImportTypeKey,
+  'requirementIds' | 'useCaseIds' | 'testCaseIds' | 'informationIds' | 'riskIds'
+> = {
+  requirements: 'requirementIds',
+  usecases: 'useCaseIds',
+  testcases: 'testCaseIds',
+  information: 'informationIds',
+  risks: 'riskIds',
+};
+
+export interface ImportedFile {
+  sourcePath: string;
+  id: string;
+  typeKey: ImportTypeKey;
+  title: string;
+}
+
+export interface SkippedFile {
+  path: string;
+  reason: string;
+}
+
+export interface MarkdownImportReport {
+  project: Project;
+  imported: ImportedFile[];
+  skipped: SkippedFile[];
+  counts: Record<ImportTypeKey, number>;
+}
+
+/**
+ * File content of a markdown file imported as an artifact: its frontmatter
+ * fields are kept, with a new ID, title and description split off the text,
+ * the given status and revision 01
+ */
+export function buildImportedArtifact(
+  typeKey: ImportTypeKey,
+  path: string,
+  text: string,
+  fields: { id: string; status: string; now: number }
+): { title: string; content: string } {
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const { title, description } = splitTitle(path, text);
+  const artifact = {
+    ...service.deserialize(text),
+    id: fields.id,
+    title,
+    description,
+    status: fields.status,
+    dateCreated: fields.now,
+    lastModified: fields.now,
+    revision: '01',
+  };
+  return { title, content: service.serialize(artifact) };
+}
+
+/**
+ * Markdown files below a folder, skipping hidden and app-managed folders
+ */
+export async function collectMarkdownFiles(folder: string = ''): Promise<string[]> {
+  const [entries, files] = await Promise.all([
+    baseDiskService.listEntries(folder),
+    baseDiskService.listFiles(folder),
+  ]);
+  const prefix = folder ? `${folder}/` : '';
+  const fileNames = new Set(files);
+
+  const found = files
+    .filter((name) => name.toLowerCase().endsWith('.md') && !name.startsWith('.'))
+    .filter((name) => folder || !SKIPPED_FILES.has(name))
+    .map((name) => `${prefix}${name}`);
+
+  for (const name of entries) {
+    if (fileNames.has(name) || name.startsWith('.')) continue;
+    if (!folder && SKIPPED_FOLDERS.has(name)) continue;
+    found.push(...(await collectMarkdownFiles(`${prefix}${name}`)));
+  }
+
+  return found.sort();
+}
+
+/**
+ * Import every markdown file of the opened directory into a new project
+ */
+export async function importMarkdownFolder(projectName: string): Promise<MarkdownImportReport> {
+  const sourcePaths = await collectMarkdownFiles();
+  const skipped: SkippedFile[] = [];
+  const byType = new Map<ImportTypeKey, { path: string; content: string }[]>();
+
+  for (const path of sourcePaths) {
+    const content = await baseDiskService.readTextFile(path);
+    if (!content.trim()) {
+      skipped.push({ path, reason: 'empty file' });
+      continue;
+    }
+    const typeKey = classifyMarkdown(path, content);
+    byType.set(typeKey, [...(byType.get(typeKey) || []), { path, content }]);
+  }
+
+  await diskProjectService.initialize();
+  const project = await diskProjectService.createProject(
+    projectName,
+    `Imported from ${sourcePaths.length} markdown files`
+  );
+  const { initialStatus } = await getStatusWorkflow();
+  const now = Date.now();
+  const imported: ImportedFile[] = [];
+  const fileCount = [...byType.values()].reduce((sum, files) => sum + files.length, 0);
+
+  // Journaled so a crash midway rolls the moved files back on the next start
+  await runJournaled(
+    'Import markdown',
+    async (journal) => {
+      for (const [typeKey, files] of byType) {
+        await journal.record(idService.getCounterPath(typeKey));
+        const ids = await idService.getNextIds(typeKey, files.length, true);
+
+        for (const [i, file] of files.entries()) {
+          const { title, content } = buildImportedArtifact(typeKey, file.path, file.content, {
+            id: ids[i],
+            status: initialStatus,
+            now,
+          });
+          const target = `${ARTIFACT_CONFIG[typeKey].folder}/${ids[i]}.md`;
+          await journal.record(target);
+          await baseDiskService.writeTextFile(target, content);
+          await journal.record(file.path);
+          await baseDiskService.deleteFile(file.path);
+          imported.push({ sourcePath: file.path, id: ids[i], typeKey, title });
+        }
+
+        project[PROJECT_ID_FIELDS[typeKey]] = ids;
+      }
+
+      project.lastModified = now;
+      await journal.record(`${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`);
+      await projectService.save(project);
+    },
+    `Import ${fileCount} markdown files as project ${projectName}`
+  );
+
+  const counts: Record<ImportTypeKey, number> = {
+    requirements: 0,
+    usecases: 0,
+    testcases: 0,
+    information: 0,
+    risks: 0,
+  };
+  imported.forEach((file) => counts[file.typeKey]++);
+
+  debug.log(`[MarkdownImportService] Imported ${imported.length} files, skipped ${skipped.length}`);
+
+  return { project, imported, skipped, counts };
+}
+
+export const markdownImportService = {
+  collectMarkdownFiles,
+  importMarkdownFolder,
+};
@@ -0,0 +1,366 @@
+/**
+ * Migration Service
+ *
+ * Versioned on-disk format. The project records its format version in
+ * config/format.json; opening a project with an older version runs the
+ * registered migrations in order, each a list of declarative steps (folder
+ * renames, frontmatter field renames, value mappings). Migrations are first
+ * planned against a virtual view of the files, which doubles as the dry-run
+ * report, and then applied under the write journal and committed together.
+ *
+ * The frontmatter steps can also be run on their own, for schema changes that
+ * belong to one repository rather than to the app, such as a renamed custom
+ * field or a status that was split in two.
+ */
+
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { baseDiskService } from './baseDiskService';
+import { projectConfigService } from './projectConfigService';
+import { runJournaled, type WriteJournal } from './writeJournalService';
+import { splitFrontmatter } from '../utils/lineDiffUtils';
+import { debug } from '../utils/debug';
+
+export const FORMAT_SECTION = 'format';
+const FORMAT_PATH = `config/${FORMAT_SECTION}.json`;
+
+// Format of the projects this version of the app writes
+export const PROJECT_FORMAT_VERSION = 1;
+
+export type MigrationStep =
+  | { kind: 'rename-folder'; from: string; to: string }
+  | { kind: 'rename-field'; folder: string; from: string; to: string }
+  | { kind: 'map-values'; folder: string; field: string; values: Record<string, string> };
+
+// Steps that only rewrite frontmatter, in place
+export type FrontmatterMigrationStep = Exclude<MigrationStep, { kind: 'rename-folder' }>;
+
+export interface Migration {
+  version: number; // Format version the migration produces
+  description: string;
+  steps: MigrationStep[];
+}
+
+/**
+ * Migrations from older formats, in any order. Add one (and raise
+ * PROJECT_FORMAT_VERSION) with every change to the on-disk layout.
+ */
+export const MIGRATIONS: Migration[] = [];
+
+export interface MigrationChange {
+  path: string;
+  action: 'move' | 'update';
+  newPath?: string; // Target of a move
+  detail: string;
+  diff?: string[]; // Changed frontmatter lines of an update, "- old" before "+ new"
+}
+
+export interface MigrationReport {
+  fromVersion: number;
+  toVersion: number;
+  tooNew: boolean; // Written by a newer version of the app; left untouched
+  migrations: { version: number; description: string; changes: MigrationChange[] }[];
+}
+
+/**
+ * Format version of the open project; projects from before versioning are 1
+ */
+export async function getFormatVersion(): Promise<number> {
+  const { version } = await projectConfigService.getSection(FORMAT_SECTION, { version: 1 });
+  return version;
+}
+
+const FIELD_NAME_PATTERN = /^[A-Za-z_][\w-]*$/;
+
+// Planned state of a path: new text, the unchanged bytes of a file moved
+// from elsewhere, or null once deleted
+type PlannedFile = { content: string } | { source: string } | null;
+
+// Files as they will be after the planned changes, read lazily from disk
+class VirtualFiles {
+  private overlay = new Map<string, PlannedFile>();
+
+  async list(folder: string): Promise<string[]> {
+    const names = new Set(await baseDiskService.listFiles(folder));
+    for (const [path, planned] of this.overlay) {
+      const slash = path.lastIndexOf('/');
+      if (path.slice(0, slash) !== folder) continue;
+      if (planned === null) names.delete(path.slice(slash + 1));
+      else names.add(path.slice(slash + 1));
+    }
+    return [...names].sort();
+  }
+
+  async read(path: string): Promise<string> {
+    const planned = this.get(path);
+    if (planned === null) return '';
+    return 'content' in planned ? planned.content : baseDiskService.readTextFile(planned.source);
+  }
+
+  get(path: string): Exclude<PlannedFile, null> | null {
+    const planned = this.overlay.get(path);
+    return planned === undefined ? { source: path } : planned;
+  }
+
+  set(path: string, planned: PlannedFile): void {
+    this.overlay.set(path, planned);
+  }
+
+  // Changed paths with their final state; writes before deletes, so moved
+  // files are copied before their source goes
+  entries(): [string, PlannedFile][] {
+    const deleted = ([, planned]: [string, PlannedFile]) => Number(planned === null);
+    return [...this.overlay.entries()].sort((a, b) => deleted(a) - deleted(b));
+  }
+}
+
+// Rename a top-level frontmatter key; null when the file has no such key
+function renameField(content: string, from: string, to: string): string | null {
+  const { frontmatter, body } = splitFrontmatter(content);
+  const lines = frontmatter.split('\n');
+  const index = lines.findIndex((line) => line.startsWith(`${from}:`));
+  if (!frontmatter || index === -1 || lines.some((line) => line.startsWith(`${to}:`))) {
+    return null;
+  }
+  lines[index] = `${to}:${lines[index].slice(from.length + 1)}`;
+  return ['---', ...lines, '---', body].join('\n');
+}
+
+function unquote(value: string): string {
+  if (/^"(?:[^"\\]|\\.)*"$/.test(value)) return value.slice(1, -1).replace(/\\"/g, '"');
+  if (/^'.*'$/.test(value)) return value.slice(1, -1).replace(/''/g, "'");
+  return value;
+}
+
+// Replace the values of a top-level frontmatter key, a scalar or a list of
+// scalars; null when no value is mapped
+function mapValues(content: string, field: string, values: Record<string, string>): string | null {
+  const { frontmatter, body } = splitFrontmatter(content);
+  const lines = frontmatter.split('\n');
+  const index = lines.findIndex((line) => line.startsWith(`${field}:`));
+  if (!frontmatter || index === -1) return null;
+
+  const mapped = (raw: string): string | null => {
+    const value = unquote(raw.trim());
+    return Object.prototype.hasOwnProperty.call(values, value)
+      ? `"${values[value].replace(/"/g, '\\"')}"`
+      : null;
+  };
+  let changed = false;
+  const scalar = lines[index].slice(field.length + 1);
+  if (scalar.trim()) {
+    const replacement = mapped(scalar);
+    if (replacement !== null) {
+      lines[index] = `${field}: ${replacement}`;
+      changed = true;
+    }
+  } else {
+    for (let i = index + 1; i < lines.length && /^\s+- /.test(lines[i]); i++) {
+      const [, indent, item] = lines[i].match(/^(\s+- )(.*)$/)!;
+      const replacement = mapped(item);
+      if (replacement === null) continue;
+      lines[i] = `${indent}${replacement}`;
+      changed = true;
+    }
+  }
+  return changed ? ['---', ...lines, '---', body].join('\n') : null;
+}
+
+// Frontmatter lines that differ; field steps rewrite lines in place
+function frontmatterDiff(before: string, after: string): string[] {
+  const oldLines = splitFrontmatter(before).frontmatter.split('\n');
+  const newLines = splitFrontmatter(after).frontmatter.split('\n');
+  return oldLines.flatMap((line, i) =>
+    line === newLines[i] ? [] : [`- ${line}`, `+ ${newLines[i]}`]
+  );
+}
+
+async function planStep(files: VirtualFiles, step: MigrationStep): Promise<MigrationChange[]> {
+  const changes: MigrationChange[] = [];
+  if (step.kind === 'rename-folder') {
+    for (const name of await files.list(step.from)) {
+      const path = `${step.from}/${name}`;
+      const newPath = `${step.to}/${name}`;
+      files.set(newPath, files.get(path));
+      files.set(path, null);
+      changes.push({ path, action: 'move', newPath, detail: `Move to ${step.to}/` });
+    }
+    return changes;
+  }
+
+  for (const name of await files.list(step.folder)) {
+    if (!name.endsWith('.md')) continue;
+    const path = `${step.folder}/${name}`;
+    const content = await files.read(path);
+    const updated =
+      step.kind === 'rename-field'
+        ? renameField(content, step.from, step.to)
+        : mapValues(content, step.field, step.values);
+    if (updated === null) continue;
+    files.set(path, { content: updated });
+    changes.push({
+      path,
+      action: 'update',
+      detail:
+        step.kind === 'rename-field'
+          ? `Rename field ${step.from} to ${step.to}`
+          : `Map values of ${step.field}`,
+      diff: frontmatterDiff(content, updated),
+    });
+  }
+  return changes;
+}
+
+async function writePlannedFiles(files: VirtualFiles, journal: WriteJournal): Promise<void> {
+  for (const [path, planned] of files.entries()) {
+    await journal.record(path);
+    if (planned === null) {
+      await baseDiskService.deleteFile(path);
+    } else if ('content' in planned) {
+      await baseDiskService.writeTextFile(path, planned.content);
+    } else if (planned.source !== path) {
+      const bytes = await baseDiskService.readBinaryFile(planned.source);
+      if (bytes) await baseDiskService.writeBinaryFile(path, bytes);
+    }
+  }
+}
+
+/**
+ * Bring the open project to the current format. With dryRun the report of
+ * what would change is returned without touching any file.
+ */
+export async function migrateProject(
+  options: { dryRun?: boolean; migrations?: Migration[]; targetVersion?: number } = {}
+): Promise<MigrationReport> {
+  const { dryRun = false, migrations = MIGRATIONS, targetVersion = PROJECT_FORMAT_VERSION } =
+    options;
+  const fromVersion = await getFormatVersion();
+  const report: MigrationReport = {
+    fromVersion,
+    toVersion: targetVersion,
+    tooNew: fromVersion > targetVersion,
+    migrations: [],
+  };
+
+  const pending = migrations
+    .filter((m) => m.version > fromVersion && m.version <= targetVersion)
+    .sort((a, b) => a.version - b.version);
+  const files = new VirtualFiles();
+  for (const migration of pending) {
+    const changes: MigrationChange[] = [];
+    for (const step of migration.steps) {
+      changes.push(...(await planStep(files, step)));
+    }
+    const { version, description } = migration;
+    report.migrations.push({ version, description, changes });
+  }
+  if (dryRun || fromVersion >= targetVersion) return report;
+
+  await runJournaled(
+    'Migrate project format',
+    async (journal) => {
+      await writePlannedFiles(files, journal);
+      await journal.record(FORMAT_PATH);
+      await baseDiskService.writeJsonFile(FORMAT_PATH, { version: targetVersion });
+    },
+    `Migrate project format from version ${fromVersion} to ${targetVersion}`
+  );
+  debug.info(`[MigrationService] Migrated project format ${fromVersion} -> ${targetVersion}`);
+  return report;
+}
+
+/**
+ * Problems with frontmatter migration steps; empty when they can run
+ */
+export function validateFrontmatterSteps(steps: FrontmatterMigrationStep[]): string[] {
+  const folders = Object.values(ARTIFACT_CONFIG).map((config) => config.folder);
+  const errors: string[] = [];
+  steps.forEach((step, i) => {
+    const label = `Step ${i + 1}`;
+    if (!folders.includes(step.folder)) errors.push(`${label}: unknown folder ${step.folder}`);
+    const fields = step.kind === 'rename-field' ? [step.from, step.to] : [step.field];
+    if (fields.some((field) => !FIELD_NAME_PATTERN.test(field))) {
+      errors.push(`${label}: invalid field name ${fields.join(', ')}`);
+    }
+    if (step.kind === 'rename-field' && step.from === step.to) {
+      errors.push(`${label}: ${step.from} is renamed to itself`);
+    }
+    if (step.kind === 'map-values' && Object.keys(step.values).length === 0) {
+      errors.push(`${label}: no values to map`);
+    }
+  });
+  return errors;
+}
+
+/**
+ * Rewrite frontmatter across artifact files with the given steps, in order,
+ * without changing the format version. With dryRun the planned changes and
+ * their changed lines are returned without touching any file; otherwise all
+ * files are written under the write journal as one commit.
+ */
+export async function migrateFrontmatter(
+  steps: FrontmatterMigrationStep[],
+  options: { dryRun?: boolean; description?: string } = {}
+): Promise<MigrationChange[]> {
+  const { dryRun = false, description } = options;
+  const errors = validateFrontmatterSteps(steps);
+  if (errors.length > 0) throw new Error(`Invalid migration: ${errors.join('; ')}`);
+
+  const files = new VirtualFiles();
+  const changes: MigrationChange[] = [];
+  for (const step of steps) {
+    changes.push(...(await planStep(files, step)));
+  }
+  if (dryRun || changes.length === 0) return changes;
+
+  const fileCount = new Set(changes.map((change) => change.path)).size;
+  await runJournaled(
+    'Migrate frontmatter',
+    (journal) => writePlannedFiles(files, journal),
+    `Migrate frontmatter${description ? `: ${description}` : ''} (${fileCount} file(s))`
+  );
+  debug.info(`[MigrationService] Migrated frontmatter of ${fileCount} file(s)`);
+  return changes;
+}
+
+function formatChange(change: MigrationChange): string[] {
+  return [
+    `  ${change.path}: ${change.detail}`,
+    ...(change.diff ?? []).map((line) => `    ${line}`),
+  ];
+}
+
+/**
+ * Plain-text listing of planned frontmatter changes, for review of a dry run
+ */
+export function formatMigrationChanges(changes: MigrationChange[]): string {
+  if (changes.length === 0) return 'No files change';
+  return changes.flatMap(formatChange).join('\n');
+}
+
+/**
+ * Plain-text summary of a report, for confirmation before migrating
+ */
+export function formatMigrationReport(report: MigrationReport): string {
+  if (report.tooNew) {
+    return (
+      `This project uses format version ${report.fromVersion}, but this version of ` +
+      `Tracyfy only supports up to ${report.toVersion}. Update Tracyfy to open it safely.`
+    );
+  }
+  const lines = [`Project format ${report.fromVersion} → ${report.toVersion}:`];
+  for (const migration of report.migrations) {
+    lines.push('', `${migration.version}. ${migration.description}`);
+    lines.push(...migration.changes.flatMap(formatChange));
+    if (migration.changes.length === 0) lines.push('  No files change');
+  }
+  return lines.join('\n');
+}
+
+export const migrationService = {
+  getFormatVersion,
+  migrateProject,
+  validateFrontmatterSteps,
+  migrateFrontmatter,
+  formatMigrationChanges,
+  formatMigrationReport,
+};
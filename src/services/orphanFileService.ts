@@ -0,0 +1,209 @@
+/**
+ * Orphan File Service
+ *
+ * Finds files dropped into artifact folders from outside the app (wrong
+ * name, not markdown, or no frontmatter) so they don't end up as broken
+ * entries in listings. Files the app keeps next to artifacts, such as the
+ * manual reading order, are not orphans. Such files can be adopted as artifacts, ignored, or
+ * moved to the quarantine folder.
+ */
+
+import { baseDiskService } from './baseDiskService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { ORDER_FILE } from './artifactOrderService';
+import { idService } from './idService';
+import { projectConfigService } from './projectConfigService';
+import { realGitService } from './realGitService';
+import { REFERENCE_SOURCE_TYPES } from './referenceValidationService';
+import { WORKFLOW_TYPES } from './statusWorkflowService';
+import { ARTIFACT_CONFIG, PREFIX_TO_TYPE } from '../constants/artifactConfig';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+import { mapWithConcurrency } from '../utils/concurrencyUtils';
+import { splitTitle } from '../utils/markdownImportUtils';
+import { debug } from '../utils/debug';
+
+const ORPHAN_SECTION = 'orphan-files';
+export const QUARANTINE_FOLDER = 'quarantine';
+
+// Artifact folders that are scanned for orphan files. Project files are
+// named by their proj-<timestamp> ID, so they don't follow the convention.
+export const SCANNED_TYPES = [
+  ...REFERENCE_SOURCE_TYPES.filter((typeKey) => typeKey !== 'projects'),
+  'links',
+];
+
+// Types whose orphan files can be turned into artifacts
+export const ADOPTABLE_TYPES = WORKFLOW_TYPES;
+
+export type OrphanReason = 'not-markdown' | 'misnamed' | 'no-frontmatter';
+
+export interface ArtifactFile {
+  path: string;
+  content: string; // Empty for non-markdown files
+}
+
+export interface OrphanFile {
+  path: string;
+  typeKey: string; // Artifact type of the folder the file is in
+  reason: OrphanReason;
+}
+
+interface OrphanFilesConfig {
+  ignored: string[];
+}
+
+function typeKeyOfPath(path: string): string | undefined {
+  const folder = path.slice(0, path.indexOf('/'));
+  return SCANNED_TYPES.find((key) => ARTIFACT_CONFIG[key].folder === folder);
+}
+
+/**
+ * Orphan files among the given artifact folder files, skipping ignored paths
+ */
+export function findOrphanFiles(files: ArtifactFile[], ignored: string[] = []): OrphanFile[] {
+  const skip = new Set(ignored);
+
+  return files.flatMap((file): OrphanFile[] => {
+    const typeKey = typeKeyOfPath(file.path);
+    const name = file.path.slice(file.path.lastIndexOf('/') + 1);
+    if (!typeKey || skip.has(file.path) || name.startsWith('.') || name === ORDER_FILE) return [];
+
+    const prefix = ARTIFACT_CONFIG[typeKey].idPrefix;
+    const stem = name.replace(/\.md$/, '');
+    const { id } = parseYamlFrontmatter(file.content).frontmatter;
+    // Renamed artifacts may use another prefix, as long as it is not another type's
+    const renamed = id === stem && !Object.hasOwn(PREFIX_TO_TYPE, stem.split('-')[0]);
+    let reason: OrphanReason | null = null;
+    if (!name.endsWith('.md')) {
+      reason = 'not-markdown';
+    } else if (!new RegExp(`^${prefix}-\\d+$`).test(stem) && !renamed) {
+      reason = 'misnamed';
+    } else if (!id) {
+      reason = 'no-frontmatter';
+    }
+    return reason ? [{ path: file.path, typeKey, reason }] : [];
+  });
+}
+
+/**
+ * Read every file in the scanned artifact folders
+ */
+export async function loadArtifactFiles(): Promise<ArtifactFile[]> {
+  const paths: string[] = [];
+  for (const typeKey of SCANNED_TYPES) {
+    const folder = ARTIFACT_CONFIG[typeKey].folder;
+    for (const name of await baseDiskService.listFiles(folder)) {
+      paths.push(`${folder}/${name}`);
+    }
+  }
+  return mapWithConcurrency(paths, async (path) => ({
+    path,
+    content: path.endsWith('.md') ? await baseDiskService.readTextFile(path) : '',
+  }));
+}
+
+/**
+ * Paths the user chose to ignore
+ */
+export async function getIgnoredFiles(): Promise<string[]> {
+  const config = await projectConfigService.getSection<OrphanFilesConfig>(ORPHAN_SECTION, {
+    ignored: [],
+  });
+  return Array.isArray(config.ignored) ? config.ignored : [];
+}
+
+/**
+ * Scan the artifact folders for orphan files
+ */
+export async function scanOrphanFiles(): Promise<OrphanFile[]> {
+  const [files, ignored] = await Promise.all([loadArtifactFiles(), getIgnoredFiles()]);
+  return findOrphanFiles(files, ignored);
+}
+
+/**
+ * Stop reporting a file as orphan
+ */
+export async function ignoreOrphanFile(path: string): Promise<void> {
+  const ignored = await getIgnoredFiles();
+  if (ignored.includes(path)) return;
+  await projectConfigService.setSection<OrphanFilesConfig>(
+    ORPHAN_SECTION,
+    { ignored: [...ignored, path].sort() },
+    `Ignore orphan file ${path}`
+  );
+}
+
+/**
+ * Move a file to the quarantine folder, keeping its relative path
+ */
+export async function quarantineOrphanFile(path: string): Promise<string> {
+  const content = await baseDiskService.readBinaryFile(path);
+  if (content === null) {
+    throw new Error(`File not found: ${path}`);
+  }
+
+  const target = `${QUARANTINE_FOLDER}/${path}`;
+  await baseDiskService.ensureDirectory(target.slice(0, target.lastIndexOf('/')));
+  await baseDiskService.writeBinaryFile(target, content);
+  await baseDiskService.deleteFile(path);
+  await realGitService.commitFiles([path, target], `Quarantine ${path}`);
+  debug.log(`[OrphanFileService] Moved ${path} to ${target}`);
+  return target;
+}
+
+/**
+ * Turn a markdown file into an artifact of its folder's type: files without
+ * a valid name get a new ID, the title comes from the first heading (or the
+ * file name) and the body becomes the description. Returns the new ID.
+ */
+export async function adoptOrphanFile(path: string): Promise<string> {
+  const typeKey = typeKeyOfPath(path);
+  if (!typeKey || !ADOPTABLE_TYPES.includes(typeKey)) {
+    throw new Error(`Files in this folder cannot be adopted: ${path}`);
+  }
+  if (!path.endsWith('.md')) {
+    throw new Error(`Only markdown files can be adopted: ${path}`);
+  }
+
+  const content = await baseDiskService.readTextFile(path);
+  const orphan = findOrphanFiles([{ path, content }])[0];
+  if (!orphan) {
+    throw new Error(`Not an orphan file: ${path}`);
+  }
+
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const config = ARTIFACT_CONFIG[typeKey];
+  const name = path.slice(path.lastIndexOf('/') + 1, -'.md'.length);
+  const id =
+    orphan.reason === 'misnamed' ? (await idService.getNextIds(typeKey, 1, true))[0] : name;
+
+  const now = Date.now();
+  const artifact = {
+    ...service.deserialize(content),
+    id,
+    ...splitTitle(path, content),
+    dateCreated: now,
+    lastModified: now,
+  };
+
+  const target = `${config.folder}/${id}.md`;
+  await baseDiskService.writeTextFile(target, service.serialize(artifact));
+  const paths = [target];
+  if (target !== path) {
+    await baseDiskService.deleteFile(path);
+    paths.push(path, idService.getCounterPath(typeKey));
+  }
+  await realGitService.commitFiles(paths, `Adopt ${path} as ${id}`);
+  debug.log(`[OrphanFileService] Adopted ${path} as ${id}`);
+  return id;
+}
+
+export const orphanFileService = {
+  findOrphanFiles,
+  loadArtifactFiles,
+  getIgnoredFiles,
+  scanOrphanFiles,
+  ignoreOrphanFile,
+  quarantineOrphanFile,
+  adoptOrphanFile,
+};
@@ -0,0 +1,77 @@
+/**
+ * Parse Cache Service
+ *
+ * Keeps parsed artifact files keyed by path, together with the modification
+ * time and size of the file they were parsed from, so listing a folder only
+ * re-parses the frontmatter of files that changed since the last listing.
+ * Entries are dropped when the file watcher reports their path, and the whole
+ * cache when another project folder is opened or the encryption key changes.
+ */
+
+import { fileSystemService, type FileStat } from './fileSystemService';
+
+interface CacheEntry {
+  stat: FileStat;
+  value: unknown;
+}
+
+const entries = new Map<string, CacheEntry>();
+let cachedRoot: string | null = null;
+
+// Paths are project-relative, so start over when another project folder is open
+function checkRoot(): void {
+  const root = fileSystemService.getRootPath() ?? fileSystemService.getDirectoryName();
+  if (root !== cachedRoot) {
+    entries.clear();
+    cachedRoot = root;
+  }
+}
+
+/**
+ * The value parsed from the file at path, if it was parsed from a file with
+ * this modification time and size
+ */
+export function getCachedParse<T>(path: string, stat: FileStat): T | undefined {
+  checkRoot();
+  const entry = entries.get(path);
+  if (!entry || entry.stat.mtime !== stat.mtime || entry.stat.size !== stat.size) {
+    return undefined;
+  }
+  return entry.value as T;
+}
+
+export function setCachedParse<T>(path: string, stat: FileStat, value: T): void {
+  checkRoot();
+  entries.set(path, { stat, value });
+}
+
+/**
+ * Drop the entries of the given paths, or all entries
+ */
+export function invalidateParseCache(paths?: string[]): void {
+  if (!paths) {
+    entries.clear();
+    return;
+  }
+  for (const path of paths) entries.delete(path);
+}
+
+/**
+ * Start dropping entries the file watcher reports as changed, with an empty
+ * cache. Returns the function that stops it and clears the cache.
+ */
+export function startParseCacheTracking(): () => void {
+  entries.clear();
+  const unsubscribe = fileSystemService.onFilesChanged((paths) => invalidateParseCache(paths));
+  return () => {
+    unsubscribe();
+    entries.clear();
+  };
+}
+
+export const parseCacheService = {
+  getCachedParse,
+  setCachedParse,
+  invalidateParseCache,
+  startParseCacheTracking,
+};
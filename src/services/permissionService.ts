@@ -0,0 +1,186 @@
+/**
+ * Permission Service
+ *
+ * Project-level roles (author, reviewer, approver, admin) assigned to
+ * identities, i.e. the email or name of an author profile or a user's name
+ * or ID. Assignments are kept per project in config/roles.json and versioned
+ * with the repository. A project without any assignments is open to
+ * everyone; once roles are assigned, editing artifacts, commenting,
+ * approving and creating or deleting baselines check the roles of the acting
+ * identity and fail with a PermissionError. Admins may take every action.
+ *
+ * Roles are advisory. The acting identity is whatever author profile and
+ * user are selected on this machine, which anyone can retype, and the files
+ * can be edited outside the app. Roles keep honest users from taking
+ * actions by mistake; they are not access control. Use the git host's
+ * branch protection and signed approvals for that.
+ */
+
+import type { User } from '../types';
+import { userService } from './artifactServices';
+import { getActiveAuthorProfile } from './authorProfileService';
+import { diskProjectService } from './diskProjectService';
+import { projectConfigService } from './projectConfigService';
+import { debug } from '../utils/debug';
+
+export const PROJECT_ROLES = ['author', 'reviewer', 'approver', 'admin'] as const;
+export type ProjectRole = (typeof PROJECT_ROLES)[number];
+
+export type PermissionAction = 'edit' | 'comment' | 'approve' | 'baseline' | 'manage-roles';
+
+// Roles that may take each action
+export const ACTION_ROLES: Record<PermissionAction, ProjectRole[]> = {
+  edit: ['author', 'admin'],
+  comment: ['author', 'reviewer', 'approver', 'admin'],
+  approve: ['approver', 'admin'],
+  baseline: ['approver', 'admin'],
+  'manage-roles': ['admin'],
+};
+
+const ROLES_SECTION = 'roles';
+
+export type RoleAssignments = Record<string, ProjectRole[]>; // Roles per identity
+
+export interface RoleConfig {
+  projects: Record<string, RoleAssignments>; // Assignments per project ID
+}
+
+export const DEFAULT_ROLE_CONFIG: RoleConfig = { projects: {} };
+
+/**
+ * Thrown when the acting identity lacks a role an action requires
+ */
+export class PermissionError extends Error {
+  action: PermissionAction;
+  projectId: string;
+  identity: string;
+  requiredRoles: ProjectRole[];
+
+  constructor(action: PermissionAction, projectId: string, identity: string) {
+    const requiredRoles = ACTION_ROLES[action];
+    super(
+      `${identity || 'The current user'} may not ${action.replace('-', ' ')} in ${projectId}: ` +
+        `requires the role ${requiredRoles.join(' or ')}`
+    );
+    this.name = 'PermissionError';
+    this.action = action;
+    this.projectId = projectId;
+    this.identity = identity;
+    this.requiredRoles = requiredRoles;
+  }
+}
+
+function normalize(identity: string): string {
+  return identity.trim().toLowerCase();
+}
+
+/**
+ * Problems with role assignments; empty when they can be saved. Assignments
+ * that are not empty need an admin, so the roles can still be managed.
+ */
+export function validateRoleAssignments(assignments: RoleAssignments): string[] {
+  const errors: string[] = [];
+  for (const [identity, roles] of Object.entries(assignments)) {
+    if (!identity.trim()) errors.push('Roles must be assigned to a named identity');
+    for (const role of roles) {
+      if (!PROJECT_ROLES.includes(role)) errors.push(`Unknown role "${role}" for ${identity}`);
+    }
+  }
+  const assigned = Object.values(assignments).some((roles) => roles.length > 0);
+  if (assigned && !Object.values(assignments).some((roles) => roles.includes('admin'))) {
+    errors.push('At least one identity needs the admin role');
+  }
+  return errors;
+}
+
+export async function getRoleConfig(): Promise<RoleConfig> {
+  return projectConfigService.getSection(ROLES_SECTION, DEFAULT_ROLE_CONFIG);
+}
+
+/**
+ * The identities the acting person is known by: the active author
+ * profile's email and name, and the current user's name and ID. All of them
+ * are chosen or typed in locally, so they only say who the person claims to be.
+ */
+export async function getCurrentIdentities(): Promise<string[]> {
+  const identities: string[] = [];
+  const profile = await getActiveAuthorProfile();
+  if (profile) identities.push(profile.email, profile.name);
+  const userId = await diskProjectService.getCurrentUserId();
+  if (userId) {
+    const user: User | null = await userService.load(userId);
+    if (user?.name) identities.push(user.name);
+    identities.push(userId);
+  }
+  return [...new Set(identities.filter(Boolean))];
+}
+
+/**
+ * Roles the given identities hold in a project
+ */
+export function getRolesOf(assignments: RoleAssignments, identities: string[]): ProjectRole[] {
+  const wanted = new Set(identities.map(normalize));
+  const roles = Object.entries(assignments)
+    .filter(([identity]) => wanted.has(normalize(identity)))
+    .flatMap(([, assigned]) => assigned);
+  return PROJECT_ROLES.filter((role) => roles.includes(role));
+}
+
+/**
+ * Fail with a PermissionError unless the acting identity may take the
+ * action in the project (the current one by default). Projects without
+ * role assignments allow everything.
+ */
+export async function checkPermission(action: PermissionAction, projectId?: string): Promise<void> {
+  const config = await getRoleConfig();
+  if (Object.keys(config.projects).length === 0) return;
+  const project = projectId ?? (await diskProjectService.getCurrentProjectId());
+  const assignments = config.projects[project] ?? {};
+  if (Object.keys(assignments).length === 0) return;
+
+  const identities = await getCurrentIdentities();
+  const roles = getRolesOf(assignments, identities);
+  if (!roles.some((role) => ACTION_ROLES[action].includes(role))) {
+    debug.warn(`[PermissionService] ${action} in ${project} refused for ${identities.join(', ')}`);
+    throw new PermissionError(action, project, identities[0] ?? '');
+  }
+}
+
+/**
+ * Replace the role assignments of a project. Only admins may change roles
+ * once any are assigned; removing every assignment opens the project again.
+ */
+export async function setProjectRoles(
+  projectId: string,
+  assignments: RoleAssignments
+): Promise<void> {
+  const errors = validateRoleAssignments(assignments);
+  if (errors.length > 0) {
+    throw new Error(`Invalid roles: ${errors.join('; ')}`);
+  }
+  await checkPermission('manage-roles', projectId);
+
+  const config = await getRoleConfig();
+  const projects = { ...config.projects };
+  const assigned = Object.fromEntries(
+    Object.entries(assignments)
+      .filter(([, roles]) => roles.length > 0)
+      .map(([identity, roles]) => [identity.trim(), [...new Set(roles)]])
+  );
+  if (Object.keys(assigned).length > 0) projects[projectId] = assigned;
+  else delete projects[projectId];
+  await projectConfigService.setSection(
+    ROLES_SECTION,
+    { projects },
+    `Update roles of ${projectId}`
+  );
+}
+
+export const permissionService = {
+  getRoleConfig,
+  getCurrentIdentities,
+  getRolesOf,
+  checkPermission,
+  setProjectRoles,
+  validateRoleAssignments,
+};
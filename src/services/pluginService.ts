@@ -0,0 +1,198 @@
+/**
+ * Plugin Service
+ *
+ * Importers and exporters for proprietary formats, provided as external
+ * executables (see PluginManifest) that the Electron main process discovers
+ * and runs. Requests and responses are JSON over stdin/stdout:
+ *
+ *   import: { action, fileName, content, encoding }
+ *        -> { artifacts: [{ type: "requirements", title, description, ... }] }
+ *   export: { action, project, artifacts: { requirements: [...], ... }, links }
+ *        -> { fileName, content, encoding? }
+ *
+ * Imported artifacts get new IDs and the initial workflow status; fields a
+ * plugin returns cannot override those. They are written and committed as
+ * one unit, so a failed import leaves no partial set of files behind.
+ */
+
+import type { Link, PluginCapability, PluginManifest, Project } from '../types';
+import { ALL_ARTIFACT_SERVICES, projectService } from './artifactServices';
+import { baseDiskService, type FileChange } from './baseDiskService';
+import { diskLinkService } from './diskLinkService';
+import { idService } from './idService';
+import { getStatusWorkflow, WORKFLOW_TYPES } from './statusWorkflowService';
+import { PROJECT_ID_FIELDS } from './markdownImportService';
+import { isElectronEnv } from './git/types';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import type { ImportTypeKey } from '../utils/markdownImportUtils';
+import { debug } from '../utils/debug';
+
+export type PluginEncoding = 'utf-8' | 'base64';
+
+export interface PluginArtifact {
+  type: string; // One of the workflow artifact types, e.g. requirements
+  title: string;
+  description?: string;
+  [field: string]: unknown;
+}
+
+export interface PluginImportResult {
+  created: { id: string; typeKey: string; title: string }[];
+  skipped: { title: string; reason: string }[];
+}
+
+export interface PluginExportResult {
+  fileName: string;
+  content: string;
+  encoding: PluginEncoding;
+}
+
+/**
+ * Installed plugins; empty outside Electron
+ */
+export async function listPlugins(capability?: PluginCapability): Promise<PluginManifest[]> {
+  if (!isElectronEnv()) return [];
+  const result = await window.electronAPI!.plugins.list();
+  if (result.error) throw new Error(result.error);
+  return (result.plugins || []).filter((p) => !capability || p.capabilities.includes(capability));
+}
+
+async function runPlugin<T>(
+  name: string,
+  request: { action: PluginCapability; [field: string]: unknown }
+): Promise<T> {
+  if (!isElectronEnv()) {
+    throw new Error('Plugins are only available in the desktop app');
+  }
+  const result = await window.electronAPI!.plugins.run(name, request);
+  if (result.error) throw new Error(result.error);
+  return result.response as T;
+}
+
+/**
+ * Convert a file with an importer plugin and create the returned artifacts,
+ * adding them to the project when one is given
+ */
+export async function importWithPlugin(
+  pluginName: string,
+  file: { name: string; content: string; encoding: PluginEncoding },
+  projectId?: string
+): Promise<PluginImportResult> {
+  const response = await runPlugin<{ artifacts?: PluginArtifact[] }>(pluginName, {
+    action: 'import',
+    fileName: file.name,
+    content: file.content,
+    encoding: file.encoding,
+  });
+  if (!Array.isArray(response?.artifacts)) {
+    throw new Error(`Plugin ${pluginName} returned no artifacts`);
+  }
+
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const result: PluginImportResult = { created: [], skipped: [] };
+  const byType = new Map<ImportTypeKey, Omit<PluginArtifact, 'type'>[]>();
+  for (const { type, ...fields } of response.artifacts) {
+    if (!WORKFLOW_TYPES.includes(type)) {
+      result.skipped.push({ title: String(fields.title), reason: `unknown type ${type}` });
+    } else if (typeof fields.title !== 'string' || !fields.title.trim()) {
+      result.skipped.push({ title: '', reason: 'missing title' });
+    } else {
+      const typeKey = type as ImportTypeKey;
+      byType.set(typeKey, [...(byType.get(typeKey) || []), fields]);
+    }
+  }
+
+  const { initialStatus } = await getStatusWorkflow();
+  const now = Date.now();
+  const changes: FileChange[] = [];
+
+  for (const [typeKey, artifacts] of byType) {
+    const service = ALL_ARTIFACT_SERVICES[typeKey];
+    const ids = await idService.getNextIds(typeKey, artifacts.length, true);
+    const counterPath = idService.getCounterPath(typeKey);
+    changes.push({ path: counterPath, content: await baseDiskService.readTextFile(counterPath) });
+
+    for (const [i, fields] of artifacts.entries()) {
+      const artifact = {
+        ...service.deserialize(''),
+        ...fields,
+        status: initialStatus,
+        id: ids[i],
+        isDeleted: false,
+        dateCreated: now,
+        lastModified: now,
+        revision: '01',
+      };
+      const target = `${ARTIFACT_CONFIG[typeKey].folder}/${ids[i]}.md`;
+      changes.push({ path: target, content: service.serialize(artifact) });
+      result.created.push({ id: ids[i], typeKey, title: fields.title });
+    }
+
+    if (project) {
+      const field = PROJECT_ID_FIELDS[typeKey];
+      project[field] = [...(project[field] || []), ...ids];
+    }
+  }
+
+  if (project && result.created.length > 0) {
+    changes.push({
+      path: `${ARTIFACT_CONFIG.projects.folder}/${project.id}.md`,
+      content: projectService.serialize({ ...project, lastModified: now }),
+    });
+  }
+  await baseDiskService.writeTextFilesAndCommit(
+    changes,
+    `Import ${result.created.length} artifacts with plugin ${pluginName}`
+  );
+  debug.log(`[PluginService] ${pluginName} imported ${result.created.length} artifacts`);
+  return result;
+}
+
+/**
+ * Export the artifacts and links (of a project, or all) with an exporter
+ * plugin; the caller saves the returned file
+ */
+export async function exportWithPlugin(
+  pluginName: string,
+  projectId?: string
+): Promise<PluginExportResult> {
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const artifacts: Record<string, { id: string }[]> = {};
+  for (const typeKey of WORKFLOW_TYPES) {
+    const all: { id: string }[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+    const memberIds = project ? project[PROJECT_ID_FIELDS[typeKey as ImportTypeKey]] || [] : null;
+    artifacts[typeKey] = memberIds ? all.filter((a) => memberIds.includes(a.id)) : all;
+  }
+  const links: Link[] = projectId
+    ? await diskLinkService.getLinksForProject(projectId)
+    : await diskLinkService.getAllLinks();
+
+  const response = await runPlugin<Partial<PluginExportResult>>(pluginName, {
+    action: 'export',
+    project,
+    artifacts,
+    links,
+  });
+  if (typeof response?.fileName !== 'string' || typeof response.content !== 'string') {
+    throw new Error(`Plugin ${pluginName} returned no file`);
+  }
+  return {
+    fileName: response.fileName,
+    content: response.content,
+    encoding: response.encoding === 'base64' ? 'base64' : 'utf-8',
+  };
+}
+
+export const pluginService = {
+  listPlugins,
+  importWithPlugin,
+  exportWithPlugin,
+};
@@ -0,0 +1,115 @@
+/**
+ * Print Service
+ *
+ * Builds the print-friendly HTML document of a selection of artifacts (see
+ * printExportUtils) from the files on disk, and hands it to the browser's or
+ * desktop window's print dialog through a hidden frame.
+ */
+
+import { baseDiskService } from './baseDiskService';
+import { diskLinkService } from './diskLinkService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import type { RedactionProfile } from '../types';
+import { getInverseType } from '../utils/linkTypes';
+import { extractImagePaths, loadImageAsBase64 } from '../utils/pdfExportUtils';
+import { buildPrintHtml, type PrintArtifact } from '../utils/printExportUtils';
+import { redactMarkdownFile } from '../utils/redactionUtils';
+import { mapWithConcurrency } from '../utils/concurrencyUtils';
+import { debug } from '../utils/debug';
+
+async function readArtifact(id: string): Promise<Omit<PrintArtifact, 'links'>> {
+  const config = ARTIFACT_CONFIG[getTypeFromId(id)];
+  if (!config) {
+    return { id, path: id, content: null };
+  }
+  const path = `${config.folder}/${id}.md`;
+  try {
+    const content = await baseDiskService.readTextFile(path);
+    return { id, path, content: content || null };
+  } catch (err) {
+    debug.warn(`[PrintService] Could not read ${path}`, err);
+    return { id, path, content: null };
+  }
+}
+
+/**
+ * Render the artifacts, in the given order, as one HTML document for printing,
+ * with the redaction profile applied to their content when one is given
+ */
+export async function buildPrintDocument(
+  ids: string[],
+  title: string,
+  redaction?: RedactionProfile | null
+): Promise<string> {
+  const [read, links] = await Promise.all([
+    mapWithConcurrency(ids, readArtifact),
+    diskLinkService.getAllLinks(),
+  ]);
+  const files = read.map((file) =>
+    redaction && file.content
+      ? { ...file, content: redactMarkdownFile(file.content, redaction) }
+      : file
+  );
+
+  const artifacts: PrintArtifact[] = files.map((file) => ({
+    ...file,
+    links: links
+      .filter((link) => !link.isDeleted)
+      .flatMap((link) => {
+        if (link.sourceId === file.id) return [{ type: link.type, targetId: link.targetId }];
+        if (link.targetId === file.id) {
+          return [{ type: getInverseType(link.type), targetId: link.sourceId }];
+        }
+        return [];
+      }),
+  }));
+
+  const imagePaths = new Set(files.flatMap((file) => extractImagePaths(file.content ?? '')));
+  const images: Record<string, string> = {};
+  await mapWithConcurrency([...imagePaths], async (path) => {
+    const data = await loadImageAsBase64(path);
+    if (data) images[path] = data;
+  });
+
+  return buildPrintHtml(artifacts, { title, images });
+}
+
+/**
+ * Open the print dialog for an HTML document without navigating away
+ */
+export function printHtml(html: string): Promise<void> {
+  return new Promise((resolve) => {
+    const frame = document.createElement('iframe');
+    frame.setAttribute('aria-hidden', 'true');
+    frame.style.cssText = 'position: fixed; width: 0; height: 0; border: 0; visibility: hidden;';
+    frame.onload = () => {
+      const view = frame.contentWindow!;
+      // Keep the frame until the dialog closes; print() does not block everywhere
+      view.addEventListener('afterprint', () => {
+        frame.remove();
+        resolve();
+      });
+      view.focus();
+      view.print();
+    };
+    frame.srcdoc = html;
+    document.body.appendChild(frame);
+  });
+}
+
+/**
+ * Build the document for the artifacts and open the print dialog for it
+ */
+export async function printArtifacts(
+  ids: string[],
+  title: string,
+  redaction?: RedactionProfile | null
+): Promise<void> {
+  await printHtml(await buildPrintDocument(ids, title, redaction));
+}
+
+export const printService = {
+  buildPrintDocument,
+  printHtml,
+  printArtifacts,
+};
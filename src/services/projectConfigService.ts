@@ -0,0 +1,64 @@
+/**
+ * Project Config Service
+ *
+ * Repository-wide configuration stored as JSON sections in the config/
+ * folder (e.g. config/workflow.json) and versioned with git.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { debug } from '../utils/debug';
+
+const CONFIG_DIR = 'config';
+const SECTION_PATTERN = /^[a-z0-9][a-z0-9-]*$/;
+
+class ProjectConfigService extends BaseDiskService {
+  /**
+   * Initialize config directory
+   */
+  async initialize(): Promise<void> {
+    await this.ensureDirectory(CONFIG_DIR);
+  }
+
+  /**
+   * Path of a config section's file, e.g. to journal it with other changes
+   */
+  getSectionPath(section: string): string {
+    if (!SECTION_PATTERN.test(section)) {
+      throw new Error(`Invalid config section name: ${section}`);
+    }
+    return `${CONFIG_DIR}/${section}.json`;
+  }
+
+  /**
+   * Read a config section, filling missing keys from the defaults
+   */
+  async getSection<T extends object>(section: string, defaults: T): Promise<T> {
+    const stored = await this.readJsonFile<Partial<T>>(this.getSectionPath(section));
+    if (!stored || typeof stored !== 'object' || Array.isArray(stored)) {
+      return defaults;
+    }
+    return { ...defaults, ...stored };
+  }
+
+  /**
+   * Write a config section without committing, as part of a larger change
+   * that commits it together with other files
+   */
+  async writeSection<T extends object>(section: string, value: T): Promise<void> {
+    await this.writeJsonFile(this.getSectionPath(section), value);
+  }
+
+  /**
+   * Write a config section and commit it
+   */
+  async setSection<T extends object>(
+    section: string,
+    value: T,
+    commitMessage: string = `Update ${section} configuration`
+  ): Promise<void> {
+    await this.writeJsonFile(this.getSectionPath(section), value, commitMessage);
+    debug.log(`[ProjectConfigService] Saved config section: ${section}`);
+  }
+}
+
+export const projectConfigService = new ProjectConfigService();
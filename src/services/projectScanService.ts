@@ -0,0 +1,171 @@
+/**
+ * Project Scan Service
+ *
+ * Reconciles the app's view of a project with what is on disk when it is
+ * opened. The artifact files and the git HEAD are recorded in an index in
+ * the app state folder at the end of every scan; the next scan compares the
+ * files and HEAD against it, so changes made while the app was closed
+ * (other editors, git on the command line) are found and reported. Runs as
+ * a background job with progress.
+ */
+
+import { baseDiskService } from './baseDiskService';
+import { fileSystemService } from './fileSystemService';
+import { realGitService } from './realGitService';
+import { submitJob, type JobContext } from './jobService';
+import { SCANNED_TYPES } from './orphanFileService';
+import { APP_STATE_DIR } from './git/types';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { sha256Hex } from '../utils/contentHashUtils';
+import { mapWithConcurrency } from '../utils/concurrencyUtils';
+import { debug } from '../utils/debug';
+
+export const SCAN_INDEX_PATH = `${APP_STATE_DIR}/index.json`;
+export const SCAN_JOB_NAME = 'Scan project';
+
+// Commits looked at when counting how far HEAD moved
+const HISTORY_DEPTH = 100;
+
+interface ScanIndex {
+  head: string | null;
+  files: Record<string, string>; // Path -> SHA-256 of the file as stored
+}
+
+export interface ProjectScanSummary {
+  firstScan: boolean; // No index from an earlier session to compare against
+  scannedFiles: number;
+  added: string[];
+  removed: string[];
+  modified: string[];
+  previousHead: string | null;
+  head: string | null;
+  newCommits: number; // Commits on top of the previous HEAD, up to HISTORY_DEPTH
+  headRewritten: boolean; // HEAD moved to a commit not descending from the previous one
+}
+
+async function readIndex(): Promise<ScanIndex | null> {
+  try {
+    const text = await fileSystemService.readFile(SCAN_INDEX_PATH);
+    if (!text) return null;
+    const index = JSON.parse(text) as ScanIndex;
+    return index && typeof index.files === 'object' ? index : null;
+  } catch (err) {
+    debug.warn('[ProjectScanService] Ignoring unreadable scan index:', err);
+    return null;
+  }
+}
+
+async function listArtifactPaths(): Promise<string[]> {
+  const paths: string[] = [];
+  for (const typeKey of SCANNED_TYPES) {
+    const folder = ARTIFACT_CONFIG[typeKey].folder;
+    for (const name of await baseDiskService.listFiles(folder)) {
+      if (name.endsWith('.md')) paths.push(`${folder}/${name}`);
+    }
+  }
+  return paths.sort();
+}
+
+// How many commits HEAD gained since previousHead; null when it is not an ancestor
+async function countNewCommits(previousHead: string): Promise<number | null> {
+  const history = await realGitService.getHistory(undefined, HISTORY_DEPTH, 'HEAD');
+  const index = history.findIndex((commit) => commit.hash === previousHead);
+  if (index !== -1) return index;
+  return history.length === HISTORY_DEPTH ? HISTORY_DEPTH : null;
+}
+
+/**
+ * Compare the project with the index from the last scan and record the new
+ * state. Files are hashed as stored, so encrypted files need no key.
+ */
+export async function scanProject(context: Partial<JobContext> = {}): Promise<ProjectScanSummary> {
+  const { signal, reportProgress } = context;
+  const previous = await readIndex();
+  const paths = await listArtifactPaths();
+
+  const files: Record<string, string> = {};
+  let completed = 0;
+  await mapWithConcurrency(paths, async (path) => {
+    if (signal?.aborted) throw new Error('Scan cancelled');
+    files[path] = await sha256Hex((await fileSystemService.readFile(path)) ?? '');
+    completed++;
+    if (completed % 50 === 0 || completed === paths.length) {
+      reportProgress?.({ completed, total: paths.length, message: 'Reading artifacts' });
+    }
+  });
+
+  const head = await realGitService.resolveRef('HEAD').catch(() => null);
+  const previousHead = previous?.head ?? null;
+  let newCommits = 0;
+  let headRewritten = false;
+  if (previousHead && head && head !== previousHead) {
+    const count = await countNewCommits(previousHead);
+    newCommits = count ?? 0;
+    headRewritten = count === null;
+  }
+
+  const before = previous?.files ?? {};
+  const summary: ProjectScanSummary = {
+    firstScan: previous === null,
+    scannedFiles: paths.length,
+    added: previous ? paths.filter((path) => !(path in before)) : [],
+    removed: Object.keys(before).filter((path) => !(path in files)),
+    modified: paths.filter((path) => path in before && before[path] !== files[path]),
+    previousHead,
+    head,
+    newCommits,
+    headRewritten,
+  };
+
+  const index: ScanIndex = { head, files };
+  await baseDiskService.writeTextFile(SCAN_INDEX_PATH, JSON.stringify(index));
+  debug.info(`[ProjectScanService] ${formatScanSummary(summary) ?? 'No changes'}`);
+  return summary;
+}
+
+/**
+ * Whether the scan found anything that changed since the last session
+ */
+export function hasScanDifferences(summary: ProjectScanSummary): boolean {
+  return (
+    summary.added.length + summary.removed.length + summary.modified.length > 0 ||
+    summary.newCommits > 0 ||
+    summary.headRewritten
+  );
+}
+
+/**
+ * Sentence describing the differences, or null when there are none
+ */
+export function formatScanSummary(summary: ProjectScanSummary): string | null {
+  if (!hasScanDifferences(summary)) return null;
+  const counts: [number, string][] = [
+    [summary.added.length, 'added'],
+    [summary.modified.length, 'changed'],
+    [summary.removed.length, 'removed'],
+  ];
+  const parts = counts
+    .filter(([n]) => n > 0)
+    .map(([n, what]) => `${n} artifact file${n === 1 ? '' : 's'} ${what}`);
+  if (summary.newCommits > 0) {
+    parts.push(`${summary.newCommits} new commit${summary.newCommits === 1 ? '' : 's'}`);
+  }
+  if (summary.headRewritten) parts.push('a different commit checked out');
+  const last = parts.pop()!;
+  const list = parts.length > 0 ? `${parts.join(', ')} and ${last}` : last;
+  return `Since the project was last open: ${list}.`;
+}
+
+/**
+ * Run the scan as a background job and return the job ID
+ */
+export function startProjectScan(): string {
+  return submitJob(SCAN_JOB_NAME, (context) => scanProject(context));
+}
+
+export const projectScanService = {
+  scanProject,
+  hasScanDifferences,
+  formatScanSummary,
+  startProjectScan,
+};
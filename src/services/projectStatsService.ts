@@ -0,0 +1,122 @@
+/**
+ * Project Statistics Service
+ *
+ * Summary numbers for the dashboard in a single call: artifact counts by type,
+ * status and priority, link counts, test coverage and suspect links.
+ */
+
+import type { Link, Project, Requirement, TestCase } from '../types';
+import { ALL_ARTIFACT_SERVICES, projectService } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { getCoverageReport } from './coverageService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+
+// Artifact types counted in the statistics
+export const STATS_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface StatsArtifact {
+  id: string;
+  status?: string;
+  priority?: string;
+  isDeleted?: boolean;
+}
+
+export interface TypeStats {
+  total: number;
+  byStatus: Record<string, number>;
+  byPriority: Record<string, number>;
+}
+
+export interface ProjectStats {
+  projectId?: string;
+  totalArtifacts: number;
+  byType: Record<string, TypeStats>;
+  links: {
+    total: number;
+    byType: Record<string, number>;
+    suspect: number;
+  };
+  coveragePercent: number;
+  testedRequirements: number;
+}
+
+function countBy<T>(items: T[], key: (item: T) => string): Record<string, number> {
+  return items.reduce(
+    (counts, item) => {
+      const value = key(item);
+      counts[value] = (counts[value] || 0) + 1;
+      return counts;
+    },
+    {} as Record<string, number>
+  );
+}
+
+/**
+ * Compute statistics from already-loaded artifacts (keyed by type) and links.
+ * Deleted artifacts and links are not counted.
+ */
+export function computeProjectStats(
+  artifactsByType: Record<string, StatsArtifact[]>,
+  links: Link[]
+): ProjectStats {
+  const byType: Record<string, TypeStats> = {};
+  Object.entries(artifactsByType).forEach(([typeKey, artifacts]) => {
+    const active = artifacts.filter((a) => !a.isDeleted);
+    byType[typeKey] = {
+      total: active.length,
+      byStatus: countBy(active, (a) => a.status || 'none'),
+      byPriority: countBy(active.filter((a) => a.priority), (a) => a.priority!),
+    };
+  });
+
+  const activeLinks = links.filter((link) => !link.isDeleted);
+  const coverage = getCoverageReport(
+    (artifactsByType.requirements || []) as Requirement[],
+    (artifactsByType.testcases || []) as TestCase[],
+    activeLinks
+  );
+
+  return {
+    totalArtifacts: Object.values(byType).reduce((sum, stats) => sum + stats.total, 0),
+    byType,
+    links: {
+      total: activeLinks.length,
+      byType: countBy(activeLinks, (link) => link.type),
+      suspect: activeLinks.filter((link) => link.suspect).length,
+    },
+    coveragePercent: coverage.coveragePercent,
+    testedRequirements: coverage.testedRequirements,
+  };
+}
+
+/**
+ * Load all artifacts and links and compute the statistics,
+ * optionally restricted to a single project
+ */
+export async function getProjectStats(projectId?: string): Promise<ProjectStats> {
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const [lists, links] = await Promise.all([
+    Promise.all(
+      STATS_TYPES.map(async (typeKey) => {
+        const items: StatsArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+        const membershipField = PROJECT_MEMBERSHIP[typeKey];
+        if (!project || !membershipField) return items;
+        const memberIds = new Set((project[membershipField] as string[]) || []);
+        return items.filter((item) => memberIds.has(item.id));
+      })
+    ),
+    projectId ? diskLinkService.getLinksForProject(projectId) : diskLinkService.getAllLinks(),
+  ]);
+
+  const artifactsByType = Object.fromEntries(STATS_TYPES.map((typeKey, i) => [typeKey, lists[i]]));
+  return { projectId, ...computeProjectStats(artifactsByType, links) };
+}
+
+export const projectStatsService = {
+  computeProjectStats,
+  getProjectStats,
+};
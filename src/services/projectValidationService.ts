@@ -0,0 +1,259 @@
+/**
+ * Project Validation Service
+ *
+ * Runs every project check in one pass (attribute schema, duplicate IDs,
+ * broken references, dangling links, trace cycles, workflow statuses and
+ * orphan files) and returns a single categorized report for the project
+ * problems panel.
+ */
+
+import { baseDiskService } from './baseDiskService';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { getAttributeSchema, validateTypeAttributes, SCHEMA_TYPES } from './attributeSchemaService';
+import { getDanglingLinks } from './danglingLinkService';
+import { diskLinkService } from './diskLinkService';
+import { referenceValidationService } from './referenceValidationService';
+import { getStatusWorkflow, validateStatusWorkflow, WORKFLOW_TYPES } from './statusWorkflowService';
+import {
+  findOrphanFiles,
+  getIgnoredFiles,
+  loadArtifactFiles,
+  type ArtifactFile,
+  type OrphanReason,
+} from './orphanFileService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+import { validateAttributeSchema } from '../utils/attributeSchemaUtils';
+import { findTraceCycles, formatCyclePath } from '../utils/traceCycleUtils';
+
+export type ProblemCategory =
+  | 'schema'
+  | 'duplicate-id'
+  | 'broken-reference'
+  | 'dangling-link'
+  | 'trace-cycle'
+  | 'workflow'
+  | 'orphan-file';
+
+export type ProblemSeverity = 'error' | 'warning';
+
+export interface ProjectProblem {
+  category: ProblemCategory;
+  severity: ProblemSeverity;
+  message: string;
+  artifactId?: string;
+  path?: string;
+}
+
+export interface ProjectValidationReport {
+  problems: ProjectProblem[];
+  counts: Record<ProblemCategory, number>;
+  checkedAt: number;
+}
+
+const ORPHAN_REASON_LABELS: Record<OrphanReason, string> = {
+  'not-markdown': 'not a markdown file',
+  misnamed: 'file name does not follow the artifact naming convention',
+  'no-frontmatter': 'missing frontmatter',
+};
+
+// Test cases may carry a result status instead of a workflow status
+const TEST_RESULT_STATUSES = ['passed', 'failed', 'blocked'];
+
+interface StatusArtifact {
+  id: string;
+  status?: string;
+  isDeleted?: boolean;
+}
+
+function fileStem(path: string): string {
+  return path.slice(path.lastIndexOf('/') + 1).replace(/\.md$/, '');
+}
+
+/**
+ * Frontmatter IDs that disagree with their file name or are used by several files
+ */
+export function findDuplicateIds(files: ArtifactFile[]): ProjectProblem[] {
+  const problems: ProjectProblem[] = [];
+  const pathsById = new Map<string, string[]>();
+
+  files
+    .filter((file) => file.path.endsWith('.md'))
+    .forEach((file) => {
+      const { frontmatter } = parseYamlFrontmatter(file.content);
+      const id = typeof frontmatter.id === 'string' ? frontmatter.id : fileStem(file.path);
+      pathsById.set(id, [...(pathsById.get(id) || []), file.path]);
+      if (id !== fileStem(file.path)) {
+        problems.push({
+          category: 'duplicate-id',
+          severity: 'warning',
+          artifactId: id,
+          path: file.path,
+          message: `${file.path} declares ID ${id}, which does not match its file name`,
+        });
+      }
+    });
+
+  pathsById.forEach((paths, id) => {
+    if (paths.length < 2) return;
+    problems.push({
+      category: 'duplicate-id',
+      severity: 'error',
+      artifactId: id,
+      path: paths[0],
+      message: `ID ${id} is used by ${paths.length} files: ${paths.join(', ')}`,
+    });
+  });
+
+  return problems;
+}
+
+async function checkOrphanFiles(files: ArtifactFile[]): Promise<ProjectProblem[]> {
+  const orphans = findOrphanFiles(files, await getIgnoredFiles());
+  return orphans.map(
+    (orphan): ProjectProblem => ({
+      category: 'orphan-file',
+      severity: 'warning',
+      path: orphan.path,
+      message: `${orphan.path}: ${ORPHAN_REASON_LABELS[orphan.reason]}`,
+    })
+  );
+}
+
+async function checkSchema(): Promise<ProjectProblem[]> {
+  const schemaErrors = validateAttributeSchema(await getAttributeSchema());
+  const problems = schemaErrors.map(
+    (message): ProjectProblem => ({
+      category: 'schema',
+      severity: 'error',
+      path: 'config/attribute-schema.json',
+      message,
+    })
+  );
+
+  for (const typeKey of Object.keys(SCHEMA_TYPES)) {
+    for (const result of await validateTypeAttributes(typeKey)) {
+      result.violations.forEach((violation) =>
+        problems.push({
+          category: 'schema',
+          severity: 'error',
+          artifactId: result.artifactId,
+          message: `${result.artifactId}: ${violation.message}`,
+        })
+      );
+    }
+  }
+  return problems;
+}
+
+async function checkReferences(): Promise<ProjectProblem[]> {
+  const report = await referenceValidationService.validate();
+  // Link endpoints are reported as dangling links
+  return report.broken
+    .filter((ref) => ref.kind !== 'link')
+    .map(
+      (ref): ProjectProblem => ({
+        category: 'broken-reference',
+        severity: 'error',
+        artifactId: ref.sourceId,
+        path: ref.sourcePath,
+        message:
+          ref.reason === 'unknown-id'
+            ? `${ref.sourceId} references unknown artifact ${ref.target}`
+            : `${ref.sourceId} links to missing file ${ref.target}`,
+      })
+    );
+}
+
+async function checkDanglingLinks(): Promise<ProjectProblem[]> {
+  const dangling = await getDanglingLinks();
+  return dangling.map(({ link, missingEndpoints }): ProjectProblem => {
+    const missing = missingEndpoints.map((end) =>
+      end === 'source' ? link.sourceId : link.targetId
+    );
+    return {
+      category: 'dangling-link',
+      severity: 'error',
+      artifactId: link.id,
+      path: `${ARTIFACT_CONFIG.links.folder}/${link.id}.md`,
+      message: `${link.id} points at missing ${missing.join(' and ')}`,
+    };
+  });
+}
+
+async function checkTraceCycles(): Promise<ProjectProblem[]> {
+  const cycles = findTraceCycles(await diskLinkService.getAllLinks());
+  return cycles.map(
+    (cycle): ProjectProblem => ({
+      category: 'trace-cycle',
+      severity: 'error',
+      artifactId: cycle[0],
+      message: `Derivation/refinement links form a cycle: ${formatCyclePath(cycle)}`,
+    })
+  );
+}
+
+async function checkWorkflow(): Promise<ProjectProblem[]> {
+  const workflow = await getStatusWorkflow();
+  const problems = validateStatusWorkflow(workflow).map(
+    (message): ProjectProblem => ({
+      category: 'workflow',
+      severity: 'error',
+      path: 'config/workflow.json',
+      message,
+    })
+  );
+
+  for (const typeKey of WORKFLOW_TYPES) {
+    const allowed = new Set(workflow.statuses);
+    if (typeKey === 'testcases') TEST_RESULT_STATUSES.forEach((s) => allowed.add(s));
+
+    const artifacts: StatusArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+    artifacts
+      .filter((a) => !a.isDeleted && a.status && !allowed.has(a.status))
+      .forEach((a) =>
+        problems.push({
+          category: 'workflow',
+          severity: 'warning',
+          artifactId: a.id,
+          message: `${a.id} has status "${a.status}", which is not part of the workflow`,
+        })
+      );
+  }
+  return problems;
+}
+
+/**
+ * Run all project checks and return a consolidated, categorized report
+ */
+export async function validateProject(): Promise<ProjectValidationReport> {
+  const files = await loadArtifactFiles();
+  const results = await Promise.all([
+    checkSchema(),
+    findDuplicateIds(files),
+    checkReferences(),
+    checkDanglingLinks(),
+    checkTraceCycles(),
+    checkWorkflow(),
+    checkOrphanFiles(files),
+  ]);
+
+  const problems = results.flat();
+  const counts: Record<ProblemCategory, number> = {
+    schema: 0,
+    'duplicate-id': 0,
+    'broken-reference': 0,
+    'dangling-link': 0,
+    'trace-cycle': 0,
+    workflow: 0,
+    'orphan-file': 0,
+  };
+  problems.forEach((problem) => counts[problem.category]++);
+
+  return { problems, counts, checkedAt: Date.now() };
+}
+
+export const projectValidationService = {
+  findDuplicateIds,
+  validateProject,
+};
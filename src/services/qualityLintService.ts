@@ -0,0 +1,82 @@
+/**
+ * Quality Lint Service
+ *
+ * Runs the requirement quality checks (ambiguous terms, passive voice, long
+ * sentences, missing acceptance criteria) on artifact files. Acceptance
+ * criteria are only required for requirements.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { lintMarkdown, type LintFinding, type LintOptions } from '../utils/qualityLintUtils';
+import { debug } from '../utils/debug';
+
+// Artifact types whose text is checked
+export const LINT_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface LintReport {
+  path: string;
+  artifactId: string;
+  findings: LintFinding[];
+}
+
+function resolveArtifactPath(path: string): { id: string; typeKey: string; filePath: string } {
+  const id = (path.split('/').pop() || '').replace(/\.md$/, '');
+  const typeKey = getTypeFromId(id);
+  const filePath = ARTIFACT_CONFIG[typeKey] && `${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`;
+  if (!LINT_TYPES.includes(typeKey) || path.replace(/^\/+/, '') !== filePath) {
+    throw new Error(`Not a lintable artifact path: ${path}`);
+  }
+  return { id, typeKey, filePath };
+}
+
+class QualityLintService extends BaseDiskService {
+  /**
+   * Lint one artifact file (repo-relative path, e.g. requirements/REQ-001.md).
+   * Finding positions refer to lines of that file.
+   */
+  async lintArtifact(
+    path: string,
+    options: Pick<LintOptions, 'maxSentenceWords'> = {}
+  ): Promise<LintReport> {
+    const { id, typeKey, filePath } = resolveArtifactPath(path);
+    if (!(await this.exists(filePath))) {
+      throw new Error(`Artifact not found: ${path}`);
+    }
+
+    const markdown = await this.readTextFile(filePath);
+    const findings = lintMarkdown(markdown, {
+      ...options,
+      requireAcceptanceCriteria: typeKey === 'requirements',
+    });
+    return { path: filePath, artifactId: id, findings };
+  }
+
+  /**
+   * Lint every artifact of the given types; only files with findings are returned
+   */
+  async lintAll(
+    typeKeys: string[] = LINT_TYPES,
+    options: Pick<LintOptions, 'maxSentenceWords'> = {}
+  ): Promise<LintReport[]> {
+    const reports: LintReport[] = [];
+
+    for (const typeKey of typeKeys.filter((key) => LINT_TYPES.includes(key))) {
+      const folder = ARTIFACT_CONFIG[typeKey].folder;
+      const files = await this.listFiles(folder);
+      for (const file of files.filter((f) => f.endsWith('.md'))) {
+        try {
+          const report = await this.lintArtifact(`${folder}/${file}`, options);
+          if (report.findings.length > 0) reports.push(report);
+        } catch (err) {
+          debug.log(`[QualityLintService] Skipping ${folder}/${file}:`, err);
+        }
+      }
+    }
+
+    debug.log(`[QualityLintService] ${reports.length} artifact(s) with findings`);
+    return reports;
+  }
+}
+
+export const qualityLintService = new QualityLintService();
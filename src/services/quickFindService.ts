@@ -0,0 +1,122 @@
+/**
+ * Quick Find Service
+ *
+ * Fuzzy lookup of artifacts by ID and title for a quick-open palette, so
+ * "REQ timout retry" finds "REQ-014 Retry after timeout". The IDs and titles
+ * are kept in memory together with their search words, built from the
+ * artifact services (whose listings come from the parse cache), so a lookup
+ * only scores strings. The index is rebuilt in the background after an
+ * artifact is saved or a file changes on disk; a lookup made meanwhile uses
+ * the previous one.
+ */
+
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { LINKABLE_TYPES } from './danglingLinkService';
+import { fileSystemService } from './fileSystemService';
+import { buildSearchTokens, fuzzyScore, tokenize } from '../utils/fuzzyMatchUtils';
+import { debug } from '../utils/debug';
+
+const DEFAULT_LIMIT = 20;
+
+export interface QuickFindResult {
+  id: string;
+  type: string;
+  title: string;
+  score: number;
+}
+
+interface IndexEntry {
+  id: string;
+  type: string;
+  title: string;
+  tokens: string[];
+}
+
+let index: IndexEntry[] = [];
+let stale = true;
+let building: Promise<void> | null = null;
+
+async function buildIndex(): Promise<IndexEntry[]> {
+  const lists = await Promise.all(
+    LINKABLE_TYPES.map(async (type) => {
+      const artifacts: { id: string; title?: string; name?: string }[] =
+        await ALL_ARTIFACT_SERVICES[type].loadAll();
+      return artifacts.map((artifact) => {
+        const title = artifact.title ?? artifact.name ?? '';
+        return { id: artifact.id, type, title, tokens: buildSearchTokens(artifact.id, title) };
+      });
+    })
+  );
+  return lists.flat();
+}
+
+/**
+ * Rebuild the index if artifacts changed since it was built; resolves when
+ * it is current
+ */
+export function loadQuickFindIndex(): Promise<void> {
+  if (building) return building;
+  if (!stale) return Promise.resolve();
+  stale = false;
+  building = buildIndex()
+    .then((entries) => {
+      index = entries;
+    })
+    .catch((err) => {
+      debug.warn('[QuickFindService] Could not build the index:', err);
+    })
+    .finally(() => {
+      building = null;
+      if (stale) void loadQuickFindIndex();
+    });
+  return building;
+}
+
+/**
+ * Artifacts matching the typed text, best first; answered from the index
+ * without waiting for a rebuild
+ */
+export function quickFind(query: string, limit: number = DEFAULT_LIMIT): QuickFindResult[] {
+  if (stale) void loadQuickFindIndex();
+  const words = tokenize(query);
+  if (words.length === 0) return [];
+
+  const results: QuickFindResult[] = [];
+  for (const entry of index) {
+    const score = fuzzyScore(words, entry.tokens);
+    if (score > 0) {
+      results.push({ id: entry.id, type: entry.type, title: entry.title, score });
+    }
+  }
+  return results
+    .sort((a, b) => b.score - a.score || a.id.localeCompare(b.id, undefined, { numeric: true }))
+    .slice(0, limit);
+}
+
+/**
+ * Build the index and rebuild it whenever an artifact is saved or deleted
+ * here or its file changes on disk. Returns the function that stops tracking
+ * and drops the index.
+ */
+export function startQuickFindTracking(): () => void {
+  const markStale = () => {
+    stale = true;
+  };
+  const unsubscribes = [
+    ...LINKABLE_TYPES.map((type) => ALL_ARTIFACT_SERVICES[type].subscribe(markStale)),
+    fileSystemService.onFilesChanged(markStale),
+  ];
+  stale = true;
+  void loadQuickFindIndex();
+  return () => {
+    unsubscribes.forEach((unsubscribe) => unsubscribe());
+    index = [];
+    stale = true;
+  };
+}
+
+export const quickFindService = {
+  loadQuickFindIndex,
+  quickFind,
+  startQuickFindTracking,
+};
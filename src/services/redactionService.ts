@@ -0,0 +1,71 @@
+/**
+ * Redaction Service
+ *
+ * Keeps the project's redaction profiles in the project config and applies
+ * one to the artifacts of an export (see redactionUtils), so a sanitized
+ * requirement set can be handed to suppliers without internal notes or
+ * customer names.
+ */
+
+import type { RedactionProfile } from '../types';
+import { projectConfigService } from './projectConfigService';
+import { redactArtifact, validateRedactionProfile } from '../utils/redactionUtils';
+
+const REDACTION_SECTION = 'redaction';
+
+interface RedactionSettings {
+  profiles: RedactionProfile[];
+}
+
+/**
+ * The configured redaction profiles, in the order they were saved
+ */
+export async function getRedactionProfiles(): Promise<RedactionProfile[]> {
+  const { profiles } = await projectConfigService.getSection<RedactionSettings>(
+    REDACTION_SECTION,
+    { profiles: [] }
+  );
+  return Array.isArray(profiles) ? profiles : [];
+}
+
+/**
+ * Validate and store the redaction profiles in the project config
+ */
+export async function setRedactionProfiles(profiles: RedactionProfile[]): Promise<void> {
+  const errors = profiles.flatMap(validateRedactionProfile);
+  const names = profiles.map((profile) => profile.name.trim());
+  names
+    .filter((name, i) => name && names.indexOf(name) !== i)
+    .forEach((name) => errors.push(`Duplicate profile name: ${name}`));
+  if (errors.length > 0) {
+    throw new Error(`Invalid redaction profiles: ${errors.join('; ')}`);
+  }
+  await projectConfigService.setSection(
+    REDACTION_SECTION,
+    { profiles },
+    'Update redaction profiles'
+  );
+}
+
+/**
+ * The artifact lists of an export with the profile applied; unchanged when
+ * there is no profile
+ */
+export function redactExportState<T extends Record<string, object[]>>(
+  state: T,
+  profile: RedactionProfile | null | undefined
+): T {
+  if (!profile) return state;
+  return Object.fromEntries(
+    Object.entries(state).map(([key, artifacts]) => [
+      key,
+      artifacts.map((artifact) => redactArtifact(artifact, profile)),
+    ])
+  ) as T;
+}
+
+export const redactionService = {
+  getRedactionProfiles,
+  setRedactionProfiles,
+  redactExportState,
+};
@@ -0,0 +1,145 @@
+/**
+ * Reference Validation Service
+ *
+ * Scans artifact markdown files for inter-artifact references (relative file
+ * links and artifact IDs) and reports the ones that point at missing files or
+ * unknown IDs. Standalone link files are checked for dangling endpoints too.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import { diskLinkService } from './diskLinkService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { extractReferences } from '../utils/referenceUtils';
+import type { ReferenceKind } from '../utils/referenceUtils';
+import { mapWithConcurrency } from '../utils/concurrencyUtils';
+import { debug } from '../utils/debug';
+
+// Folders whose markdown bodies may contain references to other artifacts
+export const REFERENCE_SOURCE_TYPES = [
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+  'documents',
+  'projects',
+] as const;
+
+export type BrokenReferenceReason = 'missing-file' | 'unknown-id';
+
+export interface BrokenReference {
+  sourcePath: string;
+  sourceId: string;
+  kind: ReferenceKind | 'link';
+  target: string;
+  line?: number; // Not set for standalone link files
+  reason: BrokenReferenceReason;
+}
+
+export interface ReferenceValidationReport {
+  scannedFiles: number;
+  checkedReferences: number;
+  broken: BrokenReference[];
+}
+
+class ReferenceValidationService extends BaseDiskService {
+  /**
+   * Collect the IDs of every artifact file on disk, keyed by repo-relative path
+   */
+  private async collectArtifactFiles(): Promise<Map<string, string>> {
+    const files = new Map<string, string>();
+
+    for (const config of Object.values(ARTIFACT_CONFIG)) {
+      const entries = await this.listFiles(config.folder);
+      for (const file of entries) {
+        if (!file.endsWith('.md')) continue;
+        files.set(`${config.folder}/${file}`, file.slice(0, -'.md'.length));
+      }
+    }
+
+    return files;
+  }
+
+  /**
+   * Validate all references in the repository
+   */
+  async validate(): Promise<ReferenceValidationReport> {
+    const artifactFiles = await this.collectArtifactFiles();
+    const knownIds = new Set(artifactFiles.values());
+    const pathCache = new Map<string, boolean>();
+
+    const pathExists = async (path: string): Promise<boolean> => {
+      if (artifactFiles.has(path)) return true;
+      if (!pathCache.has(path)) {
+        pathCache.set(path, await this.exists(path));
+      }
+      return pathCache.get(path)!;
+    };
+
+    const report: ReferenceValidationReport = {
+      scannedFiles: 0,
+      checkedReferences: 0,
+      broken: [],
+    };
+
+    // Read the sources several at a time, then check them in a stable order
+    const sources = REFERENCE_SOURCE_TYPES.flatMap((typeKey) => {
+      const folder = ARTIFACT_CONFIG[typeKey].folder;
+      return [...artifactFiles].filter(([sourcePath]) => sourcePath.startsWith(`${folder}/`));
+    });
+    const contents = await mapWithConcurrency(sources, ([sourcePath]) =>
+      this.readTextFile(sourcePath)
+    );
+
+    for (const [i, [sourcePath, sourceId]] of sources.entries()) {
+      const content = contents[i];
+      if (!content) continue;
+      report.scannedFiles++;
+
+      for (const reference of extractReferences(content, sourcePath)) {
+        report.checkedReferences++;
+
+        const isValid =
+          reference.kind === 'id'
+            ? knownIds.has(reference.target)
+            : await pathExists(reference.target);
+
+        if (!isValid) {
+          report.broken.push({
+            sourcePath,
+            sourceId,
+            kind: reference.kind,
+            target: reference.target,
+            line: reference.line,
+            reason: reference.kind === 'id' ? 'unknown-id' : 'missing-file',
+          });
+        }
+      }
+    }
+
+    // Standalone link files must point at existing artifacts on both ends
+    const links = await diskLinkService.getAllLinks();
+    for (const link of links) {
+      for (const endpoint of [link.sourceId, link.targetId]) {
+        report.checkedReferences++;
+        if (!knownIds.has(endpoint)) {
+          report.broken.push({
+            sourcePath: `${ARTIFACT_CONFIG.links.folder}/${link.id}.md`,
+            sourceId: link.id,
+            kind: 'link',
+            target: endpoint,
+            reason: 'unknown-id',
+          });
+        }
+      }
+    }
+
+    debug.log(
+      `[ReferenceValidationService] Checked ${report.checkedReferences} references in ${report.scannedFiles} files, ${report.broken.length} broken`
+    );
+
+    return report;
+  }
+}
+
+export const referenceValidationService = new ReferenceValidationService();
@@ -0,0 +1,259 @@
+/**
+ * Release Service
+ *
+ * Releases (releases/REL-xxx.json) group the artifacts that make up one
+ * product version, so a project can plan several versions side by side. An
+ * artifact is either tracked at its current version or pinned to the version
+ * of a commit. The release report shows the scope per artifact type and how
+ * much of it is complete. Every change is committed.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { Release, ReleaseItem } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { getCurrentUserName, WORKFLOW_TYPES } from './statusWorkflowService';
+import { realGitService } from './realGitService';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+import { debug } from '../utils/debug';
+
+const RELEASES_DIR = 'releases';
+
+// Statuses that count as done: approved artifacts and passed test cases
+export const DEFAULT_COMPLETE_STATUSES = ['approved', 'passed'];
+
+export interface NewRelease {
+  projectId: string;
+  name: string;
+  description?: string;
+  targetDate?: number;
+}
+
+export interface ReleaseItemStatus {
+  artifactId: string;
+  type: string; // Artifact type key, e.g. "requirements"
+  title?: string;
+  status?: string;
+  commitHash?: string;
+  found: boolean; // False when the artifact (or its pinned version) no longer exists
+  complete: boolean;
+}
+
+export interface ReleaseReport {
+  release: Release;
+  items: ReleaseItemStatus[];
+  byType: Record<string, { total: number; complete: number }>;
+  total: number;
+  complete: number;
+  completeness: number; // Share of complete items, 0 to 1 (1 for an empty release)
+}
+
+function nextReleaseId(ids: string[]): string {
+  const max = ids.reduce((highest, id) => {
+    const number = parseInt(id.replace(/^REL-/, ''), 10);
+    return Number.isNaN(number) ? highest : Math.max(highest, number);
+  }, 0);
+  return `REL-${String(max + 1).padStart(3, '0')}`;
+}
+
+function getArtifactPath(artifactId: string): string {
+  const typeKey = getTypeFromId(artifactId);
+  if (!WORKFLOW_TYPES.includes(typeKey)) {
+    throw new Error(`Not a releasable artifact: ${artifactId}`);
+  }
+  return `${ARTIFACT_CONFIG[typeKey].folder}/${artifactId}.md`;
+}
+
+class ReleaseService extends BaseDiskService {
+  private getReleasePath(releaseId: string): string {
+    return `${RELEASES_DIR}/${releaseId}.json`;
+  }
+
+  /**
+   * A release by ID, or null if it does not exist
+   */
+  async getRelease(releaseId: string): Promise<Release | null> {
+    return this.readJsonFile<Release>(this.getReleasePath(releaseId));
+  }
+
+  private async loadRelease(releaseId: string): Promise<Release> {
+    const release = await this.getRelease(releaseId);
+    if (!release) {
+      throw new Error(`Release not found: ${releaseId}`);
+    }
+    return release;
+  }
+
+  /**
+   * Releases of a project (or all), oldest first
+   */
+  async listReleases(projectId?: string): Promise<Release[]> {
+    const files = (await this.listFiles(RELEASES_DIR)).filter((file) => file.endsWith('.json'));
+    const releases = await Promise.all(
+      files.map((file) => this.getRelease(file.replace(/\.json$/, '')))
+    );
+    return releases
+      .filter((r): r is Release => r !== null && (!projectId || r.projectId === projectId))
+      .sort((a, b) => a.createdAt - b.createdAt);
+  }
+
+  /**
+   * Define a new, empty release; names are unique within a project
+   */
+  async createRelease(input: NewRelease, createdBy?: string): Promise<Release> {
+    const name = input.name.trim();
+    if (!name) {
+      throw new Error('Release name is empty');
+    }
+    const existing = await this.listReleases(input.projectId);
+    if (existing.some((r) => r.name === name)) {
+      throw new Error(`Release ${name} already exists`);
+    }
+
+    const files = await this.listFiles(RELEASES_DIR);
+    const release: Release = {
+      id: nextReleaseId(files.map((file) => file.replace(/\.json$/, ''))),
+      projectId: input.projectId,
+      name,
+      description: input.description?.trim() || undefined,
+      targetDate: input.targetDate,
+      items: [],
+      createdBy: createdBy || (await getCurrentUserName()),
+      createdAt: Date.now(),
+    };
+    await this.writeJsonFile(
+      this.getReleasePath(release.id),
+      release,
+      `Create release ${release.id}: ${name}`
+    );
+    debug.log(`[ReleaseService] Created ${release.id} (${name})`);
+    return release;
+  }
+
+  /**
+   * Remove a release; the artifacts themselves are not touched
+   */
+  async deleteRelease(releaseId: string): Promise<void> {
+    const release = await this.loadRelease(releaseId);
+    await this.deleteFile(
+      this.getReleasePath(releaseId),
+      `Delete release ${releaseId}: ${release.name}`
+    );
+  }
+
+  /**
+   * Add artifacts to a release, or re-assign them. With a commit hash they
+   * are pinned to their version at that commit, otherwise they follow the
+   * current version.
+   */
+  async assignArtifacts(
+    releaseId: string,
+    artifactIds: string[],
+    commitHash?: string
+  ): Promise<Release> {
+    const release = await this.loadRelease(releaseId);
+    const ids = [...new Set(artifactIds)];
+    for (const id of ids) {
+      const path = getArtifactPath(id);
+      const exists = commitHash
+        ? (await realGitService.readFileAtCommit(path, commitHash)) !== null
+        : await this.exists(path);
+      if (!exists) {
+        throw new Error(
+          commitHash ? `${id} does not exist at commit ${commitHash}` : `Artifact not found: ${id}`
+        );
+      }
+    }
+
+    const items: ReleaseItem[] = ids.map((artifactId) =>
+      commitHash ? { artifactId, commitHash } : { artifactId }
+    );
+    const updated: Release = {
+      ...release,
+      items: [...release.items.filter((item) => !ids.includes(item.artifactId)), ...items],
+    };
+    await this.writeJsonFile(
+      this.getReleasePath(releaseId),
+      updated,
+      `${releaseId}: assign ${ids.join(', ')}`
+    );
+    return updated;
+  }
+
+  /**
+   * Remove artifacts from the scope of a release
+   */
+  async unassignArtifacts(releaseId: string, artifactIds: string[]): Promise<Release> {
+    const release = await this.loadRelease(releaseId);
+    const updated: Release = {
+      ...release,
+      items: release.items.filter((item) => !artifactIds.includes(item.artifactId)),
+    };
+    if (updated.items.length === release.items.length) return release;
+    await this.writeJsonFile(
+      this.getReleasePath(releaseId),
+      updated,
+      `${releaseId}: unassign ${artifactIds.join(', ')}`
+    );
+    return updated;
+  }
+
+  private async getItemStatus(
+    item: ReleaseItem,
+    completeStatuses: string[]
+  ): Promise<ReleaseItemStatus> {
+    const type = getTypeFromId(item.artifactId);
+    let fields: { title?: unknown; status?: unknown } | null = null;
+    if (item.commitHash) {
+      const path = `${ARTIFACT_CONFIG[type]?.folder}/${item.artifactId}.md`;
+      const content = await this.readTextFileAtCommit(path, item.commitHash);
+      fields = content === null ? null : parseYamlFrontmatter(content).frontmatter;
+    } else {
+      const artifact = await ALL_ARTIFACT_SERVICES[type]?.load(item.artifactId);
+      fields = artifact && !artifact.isDeleted ? artifact : null;
+    }
+
+    const status = typeof fields?.status === 'string' ? fields.status : undefined;
+    return {
+      artifactId: item.artifactId,
+      type,
+      title: typeof fields?.title === 'string' ? fields.title : undefined,
+      status,
+      commitHash: item.commitHash,
+      found: fields !== null,
+      complete: !!status && completeStatuses.includes(status),
+    };
+  }
+
+  /**
+   * Scope and completeness of a release. An item is complete when its
+   * (pinned or current) status is one of the complete statuses.
+   */
+  async getReleaseReport(
+    releaseId: string,
+    completeStatuses: string[] = DEFAULT_COMPLETE_STATUSES
+  ): Promise<ReleaseReport> {
+    const release = await this.loadRelease(releaseId);
+    const items = await Promise.all(
+      release.items.map((item) => this.getItemStatus(item, completeStatuses))
+    );
+
+    const byType: ReleaseReport['byType'] = {};
+    items.forEach((item) => {
+      const counts = byType[item.type] || (byType[item.type] = { total: 0, complete: 0 });
+      counts.total++;
+      if (item.complete) counts.complete++;
+    });
+    const complete = items.filter((item) => item.complete).length;
+    return {
+      release,
+      items,
+      byType,
+      total: items.length,
+      complete,
+      completeness: items.length === 0 ? 1 : complete / items.length,
+    };
+  }
+}
+
+export const releaseService = new ReleaseService();
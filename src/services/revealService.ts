@@ -0,0 +1,46 @@
+/**
+ * Reveal Service
+ *
+ * Shows a project file in the OS file manager (Explorer, Finder or the
+ * desktop's file manager) with the file selected. Desktop app only; the
+ * main process resolves the path in the open project and checks again that
+ * it stays inside.
+ */
+
+import { isElectronEnv } from './git/types';
+
+/**
+ * Whether files can be revealed in this build
+ */
+export function canRevealInFileManager(): boolean {
+  return isElectronEnv();
+}
+
+// Project-relative path without parent references, e.g. requirements/REQ-001.md
+function isProjectPath(path: string): boolean {
+  if (!path || path.startsWith('/') || path.includes('\\') || /^[a-zA-Z]:/.test(path)) {
+    return false;
+  }
+  return !path.split('/').includes('..');
+}
+
+/**
+ * Open the file manager at a project file
+ */
+export async function revealInFileManager(path: string): Promise<void> {
+  if (!isElectronEnv()) {
+    throw new Error('Revealing files is only available in the desktop app');
+  }
+  if (!isProjectPath(path)) {
+    throw new Error(`Not a path inside the project: ${path}`);
+  }
+  const result = await window.electronAPI!.shell.revealInFileManager(path);
+  if (result.error) {
+    throw new Error(result.error);
+  }
+}
+
+export const revealService = {
+  canRevealInFileManager,
+  revealInFileManager,
+};
@@ -0,0 +1,143 @@
+/**
+ * Saved Query Service
+ *
+ * Keeps named artifact queries in the project config so a team shares views
+ * such as "high-priority unapproved requirements without tests", and runs
+ * them against the artifacts and links on disk (see artifactQueryUtils).
+ */
+
+import type { ArtifactQuery, Project, SavedQuery } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { projectConfigService } from './projectConfigService';
+import { REFERENCE_SOURCE_TYPES } from './referenceValidationService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import {
+  buildLinkIndex,
+  compileArtifactQuery,
+  validateArtifactQuery,
+  type QueryableArtifact,
+} from '../utils/artifactQueryUtils';
+
+const SAVED_QUERIES_SECTION = 'saved-queries';
+
+// Artifact types a query can search
+export const QUERY_TYPES: readonly string[] = REFERENCE_SOURCE_TYPES;
+
+interface SavedQuerySettings {
+  queries: SavedQuery[];
+}
+
+export interface QueryResult {
+  id: string;
+  type: string;
+  title: string;
+  status?: string;
+  priority?: string;
+}
+
+/**
+ * The saved queries, in the order they were added
+ */
+export async function getSavedQueries(): Promise<SavedQuery[]> {
+  const { queries } = await projectConfigService.getSection<SavedQuerySettings>(
+    SAVED_QUERIES_SECTION,
+    { queries: [] }
+  );
+  return Array.isArray(queries) ? queries : [];
+}
+
+/**
+ * Add a query, or replace the one with the same name
+ */
+export async function saveQuery(saved: SavedQuery): Promise<void> {
+  const name = saved.name.trim();
+  if (!name) throw new Error('Query name must not be empty');
+  const errors = validateArtifactQuery(saved.query, QUERY_TYPES);
+  if (errors.length > 0) {
+    throw new Error(`Invalid query ${name}: ${errors.join('; ')}`);
+  }
+
+  const queries = await getSavedQueries();
+  const index = queries.findIndex((query) => query.name === name);
+  const entry = { ...saved, name };
+  if (index === -1) queries.push(entry);
+  else queries[index] = entry;
+  await projectConfigService.setSection(
+    SAVED_QUERIES_SECTION,
+    { queries },
+    `${index === -1 ? 'Add' : 'Update'} saved query ${name}`
+  );
+}
+
+/**
+ * Remove a saved query; unknown names are ignored
+ */
+export async function deleteSavedQuery(name: string): Promise<void> {
+  const queries = await getSavedQueries();
+  const remaining = queries.filter((query) => query.name !== name);
+  if (remaining.length === queries.length) return;
+  await projectConfigService.setSection(
+    SAVED_QUERIES_SECTION,
+    { queries: remaining },
+    `Delete saved query ${name}`
+  );
+}
+
+/**
+ * Artifacts matching the query, optionally restricted to a project's
+ * artifacts and the links visible in it
+ */
+export async function runQuery(query: ArtifactQuery, projectId?: string): Promise<QueryResult[]> {
+  const errors = validateArtifactQuery(query, QUERY_TYPES);
+  if (errors.length > 0) throw new Error(`Invalid query: ${errors.join('; ')}`);
+
+  const project = projectId
+    ? ((await ALL_ARTIFACT_SERVICES.projects.load(projectId)) as Project | null)
+    : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const links = projectId
+    ? await diskLinkService.getLinksForProject(projectId)
+    : await diskLinkService.getAllLinks();
+  const matches = compileArtifactQuery(query, buildLinkIndex(links));
+
+  const results: QueryResult[] = [];
+  for (const type of query.types) {
+    const membershipField = PROJECT_MEMBERSHIP[type];
+    const memberIds =
+      project && membershipField ? new Set((project[membershipField] as string[]) || []) : null;
+    const artifacts: QueryableArtifact[] = await ALL_ARTIFACT_SERVICES[type].loadAll();
+    artifacts
+      .filter((artifact) => (!memberIds || memberIds.has(artifact.id)) && matches(artifact))
+      .forEach((artifact) =>
+        results.push({
+          id: artifact.id,
+          type,
+          title: artifact.title ?? artifact.name ?? artifact.id,
+          status: artifact.status,
+          priority: artifact.priority,
+        })
+      );
+  }
+  return results;
+}
+
+/**
+ * Run a saved query by name
+ */
+export async function runSavedQuery(name: string, projectId?: string): Promise<QueryResult[]> {
+  const saved = (await getSavedQueries()).find((query) => query.name === name);
+  if (!saved) throw new Error(`Saved query not found: ${name}`);
+  return runQuery(saved.query, projectId);
+}
+
+export const savedQueryService = {
+  getSavedQueries,
+  saveQuery,
+  deleteSavedQuery,
+  runQuery,
+  runSavedQuery,
+};
@@ -0,0 +1,223 @@
+/**
+ * Search and Replace Service
+ *
+ * Project-wide regular expression replace over the text fields of artifacts,
+ * e.g. renaming a product term everywhere. A dry run lists every changed line
+ * for review; applying writes all changed artifacts under the write journal,
+ * so either all of them change or none do, and commits them together.
+ */
+
+import type { Project } from '../types';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { LINKABLE_TYPES } from './danglingLinkService';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { runJournaled } from './writeJournalService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { incrementRevision } from '../utils/revisionUtils';
+
+// Free-text fields; IDs, statuses, dates and references are never touched
+export const REPLACEABLE_FIELDS = [
+  'title',
+  'text',
+  'description',
+  'rationale',
+  'comments',
+  'precondition',
+  'postcondition',
+  'preconditions',
+  'postconditions',
+  'mainFlow',
+  'alternativeFlows',
+  'steps',
+  'expectedResult',
+  'content',
+  'mitigation',
+  'contingency',
+];
+
+export interface SearchReplaceScope {
+  types?: string[]; // Artifact types to search; all linkable types when omitted
+  projectId?: string; // Only artifacts of this project
+  fields?: string[]; // Subset of REPLACEABLE_FIELDS
+  caseSensitive?: boolean;
+}
+
+export interface ReplacementLine {
+  field: string;
+  line: number; // 1-based line within the field
+  before: string;
+  after: string;
+}
+
+export interface ArtifactReplacement {
+  artifactId: string;
+  type: string;
+  path: string;
+  matchCount: number;
+  lines: ReplacementLine[];
+}
+
+export interface SearchReplaceResult {
+  artifacts: ArtifactReplacement[];
+  matchCount: number;
+  applied: boolean;
+}
+
+interface ReplaceableArtifact {
+  id: string;
+  revision?: string;
+  lastModified: number;
+  [field: string]: unknown;
+}
+
+interface PlannedUpdate {
+  type: string;
+  artifact: ReplaceableArtifact;
+  updates: Record<string, string>;
+}
+
+/**
+ * The compiled pattern, or an error for patterns that are invalid or could
+ * match empty text
+ */
+export function compileSearchPattern(pattern: string, caseSensitive = false): RegExp {
+  let regex: RegExp;
+  try {
+    regex = new RegExp(pattern, caseSensitive ? 'g' : 'gi');
+  } catch (err) {
+    throw new Error(`Invalid pattern: ${err instanceof Error ? err.message : String(err)}`);
+  }
+  if (regex.test('')) {
+    throw new Error(`Pattern must not match empty text: ${pattern}`);
+  }
+  regex.lastIndex = 0;
+  return regex;
+}
+
+function changedLines(field: string, before: string, after: string): ReplacementLine[] {
+  const oldLines = before.split('\n');
+  const newLines = after.split('\n');
+  if (oldLines.length !== newLines.length) {
+    return [{ field, line: 1, before, after }];
+  }
+  return oldLines
+    .map((line, i) => ({ field, line: i + 1, before: line, after: newLines[i] }))
+    .filter((entry) => entry.before !== entry.after);
+}
+
+/**
+ * The replacements in one artifact's text fields, with the updated fields;
+ * null when nothing matches
+ */
+export function planArtifactReplacement(
+  artifact: ReplaceableArtifact,
+  regex: RegExp,
+  replacement: string,
+  fields: string[]
+): { updates: Record<string, string>; matchCount: number; lines: ReplacementLine[] } | null {
+  const updates: Record<string, string> = {};
+  const lines: ReplacementLine[] = [];
+  let matchCount = 0;
+  for (const field of fields) {
+    const value = artifact[field];
+    if (typeof value !== 'string') continue;
+    const count = value.match(regex)?.length ?? 0;
+    if (count === 0) continue;
+    const replaced = value.replace(regex, replacement);
+    matchCount += count;
+    if (replaced === value) continue;
+    updates[field] = replaced;
+    lines.push(...changedLines(field, value, replaced));
+  }
+  return matchCount > 0 ? { updates, matchCount, lines } : null;
+}
+
+async function loadScopeArtifacts(
+  scope: SearchReplaceScope
+): Promise<{ type: string; artifacts: ReplaceableArtifact[] }[]> {
+  const types = scope.types ?? LINKABLE_TYPES;
+  const unknown = types.filter((type) => !LINKABLE_TYPES.includes(type));
+  if (unknown.length > 0) throw new Error(`Unknown artifact type: ${unknown.join(', ')}`);
+
+  const project = scope.projectId
+    ? ((await ALL_ARTIFACT_SERVICES.projects.load(scope.projectId)) as Project | null)
+    : null;
+  if (scope.projectId && !project) {
+    throw new Error(`Project not found: ${scope.projectId}`);
+  }
+
+  return Promise.all(
+    types.map(async (type) => {
+      const membershipField = PROJECT_MEMBERSHIP[type];
+      const memberIds =
+        project && membershipField ? new Set((project[membershipField] as string[]) || []) : null;
+      const artifacts: ReplaceableArtifact[] = await ALL_ARTIFACT_SERVICES[type].loadAll();
+      return { type, artifacts: artifacts.filter((a) => !memberIds || memberIds.has(a.id)) };
+    })
+  );
+}
+
+/**
+ * Replace pattern matches in the text fields of every artifact in scope.
+ * With dryRun (the default) nothing is written and the result previews the
+ * changes; otherwise all changed artifacts are saved as one journaled batch
+ * with a single commit.
+ */
+export async function searchReplace(
+  pattern: string,
+  replacement: string,
+  scope: SearchReplaceScope = {},
+  dryRun: boolean = true
+): Promise<SearchReplaceResult> {
+  const regex = compileSearchPattern(pattern, scope.caseSensitive);
+  const fields = scope.fields ?? REPLACEABLE_FIELDS;
+  const unknownFields = fields.filter((field) => !REPLACEABLE_FIELDS.includes(field));
+  if (unknownFields.length > 0) {
+    throw new Error(`Fields cannot be replaced in: ${unknownFields.join(', ')}`);
+  }
+
+  const planned: PlannedUpdate[] = [];
+  const result: SearchReplaceResult = { artifacts: [], matchCount: 0, applied: false };
+  for (const { type, artifacts } of await loadScopeArtifacts(scope)) {
+    for (const artifact of artifacts) {
+      const plan = planArtifactReplacement(artifact, regex, replacement, fields);
+      if (!plan) continue;
+      result.artifacts.push({
+        artifactId: artifact.id,
+        type,
+        path: `${ARTIFACT_CONFIG[type].folder}/${artifact.id}.md`,
+        matchCount: plan.matchCount,
+        lines: plan.lines,
+      });
+      result.matchCount += plan.matchCount;
+      if (Object.keys(plan.updates).length > 0) {
+        planned.push({ type, artifact, updates: plan.updates });
+      }
+    }
+  }
+  if (dryRun || planned.length === 0) return result;
+
+  const now = Date.now();
+  await runJournaled(
+    'Search and replace',
+    async (journal) => {
+      for (const { type, artifact, updates } of planned) {
+        await journal.record(`${ARTIFACT_CONFIG[type].folder}/${artifact.id}.md`);
+        await ALL_ARTIFACT_SERVICES[type].save({
+          ...artifact,
+          ...updates,
+          revision: incrementRevision(artifact.revision || '01'),
+          lastModified: now,
+        });
+      }
+    },
+    `Replace "${pattern}" with "${replacement}" in ${planned.length} artifact(s)`
+  );
+  return { ...result, applied: true };
+}
+
+export const searchReplaceService = {
+  compileSearchPattern,
+  planArtifactReplacement,
+  searchReplace,
+};
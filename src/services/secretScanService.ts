@@ -0,0 +1,88 @@
+/**
+ * Secret Scan Service
+ *
+ * Checks the files of a commit for text that looks like credentials before
+ * they are committed (see secretScanUtils). With the blockSecretCommits app
+ * setting on (the default) such a commit is refused with the offending files
+ * and lines; with it off the commit goes ahead and a secrets-detected event
+ * tells the UI to warn. Encrypted files are not scanned.
+ */
+
+import { appSettingsService } from './appSettingsService';
+import { fileSystemService } from './fileSystemService';
+import { isEncryptedContent } from '../utils/encryptionUtils';
+import {
+  formatSecretFindings,
+  scanForSecrets,
+  SECRET_ALLOW_MARKER,
+  type SecretFinding,
+} from '../utils/secretScanUtils';
+import { debug } from '../utils/debug';
+
+// Event dispatched on window with the findings as detail when a commit goes ahead anyway
+export const SECRETS_DETECTED_EVENT = 'secrets-detected';
+
+/**
+ * Thrown when a commit is refused because of possible credentials
+ */
+export class SecretsDetectedError extends Error {
+  findings: SecretFinding[];
+
+  constructor(findings: SecretFinding[]) {
+    super(
+      `Commit blocked, possible credentials in ${formatSecretFindings(findings)}. ` +
+        `Remove them, or add ${SECRET_ALLOW_MARKER} to a line that holds no secret.`
+    );
+    this.name = 'SecretsDetectedError';
+    this.findings = findings;
+  }
+}
+
+/**
+ * Possible credentials in the given files; deleted, unreadable and encrypted
+ * files are skipped
+ */
+export async function scanFilesForSecrets(paths: string[]): Promise<SecretFinding[]> {
+  const findings: SecretFinding[] = [];
+  for (const path of paths) {
+    let content: string | null;
+    try {
+      content = await fileSystemService.readFile(path);
+    } catch (err) {
+      debug.warn(`[SecretScanService] Could not read ${path}:`, err);
+      continue;
+    }
+    if (!content || isEncryptedContent(content)) continue;
+    findings.push(...scanForSecrets(path, content));
+  }
+  return findings;
+}
+
+/**
+ * Scan the files of a commit; throws SecretsDetectedError when the commit
+ * should not go ahead, otherwise resolves to the findings to warn about
+ */
+export async function checkCommitForSecrets(paths: string[]): Promise<SecretFinding[]> {
+  const findings = await scanFilesForSecrets(paths);
+  if (findings.length === 0) return findings;
+
+  const { blockSecretCommits } = await appSettingsService.getAppSettings().catch((err) => {
+    debug.warn('[SecretScanService] Could not read settings, blocking:', err);
+    return { blockSecretCommits: true };
+  });
+  if (blockSecretCommits) {
+    throw new SecretsDetectedError(findings);
+  }
+
+  const where = formatSecretFindings(findings);
+  debug.warn(`[SecretScanService] Committing possible credentials in ${where}`);
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent(SECRETS_DETECTED_EVENT, { detail: findings }));
+  }
+  return findings;
+}
+
+export const secretScanService = {
+  scanFilesForSecrets,
+  checkCommitForSecrets,
+};
@@ -0,0 +1,68 @@
+/**
+ * Secret Service
+ *
+ * Stores secrets by name (API tokens for git remotes, Jira, GitHub, GitLab,
+ * webhook secrets, encryption keys) so no integration keeps them in config
+ * files or the repository. Electron encrypts them with safeStorage, whose key
+ * lives in the platform keychain (macOS Keychain, Windows DPAPI, libsecret or
+ * KWallet on Linux); without a keychain, storing fails rather than writing
+ * plaintext. The browser build falls back to localStorage.
+ */
+
+import { isElectronEnv } from './git/types';
+
+const STORAGE_PREFIX = 'integration_token_';
+const SECRET_NAME_PATTERN = /^[a-z0-9-]+$/;
+
+function checkName(name: string): void {
+  if (!SECRET_NAME_PATTERN.test(name)) {
+    throw new Error(`Invalid secret name: ${name}`);
+  }
+}
+
+/**
+ * Read a stored secret; null when none is set
+ */
+export async function getSecret(name: string): Promise<string | null> {
+  checkName(name);
+  if (isElectronEnv()) {
+    const result = await window.electronAPI!.secure.getToken(name);
+    if (result.error) throw new Error(result.error);
+    return result.token ?? null;
+  }
+  return typeof localStorage !== 'undefined'
+    ? localStorage.getItem(`${STORAGE_PREFIX}${name}`)
+    : null;
+}
+
+/**
+ * Store a secret, replacing any previous one
+ */
+export async function storeSecret(name: string, value: string): Promise<void> {
+  checkName(name);
+  if (isElectronEnv()) {
+    const result = await window.electronAPI!.secure.setToken(value, name);
+    if (result.error) throw new Error(result.error);
+    return;
+  }
+  localStorage.setItem(`${STORAGE_PREFIX}${name}`, value);
+}
+
+/**
+ * Remove a stored secret
+ */
+export async function deleteSecret(name: string): Promise<void> {
+  checkName(name);
+  if (isElectronEnv()) {
+    const result = await window.electronAPI!.secure.removeToken(name);
+    if (result.error) throw new Error(result.error);
+    return;
+  }
+  localStorage.removeItem(`${STORAGE_PREFIX}${name}`);
+}
+
+export const secretService = {
+  getSecret,
+  storeSecret,
+  deleteSecret,
+};
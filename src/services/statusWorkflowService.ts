@@ -0,0 +1,203 @@
+/**
+ * Status Workflow Service
+ *
+ * Enforces the artifact status lifecycle configured in the project config
+ * (default: draft → in-review → approved → obsolete). Transitions record who
+ * made them and when in the artifact frontmatter and are committed right away.
+ * Status changes made while editing an artifact go through here as well (see
+ * changeArtifactStatus).
+ */
+
+import type { StatusWorkflowConfig, User } from '../types';
+import { ARTIFACT_CONFIG, getTypeFromId } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES, userService } from './artifactServices';
+import { appSettingsService } from './appSettingsService';
+import { getActiveAuthorProfile } from './authorProfileService';
+import { diskProjectService } from './diskProjectService';
+import { checkPermission } from './permissionService';
+import { projectConfigService } from './projectConfigService';
+import { realGitService } from './realGitService';
+import { debug } from '../utils/debug';
+
+// Artifact types whose status follows the workflow
+export const WORKFLOW_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+const WORKFLOW_SECTION = 'workflow';
+const APPROVED_STATUS = 'approved';
+
+export const DEFAULT_STATUS_WORKFLOW: StatusWorkflowConfig = {
+  statuses: ['draft', 'in-review', 'approved', 'obsolete'],
+  initialStatus: 'draft',
+  transitions: {
+    draft: ['in-review', 'obsolete'],
+    'in-review': ['approved', 'draft'],
+    approved: ['obsolete'],
+    obsolete: [],
+  },
+};
+
+export interface WorkflowArtifact {
+  id: string;
+  status?: string;
+  statusChangedBy?: string;
+  statusChangedAt?: number;
+  lastModified: number;
+}
+
+/**
+ * Check a workflow config for unknown statuses; returns a list of problems
+ */
+export function validateStatusWorkflow(config: StatusWorkflowConfig): string[] {
+  const errors: string[] = [];
+  const statuses = new Set(config.statuses);
+
+  if (config.statuses.length === 0) errors.push('Workflow has no statuses');
+  if (!statuses.has(config.initialStatus)) {
+    errors.push(`Initial status "${config.initialStatus}" is not a workflow status`);
+  }
+  Object.entries(config.transitions).forEach(([from, targets]) => {
+    if (!statuses.has(from)) errors.push(`Transition from unknown status "${from}"`);
+    targets
+      .filter((to) => !statuses.has(to))
+      .forEach((to) => errors.push(`Transition from "${from}" to unknown status "${to}"`));
+  });
+
+  return errors;
+}
+
+/**
+ * Statuses an artifact may move to from its current status.
+ * Artifacts without a status start from the initial status; statuses that are
+ * not part of the workflow (legacy values) may move to any workflow status.
+ */
+export function getAllowedTransitions(config: StatusWorkflowConfig, from?: string): string[] {
+  const current = from || config.initialStatus;
+  if (!config.statuses.includes(current)) {
+    return [...config.statuses];
+  }
+  return config.transitions[current] || [];
+}
+
+/**
+ * Load the configured workflow, falling back to the default
+ */
+export async function getStatusWorkflow(): Promise<StatusWorkflowConfig> {
+  return projectConfigService.getSection(WORKFLOW_SECTION, DEFAULT_STATUS_WORKFLOW);
+}
+
+/**
+ * Validate and store the workflow in the project config
+ */
+export async function setStatusWorkflow(config: StatusWorkflowConfig): Promise<void> {
+  const errors = validateStatusWorkflow(config);
+  if (errors.length > 0) {
+    throw new Error(`Invalid workflow: ${errors.join('; ')}`);
+  }
+  await projectConfigService.setSection(WORKFLOW_SECTION, config, 'Update status workflow');
+}
+
+/**
+ * Display name of the current user, used as author of workflow transitions:
+ * the project's active author profile, else the selected user
+ */
+export async function getCurrentUserName(): Promise<string> {
+  const profile = await getActiveAuthorProfile();
+  if (profile) return profile.name;
+  const userId = await diskProjectService.getCurrentUserId();
+  if (!userId) return (await appSettingsService.getAppSettings()).defaultAuthor || 'Tracyfy User';
+  const user: User | null = await userService.load(userId);
+  return user?.name || userId;
+}
+
+/**
+ * Move an artifact (given by its repo-relative path, e.g. requirements/REQ-001.md)
+ * to a new status. Illegal transitions are rejected, as are approvals by
+ * anyone without the approver role. The change is committed with the acting
+ * user as author.
+ */
+export async function transitionArtifact<T extends WorkflowArtifact>(
+  path: string,
+  newStatus: string,
+  changedBy?: string
+): Promise<T> {
+  const id = (path.split('/').pop() || '').replace(/\.md$/, '');
+  const typeKey = getTypeFromId(id);
+  const expectedPath = ARTIFACT_CONFIG[typeKey] && `${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`;
+  if (!WORKFLOW_TYPES.includes(typeKey) || path.replace(/^\/+/, '') !== expectedPath) {
+    throw new Error(`Not a workflow artifact path: ${path}`);
+  }
+
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const artifact: T | null = await service.load(id);
+  if (!artifact) {
+    throw new Error(`Artifact not found: ${path}`);
+  }
+
+  const workflow = await getStatusWorkflow();
+  if (!workflow.statuses.includes(newStatus)) {
+    throw new Error(`Unknown status "${newStatus}"`);
+  }
+  const from = artifact.status || workflow.initialStatus;
+  if (!getAllowedTransitions(workflow, from).includes(newStatus)) {
+    throw new Error(`Transition from "${from}" to "${newStatus}" is not allowed for ${id}`);
+  }
+  if (newStatus === APPROVED_STATUS) {
+    await checkPermission('approve');
+  }
+
+  const author = changedBy || (await getCurrentUserName());
+  const now = Date.now();
+  const updated: T = {
+    ...artifact,
+    status: newStatus,
+    statusChangedBy: author,
+    statusChangedAt: now,
+    lastModified: now,
+  };
+
+  await service.save(updated, undefined, newStatus === APPROVED_STATUS ? 'approve' : 'edit');
+  await realGitService.commitFile(expectedPath, `${id}: ${from} → ${newStatus}`, author);
+  debug.log(`[StatusWorkflowService] ${id}: ${from} → ${newStatus} by ${author}`);
+  return updated;
+}
+
+/**
+ * Apply a status picked while editing an artifact. Workflow artifacts move
+ * through transitionArtifact; others (e.g. documents) are saved directly, but
+ * still need the approver role to become approved. Returns the updated
+ * artifact, or null when its status is already the given one.
+ */
+export async function changeArtifactStatus<T extends WorkflowArtifact>(
+  id: string,
+  newStatus: string
+): Promise<T | null> {
+  const typeKey = getTypeFromId(id);
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  const artifact: T | null = service ? await service.load(id) : null;
+  if (!artifact) {
+    throw new Error(`Artifact not found: ${id}`);
+  }
+
+  if (WORKFLOW_TYPES.includes(typeKey)) {
+    const workflow = await getStatusWorkflow();
+    if ((artifact.status || workflow.initialStatus) === newStatus) return null;
+    return transitionArtifact<T>(`${ARTIFACT_CONFIG[typeKey].folder}/${id}.md`, newStatus);
+  }
+
+  if (artifact.status === newStatus) return null;
+  if (newStatus === APPROVED_STATUS) {
+    await checkPermission('approve');
+  }
+  const updated: T = { ...artifact, status: newStatus, lastModified: Date.now() };
+  await service.save(updated, undefined, newStatus === APPROVED_STATUS ? 'approve' : 'edit');
+  return updated;
+}
+
+export const statusWorkflowService = {
+  getStatusWorkflow,
+  setStatusWorkflow,
+  getAllowedTransitions,
+  validateStatusWorkflow,
+  transitionArtifact,
+  changeArtifactStatus,
+};
@@ -0,0 +1,136 @@
+/**
+ * Suspect Link Service
+ *
+ * When an artifact is changed and committed, links to and from it can no longer
+ * be trusted without review. This service flags those links as "suspect" and
+ * lets users list and re-confirm them. Flagging happens for every commit (see
+ * gitCoreService.commitFiles), and the flagged link files go into that commit.
+ */
+
+import type { Link } from '../types';
+import { diskLinkService } from './diskLinkService';
+import type { WriteJournal } from './writeJournalService';
+import { propagatesImpact } from '../utils/linkTypes';
+import { debug } from '../utils/debug';
+
+// Folders whose artifacts take part in traceability links
+export const SUSPECT_TRACKED_FOLDERS = [
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+  'documents',
+];
+
+function linkPath(linkId: string): string {
+  return `links/${linkId}.md`;
+}
+
+/**
+ * Get the artifact ID for a tracked artifact file path (e.g. requirements/REQ-001.md)
+ */
+export function getTrackedArtifactId(path: string): string | null {
+  const match = path.match(/^([^/]+)\/([^/]+)\.md$/);
+  if (!match || !SUSPECT_TRACKED_FOLDERS.includes(match[1])) return null;
+  return match[2];
+}
+
+/**
+ * Flag every active link to or from an artifact as suspect, except those
+ * whose type does not propagate impact (e.g. "related to"). Link files are
+ * recorded in the journal, when given, before they change.
+ * Returns the links that were newly flagged.
+ */
+export async function markLinksSuspect(
+  artifactId: string,
+  journal?: WriteJournal
+): Promise<Link[]> {
+  const links = await diskLinkService.queryLinks({ artifactId });
+  const now = Date.now();
+  const flagged: Link[] = [];
+
+  for (const link of links) {
+    if (link.suspect || !propagatesImpact(link.type)) continue;
+    const updated: Link = { ...link, suspect: true, suspectSince: now, lastModified: now };
+    await journal?.record(linkPath(link.id));
+    await diskLinkService.save(updated);
+    flagged.push(updated);
+  }
+
+  if (flagged.length > 0) {
+    debug.log(
+      `[SuspectLinkService] Marked ${flagged.length} link(s) suspect after ${artifactId} changed`
+    );
+  }
+  return flagged;
+}
+
+/**
+ * Flag the links of the tracked artifacts among files modified by a commit.
+ * Returns the paths of the link files that changed, so they can be committed
+ * together with the edit.
+ */
+export async function flagLinksOfModifiedFiles(
+  paths: string[],
+  journal?: WriteJournal
+): Promise<string[]> {
+  const artifactIds = new Set(paths.map(getTrackedArtifactId).filter((id) => id !== null));
+  const flagged: Link[] = [];
+  for (const artifactId of artifactIds) {
+    flagged.push(...(await markLinksSuspect(artifactId, journal)));
+  }
+
+  if (flagged.length > 0 && typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent('links-flagged-suspect', { detail: { links: flagged } }));
+  }
+  return [...new Set(flagged.map((link) => linkPath(link.id)))];
+}
+
+/**
+ * List suspect links, optionally limited to a project or an artifact
+ */
+export async function getSuspectLinks(
+  options: { projectId?: string; artifactId?: string } = {}
+): Promise<Link[]> {
+  const links = await diskLinkService.queryLinks(options);
+  return links.filter((link) => link.suspect);
+}
+
+/**
+ * Re-confirm a link, clearing its suspect flag
+ */
+export async function clearSuspect(linkId: string): Promise<Link | null> {
+  const link = await diskLinkService.getLinkById(linkId);
+  if (!link || !link.suspect) return link;
+
+  const updated: Link = {
+    ...link,
+    suspect: undefined,
+    suspectSince: undefined,
+    lastModified: Date.now(),
+  };
+  return diskLinkService.save(updated);
+}
+
+/**
+ * Re-confirm all suspect links touching an artifact
+ */
+export async function clearSuspectForArtifact(artifactId: string): Promise<Link[]> {
+  const suspectLinks = await getSuspectLinks({ artifactId });
+  const cleared: Link[] = [];
+  for (const link of suspectLinks) {
+    const updated = await clearSuspect(link.id);
+    if (updated) cleared.push(updated);
+  }
+  return cleared;
+}
+
+export const suspectLinkService = {
+  getTrackedArtifactId,
+  markLinksSuspect,
+  flagLinksOfModifiedFiles,
+  getSuspectLinks,
+  clearSuspect,
+  clearSuspectForArtifact,
+};
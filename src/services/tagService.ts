@@ -0,0 +1,129 @@
+/**
+ * Tag Service
+ *
+ * Manages the `tags` frontmatter array of artifacts: listing the tags in use,
+ * adding/removing tags and querying artifacts by tag expressions.
+ */
+
+import type { Project } from '../types';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { normalizeTag, parseTagQuery, matchesTagQuery } from '../utils/tagQueryUtils';
+import { ALL_ARTIFACT_SERVICES, projectService } from './artifactServices';
+import { PROJECT_MEMBERSHIP } from './traceMatrixService';
+import { debug } from '../utils/debug';
+
+// Artifact types that carry tags
+export const TAGGABLE_TYPES = ['requirements', 'usecases', 'testcases', 'information', 'risks'];
+
+export interface TaggedArtifact {
+  id: string;
+  tags?: string[];
+  lastModified: number;
+}
+
+export interface TagCount {
+  tag: string;
+  count: number;
+}
+
+function getService(artifactId: string) {
+  const typeKey = getTypeFromId(artifactId);
+  if (!TAGGABLE_TYPES.includes(typeKey)) {
+    throw new Error(`Artifact type does not support tags: ${artifactId}`);
+  }
+  return ALL_ARTIFACT_SERVICES[typeKey];
+}
+
+/**
+ * Load all taggable artifacts, optionally restricted to a project
+ */
+async function loadTaggedArtifacts(projectId?: string): Promise<TaggedArtifact[]> {
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const lists = await Promise.all(
+    TAGGABLE_TYPES.map(async (typeKey) => {
+      const items: TaggedArtifact[] = await ALL_ARTIFACT_SERVICES[typeKey].loadAll();
+      const membershipField = PROJECT_MEMBERSHIP[typeKey];
+      if (!project || !membershipField) return items;
+      const memberIds = new Set((project[membershipField] as string[]) || []);
+      return items.filter((item) => memberIds.has(item.id));
+    })
+  );
+  return lists.flat();
+}
+
+/**
+ * All tags in use with the number of artifacts carrying them, sorted by tag
+ */
+export async function listTags(projectId?: string): Promise<TagCount[]> {
+  const counts = new Map<string, number>();
+  (await loadTaggedArtifacts(projectId)).forEach((artifact) => {
+    new Set((artifact.tags || []).map(normalizeTag)).forEach((tag) => {
+      counts.set(tag, (counts.get(tag) || 0) + 1);
+    });
+  });
+
+  return [...counts.entries()]
+    .map(([tag, count]) => ({ tag, count }))
+    .sort((a, b) => a.tag.localeCompare(b.tag));
+}
+
+async function updateTags(
+  artifactId: string,
+  update: (current: string[]) => string[]
+): Promise<TaggedArtifact> {
+  const service = getService(artifactId);
+  const artifact: TaggedArtifact | null = await service.load(artifactId);
+  if (!artifact) {
+    throw new Error(`Artifact not found: ${artifactId}`);
+  }
+
+  const current = (artifact.tags || []).map(normalizeTag);
+  const tags = update(current);
+  if (tags.length === current.length && tags.every((tag, i) => tag === current[i])) {
+    return artifact;
+  }
+
+  const updated = { ...artifact, tags, lastModified: Date.now() };
+  await service.save(updated);
+  debug.log(`[TagService] Tags of ${artifactId}: ${tags.join(', ') || '(none)'}`);
+  return updated;
+}
+
+/**
+ * Add tags to an artifact (normalized, duplicates ignored)
+ */
+export async function addTags(artifactId: string, tags: string[]): Promise<TaggedArtifact> {
+  const added = tags.map(normalizeTag).filter((tag) => tag.length > 0);
+  return updateTags(artifactId, (current) => [...new Set([...current, ...added])]);
+}
+
+/**
+ * Remove tags from an artifact
+ */
+export async function removeTags(artifactId: string, tags: string[]): Promise<TaggedArtifact> {
+  const removed = new Set(tags.map(normalizeTag));
+  return updateTags(artifactId, (current) => current.filter((tag) => !removed.has(tag)));
+}
+
+/**
+ * Find artifacts whose tags match an expression (e.g. `safety AND NOT legacy`)
+ */
+export async function queryByTags<T extends TaggedArtifact>(
+  expression: string,
+  projectId?: string
+): Promise<T[]> {
+  const query = parseTagQuery(expression);
+  const artifacts = await loadTaggedArtifacts(projectId);
+  return artifacts.filter((artifact) => matchesTagQuery(query, artifact.tags)) as T[];
+}
+
+export const tagService = {
+  listTags,
+  addTags,
+  removeTags,
+  queryByTags,
+};
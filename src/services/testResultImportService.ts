@@ -0,0 +1,116 @@
+/**
+ * Test Result Import Service
+ *
+ * Imports JUnit/xUnit XML reports from CI: each result is matched to a test
+ * case artifact, and matched test cases get passed or failed as lastResult
+ * with the run time as lastRun. Their workflow status is left alone. All
+ * updates are written under the write journal and go into one commit.
+ */
+
+import type { TestCase } from '../types';
+import { testCaseService } from './artifactServices';
+import { runJournaled } from './writeJournalService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { matchTestCaseId, parseJUnitXml, type JUnitTestResult } from '../utils/junitUtils';
+import { debug } from '../utils/debug';
+
+export interface ImportedTestResult {
+  testCaseId: string;
+  status: 'passed' | 'failed';
+  tests: string[]; // Names of the report entries matched to the test case
+}
+
+export interface TestReportImportSummary {
+  timestamp: number;
+  updated: ImportedTestResult[];
+  unmatched: string[]; // Tests that could not be matched to a test case
+  skipped: string[]; // Matched tests that were skipped and left unchanged
+}
+
+export interface TestReportImportOptions {
+  timestamp?: number; // Time of the CI run, defaults to now
+  commitMessage?: string;
+}
+
+function testLabel(result: JUnitTestResult): string {
+  return result.className ? `${result.className}.${result.name}` : result.name;
+}
+
+/**
+ * Group results by matched test case. A test case fails if any of its tests
+ * failed; test cases whose tests were all skipped are not updated.
+ */
+export function matchTestResults(
+  results: JUnitTestResult[],
+  testCases: { id: string; title: string }[]
+): Omit<TestReportImportSummary, 'timestamp'> {
+  const byTestCase = new Map<string, ImportedTestResult>();
+  const unmatched: string[] = [];
+  const skipped: string[] = [];
+
+  results.forEach((result) => {
+    const testCaseId = matchTestCaseId(result, testCases);
+    if (!testCaseId) {
+      unmatched.push(testLabel(result));
+      return;
+    }
+    if (result.outcome === 'skipped') {
+      skipped.push(testLabel(result));
+      return;
+    }
+
+    const entry: ImportedTestResult = byTestCase.get(testCaseId) ?? {
+      testCaseId,
+      status: 'passed',
+      tests: [],
+    };
+    entry.tests.push(testLabel(result));
+    if (result.outcome === 'failed') entry.status = 'failed';
+    byTestCase.set(testCaseId, entry);
+  });
+
+  const updated = [...byTestCase.values()].sort((a, b) =>
+    a.testCaseId.localeCompare(b.testCaseId, undefined, { numeric: true })
+  );
+  return { updated, unmatched, skipped };
+}
+
+/**
+ * Parse a report, record the results on the matched test cases and commit them
+ */
+export async function importJUnitReport(
+  xml: string,
+  options: TestReportImportOptions = {}
+): Promise<TestReportImportSummary> {
+  const timestamp = options.timestamp ?? Date.now();
+  const results = parseJUnitXml(xml);
+  const testCases: TestCase[] = await testCaseService.loadAll();
+  const summary = { timestamp, ...matchTestResults(results, testCases) };
+  if (summary.updated.length === 0) return summary;
+
+  const byId = new Map(testCases.map((tc) => [tc.id, tc]));
+  const failed = summary.updated.filter((r) => r.status === 'failed').length;
+  await runJournaled(
+    'Import test results',
+    async (journal) => {
+      for (const { testCaseId, status } of summary.updated) {
+        await journal.record(`${ARTIFACT_CONFIG.testcases.folder}/${testCaseId}.md`);
+        await testCaseService.save({
+          ...byId.get(testCaseId)!,
+          lastResult: status,
+          lastRun: timestamp,
+          lastModified: Date.now(),
+        });
+      }
+    },
+    options.commitMessage ??
+      `Import test results: ${summary.updated.length - failed} passed, ${failed} failed`
+  );
+  debug.log(`[TestResultImportService] Updated ${summary.updated.length} test case(s)`);
+  return summary;
+}
+
+export const testResultImportService = {
+  matchTestResults,
+  importJUnitReport,
+};
@@ -0,0 +1,189 @@
+/**
+ * Test Run Service
+ *
+ * Records manual test execution. A test run (testruns/RUN-xxx.json) has a
+ * name, environment and a scope of test cases; testers record a result with
+ * evidence notes per test case while the run is open. Closing the run writes
+ * the results to the test cases (lastResult and lastRun; their workflow
+ * status is left alone). Every change is committed.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { TestRun, TestRunOutcome, TestRunResult } from '../types';
+import { testCaseService } from './artifactServices';
+import { getCurrentUserName } from './statusWorkflowService';
+import { realGitService } from './realGitService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+const TEST_RUNS_DIR = 'testruns';
+
+export interface NewTestRun {
+  name: string;
+  environment?: string;
+  testCaseIds: string[];
+}
+
+export type TestRunSummary = Record<TestRunOutcome | 'notRun', number>;
+
+/**
+ * Count the results of a run by outcome
+ */
+export function summarizeTestRun(run: TestRun): TestRunSummary {
+  const summary: TestRunSummary = { passed: 0, failed: 0, blocked: 0, skipped: 0, notRun: 0 };
+  const byTestCase = new Map(run.results.map((r) => [r.testCaseId, r.outcome]));
+  run.testCaseIds.forEach((id) => {
+    summary[byTestCase.get(id) ?? 'notRun']++;
+  });
+  return summary;
+}
+
+function nextRunId(ids: string[]): string {
+  const max = ids.reduce((highest, id) => {
+    const number = parseInt(id.replace(/^RUN-/, ''), 10);
+    return Number.isNaN(number) ? highest : Math.max(highest, number);
+  }, 0);
+  return `RUN-${String(max + 1).padStart(3, '0')}`;
+}
+
+class TestRunService extends BaseDiskService {
+  private getRunPath(runId: string): string {
+    return `${TEST_RUNS_DIR}/${runId}.json`;
+  }
+
+  /**
+   * A test run by ID, or null if it does not exist
+   */
+  async getRun(runId: string): Promise<TestRun | null> {
+    return this.readJsonFile<TestRun>(this.getRunPath(runId));
+  }
+
+  private async loadOpenRun(runId: string): Promise<TestRun> {
+    const run = await this.getRun(runId);
+    if (!run) {
+      throw new Error(`Test run not found: ${runId}`);
+    }
+    if (run.status !== 'open') {
+      throw new Error(`Test run ${runId} is closed`);
+    }
+    return run;
+  }
+
+  /**
+   * All test runs, newest first
+   */
+  async listRuns(): Promise<TestRun[]> {
+    const files = (await this.listFiles(TEST_RUNS_DIR)).filter((file) => file.endsWith('.json'));
+    const runs = await Promise.all(files.map((file) => this.getRun(file.replace(/\.json$/, ''))));
+    return runs
+      .filter((run): run is TestRun => run !== null)
+      .sort((a, b) => b.createdAt - a.createdAt);
+  }
+
+  /**
+   * Start a test run over the given test cases
+   */
+  async createRun(input: NewTestRun, createdBy?: string): Promise<TestRun> {
+    if (!input.name.trim()) {
+      throw new Error('Test run name is empty');
+    }
+    const testCaseIds = [...new Set(input.testCaseIds)];
+    if (testCaseIds.length === 0) {
+      throw new Error('A test run needs at least one test case');
+    }
+    const existing = new Set((await testCaseService.loadAll()).map((tc) => tc.id));
+    const missing = testCaseIds.filter((id) => !existing.has(id));
+    if (missing.length > 0) {
+      throw new Error(`Unknown test cases: ${missing.join(', ')}`);
+    }
+
+    const files = await this.listFiles(TEST_RUNS_DIR);
+    const run: TestRun = {
+      id: nextRunId(files.map((file) => file.replace(/\.json$/, ''))),
+      name: input.name.trim(),
+      environment: input.environment?.trim() || undefined,
+      testCaseIds,
+      results: [],
+      status: 'open',
+      createdBy: createdBy || (await getCurrentUserName()),
+      createdAt: Date.now(),
+    };
+    await this.writeJsonFile(
+      this.getRunPath(run.id),
+      run,
+      `Create test run ${run.id}: ${run.name}`
+    );
+    debug.log(`[TestRunService] Created ${run.id} with ${testCaseIds.length} test case(s)`);
+    return run;
+  }
+
+  /**
+   * Record (or replace) the result of a test case in an open run
+   */
+  async recordResult(
+    runId: string,
+    testCaseId: string,
+    outcome: TestRunOutcome,
+    notes?: string,
+    executedBy?: string
+  ): Promise<TestRun> {
+    const run = await this.loadOpenRun(runId);
+    if (!run.testCaseIds.includes(testCaseId)) {
+      throw new Error(`${testCaseId} is not part of test run ${runId}`);
+    }
+
+    const result: TestRunResult = {
+      testCaseId,
+      outcome,
+      notes: notes?.trim() || undefined,
+      executedBy: executedBy || (await getCurrentUserName()),
+      executedAt: Date.now(),
+    };
+    const updated: TestRun = {
+      ...run,
+      results: [...run.results.filter((r) => r.testCaseId !== testCaseId), result],
+    };
+    await this.writeJsonFile(this.getRunPath(runId), updated, `${runId}: ${testCaseId} ${outcome}`);
+    return updated;
+  }
+
+  /**
+   * Close a run and apply its results to the test cases in one commit.
+   * Skipped and unexecuted test cases keep their last result.
+   */
+  async closeRun(runId: string, closedBy?: string): Promise<TestRun> {
+    const run = await this.loadOpenRun(runId);
+    const author = closedBy || (await getCurrentUserName());
+    const now = Date.now();
+
+    const testCases = new Map((await testCaseService.loadAll()).map((tc) => [tc.id, tc]));
+    const applied = run.results.filter(
+      (result) => result.outcome !== 'skipped' && testCases.has(result.testCaseId)
+    );
+    for (const result of applied) {
+      const testCase = testCases.get(result.testCaseId)!;
+      await testCaseService.save({
+        ...testCase,
+        lastResult: result.outcome as Exclude<TestRunOutcome, 'skipped'>,
+        lastRun: result.executedAt,
+        lastModified: now,
+      });
+    }
+
+    const closed: TestRun = { ...run, status: 'closed', closedBy: author, closedAt: now };
+    await this.writeJsonFile(this.getRunPath(runId), closed);
+    const summary = summarizeTestRun(closed);
+    await realGitService.commitFiles(
+      [
+        this.getRunPath(runId),
+        ...applied.map((r) => `${ARTIFACT_CONFIG.testcases.folder}/${r.testCaseId}.md`),
+      ],
+      `Close test run ${runId}: ${summary.passed} passed, ${summary.failed} failed`,
+      author
+    );
+    debug.log(`[TestRunService] Closed ${runId}, applied ${applied.length} result(s)`);
+    return closed;
+  }
+}
+
+export const testRunService = new TestRunService();
@@ -0,0 +1,173 @@
+/**
+ * Trace Matrix Service
+ *
+ * Builds a traceability matrix between two artifact types (e.g. requirements ×
+ * test cases) from the standalone link data. Rows are artifacts of the "from"
+ * type, columns are artifacts of the "to" type, and each cell lists the link
+ * types connecting them as seen from the row artifact.
+ */
+
+import type { Link, Project } from '../types';
+import type { LinkType, LinkCategory } from '../utils/linkTypes';
+import { getInverseType, isLinkInCategories, LINK_TYPE_LABELS } from '../utils/linkTypes';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { ALL_ARTIFACT_SERVICES } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+
+export interface TraceMatrixArtifact {
+  id: string;
+  title: string;
+}
+
+export interface TraceMatrixCell {
+  targetId: string;
+  linkTypes: LinkType[]; // Empty when the pair is not linked
+}
+
+export interface TraceMatrixRow {
+  id: string;
+  title: string;
+  cells: TraceMatrixCell[]; // One per column, in column order
+  linkedCount: number; // Number of columns this row is linked to
+}
+
+export interface TraceMatrix {
+  fromType: string;
+  toType: string;
+  columns: TraceMatrixArtifact[];
+  rows: TraceMatrixRow[];
+  coveredRows: number; // Rows with at least one link
+}
+
+// Project fields listing the artifacts of each type that belong to the project
+export const PROJECT_MEMBERSHIP: Partial<Record<string, keyof Project>> = {
+  requirements: 'requirementIds',
+  usecases: 'useCaseIds',
+  testcases: 'testCaseIds',
+  information: 'informationIds',
+  risks: 'riskIds',
+  documents: 'documentIds',
+};
+
+function byIdNumber(a: TraceMatrixArtifact, b: TraceMatrixArtifact): number {
+  return a.id.localeCompare(b.id, undefined, { numeric: true });
+}
+
+/**
+ * Build a trace matrix from already-loaded artifacts and links.
+ * When categories are given, only links of those kinds fill cells.
+ */
+export function buildTraceMatrix(
+  fromType: string,
+  toType: string,
+  rowArtifacts: TraceMatrixArtifact[],
+  columnArtifacts: TraceMatrixArtifact[],
+  links: Link[],
+  categories?: LinkCategory[]
+): TraceMatrix {
+  const rows = [...rowArtifacts].sort(byIdNumber);
+  const columns = [...columnArtifacts].sort(byIdNumber);
+  const rowIds = new Set(rows.map((r) => r.id));
+  const columnIds = new Set(columns.map((c) => c.id));
+
+  // rowId -> columnId -> link types (from the row's perspective)
+  const cellTypes = new Map<string, Map<string, Set<LinkType>>>();
+  const addType = (rowId: string, columnId: string, type: LinkType) => {
+    if (!cellTypes.has(rowId)) cellTypes.set(rowId, new Map());
+    const rowMap = cellTypes.get(rowId)!;
+    if (!rowMap.has(columnId)) rowMap.set(columnId, new Set());
+    rowMap.get(columnId)!.add(type);
+  };
+
+  links.forEach((link) => {
+    if (link.isDeleted) return;
+    if (categories && !isLinkInCategories(link.type, categories)) return;
+    if (rowIds.has(link.sourceId) && columnIds.has(link.targetId)) {
+      addType(link.sourceId, link.targetId, link.type);
+    }
+    if (rowIds.has(link.targetId) && columnIds.has(link.sourceId)) {
+      addType(link.targetId, link.sourceId, getInverseType(link.type));
+    }
+  });
+
+  let coveredRows = 0;
+  const matrixRows: TraceMatrixRow[] = rows.map((row) => {
+    const rowMap = cellTypes.get(row.id);
+    const cells = columns.map((column) => ({
+      targetId: column.id,
+      linkTypes: rowMap?.has(column.id) ? [...rowMap.get(column.id)!].sort() : [],
+    }));
+    const linkedCount = cells.filter((cell) => cell.linkTypes.length > 0).length;
+    if (linkedCount > 0) coveredRows++;
+    return { id: row.id, title: row.title, cells, linkedCount };
+  });
+
+  return { fromType, toType, columns, rows: matrixRows, coveredRows };
+}
+
+/**
+ * Flatten a trace matrix into a header row plus data rows for CSV/Excel export
+ */
+export function traceMatrixToTable(matrix: TraceMatrix): string[][] {
+  const header = ['ID', 'Title', ...matrix.columns.map((c) => c.id)];
+  const body = matrix.rows.map((row) => [
+    row.id,
+    row.title,
+    ...row.cells.map((cell) => cell.linkTypes.map((t) => LINK_TYPE_LABELS[t]).join(', ')),
+  ]);
+  return [header, ...body];
+}
+
+/**
+ * Load artifacts of a type as matrix headers, optionally restricted to a project
+ */
+async function loadMatrixArtifacts(
+  typeKey: string,
+  project: Project | null
+): Promise<TraceMatrixArtifact[]> {
+  const service = ALL_ARTIFACT_SERVICES[typeKey];
+  if (!service || !ARTIFACT_CONFIG[typeKey]) {
+    throw new Error(`Unknown artifact type: ${typeKey}`);
+  }
+
+  const items: { id: string; title?: string; name?: string }[] = await service.loadAll();
+  const membershipField = PROJECT_MEMBERSHIP[typeKey];
+  const memberIds =
+    project && membershipField ? new Set((project[membershipField] as string[]) || []) : null;
+
+  return items
+    .filter((item) => !memberIds || memberIds.has(item.id))
+    .map((item) => ({ id: item.id, title: item.title ?? item.name ?? item.id }));
+}
+
+/**
+ * Generate the trace matrix between two artifact types from disk.
+ * When a project is given, only its artifacts and the links visible to it are used.
+ */
+export async function generateTraceMatrix(
+  fromType: string,
+  toType: string,
+  projectId?: string,
+  categories?: LinkCategory[]
+): Promise<TraceMatrix> {
+  const project = projectId
+    ? ((await ALL_ARTIFACT_SERVICES.projects.load(projectId)) as Project | null)
+    : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const [rowArtifacts, columnArtifacts, links] = await Promise.all([
+    loadMatrixArtifacts(fromType, project),
+    loadMatrixArtifacts(toType, project),
+    projectId ? diskLinkService.getLinksForProject(projectId) : diskLinkService.getAllLinks(),
+  ]);
+
+  return buildTraceMatrix(fromType, toType, rowArtifacts, columnArtifacts, links, categories);
+}
+
+export const traceMatrixService = {
+  buildTraceMatrix,
+  traceMatrixToTable,
+  generateTraceMatrix,
+};
@@ -0,0 +1,130 @@
+/**
+ * Trace Query Service
+ *
+ * Answers questions that take several link hops, such as "which test cases
+ * ultimately verify SYS-004?" (system requirement → derived software
+ * requirements → test cases). Links are followed in both directions and
+ * filtered by their type as seen from the artifact being left, so the
+ * example follows "derives_to" and "verified_by" no matter which end each
+ * link was created from.
+ */
+
+import type { Link } from '../types';
+import { getInverseType, LINK_TYPE_LABELS, type LinkType } from '../utils/linkTypes';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { diskLinkService } from './diskLinkService';
+
+export const DEFAULT_TRACE_DEPTH = 3;
+export const MAX_TRACE_DEPTH = 10;
+
+export interface TraceQuery {
+  startId: string;
+  relations?: LinkType[]; // Link types to follow, as seen from each artifact; all when omitted
+  targetType?: string; // Only report artifacts of this type, e.g. "testcases"
+  maxDepth?: number; // Hops to follow, 1 to MAX_TRACE_DEPTH
+  projectId?: string; // Only follow links visible in this project
+}
+
+export interface TraceQueryHit {
+  artifactId: string;
+  artifactType: string;
+  depth: number;
+  path: string[]; // Artifact IDs from the start to this artifact (inclusive)
+  linkTypes: LinkType[]; // Type of each hop, as seen from the artifact it left
+}
+
+interface Hop {
+  targetId: string;
+  type: LinkType;
+}
+
+/**
+ * Problem with the query, or null when it can be run
+ */
+export function validateTraceQuery(query: TraceQuery): string | null {
+  if (!query.startId) return 'A start artifact is required';
+  const unknown = (query.relations ?? []).filter((type) => !(type in LINK_TYPE_LABELS));
+  if (unknown.length > 0) return `Unknown link type: ${unknown.join(', ')}`;
+  const depth = query.maxDepth ?? DEFAULT_TRACE_DEPTH;
+  if (!Number.isInteger(depth) || depth < 1 || depth > MAX_TRACE_DEPTH) {
+    return `Depth must be between 1 and ${MAX_TRACE_DEPTH}`;
+  }
+  return null;
+}
+
+// Every link seen from both ends, keyed by the artifact it leaves from
+function buildHops(links: Link[], relations?: LinkType[]): Map<string, Hop[]> {
+  const hops = new Map<string, Hop[]>();
+  const add = (fromId: string, hop: Hop) => {
+    if (relations && !relations.includes(hop.type)) return;
+    hops.set(fromId, [...(hops.get(fromId) || []), hop]);
+  };
+  links.forEach((link) => {
+    add(link.sourceId, { targetId: link.targetId, type: link.type });
+    add(link.targetId, { targetId: link.sourceId, type: getInverseType(link.type) });
+  });
+  return hops;
+}
+
+/**
+ * Artifacts reachable from the start artifact through the given links,
+ * each with the shortest path that reaches it, nearest first
+ */
+export function traceArtifacts(links: Link[], query: TraceQuery): TraceQueryHit[] {
+  const maxDepth = query.maxDepth ?? DEFAULT_TRACE_DEPTH;
+  const hops = buildHops(links, query.relations);
+  const reached = new Map<string, TraceQueryHit>();
+  let frontier: TraceQueryHit[] = [
+    {
+      artifactId: query.startId,
+      artifactType: getTypeFromId(query.startId),
+      depth: 0,
+      path: [query.startId],
+      linkTypes: [],
+    },
+  ];
+
+  for (let depth = 1; depth <= maxDepth && frontier.length > 0; depth++) {
+    const next: TraceQueryHit[] = [];
+    frontier.forEach((from) => {
+      (hops.get(from.artifactId) || []).forEach((hop) => {
+        if (hop.targetId === query.startId || reached.has(hop.targetId)) return;
+        const hit: TraceQueryHit = {
+          artifactId: hop.targetId,
+          artifactType: getTypeFromId(hop.targetId),
+          depth,
+          path: [...from.path, hop.targetId],
+          linkTypes: [...from.linkTypes, hop.type],
+        };
+        reached.set(hop.targetId, hit);
+        next.push(hit);
+      });
+    });
+    frontier = next;
+  }
+
+  return [...reached.values()]
+    .filter((hit) => !query.targetType || hit.artifactType === query.targetType)
+    .sort(
+      (a, b) =>
+        a.depth - b.depth || a.artifactId.localeCompare(b.artifactId, undefined, { numeric: true })
+    );
+}
+
+/**
+ * Run a trace query against the links stored on disk
+ */
+export async function runTraceQuery(query: TraceQuery): Promise<TraceQueryHit[]> {
+  const problem = validateTraceQuery(query);
+  if (problem) throw new Error(problem);
+  const links = query.projectId
+    ? await diskLinkService.getLinksForProject(query.projectId)
+    : await diskLinkService.getAllLinks();
+  return traceArtifacts(links, query);
+}
+
+export const traceQueryService = {
+  validateTraceQuery,
+  traceArtifacts,
+  runTraceQuery,
+};
@@ -0,0 +1,162 @@
+/**
+ * Undo Service
+ *
+ * Durable undo/redo for artifact edits. Before each save or delete the file's
+ * current content is stored as a git blob and pushed onto its undo stack;
+ * undo and redo swap contents between the stacks and the working tree. The
+ * stacks and blobs live under a shadow ref (refs/tracyfy/undo) instead of a
+ * branch, so they survive restarts without appearing in the project history.
+ *
+ * Snapshots hold the file exactly as on disk (encrypted when encryption at
+ * rest is on). Restored content is written but not committed, like any edit.
+ */
+
+import { fileSystemService } from './fileSystemService';
+import { realGitService } from './realGitService';
+import type { SnapshotEntry } from './git';
+import { debug } from '../utils/debug';
+
+export const UNDO_REF = 'refs/tracyfy/undo';
+const STACKS_FILE = 'stacks.json';
+const MAX_UNDO_STEPS = 50;
+
+// Blob oids of earlier contents, oldest first; '' marks "file did not exist"
+interface UndoStacks {
+  undo: string[];
+  redo: string[];
+}
+
+let tracking = false;
+let stacks: Record<string, UndoStacks> | null = null;
+// Serializes snapshot writes so stacks are never persisted out of order
+let queue: Promise<unknown> = Promise.resolve();
+
+function enqueue<T>(task: () => Promise<T>): Promise<T> {
+  const result = queue.then(task);
+  queue = result.catch(() => undefined);
+  return result;
+}
+
+async function loadStacks(): Promise<Record<string, UndoStacks>> {
+  if (stacks) return stacks;
+  const commit = await realGitService.resolveRef(UNDO_REF);
+  const content = commit ? await realGitService.readFileAtCommit(STACKS_FILE, commit) : null;
+  stacks = content ? (JSON.parse(content) as Record<string, UndoStacks>) : {};
+  return stacks;
+}
+
+async function persistStacks(all: Record<string, UndoStacks>): Promise<void> {
+  const oids = new Set(
+    Object.values(all)
+      .flatMap((s) => [...s.undo, ...s.redo])
+      .filter(Boolean)
+  );
+  const entries: SnapshotEntry[] = [...oids].map((oid) => ({ path: oid, oid }));
+  entries.push({ path: STACKS_FILE, oid: await realGitService.writeBlob(JSON.stringify(all)) });
+  await realGitService.writeSnapshotRef(UNDO_REF, entries, 'Undo snapshots');
+}
+
+// Current file content as a blob oid ('' when the file does not exist)
+async function snapshot(path: string): Promise<string> {
+  const content = await fileSystemService.readFile(path);
+  return content === null ? '' : realGitService.writeBlob(content);
+}
+
+async function restore(path: string, oid: string): Promise<void> {
+  if (!oid) {
+    await fileSystemService.deleteFile(path);
+    return;
+  }
+  const commit = await realGitService.resolveRef(UNDO_REF);
+  const content = commit ? await realGitService.readFileAtCommit(oid, commit) : null;
+  if (content === null) {
+    throw new Error(`Undo snapshot ${oid} is missing`);
+  }
+  await fileSystemService.writeFile(path, content);
+}
+
+/**
+ * Snapshot a file before it is overwritten or deleted and clear its redo
+ * stack. Does nothing unless tracking is on; never throws, since failing to
+ * record undo must not block the save.
+ */
+export async function recordBeforeChange(path: string): Promise<void> {
+  if (!tracking) return;
+  try {
+    await enqueue(async () => {
+      const all = await loadStacks();
+      const entry = all[path] ?? { undo: [], redo: [] };
+      const oid = await snapshot(path);
+      if (entry.undo[entry.undo.length - 1] !== oid) {
+        entry.undo = [...entry.undo, oid].slice(-MAX_UNDO_STEPS);
+      }
+      entry.redo = [];
+      all[path] = entry;
+      await persistStacks(all);
+    });
+  } catch (err) {
+    debug.warn(`[UndoService] Could not snapshot ${path}:`, err);
+  }
+}
+
+async function step(path: string, from: 'undo' | 'redo'): Promise<boolean> {
+  const to = from === 'undo' ? 'redo' : 'undo';
+  return enqueue(async () => {
+    const all = await loadStacks();
+    const entry = all[path];
+    if (!entry || entry[from].length === 0) return false;
+
+    const target = entry[from][entry[from].length - 1];
+    const current = await snapshot(path);
+    await restore(path, target);
+    entry[from] = entry[from].slice(0, -1);
+    entry[to] = [...entry[to], current].slice(-MAX_UNDO_STEPS);
+    await persistStacks(all);
+    debug.log(`[UndoService] ${from} ${path}`);
+    if (typeof window !== 'undefined') {
+      window.dispatchEvent(new CustomEvent('git-status-changed'));
+    }
+    return true;
+  });
+}
+
+/**
+ * Restore the content a file had before its last save; false when there is
+ * nothing to undo
+ */
+export function undoArtifact(path: string): Promise<boolean> {
+  return step(path, 'undo');
+}
+
+/**
+ * Re-apply the last undone save of a file; false when there is nothing to redo
+ */
+export function redoArtifact(path: string): Promise<boolean> {
+  return step(path, 'redo');
+}
+
+export async function getUndoState(path: string): Promise<{ canUndo: boolean; canRedo: boolean }> {
+  const entry = (await loadStacks())[path];
+  return { canUndo: !!entry?.undo.length, canRedo: !!entry?.redo.length };
+}
+
+/**
+ * Record undo snapshots for saves in the open project until the returned
+ * function is called
+ */
+export function startUndoTracking(): () => void {
+  tracking = true;
+  stacks = null;
+  return () => {
+    tracking = false;
+    stacks = null;
+  };
+}
+
+export const undoService = {
+  recordBeforeChange,
+  undoArtifact,
+  redoArtifact,
+  getUndoState,
+  startUndoTracking,
+};
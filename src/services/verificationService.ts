@@ -0,0 +1,149 @@
+/**
+ * Verification Service
+ *
+ * Derives each requirement's verification state from the last results
+ * (lastResult) recorded on its linked test cases by CI imports or closed test
+ * runs, for the verification dashboard. The workflow status of a test case
+ * plays no part.
+ */
+
+import type { Link, Project, Requirement, TestCase } from '../types';
+import { requirementService, testCaseService, projectService } from './artifactServices';
+import { diskLinkService } from './diskLinkService';
+import { getTestCasesByRequirement } from './coverageService';
+import { groupChildren } from '../utils/hierarchyUtils';
+
+export type VerificationState = 'passed' | 'failed' | 'partial' | 'untested';
+
+export interface RequirementVerification {
+  requirementId: string;
+  state: VerificationState;
+  testCaseIds: string[];
+  passed: number;
+  failed: number;
+  notRun: number; // Linked test cases without a pass/fail result (incl. blocked)
+  rolledUpState: VerificationState; // Own state combined with all child requirements
+}
+
+export interface VerificationRollup {
+  requirements: RequirementVerification[];
+  counts: Record<VerificationState, number>;
+}
+
+/**
+ * Verification state from the results of a requirement's test cases:
+ * failed if any failed, passed if all passed, partial if only some were run
+ * and untested if none were
+ */
+export function getVerificationState(
+  passed: number,
+  failed: number,
+  notRun: number
+): VerificationState {
+  if (failed > 0) return 'failed';
+  if (passed === 0) return 'untested';
+  return notRun > 0 ? 'partial' : 'passed';
+}
+
+/**
+ * Combine the states of a requirement hierarchy: failed if any part failed,
+ * passed or untested if all parts are, and partial (incomplete) otherwise
+ */
+export function combineVerificationStates(states: VerificationState[]): VerificationState {
+  if (states.includes('failed')) return 'failed';
+  if (states.every((state) => state === 'passed')) return 'passed';
+  if (states.every((state) => state === 'untested')) return 'untested';
+  return 'partial';
+}
+
+/**
+ * Compute the verification state of every (non-deleted) requirement, and
+ * roll child states up through the requirement hierarchy. A parent's own
+ * state only counts if it has test cases of its own.
+ */
+export function computeVerificationRollup(
+  requirements: Requirement[],
+  testCases: TestCase[],
+  links: Link[]
+): VerificationRollup {
+  const activeTestCases = testCases.filter((tc) => !tc.isDeleted);
+  const resultById = new Map(activeTestCases.map((tc) => [tc.id, tc.lastResult]));
+  const byRequirement = getTestCasesByRequirement(requirements, activeTestCases, links);
+  const counts: Record<VerificationState, number> = {
+    passed: 0,
+    failed: 0,
+    partial: 0,
+    untested: 0,
+  };
+
+  const activeRequirements = requirements.filter((r) => !r.isDeleted);
+  const results = activeRequirements
+    .map((requirement): RequirementVerification => {
+      const testCaseIds = [...(byRequirement.get(requirement.id) ?? [])].sort((a, b) =>
+        a.localeCompare(b, undefined, { numeric: true })
+      );
+      const passed = testCaseIds.filter((id) => resultById.get(id) === 'passed').length;
+      const failed = testCaseIds.filter((id) => resultById.get(id) === 'failed').length;
+      const notRun = testCaseIds.length - passed - failed;
+      const state = getVerificationState(passed, failed, notRun);
+      counts[state]++;
+      return {
+        requirementId: requirement.id,
+        state,
+        testCaseIds,
+        passed,
+        failed,
+        notRun,
+        rolledUpState: state,
+      };
+    })
+    .sort((a, b) => a.requirementId.localeCompare(b.requirementId, undefined, { numeric: true }));
+
+  const children = groupChildren(activeRequirements);
+  const byId = new Map(results.map((result) => [result.requirementId, result]));
+  const done = new Set<string>();
+  const rollUp = (id: string): VerificationState => {
+    const result = byId.get(id)!;
+    if (done.has(id)) return result.rolledUpState;
+    done.add(id); // Marked before visiting children so parent cycles terminate
+    const childStates = (children.get(id) || []).map((child) => rollUp(child.id));
+    if (childStates.length > 0) {
+      const own = result.testCaseIds.length > 0 ? [result.state] : [];
+      result.rolledUpState = combineVerificationStates([...own, ...childStates]);
+    }
+    return result.rolledUpState;
+  };
+  results.forEach((result) => rollUp(result.requirementId));
+
+  return { requirements: results, counts };
+}
+
+/**
+ * Load artifacts and links and compute the verification rollup,
+ * optionally restricted to a single project
+ */
+export async function getVerificationRollup(projectId?: string): Promise<VerificationRollup> {
+  const project: Project | null = projectId ? await projectService.load(projectId) : null;
+  if (projectId && !project) {
+    throw new Error(`Project not found: ${projectId}`);
+  }
+
+  const [requirements, testCases, links] = await Promise.all([
+    requirementService.loadAll(),
+    testCaseService.loadAll(),
+    projectId ? diskLinkService.getLinksForProject(projectId) : diskLinkService.getAllLinks(),
+  ]);
+
+  return computeVerificationRollup(
+    project ? requirements.filter((r) => project.requirementIds.includes(r.id)) : requirements,
+    project ? testCases.filter((t) => project.testCaseIds.includes(t.id)) : testCases,
+    links
+  );
+}
+
+export const verificationService = {
+  getVerificationState,
+  combineVerificationStates,
+  computeVerificationRollup,
+  getVerificationRollup,
+};
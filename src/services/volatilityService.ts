@@ -0,0 +1,116 @@
+/**
+ * Volatility Service
+ *
+ * Measures how often artifacts change, from their git history: the number of
+ * commits touching each artifact file within a time window, how many people
+ * made them and how long ago the last one was. Requirements that keep
+ * changing are an early warning sign, so the report ranks the most volatile
+ * artifacts first.
+ */
+
+import { BaseDiskService } from './baseDiskService';
+import type { CommitInfo } from '../types';
+import { realGitService } from './realGitService';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { debug } from '../utils/debug';
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+// Upper bound of commits read per artifact file
+const MAX_HISTORY_DEPTH = 1000;
+
+export interface VolatilityOptions {
+  days?: number; // Length of the window ending now (default 90)
+  typeKeys?: string[]; // Artifact types to include (default requirements)
+  limit?: number; // Maximum number of ranked artifacts
+}
+
+export interface ArtifactVolatility {
+  artifactId: string;
+  path: string;
+  changes: number; // Commits within the window
+  authors: string[]; // Distinct authors within the window
+  lastChangedAt: number | null; // Last commit overall, also when outside the window
+  daysSinceLastChange: number | null;
+}
+
+export interface VolatilityReport {
+  since: number;
+  until: number;
+  artifacts: ArtifactVolatility[]; // Most volatile first
+}
+
+/**
+ * Volatility of one artifact from its commits (any order) within [since, until]
+ */
+export function computeArtifactVolatility(
+  artifactId: string,
+  path: string,
+  commits: CommitInfo[],
+  since: number,
+  until: number
+): ArtifactVolatility {
+  const inWindow = commits.filter((c) => c.timestamp >= since && c.timestamp <= until);
+  const changedAt = commits.filter((c) => c.timestamp <= until).map((c) => c.timestamp);
+  const lastChangedAt = changedAt.length > 0 ? Math.max(...changedAt) : null;
+  return {
+    artifactId,
+    path,
+    changes: inWindow.length,
+    authors: [...new Set(inWindow.map((c) => c.author))].sort(),
+    lastChangedAt,
+    daysSinceLastChange:
+      lastChangedAt === null ? null : Math.floor((until - lastChangedAt) / DAY_MS),
+  };
+}
+
+/**
+ * Most volatile first: more changes, then more authors, then most recently
+ * changed
+ */
+export function rankByVolatility(artifacts: ArtifactVolatility[]): ArtifactVolatility[] {
+  return [...artifacts].sort(
+    (a, b) =>
+      b.changes - a.changes ||
+      b.authors.length - a.authors.length ||
+      (b.lastChangedAt ?? 0) - (a.lastChangedAt ?? 0) ||
+      a.artifactId.localeCompare(b.artifactId, undefined, { numeric: true })
+  );
+}
+
+class VolatilityService extends BaseDiskService {
+  /**
+   * Rank the artifacts of the given types by how much they changed in the
+   * last `days` days
+   */
+  async getVolatilityReport(options: VolatilityOptions = {}): Promise<VolatilityReport> {
+    const { days = 90, typeKeys = ['requirements'], limit } = options;
+    if (!(days > 0)) {
+      throw new Error(`Invalid window: ${days} days`);
+    }
+    const until = Date.now();
+    const since = until - days * DAY_MS;
+
+    const artifacts: ArtifactVolatility[] = [];
+    for (const typeKey of typeKeys) {
+      const config = ARTIFACT_CONFIG[typeKey];
+      if (!config) {
+        throw new Error(`Unknown artifact type: ${typeKey}`);
+      }
+      const files = (await this.listFiles(config.folder)).filter((f) => f.endsWith('.md'));
+      for (const file of files) {
+        const path = `${config.folder}/${file}`;
+        const commits = await realGitService.getHistory(path, MAX_HISTORY_DEPTH);
+        artifacts.push(
+          computeArtifactVolatility(file.replace(/\.md$/, ''), path, commits, since, until)
+        );
+      }
+    }
+
+    const ranked = rankByVolatility(artifacts);
+    debug.log(`[VolatilityService] Ranked ${ranked.length} artifacts over ${days} days`);
+    return { since, until, artifacts: limit ? ranked.slice(0, limit) : ranked };
+  }
+}
+
+export const volatilityService = new VolatilityService();
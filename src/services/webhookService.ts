@@ -0,0 +1,188 @@
+/**
+ * Webhook Service
+ *
+ * Outbound webhooks fired when a commit, baseline or approval happens, so
+ * chat, CI or dashboards can react to requirement changes. Hooks (URL and
+ * subscribed events) are app settings of this machine, kept per project
+ * folder, and their secrets are in the secret store under a name that
+ * includes a hash of that folder, so hooks with the same ID in two projects
+ * never share a secret. Neither is read from
+ * the repository, so cloning a project never sends its data to URLs the
+ * user did not set up.
+ *
+ * Each delivery is a JSON POST of { event, timestamp, data }. With a secret,
+ * the body is signed with HMAC-SHA256 in the X-Tracyfy-Signature-256 header
+ * ("sha256=<hex>"), the same scheme GitHub uses.
+ */
+
+import type { Webhook, WebhookEvent } from '../types';
+import { secretService } from './secretService';
+import { httpRequest } from './httpClient';
+import { appSettingsService } from './appSettingsService';
+import { sha256Hex } from '../utils/contentHashUtils';
+import { debug } from '../utils/debug';
+
+export type { Webhook, WebhookEvent };
+
+// Window events (dispatched by the git, baseline and approval services) per webhook event
+const SOURCE_EVENTS: Record<WebhookEvent, string> = {
+  commit: 'git-commit-created',
+  baseline: 'baseline-created',
+  approval: 'artifact-approved',
+};
+
+export const WEBHOOK_EVENTS = Object.keys(SOURCE_EVENTS) as WebhookEvent[];
+
+export interface WebhookDelivery {
+  hookId: string;
+  status?: number;
+  error?: string;
+}
+
+// Secret names allow only [a-z0-9-], so the project folder goes in as a short hash
+async function secretName(id: string): Promise<string> {
+  const project = (await sha256Hex(appSettingsService.getProjectSettingsKey())).slice(0, 16);
+  return `webhook-${project}-${id}`;
+}
+
+async function saveHooks(hooks: Webhook[]): Promise<void> {
+  const { webhooks } = await appSettingsService.getAppSettings();
+  const key = appSettingsService.getProjectSettingsKey();
+  await appSettingsService.updateAppSettings({ webhooks: { ...webhooks, [key]: hooks } });
+}
+
+/**
+ * Hex HMAC-SHA256 of a payload
+ */
+export async function signPayload(payload: string, secret: string): Promise<string> {
+  const encoder = new TextEncoder();
+  const key = await globalThis.crypto.subtle.importKey(
+    'raw',
+    encoder.encode(secret),
+    { name: 'HMAC', hash: 'SHA-256' },
+    false,
+    ['sign']
+  );
+  const signature = await globalThis.crypto.subtle.sign('HMAC', key, encoder.encode(payload));
+  return Array.from(new Uint8Array(signature), (b) => b.toString(16).padStart(2, '0')).join('');
+}
+
+/**
+ * Webhooks set up on this machine for the open project
+ */
+export async function getWebhooks(): Promise<Webhook[]> {
+  const { webhooks } = await appSettingsService.getAppSettings();
+  return webhooks[appSettingsService.getProjectSettingsKey()] ?? [];
+}
+
+/**
+ * Add or update a webhook; a given secret replaces the stored one
+ */
+export async function saveWebhook(hook: Webhook, secret?: string): Promise<void> {
+  if (!/^[a-z0-9-]+$/.test(hook.id)) {
+    throw new Error(`Invalid webhook id: ${hook.id}`);
+  }
+  if (!/^https?:\/\/[^/\s]+/.test(hook.url)) {
+    throw new Error('Webhook URL must be an http(s) URL');
+  }
+  if (hook.events.length === 0 || hook.events.some((e) => !WEBHOOK_EVENTS.includes(e))) {
+    throw new Error(`Webhook events must be some of: ${WEBHOOK_EVENTS.join(', ')}`);
+  }
+
+  const hooks = await getWebhooks();
+  const exists = hooks.some((h) => h.id === hook.id);
+  const updated = exists ? hooks.map((h) => (h.id === hook.id ? hook : h)) : [...hooks, hook];
+  if (secret !== undefined) {
+    const name = await secretName(hook.id);
+    await (secret ? secretService.storeSecret(name, secret) : secretService.deleteSecret(name));
+  }
+  await saveHooks(updated);
+}
+
+export async function deleteWebhook(id: string): Promise<void> {
+  const hooks = await getWebhooks();
+  await secretService.deleteSecret(await secretName(id));
+  await saveHooks(hooks.filter((h) => h.id !== id));
+}
+
+/**
+ * POST one event to a webhook. Failures are returned, not thrown.
+ */
+export async function deliverWebhook(
+  hook: Webhook,
+  event: WebhookEvent | 'ping',
+  data: unknown
+): Promise<WebhookDelivery> {
+  const body = JSON.stringify({ event, timestamp: Date.now(), data });
+  try {
+    const headers: Record<string, string> = {
+      'Content-Type': 'application/json',
+      'X-Tracyfy-Event': event,
+    };
+    const secret = await secretService.getSecret(await secretName(hook.id));
+    if (secret) {
+      headers['X-Tracyfy-Signature-256'] = `sha256=${await signPayload(body, secret)}`;
+    }
+    const response = await httpRequest({
+      integration: 'webhook',
+      url: hook.url,
+      method: 'POST',
+      headers,
+      body,
+    });
+    if (response.status >= 300) {
+      debug.warn(`[WebhookService] ${hook.id} responded ${response.status} to ${event}`);
+    }
+    return { hookId: hook.id, status: response.status };
+  } catch (err) {
+    const error = err instanceof Error ? err.message : String(err);
+    debug.warn(`[WebhookService] Delivering ${event} to ${hook.id} failed:`, error);
+    return { hookId: hook.id, error };
+  }
+}
+
+/**
+ * Send an event to every enabled webhook subscribed to it
+ */
+export async function fireWebhooks(event: WebhookEvent, data: unknown): Promise<WebhookDelivery[]> {
+  const hooks = (await getWebhooks()).filter((h) => h.enabled && h.events.includes(event));
+  return Promise.all(hooks.map((hook) => deliverWebhook(hook, event, data)));
+}
+
+/**
+ * Send a ping event to check a webhook's URL and secret
+ */
+export async function testWebhook(id: string): Promise<WebhookDelivery> {
+  const hook = (await getWebhooks()).find((h) => h.id === id);
+  if (!hook) {
+    throw new Error(`Webhook not found: ${id}`);
+  }
+  return deliverWebhook(hook, 'ping', { hookId: id });
+}
+
+/**
+ * Fire webhooks for commit, baseline and approval events until the
+ * returned function is called
+ */
+export function startWebhooks(): () => void {
+  const listeners = WEBHOOK_EVENTS.map((event) => {
+    const listener = (e: Event) => {
+      fireWebhooks(event, (e as CustomEvent).detail).catch((err) =>
+        debug.warn('[WebhookService] Firing webhooks failed:', err)
+      );
+    };
+    window.addEventListener(SOURCE_EVENTS[event], listener);
+    return () => window.removeEventListener(SOURCE_EVENTS[event], listener);
+  });
+  return () => listeners.forEach((remove) => remove());
+}
+
+export const webhookService = {
+  getWebhooks,
+  saveWebhook,
+  deleteWebhook,
+  deliverWebhook,
+  fireWebhooks,
+  testWebhook,
+  startWebhooks,
+};
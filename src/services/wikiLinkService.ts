@@ -0,0 +1,180 @@
+/**
+ * Wiki Link Service
+ *
+ * Turns [[REQ-001]] references written in artifact text into trace links, so
+ * authors can link while writing. A reference may name the link type
+ * ([[verifies:REQ-001]]); otherwise it becomes a related_to link. Links made
+ * this way are marked with the wiki origin: a sync creates the links that are
+ * missing and removes the wiki links whose reference was deleted from the
+ * text. Links created by hand are never removed, and a reference that an
+ * existing link already covers creates nothing.
+ */
+
+import type { Link, LinkType } from '../types';
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { baseDiskService } from './baseDiskService';
+import { LINKABLE_TYPES } from './danglingLinkService';
+import { diskLinkService } from './diskLinkService';
+import { idService } from './idService';
+import { runJournaled } from './writeJournalService';
+import { parseYamlFrontmatter } from '../utils/markdownBase';
+import { LINK_TYPE_LABELS } from '../utils/linkTypes';
+import { extractWikiLinks } from '../utils/referenceUtils';
+import { findCycleThroughLink, isDecompositionLink } from '../utils/traceCycleUtils';
+import { debug } from '../utils/debug';
+
+export const DEFAULT_WIKI_LINK_TYPE: LinkType = 'related_to';
+
+export type SkippedWikiLinkReason = 'unknown-id' | 'unknown-type' | 'self' | 'cycle';
+
+export interface WikiLinkSpec {
+  sourceId: string;
+  targetId: string;
+  type: LinkType;
+  line: number;
+}
+
+export interface SkippedWikiLink {
+  sourceId: string;
+  targetId: string;
+  linkType?: string;
+  line: number;
+  reason: SkippedWikiLinkReason;
+}
+
+export interface WikiLinkSyncResult {
+  created: WikiLinkSpec[];
+  removed: Link[];
+  skipped: SkippedWikiLink[];
+  applied: boolean;
+}
+
+function linkKey(link: { sourceId: string; targetId: string; type: string }): string {
+  return `${link.sourceId}:${link.targetId}:${link.type}`;
+}
+
+async function collectArtifactIds(): Promise<Set<string>> {
+  const ids = new Set<string>();
+  for (const { folder } of Object.values(ARTIFACT_CONFIG)) {
+    for (const name of await baseDiskService.listFiles(folder)) {
+      if (name.endsWith('.md')) ids.add(name.slice(0, -'.md'.length));
+    }
+  }
+  return ids;
+}
+
+/**
+ * The links the [[ID]] references in one artifact stand for, and the
+ * references that cannot become links
+ */
+export function resolveWikiLinks(
+  sourceId: string,
+  markdown: string,
+  knownIds: Set<string>
+): { specs: WikiLinkSpec[]; skipped: SkippedWikiLink[] } {
+  const specs: WikiLinkSpec[] = [];
+  const skipped: SkippedWikiLink[] = [];
+  for (const { targetId, linkType, line } of extractWikiLinks(markdown)) {
+    const skip = (reason: SkippedWikiLinkReason) =>
+      skipped.push({ sourceId, targetId, ...(linkType && { linkType }), line, reason });
+    if (linkType && !Object.keys(LINK_TYPE_LABELS).includes(linkType)) skip('unknown-type');
+    else if (targetId === sourceId) skip('self');
+    else if (!knownIds.has(targetId)) skip('unknown-id');
+    else {
+      specs.push({
+        sourceId,
+        targetId,
+        type: (linkType as LinkType) ?? DEFAULT_WIKI_LINK_TYPE,
+        line,
+      });
+    }
+  }
+  return { specs, skipped };
+}
+
+/**
+ * Bring the wiki links in line with the [[ID]] references in artifact text.
+ * With dryRun nothing is written and the result lists what would change;
+ * otherwise the new and removed link files are committed together.
+ */
+export async function syncWikiLinks(dryRun: boolean = false): Promise<WikiLinkSyncResult> {
+  const knownIds = await collectArtifactIds();
+  const wanted: WikiLinkSpec[] = [];
+  const skipped: SkippedWikiLink[] = [];
+
+  for (const type of LINKABLE_TYPES) {
+    const folder = ARTIFACT_CONFIG[type].folder;
+    for (const name of await baseDiskService.listFiles(folder)) {
+      if (!name.endsWith('.md')) continue;
+      const markdown = await baseDiskService.readTextFile(`${folder}/${name}`);
+      if (parseYamlFrontmatter(markdown).frontmatter.isDeleted) continue;
+      const resolved = resolveWikiLinks(name.slice(0, -'.md'.length), markdown, knownIds);
+      wanted.push(...resolved.specs);
+      skipped.push(...resolved.skipped);
+    }
+  }
+
+  const links = (await diskLinkService.getAllLinks()).filter((link) => !link.isDeleted);
+  const wantedKeys = new Set(wanted.map(linkKey));
+  const removed = links.filter((link) => link.origin === 'wiki' && !wantedKeys.has(linkKey(link)));
+
+  // Links the sync keeps, against which new ones are checked for duplicates and cycles
+  const kept: Pick<Link, 'sourceId' | 'targetId' | 'type'>[] = links.filter(
+    (link) => !removed.includes(link)
+  );
+  const keptKeys = new Set(kept.map(linkKey));
+  const created: WikiLinkSpec[] = [];
+  for (const spec of wanted) {
+    if (keptKeys.has(linkKey(spec))) continue;
+    if (isDecompositionLink(spec.type) && findCycleThroughLink(kept, spec)) {
+      const { sourceId, targetId, type, line } = spec;
+      skipped.push({ sourceId, targetId, linkType: type, line, reason: 'cycle' });
+      continue;
+    }
+    keptKeys.add(linkKey(spec));
+    kept.push(spec);
+    created.push(spec);
+  }
+
+  const result: WikiLinkSyncResult = { created, removed, skipped, applied: false };
+  if (dryRun || (created.length === 0 && removed.length === 0)) return result;
+
+  const linkFolder = ARTIFACT_CONFIG.links.folder;
+  await runJournaled(
+    'Sync wiki links',
+    async (journal) => {
+      if (created.length > 0) {
+        await journal.record(idService.getCounterPath('links'));
+      }
+      const ids = await idService.getNextIds('links', created.length, true);
+      const now = Date.now();
+      for (let i = 0; i < created.length; i++) {
+        const { sourceId, targetId, type } = created[i];
+        await journal.record(`${linkFolder}/${ids[i]}.md`);
+        await diskLinkService.save({
+          id: ids[i],
+          sourceId,
+          targetId,
+          type,
+          projectIds: [],
+          origin: 'wiki',
+          dateCreated: now,
+          lastModified: now,
+          revision: '01',
+        });
+      }
+      for (const link of removed) {
+        await journal.record(`${linkFolder}/${link.id}.md`);
+        await diskLinkService.delete(link.id);
+      }
+    },
+    `Sync wiki links (${created.length} added, ${removed.length} removed)`
+  );
+  debug.info(`[WikiLinkService] Added ${created.length}, removed ${removed.length} wiki link(s)`);
+  return { ...result, applied: true };
+}
+
+export const wikiLinkService = {
+  resolveWikiLinks,
+  syncWikiLinks,
+};
@@ -0,0 +1,191 @@
+/**
+ * Write Journal Service
+ *
+ * Write-ahead journal for operations that change several files (batch
+ * writes, markdown imports). Before a file is first touched its current
+ * bytes are recorded in .tracyfy/journal.json; once every change is on disk
+ * the journal is marked applied, the files are committed and the journal is
+ * removed. A journal left behind by a crash is resolved on the next start:
+ * an operation that was still applying is rolled back to the recorded
+ * contents, one that was applied is rolled forward by committing its files.
+ *
+ * Recorded contents are the bytes on disk, so files encrypted at rest stay
+ * encrypted in the journal.
+ */
+
+import { fileSystemService } from './fileSystemService';
+import { realGitService } from './realGitService';
+import { debug } from '../utils/debug';
+
+export const JOURNAL_PATH = '.tracyfy/journal.json';
+
+interface JournalEntry {
+  path: string;
+  original: string | null; // Base64 content before the operation; null when the file was new
+}
+
+interface JournalFile {
+  operation: string;
+  startedAt: number;
+  state: 'applying' | 'applied';
+  commitMessage?: string;
+  entries: JournalEntry[];
+}
+
+export interface WriteJournal {
+  /** Record a file's current content; call before each file is first written or deleted */
+  record(path: string): Promise<void>;
+}
+
+export interface JournalRecovery {
+  operation: string;
+  action: 'rolled-back' | 'rolled-forward';
+  paths: string[];
+}
+
+let queue: Promise<unknown> = Promise.resolve();
+
+function toBase64(bytes: Uint8Array): string {
+  let binary = '';
+  for (let i = 0; i < bytes.length; i += 0x8000) {
+    binary += String.fromCharCode(...bytes.subarray(i, i + 0x8000));
+  }
+  return btoa(binary);
+}
+
+function fromBase64(base64: string): Uint8Array {
+  return Uint8Array.from(atob(base64), (c) => c.charCodeAt(0));
+}
+
+async function saveJournal(journal: JournalFile): Promise<void> {
+  await fileSystemService.writeFile(JOURNAL_PATH, JSON.stringify(journal));
+}
+
+async function loadJournal(): Promise<JournalFile | null> {
+  const content = await fileSystemService.readFile(JOURNAL_PATH);
+  if (!content) return null;
+  try {
+    return JSON.parse(content) as JournalFile;
+  } catch {
+    // A crash while the journal itself was written: nothing was touched after it
+    debug.warn('[WriteJournalService] Discarding unreadable journal');
+    return { operation: 'unknown', startedAt: 0, state: 'applying', entries: [] };
+  }
+}
+
+// Restore recorded contents, latest first; keeps going past failures
+async function rollBack(entries: JournalEntry[]): Promise<void> {
+  for (const { path, original } of [...entries].reverse()) {
+    try {
+      if (original === null) {
+        await fileSystemService.deleteFile(path);
+      } else {
+        await fileSystemService.writeFileBinary(path, fromBase64(original));
+      }
+    } catch (err) {
+      console.error(`[WriteJournalService] Failed to roll back ${path}:`, err);
+    }
+  }
+}
+
+/**
+ * Run a multi-file operation under the journal. Files passed to
+ * journal.record() are restored if the operation throws, and committed
+ * together afterwards when a commit message is given; if that commit fails
+ * they are restored as well.
+ */
+export function runJournaled<T>(
+  operation: string,
+  apply: (journal: WriteJournal) => Promise<T>,
+  commitMessage?: string
+): Promise<T> {
+  // One journal file, so journaled operations run one at a time
+  const result = queue.then(() => runOperation(operation, apply, commitMessage));
+  queue = result.catch(() => undefined);
+  return result;
+}
+
+async function runOperation<T>(
+  operation: string,
+  apply: (journal: WriteJournal) => Promise<T>,
+  commitMessage?: string
+): Promise<T> {
+  if (await loadJournal()) {
+    throw new Error('An interrupted operation has not been recovered yet');
+  }
+  const journal: JournalFile = {
+    operation,
+    startedAt: Date.now(),
+    state: 'applying',
+    ...(commitMessage ? { commitMessage } : {}),
+    entries: [],
+  };
+  await saveJournal(journal);
+
+  const writeJournal: WriteJournal = {
+    record: async (path) => {
+      if (journal.entries.some((e) => e.path === path)) return;
+      const bytes = await fileSystemService.readFileBinary(path);
+      journal.entries.push({ path, original: bytes && toBase64(bytes) });
+      await saveJournal(journal);
+    },
+  };
+
+  let result: T;
+  try {
+    result = await apply(writeJournal);
+  } catch (err) {
+    await rollBack(journal.entries);
+    await fileSystemService.deleteFile(JOURNAL_PATH);
+    throw err;
+  }
+
+  journal.state = 'applied';
+  await saveJournal(journal);
+  try {
+    if (commitMessage) {
+      // Link files the commit flags as suspect join the journal, so they roll back too
+      const paths = journal.entries.map((e) => e.path);
+      await realGitService.commitFiles(paths, commitMessage, undefined, writeJournal);
+    }
+  } catch (err) {
+    // A refused commit (e.g. secrets detected) must not leave the files changed
+    await rollBack(journal.entries);
+    throw err;
+  } finally {
+    await fileSystemService.deleteFile(JOURNAL_PATH);
+  }
+  return result;
+}
+
+/**
+ * Finish or undo an operation interrupted by a crash; null when there was
+ * none. Call once the project directory and git are initialized.
+ */
+export async function recoverWriteJournal(): Promise<JournalRecovery | null> {
+  const journal = await loadJournal();
+  if (!journal) return null;
+
+  const paths = journal.entries.map((e) => e.path);
+  let action: JournalRecovery['action'];
+  if (journal.state === 'applied') {
+    if (journal.commitMessage) {
+      await realGitService.commitFiles(paths, journal.commitMessage);
+    }
+    action = 'rolled-forward';
+  } else {
+    await rollBack(journal.entries);
+    action = 'rolled-back';
+  }
+  await fileSystemService.deleteFile(JOURNAL_PATH);
+  debug.info(`[WriteJournalService] ${journal.operation}: ${action} ${paths.length} files`);
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent('git-status-changed'));
+  }
+  return { operation: journal.operation, action, paths };
+}
+
+export const writeJournalService = {
+  runJournaled,
+  recoverWriteJournal,
+};
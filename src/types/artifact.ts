@@ -1,5 +1,6 @@
 import type { CustomAttributeValue } from './customAttributes';
 import type { ArtifactLink } from './link';
+import type { ExternalIssueRef } from './integration';
 
 export type ArtifactType =
   | 'requirements'
@@ -18,6 +19,13 @@ export interface BaseArtifact {
   deletedAt?: number;
   customAttributes?: CustomAttributeValue[];
   linkedArtifacts?: ArtifactLink[];
+  parentId?: string; // Parent artifact of the same type (hierarchy)
+  order?: number; // 1-based position among siblings
+  tags?: string[];
+  statusChangedBy?: string; // Who made the last workflow transition
+  statusChangedAt?: number; // When the last workflow transition happened
+  externalIssues?: ExternalIssueRef[]; // Linked Jira/GitHub issues
+  aliases?: string[]; // IDs of artifacts merged into this one
 }
 
 export interface DocumentEntry {
@@ -80,6 +88,7 @@ export interface TestCase extends BaseArtifact {
   expectedResult?: string;
   dateCreated: number;
   lastRun?: number;
+  lastResult?: 'passed' | 'failed' | 'blocked'; // Outcome of the last run; not a workflow status
   author?: string;
   requirementIds?: string[];
 }
@@ -116,6 +125,25 @@ export interface Risk extends BaseArtifact {
   dateCreated: number;
 }
 
+export interface ChangeRequest extends BaseArtifact {
+  title: string;
+  description: string;
+  reason?: string;
+  status?: string;
+  priority?: string;
+  author?: string;
+  affectedRequirementIds: string[]; // Requirements this change modifies
+  dateCreated: number;
+}
+
+export interface GlossaryTerm extends BaseArtifact {
+  term: string;
+  definition: string;
+  abbreviation?: string; // Matched case-sensitively, e.g. "ECU"
+  synonyms: string[];
+  dateCreated: number;
+}
+
 export interface Workflow extends BaseArtifact {
   title: string;
   description: string;
@@ -44,3 +44,17 @@ export interface CustomAttributeValue {
 export interface HasCustomAttributes {
   customAttributes?: CustomAttributeValue[];
 }
+
+// Attribute declared for an artifact type in the project's attribute schema
+// (config/attribute-schema.json). Values are stored in the artifact's
+// customAttributes with the field name as attributeId.
+export interface AttributeSchemaField {
+  name: string;
+  type: AttributeType;
+  options?: string[]; // Allowed values (dropdown)
+  required?: boolean;
+  description?: string;
+}
+
+// Schema fields keyed by artifact type key (e.g. 'requirements')
+export type AttributeSchema = Record<string, AttributeSchemaField[]>;
@@ -0,0 +1,11 @@
+/**
+ * Request to open a project and/or artifact from outside the app: a
+ * tracyfy://open?project=...&artifact=... link (project matched by ID or
+ * name), or a project folder given on the command line or opened through its
+ * project.tracyfy file.
+ */
+export interface DeepLink {
+  project?: string;
+  artifact?: string;
+  projectPath?: string; // Absolute path of a project folder to open
+}
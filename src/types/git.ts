@@ -62,4 +62,7 @@ export interface ProjectBaseline {
   // Track what was added/removed from project since last baseline
   addedArtifacts?: string[];
   removedArtifacts?: string[];
+  approver?: string;
+  tag?: string; // Annotated git tag on the commit that recorded the baseline
+  manifest?: Record<string, string>; // Content hash of each included artifact at its commit
 }
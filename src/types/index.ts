@@ -6,5 +6,14 @@ export * from './project';
 export * from './user';
 export * from './link';
 export * from './state';
+export * from './review';
+export * from './lock';
+export * from './testRun';
+export * from './release';
+export * from './redaction';
+export * from './savedQuery';
+export * from './integration';
+export * from './settings';
+export * from './deepLink';
 
 export type { LinkType } from '../utils/linkTypes';
@@ -0,0 +1,30 @@
+export type IssueTracker = 'jira' | 'github' | 'gitlab';
+
+/**
+ * Issue in an external tracker that an artifact is linked to, stored in the
+ * artifact's frontmatter (externalIssues)
+ */
+export interface ExternalIssueRef {
+  tracker: IssueTracker;
+  key: string; // e.g. PROJ-42 (Jira), owner/repo#12 (GitHub), group/app!7 (GitLab merge request)
+  url?: string;
+  status?: string; // Issue status as of the last sync
+  labels?: string[]; // GitHub/GitLab labels as of the last sync
+  syncedAt?: number;
+}
+
+export type PluginCapability = 'import' | 'export';
+
+/**
+ * plugin.json of an importer/exporter plugin. The command receives a JSON
+ * request on stdin and answers with JSON on stdout.
+ */
+export interface PluginManifest {
+  name: string;
+  version?: string;
+  description?: string;
+  capabilities: PluginCapability[];
+  command: string; // Executable; ./relative paths resolve inside the plugin folder
+  args?: string[];
+  fileExtensions?: string[]; // Files an importer accepts / an exporter writes, e.g. ".reqif"
+}
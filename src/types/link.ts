@@ -4,21 +4,13 @@ import type { LinkType } from '../utils/linkTypes';
 // Link stored within an artifact (DEPRECATED - use Link instead)
 export interface ArtifactLink {
   targetId: string;
-  type:
-    | 'parent' // Hierarchical decomposition - this is a parent of target
-    | 'child' // Hierarchical decomposition - this is a child of target
-    | 'derived_from' // Logical derivation, not strict hierarchy
-    | 'depends_on' // Dependency
-    | 'conflicts_with' // Mutual exclusivity
-    | 'duplicates' // Redundancy or overlap (Similar To)
-    | 'refines' // Adds detail without changing intent
-    | 'satisfies' // Links to design or implementation (Implements)
-    | 'verifies' // Links to test cases or validation
-    | 'constrains' // Imposes restrictions on another requirement
-    | 'requires' // Precondition for another requirement
-    | 'related_to'; // Generic association for context
+  // Any link type; inverse types (e.g. verified_by) appear on mirrored entries
+  type: LinkType;
 }
 
+// How a link came about; unset for links created by hand
+export type LinkOrigin = 'wiki'; // From a [[ID]] reference in the source's text
+
 // Standalone link entity stored in links/ folder
 export interface Link {
   id: string; // LINK-001, LINK-002, etc.
@@ -29,6 +21,9 @@ export interface Link {
   dateCreated: number;
   lastModified: number;
   revision: string; // Satisfies BaseArtifact
+  suspect?: boolean; // Set when an endpoint changed after the link was last confirmed
+  suspectSince?: number;
+  origin?: LinkOrigin;
   isDeleted?: boolean;
   deletedAt?: number;
   customAttributes?: CustomAttributeValue[];
@@ -0,0 +1,11 @@
+/**
+ * Advisory edit lock on an artifact, stored in .locks/<artifactId>.json.
+ * Locks are not committed; they only coordinate users sharing a folder.
+ */
+export interface ArtifactLock {
+  artifactId: string;
+  path: string; // Repo-relative artifact path, e.g. requirements/REQ-001.md
+  owner: string; // User ID of the lock holder
+  acquiredAt: number;
+  expiresAt: number; // The lock is stale after this time
+}
@@ -13,3 +13,12 @@ export interface Project {
   isDeleted?: boolean; // Soft delete flag
   lastModified: number;
 }
+
+/**
+ * Status lifecycle of artifacts, stored in the project config (config/workflow.json)
+ */
+export interface StatusWorkflowConfig {
+  statuses: string[];
+  initialStatus: string;
+  transitions: Record<string, string[]>; // Allowed next statuses per status
+}
@@ -0,0 +1,11 @@
+/**
+ * Named set of rules for removing internal content from exports, so a
+ * sanitized copy of the project can be shared outside the team. Stored in
+ * config/redaction.json.
+ */
+export interface RedactionProfile {
+  name: string; // e.g. "Supplier"
+  fields: string[]; // Frontmatter fields and custom attribute IDs left out, e.g. "customer"
+  sectionTags: string[]; // Body sections whose heading carries one of these tags, e.g. "internal"
+  patterns: string[]; // Regular expressions whose matches are replaced, e.g. "ACME( Corp)?"
+}
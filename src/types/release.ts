@@ -0,0 +1,23 @@
+/**
+ * Artifact in the scope of a release, optionally pinned to the version of a
+ * specific commit
+ */
+export interface ReleaseItem {
+  artifactId: string;
+  commitHash?: string; // Pinned version; the current version when absent
+}
+
+/**
+ * Product release of a project, stored in releases/<id>.json. A project can
+ * have several releases in progress at once.
+ */
+export interface Release {
+  id: string; // e.g. "REL-001"
+  projectId: string;
+  name: string; // e.g. "2.1"
+  description?: string;
+  targetDate?: number;
+  items: ReleaseItem[];
+  createdBy: string;
+  createdAt: number;
+}
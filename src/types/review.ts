@@ -0,0 +1,32 @@
+/**
+ * Approval record of an artifact, stored in approvals/<artifactId>.json
+ */
+export interface ApprovalRecord {
+  artifactId: string;
+  reviewer: string;
+  comment?: string;
+  timestamp: number;
+  contentHash: string; // SHA-256 of the artifact content at approval time
+  signature?: string; // Optional signature over the approval payload
+}
+
+/**
+ * Review comment on an artifact, stored in comments/<artifactId>.json.
+ * Replies reference the comment they answer; resolving applies to a thread.
+ */
+export interface ArtifactComment {
+  id: string; // e.g. "C-001", unique per artifact
+  artifactId: string;
+  parentId?: string; // Comment this one replies to
+  author: string;
+  text: string;
+  timestamp: number;
+  resolved?: boolean;
+  resolvedBy?: string;
+  resolvedAt?: number;
+}
+
+export interface CommentThread {
+  comment: ArtifactComment;
+  replies: ArtifactComment[]; // All replies in the thread, oldest first
+}
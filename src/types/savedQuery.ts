@@ -0,0 +1,35 @@
+import type { LinkType } from '../utils/linkTypes';
+
+/**
+ * Condition on an artifact's links, e.g. "has no verified_by link to a test
+ * case". The link type is read from the artifact's side, whichever end the
+ * link was created from.
+ */
+export interface LinkPredicate {
+  type: LinkType;
+  present: boolean; // false: the artifact must have no such link
+  targetType?: string; // Only count links to this artifact type, e.g. "testcases"
+}
+
+/**
+ * Filter over artifacts; every condition given must hold
+ */
+export interface ArtifactQuery {
+  types: string[]; // Artifact types to search, e.g. ["requirements"]
+  status?: string[]; // Any of these statuses
+  excludeStatus?: string[]; // None of these statuses
+  priority?: string[]; // Any of these priorities
+  tags?: string; // Tag expression, e.g. "safety AND NOT legacy"
+  links?: LinkPredicate[];
+  text?: string; // Words that must all appear in the title or text
+}
+
+/**
+ * Named query shared with the team through config/saved-queries.json, e.g.
+ * "High-priority unapproved requirements without tests"
+ */
+export interface SavedQuery {
+  name: string;
+  description?: string;
+  query: ArtifactQuery;
+}
@@ -0,0 +1,87 @@
+/**
+ * An identity the user can author under, e.g. one per employer or customer
+ */
+export interface AuthorProfile {
+  id: string; // Lowercase letters, digits and dashes, e.g. "work"
+  name: string;
+  email: string;
+  initials: string;
+  signingKeyId?: string; // Minisign key ID of the profile's key, kept in the secret store
+  publicKey?: string; // Minisign public key matching signingKeyId
+}
+
+export type WebhookEvent = 'commit' | 'baseline' | 'approval';
+
+/**
+ * Outbound webhook; its secret is kept in the secret store
+ */
+export interface Webhook {
+  id: string;
+  url: string;
+  events: WebhookEvent[];
+  enabled: boolean;
+}
+
+/**
+ * Issue tracker servers of a project. Project data and API tokens are only
+ * sent to these hosts, so they are set up per machine, never read from the
+ * repository.
+ */
+export interface IntegrationUrls {
+  jira?: string; // e.g. https://acme.atlassian.net
+  gitlab?: string; // e.g. https://gitlab.acme.internal; gitlab.com when not set
+}
+
+/**
+ * Locally installed tools diagrams are rendered with
+ */
+export interface DiagramSettings {
+  mermaidCli: string; // mermaid-cli executable
+  plantumlJar: string; // Path to plantuml.jar; PlantUML is disabled when empty
+  javaPath: string;
+}
+
+/**
+ * The local REST API for scripts and CI jobs. Kept on this machine, not in the
+ * project, so a cloned repository cannot open a port.
+ */
+export interface LocalApiSettings {
+  enabled: boolean;
+  port: number; // 1024-65535; the server only listens on 127.0.0.1
+}
+
+/**
+ * App-level preferences, independent of the open project. Kept in
+ * settings.json in the app's config directory by the desktop app.
+ */
+export interface AppSettings {
+  defaultAuthor: string; // Author when no current user is set; empty for the built-in name
+  defaultProjectLocation: string; // Folder the project picker opens in; empty for the OS default
+  autoSync: boolean; // Push commits to the remote as they are made
+  notifyExternalChanges: boolean; // Desktop notification for changes on disk or upstream
+  blockSecretCommits: boolean; // Refuse commits with text that looks like credentials, else warn
+  pollIntervals: {
+    syncStatus: number; // Milliseconds between sync status checks in the sidebar
+    remoteStatus: number; // Milliseconds between checks of the header's remote indicator
+  };
+  authorProfiles: AuthorProfile[];
+  activeAuthorProfiles: Record<string, string>; // Profile ID per project ID
+  webhooks: Record<string, Webhook[]>; // Webhooks per project folder, set up on this machine
+  integrationUrls: Record<string, IntegrationUrls>; // Tracker servers per project folder
+  diagrams: DiagramSettings; // Programs run by the main process to render diagrams
+  trustedSigningKeys: string[]; // Minisign public keys whose signatures are trusted
+  localApi: LocalApiSettings;
+  readOnlyProjects: string[]; // Project folders opened in reviewer mode
+}
+
+/**
+ * Change to some app settings; poll intervals are merged individually. Diagram
+ * tools and trusted signing keys are left out: they change only through a
+ * dialog of the desktop app (appSettingsService.chooseDiagramTool and
+ * trustSigningKey). So are read-only projects (fileSystemService.setReadOnly).
+ */
+export type AppSettingsUpdate = Partial<
+  Omit<AppSettings, 'pollIntervals' | 'diagrams' | 'trustedSigningKeys' | 'readOnlyProjects'>
+> & {
+  pollIntervals?: Partial<AppSettings['pollIntervals']>;
+};
@@ -0,0 +1,30 @@
+/**
+ * Outcome of executing a test case within a test run
+ */
+export type TestRunOutcome = 'passed' | 'failed' | 'blocked' | 'skipped';
+
+export interface TestRunResult {
+  testCaseId: string;
+  outcome: TestRunOutcome;
+  notes?: string; // Evidence: observations, log excerpts, screenshot references
+  executedBy: string;
+  executedAt: number;
+}
+
+/**
+ * Manual test execution session, stored in testruns/<id>.json.
+ * Results can be recorded while the run is open; closing it applies the
+ * results to the test cases.
+ */
+export interface TestRun {
+  id: string; // e.g. "RUN-001"
+  name: string;
+  environment?: string; // e.g. "staging, Chrome 120"
+  testCaseIds: string[]; // Scope of the run
+  results: TestRunResult[]; // Latest result per test case
+  status: 'open' | 'closed';
+  createdBy: string;
+  createdAt: number;
+  closedBy?: string;
+  closedAt?: number;
+}
@@ -1,4 +1,5 @@
 import type { ProjectBaseline } from './git';
+import type { RedactionProfile } from './redaction';
 
 export interface ColumnVisibility {
   idTitle: boolean;
@@ -112,6 +113,7 @@ export type ModalType =
   | 'history'
   | 'search'
   | 'custom-attribute'
+  | 'artifact-types'
   | 'workflow'
   | 'global-library'
   | 'documents'
@@ -152,4 +154,6 @@ export interface ExportOptions {
   includeRevisionHistory: boolean;
   includeTraceability: boolean;
   includeVerificationMatrix: boolean;
+  // Internal content left out of the export; none when absent
+  redactionProfile?: RedactionProfile | null;
 }
@@ -0,0 +1,77 @@
+import { describe, it, expect } from 'vitest';
+import type { ArtifactQuery, Link } from '../../types';
+import {
+  buildLinkIndex,
+  compileArtifactQuery,
+  validateArtifactQuery,
+  type QueryableArtifact,
+} from '../artifactQueryUtils';
+
+const LINKS = [
+  { id: 'LINK-001', sourceId: 'TC-001', targetId: 'REQ-001', type: 'verifies' },
+  { id: 'LINK-002', sourceId: 'REQ-002', targetId: 'UC-001', type: 'related_to' },
+] as Link[];
+
+const ARTIFACTS: QueryableArtifact[] = [
+  { id: 'REQ-001', title: 'Login timeout', status: 'draft', priority: 'high', tags: ['safety'] },
+  { id: 'REQ-002', title: 'Login lockout', status: 'draft', priority: 'high', tags: ['ui'] },
+  { id: 'REQ-003', title: 'Audit log', status: 'approved', priority: 'high', tags: ['safety'] },
+  { id: 'REQ-004', title: 'Export', status: 'draft', priority: 'low' },
+  { id: 'REQ-005', title: 'Old login', status: 'draft', priority: 'high', isDeleted: true },
+];
+
+const run = (query: ArtifactQuery) =>
+  ARTIFACTS.filter(compileArtifactQuery(query, buildLinkIndex(LINKS))).map((a) => a.id);
+
+describe('artifactQueryUtils', () => {
+  it('should find high-priority unapproved requirements without tests', () => {
+    expect(
+      run({
+        types: ['requirements'],
+        priority: ['high'],
+        excludeStatus: ['approved'],
+        links: [{ type: 'verified_by', present: false, targetType: 'testcases' }],
+      })
+    ).toEqual(['REQ-002']);
+  });
+
+  it('should read link predicates from either end of the link', () => {
+    expect(
+      run({ types: ['requirements'], links: [{ type: 'verified_by', present: true }] })
+    ).toEqual(['REQ-001']);
+    expect(
+      run({
+        types: ['requirements'],
+        links: [{ type: 'related_to', present: true, targetType: 'testcases' }],
+      })
+    ).toEqual([]);
+  });
+
+  it('should combine status, tags and text terms', () => {
+    expect(run({ types: ['requirements'], status: ['draft'], tags: 'safety OR ui' })).toEqual([
+      'REQ-001',
+      'REQ-002',
+    ]);
+    expect(run({ types: ['requirements'], text: 'LOGIN lock' })).toEqual(['REQ-002']);
+  });
+
+  it('should report unknown types, link types and bad tag expressions', () => {
+    expect(
+      validateArtifactQuery(
+        {
+          types: ['requirements', 'widgets'],
+          tags: 'safety AND (ui',
+          links: [{ type: 'tests' as Link['type'], present: true }],
+        },
+        ['requirements']
+      )
+    ).toEqual([
+      'Unknown artifact type: widgets',
+      'Missing closing parenthesis in tag expression: safety AND (ui',
+      'Unknown link type: tests',
+    ]);
+    expect(validateArtifactQuery({ types: [] }, ['requirements'])).toEqual([
+      'Query must search at least one artifact type',
+    ]);
+  });
+});
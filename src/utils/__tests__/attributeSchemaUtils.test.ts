@@ -0,0 +1,87 @@
+/**
+ * Attribute Schema Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { validateAttributeSchema, validateAttributeValues } from '../attributeSchemaUtils';
+import type { AttributeSchemaField } from '../../types/customAttributes';
+
+describe('attributeSchemaUtils', () => {
+  describe('validateAttributeSchema', () => {
+    it('should accept a valid schema', () => {
+      expect(
+        validateAttributeSchema({
+          requirements: [
+            { name: 'component', type: 'dropdown', options: ['UI', 'API'], required: true },
+            { name: 'safetyLevel', type: 'number' },
+          ],
+        })
+      ).toEqual([]);
+    });
+
+    it('should report invalid names, duplicates, types and dropdowns without options', () => {
+      expect(
+        validateAttributeSchema({
+          risks: [
+            { name: 'has space', type: 'text' },
+            { name: 'owner', type: 'text' },
+            { name: 'Owner', type: 'text' },
+            { name: 'kind', type: 'list' as never },
+            { name: 'area', type: 'dropdown' },
+          ],
+        })
+      ).toEqual([
+        'risks.has space: invalid attribute name',
+        'risks.Owner: duplicate attribute name',
+        'risks.kind: unknown data type "list"',
+        'risks.area: dropdown needs allowed values',
+      ]);
+    });
+  });
+
+  describe('validateAttributeValues', () => {
+    const fields: AttributeSchemaField[] = [
+      { name: 'component', type: 'dropdown', options: ['UI', 'API'], required: true },
+      { name: 'effort', type: 'number' },
+      { name: 'safety', type: 'checkbox' },
+      { name: 'due', type: 'date' },
+    ];
+
+    it('should accept valid values and missing optional ones', () => {
+      expect(
+        validateAttributeValues(
+          [
+            { attributeId: 'component', value: 'API' },
+            { attributeId: 'effort', value: '3' },
+            { attributeId: 'due', value: '2026-01-31' },
+          ],
+          fields
+        )
+      ).toEqual([]);
+    });
+
+    it('should report missing required values and wrong types', () => {
+      expect(
+        validateAttributeValues(
+          [
+            { attributeId: 'effort', value: 'lots' },
+            { attributeId: 'safety', value: 'yes' },
+            { attributeId: 'due', value: 'someday' },
+          ],
+          fields
+        )
+      ).toEqual([
+        { attribute: 'component', message: 'component is required' },
+        { attribute: 'effort', message: 'effort must be a number' },
+        { attribute: 'safety', message: 'safety must be true or false' },
+        { attribute: 'due', message: 'due must be a date' },
+      ]);
+    });
+
+    it('should enforce allowed values', () => {
+      expect(validateAttributeValues([{ attributeId: 'component', value: 'DB' }], fields)).toEqual([
+        { attribute: 'component', message: 'component must be one of: UI, API' },
+      ]);
+    });
+  });
+});
@@ -0,0 +1,83 @@
+/**
+ * Baseline Export Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildBaselineSnapshotHtml,
+  getSnapshotFileName,
+  SNAPSHOT_METADATA_ID,
+} from '../baselineExportUtils';
+import type { BaselineContents } from '../../services/baselineService';
+
+const contents: BaselineContents = {
+  baseline: {
+    id: 'bl-1',
+    projectId: 'proj-1',
+    name: '[Demo] 1.0',
+    description: 'First release',
+    version: '1.0',
+    timestamp: Date.UTC(2026, 0, 15),
+    artifactCommits: {},
+    addedArtifacts: [],
+    removedArtifacts: [],
+    approver: 'Alice',
+    tag: 'baseline/proj-1/1.0',
+    manifest: { 'REQ-002': 'hash-2', 'REQ-010': 'hash-10' },
+  },
+  artifacts: [
+    {
+      id: 'REQ-010',
+      type: 'requirement',
+      path: 'requirements/REQ-010.md',
+      commitHash: 'c10',
+      content: null,
+      intact: false,
+    },
+    {
+      id: 'REQ-002',
+      type: 'requirement',
+      path: 'requirements/REQ-002.md',
+      commitHash: 'c2',
+      content: '---\ntitle: Login </script>\nstatus: approved\n---\nUsers **must** log in.',
+      intact: true,
+    },
+  ],
+};
+
+describe('baselineExportUtils', () => {
+  describe('buildBaselineSnapshotHtml', () => {
+    const html = buildBaselineSnapshotHtml(contents, 'Demo', new Date(Date.UTC(2026, 0, 16)));
+
+    it('should embed the baseline metadata as JSON', () => {
+      const doc = new DOMParser().parseFromString(html, 'text/html');
+      const metadata = JSON.parse(doc.getElementById(SNAPSHOT_METADATA_ID)!.textContent!);
+
+      expect(metadata).toMatchObject({
+        project: 'Demo',
+        version: '1.0',
+        tag: 'baseline/proj-1/1.0',
+        approver: 'Alice',
+        createdAt: '2026-01-15T00:00:00.000Z',
+        exportedAt: '2026-01-16T00:00:00.000Z',
+        artifacts: { 'REQ-002': { commit: 'c2', hash: 'hash-2' } },
+      });
+    });
+
+    it('should render artifacts in ID order with escaped titles', () => {
+      const doc = new DOMParser().parseFromString(html, 'text/html');
+      const sections = [...doc.querySelectorAll('section')];
+
+      expect(sections.map((s) => s.id)).toEqual(['REQ-002', 'REQ-010']);
+      expect(sections[0].querySelector('h2')!.textContent).toBe('REQ-002 – Login </script>');
+      expect(sections[0].querySelector('strong')!.textContent).toBe('must');
+      expect(sections[1].textContent).toContain('Content could not be read.');
+    });
+  });
+
+  describe('getSnapshotFileName', () => {
+    it('should replace unsafe characters', () => {
+      expect(getSnapshotFileName('My Project/A', '1.0')).toBe('My_Project_A_baseline_1.0.html');
+    });
+  });
+});
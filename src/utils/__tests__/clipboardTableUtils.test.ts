@@ -0,0 +1,42 @@
+/**
+ * Clipboard Table Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { artifactsToTable, tableToHtml, tableToTsv } from '../clipboardTableUtils';
+
+describe('clipboardTableUtils', () => {
+  const table = [
+    ['ID', 'Title'],
+    ['REQ-001', 'Login <admin>'],
+    ['REQ-002', 'Tabs\tand "quotes"\nover lines'],
+  ];
+
+  it('should quote TSV cells that would break rows or columns', () => {
+    expect(tableToTsv(table)).toBe(
+      'ID\tTitle\nREQ-001\tLogin <admin>\nREQ-002\t"Tabs\tand ""quotes""\nover lines"'
+    );
+  });
+
+  it('should render the first row as header and escape cell content', () => {
+    const html = tableToHtml(table);
+
+    expect(html).toMatch(/^<table[^>]*><thead><tr><th[^>]*>ID<\/th><th[^>]*>Title<\/th><\/tr>/);
+    expect(html).toContain('>Login &lt;admin&gt;</td>');
+    expect(html).toContain('>Tabs\tand &quot;quotes&quot;<br>over lines</td>');
+    expect(html.match(/<tr>/g)).toHaveLength(3);
+  });
+
+  it('should list artifacts with their title or name', () => {
+    expect(
+      artifactsToTable([
+        { id: 'REQ-001', title: 'Login', status: 'draft', priority: 'high' },
+        { id: 'DOC-001', name: 'Spec' },
+      ])
+    ).toEqual([
+      ['ID', 'Title', 'Status', 'Priority', 'Author'],
+      ['REQ-001', 'Login', 'draft', 'high', ''],
+      ['DOC-001', 'Spec', '', '', ''],
+    ]);
+  });
+});
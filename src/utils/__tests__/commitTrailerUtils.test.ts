@@ -0,0 +1,35 @@
+/**
+ * Commit Trailer Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { addTrailer, getChangeRequestIds, parseTrailers } from '../commitTrailerUtils';
+
+describe('commitTrailerUtils', () => {
+  it('parses trailers from the last paragraph only', () => {
+    expect(parseTrailers('Update REQ-001\n\nChange-Request: CR-001\nReviewed-By: Sam')).toEqual([
+      { key: 'Change-Request', value: 'CR-001' },
+      { key: 'Reviewed-By', value: 'Sam' },
+    ]);
+    expect(parseTrailers('Change-Request: CR-001')).toEqual([]);
+    expect(parseTrailers('Update\n\nSee the note: it matters\nand more')).toEqual([]);
+  });
+
+  it('appends trailers without duplicating them', () => {
+    const once = addTrailer('Update REQ-001', 'Change-Request', 'CR-001');
+
+    expect(once).toBe('Update REQ-001\n\nChange-Request: CR-001');
+    expect(addTrailer(once, 'Change-Request', 'CR-001')).toBe(once);
+    expect(addTrailer(once, 'Change-Request', 'CR-002')).toBe(
+      'Update REQ-001\n\nChange-Request: CR-001\nChange-Request: CR-002'
+    );
+  });
+
+  it('extracts change request IDs', () => {
+    expect(getChangeRequestIds('Fix\n\nchange-request: CR-001, CR-003')).toEqual([
+      'CR-001',
+      'CR-003',
+    ]);
+    expect(getChangeRequestIds('Fix typo')).toEqual([]);
+  });
+});
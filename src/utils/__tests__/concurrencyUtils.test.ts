@@ -0,0 +1,52 @@
+import { describe, it, expect } from 'vitest';
+import { mapWithConcurrency } from '../concurrencyUtils';
+
+const delay = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe('mapWithConcurrency', () => {
+  it('should keep the order of the items', async () => {
+    const results = await mapWithConcurrency([30, 10, 20], async (ms, i) => {
+      await delay(ms);
+      return `${i}:${ms}`;
+    });
+
+    expect(results).toEqual(['0:30', '1:10', '2:20']);
+  });
+
+  it('should run at most limit tasks at a time', async () => {
+    let running = 0;
+    let peak = 0;
+    await mapWithConcurrency(
+      Array.from({ length: 10 }, (_, i) => i),
+      async () => {
+        running++;
+        peak = Math.max(peak, running);
+        await delay(1);
+        running--;
+      },
+      3
+    );
+
+    expect(peak).toBe(3);
+  });
+
+  it('should reject on the first failure and start no more work', async () => {
+    const started: number[] = [];
+    const run = mapWithConcurrency(
+      [0, 1, 2, 3, 4],
+      async (i) => {
+        started.push(i);
+        await delay(1);
+        if (i === 0) throw new Error('boom');
+      },
+      1
+    );
+
+    await expect(run).rejects.toThrow('boom');
+    expect(started).toEqual([0]);
+  });
+
+  it('should handle no items', async () => {
+    expect(await mapWithConcurrency([], async () => 1)).toEqual([]);
+  });
+});
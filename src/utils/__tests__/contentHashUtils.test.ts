@@ -0,0 +1,39 @@
+// @vitest-environment node
+/**
+ * Content Hash Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { canonicalArtifactContent, computeContentHash, sha256Hex } from '../contentHashUtils';
+
+describe('contentHashUtils', () => {
+  const markdown = `---
+id: "REQ-001"
+status: "draft"
+lastModified: 1
+title: "Login"
+---
+
+# Login`;
+
+  it('computes SHA-256 hex digests', async () => {
+    expect(await sha256Hex('abc')).toBe(
+      'ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad'
+    );
+  });
+
+  it('ignores workflow bookkeeping fields', async () => {
+    const approved = markdown
+      .replace('"draft"', '"approved"')
+      .replace('lastModified: 1', 'lastModified: 2');
+
+    expect(canonicalArtifactContent(approved)).toBe(canonicalArtifactContent(markdown));
+    expect(await computeContentHash(approved)).toBe(await computeContentHash(markdown));
+  });
+
+  it('changes when the content changes', async () => {
+    const edited = markdown.replace('"Login"', '"Sign in"');
+
+    expect(await computeContentHash(edited)).not.toBe(await computeContentHash(markdown));
+  });
+});
@@ -0,0 +1,75 @@
+/**
+ * Encoding Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { decodeText, detectEncoding, encodeText, isValidUtf8 } from '../encodingUtils';
+
+const bytes = (...values: number[]) => new Uint8Array(values);
+
+describe('encodingUtils', () => {
+  describe('isValidUtf8', () => {
+    it('should accept UTF-8 and reject stray high bytes', () => {
+      expect(isValidUtf8(new TextEncoder().encode('Größe'))).toBe(true);
+      expect(isValidUtf8(bytes(0x47, 0x72, 0xf6, 0xdf, 0x65))).toBe(false);
+    });
+  });
+
+  describe('detectEncoding', () => {
+    it('should detect byte order marks', () => {
+      expect(detectEncoding(bytes(0xef, 0xbb, 0xbf, 0x41))).toBe('utf-8-bom');
+      expect(detectEncoding(bytes(0xff, 0xfe, 0x41, 0x00))).toBe('utf-16le');
+      expect(detectEncoding(bytes(0xfe, 0xff, 0x00, 0x41))).toBe('utf-16be');
+    });
+
+    it('should detect UTF-16 without a BOM', () => {
+      expect(detectEncoding(bytes(0x48, 0x00, 0x69, 0x00, 0x21, 0x00))).toBe('utf-16le');
+      expect(detectEncoding(bytes(0x00, 0x48, 0x00, 0x69, 0x00, 0x21))).toBe('utf-16be');
+    });
+
+    it('should fall back to Latin-1 for invalid UTF-8', () => {
+      expect(detectEncoding(new TextEncoder().encode('plain text'))).toBe('utf-8');
+      expect(detectEncoding(bytes(0x63, 0x61, 0x66, 0xe9))).toBe('latin1');
+    });
+  });
+
+  describe('decodeText', () => {
+    it('should decode Latin-1 content', () => {
+      expect(decodeText(bytes(0x63, 0x61, 0x66, 0xe9))).toEqual({
+        content: 'café',
+        encoding: 'latin1',
+      });
+    });
+
+    it('should decode UTF-16 and strip the BOM', () => {
+      expect(decodeText(bytes(0xff, 0xfe, 0x48, 0x00, 0xe9, 0x00))).toEqual({
+        content: 'Hé',
+        encoding: 'utf-16le',
+      });
+      expect(decodeText(bytes(0xfe, 0xff, 0x00, 0x48, 0x00, 0xe9))).toEqual({
+        content: 'Hé',
+        encoding: 'utf-16be',
+      });
+    });
+
+    it('should strip the UTF-8 BOM', () => {
+      expect(decodeText(bytes(0xef, 0xbb, 0xbf, 0x4f, 0x4b))).toEqual({
+        content: 'OK',
+        encoding: 'utf-8-bom',
+      });
+    });
+  });
+
+  describe('encodeText', () => {
+    it('should round-trip every encoding', () => {
+      const text = '# Title\n\nCafé résumé';
+      (['utf-8', 'utf-8-bom', 'utf-16le', 'utf-16be', 'latin1'] as const).forEach((encoding) => {
+        expect(decodeText(encodeText(text, encoding))).toEqual({ content: text, encoding });
+      });
+    });
+
+    it('should replace characters Latin-1 cannot represent', () => {
+      expect(Array.from(encodeText('a€', 'latin1'))).toEqual([0x61, 0x3f]);
+    });
+  });
+});
@@ -0,0 +1,60 @@
+// @vitest-environment node
+/**
+ * Encryption Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  decryptText,
+  encryptText,
+  generateEncryptionKey,
+  isEncryptedContent,
+  isEncryptedPath,
+} from '../encryptionUtils';
+import { registerCustomArtifactTypes } from '../../constants/artifactConfig';
+
+describe('encryptionUtils', () => {
+  const key = generateEncryptionKey();
+
+  it('round-trips text through AES-GCM', async () => {
+    const encrypted = await encryptText('# Login\n\nÄrzte können sich anmelden', key);
+
+    expect(isEncryptedContent(encrypted)).toBe(true);
+    expect(encrypted).not.toContain('Login');
+    expect(await decryptText(encrypted, key)).toBe('# Login\n\nÄrzte können sich anmelden');
+  });
+
+  it('uses a fresh IV for every encryption', async () => {
+    expect(await encryptText('same', key)).not.toBe(await encryptText('same', key));
+  });
+
+  it('rejects a wrong key and tampered content', async () => {
+    const encrypted = await encryptText('secret', key);
+
+    await expect(decryptText(encrypted, generateEncryptionKey())).rejects.toThrow(
+      'Cannot decrypt content'
+    );
+    const tampered = encrypted.slice(0, -4) + (encrypted.endsWith('AAAA') ? 'BBBB' : 'AAAA');
+    await expect(decryptText(tampered, key)).rejects.toThrow('Cannot decrypt content');
+  });
+
+  it('only encrypts artifact content folders and comments', () => {
+    expect(isEncryptedPath('requirements/REQ-001.md')).toBe(true);
+    expect(isEncryptedPath('change-requests/CR-001.md')).toBe(true);
+    expect(isEncryptedPath('glossary/TERM-001.md')).toBe(true);
+    expect(isEncryptedPath('comments/REQ-001.json')).toBe(true);
+    expect(isEncryptedPath('approvals/REQ-001.json')).toBe(false);
+    expect(isEncryptedPath('projects/PROJ-001.md')).toBe(false);
+    expect(isEncryptedPath('config/encryption.json')).toBe(false);
+  });
+
+  it('encrypts the folders of custom types registered later', () => {
+    registerCustomArtifactTypes([
+      { key: 'hazards', label: 'Hazard', idPrefix: 'HAZ', folder: 'hazards' },
+    ]);
+    expect(isEncryptedPath('hazards/HAZ-001.md')).toBe(true);
+
+    registerCustomArtifactTypes([]);
+    expect(isEncryptedPath('hazards/HAZ-001.md')).toBe(false);
+  });
+});
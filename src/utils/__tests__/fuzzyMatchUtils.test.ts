@@ -0,0 +1,42 @@
+import { describe, it, expect } from 'vitest';
+import { buildSearchTokens, fuzzyScore, tokenize } from '../fuzzyMatchUtils';
+
+const score = (query: string, id: string, title: string) =>
+  fuzzyScore(tokenize(query), buildSearchTokens(id, title));
+
+describe('fuzzyMatchUtils', () => {
+  it('should find IDs with or without dash and leading zeros', () => {
+    expect(buildSearchTokens('REQ-012', 'Login')).toEqual([
+      'req012',
+      'req12',
+      'req',
+      '012',
+      'login',
+    ]);
+    expect(score('req12', 'REQ-012', 'Login')).toBeGreaterThan(0);
+    expect(score('REQ-012', 'REQ-012', 'Login')).toBeGreaterThan(0);
+  });
+
+  it('should forgive skipped letters and single typos', () => {
+    expect(score('REQ timout retry', 'REQ-014', 'Retry after timeout')).toBeGreaterThan(0);
+    expect(score('tiemout', 'REQ-014', 'Retry after timeout')).toBeGreaterThan(0);
+    expect(score('rtry', 'REQ-014', 'Retry after timeout')).toBeGreaterThan(0);
+  });
+
+  it('should require every typed word to match', () => {
+    expect(score('timeout login', 'REQ-014', 'Retry after timeout')).toBe(0);
+    expect(score('tme', 'REQ-014', 'Retry after timeout')).toBeGreaterThan(0);
+    expect(score('xyz', 'REQ-014', 'Retry after timeout')).toBe(0);
+  });
+
+  it('should rank exact words above prefixes, parts and typos', () => {
+    const exact = score('timeout', 'REQ-001', 'Session timeout');
+    const prefix = score('time', 'REQ-001', 'Session timeout');
+    const part = score('meout', 'REQ-001', 'Session timeout');
+    const typo = score('timout', 'REQ-001', 'Session timeout');
+
+    expect(exact).toBeGreaterThan(prefix);
+    expect(prefix).toBeGreaterThan(part);
+    expect(part).toBeGreaterThan(typo);
+  });
+});
@@ -0,0 +1,80 @@
+/**
+ * Glossary Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { findTermOccurrences, findUndefinedTerms, type GlossaryEntry } from '../glossaryUtils';
+
+const glossary: GlossaryEntry[] = [
+  {
+    id: 'TERM-001',
+    term: 'Electronic Control Unit',
+    abbreviation: 'ECU',
+    synonyms: ['controller'],
+  },
+  { id: 'TERM-002', term: 'Control Unit' },
+];
+
+const markdown = `---
+id: REQ-001
+title: ECU timing
+---
+
+# ECU timing
+
+## Description
+The Flight Management System shall query the Electronic Control Unit.
+Each controller reports to the ECU via CAN, see REQ-002.
+The ecu is not an abbreviation here.
+
+\`\`\`
+Ignored Code Block ECU
+\`\`\``;
+
+describe('glossaryUtils', () => {
+  describe('findTermOccurrences', () => {
+    it('finds terms, synonyms and abbreviations with positions', () => {
+      const occurrences = findTermOccurrences(markdown, glossary);
+
+      expect(occurrences).toEqual([
+        { text: 'ECU', termId: 'TERM-001', line: 6, column: 3, length: 3 },
+        {
+          text: 'Electronic Control Unit',
+          termId: 'TERM-001',
+          line: 9,
+          column: 46,
+          length: 23,
+        },
+        { text: 'controller', termId: 'TERM-001', line: 10, column: 6, length: 10 },
+        { text: 'ECU', termId: 'TERM-001', line: 10, column: 32, length: 3 },
+      ]);
+    });
+
+    it('matches whole words only', () => {
+      expect(findTermOccurrences('Two controllers', glossary)).toEqual([]);
+    });
+
+    it('returns nothing for an empty glossary', () => {
+      expect(findTermOccurrences(markdown, [])).toEqual([]);
+    });
+  });
+
+  describe('findUndefinedTerms', () => {
+    it('flags capitalized terms and acronyms without glossary entries', () => {
+      const undefinedTerms = findUndefinedTerms(markdown, glossary);
+
+      expect(undefinedTerms.map((o) => [o.text, o.line, o.column])).toEqual([
+        ['Flight Management System', 9, 5],
+        ['CAN', 10, 40],
+      ]);
+    });
+
+    it('ignores artifact IDs and sentence-initial words', () => {
+      const text = 'See REQ-001. The System starts. Data Bus Load is high.';
+
+      expect(findUndefinedTerms(text, [])).toEqual([
+        { text: 'Bus Load', line: 1, column: 38, length: 8 },
+      ]);
+    });
+  });
+});
@@ -0,0 +1,70 @@
+/**
+ * Hierarchy Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildHierarchyTree,
+  computeHierarchyNumbers,
+  getSubtreeIds,
+  wouldCreateCycle,
+} from '../hierarchyUtils';
+
+describe('hierarchyUtils', () => {
+  const nodes = [
+    { id: 'REQ-001' },
+    { id: 'REQ-002' },
+    { id: 'REQ-003', parentId: 'REQ-001', order: 2 },
+    { id: 'REQ-004', parentId: 'REQ-001', order: 1 },
+    { id: 'REQ-005', parentId: 'REQ-004' },
+    { id: 'REQ-006', parentId: 'REQ-404' }, // Orphan: parent not loaded
+  ];
+
+  describe('computeHierarchyNumbers', () => {
+    it('numbers roots and children by sibling order', () => {
+      const numbers = computeHierarchyNumbers(nodes);
+
+      expect(numbers.get('REQ-001')).toBe('1');
+      expect(numbers.get('REQ-002')).toBe('2');
+      expect(numbers.get('REQ-004')).toBe('1.1');
+      expect(numbers.get('REQ-003')).toBe('1.2');
+      expect(numbers.get('REQ-005')).toBe('1.1.1');
+      expect(numbers.get('REQ-006')).toBe('3');
+    });
+
+    it('leaves nodes in a parent cycle unnumbered', () => {
+      const numbers = computeHierarchyNumbers([
+        { id: 'REQ-001' },
+        { id: 'REQ-002', parentId: 'REQ-003' },
+        { id: 'REQ-003', parentId: 'REQ-002' },
+      ]);
+
+      expect([...numbers.keys()]).toEqual(['REQ-001']);
+    });
+  });
+
+  describe('getSubtreeIds', () => {
+    it('returns the root and its descendants in tree order', () => {
+      expect(getSubtreeIds(nodes, 'REQ-001')).toEqual(['REQ-001', 'REQ-004', 'REQ-005', 'REQ-003']);
+      expect(getSubtreeIds(nodes, 'REQ-404')).toEqual([]);
+    });
+  });
+
+  describe('wouldCreateCycle', () => {
+    it('detects self and descendant parents', () => {
+      expect(wouldCreateCycle(nodes, 'REQ-001', 'REQ-001')).toBe(true);
+      expect(wouldCreateCycle(nodes, 'REQ-001', 'REQ-005')).toBe(true);
+      expect(wouldCreateCycle(nodes, 'REQ-005', 'REQ-002')).toBe(false);
+    });
+  });
+
+  describe('buildHierarchyTree', () => {
+    it('nests children under their parents', () => {
+      const tree = buildHierarchyTree(nodes);
+
+      expect(tree.map((n) => n.artifact.id)).toEqual(['REQ-001', 'REQ-002', 'REQ-006']);
+      expect(tree[0].children.map((n) => n.number)).toEqual(['1.1', '1.2']);
+      expect(tree[0].children[0].children[0].artifact.id).toBe('REQ-005');
+    });
+  });
+});
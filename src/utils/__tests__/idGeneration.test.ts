@@ -1,4 +1,5 @@
 import { describe, it, expect } from 'vitest';
+import { expandIdRange } from '../idGenerationUtils';
 
 describe('ID Generation Logic', () => {
   describe('Sequential Numbering', () => {
@@ -158,4 +159,17 @@ describe('ID Generation Logic', () => {
       expect(usedUcNumbers.has(3)).toBe(false);
     });
   });
+
+  describe('ID Ranges', () => {
+    it('should expand an inclusive range with padding', () => {
+      expect(expandIdRange('TC-010', 'TC-013')).toEqual(['TC-010', 'TC-011', 'TC-012', 'TC-013']);
+      expect(expandIdRange('REQ-005', 'REQ-005')).toEqual(['REQ-005']);
+    });
+
+    it('should reject mixed prefixes and reversed ranges', () => {
+      expect(() => expandIdRange('TC-001', 'REQ-003')).toThrow('single prefix');
+      expect(() => expandIdRange('TC-005', 'TC-001')).toThrow('reversed');
+      expect(() => expandIdRange('TC-1a', 'TC-003')).toThrow('Invalid ID range');
+    });
+  });
 });
@@ -0,0 +1,102 @@
+/**
+ * JUnit Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { matchTestCaseId, parseJUnitXml } from '../junitUtils';
+
+const JUNIT_REPORT = `<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="auth" tests="3">
+    <testcase classname="auth.LoginTest" name="TC-001 valid login" time="0.25"/>
+    <testcase classname="auth.LoginTest" name="rejects bad password" time="1.5">
+      <failure message="expected 401">stack trace</failure>
+    </testcase>
+    <testcase classname="auth.LogoutTest" name="logout">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>`;
+
+const XUNIT_REPORT = `<assemblies>
+  <assembly name="Tests.dll">
+    <collection>
+      <test name="Login works" type="Auth.TC_002" result="Pass" time="0.1"/>
+      <test name="Lockout" type="Auth.Lockout" result="Fail" time="0.2">
+        <failure><message>Account not locked</message></failure>
+      </test>
+    </collection>
+  </assembly>
+</assemblies>`;
+
+describe('junitUtils', () => {
+  describe('parseJUnitXml', () => {
+    it('should parse JUnit test cases with outcomes and durations', () => {
+      expect(parseJUnitXml(JUNIT_REPORT)).toEqual([
+        {
+          name: 'TC-001 valid login',
+          className: 'auth.LoginTest',
+          outcome: 'passed',
+          durationMs: 250,
+          message: undefined,
+        },
+        {
+          name: 'rejects bad password',
+          className: 'auth.LoginTest',
+          outcome: 'failed',
+          durationMs: 1500,
+          message: 'expected 401',
+        },
+        {
+          name: 'logout',
+          className: 'auth.LogoutTest',
+          outcome: 'skipped',
+          durationMs: undefined,
+          message: undefined,
+        },
+      ]);
+    });
+
+    it('should parse xUnit.net reports', () => {
+      const results = parseJUnitXml(XUNIT_REPORT);
+
+      expect(results.map((r) => [r.name, r.outcome, r.durationMs])).toEqual([
+        ['Login works', 'passed', 100],
+        ['Lockout', 'failed', 200],
+      ]);
+      expect(results[1].message).toBe('Account not locked');
+    });
+
+    it('should reject malformed XML', () => {
+      expect(() => parseJUnitXml('<testsuite><testcase></testsuite>')).toThrow(
+        'Invalid test report'
+      );
+    });
+  });
+
+  describe('matchTestCaseId', () => {
+    const testCases = [
+      { id: 'TC-001', title: 'Valid login' },
+      { id: 'TC-002', title: 'Rejects bad password' },
+    ];
+    const result = (name: string, className = '') => ({
+      name,
+      className,
+      outcome: 'passed' as const,
+    });
+
+    it('should match an ID in the test or class name', () => {
+      expect(matchTestCaseId(result('TC-001 valid login'), testCases)).toBe('TC-001');
+      expect(matchTestCaseId(result('works', 'suite.TC-002'), testCases)).toBe('TC-002');
+    });
+
+    it('should fall back to a case-insensitive title match', () => {
+      expect(matchTestCaseId(result('rejects bad password'), testCases)).toBe('TC-002');
+    });
+
+    it('should ignore unknown IDs and unmatched names', () => {
+      expect(matchTestCaseId(result('TC-099 something'), testCases)).toBeNull();
+      expect(matchTestCaseId(result('unrelated'), testCases)).toBeNull();
+    });
+  });
+});
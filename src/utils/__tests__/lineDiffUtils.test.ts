@@ -0,0 +1,30 @@
+/**
+ * Line Diff Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { countLineChanges, splitFrontmatter } from '../lineDiffUtils';
+
+describe('lineDiffUtils', () => {
+  it('counts added and removed lines', () => {
+    expect(countLineChanges('a\nb\nc\nd', 'a\nx\nc\nd\ne')).toEqual({ added: 2, removed: 1 });
+    expect(countLineChanges('a\nb\nc', 'c\nb\na')).toEqual({ added: 2, removed: 2 });
+  });
+
+  it('handles empty and identical texts', () => {
+    expect(countLineChanges('', 'a\nb\n')).toEqual({ added: 2, removed: 0 });
+    expect(countLineChanges('a\nb', '')).toEqual({ added: 0, removed: 2 });
+    expect(countLineChanges('a\r\nb\n', 'a\nb')).toEqual({ added: 0, removed: 0 });
+  });
+
+  it('splits frontmatter from the body', () => {
+    expect(splitFrontmatter('---\nid: REQ-001\n---\n# Title')).toEqual({
+      frontmatter: 'id: REQ-001',
+      body: '# Title',
+    });
+    expect(splitFrontmatter('# No frontmatter')).toEqual({
+      frontmatter: '',
+      body: '# No frontmatter',
+    });
+  });
+});
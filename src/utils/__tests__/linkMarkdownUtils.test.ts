@@ -305,6 +305,26 @@ lastModified: 1700000000000
       expect(parsed!.lastModified).toBe(original.lastModified);
     });
 
+    it('should preserve the suspect flag through round-trip', () => {
+      const original: Link = {
+        id: 'LINK-003',
+        sourceId: 'REQ-001',
+        targetId: 'TC-001',
+        type: 'verified_by',
+        projectIds: [],
+        dateCreated: 1700000000000,
+        lastModified: 1700000000000,
+        revision: '01',
+        suspect: true,
+        suspectSince: 1700000200000,
+      };
+
+      const parsed = parseMarkdownLink(linkToMarkdown(original));
+
+      expect(parsed!.suspect).toBe(true);
+      expect(parsed!.suspectSince).toBe(1700000200000);
+    });
+
     it('should preserve global links through round-trip', () => {
       const original: Link = {
         id: 'LINK-001',
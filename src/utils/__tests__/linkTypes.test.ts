@@ -0,0 +1,42 @@
+/**
+ * Link Types Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  LINK_INVERSE,
+  LINK_TYPE_SEMANTICS,
+  getLinkCategory,
+  isLinkInCategories,
+  propagatesImpact,
+  type LinkType,
+} from '../linkTypes';
+
+describe('linkTypes', () => {
+  it('gives every link type and its inverse the same category', () => {
+    (Object.keys(LINK_INVERSE) as LinkType[]).forEach((type) => {
+      expect(getLinkCategory(type)).toBe(getLinkCategory(LINK_INVERSE[type]));
+      expect(LINK_TYPE_SEMANTICS[type].propagatesImpact).toBe(
+        LINK_TYPE_SEMANTICS[LINK_INVERSE[type]].propagatesImpact
+      );
+    });
+  });
+
+  it('distinguishes verification from derivation links', () => {
+    expect(getLinkCategory('verifies')).toBe('verification');
+    expect(getLinkCategory('derived_from')).toBe('derivation');
+    expect(isLinkInCategories('verified_by', ['verification'])).toBe(true);
+    expect(isLinkInCategories('derives_to', ['verification'])).toBe(false);
+  });
+
+  it('returns undefined for unknown legacy types', () => {
+    expect(getLinkCategory('implements')).toBeUndefined();
+    expect(isLinkInCategories('implements', ['satisfaction'])).toBe(false);
+  });
+
+  it('propagates impact along all but association links', () => {
+    expect(propagatesImpact('verifies')).toBe(true);
+    expect(propagatesImpact('related_to')).toBe(false);
+    expect(propagatesImpact('implements')).toBe(true);
+  });
+});
@@ -0,0 +1,59 @@
+/**
+ * Markdown Import Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { classifyMarkdown, splitTitle, titleFromFileName } from '../markdownImportUtils';
+
+describe('markdownImportUtils', () => {
+  describe('classifyMarkdown', () => {
+    it('should use folder and file names as strong hints', () => {
+      expect(classifyMarkdown('specs/login.md', '# Login')).toBe('requirements');
+      expect(classifyMarkdown('qa/tests/login.md', '# Login')).toBe('testcases');
+      expect(classifyMarkdown('use-cases/checkout.md', '# Checkout')).toBe('usecases');
+      expect(classifyMarkdown('docs/risks.md', '# Open points')).toBe('risks');
+    });
+
+    it('should score content keywords', () => {
+      expect(classifyMarkdown('notes/login.md', 'The system shall lock. It must log.')).toBe(
+        'requirements'
+      );
+      expect(classifyMarkdown('docs/checkout.md', '## Actor\nCustomer\n## Main Flow\n1. Pay')).toBe(
+        'usecases'
+      );
+      expect(classifyMarkdown('docs/backup.md', 'Risk: data loss.\nMitigation: backups.')).toBe(
+        'risks'
+      );
+    });
+
+    it('should fall back to information', () => {
+      expect(classifyMarkdown('README.md', '# Project\n\nOverview of the system.')).toBe(
+        'information'
+      );
+      expect(classifyMarkdown('docs/latest.md', 'Release notes')).toBe('information');
+    });
+  });
+
+  describe('titleFromFileName', () => {
+    it('should build a readable title', () => {
+      expect(titleFromFileName('docs/login_flow-v2.md')).toBe('Login flow v2');
+    });
+  });
+
+  describe('splitTitle', () => {
+    it('should take the first heading as title', () => {
+      expect(splitTitle('a.md', 'Intro\n# Password rules\n\nAt least 12 characters.')).toEqual({
+        title: 'Password rules',
+        description: 'Intro\n\n\nAt least 12 characters.',
+      });
+    });
+
+    it('should prefer a frontmatter title and fall back to the file name', () => {
+      expect(splitTitle('a.md', '---\ntitle: "Login"\n---\n# Other').title).toBe('Login');
+      expect(splitTitle('docs/release-plan.md', 'No heading')).toEqual({
+        title: 'Release plan',
+        description: 'No heading',
+      });
+    });
+  });
+});
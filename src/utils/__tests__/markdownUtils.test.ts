@@ -27,6 +27,7 @@ import {
   projectToMarkdown,
   markdownToProject,
 } from '../markdownUtils';
+import { parseFrontmatter } from '../markdownBase';
 import type { Requirement, UseCase, TestCase, Information, User, Project } from '../../types';
 
 describe('Requirement Markdown Conversion', () => {
@@ -276,6 +277,24 @@ Test
       expect(parsed.revision).toBe(original.revision);
     });
 
+    it('should round-trip linked external issues', () => {
+      const original = {
+        id: 'REQ-001',
+        title: 'Test Requirement',
+        text: 'The system shall do something',
+        dateCreated: 1700000000000,
+        lastModified: 1700000000000,
+        revision: '01',
+        externalIssues: [{ tracker: 'jira', key: 'APP-42', status: 'In Progress' }],
+      } as Requirement;
+
+      const parsed = markdownToRequirement(requirementToMarkdown(original));
+
+      expect(parsed.externalIssues).toEqual(original.externalIssues);
+      const unlinked = requirementToMarkdown({ ...original, externalIssues: undefined });
+      expect(markdownToRequirement(unlinked).externalIssues).toBeUndefined();
+    });
+
     it('should handle array parsing in YAML list format', () => {
       // The parser now supports both YAML list format and JSON array format
       const markdown = `---
@@ -785,6 +804,68 @@ Desc
   });
 });
 
+describe('TOML and JSON Frontmatter', () => {
+  it('should read TOML frontmatter', () => {
+    const markdown = `+++
+id = "REQ-001"
+title = "Imported"
+status = "approved"
+tags = ["ui", "login"]
+dateCreated = 1700000000000
++++
+
+# Imported
+
+## Description
+From Hugo
+`;
+
+    const requirement = markdownToRequirement(markdown);
+    expect(requirement).toMatchObject({
+      id: 'REQ-001',
+      title: 'Imported',
+      status: 'approved',
+      tags: ['ui', 'login'],
+      dateCreated: 1700000000000,
+      description: 'From Hugo',
+    });
+  });
+
+  it('should read JSON frontmatter', () => {
+    const markdown = `{
+  "id": "REQ-002",
+  "title": "Braces } in \\"text\\"",
+  "linkedArtifacts": [{ "targetId": "TC-001", "type": "verified_by" }]
+}
+
+# Imported
+
+## Description
+From another tool
+`;
+
+    const requirement = markdownToRequirement(markdown);
+    expect(requirement.id).toBe('REQ-002');
+    expect(requirement.title).toBe('Braces } in "text"');
+    expect(requirement.linkedArtifacts).toEqual([{ targetId: 'TC-001', type: 'verified_by' }]);
+    expect(requirement.description).toBe('From another tool');
+  });
+
+  it('should write imported frontmatter back as YAML', () => {
+    const requirement = markdownToRequirement('+++\nid = "REQ-003"\ntitle = "T"\n+++\n\n# T');
+
+    expect(requirementToMarkdown(requirement).startsWith('---\nid: "REQ-003"')).toBe(true);
+  });
+
+  it('should treat invalid frontmatter as body', () => {
+    const { frontmatter, body, format } = parseFrontmatter('{ not json }\n\nText');
+
+    expect(frontmatter).toEqual({});
+    expect(format).toBeNull();
+    expect(body).toBe('{ not json }\n\nText');
+  });
+});
+
 describe('User Markdown Conversion', () => {
   describe('userToMarkdown', () => {
     it('should serialize all user fields', () => {
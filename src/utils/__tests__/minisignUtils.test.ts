@@ -0,0 +1,131 @@
+// @vitest-environment node
+/**
+ * Minisign Utilities Tests
+ */
+
+import { describe, it, expect, beforeAll, afterEach, vi } from 'vitest';
+import {
+  createPrivateKey,
+  createPublicKey,
+  generateKeyPairSync,
+  sign as signData,
+  verify as verifyData,
+} from 'node:crypto';
+import {
+  generateSigningKey,
+  getPublicKeyId,
+  getSignatureKeyId,
+  signMinisign,
+  verifyMinisign,
+  type SigningKey,
+} from '../minisignUtils';
+
+describe('minisignUtils', () => {
+  let key: SigningKey;
+
+  beforeAll(async () => {
+    key = await generateSigningKey();
+  });
+
+  it('should write keys in the minisign public key format', () => {
+    const [comment, line] = key.publicKey.trim().split('\n');
+    expect(comment).toBe(`untrusted comment: minisign public key ${key.keyId}`);
+    expect(atob(line).length).toBe(42);
+    expect(atob(line).slice(0, 2)).toBe('Ed');
+    expect(getPublicKeyId(key.publicKey)).toBe(key.keyId);
+    expect(key.keyId).toMatch(/^[0-9A-F]{16}$/);
+  });
+
+  it('should verify a signature with its trusted comment', async () => {
+    const signature = await signMinisign('manifest', key, 'timestamp:1\tbaseline:v1');
+
+    expect(signature.split('\n')[2]).toBe('trusted comment: timestamp:1\tbaseline:v1');
+    expect(getSignatureKeyId(signature)).toBe(key.keyId);
+    expect(await verifyMinisign('manifest', signature, key.publicKey)).toEqual({
+      valid: true,
+      keyId: key.keyId,
+      trustedComment: 'timestamp:1\tbaseline:v1',
+    });
+  });
+
+  it('should reject a changed message or trusted comment', async () => {
+    const signature = await signMinisign('manifest', key, 'timestamp:1');
+
+    const changedMessage = await verifyMinisign('manifest!', signature, key.publicKey);
+    expect(changedMessage.valid).toBe(false);
+    expect(changedMessage.error).toBe('Signature does not match the message');
+
+    const forged = signature.replace('timestamp:1', 'timestamp:2');
+    const changedComment = await verifyMinisign('manifest', forged, key.publicKey);
+    expect(changedComment.valid).toBe(false);
+    expect(changedComment.error).toBe('Trusted comment signature is invalid');
+  });
+
+  it('should reject a signature made with another key', async () => {
+    const other = await generateSigningKey();
+    const signature = await signMinisign('manifest', other, 'timestamp:1');
+
+    const result = await verifyMinisign('manifest', signature, key.publicKey);
+    expect(result.valid).toBe(false);
+    expect(result.keyId).toBe(other.keyId);
+    expect(result.error).toContain(`Signed with key ${other.keyId}`);
+  });
+
+  it('should report malformed signatures', async () => {
+    expect(getSignatureKeyId('not a signature')).toBeNull();
+    expect(await verifyMinisign('manifest', '', key.publicKey)).toMatchObject({
+      valid: false,
+      error: 'Malformed minisign data',
+    });
+    await expect(signMinisign('manifest', key, 'two\nlines')).rejects.toThrow('single line');
+  });
+
+  describe('in the desktop app', () => {
+    // The main process's Ed25519 handlers, answered with node:crypto
+    const signing = {
+      generateKey: vi.fn(async () => {
+        const pair = generateKeyPairSync('ed25519');
+        const { x } = pair.publicKey.export({ format: 'jwk' });
+        return {
+          publicKey: Array.from(Buffer.from(x!, 'base64url')),
+          privateKey: pair.privateKey.export({ format: 'der', type: 'pkcs8' }).toString('base64'),
+        };
+      }),
+      sign: vi.fn(async (privateKey: string, data: number[]) => {
+        const pkcs8 = createPrivateKey({
+          key: Buffer.from(privateKey, 'base64'),
+          format: 'der',
+          type: 'pkcs8',
+        });
+        return { signature: Array.from(signData(null, Buffer.from(data), pkcs8)) };
+      }),
+      verify: vi.fn(async (publicKey: number[], signature: number[], data: number[]) => {
+        const x = Buffer.from(publicKey).toString('base64url');
+        const raw = createPublicKey({ key: { kty: 'OKP', crv: 'Ed25519', x }, format: 'jwk' });
+        return { valid: verifyData(null, Buffer.from(data), raw, Buffer.from(signature)) };
+      }),
+    };
+
+    afterEach(() => {
+      vi.unstubAllGlobals();
+    });
+
+    it('should sign and verify through the main process', async () => {
+      vi.stubGlobal('window', { electronAPI: { signing } });
+      const appKey = await generateSigningKey();
+      const signature = await signMinisign('manifest', appKey, 'timestamp:1');
+
+      expect(await verifyMinisign('manifest', signature, appKey.publicKey)).toMatchObject({
+        valid: true,
+      });
+      expect(signing.sign).toHaveBeenCalledTimes(2);
+      expect(signing.verify).toHaveBeenCalledTimes(2);
+
+      // Keys and signatures are the same as WebCrypto's
+      vi.unstubAllGlobals();
+      expect(await verifyMinisign('manifest', signature, appKey.publicKey)).toMatchObject({
+        valid: true,
+      });
+    });
+  });
+});
@@ -65,7 +65,7 @@ const mockInfo: Information = {
   isDeleted: false,
 };
 import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { exportProjectToPDF } from '../pdfExportUtils';
+import { exportProjectToPDF, PDF_EXPORT_STAGES } from '../pdfExportUtils';
 import type { Project, Requirement, UseCase, TestCase, Information, Risk } from '../../types';
 import { formatDate } from '../dateUtils';
 import { diskLinkService } from '../../services/diskLinkService';
@@ -396,6 +396,34 @@ describe('pdfExportUtils', () => {
     expect(mockSave).toHaveBeenCalledWith('Test_Project - Current_State.pdf');
   });
 
+  it('should report progress for each export stage in order', async () => {
+    delete (window as unknown as Record<string, unknown>).showSaveFilePicker;
+    const onProgress = vi.fn();
+
+    await exportProjectToPDF(
+      mockProject,
+      globalState,
+      ['r1'],
+      [],
+      [],
+      [],
+      [],
+      null,
+      undefined,
+      true,
+      [],
+      onProgress
+    );
+
+    const stages = onProgress.mock.calls.map(([progress]) => progress.stage);
+    expect(stages).toEqual([...PDF_EXPORT_STAGES]);
+    expect(onProgress).toHaveBeenLastCalledWith({
+      stage: 'saving',
+      completed: PDF_EXPORT_STAGES.length - 1,
+      total: PDF_EXPORT_STAGES.length,
+    });
+  });
+
   it('should export all requirement attributes', async () => {
     const requirements: Requirement[] = [
       {
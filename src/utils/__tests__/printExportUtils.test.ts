@@ -0,0 +1,76 @@
+/**
+ * Print Export Utils Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { buildPrintHtml, type PrintArtifact } from '../printExportUtils';
+
+const artifacts: PrintArtifact[] = [
+  {
+    id: 'REQ-001',
+    path: 'requirements/REQ-001.md',
+    content: [
+      '---',
+      'id: REQ-001',
+      'title: Login',
+      'status: approved',
+      'priority: high',
+      '---',
+      '# Login',
+      '',
+      '## Description',
+      'Users sign in as described in UC-001 and [the lockout rule](REQ-002.md).',
+      '',
+      '![flow](./assets/flow.png)',
+      '',
+      '## Rationale',
+      '',
+      '## Requirement Text',
+      'See `REQ-002` and REQ-099.',
+    ].join('\n'),
+    links: [{ type: 'verified_by', targetId: 'TC-001' }],
+  },
+  {
+    id: 'REQ-002',
+    path: 'requirements/REQ-002.md',
+    content: '---\nid: REQ-002\ntitle: Lockout\n---\n# Lockout\n\n## Description\nLock after 5.',
+    links: [],
+  },
+  { id: 'UC-001', path: 'usecases/UC-001.md', content: null, links: [] },
+];
+
+describe('printExportUtils', () => {
+  const html = buildPrintHtml(artifacts, {
+    title: 'Login spec',
+    images: { './assets/flow.png': 'data:image/png;base64,AAAA' },
+    printedAt: new Date('2026-03-01T12:00:00Z'),
+  });
+
+  it('should number artifacts in the contents and headings', () => {
+    expect(html).toContain('<li>1. <a href="#REQ-001">REQ-001 – Login</a></li>');
+    expect(html).toContain('<h2>2. REQ-002 – Lockout</h2>');
+    expect(html).toContain('<h3>1.1 Description</h3>');
+    expect(html).toContain('<h3>1.2 Requirement Text</h3>');
+    expect(html).toContain('<h3>2.1 Description</h3>');
+  });
+
+  it('should drop the repeated title and empty sections', () => {
+    expect(html).not.toContain('<h1>Login</h1>');
+    expect(html).not.toContain('Rationale');
+  });
+
+  it('should turn references to artifacts in the set into anchors', () => {
+    expect(html).toContain('described in <a href="#UC-001">UC-001</a>');
+    expect(html).toContain('<a href="#REQ-002">the lockout rule</a>');
+    // Code and artifacts outside the set stay as written
+    expect(html).toContain('<code>REQ-002</code> and REQ-099.');
+    expect(html).toContain('<li>Verified by TC-001</li>');
+  });
+
+  it('should embed images and show fields and unreadable artifacts', () => {
+    expect(html).toContain('<img src="data:image/png;base64,AAAA" alt="flow"');
+    expect(html).toContain('<tr><th>status</th><td>approved</td></tr>');
+    expect(html).toContain('<h2>3. UC-001</h2>\n<p class="warning">Content could not be read.</p>');
+    expect(html).toContain('3 artifacts, printed 2026-03-01');
+  });
+});
@@ -0,0 +1,64 @@
+/**
+ * Quality Lint Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { lintMarkdown } from '../qualityLintUtils';
+
+const markdown = `---
+id: REQ-001
+title: Login
+---
+
+# Login
+
+## Requirement Text
+The system should lock the account after 3 failed attempts, as appropriate.
+The password is hashed with bcrypt and the value is automatically stored in the database. TBD
+
+\`\`\`
+The value may be logged.
+\`\`\``;
+
+describe('qualityLintUtils', () => {
+  it('reports ambiguous terms and passive voice with positions', () => {
+    const findings = lintMarkdown(markdown);
+
+    expect(findings.map((f) => [f.rule, f.line, f.column, f.length])).toEqual([
+      ['ambiguous-term', 9, 12, 6],
+      ['ambiguous-term', 9, 61, 14],
+      ['passive-voice', 10, 14, 9],
+      ['passive-voice', 10, 50, 23],
+      ['ambiguous-term', 10, 91, 3],
+    ]);
+  });
+
+  it('reports sentences longer than the limit', () => {
+    const findings = lintMarkdown('One two three. The operator acknowledges every active alarm.', {
+      maxSentenceWords: 5,
+    });
+
+    expect(findings).toEqual([
+      {
+        rule: 'long-sentence',
+        severity: 'warning',
+        message: 'Sentence has 6 words (max 5)',
+        line: 1,
+        column: 16,
+        length: 45,
+      },
+    ]);
+  });
+
+  it('requires a non-empty acceptance criteria section when asked', () => {
+    const options = { requireAcceptanceCriteria: true };
+
+    expect(lintMarkdown(markdown, options)[0]).toMatchObject({
+      rule: 'missing-acceptance-criteria',
+      line: 6,
+    });
+    expect(lintMarkdown('# T\n\n## Acceptance Criteria\n\n## Notes', options)).toHaveLength(1);
+    expect(lintMarkdown('# T\n\n## Acceptance Criteria\n- Given x, then y', options)).toEqual([]);
+    expect(lintMarkdown('**Acceptance criteria:** login succeeds', options)).toEqual([]);
+  });
+});
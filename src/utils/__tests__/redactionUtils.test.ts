@@ -0,0 +1,89 @@
+import { describe, it, expect } from 'vitest';
+import {
+  redactArtifact,
+  redactMarkdownFile,
+  redactText,
+  validateRedactionProfile,
+} from '../redactionUtils';
+import type { RedactionProfile } from '../../types';
+
+const profile: RedactionProfile = {
+  name: 'Supplier',
+  fields: ['comments', 'customer'],
+  sectionTags: ['internal'],
+  patterns: ['ACME( Corp)?'],
+};
+
+describe('redactText', () => {
+  it('should remove tagged sections with their subsections', () => {
+    const text = [
+      '## Flow',
+      'Log in.',
+      '## Customer notes #internal',
+      'Call Bob.',
+      '### Contacts',
+      'bob@example.com',
+      '## Acceptance',
+      'Works.',
+    ].join('\n');
+
+    expect(redactText(text, profile)).toBe('## Flow\nLog in.\n## Acceptance\nWorks.');
+  });
+
+  it('should replace pattern matches regardless of case', () => {
+    expect(redactText('Agreed with ACME Corp and acme support.', profile)).toBe(
+      'Agreed with [redacted] and [redacted] support.'
+    );
+  });
+
+  it('should not treat headings inside code blocks as sections', () => {
+    const text = '```\n# note #internal\n```\nKept.';
+
+    expect(redactText(text, profile)).toBe(text);
+  });
+});
+
+describe('redactArtifact', () => {
+  it('should leave out listed fields and custom attributes and redact text', () => {
+    const artifact = {
+      id: 'REQ-001',
+      title: 'Export for ACME',
+      text: 'The system shall export.',
+      comments: 'Internal only',
+      customAttributes: [
+        { attributeId: 'customer', value: 'ACME' },
+        { attributeId: 'safety-level', value: 'B' },
+      ],
+      tags: ['acme'],
+    };
+
+    expect(redactArtifact(artifact, profile)).toEqual({
+      id: 'REQ-001',
+      title: 'Export for [redacted]',
+      text: 'The system shall export.',
+      customAttributes: [{ attributeId: 'safety-level', value: 'B' }],
+      tags: ['acme'],
+    });
+  });
+});
+
+describe('redactMarkdownFile', () => {
+  it('should apply the profile to frontmatter and body', () => {
+    const content = '---\ntitle: Login\ncustomer: ACME\n---\nAsked by ACME.';
+
+    expect(redactMarkdownFile(content, profile)).toBe(
+      '---\ntitle: "Login"\n---\nAsked by [redacted].'
+    );
+  });
+});
+
+describe('validateRedactionProfile', () => {
+  it('should report invalid patterns and protected fields', () => {
+    const errors = validateRedactionProfile({ ...profile, fields: ['id'], patterns: ['(open'] });
+
+    expect(errors).toEqual([
+      'Invalid pattern in profile Supplier: (open',
+      'Fields cannot be redacted: id',
+    ]);
+  });
+});
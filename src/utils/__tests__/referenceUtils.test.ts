@@ -0,0 +1,98 @@
+/**
+ * Reference Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { extractReferences, extractWikiLinks, resolveRelativePath } from '../referenceUtils';
+
+describe('referenceUtils', () => {
+  describe('resolveRelativePath', () => {
+    it('should resolve sibling and parent paths', () => {
+      expect(resolveRelativePath('requirements/REQ-001.md', 'REQ-002.md')).toBe(
+        'requirements/REQ-002.md'
+      );
+      expect(resolveRelativePath('requirements/REQ-001.md', '../testcases/TC-001.md')).toBe(
+        'testcases/TC-001.md'
+      );
+      expect(resolveRelativePath('requirements/REQ-001.md', './../assets/a.png')).toBe(
+        'assets/a.png'
+      );
+    });
+
+    it('should treat leading slash as repository root', () => {
+      expect(resolveRelativePath('requirements/REQ-001.md', '/usecases/UC-001.md')).toBe(
+        'usecases/UC-001.md'
+      );
+    });
+  });
+
+  describe('extractReferences', () => {
+    it('should extract relative links and artifact IDs with line numbers', () => {
+      const markdown = [
+        '# REQ-001: Login',
+        '',
+        'Verified by [the test](../testcases/TC-003.md#steps).',
+        'See also UC-002 and RISK-010.',
+      ].join('\n');
+
+      const refs = extractReferences(markdown, 'requirements/REQ-001.md');
+
+      expect(refs).toContainEqual({ kind: 'path', target: 'testcases/TC-003.md', line: 3 });
+      expect(refs).toContainEqual({ kind: 'id', target: 'TC-003', line: 3 });
+      expect(refs).toContainEqual({ kind: 'id', target: 'UC-002', line: 4 });
+      expect(refs).toContainEqual({ kind: 'id', target: 'RISK-010', line: 4 });
+      expect(refs).toContainEqual({ kind: 'id', target: 'REQ-001', line: 1 });
+    });
+
+    it('should ignore external links and in-page anchors', () => {
+      const markdown = '[site](https://example.com) [mail](mailto:a@b.c) [top](#intro)';
+
+      expect(extractReferences(markdown, 'requirements/REQ-001.md')).toEqual([]);
+    });
+
+    it('should skip frontmatter and fenced code blocks', () => {
+      const markdown = [
+        '---',
+        'id: REQ-001',
+        'parentId: REQ-999',
+        '---',
+        '```',
+        'REQ-123 [x](missing.md)',
+        '```',
+        'Depends on REQ-002',
+      ].join('\n');
+
+      const refs = extractReferences(markdown, 'requirements/REQ-001.md');
+
+      expect(refs).toEqual([{ kind: 'id', target: 'REQ-002', line: 8 }]);
+    });
+
+    it('should include image links', () => {
+      const refs = extractReferences('![diagram](../assets/flow.png)', 'usecases/UC-001.md');
+
+      expect(refs).toEqual([{ kind: 'path', target: 'assets/flow.png', line: 1 }]);
+    });
+  });
+
+  describe('extractWikiLinks', () => {
+    it('should extract wiki links with optional type and label', () => {
+      const markdown = [
+        'See [[REQ-002]] and [[verifies:REQ-003|the login rule]].',
+        'Again [[REQ-002]], not [REQ-004](REQ-004.md) or [[ not an id ]]',
+      ].join('\n');
+
+      expect(extractWikiLinks(markdown)).toEqual([
+        { targetId: 'REQ-002', line: 1 },
+        { targetId: 'REQ-003', linkType: 'verifies', line: 1 },
+      ]);
+    });
+
+    it('should skip frontmatter and fenced code blocks', () => {
+      const markdown = ['---', 'note: "[[REQ-009]]"', '---', '```', '[[REQ-008]]', '```'].join(
+        '\n'
+      );
+
+      expect(extractWikiLinks(markdown)).toEqual([]);
+    });
+  });
+});
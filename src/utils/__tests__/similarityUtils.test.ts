@@ -0,0 +1,56 @@
+/**
+ * Similarity Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { cosineSimilarity, findSimilarPairs, shingle, tokenize } from '../similarityUtils';
+
+describe('similarityUtils', () => {
+  it('tokenizes into lowercase words', () => {
+    expect(tokenize("The user's e-mail, **REQ-001**!")).toEqual([
+      'the',
+      "user's",
+      'e-mail',
+      'req-001',
+    ]);
+  });
+
+  it('counts word shingles', () => {
+    expect([...shingle('a b c a b')]).toEqual([
+      ['a b', 2],
+      ['b c', 1],
+      ['c a', 1],
+    ]);
+    expect([...shingle('single', 3)]).toEqual([['single', 1]]);
+    expect(shingle('').size).toBe(0);
+  });
+
+  it('computes cosine similarity', () => {
+    expect(cosineSimilarity(shingle('same text'), shingle('Same text.'))).toBe(1);
+    expect(cosineSimilarity(shingle('one two'), shingle('three four'))).toBe(0);
+    expect(cosineSimilarity(shingle(''), shingle('text'))).toBe(0);
+  });
+
+  describe('findSimilarPairs', () => {
+    const documents = [
+      {
+        id: 'REQ-001',
+        text: 'The system shall lock the account after three failed login attempts.',
+      },
+      { id: 'REQ-002', text: 'The system shall lock the account after 3 failed login attempts.' },
+      { id: 'REQ-003', text: 'Reports are exported as PDF.' },
+      { id: 'REQ-004', text: '' },
+    ];
+
+    it('reports pairs at or above the threshold', () => {
+      expect(findSimilarPairs(documents)).toEqual([{ a: 'REQ-001', b: 'REQ-002', score: 0.8 }]);
+      expect(findSimilarPairs(documents, { threshold: 0.9 })).toEqual([]);
+    });
+
+    it('supports other shingle sizes', () => {
+      expect(findSimilarPairs(documents, { shingleSize: 1 })).toEqual([
+        { a: 'REQ-001', b: 'REQ-002', score: 0.923 },
+      ]);
+    });
+  });
+});
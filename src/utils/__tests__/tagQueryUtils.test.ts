@@ -0,0 +1,41 @@
+/**
+ * Tag Query Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { normalizeTag, parseTagQuery, matchesTagQuery } from '../tagQueryUtils';
+
+describe('tagQueryUtils', () => {
+  const matches = (expression: string, tags: string[]) =>
+    matchesTagQuery(parseTagQuery(expression), tags);
+
+  it('normalizes tags', () => {
+    expect(normalizeTag('  Safety Critical ')).toBe('safety-critical');
+  });
+
+  it('evaluates AND, OR and NOT', () => {
+    expect(matches('safety AND ui', ['safety', 'ui'])).toBe(true);
+    expect(matches('safety AND ui', ['safety'])).toBe(false);
+    expect(matches('safety OR ui', ['ui'])).toBe(true);
+    expect(matches('NOT legacy', ['ui'])).toBe(true);
+    expect(matches('NOT legacy', ['Legacy'])).toBe(false);
+  });
+
+  it('gives AND precedence over OR and honors parentheses', () => {
+    expect(matches('a OR b AND c', ['a'])).toBe(true);
+    expect(matches('(a OR b) AND c', ['a'])).toBe(false);
+    expect(matches('safety and (ui or not legacy)', ['safety'])).toBe(true);
+  });
+
+  it('combines adjacent terms with AND', () => {
+    expect(matches('safety ui', ['safety', 'ui'])).toBe(true);
+    expect(matches('safety ui', ['ui'])).toBe(false);
+  });
+
+  it('rejects malformed expressions', () => {
+    expect(() => parseTagQuery('')).toThrow('empty');
+    expect(() => parseTagQuery('safety AND')).toThrow('Incomplete');
+    expect(() => parseTagQuery('(safety OR ui')).toThrow('Missing closing parenthesis');
+    expect(() => parseTagQuery('safety)')).toThrow('Unexpected ")"');
+  });
+});
@@ -0,0 +1,74 @@
+/**
+ * Text Metrics Utilities Tests
+ */
+
+import { describe, it, expect } from 'vitest';
+import { aggregateTextMetrics, computeTextMetrics, countSyllables } from '../textMetricsUtils';
+
+const markdown = `---
+id: REQ-001
+---
+
+# Login
+
+## Requirement Text
+The system shall lock the account. It logs the event!
+
+## Steps
+- Enter the [password](../assets/password.md)
+- Press **OK**
+
+\`\`\`
+ignored code here.
+\`\`\``;
+
+describe('textMetricsUtils', () => {
+  it('estimates syllables', () => {
+    expect(countSyllables('the')).toBe(1);
+    expect(countSyllables('cake')).toBe(1);
+    expect(countSyllables('jumped')).toBe(1);
+    expect(countSyllables('table')).toBe(2);
+    expect(countSyllables('beautiful')).toBe(3);
+    expect(countSyllables('requirements')).toBe(4);
+  });
+
+  it('counts words and sentences outside frontmatter, headings and code', () => {
+    const metrics = computeTextMetrics(markdown);
+
+    expect(metrics).toMatchObject({
+      wordCount: 15,
+      sentenceCount: 4,
+      averageSentenceLength: 3.8,
+      readingEase: 90.2,
+    });
+  });
+
+  it('returns the heading outline', () => {
+    expect(computeTextMetrics(markdown).headings).toEqual([
+      { level: 1, text: 'Login', line: 5 },
+      { level: 2, text: 'Requirement Text', line: 7 },
+      { level: 2, text: 'Steps', line: 10 },
+    ]);
+  });
+
+  it('has no readability score without text', () => {
+    expect(computeTextMetrics('# Empty')).toMatchObject({ wordCount: 0, readingEase: null });
+  });
+
+  it('aggregates metrics', () => {
+    const aggregate = aggregateTextMetrics([
+      computeTextMetrics(markdown),
+      computeTextMetrics('# Empty'),
+    ]);
+
+    expect(aggregate).toEqual({
+      artifactCount: 2,
+      totalWords: 15,
+      averageWords: 7.5,
+      minWords: 0,
+      maxWords: 15,
+      averageReadingEase: 90.2,
+    });
+    expect(aggregateTextMetrics([])).toMatchObject({ artifactCount: 0, averageReadingEase: null });
+  });
+});
@@ -0,0 +1,63 @@
+import { describe, it, expect } from 'vitest';
+import { parseToml } from '../tomlUtils';
+
+describe('tomlUtils', () => {
+  describe('parseToml', () => {
+    it('parses scalars, strings and arrays', () => {
+      const toml = [
+        'title = "Say \\"hi\\"\\n" # comment',
+        "path = 'C:\\dir'",
+        'count = 1_000',
+        'ratio = -0.5',
+        'draft = false',
+        'date = 2024-05-01',
+        'tags = [',
+        '  "a", # first',
+        '  "b",',
+        ']',
+        'notes = """',
+        'Line one',
+        'Line two"""',
+      ].join('\n');
+
+      expect(parseToml(toml)).toEqual({
+        title: 'Say "hi"\n',
+        path: 'C:\\dir',
+        count: 1000,
+        ratio: -0.5,
+        draft: false,
+        date: '2024-05-01',
+        tags: ['a', 'b'],
+        notes: 'Line one\nLine two',
+      });
+    });
+
+    it('parses tables, dotted keys and inline tables', () => {
+      const toml = [
+        'meta.owner = "Ann"',
+        'link = { targetId = "TC-001", type = "verifies" }',
+        '[params]',
+        'level = 2',
+        '[[issues]]',
+        'key = "A-1"',
+        '[[issues]]',
+        'key = "A-2"',
+      ].join('\n');
+
+      expect(parseToml(toml)).toEqual({
+        meta: { owner: 'Ann' },
+        link: { targetId: 'TC-001', type: 'verifies' },
+        params: { level: 2 },
+        issues: [{ key: 'A-1' }, { key: 'A-2' }],
+      });
+    });
+
+    it('reports invalid input with its line', () => {
+      expect(() => parseToml('a = 1\nb = yes')).toThrow(
+        'Invalid TOML at line 2: invalid value yes'
+      );
+      expect(() => parseToml('a = 1\na = 2')).toThrow('a is defined twice');
+      expect(() => parseToml('a = "open')).toThrow('unterminated string');
+    });
+  });
+});
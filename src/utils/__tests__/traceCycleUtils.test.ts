@@ -0,0 +1,84 @@
+import { describe, it, expect } from 'vitest';
+import type { Link } from '../../types';
+import type { LinkType } from '../linkTypes';
+import { findCycleThroughLink, findTraceCycles, formatCyclePath } from '../traceCycleUtils';
+
+const link = (sourceId: string, type: LinkType, targetId: string) =>
+  ({ sourceId, targetId, type }) as Link;
+
+describe('traceCycleUtils', () => {
+  describe('findTraceCycles', () => {
+    it('should find no cycles in a proper decomposition', () => {
+      expect(
+        findTraceCycles([
+          link('REQ-001', 'derived_from', 'SYS-001'),
+          link('REQ-002', 'refines', 'SYS-001'),
+          link('SYS-001', 'derives_to', 'REQ-003'),
+          link('REQ-003', 'refined_by', 'REQ-004'),
+        ])
+      ).toEqual([]);
+    });
+
+    it('should read both directions of derivation and refinement links', () => {
+      const cycles = findTraceCycles([
+        link('REQ-001', 'derived_from', 'SYS-001'),
+        link('SYS-001', 'refines', 'REQ-002'),
+        link('SYS-002', 'derives_to', 'REQ-002'),
+        link('REQ-001', 'refined_by', 'SYS-002'),
+      ]);
+
+      expect(cycles).toEqual([['REQ-001', 'SYS-001', 'REQ-002', 'SYS-002', 'REQ-001']]);
+    });
+
+    it('should ignore links of other types', () => {
+      expect(
+        findTraceCycles([
+          link('REQ-001', 'derived_from', 'SYS-001'),
+          link('SYS-001', 'depends_on', 'REQ-001'),
+          link('SYS-001', 'related_to', 'REQ-001'),
+        ])
+      ).toEqual([]);
+    });
+
+    it('should report each cycle once', () => {
+      const cycles = findTraceCycles([
+        link('REQ-001', 'derived_from', 'REQ-002'),
+        link('REQ-002', 'derived_from', 'REQ-001'),
+        link('REQ-002', 'refines', 'REQ-001'),
+        link('REQ-003', 'refines', 'REQ-003'),
+      ]);
+
+      expect(cycles.map(formatCyclePath)).toEqual([
+        'REQ-001 → REQ-002 → REQ-001',
+        'REQ-003 → REQ-003',
+      ]);
+    });
+  });
+
+  describe('findCycleThroughLink', () => {
+    const links = [
+      link('REQ-001', 'derived_from', 'SYS-001'),
+      link('SYS-001', 'derived_from', 'STK-001'),
+    ];
+
+    it('should return the cycle a new link would close', () => {
+      expect(findCycleThroughLink(links, link('STK-001', 'refines', 'REQ-001'))).toEqual([
+        'STK-001',
+        'REQ-001',
+        'SYS-001',
+        'STK-001',
+      ]);
+      expect(findCycleThroughLink(links, link('REQ-001', 'derives_to', 'STK-001'))).toEqual([
+        'STK-001',
+        'REQ-001',
+        'SYS-001',
+        'STK-001',
+      ]);
+    });
+
+    it('should accept links that close no cycle', () => {
+      expect(findCycleThroughLink(links, link('REQ-002', 'derived_from', 'SYS-001'))).toBeNull();
+      expect(findCycleThroughLink(links, link('STK-001', 'verifies', 'REQ-001'))).toBeNull();
+    });
+  });
+});
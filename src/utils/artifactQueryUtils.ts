@@ -0,0 +1,106 @@
+/**
+ * Artifact Query Utilities
+ *
+ * Validates and evaluates artifact queries (see SavedQuery): status,
+ * priority, tag expressions, text terms and conditions on links, all of
+ * which must hold for an artifact to match.
+ */
+
+import type { ArtifactQuery, Link } from '../types';
+import { getTypeFromId } from '../constants/artifactConfig';
+import { getInverseType, LINK_TYPE_LABELS, type LinkType } from './linkTypes';
+import { matchesTagQuery, parseTagQuery, type TagQuery } from './tagQueryUtils';
+
+export interface QueryableArtifact {
+  id: string;
+  title?: string;
+  name?: string;
+  description?: string;
+  text?: string;
+  status?: string;
+  priority?: string;
+  tags?: string[];
+  isDeleted?: boolean;
+}
+
+interface LinkEnd {
+  type: LinkType;
+  otherId: string;
+}
+
+/**
+ * Problems with a query; empty when it can be run
+ */
+export function validateArtifactQuery(
+  query: ArtifactQuery,
+  knownTypes: readonly string[]
+): string[] {
+  const errors: string[] = [];
+  if (query.types.length === 0) {
+    errors.push('Query must search at least one artifact type');
+  }
+  query.types
+    .filter((type) => !knownTypes.includes(type))
+    .forEach((type) => errors.push(`Unknown artifact type: ${type}`));
+  if (query.tags?.trim()) {
+    try {
+      parseTagQuery(query.tags);
+    } catch (err) {
+      errors.push(err instanceof Error ? err.message : String(err));
+    }
+  }
+  (query.links ?? [])
+    .filter((predicate) => !(predicate.type in LINK_TYPE_LABELS))
+    .forEach((predicate) => errors.push(`Unknown link type: ${predicate.type}`));
+  return errors;
+}
+
+/**
+ * Links of every artifact as seen from its side, for link predicates
+ */
+export function buildLinkIndex(links: Link[]): Map<string, LinkEnd[]> {
+  const index = new Map<string, LinkEnd[]>();
+  const add = (id: string, end: LinkEnd) => index.set(id, [...(index.get(id) || []), end]);
+  links.forEach((link) => {
+    add(link.sourceId, { type: link.type, otherId: link.targetId });
+    add(link.targetId, { type: getInverseType(link.type), otherId: link.sourceId });
+  });
+  return index;
+}
+
+/**
+ * Predicate for one query; parse once and apply to many artifacts
+ */
+export function compileArtifactQuery(
+  query: ArtifactQuery,
+  linkIndex: Map<string, LinkEnd[]>
+): (artifact: QueryableArtifact) => boolean {
+  const tagQuery: TagQuery | null = query.tags?.trim() ? parseTagQuery(query.tags) : null;
+  const words = (query.text || '').toLowerCase().split(/\s+/).filter(Boolean);
+
+  return (artifact) => {
+    if (artifact.isDeleted) return false;
+    if (query.status?.length && !query.status.includes(artifact.status ?? '')) return false;
+    if (query.excludeStatus?.includes(artifact.status ?? '')) return false;
+    if (query.priority?.length && !query.priority.includes(artifact.priority ?? '')) return false;
+    if (tagQuery && !matchesTagQuery(tagQuery, artifact.tags)) return false;
+
+    if (words.length > 0) {
+      const haystack = [artifact.title, artifact.name, artifact.description, artifact.text]
+        .filter(Boolean)
+        .join(' ')
+        .toLowerCase();
+      if (!words.every((word) => haystack.includes(word))) return false;
+    }
+
+    const ends = linkIndex.get(artifact.id) || [];
+    return (query.links ?? []).every((predicate) => {
+      const found = ends.some(
+        (end) =>
+          end.type === predicate.type &&
+          (!predicate.targetType || getTypeFromId(end.otherId) === predicate.targetType)
+      );
+      return found === predicate.present;
+    });
+  };
+}
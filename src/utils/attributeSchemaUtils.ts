@@ -0,0 +1,98 @@
+/**
+ * Attribute Schema Utilities
+ *
+ * Checks attribute schemas and validates artifact attribute values against
+ * the fields declared for their type (required, data type, allowed values).
+ */
+
+import type {
+  AttributeSchema,
+  AttributeSchemaField,
+  AttributeType,
+  CustomAttributeValue,
+} from '../types/customAttributes';
+
+export interface AttributeViolation {
+  attribute: string;
+  message: string;
+}
+
+const FIELD_NAME_PATTERN = /^[a-zA-Z][a-zA-Z0-9_]*$/;
+const ATTRIBUTE_TYPES: AttributeType[] = ['text', 'number', 'date', 'dropdown', 'checkbox'];
+
+/**
+ * Problems with a schema definition, e.g. invalid names or dropdowns without options
+ */
+export function validateAttributeSchema(schema: AttributeSchema): string[] {
+  const errors: string[] = [];
+  Object.entries(schema).forEach(([typeKey, fields]) => {
+    const seen = new Set<string>();
+    (fields || []).forEach((field) => {
+      const label = `${typeKey}.${field.name}`;
+      if (!FIELD_NAME_PATTERN.test(field.name || '')) {
+        errors.push(`${label}: invalid attribute name`);
+      } else if (seen.has(field.name.toLowerCase())) {
+        errors.push(`${label}: duplicate attribute name`);
+      }
+      seen.add((field.name || '').toLowerCase());
+      if (!ATTRIBUTE_TYPES.includes(field.type)) {
+        errors.push(`${label}: unknown data type "${field.type}"`);
+      }
+      if (field.type === 'dropdown' && !field.options?.length) {
+        errors.push(`${label}: dropdown needs allowed values`);
+      }
+    });
+  });
+  return errors;
+}
+
+type AttributeValue = CustomAttributeValue['value'];
+
+function isEmpty(value: AttributeValue | undefined): boolean {
+  return value === undefined || value === null || value === '';
+}
+
+function checkType(field: AttributeSchemaField, value: AttributeValue): string | null {
+  switch (field.type) {
+    case 'number':
+      return typeof value === 'number' || !Number.isNaN(Number(value)) ? null : 'must be a number';
+    case 'checkbox':
+      return typeof value === 'boolean' ? null : 'must be true or false';
+    case 'date':
+      return typeof value === 'number' || !Number.isNaN(Date.parse(String(value)))
+        ? null
+        : 'must be a date';
+    default:
+      break;
+  }
+  if (field.options?.length && !field.options.includes(String(value))) {
+    return `must be one of: ${field.options.join(', ')}`;
+  }
+  return null;
+}
+
+/**
+ * Violations of an artifact's attribute values against the schema fields of its type
+ */
+export function validateAttributeValues(
+  values: CustomAttributeValue[] | undefined,
+  fields: AttributeSchemaField[]
+): AttributeViolation[] {
+  const byName = new Map((values || []).map((v) => [v.attributeId, v.value]));
+  const violations: AttributeViolation[] = [];
+
+  fields.forEach((field) => {
+    const value = byName.get(field.name);
+    if (isEmpty(value)) {
+      if (field.required) {
+        violations.push({ attribute: field.name, message: `${field.name} is required` });
+      }
+      return;
+    }
+    const problem = checkType(field, value!);
+    if (problem) {
+      violations.push({ attribute: field.name, message: `${field.name} ${problem}` });
+    }
+  });
+  return violations;
+}
@@ -0,0 +1,168 @@
+/**
+ * Baseline Export Utilities
+ *
+ * Renders a baseline as a frozen, self-contained HTML document: every
+ * artifact as it was at its baseline commit, with the baseline metadata
+ * (version, tag, approver, hash manifest) embedded both visibly and as JSON,
+ * so the deliverable can be archived outside the repository exactly as
+ * approved.
+ */
+
+import { createElement } from 'react';
+import { renderToStaticMarkup } from 'react-dom/server';
+import ReactMarkdown from 'react-markdown';
+import remarkGfm from 'remark-gfm';
+import type { BaselineArtifact, BaselineContents } from '../services/baselineService';
+import { parseYamlFrontmatter } from './markdownBase';
+import { isEncryptedContent } from './encryptionUtils';
+import { saveFile } from './fileSaveUtils';
+
+export const SNAPSHOT_METADATA_ID = 'tracyfy-baseline';
+
+// Frontmatter fields shown above each artifact
+const SNAPSHOT_FIELDS = ['status', 'priority', 'revision', 'author'];
+
+const SNAPSHOT_STYLE = `
+body { font-family: system-ui, sans-serif; max-width: 900px; margin: 0 auto; padding: 24px; }
+table { border-collapse: collapse; margin: 8px 0; }
+th, td { border: 1px solid #d1d5db; padding: 4px 8px; text-align: left; vertical-align: top; }
+section { border-top: 1px solid #d1d5db; margin-top: 24px; }
+.meta { color: #6b7280; font-size: 0.85em; }
+.warning { color: #b91c1c; }`;
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+function renderMarkdown(markdown: string): string {
+  return renderToStaticMarkup(
+    createElement(ReactMarkdown, { remarkPlugins: [remarkGfm] }, markdown)
+  );
+}
+
+function renderArtifact(artifact: BaselineArtifact): string {
+  const heading = (title?: unknown) =>
+    typeof title === 'string'
+      ? `<h2>${escapeHtml(artifact.id)} – ${escapeHtml(title)}</h2>`
+      : `<h2>${escapeHtml(artifact.id)}</h2>`;
+  const open = `<section id="${escapeHtml(artifact.id)}">`;
+  const footer = `<p class="meta">Commit ${escapeHtml(artifact.commitHash)}</p>\n</section>`;
+  if (artifact.content === null || isEncryptedContent(artifact.content)) {
+    const reason = artifact.content === null ? 'could not be read' : 'is encrypted';
+    return [open, heading(), `<p class="warning">Content ${reason}.</p>`, footer].join('\n');
+  }
+
+  const { frontmatter, body } = parseYamlFrontmatter(artifact.content);
+  const rows = SNAPSHOT_FIELDS.filter((field) => frontmatter[field] !== undefined)
+    .map((field) => `<tr><th>${field}</th><td>${escapeHtml(String(frontmatter[field]))}</td></tr>`)
+    .join('');
+  const warning = artifact.intact
+    ? ''
+    : '<p class="warning">Content does not match the baseline manifest.</p>';
+  return [
+    open,
+    heading(frontmatter.title),
+    rows ? `<table>${rows}</table>` : '',
+    warning,
+    renderMarkdown(body),
+    footer,
+  ].join('\n');
+}
+
+/**
+ * The baseline as a standalone, read-only HTML document
+ */
+export function buildBaselineSnapshotHtml(
+  contents: BaselineContents,
+  projectName: string,
+  exportedAt: Date = new Date()
+): string {
+  const { baseline } = contents;
+  const artifacts = [...contents.artifacts].sort((a, b) =>
+    a.id.localeCompare(b.id, undefined, { numeric: true })
+  );
+  const metadata = {
+    project: projectName,
+    id: baseline.id,
+    name: baseline.name,
+    version: baseline.version,
+    description: baseline.description,
+    tag: baseline.tag,
+    approver: baseline.approver,
+    createdAt: new Date(baseline.timestamp).toISOString(),
+    exportedAt: exportedAt.toISOString(),
+    artifacts: Object.fromEntries(
+      artifacts.map((a) => [a.id, { commit: a.commitHash, hash: baseline.manifest?.[a.id] }])
+    ),
+  };
+  // Keep "</script>" in values from ending the JSON block
+  const metadataJson = JSON.stringify(metadata, null, 2).replace(/</g, '\\u003c');
+
+  const summary = [
+    ['Version', baseline.version],
+    ['Tag', baseline.tag],
+    ['Created', metadata.createdAt],
+    ['Approver', baseline.approver],
+    ['Artifacts', String(artifacts.length)],
+  ]
+    .filter((row): row is [string, string] => !!row[1])
+    .map(([label, value]) => `<tr><th>${label}</th><td>${escapeHtml(value)}</td></tr>`)
+    .join('');
+  const toc = artifacts
+    .map((a) => `<li><a href="#${escapeHtml(a.id)}">${escapeHtml(a.id)}</a></li>`)
+    .join('');
+  const title = `${projectName} – Baseline ${baseline.version}`;
+
+  return `<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>${escapeHtml(title)}</title>
+<meta name="generator" content="Tracyfy">
+<script type="application/json" id="${SNAPSHOT_METADATA_ID}">
+${metadataJson}
+</script>
+<style>${SNAPSHOT_STYLE}
+</style>
+</head>
+<body>
+<header>
+<h1>${escapeHtml(title)}</h1>
+${baseline.description ? `<p>${escapeHtml(baseline.description)}</p>` : ''}
+<table>${summary}</table>
+<p class="meta">Frozen snapshot exported ${metadata.exportedAt}. Do not edit.</p>
+</header>
+<nav><ul>${toc}</ul></nav>
+${artifacts.map(renderArtifact).join('\n')}
+</body>
+</html>
+`;
+}
+
+/**
+ * File name for a baseline snapshot, e.g. "My_Project_baseline_1.0.html"
+ */
+export function getSnapshotFileName(projectName: string, version: string): string {
+  const safe = (text: string) => text.replace(/[^a-z0-9.-]/gi, '_');
+  return `${safe(projectName)}_baseline_${safe(version)}.html`;
+}
+
+/**
+ * Render a baseline snapshot and save it outside the repository, via the
+ * save file picker when available and as a download otherwise
+ */
+export async function exportBaselineSnapshot(
+  contents: BaselineContents,
+  projectName: string
+): Promise<void> {
+  const html = buildBaselineSnapshotHtml(contents, projectName);
+  const filename = getSnapshotFileName(projectName, contents.baseline.version);
+  await saveFile(new Blob([html], { type: 'text/html' }), filename, {
+    description: 'HTML File',
+    accept: { 'text/html': ['.html'] },
+  });
+}
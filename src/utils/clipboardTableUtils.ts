@@ -0,0 +1,86 @@
+/**
+ * Clipboard Table Utilities
+ *
+ * Renders a table (header row plus data rows) as the two clipboard flavors
+ * office tools read: HTML for Word and Confluence, tab-separated text for
+ * Excel and plain-text targets.
+ */
+
+export interface ClipboardTable {
+  html: string;
+  text: string;
+}
+
+// Fields of an artifact shown in a copied artifact list
+export interface ArtifactListItem {
+  id: string;
+  title?: string;
+  name?: string;
+  status?: string;
+  priority?: string;
+  author?: string;
+}
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+// Quote cells that would otherwise split the row or column, as Excel does
+function escapeTsvCell(value: string): string {
+  return /[\t\n\r"]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value;
+}
+
+/**
+ * Tab-separated text, one line per row
+ */
+export function tableToTsv(table: string[][]): string {
+  return table.map((row) => row.map(escapeTsvCell).join('\t')).join('\n');
+}
+
+/**
+ * HTML table with the first row as header; borders are inline so they
+ * survive pasting into editors that drop style sheets
+ */
+export function tableToHtml(table: string[][]): string {
+  const cellStyle = 'border: 1px solid #d1d5db; padding: 4px 8px; vertical-align: top;';
+  const renderRow = (row: string[], tag: 'th' | 'td') =>
+    `<tr>${row
+      .map((cell) => {
+        const content = escapeHtml(cell).replace(/\r?\n/g, '<br>');
+        return `<${tag} style="${cellStyle}">${content}</${tag}>`;
+      })
+      .join('')}</tr>`;
+  const [header = [], ...body] = table;
+  return (
+    '<table style="border-collapse: collapse;">' +
+    `<thead>${renderRow(header, 'th')}</thead>` +
+    `<tbody>${body.map((row) => renderRow(row, 'td')).join('')}</tbody>` +
+    '</table>'
+  );
+}
+
+/**
+ * Both clipboard flavors of a table
+ */
+export function tableToClipboard(table: string[][]): ClipboardTable {
+  return { html: tableToHtml(table), text: tableToTsv(table) };
+}
+
+/**
+ * Flatten a list of artifacts into a table, in the given order
+ */
+export function artifactsToTable(artifacts: ArtifactListItem[]): string[][] {
+  const header = ['ID', 'Title', 'Status', 'Priority', 'Author'];
+  const body = artifacts.map((artifact) => [
+    artifact.id,
+    artifact.title ?? artifact.name ?? '',
+    artifact.status ?? '',
+    artifact.priority ?? '',
+    artifact.author ?? '',
+  ]);
+  return [header, ...body];
+}
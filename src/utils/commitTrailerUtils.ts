@@ -0,0 +1,48 @@
+/**
+ * Commit Trailer Utilities
+ *
+ * Reads and writes git commit trailers (`Key: value` lines in the last
+ * paragraph of a commit message), used to tie commits to change requests.
+ */
+
+export const CHANGE_REQUEST_TRAILER = 'Change-Request';
+
+const TRAILER_LINE = /^([A-Za-z0-9-]+):\s*(.+)$/;
+
+/**
+ * Parse the trailers of a commit message. Only the last paragraph counts,
+ * and only when every line in it is a trailer.
+ */
+export function parseTrailers(message: string): { key: string; value: string }[] {
+  const paragraphs = message.trim().split(/\n\s*\n/);
+  if (paragraphs.length < 2) return [];
+
+  const lines = paragraphs[paragraphs.length - 1].split('\n').map((line) => line.trim());
+  const matches = lines.map((line) => line.match(TRAILER_LINE));
+  if (matches.some((match) => !match)) return [];
+
+  return matches.map((match) => ({ key: match![1], value: match![2].trim() }));
+}
+
+/**
+ * Append a trailer to a commit message (no-op if the same trailer exists)
+ */
+export function addTrailer(message: string, key: string, value: string): string {
+  const trimmed = message.trim();
+  const trailers = parseTrailers(trimmed);
+  if (trailers.some((t) => t.key.toLowerCase() === key.toLowerCase() && t.value === value)) {
+    return trimmed;
+  }
+  const separator = trailers.length > 0 ? '\n' : '\n\n';
+  return `${trimmed}${separator}${key}: ${value}`;
+}
+
+/**
+ * Change request IDs referenced by a commit message's trailers
+ */
+export function getChangeRequestIds(message: string): string[] {
+  return parseTrailers(message)
+    .filter((t) => t.key.toLowerCase() === CHANGE_REQUEST_TRAILER.toLowerCase())
+    .flatMap((t) => t.value.split(/[\s,]+/))
+    .filter((id) => id.length > 0);
+}
@@ -0,0 +1,45 @@
+/**
+ * Compliance Export Utilities
+ *
+ * Writes a compliance matrix (header row plus data rows) as an Excel or CSV
+ * file for auditors.
+ */
+
+import * as XLSX from 'xlsx';
+import type { RedactionProfile } from '../types/redaction';
+import { redactTable } from './redactionUtils';
+
+export type ComplianceExportFormat = 'xlsx' | 'csv';
+
+/**
+ * Build a workbook with the matrix on a single sheet, with column widths
+ * fitted to the content
+ */
+export function complianceMatrixToWorkbook(table: string[][], sheetName: string): XLSX.WorkBook {
+  const sheet = XLSX.utils.aoa_to_sheet(table);
+  sheet['!cols'] = (table[0] || []).map((_, col) => ({
+    wch: Math.min(60, Math.max(10, ...table.map((row) => (row[col] || '').length))),
+  }));
+  const workbook = XLSX.utils.book_new();
+  // Excel limits sheet names to 31 characters without []:*?/\
+  XLSX.utils.book_append_sheet(workbook, sheet, sheetName.replace(/[[\]:*?/\\]/g, '').slice(0, 31));
+  return workbook;
+}
+
+/**
+ * Download a compliance matrix, e.g. as "My_Project_iso-26262.xlsx", with the
+ * redaction profile applied to its cells when one is given
+ */
+export function exportComplianceMatrix(
+  table: string[][],
+  fileBaseName: string,
+  sheetName: string,
+  format: ComplianceExportFormat = 'xlsx',
+  redaction?: RedactionProfile | null
+): void {
+  const rows = redaction ? redactTable(table, redaction) : table;
+  const workbook = complianceMatrixToWorkbook(rows, sheetName);
+  XLSX.writeFile(workbook, `${fileBaseName.replace(/[^a-z0-9.-]/gi, '_')}.${format}`, {
+    bookType: format,
+  });
+}
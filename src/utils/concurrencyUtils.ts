@@ -0,0 +1,40 @@
+/**
+ * Concurrency Utilities
+ *
+ * Runs work over many files a bounded number at a time, so scans, validation
+ * and exports overlap their file IO without opening thousands of reads (or
+ * IPC calls) at once on large projects.
+ */
+
+// Files read at the same time by the commands touching the whole project
+export const MAX_PARALLEL_READS = 16;
+
+/**
+ * Map items with an async function, at most limit at a time. Results keep
+ * the order of the items; the first failure rejects and starts no more work.
+ */
+export async function mapWithConcurrency<T, R>(
+  items: readonly T[],
+  fn: (item: T, index: number) => Promise<R>,
+  limit: number = MAX_PARALLEL_READS
+): Promise<R[]> {
+  const results = new Array<R>(items.length);
+  let next = 0;
+  let failed = false;
+
+  const worker = async () => {
+    while (!failed && next < items.length) {
+      const index = next++;
+      try {
+        results[index] = await fn(items[index], index);
+      } catch (err) {
+        failed = true;
+        throw err;
+      }
+    }
+  };
+
+  const workers = Array.from({ length: Math.min(Math.max(1, limit), items.length) }, worker);
+  await Promise.all(workers);
+  return results;
+}
@@ -0,0 +1,48 @@
+/**
+ * Content Hash Utilities
+ *
+ * Hashes the reviewable content of an artifact markdown file so approvals can
+ * detect later edits. Workflow bookkeeping fields (status, timestamps) are
+ * excluded so that marking an artifact approved does not change its hash.
+ */
+
+import { parseYamlFrontmatter } from './markdownBase';
+
+// Frontmatter keys that change without the content being edited
+export const CONTENT_HASH_IGNORED_KEYS = [
+  'status',
+  'lastModified',
+  'statusChangedBy',
+  'statusChangedAt',
+  'approvalDate',
+];
+
+/**
+ * SHA-256 of a string as lowercase hex
+ */
+export async function sha256Hex(text: string): Promise<string> {
+  const digest = await globalThis.crypto.subtle.digest('SHA-256', new TextEncoder().encode(text));
+  return Array.from(new Uint8Array(digest))
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('');
+}
+
+/**
+ * Canonical form of an artifact markdown file: sorted frontmatter without
+ * bookkeeping keys, followed by the trimmed body
+ */
+export function canonicalArtifactContent(markdown: string): string {
+  const { frontmatter, body } = parseYamlFrontmatter(markdown);
+  const keys = Object.keys(frontmatter)
+    .filter((key) => !CONTENT_HASH_IGNORED_KEYS.includes(key))
+    .sort();
+  const canonical = Object.fromEntries(keys.map((key) => [key, frontmatter[key]]));
+  return `${JSON.stringify(canonical)}\n${body.trim()}`;
+}
+
+/**
+ * Hash of the reviewable content of an artifact markdown file
+ */
+export async function computeContentHash(markdown: string): Promise<string> {
+  return sha256Hex(canonicalArtifactContent(markdown));
+}
@@ -0,0 +1,132 @@
+/**
+ * Encoding Utilities
+ *
+ * Detects the text encoding of file content (UTF-8 with or without BOM,
+ * UTF-16 LE/BE, Latin-1) so files created by Windows tools can be read, and
+ * encodes text back into a given encoding when writes should preserve it.
+ */
+
+export type TextEncodingName = 'utf-8' | 'utf-8-bom' | 'utf-16le' | 'utf-16be' | 'latin1';
+
+export interface DecodedText {
+  content: string;
+  encoding: TextEncodingName;
+}
+
+/**
+ * Whether the bytes form valid UTF-8
+ */
+export function isValidUtf8(bytes: Uint8Array): boolean {
+  try {
+    new TextDecoder('utf-8', { fatal: true }).decode(bytes);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Guess UTF-16 without a BOM: mostly-ASCII text has a zero byte in every
+ * other position
+ */
+function detectBomlessUtf16(bytes: Uint8Array): 'utf-16le' | 'utf-16be' | null {
+  if (bytes.length < 4 || bytes.length % 2 !== 0) return null;
+
+  let evenZeros = 0;
+  let oddZeros = 0;
+  for (let i = 0; i < bytes.length; i += 2) {
+    if (bytes[i] === 0) evenZeros++;
+    if (bytes[i + 1] === 0) oddZeros++;
+  }
+
+  const pairs = bytes.length / 2;
+  if (oddZeros > pairs * 0.4 && evenZeros < pairs * 0.1) return 'utf-16le';
+  if (evenZeros > pairs * 0.4 && oddZeros < pairs * 0.1) return 'utf-16be';
+  return null;
+}
+
+/**
+ * Detect the encoding of file content. Anything that is neither UTF-16 nor
+ * valid UTF-8 is treated as Latin-1, which can decode any byte sequence.
+ */
+export function detectEncoding(bytes: Uint8Array): TextEncodingName {
+  if (bytes[0] === 0xef && bytes[1] === 0xbb && bytes[2] === 0xbf) return 'utf-8-bom';
+  if (bytes[0] === 0xff && bytes[1] === 0xfe) return 'utf-16le';
+  if (bytes[0] === 0xfe && bytes[1] === 0xff) return 'utf-16be';
+
+  const utf16 = detectBomlessUtf16(bytes);
+  if (utf16) return utf16;
+  return isValidUtf8(bytes) ? 'utf-8' : 'latin1';
+}
+
+function decodeUtf16(bytes: Uint8Array, littleEndian: boolean): string {
+  const hasBom = littleEndian
+    ? bytes[0] === 0xff && bytes[1] === 0xfe
+    : bytes[0] === 0xfe && bytes[1] === 0xff;
+  const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+  const codeUnits: number[] = [];
+  for (let i = hasBom ? 2 : 0; i + 1 < bytes.length; i += 2) {
+    codeUnits.push(view.getUint16(i, littleEndian));
+  }
+
+  // Convert in chunks to stay below the argument limit of fromCharCode
+  let text = '';
+  for (let i = 0; i < codeUnits.length; i += 8192) {
+    text += String.fromCharCode(...codeUnits.slice(i, i + 8192));
+  }
+  return text;
+}
+
+/**
+ * Decode file content to a string, reporting the detected encoding
+ */
+export function decodeText(bytes: Uint8Array): DecodedText {
+  const encoding = detectEncoding(bytes);
+
+  switch (encoding) {
+    case 'utf-8-bom':
+      return { content: new TextDecoder('utf-8').decode(bytes.subarray(3)), encoding };
+    case 'utf-16le':
+      return { content: decodeUtf16(bytes, true), encoding };
+    case 'utf-16be':
+      return { content: decodeUtf16(bytes, false), encoding };
+    case 'latin1':
+      return { content: Array.from(bytes, (byte) => String.fromCharCode(byte)).join(''), encoding };
+    default:
+      return { content: new TextDecoder('utf-8').decode(bytes), encoding };
+  }
+}
+
+/**
+ * Encode a string in the given encoding (with BOM for utf-8-bom and UTF-16).
+ * Characters Latin-1 cannot represent are written as "?".
+ */
+export function encodeText(content: string, encoding: TextEncodingName): Uint8Array {
+  switch (encoding) {
+    case 'utf-8-bom': {
+      const body = new TextEncoder().encode(content);
+      const bytes = new Uint8Array(body.length + 3);
+      bytes.set([0xef, 0xbb, 0xbf]);
+      bytes.set(body, 3);
+      return bytes;
+    }
+    case 'utf-16le':
+    case 'utf-16be': {
+      const littleEndian = encoding === 'utf-16le';
+      const bytes = new Uint8Array(content.length * 2 + 2);
+      const view = new DataView(bytes.buffer);
+      view.setUint16(0, 0xfeff, littleEndian);
+      for (let i = 0; i < content.length; i++) {
+        view.setUint16(i * 2 + 2, content.charCodeAt(i), littleEndian);
+      }
+      return bytes;
+    }
+    case 'latin1':
+      return Uint8Array.from(content, (char) => {
+        const code = char.charCodeAt(0);
+        return code <= 0xff ? code : 0x3f;
+      });
+    default:
+      return new TextEncoder().encode(content);
+  }
+}
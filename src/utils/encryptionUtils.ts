@@ -0,0 +1,98 @@
+/**
+ * Encryption Utilities
+ *
+ * AES-256-GCM encryption of artifact files at rest. An encrypted file is a
+ * single line: a marker prefix, then the base64 IV and ciphertext, so it can
+ * be told apart from plaintext markdown without knowing the key.
+ */
+
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+
+export const ENCRYPTED_PREFIX = 'tracyfy-encrypted:v1:';
+
+// Types whose files are project structure and stay readable
+const PLAINTEXT_TYPES = ['links', 'users', 'workflows', 'projects', 'customAttributes'];
+
+// Comment sidecars quote and discuss artifact text, so they are encrypted too. Approval
+// sidecars hold only hashes, names and signatures and stay readable, so approvals can be
+// verified without the key.
+const ENCRYPTED_SIDECAR_FOLDERS = ['comments'];
+
+/**
+ * Folders whose files are encrypted when encryption is on: those of every
+ * artifact type, including the glossary and the project's own types, apart
+ * from structure (projects, links, users, workflows), plus comment sidecars.
+ * Read at call time, as custom types are registered when a project opens.
+ */
+export function getEncryptedFolders(): string[] {
+  const artifactFolders = Object.entries(ARTIFACT_CONFIG)
+    .filter(([type]) => !PLAINTEXT_TYPES.includes(type))
+    .map(([, config]) => config.folder);
+  return [...artifactFolders, ...ENCRYPTED_SIDECAR_FOLDERS];
+}
+
+const IV_BYTES = 12;
+
+function toBase64(bytes: Uint8Array): string {
+  return btoa(Array.from(bytes, (b) => String.fromCharCode(b)).join(''));
+}
+
+function fromBase64(base64: string): Uint8Array {
+  return Uint8Array.from(atob(base64), (c) => c.charCodeAt(0));
+}
+
+function importKey(key: string): Promise<CryptoKey> {
+  return globalThis.crypto.subtle.importKey('raw', fromBase64(key), 'AES-GCM', false, [
+    'encrypt',
+    'decrypt',
+  ]);
+}
+
+export function isEncryptedContent(content: string): boolean {
+  return content.startsWith(ENCRYPTED_PREFIX);
+}
+
+/**
+ * Whether files at this path are encrypted when encryption is on
+ */
+export function isEncryptedPath(path: string): boolean {
+  return getEncryptedFolders().includes(path.split('/')[0]);
+}
+
+/**
+ * New random 256-bit key, base64 encoded
+ */
+export function generateEncryptionKey(): string {
+  return toBase64(globalThis.crypto.getRandomValues(new Uint8Array(32)));
+}
+
+export async function encryptText(text: string, key: string): Promise<string> {
+  const iv = globalThis.crypto.getRandomValues(new Uint8Array(IV_BYTES));
+  const ciphertext = await globalThis.crypto.subtle.encrypt(
+    { name: 'AES-GCM', iv },
+    await importKey(key),
+    new TextEncoder().encode(text)
+  );
+  return `${ENCRYPTED_PREFIX}${toBase64(iv)}:${toBase64(new Uint8Array(ciphertext))}`;
+}
+
+/**
+ * Decrypt content produced by encryptText; throws on a wrong key or
+ * tampered content
+ */
+export async function decryptText(content: string, key: string): Promise<string> {
+  const [iv, ciphertext] = content.trim().slice(ENCRYPTED_PREFIX.length).split(':');
+  if (!iv || !ciphertext) {
+    throw new Error('Malformed encrypted content');
+  }
+  try {
+    const plaintext = await globalThis.crypto.subtle.decrypt(
+      { name: 'AES-GCM', iv: fromBase64(iv) },
+      await importKey(key),
+      fromBase64(ciphertext)
+    );
+    return new TextDecoder().decode(plaintext);
+  } catch {
+    throw new Error('Cannot decrypt content: wrong key or corrupted file');
+  }
+}
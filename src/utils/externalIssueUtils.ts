@@ -0,0 +1,48 @@
+/**
+ * External Issue Utilities
+ *
+ * Helpers for the externalIssues list in artifact frontmatter, which maps
+ * artifacts to issues in Jira, GitHub or GitLab.
+ */
+
+import type { ExternalIssueRef, IssueTracker } from '../types';
+
+export const ISSUE_TRACKER_LABELS: Record<IssueTracker, string> = {
+  jira: 'Jira',
+  github: 'GitHub',
+  gitlab: 'GitLab',
+};
+
+/**
+ * Issues of one tracker linked to an artifact
+ */
+export function getIssueRefs(
+  refs: ExternalIssueRef[] | undefined,
+  tracker: IssueTracker
+): ExternalIssueRef[] {
+  return (refs || []).filter((ref) => ref.tracker === tracker);
+}
+
+/**
+ * Add an issue reference, or update it if the issue is already linked
+ */
+export function upsertIssueRef(
+  refs: ExternalIssueRef[] | undefined,
+  ref: ExternalIssueRef
+): ExternalIssueRef[] {
+  const list = refs || [];
+  const index = list.findIndex((r) => r.tracker === ref.tracker && r.key === ref.key);
+  if (index === -1) return [...list, ref];
+  return list.map((r, i) => (i === index ? { ...r, ...ref } : r));
+}
+
+/**
+ * Remove an issue reference
+ */
+export function removeIssueRef(
+  refs: ExternalIssueRef[] | undefined,
+  tracker: IssueTracker,
+  key: string
+): ExternalIssueRef[] {
+  return (refs || []).filter((r) => !(r.tracker === tracker && r.key === key));
+}
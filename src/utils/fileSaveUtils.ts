@@ -0,0 +1,43 @@
+/**
+ * File Save Utilities
+ *
+ * Saves generated files outside the repository: through the save file
+ * picker where the browser supports it, as a download otherwise.
+ */
+
+export interface SaveFileType {
+  description: string;
+  accept: Record<string, string[]>; // MIME type to extensions, e.g. { 'text/html': ['.html'] }
+}
+
+/**
+ * Save a blob under a suggested file name; resolves without saving when the
+ * user cancels the picker
+ */
+export async function saveFile(blob: Blob, filename: string, type: SaveFileType): Promise<void> {
+  try {
+    if ('showSaveFilePicker' in window) {
+      const handle = await (
+        window as Window & {
+          showSaveFilePicker: (options: unknown) => Promise<FileSystemFileHandle>;
+        }
+      ).showSaveFilePicker({ suggestedName: filename, types: [type] });
+      const writable = await handle.createWritable();
+      await writable.write(blob);
+      await writable.close();
+      return;
+    }
+  } catch (err) {
+    if (err instanceof Error && err.name === 'AbortError') return; // User cancelled
+    console.error('Error with save file picker:', err);
+  }
+
+  const url = URL.createObjectURL(blob);
+  const a = document.createElement('a');
+  a.href = url;
+  a.download = filename;
+  document.body.appendChild(a);
+  a.click();
+  document.body.removeChild(a);
+  URL.revokeObjectURL(url);
+}
@@ -0,0 +1,87 @@
+/**
+ * Fuzzy Match Utilities
+ *
+ * Scores artifact IDs and titles against what was typed into a quick-open
+ * box. Every typed word has to match some word of the ID or title, but the
+ * match may be a prefix, a part, the letters in order ("tmout" → "timeout")
+ * or off by one typo ("timout" → "timeout"). IDs also match without their
+ * dash and leading zeros, so "req12" finds REQ-012.
+ */
+
+// How well a typed word matches a word of the candidate
+const EXACT_SCORE = 10;
+const PREFIX_SCORE = 8;
+const PART_SCORE = 6;
+const SUBSEQUENCE_SCORE = 4;
+const TYPO_SCORE = 3;
+// Typos are only forgiven in words at least this long
+const MIN_TYPO_LENGTH = 4;
+
+/**
+ * Lower-case words of a text
+ */
+export function tokenize(text: string): string[] {
+  return text.toLowerCase().match(/[\p{L}\p{N}]+/gu) || [];
+}
+
+/**
+ * Words an artifact can be found by: its title words, the parts of its ID,
+ * and the ID without separators and with and without leading zeros
+ */
+export function buildSearchTokens(id: string, title: string): string[] {
+  const idParts = tokenize(id);
+  const compact = idParts.join('');
+  const unpadded = idParts.map((part) => part.replace(/^0+(?=\d)/, '')).join('');
+  return [...new Set([compact, unpadded, ...idParts, ...tokenize(title)])];
+}
+
+function isSubsequence(word: string, token: string): boolean {
+  let position = 0;
+  for (const char of token) {
+    if (char === word[position]) position++;
+    if (position === word.length) return true;
+  }
+  return false;
+}
+
+// Whether the words differ by at most one insertion, deletion, substitution
+// or swap of neighbouring letters
+function isOneTypoAway(a: string, b: string): boolean {
+  if (Math.abs(a.length - b.length) > 1) return false;
+  let i = 0;
+  while (i < a.length && i < b.length && a[i] === b[i]) i++;
+  if (a.length === b.length) {
+    if (a.slice(i + 1) === b.slice(i + 1)) return true;
+    return a[i] === b[i + 1] && a[i + 1] === b[i] && a.slice(i + 2) === b.slice(i + 2);
+  }
+  return a.length > b.length ? a.slice(i + 1) === b.slice(i) : a.slice(i) === b.slice(i + 1);
+}
+
+function scoreWord(word: string, token: string): number {
+  if (token === word) return EXACT_SCORE;
+  if (token.startsWith(word)) return PREFIX_SCORE;
+  if (token.includes(word)) return PART_SCORE;
+  if (word.length > 1 && word[0] === token[0] && isSubsequence(word, token)) {
+    return SUBSEQUENCE_SCORE;
+  }
+  if (word.length >= MIN_TYPO_LENGTH && isOneTypoAway(word, token)) return TYPO_SCORE;
+  return 0;
+}
+
+/**
+ * Score of the candidate's search tokens for the typed query; 0 when some
+ * typed word matches none of them. Higher is better.
+ */
+export function fuzzyScore(queryWords: string[], tokens: string[]): number {
+  let total = 0;
+  for (const word of queryWords) {
+    let best = 0;
+    for (const token of tokens) {
+      best = Math.max(best, scoreWord(word, token));
+      if (best === EXACT_SCORE) break;
+    }
+    if (best === 0) return 0;
+    total += best;
+  }
+  return total;
+}
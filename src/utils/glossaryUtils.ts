@@ -0,0 +1,171 @@
+/**
+ * Glossary Utilities
+ *
+ * Finds glossary terms in artifact markdown so the UI can highlight and link
+ * them, and flags capitalized terms (acronyms, multi-word proper terms) that
+ * have no glossary entry yet.
+ */
+
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { getBodyStartLine } from './markdownBase';
+
+export interface GlossaryEntry {
+  id: string;
+  term: string;
+  abbreviation?: string;
+  synonyms?: string[];
+}
+
+export interface TermOccurrence {
+  text: string; // Matched text as written in the source
+  termId?: string; // Glossary term the text refers to; absent for undefined terms
+  line: number; // 1-based line number in the source file
+  column: number; // 1-based column of the first character
+  length: number;
+}
+
+interface TermMatcher {
+  termId: string;
+  pattern: RegExp;
+  length: number;
+}
+
+const ID_PREFIXES = new Set(Object.values(ARTIFACT_CONFIG).map((config) => config.idPrefix));
+
+// Acronyms (ECU, CAN2) and runs of capitalized words (Flight Control Unit)
+const CAPITALIZED_TERM_PATTERN = /\b(?:[A-Z][A-Z0-9]+|[A-Z][a-z]+(?:[ -][A-Z][a-z]+)+)\b(?!-\d)/g;
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+function createTermPattern(text: string, caseSensitive: boolean): RegExp {
+  const flags = caseSensitive ? 'gu' : 'giu';
+  return new RegExp(`(?<![\\p{L}\\p{N}_])${escapeRegExp(text)}(?![\\p{L}\\p{N}_])`, flags);
+}
+
+/**
+ * Lines of the markdown that hold prose: frontmatter and fenced code blocks
+ * are skipped. Returns [zero-based index, line] pairs.
+ */
+function getProseLines(markdown: string): [number, string][] {
+  const lines = markdown.split('\n');
+  let index = getBodyStartLine(lines);
+
+  const prose: [number, string][] = [];
+  let inCodeFence = false;
+  for (; index < lines.length; index++) {
+    if (lines[index].trim().startsWith('```')) {
+      inCodeFence = !inCodeFence;
+      continue;
+    }
+    if (!inCodeFence) prose.push([index, lines[index]]);
+  }
+  return prose;
+}
+
+function createMatchers(entries: GlossaryEntry[]): TermMatcher[] {
+  const matchers: TermMatcher[] = [];
+  entries.forEach((entry) => {
+    [entry.term, ...(entry.synonyms || [])]
+      .map((text) => text.trim())
+      .filter((text) => text.length > 0)
+      .forEach((text) => {
+        matchers.push({
+          termId: entry.id,
+          pattern: createTermPattern(text, false),
+          length: text.length,
+        });
+      });
+    const abbreviation = entry.abbreviation?.trim();
+    if (abbreviation) {
+      matchers.push({
+        termId: entry.id,
+        pattern: createTermPattern(abbreviation, true),
+        length: abbreviation.length,
+      });
+    }
+  });
+
+  // Longest first, so "Flight Control Unit" wins over "Control Unit"
+  return matchers.sort((a, b) => b.length - a.length);
+}
+
+function sortOccurrences(occurrences: TermOccurrence[]): TermOccurrence[] {
+  return occurrences.sort((a, b) => a.line - b.line || a.column - b.column);
+}
+
+/**
+ * Find all occurrences of glossary terms, synonyms and abbreviations.
+ * Terms and synonyms match case-insensitively, abbreviations case-sensitively.
+ * Overlapping matches resolve to the longest term.
+ */
+export function findTermOccurrences(markdown: string, entries: GlossaryEntry[]): TermOccurrence[] {
+  const matchers = createMatchers(entries);
+  if (matchers.length === 0) return [];
+
+  const occurrences: TermOccurrence[] = [];
+  getProseLines(markdown).forEach(([index, line]) => {
+    const taken = new Array<boolean>(line.length).fill(false);
+    matchers.forEach((matcher) => {
+      for (const match of line.matchAll(matcher.pattern)) {
+        const start = match.index;
+        const end = start + match[0].length;
+        if (taken.slice(start, end).some(Boolean)) continue;
+
+        taken.fill(true, start, end);
+        occurrences.push({
+          text: match[0],
+          termId: matcher.termId,
+          line: index + 1,
+          column: start + 1,
+          length: match[0].length,
+        });
+      }
+    });
+  });
+
+  return sortOccurrences(occurrences);
+}
+
+/**
+ * Find capitalized terms that are not in the glossary: acronyms and runs of
+ * two or more capitalized words. Headings, artifact IDs and the first word of
+ * a sentence are ignored.
+ */
+export function findUndefinedTerms(markdown: string, entries: GlossaryEntry[]): TermOccurrence[] {
+  const defined = findTermOccurrences(markdown, entries);
+  const occurrences: TermOccurrence[] = [];
+
+  getProseLines(markdown).forEach(([index, line]) => {
+    if (line.trimStart().startsWith('#')) return;
+    const definedRanges = defined
+      .filter((o) => o.line === index + 1)
+      .map((o) => [o.column - 1, o.column - 1 + o.length]);
+
+    for (const match of line.matchAll(CAPITALIZED_TERM_PATTERN)) {
+      let text = match[0];
+      let start = match.index;
+
+      // A sentence-initial word is capitalized for grammar, not because it is a term
+      const atSentenceStart = /(^|[.!?:]\s+|^\s*[-*+]\s+|^\s*\d+\.\s+)$/.test(
+        line.slice(0, start)
+      );
+      if (atSentenceStart && /[a-z]/.test(text)) {
+        const firstSpace = text.search(/[ -]/);
+        const rest = text.slice(firstSpace + 1);
+        if (!/[ -]/.test(rest)) continue;
+        start += firstSpace + 1;
+        text = rest;
+      }
+
+      if (ID_PREFIXES.has(text)) continue;
+      const end = start + text.length;
+      if (definedRanges.some(([from, to]) => start < to && end > from)) continue;
+
+      occurrences.push({ text, line: index + 1, column: start + 1, length: text.length });
+    }
+  });
+
+  return sortOccurrences(occurrences);
+}
@@ -0,0 +1,114 @@
+/**
+ * Hierarchy Utilities
+ *
+ * Derives document-style numbering (1, 1.1, 1.1.2) from the parent/child
+ * hierarchy stored in artifact frontmatter (`parentId`, `order`).
+ */
+
+export interface HierarchyNode {
+  id: string;
+  parentId?: string;
+  order?: number;
+}
+
+export interface HierarchyTreeNode<T extends HierarchyNode = HierarchyNode> {
+  artifact: T;
+  number: string;
+  children: HierarchyTreeNode<T>[];
+}
+
+/**
+ * Sibling ordering: explicit `order` first, then by ID (numeric-aware)
+ */
+export function compareSiblings(a: HierarchyNode, b: HierarchyNode): number {
+  const orderA = a.order ?? Number.POSITIVE_INFINITY;
+  const orderB = b.order ?? Number.POSITIVE_INFINITY;
+  if (orderA !== orderB) return orderA < orderB ? -1 : 1;
+  return a.id.localeCompare(b.id, undefined, { numeric: true });
+}
+
+/**
+ * Group nodes by parent, with siblings sorted. Nodes whose parent is not in
+ * the list are treated as roots (key `null`).
+ */
+export function groupChildren<T extends HierarchyNode>(nodes: T[]): Map<string | null, T[]> {
+  const ids = new Set(nodes.map((n) => n.id));
+  const children = new Map<string | null, T[]>();
+
+  nodes.forEach((node) => {
+    const key = node.parentId && ids.has(node.parentId) ? node.parentId : null;
+    const siblings = children.get(key) || [];
+    siblings.push(node);
+    children.set(key, siblings);
+  });
+
+  children.forEach((siblings) => siblings.sort(compareSiblings));
+  return children;
+}
+
+/**
+ * Compute hierarchical numbers for all nodes reachable from a root.
+ * Nodes caught in a parent cycle are not reachable and get no number.
+ */
+export function computeHierarchyNumbers(nodes: HierarchyNode[]): Map<string, string> {
+  const children = groupChildren(nodes);
+  const numbers = new Map<string, string>();
+
+  const visit = (parentId: string | null, prefix: string) => {
+    (children.get(parentId) || []).forEach((node, index) => {
+      if (numbers.has(node.id)) return;
+      const number = prefix ? `${prefix}.${index + 1}` : String(index + 1);
+      numbers.set(node.id, number);
+      visit(node.id, number);
+    });
+  };
+
+  visit(null, '');
+  return numbers;
+}
+
+/**
+ * IDs of a node and all its descendants, parents before children
+ */
+export function getSubtreeIds(nodes: HierarchyNode[], rootId: string): string[] {
+  const children = groupChildren(nodes);
+  const result: string[] = [];
+  const seen = new Set<string>();
+
+  const visit = (id: string) => {
+    if (seen.has(id)) return;
+    seen.add(id);
+    result.push(id);
+    (children.get(id) || []).forEach((child) => visit(child.id));
+  };
+
+  if (nodes.some((n) => n.id === rootId)) visit(rootId);
+  return result;
+}
+
+/**
+ * Whether making `parentId` the parent of `id` would create a cycle
+ * (the new parent is the node itself or one of its descendants)
+ */
+export function wouldCreateCycle(nodes: HierarchyNode[], id: string, parentId: string): boolean {
+  return getSubtreeIds(nodes, id).includes(parentId) || id === parentId;
+}
+
+/**
+ * Build the numbered tree of all nodes reachable from a root
+ */
+export function buildHierarchyTree<T extends HierarchyNode>(nodes: T[]): HierarchyTreeNode<T>[] {
+  const children = groupChildren(nodes);
+  const seen = new Set<string>();
+
+  const build = (parentId: string | null, prefix: string): HierarchyTreeNode<T>[] =>
+    (children.get(parentId) || [])
+      .filter((node) => !seen.has(node.id))
+      .map((node, index) => {
+        seen.add(node.id);
+        const number = prefix ? `${prefix}.${index + 1}` : String(index + 1);
+        return { artifact: node, number, children: build(node.id, number) };
+      });
+
+  return build(null, '');
+}
@@ -65,3 +65,35 @@ export function generateNextAttributeId(usedAttrNumbers: Set<number>): string {
   const nextNumber = findLowestAvailableNumber(usedAttrNumbers);
   return `ATTR-${String(nextNumber).padStart(3, '0')}`;
 }
+
+/**
+ * Expands an inclusive ID range (e.g. TC-010..TC-025) into the individual IDs.
+ * Both ends must share a prefix; padding follows the start ID (minimum 3 digits).
+ * @param startId - First ID of the range
+ * @param endId - Last ID of the range
+ * @returns IDs from startId to endId in ascending order
+ */
+export function expandIdRange(startId: string, endId: string): string[] {
+  const pattern = /^([A-Z]+)-(\d+)$/;
+  const start = startId.trim().match(pattern);
+  const end = endId.trim().match(pattern);
+  if (!start || !end) {
+    throw new Error(`Invalid ID range: ${startId}..${endId}`);
+  }
+  if (start[1] !== end[1]) {
+    throw new Error(`ID range must use a single prefix: ${startId}..${endId}`);
+  }
+
+  const from = parseInt(start[2], 10);
+  const to = parseInt(end[2], 10);
+  if (from > to) {
+    throw new Error(`ID range is reversed: ${startId}..${endId}`);
+  }
+
+  const width = Math.max(3, start[2].length);
+  const ids: string[] = [];
+  for (let i = from; i <= to; i++) {
+    ids.push(`${start[1]}-${String(i).padStart(width, '0')}`);
+  }
+  return ids;
+}
@@ -40,7 +40,10 @@ export interface ExportData {
   exportedAt: string;
 }
 
-export async function exportProjectToJSON(
+/**
+ * Collect a project's artifacts and links for export, sorted by ID number
+ */
+export async function buildExportData(
   project: Project,
   globalState: {
     requirements: Requirement[];
@@ -56,7 +59,7 @@ export async function exportProjectToJSON(
   projectInformationIds: string[],
   projectRiskIds: string[] = [],
   projectDocumentIds: string[] = []
-): Promise<void> {
+): Promise<ExportData> {
   // Filter artifacts and sort by ID number for consistent ordering
   const requirements = sortByIdNumber(
     globalState.requirements.filter((r) => projectRequirementIds.includes(r.id) && !r.isDeleted)
@@ -84,7 +87,7 @@ export async function exportProjectToJSON(
     (globalState.documents || []).filter((d) => projectDocumentIds.includes(d.id) && !d.isDeleted)
   );
 
-  const dataToExport: ExportData = {
+  return {
     project: {
       id: project.id,
       name: project.name,
@@ -101,6 +104,35 @@ export async function exportProjectToJSON(
     links,
     exportedAt: new Date().toISOString(),
   };
+}
+
+export async function exportProjectToJSON(
+  project: Project,
+  globalState: {
+    requirements: Requirement[];
+    useCases: UseCase[];
+    testCases: TestCase[];
+    information: Information[];
+    risks?: Risk[];
+    documents?: ArtifactDocument[];
+  },
+  projectRequirementIds: string[],
+  projectUseCaseIds: string[],
+  projectTestCaseIds: string[],
+  projectInformationIds: string[],
+  projectRiskIds: string[] = [],
+  projectDocumentIds: string[] = []
+): Promise<void> {
+  const dataToExport = await buildExportData(
+    project,
+    globalState,
+    projectRequirementIds,
+    projectUseCaseIds,
+    projectTestCaseIds,
+    projectInformationIds,
+    projectRiskIds,
+    projectDocumentIds
+  );
 
   const jsonString = JSON.stringify(dataToExport, null, 2);
 
@@ -0,0 +1,86 @@
+/**
+ * JUnit Utilities
+ *
+ * Parses JUnit XML reports (the format most CI tools emit) and xUnit.net
+ * reports into flat test results, and matches results to test case
+ * artifacts by ID (e.g. "TC-012 login works") or by title.
+ */
+
+export type TestOutcome = 'passed' | 'failed' | 'skipped';
+
+export interface JUnitTestResult {
+  name: string;
+  className: string;
+  outcome: TestOutcome;
+  durationMs?: number;
+  message?: string; // Failure or error message
+}
+
+const TEST_CASE_ID_PATTERN = /\bTC-\d+\b/;
+
+function childElement(element: Element, ...tagNames: string[]): Element | undefined {
+  return Array.from(element.children).find((child) => tagNames.includes(child.tagName));
+}
+
+function toDuration(seconds: string | null): number | undefined {
+  const value = seconds === null ? NaN : parseFloat(seconds);
+  return Number.isFinite(value) ? Math.round(value * 1000) : undefined;
+}
+
+function parseJUnitCase(element: Element): JUnitTestResult {
+  const problem = childElement(element, 'failure', 'error');
+  const skipped = childElement(element, 'skipped');
+  return {
+    name: element.getAttribute('name') || '',
+    className: element.getAttribute('classname') || '',
+    outcome: problem ? 'failed' : skipped ? 'skipped' : 'passed',
+    durationMs: toDuration(element.getAttribute('time')),
+    message: problem ? problem.getAttribute('message') || problem.textContent?.trim() : undefined,
+  };
+}
+
+// xUnit.net: <test name="..." type="..." result="Pass|Fail|Skip" time="...">
+function parseXUnitCase(element: Element): JUnitTestResult {
+  const result = (element.getAttribute('result') || '').toLowerCase();
+  const failure = childElement(element, 'failure');
+  const message = failure && childElement(failure, 'message');
+  return {
+    name: element.getAttribute('name') || '',
+    className: element.getAttribute('type') || '',
+    outcome: result === 'fail' ? 'failed' : result === 'pass' ? 'passed' : 'skipped',
+    durationMs: toDuration(element.getAttribute('time')),
+    message: message?.textContent?.trim() || undefined,
+  };
+}
+
+/**
+ * Parse a JUnit or xUnit.net XML report into test results
+ */
+export function parseJUnitXml(xml: string): JUnitTestResult[] {
+  const doc = new DOMParser().parseFromString(xml, 'application/xml');
+  if (doc.getElementsByTagName('parsererror').length > 0) {
+    throw new Error('Invalid test report: not well-formed XML');
+  }
+
+  const junitCases = Array.from(doc.getElementsByTagName('testcase'));
+  if (junitCases.length > 0) return junitCases.map(parseJUnitCase);
+  return Array.from(doc.getElementsByTagName('test')).map(parseXUnitCase);
+}
+
+/**
+ * The ID of the test case a result belongs to: an ID in the test or class
+ * name, or else a test case whose title equals the test name
+ */
+export function matchTestCaseId(
+  result: JUnitTestResult,
+  testCases: { id: string; title: string }[]
+): string | null {
+  const knownIds = new Set(testCases.map((tc) => tc.id));
+  for (const text of [result.name, result.className]) {
+    const id = text.match(TEST_CASE_ID_PATTERN)?.[0];
+    if (id && knownIds.has(id)) return id;
+  }
+
+  const name = result.name.trim().toLowerCase();
+  return testCases.find((tc) => tc.title.trim().toLowerCase() === name)?.id ?? null;
+}
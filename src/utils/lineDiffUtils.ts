@@ -0,0 +1,76 @@
+/**
+ * Line Diff Utilities
+ *
+ * Counts added and removed lines between two versions of a text using the
+ * Myers shortest edit script, and splits artifact markdown into its
+ * frontmatter and body so changes to each can be counted separately.
+ */
+
+export interface LineChanges {
+  added: number;
+  removed: number;
+}
+
+function toLines(text: string): string[] {
+  if (text === '') return [];
+  return text.replace(/\r\n/g, '\n').replace(/\n$/, '').split('\n');
+}
+
+/**
+ * Lines added and removed between two texts (a minimal line diff)
+ */
+export function countLineChanges(before: string, after: string): LineChanges {
+  const a = toLines(before);
+  const b = toLines(after);
+
+  // Common prefix and suffix never change the result; skipping them keeps
+  // typical edits of long files cheap
+  let start = 0;
+  while (start < a.length && start < b.length && a[start] === b[start]) start++;
+  let endA = a.length;
+  let endB = b.length;
+  while (endA > start && endB > start && a[endA - 1] === b[endB - 1]) {
+    endA--;
+    endB--;
+  }
+  const n = endA - start;
+  const m = endB - start;
+  if (n === 0 || m === 0) return { added: m, removed: n };
+
+  // Myers: furthest reaching x per diagonal k, for increasing edit distance d
+  const max = n + m;
+  const v = new Array<number>(2 * max + 2).fill(0);
+  for (let d = 0; d <= max; d++) {
+    for (let k = -d; k <= d; k += 2) {
+      let x =
+        k === -d || (k !== d && v[max + k - 1] < v[max + k + 1])
+          ? v[max + k + 1]
+          : v[max + k - 1] + 1;
+      let y = x - k;
+      while (x < n && y < m && a[start + x] === b[start + y]) {
+        x++;
+        y++;
+      }
+      v[max + k] = x;
+      if (x >= n && y >= m) {
+        // d = added + removed and m - n = added - removed
+        return { added: (d + m - n) / 2, removed: (d - m + n) / 2 };
+      }
+    }
+  }
+  return { added: m, removed: n };
+}
+
+/**
+ * Raw frontmatter block (without the --- fences) and body of a markdown file
+ */
+export function splitFrontmatter(content: string): { frontmatter: string; body: string } {
+  const lines = content.split('\n');
+  if (lines[0] !== '---') return { frontmatter: '', body: content };
+  const end = lines.indexOf('---', 1);
+  if (end === -1) return { frontmatter: '', body: content };
+  return {
+    frontmatter: lines.slice(1, end).join('\n'),
+    body: lines.slice(end + 1).join('\n'),
+  };
+}
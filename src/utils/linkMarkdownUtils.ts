@@ -4,7 +4,7 @@
  * Functions for converting Link objects to/from Markdown files with YAML frontmatter.
  */
 
-import type { Link } from '../types';
+import type { Link, LinkOrigin } from '../types';
 import type { LinkType } from './linkTypes';
 import { objectToYaml, parseYamlFrontmatter, ensureArray } from './markdownBase';
 
@@ -21,6 +21,9 @@ export function linkToMarkdown(link: Link): string {
     dateCreated: link.dateCreated,
     lastModified: link.lastModified,
     revision: link.revision || '01',
+    ...(link.suspect && { suspect: link.suspect }),
+    ...(link.suspectSince && { suspectSince: link.suspectSince }),
+    ...(link.origin && { origin: link.origin }),
     ...(link.isDeleted && { isDeleted: link.isDeleted }),
     ...(link.deletedAt && { deletedAt: link.deletedAt }),
   };
@@ -60,6 +63,9 @@ export function parseMarkdownLink(content: string): Link | null {
     dateCreated: (frontmatter.dateCreated as number) || Date.now(),
     lastModified: (frontmatter.lastModified as number) || Date.now(),
     revision: (frontmatter.revision as string) || '01',
+    suspect: frontmatter.suspect as boolean | undefined,
+    suspectSince: frontmatter.suspectSince as number | undefined,
+    origin: frontmatter.origin as LinkOrigin | undefined,
     isDeleted: frontmatter.isDeleted as boolean | undefined,
     deletedAt: frontmatter.deletedAt as number | undefined,
   };
@@ -114,3 +114,84 @@ export function isSymmetricType(type: LinkType): boolean {
 export function getInverseType(type: LinkType): LinkType {
   return LINK_INVERSE[type];
 }
+
+/**
+ * Semantic category of a link type. A type and its inverse share a category,
+ * so e.g. "verifies" and "verified_by" are both verification links.
+ */
+export type LinkCategory =
+  | 'hierarchy'
+  | 'derivation'
+  | 'dependency'
+  | 'refinement'
+  | 'satisfaction'
+  | 'verification'
+  | 'constraint'
+  | 'precondition'
+  | 'conflict'
+  | 'duplication'
+  | 'association';
+
+export interface LinkTypeSemantics {
+  category: LinkCategory;
+  propagatesImpact: boolean; // Whether a change on one end is likely to affect the other
+}
+
+export const LINK_TYPE_SEMANTICS: Record<LinkType, LinkTypeSemantics> = {
+  parent: { category: 'hierarchy', propagatesImpact: true },
+  child: { category: 'hierarchy', propagatesImpact: true },
+  derived_from: { category: 'derivation', propagatesImpact: true },
+  derives_to: { category: 'derivation', propagatesImpact: true },
+  depends_on: { category: 'dependency', propagatesImpact: true },
+  depended_on_by: { category: 'dependency', propagatesImpact: true },
+  refines: { category: 'refinement', propagatesImpact: true },
+  refined_by: { category: 'refinement', propagatesImpact: true },
+  satisfies: { category: 'satisfaction', propagatesImpact: true },
+  satisfied_by: { category: 'satisfaction', propagatesImpact: true },
+  verifies: { category: 'verification', propagatesImpact: true },
+  verified_by: { category: 'verification', propagatesImpact: true },
+  constrains: { category: 'constraint', propagatesImpact: true },
+  constrained_by: { category: 'constraint', propagatesImpact: true },
+  requires: { category: 'precondition', propagatesImpact: true },
+  required_by: { category: 'precondition', propagatesImpact: true },
+  conflicts_with: { category: 'conflict', propagatesImpact: true },
+  duplicates: { category: 'duplication', propagatesImpact: true },
+  related_to: { category: 'association', propagatesImpact: false },
+};
+
+export const LINK_CATEGORY_LABELS: Record<LinkCategory, string> = {
+  hierarchy: 'Hierarchy',
+  derivation: 'Derivation',
+  dependency: 'Dependency',
+  refinement: 'Refinement',
+  satisfaction: 'Satisfaction',
+  verification: 'Verification',
+  constraint: 'Constraint',
+  precondition: 'Precondition',
+  conflict: 'Conflict',
+  duplication: 'Duplication',
+  association: 'Association',
+};
+
+/**
+ * Get the semantic category of a link type (undefined for unknown legacy types)
+ */
+export function getLinkCategory(type: string): LinkCategory | undefined {
+  return LINK_TYPE_SEMANTICS[type as LinkType]?.category;
+}
+
+/**
+ * Check whether a link type belongs to one of the given categories
+ */
+export function isLinkInCategories(type: string, categories: LinkCategory[]): boolean {
+  const category = getLinkCategory(type);
+  return category !== undefined && categories.includes(category);
+}
+
+/**
+ * Check whether a change on one end of a link is likely to affect the other.
+ * Unknown legacy types are treated as propagating, to err on the side of review.
+ */
+export function propagatesImpact(type: string): boolean {
+  return LINK_TYPE_SEMANTICS[type as LinkType]?.propagatesImpact ?? true;
+}
@@ -1,4 +1,5 @@
 import type { CustomAttributeValue } from '../types/customAttributes';
+import { parseToml } from './tomlUtils';
 
 /**
  * Filter out corrupted custom attribute values (strings like "[object Object]")
@@ -62,30 +63,9 @@ export function objectToYaml(obj: Record<string, unknown>): string {
 }
 
 /**
- * Parse YAML frontmatter from markdown content
+ * Parse the lines of a YAML frontmatter block (without the --- fences)
  */
-export function parseYamlFrontmatter(content: string): { frontmatter: Record<string, unknown>; body: string } {
-    const lines = content.split('\n');
-
-    if (lines[0] !== '---') {
-        return { frontmatter: {}, body: content };
-    }
-
-    let endIndex = -1;
-    for (let i = 1; i < lines.length; i++) {
-        if (lines[i] === '---') {
-            endIndex = i;
-            break;
-        }
-    }
-
-    if (endIndex === -1) {
-        return { frontmatter: {}, body: content };
-    }
-
-    const frontmatterLines = lines.slice(1, endIndex);
-    const bodyLines = lines.slice(endIndex + 1);
-
+function parseYamlBlock(frontmatterLines: string[]): Record<string, unknown> {
     const frontmatter: Record<string, unknown> = {};
     let currentKey: string | null = null;
     let currentMultiline: string[] = [];
@@ -201,12 +181,134 @@ export function parseYamlFrontmatter(content: string): { frontmatter: Record<str
         frontmatter[currentKey] = currentArray;
     }
 
+    return frontmatter;
+}
+
+/**
+ * Frontmatter conventions: YAML between --- lines (written by this app),
+ * TOML between +++ lines and a JSON object at the top of the file (as used by
+ * Hugo and other static site tools). TOML and JSON are only read; saving a
+ * file rewrites its frontmatter as YAML.
+ */
+export type FrontmatterFormat = 'yaml' | 'toml' | 'json';
+
+interface FrontmatterCodec {
+    // Lines of the block and the index of its last line, or null when the
+    // content does not start with this format
+    find(lines: string[]): { block: string[]; end: number } | null;
+    parse(block: string[]): Record<string, unknown>;
+}
+
+function findFencedBlock(lines: string[], fence: string): { block: string[]; end: number } | null {
+    if (lines[0]?.trimEnd() !== fence) return null;
+    const end = lines.findIndex((line, i) => i > 0 && line.trimEnd() === fence);
+    return end === -1 ? null : { block: lines.slice(1, end), end };
+}
+
+// The lines up to the brace that closes the object opened on the first line
+function findJsonBlock(lines: string[]): { block: string[]; end: number } | null {
+    if (!lines[0]?.trimStart().startsWith('{')) return null;
+    let depth = 0;
+    let inString = false;
+    let escaped = false;
+    for (let i = 0; i < lines.length; i++) {
+        for (const char of lines[i]) {
+            if (inString) {
+                if (escaped) escaped = false;
+                else if (char === '\\') escaped = true;
+                else if (char === '"') inString = false;
+            } else if (char === '"') {
+                inString = true;
+            } else if (char === '{') {
+                depth++;
+            } else if (char === '}' && --depth === 0) {
+                return { block: lines.slice(0, i + 1), end: i };
+            }
+        }
+    }
+    return null;
+}
+
+/**
+ * Frontmatter formats by name, in the order they are tried when reading
+ */
+export const FRONTMATTER_FORMATS: Record<FrontmatterFormat, FrontmatterCodec> = {
+    yaml: {
+        find: (lines) => findFencedBlock(lines, '---'),
+        parse: parseYamlBlock,
+    },
+    toml: {
+        find: (lines) => findFencedBlock(lines, '+++'),
+        parse: (block) => parseToml(block.join('\n')),
+    },
+    json: {
+        find: findJsonBlock,
+        parse: (block) => {
+            const data = JSON.parse(block.join('\n'));
+            if (typeof data !== 'object' || data === null || Array.isArray(data)) {
+                throw new Error('JSON frontmatter must be an object');
+            }
+            return data;
+        },
+    },
+};
+
+// The parsed frontmatter at the top of the lines and the index of its last
+// line; null when there is none or it is not valid in its format
+function readFrontmatter(
+    lines: string[]
+): { format: FrontmatterFormat; frontmatter: Record<string, unknown>; end: number } | null {
+    for (const format of Object.keys(FRONTMATTER_FORMATS) as FrontmatterFormat[]) {
+        const codec = FRONTMATTER_FORMATS[format];
+        const found = codec.find(lines);
+        if (!found) continue;
+        try {
+            return { format, frontmatter: codec.parse(found.block), end: found.end };
+        } catch {
+            return null;
+        }
+    }
+    return null;
+}
+
+/**
+ * Parse the frontmatter of markdown content in whichever format it is
+ * written. Content without frontmatter, or whose frontmatter cannot be
+ * parsed, has an empty frontmatter, a null format and all of it as body.
+ */
+export function parseFrontmatter(content: string): {
+    frontmatter: Record<string, unknown>;
+    body: string;
+    format: FrontmatterFormat | null;
+} {
+    const lines = content.split('\n');
+    const found = readFrontmatter(lines);
+    if (!found) return { frontmatter: {}, body: content, format: null };
     return {
-        frontmatter,
-        body: bodyLines.join('\n').trim(),
+        frontmatter: found.frontmatter,
+        body: lines.slice(found.end + 1).join('\n').trim(),
+        format: found.format,
     };
 }
 
+/**
+ * Parse frontmatter from markdown content. Files from other tools may use
+ * TOML or JSON frontmatter, which is read the same way and written back as
+ * YAML on the next save.
+ */
+export function parseYamlFrontmatter(content: string): { frontmatter: Record<string, unknown>; body: string } {
+    const { frontmatter, body } = parseFrontmatter(content);
+    return { frontmatter, body };
+}
+
+/**
+ * Index of the first line after the frontmatter block; 0 without frontmatter
+ */
+export function getBodyStartLine(lines: string[]): number {
+    const found = readFrontmatter(lines);
+    return found ? found.end + 1 : 0;
+}
+
 /**
  * Robustly ensure a value is an array of strings.
  * Handles:
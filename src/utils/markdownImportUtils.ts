@@ -0,0 +1,95 @@
+/**
+ * Markdown Import Utilities
+ *
+ * Heuristics for turning plain markdown files into artifacts: guessing the
+ * artifact type from the file's path and content, and splitting a title off
+ * the body.
+ */
+
+import { parseYamlFrontmatter } from './markdownBase';
+
+export type ImportTypeKey = 'requirements' | 'usecases' | 'testcases' | 'information' | 'risks';
+
+interface TypeHints {
+  path: RegExp; // Matched against folder and file names
+  content: RegExp[]; // Each match in the text adds to the score
+}
+
+const TYPE_HINTS: Record<Exclude<ImportTypeKey, 'information'>, TypeHints> = {
+  requirements: {
+    path: /\breq(uirement)?s?\b|\bspec(ification)?s?\b/i,
+    content: [/\bshall\b/gi, /\bmust\b/gi, /\brequirements?\b/gi, /\brationale\b/gi],
+  },
+  usecases: {
+    path: /use[-_ ]?cases?|scenarios?|user[-_ ]?stor(y|ies)/i,
+    content: [
+      /\bactors?\b/gi,
+      /\bmain (success )?flow\b/gi,
+      /\bpre-?conditions?\b/gi,
+      /\bas an? .+ i want\b/gi,
+    ],
+  },
+  testcases: {
+    path: /\btests?\b|test[-_ ]?cases?|\btc\b|verification/i,
+    content: [/\btest steps?\b/gi, /\bexpected (result|outcome)s?\b/gi, /\bgiven\b.+\bwhen\b/gi],
+  },
+  risks: {
+    path: /\brisks?\b|hazards?/i,
+    content: [/\brisks?\b/gi, /\bmitigation\b/gi, /\blikelihood\b/gi, /\bhazards?\b/gi],
+  },
+};
+
+const PATH_WEIGHT = 3;
+const MIN_CONTENT_SCORE = 2; // Below this a file is treated as plain information
+
+/**
+ * Guess the artifact type of a markdown file; files without clear hints
+ * become information artifacts
+ */
+export function classifyMarkdown(path: string, content: string): ImportTypeKey {
+  let best: ImportTypeKey = 'information';
+  let bestScore = MIN_CONTENT_SCORE - 1;
+
+  (Object.keys(TYPE_HINTS) as (keyof typeof TYPE_HINTS)[]).forEach((typeKey) => {
+    const hints = TYPE_HINTS[typeKey];
+    const pathScore = hints.path.test(path) ? PATH_WEIGHT : 0;
+    const contentScore = hints.content.reduce(
+      (sum, pattern) => sum + (content.match(pattern)?.length ?? 0),
+      0
+    );
+    const score = pathScore + contentScore;
+    if (score > bestScore) {
+      best = typeKey;
+      bestScore = score;
+    }
+  });
+
+  return best;
+}
+
+/**
+ * Readable title from a file name, e.g. "login_flow-v2.md" -> "Login flow v2"
+ */
+export function titleFromFileName(path: string): string {
+  const name = path
+    .slice(path.lastIndexOf('/') + 1)
+    .replace(/\.md$/i, '')
+    .replace(/[-_]+/g, ' ')
+    .trim();
+  return name.charAt(0).toUpperCase() + name.slice(1);
+}
+
+/**
+ * Title and description of a markdown file: the frontmatter title or first
+ * heading (falling back to the file name) and the body without that heading
+ */
+export function splitTitle(path: string, content: string): { title: string; description: string } {
+  const { frontmatter, body } = parseYamlFrontmatter(content);
+  const heading = body.match(/^#\s+(.+)$/m);
+  const description = (heading ? body.replace(heading[0], '') : body).trim();
+  const title =
+    (typeof frontmatter.title === 'string' && frontmatter.title) ||
+    heading?.[1].trim() ||
+    titleFromFileName(path);
+  return { title, description };
+}
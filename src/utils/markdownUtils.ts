@@ -9,6 +9,9 @@ import type {
   ArtifactLink,
   ArtifactDocument,
   DocumentEntry,
+  ChangeRequest,
+  GlossaryTerm,
+  ExternalIssueRef,
 } from '../types';
 import type {
   CustomAttributeDefinition,
@@ -40,6 +43,13 @@ export function requirementToMarkdown(requirement: Requirement): string {
 
     useCaseIds: requirement.useCaseIds || [],
     linkedArtifacts: requirement.linkedArtifacts || [],
+    parentId: requirement.parentId,
+    order: requirement.order,
+    tags: requirement.tags || [],
+    statusChangedBy: requirement.statusChangedBy,
+    statusChangedAt: requirement.statusChangedAt,
+    externalIssues: requirement.externalIssues,
+    aliases: requirement.aliases,
     author: requirement.author || '',
     verificationMethod: requirement.verificationMethod || '',
     approvalDate: requirement.approvalDate || null,
@@ -84,6 +94,15 @@ export function markdownToRequirement(markdown: string): Requirement {
     category: (frontmatter.category as string) || 'technical',
     useCaseIds: ensureArray<string>(frontmatter.useCaseIds),
     linkedArtifacts: ensureArray<ArtifactLink>(frontmatter.linkedArtifacts),
+    parentId: (frontmatter.parentId as string) || undefined,
+    order: (frontmatter.order as number) || undefined,
+    tags: ensureArray<string>(frontmatter.tags),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    externalIssues: frontmatter.externalIssues
+      ? ensureArray<ExternalIssueRef>(frontmatter.externalIssues)
+      : undefined,
+    aliases: frontmatter.aliases ? ensureArray<string>(frontmatter.aliases) : undefined,
     status: (frontmatter.status as string) || 'draft',
     priority: (frontmatter.priority as string) || 'medium',
     author: (frontmatter.author as string) || undefined,
@@ -112,6 +131,13 @@ export function convertUseCaseToMarkdown(useCase: UseCase): string {
     lastModified: useCase.lastModified,
     actor: useCase.actor,
     linkedArtifacts: useCase.linkedArtifacts || [],
+    parentId: useCase.parentId,
+    order: useCase.order,
+    tags: useCase.tags || [],
+    statusChangedBy: useCase.statusChangedBy,
+    statusChangedAt: useCase.statusChangedAt,
+    externalIssues: useCase.externalIssues,
+    aliases: useCase.aliases,
     isDeleted: useCase.isDeleted || false,
     deletedAt: useCase.deletedAt || null,
     customAttributes: filterValidCustomAttributes(useCase.customAttributes),
@@ -165,6 +191,15 @@ export function markdownToUseCase(markdown: string): UseCase {
     lastModified: (frontmatter.lastModified as number) || Date.now(),
     dateCreated: (frontmatter.dateCreated as number) || Date.now(),
     linkedArtifacts: ensureArray<ArtifactLink>(frontmatter.linkedArtifacts),
+    parentId: (frontmatter.parentId as string) || undefined,
+    order: (frontmatter.order as number) || undefined,
+    tags: ensureArray<string>(frontmatter.tags),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    externalIssues: frontmatter.externalIssues
+      ? ensureArray<ExternalIssueRef>(frontmatter.externalIssues)
+      : undefined,
+    aliases: frontmatter.aliases ? ensureArray<string>(frontmatter.aliases) : undefined,
     isDeleted: (frontmatter.isDeleted as boolean) || false,
     deletedAt: (frontmatter.deletedAt as number) || undefined,
     revision: (frontmatter.revision as string) || '01',
@@ -187,7 +222,15 @@ export function testCaseToMarkdown(testCase: TestCase): string {
     requirementIds: testCase.requirementIds || [],
     author: testCase.author || '',
     lastRun: testCase.lastRun || null,
+    lastResult: testCase.lastResult,
     linkedArtifacts: testCase.linkedArtifacts || [],
+    parentId: testCase.parentId,
+    order: testCase.order,
+    tags: testCase.tags || [],
+    statusChangedBy: testCase.statusChangedBy,
+    statusChangedAt: testCase.statusChangedAt,
+    externalIssues: testCase.externalIssues,
+    aliases: testCase.aliases,
     isDeleted: testCase.isDeleted || false,
     deletedAt: testCase.deletedAt || null,
     customAttributes: filterValidCustomAttributes(testCase.customAttributes),
@@ -231,9 +274,19 @@ export function markdownToTestCase(markdown: string): TestCase {
     priority: (frontmatter.priority as string) || 'medium',
     author: (frontmatter.author as string) || undefined,
     lastRun: (frontmatter.lastRun as number) || undefined,
+    lastResult: (frontmatter.lastResult as TestCase['lastResult']) || undefined,
     dateCreated: (frontmatter.dateCreated as number) || Date.now(),
     lastModified: (frontmatter.lastModified as number) || Date.now(),
     linkedArtifacts: ensureArray<ArtifactLink>(frontmatter.linkedArtifacts),
+    parentId: (frontmatter.parentId as string) || undefined,
+    order: (frontmatter.order as number) || undefined,
+    tags: ensureArray<string>(frontmatter.tags),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    externalIssues: frontmatter.externalIssues
+      ? ensureArray<ExternalIssueRef>(frontmatter.externalIssues)
+      : undefined,
+    aliases: frontmatter.aliases ? ensureArray<string>(frontmatter.aliases) : undefined,
     isDeleted: (frontmatter.isDeleted as boolean) || false,
     deletedAt: (frontmatter.deletedAt as number) || undefined,
     revision: (frontmatter.revision as string) || '01',
@@ -253,6 +306,13 @@ export function informationToMarkdown(information: Information): string {
     dateCreated: information.dateCreated,
     lastModified: information.lastModified,
     linkedArtifacts: information.linkedArtifacts || [],
+    parentId: information.parentId,
+    order: information.order,
+    tags: information.tags || [],
+    statusChangedBy: information.statusChangedBy,
+    statusChangedAt: information.statusChangedAt,
+    externalIssues: information.externalIssues,
+    aliases: information.aliases,
     isDeleted: information.isDeleted || false,
     deletedAt: information.deletedAt || null,
     customAttributes: filterValidCustomAttributes(information.customAttributes),
@@ -288,6 +348,15 @@ export function markdownToInformation(markdown: string): Information {
     dateCreated: (frontmatter.dateCreated as number) || Date.now(),
     lastModified: (frontmatter.lastModified as number) || Date.now(),
     linkedArtifacts: ensureArray<ArtifactLink>(frontmatter.linkedArtifacts),
+    parentId: (frontmatter.parentId as string) || undefined,
+    order: (frontmatter.order as number) || undefined,
+    tags: ensureArray<string>(frontmatter.tags),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    externalIssues: frontmatter.externalIssues
+      ? ensureArray<ExternalIssueRef>(frontmatter.externalIssues)
+      : undefined,
+    aliases: frontmatter.aliases ? ensureArray<string>(frontmatter.aliases) : undefined,
     isDeleted: (frontmatter.isDeleted as boolean) || false,
     deletedAt: (frontmatter.deletedAt as number) || undefined,
     revision: (frontmatter.revision as string) || '01',
@@ -400,6 +469,13 @@ export function riskToMarkdown(risk: Risk): string {
     dateCreated: risk.dateCreated,
     lastModified: risk.lastModified,
     linkedArtifacts: risk.linkedArtifacts || [],
+    parentId: risk.parentId,
+    order: risk.order,
+    tags: risk.tags || [],
+    statusChangedBy: risk.statusChangedBy,
+    statusChangedAt: risk.statusChangedAt,
+    externalIssues: risk.externalIssues,
+    aliases: risk.aliases,
     isDeleted: risk.isDeleted || false,
     deletedAt: risk.deletedAt || null,
     customAttributes: filterValidCustomAttributes(risk.customAttributes),
@@ -443,6 +519,15 @@ export function markdownToRisk(markdown: string): Risk {
     dateCreated: (frontmatter.dateCreated as number) || Date.now(),
     lastModified: (frontmatter.lastModified as number) || Date.now(),
     linkedArtifacts: ensureArray<ArtifactLink>(frontmatter.linkedArtifacts),
+    parentId: (frontmatter.parentId as string) || undefined,
+    order: (frontmatter.order as number) || undefined,
+    tags: ensureArray<string>(frontmatter.tags),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    externalIssues: frontmatter.externalIssues
+      ? ensureArray<ExternalIssueRef>(frontmatter.externalIssues)
+      : undefined,
+    aliases: frontmatter.aliases ? ensureArray<string>(frontmatter.aliases) : undefined,
     isDeleted: (frontmatter.isDeleted as boolean) || false,
     deletedAt: (frontmatter.deletedAt as number) || undefined,
     revision: (frontmatter.revision as string) || '01',
@@ -553,3 +638,114 @@ export function markdownToDocument(markdown: string): ArtifactDocument {
     customAttributes: ensureArray<CustomAttributeValue>(frontmatter.customAttributes),
   };
 }
+
+/**
+ * Convert a ChangeRequest to Markdown with YAML frontmatter
+ */
+export function changeRequestToMarkdown(changeRequest: ChangeRequest): string {
+  const frontmatter = {
+    id: changeRequest.id,
+    title: changeRequest.title,
+    status: changeRequest.status,
+    priority: changeRequest.priority,
+    revision: changeRequest.revision,
+    dateCreated: changeRequest.dateCreated,
+    lastModified: changeRequest.lastModified,
+    author: changeRequest.author || '',
+    affectedRequirementIds: changeRequest.affectedRequirementIds || [],
+    statusChangedBy: changeRequest.statusChangedBy,
+    statusChangedAt: changeRequest.statusChangedAt,
+    isDeleted: changeRequest.isDeleted || false,
+    deletedAt: changeRequest.deletedAt || null,
+  };
+
+  const yaml = objectToYaml(frontmatter);
+
+  const body = `# ${changeRequest.title}
+
+## Description
+${changeRequest.description || ''}
+
+## Reason
+${changeRequest.reason || ''}
+
+## Affected Requirements
+${(changeRequest.affectedRequirementIds || []).map((id) => `- ${id}`).join('\n')}
+`.trim();
+
+  return `${yaml}\n\n${body}`;
+}
+
+/**
+ * Parse Markdown content into a ChangeRequest object
+ */
+export function markdownToChangeRequest(markdown: string): ChangeRequest {
+  const { frontmatter, body } = parseYamlFrontmatter(markdown);
+  const sections = extractH2Sections(body);
+
+  return {
+    id: (frontmatter.id as string) || '',
+    title: (frontmatter.title as string) || '',
+    description: sections['Description'] || '',
+    reason: sections['Reason'] || undefined,
+    status: (frontmatter.status as string) || 'open',
+    priority: (frontmatter.priority as string) || 'medium',
+    author: (frontmatter.author as string) || undefined,
+    affectedRequirementIds: ensureArray<string>(frontmatter.affectedRequirementIds),
+    statusChangedBy: (frontmatter.statusChangedBy as string) || undefined,
+    statusChangedAt: (frontmatter.statusChangedAt as number) || undefined,
+    dateCreated: (frontmatter.dateCreated as number) || Date.now(),
+    lastModified: (frontmatter.lastModified as number) || Date.now(),
+    isDeleted: (frontmatter.isDeleted as boolean) || false,
+    deletedAt: (frontmatter.deletedAt as number) || undefined,
+    revision: (frontmatter.revision as string) || '01',
+  };
+}
+
+/**
+ * Convert a GlossaryTerm to Markdown with YAML frontmatter
+ */
+export function glossaryTermToMarkdown(term: GlossaryTerm): string {
+  const frontmatter = {
+    id: term.id,
+    term: term.term,
+    abbreviation: term.abbreviation || '',
+    synonyms: term.synonyms || [],
+    revision: term.revision,
+    dateCreated: term.dateCreated,
+    lastModified: term.lastModified,
+    isDeleted: term.isDeleted || false,
+    deletedAt: term.deletedAt || null,
+  };
+
+  const yaml = objectToYaml(frontmatter);
+
+  const body = `# ${term.term}
+
+## Definition
+${term.definition || ''}
+`.trim();
+
+  return `${yaml}\n\n${body}`;
+}
+
+/**
+ * Parse Markdown content into a GlossaryTerm object
+ */
+export function markdownToGlossaryTerm(markdown: string): GlossaryTerm {
+  const { frontmatter, body } = parseYamlFrontmatter(markdown);
+  const sections = extractH2Sections(body);
+
+  return {
+    id: (frontmatter.id as string) || '',
+    term: (frontmatter.term as string) || '',
+    definition: sections['Definition'] || '',
+    abbreviation: (frontmatter.abbreviation as string) || undefined,
+    synonyms: ensureArray<string>(frontmatter.synonyms),
+    dateCreated: (frontmatter.dateCreated as number) || Date.now(),
+    lastModified: (frontmatter.lastModified as number) || Date.now(),
+    isDeleted: (frontmatter.isDeleted as boolean) || false,
+    deletedAt: (frontmatter.deletedAt as number) || undefined,
+    revision: (frontmatter.revision as string) || '01',
+  };
+}
@@ -0,0 +1,271 @@
+/**
+ * Minisign Utilities
+ *
+ * Ed25519 keys and signatures in the minisign file formats, so a signature
+ * made in the app can be checked years later with nothing but the minisign
+ * tool (minisign -Vm <file> -p <key>.pub). Signatures use minisign's "Ed"
+ * algorithm, which signs the message itself rather than its BLAKE2b hash,
+ * so no BLAKE2b is needed. The trusted comment is signed together with the
+ * signature, as minisign does. In the desktop app Ed25519 runs in the main
+ * process, as Electron's WebCrypto lacks it; elsewhere WebCrypto is used.
+ */
+
+const ALGORITHM = 'Ed25519';
+const SIGNATURE_ALGORITHM = 'Ed';
+const KEY_ID_BYTES = 8;
+const PUBLIC_KEY_BYTES = 32;
+const SIGNATURE_BYTES = 64;
+const UNTRUSTED_PREFIX = 'untrusted comment: ';
+const TRUSTED_PREFIX = 'trusted comment: ';
+
+export interface SigningKey {
+  keyId: string; // Hex, as minisign prints it
+  publicKey: string; // Contents of a minisign .pub file
+  privateKey: string; // Base64 PKCS #8 from the secret store; sent to main over IPC to sign
+}
+
+export interface MinisignVerification {
+  valid: boolean;
+  keyId: string | null;
+  trustedComment: string | null;
+  error?: string;
+}
+
+function toBase64(bytes: Uint8Array): string {
+  return btoa(String.fromCharCode(...bytes));
+}
+
+function fromBase64(text: string): Uint8Array {
+  return Uint8Array.from(atob(text), (char) => char.charCodeAt(0));
+}
+
+function concat(...parts: Uint8Array[]): Uint8Array {
+  const result = new Uint8Array(parts.reduce((length, part) => length + part.length, 0));
+  let offset = 0;
+  for (const part of parts) {
+    result.set(part, offset);
+    offset += part.length;
+  }
+  return result;
+}
+
+// minisign shows the key ID as a little-endian 64-bit number
+function formatKeyId(keyId: Uint8Array): string {
+  return Array.from(keyId)
+    .reverse()
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('')
+    .toUpperCase();
+}
+
+/**
+ * The base64 line of a minisign key or signature file: algorithm, key ID
+ * and payload of the expected length
+ */
+function decodeMinisignLine(
+  text: string,
+  payloadBytes: number
+): { keyId: Uint8Array; payload: Uint8Array } {
+  const line = text
+    .split(/\r?\n/)
+    .map((l) => l.trim())
+    .find((l) => l && !l.startsWith(UNTRUSTED_PREFIX));
+  const bytes = line ? fromBase64(line) : new Uint8Array();
+  const algorithm = String.fromCharCode(bytes[0], bytes[1]);
+  if (bytes.length !== 2 + KEY_ID_BYTES + payloadBytes) {
+    throw new Error('Malformed minisign data');
+  }
+  if (algorithm !== SIGNATURE_ALGORITHM) {
+    throw new Error(`Unsupported minisign algorithm: ${algorithm}`);
+  }
+  return { keyId: bytes.slice(2, 2 + KEY_ID_BYTES), payload: bytes.slice(2 + KEY_ID_BYTES) };
+}
+
+/**
+ * The key ID of a minisign public key
+ */
+export function getPublicKeyId(publicKey: string): string {
+  return formatKeyId(decodeMinisignLine(publicKey, PUBLIC_KEY_BYTES).keyId);
+}
+
+/**
+ * The ID of the key a minisign signature was made with; null when the
+ * signature is malformed
+ */
+export function getSignatureKeyId(signatureFile: string): string | null {
+  try {
+    return formatKeyId(decodeMinisignLine(signatureFile, SIGNATURE_BYTES).keyId);
+  } catch {
+    return null;
+  }
+}
+
+// Ed25519 through the desktop app's main process when it is available
+function getMainProcessSigning() {
+  return typeof window !== 'undefined' ? window.electronAPI?.signing : undefined;
+}
+
+async function generateKeyPair(): Promise<{ publicKey: Uint8Array; privateKey: string }> {
+  const signing = getMainProcessSigning();
+  if (signing) {
+    const result = await signing.generateKey();
+    if (result.error || !result.publicKey || !result.privateKey) {
+      throw new Error(result.error || 'Could not create a signing key');
+    }
+    return { publicKey: Uint8Array.from(result.publicKey), privateKey: result.privateKey };
+  }
+  const pair = (await globalThis.crypto.subtle.generateKey(ALGORITHM, true, [
+    'sign',
+    'verify',
+  ])) as CryptoKeyPair;
+  const raw = new Uint8Array(await globalThis.crypto.subtle.exportKey('raw', pair.publicKey));
+  const pkcs8 = new Uint8Array(await globalThis.crypto.subtle.exportKey('pkcs8', pair.privateKey));
+  return { publicKey: raw, privateKey: toBase64(pkcs8) };
+}
+
+// Signs with a base64 PKCS #8 private key
+async function createSigner(
+  privateKey: string
+): Promise<(data: Uint8Array) => Promise<Uint8Array>> {
+  const signing = getMainProcessSigning();
+  if (signing) {
+    return async (data) => {
+      const result = await signing.sign(privateKey, Array.from(data));
+      if (result.error || !result.signature) throw new Error(result.error || 'Signing failed');
+      return Uint8Array.from(result.signature);
+    };
+  }
+  const key = await globalThis.crypto.subtle.importKey(
+    'pkcs8',
+    fromBase64(privateKey) as BufferSource,
+    ALGORITHM,
+    false,
+    ['sign']
+  );
+  return async (data) =>
+    new Uint8Array(await globalThis.crypto.subtle.sign(ALGORITHM, key, data as BufferSource));
+}
+
+// Verifies with a raw 32-byte public key
+async function createVerifier(
+  publicKey: Uint8Array
+): Promise<(signature: Uint8Array, data: Uint8Array) => Promise<boolean>> {
+  const signing = getMainProcessSigning();
+  if (signing) {
+    return async (signature, data) => {
+      const result = await signing.verify(
+        Array.from(publicKey),
+        Array.from(signature),
+        Array.from(data)
+      );
+      if (result.error) throw new Error(result.error);
+      return result.valid === true;
+    };
+  }
+  const key = await globalThis.crypto.subtle.importKey(
+    'raw',
+    publicKey as BufferSource,
+    ALGORITHM,
+    false,
+    ['verify']
+  );
+  return (signature, data) =>
+    globalThis.crypto.subtle.verify(
+      ALGORITHM,
+      key,
+      signature as BufferSource,
+      data as BufferSource
+    );
+}
+
+/**
+ * New Ed25519 key pair with a random key ID
+ */
+export async function generateSigningKey(): Promise<SigningKey> {
+  const { publicKey, privateKey } = await generateKeyPair();
+  const keyId = globalThis.crypto.getRandomValues(new Uint8Array(KEY_ID_BYTES));
+  const algorithm = new TextEncoder().encode(SIGNATURE_ALGORITHM);
+  return {
+    keyId: formatKeyId(keyId),
+    publicKey:
+      `${UNTRUSTED_PREFIX}minisign public key ${formatKeyId(keyId)}\n` +
+      `${toBase64(concat(algorithm, keyId, publicKey))}\n`,
+    privateKey,
+  };
+}
+
+/**
+ * Sign a message; returns the contents of a minisign .minisig file
+ */
+export async function signMinisign(
+  message: string,
+  key: SigningKey,
+  trustedComment: string
+): Promise<string> {
+  if (/[\r\n]/.test(trustedComment)) {
+    throw new Error('The trusted comment must be a single line');
+  }
+  const encoder = new TextEncoder();
+  const sign = await createSigner(key.privateKey);
+
+  const { keyId } = decodeMinisignLine(key.publicKey, PUBLIC_KEY_BYTES);
+  const signature = await sign(encoder.encode(message));
+  const globalSignature = await sign(concat(signature, encoder.encode(trustedComment)));
+  return [
+    `${UNTRUSTED_PREFIX}signature from tracyfy secret key`,
+    toBase64(concat(encoder.encode(SIGNATURE_ALGORITHM), keyId, signature)),
+    `${TRUSTED_PREFIX}${trustedComment}`,
+    toBase64(globalSignature),
+    '',
+  ].join('\n');
+}
+
+/**
+ * Check a minisign signature of a message against a public key, including
+ * the signed trusted comment
+ */
+export async function verifyMinisign(
+  message: string,
+  signatureFile: string,
+  publicKey: string
+): Promise<MinisignVerification> {
+  const lines = signatureFile.split(/\r?\n/).map((line) => line.trim());
+  const trustedLine = lines.findIndex((line) => line.startsWith(TRUSTED_PREFIX));
+  const trustedComment =
+    trustedLine === -1 ? null : lines[trustedLine].slice(TRUSTED_PREFIX.length);
+  const failed = (error: string, keyId: string | null = null) => ({
+    valid: false,
+    keyId,
+    trustedComment,
+    error,
+  });
+
+  let signature: { keyId: Uint8Array; payload: Uint8Array };
+  let key: { keyId: Uint8Array; payload: Uint8Array };
+  let globalSignature: Uint8Array;
+  try {
+    signature = decodeMinisignLine(signatureFile, SIGNATURE_BYTES);
+    key = decodeMinisignLine(publicKey, PUBLIC_KEY_BYTES);
+    globalSignature = fromBase64(trustedLine === -1 ? '' : lines[trustedLine + 1] || '');
+  } catch (err) {
+    return failed(err instanceof Error ? err.message : String(err));
+  }
+  const keyId = formatKeyId(signature.keyId);
+  if (keyId !== formatKeyId(key.keyId)) {
+    return failed(`Signed with key ${keyId}, not ${formatKeyId(key.keyId)}`, keyId);
+  }
+  if (trustedComment === null || globalSignature.length !== SIGNATURE_BYTES) {
+    return failed('Trusted comment is missing', keyId);
+  }
+
+  const encoder = new TextEncoder();
+  const verify = await createVerifier(key.payload);
+
+  if (!(await verify(signature.payload, encoder.encode(message)))) {
+    return failed('Signature does not match the message', keyId);
+  }
+  if (!(await verify(globalSignature, concat(signature.payload, encoder.encode(trustedComment))))) {
+    return failed('Trusted comment signature is invalid', keyId);
+  }
+  return { valid: true, keyId, trustedComment };
+}
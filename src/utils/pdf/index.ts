@@ -7,7 +7,15 @@
  */
 
 // Types
-export type { TOCEntry, ArtifactCommit, RemovedArtifact, PageRef } from './types';
+export type {
+  TOCEntry,
+  ArtifactCommit,
+  RemovedArtifact,
+  PageRef,
+  PdfExportStage,
+  PdfExportProgress,
+} from './types';
+export { PDF_EXPORT_STAGES } from './types';
 
 // Image utilities
 export {
@@ -2,10 +2,13 @@
  * PDF Image Utilities
  *
  * Handles image extraction from markdown, loading from filesystem, and embedding in PDF.
+ * Mermaid/PlantUML code blocks are rendered to PNG and embedded like images.
  */
 
 import jsPDF from 'jspdf';
 import { fileSystemService } from '../../services/fileSystemService';
+import { renderDiagramsInMarkdown } from '../../services/diagramService';
+import { mapWithConcurrency } from '../concurrencyUtils';
 import type { PageRef } from './types';
 
 // Image cache to avoid loading the same image multiple times
@@ -84,14 +87,20 @@ export async function addImagesFromMarkdown(
   pageRef: PageRef
 ): Promise<number> {
   const imagePaths = extractImagePaths(markdown);
-  if (imagePaths.length === 0) return startY;
+  const diagrams = await renderDiagramsInMarkdown(markdown, 'png');
+  if (imagePaths.length === 0 && diagrams.length === 0) return startY;
+
+  const images: { label: string; dataUrl: string | null }[] = await mapWithConcurrency(
+    imagePaths,
+    async (imagePath) => ({ label: imagePath, dataUrl: await loadImageAsBase64(imagePath) })
+  );
+  diagrams.forEach((dataUrl, i) => images.push({ label: `diagram ${i + 1}`, dataUrl }));
 
   let currentY = startY;
   const maxImageHeight = 60; // Maximum image height in mm
   const maxImageWidth = contentWidth;
 
-  for (const imagePath of imagePaths) {
-    const dataUrl = await loadImageAsBase64(imagePath);
+  for (const { label, dataUrl } of images) {
     if (!dataUrl) continue;
 
     try {
@@ -132,14 +141,14 @@ export async function addImagesFromMarkdown(
       }
 
       // Determine format (jsPDF only supports JPEG, PNG, WEBP)
-      const ext = imagePath.split('.').pop()?.toLowerCase() || 'png';
-      const format = ext === 'jpg' ? 'JPEG' : ext.toUpperCase();
+      const mimeSubtype = dataUrl.match(/^data:image\/([a-z]+)/)?.[1] || 'png';
+      const format = mimeSubtype.toUpperCase();
 
       // Add the image
       doc.addImage(dataUrl, format, contentLeft, currentY, imgWidth, imgHeight);
       currentY += imgHeight + 3; // Add some spacing after image
     } catch (error) {
-      console.error(`[PDF Export] Failed to add image to PDF: ${imagePath}`, error);
+      console.error(`[PDF Export] Failed to add image to PDF: ${label}`, error);
     }
   }
 
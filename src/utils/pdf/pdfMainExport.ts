@@ -1,5 +1,6 @@
 import jsPDF from 'jspdf';
 import { debug } from '../debug';
+import { mapWithConcurrency } from '../concurrencyUtils';
 import type {
   Requirement,
   UseCase,
@@ -14,7 +15,14 @@ import { realGitService } from '../../services/realGitService';
 import { diskLinkService } from '../../services/diskLinkService';
 import { diskCustomAttributeService } from '../../services/diskCustomAttributeService';
 import type { CommitInfo } from '../../types';
-import type { TOCEntry, ArtifactCommit, RemovedArtifact } from './types';
+import type {
+  TOCEntry,
+  ArtifactCommit,
+  RemovedArtifact,
+  PdfExportStage,
+  PdfExportProgress,
+} from './types';
+import { PDF_EXPORT_STAGES } from './types';
 import {
   addCoverPage,
   addTableOfContents,
@@ -34,6 +42,9 @@ import { addRevisionHistory } from './pdfRevisionHistory';
 
 /**
  * Main export function for PDF
+ *
+ * `onProgress` is called as each stage starts, so callers can surface
+ * progress for long exports.
  */
 export async function exportProjectToPDF(
   project: Project,
@@ -52,8 +63,17 @@ export async function exportProjectToPDF(
   selectedBaseline: ProjectBaseline | null, // null = Current State
   currentUserName?: string,
   includeDocuments: boolean = true,
-  projectDocuments: ArtifactDocument[] = []
+  projectDocuments: ArtifactDocument[] = [],
+  onProgress?: (progress: PdfExportProgress) => void
 ): Promise<void> {
+  const reportProgress = (stage: PdfExportStage) => {
+    onProgress?.({
+      stage,
+      completed: PDF_EXPORT_STAGES.indexOf(stage),
+      total: PDF_EXPORT_STAGES.length,
+    });
+  };
+
   // Types for File System Access API
   interface ExtendedFileSystemFileHandle extends FileSystemHandle {
     createWritable(): Promise<FileSystemWritableFileStream>;
@@ -97,6 +117,7 @@ export async function exportProjectToPDF(
   const tocEntries: TOCEntry[] = [];
 
   // 1. Cover Page
+  reportProgress('cover');
   addCoverPage(doc, project, selectedBaseline, currentUserName);
   currentPage++;
 
@@ -185,82 +206,38 @@ export async function exportProjectToPDF(
     return oldestCommit.timestamp > previousBaseline.timestamp;
   };
 
-  // Fetch history for each artifact type
-  for (const req of projectRequirements) {
-    try {
-      const history = await realGitService.getHistory(`requirements/${req.id}.md`);
-      const filteredHistory = filterCommits(history);
-      const isNew = isArtifactNew(history);
-      if (filteredHistory.length > 0 || isNew) {
-        artifactCommits.push({
-          artifactId: req.id,
-          artifactTitle: req.title,
-          artifactType: 'requirement',
-          commits: filteredHistory,
-          isNew,
-        });
-      }
-    } catch (e) {
-      console.error(e);
-    }
-  }
-
-  for (const uc of projectUseCases) {
+  // Fetch history for each artifact, several at a time
+  reportProgress('history');
+  type HistorySource = [{ id: string; title: string }, string, ArtifactCommit['artifactType']];
+  const historySources: HistorySource[] = [
+    ...projectRequirements.map((a): HistorySource => [a, 'requirements', 'requirement']),
+    ...projectUseCases.map((a): HistorySource => [a, 'usecases', 'usecase']),
+    ...projectTestCases.map((a): HistorySource => [a, 'testcases', 'testcase']),
+    ...projectInformation.map((a): HistorySource => [a, 'information', 'information']),
+  ];
+  const histories = await mapWithConcurrency(historySources, async ([artifact, folder]) => {
     try {
-      const history = await realGitService.getHistory(`usecases/${uc.id}.md`);
-      const filteredHistory = filterCommits(history);
-      const isNew = isArtifactNew(history);
-      if (filteredHistory.length > 0 || isNew) {
-        artifactCommits.push({
-          artifactId: uc.id,
-          artifactTitle: uc.title,
-          artifactType: 'usecase',
-          commits: filteredHistory,
-          isNew,
-        });
-      }
+      return await realGitService.getHistory(`${folder}/${artifact.id}.md`);
     } catch (e) {
       console.error(e);
+      return null;
     }
-  }
-
-  for (const tc of projectTestCases) {
-    try {
-      const history = await realGitService.getHistory(`testcases/${tc.id}.md`);
-      const filteredHistory = filterCommits(history);
-      const isNew = isArtifactNew(history);
-      if (filteredHistory.length > 0 || isNew) {
-        artifactCommits.push({
-          artifactId: tc.id,
-          artifactTitle: tc.title,
-          artifactType: 'testcase',
-          commits: filteredHistory,
-          isNew,
-        });
-      }
-    } catch (e) {
-      console.error(e);
-    }
-  }
-
-  for (const info of projectInformation) {
-    try {
-      const history = await realGitService.getHistory(`information/${info.id}.md`);
-      const filteredHistory = filterCommits(history);
-      const isNew = isArtifactNew(history);
-      if (filteredHistory.length > 0 || isNew) {
-        artifactCommits.push({
-          artifactId: info.id,
-          artifactTitle: info.title,
-          artifactType: 'information',
-          commits: filteredHistory,
-          isNew,
-        });
-      }
-    } catch (e) {
-      console.error(e);
+  });
+  historySources.forEach(([artifact, , artifactType], i) => {
+    const history = histories[i];
+    if (!history) return;
+    const filteredHistory = filterCommits(history);
+    const isNew = isArtifactNew(history);
+    if (filteredHistory.length > 0 || isNew) {
+      artifactCommits.push({
+        artifactId: artifact.id,
+        artifactTitle: artifact.title,
+        artifactType,
+        commits: filteredHistory,
+        isNew,
+      });
     }
-  }
+  });
 
   let sectionNumber = 1;
 
@@ -274,6 +251,7 @@ export async function exportProjectToPDF(
   }
 
   // 5. Requirements Section
+  reportProgress('requirements');
   if (projectRequirements.length > 0) {
     doc.addPage();
     sectionNumber++;
@@ -289,6 +267,7 @@ export async function exportProjectToPDF(
   }
 
   // 6. Use Cases Section
+  reportProgress('usecases');
   if (projectUseCases.length > 0) {
     doc.addPage();
     sectionNumber++;
@@ -304,6 +283,7 @@ export async function exportProjectToPDF(
   }
 
   // 7. Test Cases Section
+  reportProgress('testcases');
   if (projectTestCases.length > 0) {
     doc.addPage();
     sectionNumber++;
@@ -319,6 +299,7 @@ export async function exportProjectToPDF(
   }
 
   // 8. Information Section
+  reportProgress('information');
   if (projectInformation.length > 0) {
     doc.addPage();
     sectionNumber++;
@@ -334,6 +315,7 @@ export async function exportProjectToPDF(
   }
 
   // 9. Risks Section
+  reportProgress('risks');
   const projectRisks = sortByIdNumber(
     (globalState.risks || []).filter((r: Risk) => project.riskIds?.includes(r.id) && !r.isDeleted)
   );
@@ -345,6 +327,7 @@ export async function exportProjectToPDF(
   }
 
   // 10. Documents Section
+  reportProgress('documents');
   if (includeDocuments && projectDocuments.length > 0) {
     currentPage = await addDocumentsSection(
       {
@@ -364,6 +347,7 @@ export async function exportProjectToPDF(
   }
 
   // 11. Links Section
+  reportProgress('links');
   const projectLinks = await diskLinkService.getLinksForProject(project.id);
   if (projectLinks.length > 0) {
     doc.addPage();
@@ -373,12 +357,14 @@ export async function exportProjectToPDF(
   }
 
   // Add TOC
+  reportProgress('toc');
   addTableOfContents(doc, tocEntries, tocStartPage, tocPagesNeeded);
 
   // Add Page Numbers
   addPageNumbers(doc);
 
   // Save
+  reportProgress('saving');
   if (typeof window !== 'undefined' && 'showSaveFilePicker' in window) {
     try {
       const pdfBlob = doc.output('blob');
@@ -32,3 +32,27 @@ export interface RemovedArtifact {
 export interface PageRef {
   page: number;
 }
+
+// Stages reported while a project PDF is being built, in the order they run
+export const PDF_EXPORT_STAGES = [
+  'cover',
+  'history',
+  'requirements',
+  'usecases',
+  'testcases',
+  'information',
+  'risks',
+  'documents',
+  'links',
+  'toc',
+  'saving',
+] as const;
+
+export type PdfExportStage = (typeof PDF_EXPORT_STAGES)[number];
+
+// Progress update emitted by exportProjectToPDF
+export interface PdfExportProgress {
+  stage: PdfExportStage;
+  completed: number;
+  total: number;
+}
@@ -0,0 +1,234 @@
+/**
+ * Print Export Utilities
+ *
+ * Renders a set of artifacts as one print-optimized HTML document: a
+ * numbered contents list, numbered artifact and section headings, links
+ * between artifacts of the set turned into in-document anchors, and images
+ * embedded as data URLs so the document prints without access to the
+ * project folder.
+ */
+
+import { createElement } from 'react';
+import { renderToStaticMarkup } from 'react-dom/server';
+import ReactMarkdown from 'react-markdown';
+import remarkGfm from 'remark-gfm';
+import { parseYamlFrontmatter } from './markdownBase';
+import { isEncryptedContent } from './encryptionUtils';
+import { resolveRelativePath } from './referenceUtils';
+import { LINK_TYPE_LABELS, type LinkType } from './linkTypes';
+
+export interface PrintArtifact {
+  id: string;
+  path: string; // Repo-relative file path, e.g. requirements/REQ-001.md
+  content: string | null; // Raw file content; null when it could not be read
+  links: { type: LinkType; targetId: string }[]; // As seen from this artifact
+}
+
+export interface PrintOptions {
+  title: string;
+  images?: Record<string, string>; // Image path as written in markdown -> data URL
+  printedAt?: Date;
+}
+
+// Frontmatter fields shown under each artifact heading
+const PRINT_FIELDS = ['status', 'priority', 'revision', 'author'];
+
+const PRINT_STYLE = `
+body { font-family: system-ui, sans-serif; font-size: 11pt; line-height: 1.4; margin: 0 auto;
+  max-width: 800px; padding: 24px; color: #111827; }
+h1 { font-size: 20pt; }
+h2 { font-size: 15pt; border-bottom: 1px solid #d1d5db; padding-bottom: 4px; }
+h3 { font-size: 12pt; }
+h4, h5, h6 { font-size: 11pt; }
+h2, h3, h4, h5, h6 { break-after: avoid; page-break-after: avoid; }
+table { border-collapse: collapse; margin: 8px 0; }
+th, td { border: 1px solid #d1d5db; padding: 4px 8px; text-align: left; vertical-align: top; }
+tr, img, pre { break-inside: avoid; page-break-inside: avoid; }
+img { max-width: 100%; }
+pre { white-space: pre-wrap; }
+a { color: inherit; }
+nav ol { list-style: none; padding-left: 0; }
+.meta { color: #6b7280; font-size: 9pt; }
+.warning { color: #b91c1c; }
+@page { margin: 2cm; }`;
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+function renderFields(frontmatter: Record<string, unknown>): string {
+  const isSet = (value: unknown) => value !== undefined && value !== null && value !== '';
+  return PRINT_FIELDS.filter((field) => isSet(frontmatter[field]))
+    .map((field) => `<tr><th>${field}</th><td>${escapeHtml(String(frontmatter[field]))}</td></tr>`)
+    .join('');
+}
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+// Drop the leading "# Title" (shown in the artifact heading) and sections
+// with nothing under them, which artifact templates leave behind
+function tidyBody(body: string, title: string): string {
+  const lines = body.trim().split('\n');
+  if (lines[0]?.startsWith('# ') && lines[0].slice(2).trim() === title.trim()) {
+    lines.shift();
+  }
+  const isHeading = (line?: string) => line !== undefined && /^#{1,6}\s/.test(line);
+  return lines
+    .filter((line, i) => {
+      if (!isHeading(line)) return true;
+      const next = lines.slice(i + 1).find((l) => l.trim() !== '');
+      const level = (text: string) => text.match(/^#+/)![0].length;
+      return next !== undefined && !(isHeading(next) && level(next) <= level(line));
+    })
+    .join('\n');
+}
+
+function renderMarkdown(markdown: string): string {
+  return renderToStaticMarkup(
+    createElement(ReactMarkdown, { remarkPlugins: [remarkGfm] }, markdown)
+  );
+}
+
+// Artifact headings are h2; body headings move below them and the top level
+// of them is numbered within the artifact (1.1, 1.2, ...)
+function numberBodyHeadings(html: string, number: number): string {
+  const levels = [...html.matchAll(/<h([1-6])>/g)].map((m) => Number(m[1]));
+  const top = levels.length > 0 ? Math.min(...levels) : 1;
+  let section = 0;
+  return html.replace(/<(\/?)h([1-6])>/g, (_match, close: string, level: string) => {
+    const shifted = Math.min(Number(level) - top + 3, 6);
+    if (close) return `</h${shifted}>`;
+    return shifted === 3 ? `<h3>${number}.${++section} ` : `<h${shifted}>`;
+  });
+}
+
+// Point links and bare mentions of artifacts in the set at their section,
+// and images at their embedded data
+function resolveReferences(
+  html: string,
+  artifact: PrintArtifact,
+  pathToId: Map<string, string>,
+  idPattern: RegExp | null,
+  images: Record<string, string>
+): string {
+  const withAttributes = html
+    .replace(/<img src="([^"]*)"/g, (match, src: string) => {
+      const data = images[src.replace(/&amp;/g, '&')];
+      return data ? `<img src="${data}"` : match;
+    })
+    .replace(/<a href="([^"]*)"/g, (match, href: string) => {
+      if (/^[a-z][a-z0-9+.-]*:|^#/i.test(href)) return match;
+      const id = pathToId.get(resolveRelativePath(artifact.path, href.split('#')[0]));
+      return id ? `<a href="#${escapeHtml(id)}"` : match;
+    });
+  if (!idPattern) return withAttributes;
+
+  // Bare IDs in text, outside existing links and code
+  let skipDepth = 0;
+  return withAttributes
+    .split(/(<[^>]+>)/)
+    .map((part) => {
+      const tag = part.match(/^<(\/?)(a|code|pre)[\s>]/);
+      if (tag) {
+        skipDepth += tag[1] ? -1 : 1;
+        return part;
+      }
+      if (part.startsWith('<') || skipDepth > 0) return part;
+      return part.replace(idPattern, (id) =>
+        id === artifact.id ? id : `<a href="#${id}">${id}</a>`
+      );
+    })
+    .join('');
+}
+
+function renderLinks(artifact: PrintArtifact, ids: Set<string>): string {
+  if (artifact.links.length === 0) return '';
+  const items = artifact.links
+    .map(({ type, targetId }) => {
+      const target = ids.has(targetId)
+        ? `<a href="#${escapeHtml(targetId)}">${escapeHtml(targetId)}</a>`
+        : escapeHtml(targetId);
+      return `<li>${escapeHtml(LINK_TYPE_LABELS[type] ?? type)} ${target}</li>`;
+    })
+    .join('');
+  return `<h4>Links</h4>\n<ul>${items}</ul>`;
+}
+
+/**
+ * The artifacts, in the given order, as a standalone HTML document for printing
+ */
+export function buildPrintHtml(artifacts: PrintArtifact[], options: PrintOptions): string {
+  const { title, images = {}, printedAt = new Date() } = options;
+  const ids = new Set(artifacts.map((a) => a.id));
+  const pathToId = new Map(artifacts.map((a) => [a.path, a.id]));
+  const idPattern =
+    ids.size > 0 ? new RegExp(`\\b(?:${[...ids].map(escapeRegExp).join('|')})\\b`, 'g') : null;
+
+  const headings: string[] = [];
+  const sections = artifacts.map((artifact, index) => {
+    const number = index + 1;
+    const open = `<section id="${escapeHtml(artifact.id)}">`;
+    if (artifact.content === null || isEncryptedContent(artifact.content)) {
+      const reason = artifact.content === null ? 'could not be read' : 'is encrypted';
+      headings.push(escapeHtml(artifact.id));
+      return [
+        open,
+        `<h2>${number}. ${escapeHtml(artifact.id)}</h2>`,
+        `<p class="warning">Content ${reason}.</p>`,
+        '</section>',
+      ].join('\n');
+    }
+
+    const { frontmatter, body } = parseYamlFrontmatter(artifact.content);
+    const artifactTitle =
+      typeof frontmatter.title === 'string'
+        ? frontmatter.title
+        : typeof frontmatter.name === 'string'
+          ? frontmatter.name
+          : '';
+    const heading = artifactTitle
+      ? `${escapeHtml(artifact.id)} – ${escapeHtml(artifactTitle)}`
+      : escapeHtml(artifact.id);
+    headings.push(heading);
+    const rows = renderFields(frontmatter);
+    const content = numberBodyHeadings(renderMarkdown(tidyBody(body, artifactTitle)), number);
+    return [
+      open,
+      `<h2>${number}. ${heading}</h2>`,
+      rows ? `<table>${rows}</table>` : '',
+      resolveReferences(content, artifact, pathToId, idPattern, images),
+      renderLinks(artifact, ids),
+      '</section>',
+    ].join('\n');
+  });
+
+  const toc = artifacts
+    .map((a, i) => `<li>${i + 1}. <a href="#${escapeHtml(a.id)}">${headings[i]}</a></li>`)
+    .join('');
+
+  return `<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>${escapeHtml(title)}</title>
+<meta name="generator" content="Tracyfy">
+<style>${PRINT_STYLE}
+</style>
+</head>
+<body>
+<header>
+<h1>${escapeHtml(title)}</h1>
+<p class="meta">${artifacts.length} artifacts, printed ${printedAt.toISOString().slice(0, 10)}</p>
+<nav><ol>${toc}</ol></nav>
+</header>
+${sections.join('\n')}
+</body>
+</html>
+`;
+}
@@ -0,0 +1,201 @@
+/**
+ * Quality Lint Utilities
+ *
+ * INCOSE-style quality checks for requirement text: ambiguous wording, passive
+ * voice, over-long sentences and missing acceptance criteria. Findings carry
+ * line/column positions in the source markdown so editors can mark them.
+ */
+
+import { getBodyStartLine } from './markdownBase';
+
+export type LintRule =
+  | 'ambiguous-term'
+  | 'passive-voice'
+  | 'long-sentence'
+  | 'missing-acceptance-criteria';
+
+export type LintSeverity = 'warning' | 'info';
+
+export interface LintFinding {
+  rule: LintRule;
+  severity: LintSeverity;
+  message: string;
+  line: number; // 1-based line number in the source file
+  column: number; // 1-based column of the first character
+  length: number;
+}
+
+export interface LintOptions {
+  requireAcceptanceCriteria?: boolean;
+  maxSentenceWords?: number;
+}
+
+export const DEFAULT_MAX_SENTENCE_WORDS = 30;
+
+// Vague or non-binding wording that makes a requirement unverifiable
+export const AMBIGUOUS_TERMS = [
+  'should',
+  'may',
+  'might',
+  'could',
+  'as appropriate',
+  'as applicable',
+  'as required',
+  'as needed',
+  'if possible',
+  'if necessary',
+  'where possible',
+  'and/or',
+  'etc.',
+  'TBD',
+  'TBC',
+  'TBS',
+  'approximately',
+  'adequate',
+  'sufficient',
+  'user-friendly',
+  'easy',
+  'fast',
+  'quickly',
+  'normally',
+  'typically',
+];
+
+const IRREGULAR_PARTICIPLES = [
+  'been',
+  'built',
+  'done',
+  'found',
+  'given',
+  'held',
+  'kept',
+  'known',
+  'made',
+  'read',
+  'run',
+  'seen',
+  'sent',
+  'set',
+  'shown',
+  'taken',
+  'written',
+];
+
+// A form of "to be", an optional adverb and a past participle ("is automatically sent")
+const PARTICIPLE = `(?:\\w{2,}ed|${IRREGULAR_PARTICIPLES.join('|')})`;
+const PASSIVE_PATTERN = new RegExp(
+  `\\b(?:am|is|are|was|were|be|been|being)\\s+(?:\\w+ly\\s+)?${PARTICIPLE}\\b`,
+  'gi'
+);
+const ACCEPTANCE_CRITERIA_PATTERN = /^(?:#{2,6}\s*|\*\*)?acceptance criteria\b:?(?:\*\*)?:?/i;
+const SENTENCE_PATTERN = /[^.!?]+(?:[.!?]+|$)/g;
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+function createAmbiguousPattern(): RegExp {
+  const alternatives = AMBIGUOUS_TERMS.map((term) => {
+    const escaped = escapeRegExp(term);
+    // Terms ending in punctuation (etc.) cannot use a trailing word boundary
+    return /\w$/.test(term) ? `\\b${escaped}\\b` : `\\b${escaped}`;
+  });
+  return new RegExp(alternatives.join('|'), 'gi');
+}
+
+/**
+ * Lines of the markdown that hold prose: frontmatter, fenced code blocks and
+ * headings are skipped. Returns [zero-based index, line] pairs.
+ */
+function getProseLines(lines: string[]): [number, string][] {
+  let index = getBodyStartLine(lines);
+
+  const prose: [number, string][] = [];
+  let inCodeFence = false;
+  for (; index < lines.length; index++) {
+    const line = lines[index];
+    if (line.trim().startsWith('```')) {
+      inCodeFence = !inCodeFence;
+      continue;
+    }
+    if (inCodeFence || line.trimStart().startsWith('#')) continue;
+    prose.push([index, line]);
+  }
+  return prose;
+}
+
+function hasAcceptanceCriteria(lines: string[]): boolean {
+  const start = lines.findIndex((line) => ACCEPTANCE_CRITERIA_PATTERN.test(line.trim()));
+  if (start === -1) return false;
+
+  // The section needs content before the next heading
+  const next = lines.findIndex((line, i) => i > start && /^#{1,6}\s/.test(line.trim()));
+  const content = lines.slice(start + 1, next === -1 ? undefined : next);
+  const inline = lines[start].trim().replace(ACCEPTANCE_CRITERIA_PATTERN, '');
+  return inline.trim().length > 0 || content.some((line) => line.trim().length > 0);
+}
+
+function lintLine(index: number, line: string, maxSentenceWords: number): LintFinding[] {
+  const findings: LintFinding[] = [];
+  const at = (start: number, length: number) => ({ line: index + 1, column: start + 1, length });
+
+  for (const match of line.matchAll(createAmbiguousPattern())) {
+    findings.push({
+      rule: 'ambiguous-term',
+      severity: 'warning',
+      message: `Ambiguous term "${match[0]}"`,
+      ...at(match.index, match[0].length),
+    });
+  }
+
+  for (const match of line.matchAll(PASSIVE_PATTERN)) {
+    findings.push({
+      rule: 'passive-voice',
+      severity: 'info',
+      message: `Passive voice "${match[0]}"; name the actor responsible`,
+      ...at(match.index, match[0].length),
+    });
+  }
+
+  for (const match of line.matchAll(SENTENCE_PATTERN)) {
+    const words = match[0].match(/\S+/g) || [];
+    if (words.length <= maxSentenceWords) continue;
+
+    const start = match.index + (match[0].length - match[0].trimStart().length);
+    findings.push({
+      rule: 'long-sentence',
+      severity: 'warning',
+      message: `Sentence has ${words.length} words (max ${maxSentenceWords})`,
+      ...at(start, match[0].trim().length),
+    });
+  }
+
+  return findings;
+}
+
+/**
+ * Run all quality checks on an artifact markdown file.
+ * Findings are sorted by position.
+ */
+export function lintMarkdown(markdown: string, options: LintOptions = {}): LintFinding[] {
+  const maxSentenceWords = options.maxSentenceWords ?? DEFAULT_MAX_SENTENCE_WORDS;
+  const lines = markdown.split('\n');
+  const findings = getProseLines(lines).flatMap(([index, line]) =>
+    lintLine(index, line, maxSentenceWords)
+  );
+
+  if (options.requireAcceptanceCriteria && !hasAcceptanceCriteria(lines)) {
+    const titleIndex = lines.findIndex((line) => line.startsWith('# '));
+    const index = Math.max(titleIndex, 0);
+    findings.push({
+      rule: 'missing-acceptance-criteria',
+      severity: 'warning',
+      message: 'No "Acceptance Criteria" section',
+      line: index + 1,
+      column: 1,
+      length: lines[index].length,
+    });
+  }
+
+  return findings.sort((a, b) => a.line - b.line || a.column - b.column);
+}
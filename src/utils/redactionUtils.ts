@@ -0,0 +1,125 @@
+/**
+ * Redaction Utilities
+ *
+ * Applies a redaction profile to what goes into an export: listed
+ * frontmatter fields and custom attributes are left out, body sections whose
+ * heading carries a listed tag ("## Customer notes #internal") are removed
+ * with everything under them, and text matching the profile's patterns is
+ * replaced with a marker. Works on loaded artifacts (PDF, Excel, JSON
+ * export), raw markdown files (print) and tables (CSV).
+ */
+
+import type { RedactionProfile } from '../types/redaction';
+import { objectToYaml, parseYamlFrontmatter } from './markdownBase';
+import { normalizeTag } from './tagQueryUtils';
+
+export const REDACTED_TEXT = '[redacted]';
+
+// Fields an export cannot do without, never left out
+const PROTECTED_FIELDS = new Set(['id', 'title', 'name']);
+
+// Identifiers and references, kept as they are so links still resolve
+const UNREDACTED_KEYS = new Set(['id', 'projectId', 'tags', 'linkedArtifacts', 'structure']);
+
+/**
+ * Problems with a profile; empty when it can be used
+ */
+export function validateRedactionProfile(profile: RedactionProfile): string[] {
+  const errors: string[] = [];
+  if (!profile.name.trim()) {
+    errors.push('Profile name must not be empty');
+  }
+  for (const pattern of profile.patterns) {
+    try {
+      new RegExp(pattern);
+    } catch {
+      errors.push(`Invalid pattern in profile ${profile.name}: ${pattern}`);
+    }
+  }
+  const fields = profile.fields.filter((field) => PROTECTED_FIELDS.has(field));
+  if (fields.length > 0) {
+    errors.push(`Fields cannot be redacted: ${fields.join(', ')}`);
+  }
+  return errors;
+}
+
+function compilePatterns(patterns: string[]): RegExp[] {
+  return patterns.filter((pattern) => pattern.trim()).map((pattern) => new RegExp(pattern, 'gi'));
+}
+
+// Drop tagged sections, up to the next heading of the same or a higher level;
+// fenced code is left alone
+function removeTaggedSections(text: string, tags: Set<string>): string {
+  if (tags.size === 0) return text;
+  const kept: string[] = [];
+  let skipLevel = 0;
+  let inFence = false;
+  for (const line of text.split('\n')) {
+    if (/^\s*(?:```|~~~)/.test(line)) inFence = !inFence;
+    const heading = inFence ? null : line.match(/^(#{1,6})\s+(.*)$/);
+    if (heading) {
+      const level = heading[1].length;
+      if (skipLevel > 0 && level > skipLevel) continue;
+      const lineTags = [...heading[2].matchAll(/(?:^|\s)#([\w-]+)/g)].map((m) => m[1]);
+      skipLevel = lineTags.some((tag) => tags.has(normalizeTag(tag))) ? level : 0;
+    }
+    if (skipLevel === 0) kept.push(line);
+  }
+  return kept.join('\n');
+}
+
+/**
+ * Text with tagged sections removed and pattern matches replaced
+ */
+export function redactText(text: string, profile: RedactionProfile): string {
+  const tags = new Set(profile.sectionTags.map(normalizeTag));
+  return compilePatterns(profile.patterns).reduce(
+    (result, pattern) => result.replace(pattern, REDACTED_TEXT),
+    removeTaggedSections(text, tags)
+  );
+}
+
+function redactValue(value: unknown, profile: RedactionProfile): unknown {
+  if (typeof value === 'string') return redactText(value, profile);
+  if (Array.isArray(value)) return value.map((item) => redactValue(item, profile));
+  return value;
+}
+
+/**
+ * Copy of a loaded artifact with the profile applied to its fields; IDs,
+ * links and other non-text values are kept as they are
+ */
+export function redactArtifact<T extends object>(artifact: T, profile: RedactionProfile): T {
+  const fields = new Set(profile.fields);
+  const result: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(artifact)) {
+    if (fields.has(key) && !PROTECTED_FIELDS.has(key)) continue;
+    if (key === 'customAttributes' && Array.isArray(value)) {
+      result[key] = value.filter((attr: { attributeId: string }) => !fields.has(attr.attributeId));
+    } else if (UNREDACTED_KEYS.has(key)) {
+      result[key] = value;
+    } else {
+      result[key] = redactValue(value, profile);
+    }
+  }
+  return result as T;
+}
+
+/**
+ * Markdown file content with the profile applied to frontmatter and body
+ */
+export function redactMarkdownFile(content: string, profile: RedactionProfile): string {
+  const { frontmatter, body } = parseYamlFrontmatter(content);
+  if (Object.keys(frontmatter).length === 0) {
+    return redactText(content, profile);
+  }
+  const redacted = redactArtifact(frontmatter, profile);
+  return `${objectToYaml(redacted)}\n${redactText(body, profile)}`;
+}
+
+/**
+ * Table (header row plus data rows) with the profile applied to every cell
+ */
+export function redactTable(table: string[][], profile: RedactionProfile): string[][] {
+  return table.map((row) => row.map((cell) => redactText(cell, profile)));
+}
@@ -0,0 +1,148 @@
+/**
+ * Reference Utilities
+ *
+ * Extracts inter-artifact references from markdown content: relative links to
+ * other files in the repository and bare artifact IDs (e.g. REQ-001, TC-014).
+ * Wiki-style references ([[REQ-001]], [[verifies:REQ-001|see here]]) are
+ * extracted separately, as they stand for trace links.
+ */
+
+import { ARTIFACT_CONFIG } from '../constants/artifactConfig';
+import { getBodyStartLine } from './markdownBase';
+
+export type ReferenceKind = 'path' | 'id';
+
+export interface ArtifactReference {
+  kind: ReferenceKind;
+  target: string; // Repo-relative path for 'path', artifact ID for 'id'
+  line: number; // 1-based line number in the source file
+}
+
+export interface WikiLinkReference {
+  targetId: string;
+  linkType?: string; // As written; not checked against the known link types
+  line: number; // 1-based line number in the source file
+}
+
+const ID_PREFIXES = Object.values(ARTIFACT_CONFIG).map((config) => config.idPrefix);
+const MARKDOWN_LINK_PATTERN = /!?\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)/g;
+const EXTERNAL_TARGET_PATTERN = /^[a-z][a-z0-9+.-]*:/i;
+// [[ID]], optionally with a link type before the ID and a label after it
+const WIKI_LINK_PATTERN = /\[\[(?:([a-z_]+):)?([A-Za-z]+-[A-Za-z0-9-]+)(?:\|[^\]]*)?\]\]/g;
+
+function createIdPattern(): RegExp {
+  return new RegExp(`\\b(?:${ID_PREFIXES.join('|')})-\\d+\\b`, 'g');
+}
+
+/**
+ * Resolve a relative link target against the file that contains it.
+ * Returns a repo-relative path without leading slashes.
+ */
+export function resolveRelativePath(fromFile: string, target: string): string {
+  const baseParts = target.startsWith('/') ? [] : fromFile.split('/').slice(0, -1);
+  const parts = [...baseParts];
+
+  for (const segment of target.split('/')) {
+    if (segment === '' || segment === '.') continue;
+    if (segment === '..') {
+      parts.pop();
+    } else {
+      parts.push(segment);
+    }
+  }
+
+  return parts.join('/');
+}
+
+/**
+ * Clean up a raw link target: drop anchors/queries and decode escapes.
+ * Returns null for targets that do not point at a repository file.
+ */
+function normalizeLinkTarget(rawTarget: string): string | null {
+  if (rawTarget.startsWith('#') || EXTERNAL_TARGET_PATTERN.test(rawTarget)) {
+    return null;
+  }
+
+  const withoutAnchor = rawTarget.split('#')[0].split('?')[0];
+  if (!withoutAnchor) return null;
+
+  try {
+    return decodeURIComponent(withoutAnchor);
+  } catch {
+    return withoutAnchor;
+  }
+}
+
+// Call back for each line outside the YAML frontmatter and fenced code blocks
+function forEachContentLine(
+  markdown: string,
+  callback: (line: string, lineNumber: number) => void
+): void {
+  const lines = markdown.split('\n');
+  let index = getBodyStartLine(lines);
+
+  let inCodeFence = false;
+  for (; index < lines.length; index++) {
+    const line = lines[index];
+    if (line.trim().startsWith('```')) {
+      inCodeFence = !inCodeFence;
+      continue;
+    }
+    if (!inCodeFence) callback(line, index + 1);
+  }
+}
+
+/**
+ * Extract relative file links and artifact ID references from markdown.
+ *
+ * YAML frontmatter and fenced code blocks are skipped. Paths are resolved
+ * relative to `sourcePath` so they can be compared against repository files.
+ */
+export function extractReferences(markdown: string, sourcePath: string): ArtifactReference[] {
+  const references: ArtifactReference[] = [];
+  const seen = new Set<string>();
+
+  const add = (kind: ReferenceKind, target: string, line: number) => {
+    const key = `${kind}:${target}:${line}`;
+    if (seen.has(key)) return;
+    seen.add(key);
+    references.push({ kind, target, line });
+  };
+
+  forEachContentLine(markdown, (line, lineNumber) => {
+    for (const match of line.matchAll(MARKDOWN_LINK_PATTERN)) {
+      const target = normalizeLinkTarget(match[1]);
+      if (target) {
+        add('path', resolveRelativePath(sourcePath, target), lineNumber);
+      }
+    }
+
+    for (const match of line.matchAll(createIdPattern())) {
+      add('id', match[0], lineNumber);
+    }
+  });
+
+  return references;
+}
+
+/**
+ * Extract [[ID]] references from markdown, skipping YAML frontmatter and
+ * fenced code blocks. Only the first mention of each target and link type
+ * is returned.
+ */
+export function extractWikiLinks(markdown: string): WikiLinkReference[] {
+  const references: WikiLinkReference[] = [];
+  const seen = new Set<string>();
+
+  forEachContentLine(markdown, (line, lineNumber) => {
+    for (const match of line.matchAll(WIKI_LINK_PATTERN)) {
+      const [, linkType, targetId] = match;
+      const key = `${linkType ?? ''}:${targetId}`;
+      if (seen.has(key)) continue;
+      seen.add(key);
+      references.push({ targetId, ...(linkType && { linkType }), line: lineNumber });
+    }
+  });
+
+  return references;
+}
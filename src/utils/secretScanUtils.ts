@@ -0,0 +1,79 @@
+/**
+ * Secret Scan Utilities
+ *
+ * Finds text that looks like credentials (cloud and forge access tokens,
+ * private keys, passwords assigned in config snippets, URLs with a password)
+ * in file content about to be committed. Requirements imported from other
+ * systems often carry such text along. A line containing the allow marker is
+ * skipped, for examples that only look like secrets.
+ */
+
+export const SECRET_ALLOW_MARKER = 'tracyfy:allow-secret';
+
+export interface SecretFinding {
+  path: string;
+  line: number; // 1-based
+  rule: string; // What the text looks like, e.g. "AWS access key"; never the secret itself
+}
+
+interface SecretRule {
+  name: string;
+  pattern: RegExp;
+  // Extra check of the captured value, to skip placeholders and prose
+  accept?: (value: string) => boolean;
+}
+
+// A value that mixes letters and digits and is not a placeholder like <token> or ${TOKEN}
+const looksLikeCredential = (value: string) =>
+  /[a-z]/i.test(value) && /\d/.test(value) && !/^[<{$%*x]|^(?:changeme|example)/i.test(value);
+
+const SECRET_RULES: SecretRule[] = [
+  { name: 'AWS access key', pattern: /\b(?:AKIA|ASIA)[0-9A-Z]{16}\b/ },
+  { name: 'GitHub token', pattern: /\b(?:gh[pousr]_[A-Za-z0-9]{36,}|github_pat_\w{60,})/ },
+  { name: 'GitLab token', pattern: /\bglpat-[A-Za-z0-9_-]{20,}/ },
+  { name: 'Slack token', pattern: /\bxox[abposr]-[A-Za-z0-9-]{10,}/ },
+  { name: 'Google API key', pattern: /\bAIza[0-9A-Za-z_-]{35}/ },
+  { name: 'Stripe secret key', pattern: /\b[rs]k_live_[0-9A-Za-z]{24,}/ },
+  { name: 'private key', pattern: /-----BEGIN (?:[A-Z0-9]+ )*PRIVATE KEY(?: BLOCK)?-----/ },
+  { name: 'JSON web token', pattern: /\beyJ[\w-]{10,}\.eyJ[\w-]{10,}\.[\w-]{10,}/ },
+  {
+    name: 'password in URL',
+    pattern: /\b[a-z][a-z0-9+.-]*:\/\/[^\s:/@]+:([^\s:/@]+)@/i,
+    accept: looksLikeCredential,
+  },
+  {
+    name: 'password or API key',
+    pattern:
+      /(?<![a-z])(?:password|passwd|pwd|secret|api[_-]?key|access[_-]?token|client[_-]?secret)\b["']?\s*[:=]\s*["']?([^\s"',;]{8,})/i,
+    accept: looksLikeCredential,
+  },
+];
+
+/**
+ * Places in the content of one file that look like credentials, at most one
+ * per line
+ */
+export function scanForSecrets(path: string, content: string): SecretFinding[] {
+  const findings: SecretFinding[] = [];
+  content.split('\n').forEach((text, i) => {
+    if (text.includes(SECRET_ALLOW_MARKER)) return;
+    const rule = SECRET_RULES.find(({ pattern, accept }) => {
+      const match = text.match(pattern);
+      return !!match && (!accept || accept(match[1] ?? match[0]));
+    });
+    if (rule) findings.push({ path, line: i + 1, rule: rule.name });
+  });
+  return findings;
+}
+
+/**
+ * Findings as a short list for messages, e.g. "requirements/REQ-001.md line 12
+ * (AWS access key)"
+ */
+export function formatSecretFindings(findings: SecretFinding[], limit: number = 5): string {
+  const shown = findings
+    .slice(0, limit)
+    .map((finding) => `${finding.path} line ${finding.line} (${finding.rule})`);
+  const more = findings.length - shown.length;
+  return more > 0 ? `${shown.join(', ')} and ${more} more` : shown.join(', ');
+}
@@ -0,0 +1,95 @@
+/**
+ * Similarity Utilities
+ *
+ * Text similarity for near-duplicate detection: texts are tokenized into word
+ * shingles (overlapping n-word sequences) and compared by cosine similarity of
+ * their shingle counts.
+ */
+
+export interface SimilarityDocument {
+  id: string;
+  text: string;
+}
+
+export interface SimilarPair {
+  a: string;
+  b: string;
+  score: number; // Cosine similarity between 0 and 1, rounded to 3 decimals
+}
+
+export interface SimilarityOptions {
+  threshold?: number; // Minimum score for a pair to be reported
+  shingleSize?: number; // Words per shingle
+}
+
+export const DEFAULT_SIMILARITY_THRESHOLD = 0.8;
+export const DEFAULT_SHINGLE_SIZE = 2;
+
+/**
+ * Lowercase word tokens, ignoring punctuation and markdown syntax
+ */
+export function tokenize(text: string): string[] {
+  return text.toLowerCase().match(/[\p{L}\p{N}]+(?:['-][\p{L}\p{N}]+)*/gu) || [];
+}
+
+/**
+ * Count the n-word shingles of a text. Texts shorter than n words form a
+ * single shingle so they can still be compared.
+ */
+export function shingle(text: string, size: number = DEFAULT_SHINGLE_SIZE): Map<string, number> {
+  const tokens = tokenize(text);
+  const counts = new Map<string, number>();
+  if (tokens.length === 0) return counts;
+
+  const count = Math.max(tokens.length - size + 1, 1);
+  for (let i = 0; i < count; i++) {
+    const key = tokens.slice(i, i + size).join(' ');
+    counts.set(key, (counts.get(key) || 0) + 1);
+  }
+  return counts;
+}
+
+/**
+ * Cosine similarity of two shingle count vectors
+ */
+export function cosineSimilarity(a: Map<string, number>, b: Map<string, number>): number {
+  if (a.size === 0 || b.size === 0) return 0;
+
+  const [small, large] = a.size <= b.size ? [a, b] : [b, a];
+  let dot = 0;
+  small.forEach((count, key) => {
+    dot += count * (large.get(key) || 0);
+  });
+
+  const norm = (vector: Map<string, number>) =>
+    Math.sqrt([...vector.values()].reduce((sum, count) => sum + count * count, 0));
+  return dot / (norm(a) * norm(b));
+}
+
+/**
+ * All pairs of documents whose similarity reaches the threshold, most similar first
+ */
+export function findSimilarPairs(
+  documents: SimilarityDocument[],
+  options: SimilarityOptions = {}
+): SimilarPair[] {
+  const threshold = options.threshold ?? DEFAULT_SIMILARITY_THRESHOLD;
+  const vectors = documents
+    .map((doc) => ({ id: doc.id, shingles: shingle(doc.text, options.shingleSize) }))
+    .filter((doc) => doc.shingles.size > 0);
+
+  const pairs: SimilarPair[] = [];
+  for (let i = 0; i < vectors.length; i++) {
+    for (let j = i + 1; j < vectors.length; j++) {
+      const similarity = cosineSimilarity(vectors[i].shingles, vectors[j].shingles);
+      const score = Math.round(similarity * 1000) / 1000;
+      if (score >= threshold) {
+        pairs.push({ a: vectors[i].id, b: vectors[j].id, score });
+      }
+    }
+  }
+
+  return pairs.sort(
+    (x, y) => y.score - x.score || x.a.localeCompare(y.a, undefined, { numeric: true })
+  );
+}
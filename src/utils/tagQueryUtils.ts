@@ -0,0 +1,103 @@
+/**
+ * Tag Query Utilities
+ *
+ * Normalizes artifact tags and evaluates tag expressions such as
+ * `safety AND (ui OR NOT legacy)`. Operators are case-insensitive;
+ * adjacent terms without an operator are combined with AND.
+ */
+
+export type TagQuery =
+  | { kind: 'tag'; tag: string }
+  | { kind: 'not'; operand: TagQuery }
+  | { kind: 'and' | 'or'; left: TagQuery; right: TagQuery };
+
+const TOKEN_PATTERN = /\(|\)|[^\s()]+/g;
+const OPERATORS = ['AND', 'OR', 'NOT'];
+
+/**
+ * Normalize a tag: trimmed, lower-case, inner whitespace replaced by dashes
+ */
+export function normalizeTag(tag: string): string {
+  return tag.trim().toLowerCase().replace(/\s+/g, '-');
+}
+
+/**
+ * Parse a tag expression into a query tree
+ */
+export function parseTagQuery(expression: string): TagQuery {
+  const tokens = expression.match(TOKEN_PATTERN) || [];
+  let position = 0;
+
+  const peek = () => tokens[position];
+  const isOperator = (token: string | undefined, operator: string) =>
+    token !== undefined && token.toUpperCase() === operator;
+
+  const parseOr = (): TagQuery => {
+    let left = parseAnd();
+    while (isOperator(peek(), 'OR')) {
+      position++;
+      left = { kind: 'or', left, right: parseAnd() };
+    }
+    return left;
+  };
+
+  const parseAnd = (): TagQuery => {
+    let left = parseNot();
+    while (peek() !== undefined && peek() !== ')' && !isOperator(peek(), 'OR')) {
+      if (isOperator(peek(), 'AND')) position++;
+      left = { kind: 'and', left, right: parseNot() };
+    }
+    return left;
+  };
+
+  const parseNot = (): TagQuery => {
+    if (isOperator(peek(), 'NOT')) {
+      position++;
+      return { kind: 'not', operand: parseNot() };
+    }
+    return parsePrimary();
+  };
+
+  const parsePrimary = (): TagQuery => {
+    const token = tokens[position++];
+    if (token === undefined) {
+      throw new Error(`Incomplete tag expression: ${expression}`);
+    }
+    if (token === '(') {
+      const inner = parseOr();
+      if (tokens[position++] !== ')') {
+        throw new Error(`Missing closing parenthesis in tag expression: ${expression}`);
+      }
+      return inner;
+    }
+    if (token === ')' || OPERATORS.includes(token.toUpperCase())) {
+      throw new Error(`Unexpected "${token}" in tag expression: ${expression}`);
+    }
+    return { kind: 'tag', tag: normalizeTag(token) };
+  };
+
+  if (tokens.length === 0) {
+    throw new Error('Tag expression is empty');
+  }
+  const query = parseOr();
+  if (position < tokens.length) {
+    throw new Error(`Unexpected "${tokens[position]}" in tag expression: ${expression}`);
+  }
+  return query;
+}
+
+/**
+ * Evaluate a query tree against the tags of an artifact
+ */
+export function matchesTagQuery(query: TagQuery, tags: string[] = []): boolean {
+  switch (query.kind) {
+    case 'tag':
+      return tags.some((tag) => normalizeTag(tag) === query.tag);
+    case 'not':
+      return !matchesTagQuery(query.operand, tags);
+    case 'and':
+      return matchesTagQuery(query.left, tags) && matchesTagQuery(query.right, tags);
+    case 'or':
+      return matchesTagQuery(query.left, tags) || matchesTagQuery(query.right, tags);
+  }
+}
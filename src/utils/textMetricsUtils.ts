@@ -0,0 +1,136 @@
+/**
+ * Text Metrics Utilities
+ *
+ * Size and readability metrics for artifact markdown: word and sentence
+ * counts, Flesch reading ease and the heading outline. Frontmatter and fenced
+ * code blocks are not counted.
+ */
+
+import { getBodyStartLine } from './markdownBase';
+
+export interface HeadingInfo {
+  level: number;
+  text: string;
+  line: number; // 1-based line number in the source file
+}
+
+export interface TextMetrics {
+  wordCount: number;
+  sentenceCount: number;
+  averageSentenceLength: number; // Words per sentence
+  readingEase: number | null; // Flesch reading ease (higher is easier); null without text
+  headings: HeadingInfo[];
+}
+
+export interface AggregateMetrics {
+  artifactCount: number;
+  totalWords: number;
+  averageWords: number;
+  minWords: number;
+  maxWords: number;
+  averageReadingEase: number | null;
+}
+
+const HEADING_PATTERN = /^(#{1,6})\s+(.*)$/;
+
+function round(value: number): number {
+  return Math.round(value * 10) / 10;
+}
+
+/**
+ * Estimate the syllables of an English word
+ */
+export function countSyllables(word: string): number {
+  const letters = word.toLowerCase().replace(/[^a-z]/g, '');
+  if (letters.length === 0) return 0;
+  if (letters.length <= 3) return 1;
+
+  // Silent endings: "jumped", "makes", "cake" (but not "created", "boxes", "table")
+  const stem = letters
+    .replace(/([^tdaeiouy])ed$/, '$1')
+    .replace(/([^aeiouyszxh])es$/, '$1')
+    .replace(/([^laeiouy])e$/, '$1');
+  const groups = stem.match(/[aeiouy]+/g);
+  return Math.max(groups ? groups.length : 0, 1);
+}
+
+/**
+ * Strip inline markdown so only the readable text remains
+ */
+function toPlainText(line: string): string {
+  return line
+    .replace(/!?\[([^\]]*)\]\([^)]*\)/g, '$1')
+    .replace(/^\s*(?:[-*+]|\d+\.)\s+/, '')
+    .replace(/^\s*>\s?/, '')
+    .replace(/[`*_~|]/g, ' ');
+}
+
+/**
+ * Compute size and readability metrics of an artifact markdown file
+ */
+export function computeTextMetrics(markdown: string): TextMetrics {
+  const lines = markdown.split('\n');
+  let index = getBodyStartLine(lines);
+
+  const headings: HeadingInfo[] = [];
+  let wordCount = 0;
+  let sentenceCount = 0;
+  let syllableCount = 0;
+  let inCodeFence = false;
+
+  for (; index < lines.length; index++) {
+    const line = lines[index];
+    if (line.trim().startsWith('```')) {
+      inCodeFence = !inCodeFence;
+      continue;
+    }
+    if (inCodeFence) continue;
+
+    const heading = line.match(HEADING_PATTERN);
+    if (heading) {
+      headings.push({ level: heading[1].length, text: heading[2].trim(), line: index + 1 });
+      continue;
+    }
+
+    const text = toPlainText(line);
+    const words = text.match(/[A-Za-z0-9][\w'-]*/g) || [];
+    if (words.length === 0) continue;
+
+    wordCount += words.length;
+    syllableCount += words.reduce((sum, word) => sum + countSyllables(word), 0);
+    // A line without terminal punctuation (e.g. a list item) still counts as one sentence
+    sentenceCount += text.split(/[.!?]+(?=\s|$)/).filter((part) => /\w/.test(part)).length;
+  }
+
+  const readingEase =
+    wordCount > 0
+      ? round(206.835 - 1.015 * (wordCount / sentenceCount) - 84.6 * (syllableCount / wordCount))
+      : null;
+
+  return {
+    wordCount,
+    sentenceCount,
+    averageSentenceLength: sentenceCount > 0 ? round(wordCount / sentenceCount) : 0,
+    readingEase,
+    headings,
+  };
+}
+
+/**
+ * Combine the metrics of several artifacts (e.g. all artifacts of a type)
+ */
+export function aggregateTextMetrics(metrics: TextMetrics[]): AggregateMetrics {
+  const words = metrics.map((m) => m.wordCount);
+  const scores = metrics.map((m) => m.readingEase).filter((s): s is number => s !== null);
+  const totalWords = words.reduce((sum, count) => sum + count, 0);
+
+  return {
+    artifactCount: metrics.length,
+    totalWords,
+    averageWords: metrics.length > 0 ? round(totalWords / metrics.length) : 0,
+    minWords: words.length > 0 ? Math.min(...words) : 0,
+    maxWords: words.length > 0 ? Math.max(...words) : 0,
+    averageReadingEase:
+      scores.length > 0 ? round(scores.reduce((sum, s) => sum + s, 0) / scores.length) : null,
+  };
+}
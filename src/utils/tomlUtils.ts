@@ -0,0 +1,205 @@
+/**
+ * TOML Utilities
+ *
+ * Reads the TOML that other tools (Hugo, Zola) put in +++ fenced
+ * frontmatter. Covers what frontmatter uses: key/value pairs with dotted or
+ * quoted keys, all string forms, numbers, booleans, arrays, inline tables and
+ * [table] / [[array of tables]] sections. Dates and times are kept as their
+ * text.
+ */
+
+const NUMBER_PATTERN =
+  /^(?:0x[0-9a-f_]+|0o[0-7_]+|0b[01_]+|[+-]?[\d_]+(?:\.[\d_]+)?(?:e[+-]?\d+)?)$/i;
+// Dates, date-times and times, which are kept as text
+const DATE_PATTERN =
+  /^\d{4}-\d{2}-\d{2}(?:T[\d:.]+(?:Z|[+-]\d{2}:\d{2})?)?$|^\d{2}:\d{2}:[\d.]+$/;
+
+const ESCAPES: Record<string, string> = {
+  b: '\b',
+  t: '\t',
+  n: '\n',
+  f: '\f',
+  r: '\r',
+  '"': '"',
+  '\\': '\\',
+};
+
+type Table = Record<string, unknown>;
+
+/**
+ * Parse a TOML document; throws with the line number on invalid input
+ */
+export function parseToml(text: string): Table {
+  const root: Table = {};
+  let table = root;
+  let pos = 0;
+
+  const fail = (message: string): never => {
+    const line = text.slice(0, pos).split('\n').length;
+    throw new Error(`Invalid TOML at line ${line}: ${message}`);
+  };
+
+  const skipSpace = () => {
+    while (text[pos] === ' ' || text[pos] === '\t') pos++;
+  };
+  const skipComment = () => {
+    if (text[pos] === '#') while (pos < text.length && text[pos] !== '\n') pos++;
+  };
+  // Whitespace, comments and line breaks, e.g. between array items
+  const skipBlank = () => {
+    for (;;) {
+      skipSpace();
+      skipComment();
+      if (text[pos] !== '\n' && text[pos] !== '\r') return;
+      pos++;
+    }
+  };
+
+  const readString = (): string => {
+    const quote = text[pos];
+    const delimiter = text.startsWith(quote.repeat(3), pos) ? quote.repeat(3) : quote;
+    const multiline = delimiter.length === 3;
+    pos += delimiter.length;
+    if (multiline && text.startsWith('\r\n', pos)) pos += 2;
+    else if (multiline && text[pos] === '\n') pos++;
+
+    let value = '';
+    while (!text.startsWith(delimiter, pos)) {
+      if (pos >= text.length || (!multiline && text[pos] === '\n')) fail('unterminated string');
+      const char = text[pos++];
+      if (char !== '\\' || quote === "'") {
+        value += char;
+        continue;
+      }
+      const code = text[pos++];
+      if (code === 'u' || code === 'U') {
+        const length = code === 'u' ? 4 : 8;
+        value += String.fromCodePoint(parseInt(text.slice(pos, pos + length), 16));
+        pos += length;
+      } else if (multiline && /\s/.test(code)) {
+        // A backslash at the end of a line joins it with the next
+        while (/\s/.test(text[pos] ?? '')) pos++;
+      } else if (code in ESCAPES) {
+        value += ESCAPES[code];
+      } else {
+        fail(`invalid escape \\${code}`);
+      }
+    }
+    pos += delimiter.length;
+    return value;
+  };
+
+  const readKey = (): string[] => {
+    const parts: string[] = [];
+    for (;;) {
+      skipSpace();
+      if (text[pos] === '"' || text[pos] === "'") {
+        parts.push(readString());
+      } else {
+        const bare = /^[A-Za-z0-9_-]+/.exec(text.slice(pos))?.[0];
+        if (!bare) fail('expected a key');
+        parts.push(bare!);
+        pos += bare!.length;
+      }
+      skipSpace();
+      if (text[pos] !== '.') return parts;
+      pos++;
+    }
+  };
+
+  // The table at key within parent, created when missing; the last entry of
+  // an array of tables
+  const descend = (parent: Table, key: string): Table => {
+    const existing = parent[key];
+    if (existing === undefined) {
+      const created: Table = {};
+      parent[key] = created;
+      return created;
+    }
+    if (Array.isArray(existing)) return existing[existing.length - 1] as Table;
+    if (typeof existing === 'object' && existing !== null) return existing as Table;
+    return fail(`${key} is not a table`);
+  };
+
+  const assign = (target: Table, keys: string[], value: unknown) => {
+    const parent = keys.slice(0, -1).reduce(descend, target);
+    const key = keys[keys.length - 1];
+    if (key in parent) fail(`${keys.join('.')} is defined twice`);
+    parent[key] = value;
+  };
+
+  const readValue = (): unknown => {
+    skipSpace();
+    const char = text[pos];
+    if (char === '"' || char === "'") return readString();
+    if (char === '[') {
+      pos++;
+      const items: unknown[] = [];
+      for (;;) {
+        skipBlank();
+        if (text[pos] === ']') break;
+        items.push(readValue());
+        skipBlank();
+        if (text[pos] === ',') pos++;
+        else if (text[pos] !== ']') fail('expected , or ]');
+      }
+      pos++;
+      return items;
+    }
+    if (char === '{') {
+      pos++;
+      const inline: Table = {};
+      skipSpace();
+      while (text[pos] !== '}') {
+        const keys = readKey();
+        if (text[pos++] !== '=') fail('expected =');
+        assign(inline, keys, readValue());
+        skipSpace();
+        if (text[pos] === ',') pos++;
+        else if (text[pos] !== '}') fail('expected , or }');
+        skipSpace();
+      }
+      pos++;
+      return inline;
+    }
+
+    const token = /^[^\s,\]}#]+/.exec(text.slice(pos))?.[0] ?? '';
+    pos += token.length;
+    if (token === 'true' || token === 'false') return token === 'true';
+    if (NUMBER_PATTERN.test(token)) return Number(token.replace(/_/g, ''));
+    if (DATE_PATTERN.test(token)) return token;
+    return fail(token ? `invalid value ${token}` : 'expected a value');
+  };
+
+  for (;;) {
+    skipBlank();
+    if (pos >= text.length) return root;
+    if (text[pos] === '[') {
+      const isArray = text[pos + 1] === '[';
+      pos += isArray ? 2 : 1;
+      const keys = readKey();
+      const close = isArray ? ']]' : ']';
+      if (!text.startsWith(close, pos)) fail(`expected ${close}`);
+      pos += close.length;
+      if (isArray) {
+        const parent = keys.slice(0, -1).reduce(descend, root);
+        const key = keys[keys.length - 1];
+        if (parent[key] === undefined) parent[key] = [];
+        if (!Array.isArray(parent[key])) fail(`${keys.join('.')} is not an array of tables`);
+        table = {};
+        (parent[key] as Table[]).push(table);
+      } else {
+        table = keys.reduce(descend, root);
+      }
+    } else {
+      const keys = readKey();
+      if (text[pos++] !== '=') fail('expected =');
+      assign(table, keys, readValue());
+    }
+    skipSpace();
+    skipComment();
+    if (pos < text.length && text[pos] !== '\n' && text[pos] !== '\r') {
+      fail('expected the end of the line');
+    }
+  }
+}
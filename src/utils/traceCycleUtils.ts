@@ -0,0 +1,129 @@
+/**
+ * Trace Cycle Utilities
+ *
+ * Derivation and refinement links say that one artifact is a breakdown of
+ * another, so following them must never lead back to where it started: if
+ * SYS-001 derives to REQ-010 and REQ-010 is refined by SYS-001, neither is the
+ * decomposition of the other. Both kinds are read as edges from the detailed
+ * artifact to the one it breaks down, whichever end the link was stored on.
+ */
+
+import type { Link } from '../types';
+import type { LinkType } from './linkTypes';
+
+type LinkEnds = Pick<Link, 'sourceId' | 'targetId' | 'type'>;
+
+// Stored as detailed → broken-down artifact
+const UPWARD_TYPES: LinkType[] = ['derived_from', 'refines'];
+// Stored as broken-down → detailed artifact
+const DOWNWARD_TYPES: LinkType[] = ['derives_to', 'refined_by'];
+
+export const DECOMPOSITION_LINK_TYPES: LinkType[] = [...UPWARD_TYPES, ...DOWNWARD_TYPES];
+
+/**
+ * Whether the link type takes part in cycle checks
+ */
+export function isDecompositionLink(type: LinkType): boolean {
+  return DECOMPOSITION_LINK_TYPES.includes(type);
+}
+
+// [detailed, broken-down] for decomposition links, null for all others
+function toEdge(link: LinkEnds): [string, string] | null {
+  if (UPWARD_TYPES.includes(link.type)) return [link.sourceId, link.targetId];
+  if (DOWNWARD_TYPES.includes(link.type)) return [link.targetId, link.sourceId];
+  return null;
+}
+
+function buildGraph(links: LinkEnds[]): Map<string, string[]> {
+  const graph = new Map<string, string[]>();
+  links.forEach((link) => {
+    const edge = toEdge(link);
+    if (!edge) return;
+    const [from, to] = edge;
+    const next = graph.get(from) || [];
+    if (!next.includes(to)) next.push(to);
+    graph.set(from, next);
+  });
+  return graph;
+}
+
+// Same cycle no matter which artifact it is reported from
+function cycleKey(cycle: string[]): string {
+  const nodes = cycle.slice(0, -1);
+  const start = nodes.indexOf([...nodes].sort()[0]);
+  return [...nodes.slice(start), ...nodes.slice(0, start)].join('>');
+}
+
+/**
+ * Cycles in the derivation and refinement links, each as the path of artifact
+ * IDs from the detailed artifact upwards, ending where it started
+ * (REQ-001 → SYS-001 → REQ-001). One path is reported per closing link.
+ */
+export function findTraceCycles(links: LinkEnds[]): string[][] {
+  const graph = buildGraph(links);
+  const cycles = new Map<string, string[]>();
+  const done = new Set<string>();
+  const path: string[] = [];
+  const onPath = new Set<string>();
+
+  const visit = (id: string) => {
+    path.push(id);
+    onPath.add(id);
+    for (const next of graph.get(id) || []) {
+      if (onPath.has(next)) {
+        const cycle = [...path.slice(path.indexOf(next)), next];
+        const key = cycleKey(cycle);
+        if (!cycles.has(key)) cycles.set(key, cycle);
+      } else if (!done.has(next)) {
+        visit(next);
+      }
+    }
+    path.pop();
+    onPath.delete(id);
+    done.add(id);
+  };
+
+  [...graph.keys()].sort().forEach((id) => {
+    if (!done.has(id)) visit(id);
+  });
+  return [...cycles.values()];
+}
+
+/**
+ * The cycle that adding the link would close, or null when it closes none
+ */
+export function findCycleThroughLink(links: LinkEnds[], link: LinkEnds): string[] | null {
+  const edge = toEdge(link);
+  if (!edge) return null;
+  const [from, to] = edge;
+  if (from === to) return [from, from];
+
+  // Breadth-first from the broken-down end back to the detailed end
+  const graph = buildGraph(links);
+  const previous = new Map<string, string>([[to, to]]);
+  const queue = [to];
+  while (queue.length > 0) {
+    const id = queue.shift()!;
+    if (id === from) {
+      const cycle = [from];
+      for (let step = from; step !== to; step = previous.get(step)!) {
+        cycle.unshift(previous.get(step)!);
+      }
+      return [from, ...cycle];
+    }
+    for (const next of graph.get(id) || []) {
+      if (!previous.has(next)) {
+        previous.set(next, id);
+        queue.push(next);
+      }
+    }
+  }
+  return null;
+}
+
+/**
+ * "REQ-001 → SYS-001 → REQ-001"
+ */
+export function formatCyclePath(cycle: string[]): string {
+  return cycle.join(' → ');
+}
@@ -0,0 +1,103 @@
+/**
+ * ZIP Utilities
+ *
+ * Minimal ZIP archive writer for export bundles. Entries are stored without
+ * compression, which keeps the writer small and the archive readable by any
+ * unzip tool.
+ */
+
+export interface ZipEntry {
+  path: string; // Forward slashes, e.g. "artifacts/requirements/REQ-001.md"
+  data: Uint8Array | string;
+}
+
+let crcTable: Uint32Array | null = null;
+
+/**
+ * CRC-32 (IEEE 802.3) checksum as stored in ZIP headers
+ */
+export function crc32(data: Uint8Array): number {
+  if (!crcTable) {
+    crcTable = new Uint32Array(256);
+    for (let n = 0; n < 256; n++) {
+      let c = n;
+      for (let k = 0; k < 8; k++) c = c & 1 ? 0xedb88320 ^ (c >>> 1) : c >>> 1;
+      crcTable[n] = c >>> 0;
+    }
+  }
+  let crc = 0xffffffff;
+  for (const byte of data) crc = crcTable[(crc ^ byte) & 0xff] ^ (crc >>> 8);
+  return (crc ^ 0xffffffff) >>> 0;
+}
+
+// DOS date and time fields of a ZIP header
+function dosDateTime(date: Date): { time: number; date: number } {
+  return {
+    time: (date.getHours() << 11) | (date.getMinutes() << 5) | (date.getSeconds() >> 1),
+    date: ((date.getFullYear() - 1980) << 9) | ((date.getMonth() + 1) << 5) | date.getDate(),
+  };
+}
+
+/**
+ * Build a ZIP archive from the given entries, in order
+ */
+export function createZip(entries: ZipEntry[], modified: Date = new Date()): Uint8Array {
+  const encoder = new TextEncoder();
+  const { time, date } = dosDateTime(modified);
+  const localParts: Uint8Array[] = [];
+  const centralParts: Uint8Array[] = [];
+  let offset = 0;
+
+  for (const entry of entries) {
+    const name = encoder.encode(entry.path);
+    const data = typeof entry.data === 'string' ? encoder.encode(entry.data) : entry.data;
+    const crc = crc32(data);
+
+    const local = new DataView(new ArrayBuffer(30));
+    local.setUint32(0, 0x04034b50, true); // Local file header signature
+    local.setUint16(4, 20, true); // Version needed (2.0)
+    local.setUint16(6, 0x0800, true); // UTF-8 file names
+    local.setUint16(8, 0, true); // Stored
+    local.setUint16(10, time, true);
+    local.setUint16(12, date, true);
+    local.setUint32(14, crc, true);
+    local.setUint32(18, data.length, true);
+    local.setUint32(22, data.length, true);
+    local.setUint16(26, name.length, true);
+    localParts.push(new Uint8Array(local.buffer), name, data);
+
+    const central = new DataView(new ArrayBuffer(46));
+    central.setUint32(0, 0x02014b50, true); // Central directory header signature
+    central.setUint16(4, 20, true); // Version made by
+    central.setUint16(6, 20, true);
+    central.setUint16(8, 0x0800, true);
+    central.setUint16(10, 0, true);
+    central.setUint16(12, time, true);
+    central.setUint16(14, date, true);
+    central.setUint32(16, crc, true);
+    central.setUint32(20, data.length, true);
+    central.setUint32(24, data.length, true);
+    central.setUint16(28, name.length, true);
+    central.setUint32(42, offset, true); // Offset of the local header
+    centralParts.push(new Uint8Array(central.buffer), name);
+
+    offset += 30 + name.length + data.length;
+  }
+
+  const centralSize = centralParts.reduce((sum, part) => sum + part.length, 0);
+  const end = new DataView(new ArrayBuffer(22));
+  end.setUint32(0, 0x06054b50, true); // End of central directory signature
+  end.setUint16(8, entries.length, true);
+  end.setUint16(10, entries.length, true);
+  end.setUint32(12, centralSize, true);
+  end.setUint32(16, offset, true);
+
+  const parts = [...localParts, ...centralParts, new Uint8Array(end.buffer)];
+  const result = new Uint8Array(parts.reduce((sum, part) => sum + part.length, 0));
+  let position = 0;
+  for (const part of parts) {
+    result.set(part, position);
+    position += part.length;
+  }
+  return result;
+}